@@ -0,0 +1,51 @@
+//! Alarm Signal action — a non-verbal warning call to the herd.
+//!
+//! Reads:  nothing beyond the caller's own species
+//! Writes: SimEvent::ActionStarted (picked up by `emit_alarm_calls`)
+//! Upstream: any agent that can vocalize; herd animals also sound it
+//!           reflexively when they start to flee
+//! Downstream: perception::hear_alarm_signals raises fear in same-species
+//!             listeners. No triples are shared — the call says "danger",
+//!             not what or where.
+
+use crate::agent::actions::ActionType;
+use crate::agent::actions::channel::{Channel, ChannelUsage};
+use crate::agent::actions::definition::{
+    ActionDefinition, CompletionPredicate, Hooks, PlanValidity, TargetEffects,
+};
+use crate::agent::actions::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
+use crate::agent::actions::registry::{ActionKind, TargetSource};
+use crate::constants::actions::alarm_signal::DURATION_TICKS;
+
+const CHANNELS: &[ChannelUsage] = &[ChannelUsage::new(Channel::Vocalization, 0.8)];
+
+pub static ALARM_SIGNAL_DEF: ActionDefinition = ActionDefinition {
+    action_type: ActionType::AlarmSignal,
+    kind: ActionKind::Timed {
+        duration_ticks: DURATION_TICKS,
+    },
+    target_source: TargetSource::None,
+    base_cost: 0.5,
+    primitive: ActionPrimitive::Vocalize,
+    target_selector: TargetSelector::InPlace,
+    intensity: IntensityPolicy::Fixed(1.0),
+    intent: Intent::Safety,
+    body_channels: CHANNELS,
+    posture: None,
+    interruptible: true,
+    start_log: Some("sounded an alarm"),
+    complete_log: None,
+    joy_per_sec: 0.0,
+    stomach_carbs_per_sec: 0.0,
+    preconditions: &[],
+    plan_effects: &[],
+    plan_consumes: &[],
+    target_effects: TargetEffects::Static,
+    plan_validity: PlanValidity::Always,
+    gates: &[],
+    satiation: None,
+    completion: CompletionPredicate::Never,
+    on_complete_ops: &[],
+    hooks: Hooks::EMPTY,
+    recipe: None,
+};
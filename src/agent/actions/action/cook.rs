@@ -3,7 +3,7 @@
 //! Reads:  agent inventory (raw food), MindGraph (HeatEmitting belief gate)
 //! Writes: agent inventory (cooked Thing with freshness), SimEvent::ActionCompleted
 //! Upstream: rational brain GOAP planner (chains Cook before Eat for storage / quality)
-//! Downstream: eat_on_complete (cooked food yields better macros via `food_macros`)
+//! Downstream: eat_on_progress (cooked food yields better macros via `food_macros`)
 
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::{Channel, ChannelUsage, Posture};
@@ -0,0 +1,126 @@
+//! Drop action — set a whole stack of one item down on the agent's tile.
+//!
+//! The inverse of `Pickup`: frees carry capacity so a full-handed agent can
+//! collect something worth more. The dropped Things keep their properties
+//! (freshness, provenance) and land in a `GroundItem` anyone can pick up.
+//! The survival brain proposes this when a hungry agent's inventory is full
+//! and food is in view; [`least_valuable_stack`] picks what goes.
+
+use crate::agent::actions::ActionType;
+use crate::agent::actions::channel::{Channel, ChannelUsage, Posture};
+use crate::agent::actions::definition::{
+    ActionDefinition, CompletionPredicate, Gate, Hooks, PlanValidity, TargetEffects,
+};
+use crate::agent::actions::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
+use crate::agent::actions::registry::{ActionKind, CompletionContext, SpawnRequest, TargetSource};
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::{Concept, Ontology};
+use crate::constants::actions::drop::DURATION_TICKS;
+
+const CHANNELS: &[ChannelUsage] = &[ChannelUsage::new(Channel::Manipulation, 0.5)];
+
+pub static DROP_DEF: ActionDefinition = ActionDefinition {
+    action_type: ActionType::Drop,
+    kind: ActionKind::Timed {
+        duration_ticks: DURATION_TICKS,
+    },
+    target_source: TargetSource::None,
+    base_cost: 1.0,
+    primitive: ActionPrimitive::Manipulate,
+    target_selector: TargetSelector::InPlace,
+    intensity: IntensityPolicy::Fixed(0.0),
+    intent: Intent::Goal,
+    body_channels: CHANNELS,
+    posture: Some(Posture::Stationary),
+    interruptible: true,
+    start_log: None,
+    complete_log: Some("dropped"),
+    joy_per_sec: 0.0,
+    stomach_carbs_per_sec: 0.0,
+    preconditions: &[],
+    plan_effects: &[],
+    plan_consumes: &[],
+    target_effects: TargetEffects::Static,
+    plan_validity: PlanValidity::Always,
+    gates: &[Gate::InventoryNonEmpty],
+    satiation: None,
+    completion: CompletionPredicate::Never,
+    on_complete_ops: &[],
+    hooks: Hooks {
+        on_complete: Some(drop_on_complete),
+        ..Hooks::EMPTY
+    },
+    recipe: None,
+};
+
+/// The stack an agent would miss least. Inedible items (including food
+/// that has rotted) go before edible ones; among equals the largest stack
+/// goes first since it frees the most room. Remaining ties fall back to
+/// declaration order so the choice doesn't depend on hash iteration.
+pub fn least_valuable_stack(inventory: &ItemSlots, ontology: &Ontology) -> Option<Concept> {
+    inventory
+        .group_by_concept()
+        .into_iter()
+        .min_by_key(|(concept, count)| {
+            (
+                ontology.has_trait(*concept, Concept::Edible),
+                std::cmp::Reverse(*count),
+                *concept as u32,
+            )
+        })
+        .map(|(concept, _)| concept)
+}
+
+/// Move every Thing of the least valuable concept onto the ground.
+fn drop_on_complete(ctx: &mut CompletionContext) {
+    let Some(concept) = least_valuable_stack(ctx.inventory, &ctx.mind.ontology) else {
+        return;
+    };
+    let mut things = Vec::new();
+    while let Some(thing) = ctx.inventory.remove_thing_unchecked(concept) {
+        things.push(thing);
+    }
+    ctx.spawn_requests.push(SpawnRequest::GroundItem {
+        position: ctx.agent_position,
+        things,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::setup_ontology;
+
+    #[test]
+    fn inedible_stack_is_dropped_before_food() {
+        let ontology = setup_ontology();
+        let mut inventory = ItemSlots::agent_carry();
+        inventory.add(Concept::Apple, 3);
+        inventory.add(Concept::Stone, 1);
+        assert_eq!(
+            least_valuable_stack(&inventory, &ontology),
+            Some(Concept::Stone)
+        );
+    }
+
+    #[test]
+    fn larger_stack_is_dropped_among_equals() {
+        let ontology = setup_ontology();
+        let mut inventory = ItemSlots::agent_carry();
+        inventory.add(Concept::Stick, 1);
+        inventory.add(Concept::Stone, 4);
+        assert_eq!(
+            least_valuable_stack(&inventory, &ontology),
+            Some(Concept::Stone)
+        );
+    }
+
+    #[test]
+    fn empty_inventory_has_nothing_to_drop() {
+        let ontology = setup_ontology();
+        assert_eq!(
+            least_valuable_stack(&ItemSlots::agent_carry(), &ontology),
+            None
+        );
+    }
+}
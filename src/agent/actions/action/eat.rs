@@ -1,8 +1,12 @@
 //! Eat action — consume food from inventory.
 //!
-//! The metabolism's `eat()` returns false when the stomach is full; the
-//! inventory item is only removed if the metabolism accepted the food,
-//! otherwise the berry stays put until digestion makes room.
+//! Eating accrues bite by bite over the action's duration, so an agent
+//! interrupted halfway has had half a meal. The first bite takes the item
+//! out of the pouch; the rest of the action keeps eating it. The
+//! metabolism's `eat()` returns false when the stomach is full; the item
+//! is only claimed once a bite has been accepted, otherwise the berry
+//! stays put until digestion makes room. Stale food feeds less: every bite
+//! is scaled by the claimed item's freshness.
 
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::{Channel, ChannelUsage};
@@ -11,10 +15,10 @@ use crate::agent::actions::definition::{
     SatiationGate, TargetEffects,
 };
 use crate::agent::actions::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
-use crate::agent::actions::registry::{ActionKind, CompletionContext, TargetSource};
+use crate::agent::actions::registry::{Accrual, ActionKind, CompletionContext, TargetSource};
 use crate::agent::body::metabolism::{FALLBACK_MEAL, food_macros};
 use crate::agent::mind::knowledge::{Concept, Node, Predicate};
-use crate::constants::actions::eat::{DURATION_TICKS, STAMINA_GAIN};
+use crate::constants::actions::eat::{DURATION_TICKS, STALE_NUTRITION_FLOOR, STAMINA_GAIN};
 
 const CHANNELS: &[ChannelUsage] = &[ChannelUsage::new(Channel::Consumption, 0.8)];
 
@@ -50,27 +54,63 @@ pub static EAT_DEF: ActionDefinition = ActionDefinition {
     completion: CompletionPredicate::Never,
     on_complete_ops: &[],
     hooks: Hooks {
-        on_complete: Some(eat_on_complete),
+        on_progress: Some(eat_on_progress),
         ..Hooks::EMPTY
     },
     recipe: None,
 };
 
-fn eat_on_complete(ctx: &mut CompletionContext) {
-    // Pick the first food item (IsA Food) from inventory. Unknown edibles
-    // fall back to a generic meal so the action always produces some satiety.
-    let concept = ctx
-        .inventory
-        .all_items()
-        .find(|item| ctx.mind.is_a(&Node::Concept(item.concept), Concept::Food))
-        .map(|t| t.concept);
-    if let Some(concept) = concept {
-        let macros = food_macros(concept).unwrap_or(FALLBACK_MEAL);
-        // Only consume the inventory item if metabolism actually accepted
-        // the food; otherwise a full stomach silently loses the item.
-        if ctx.physical.metabolism.eat(macros) {
+fn eat_on_progress(ctx: &mut CompletionContext, accrual: &mut Accrual, done: f32) {
+    let share = done - accrual.applied;
+    ctx.physical.stamina.adjust_aerobic(STAMINA_GAIN * share);
+
+    // Keep eating what the first bite claimed. Otherwise pick the first
+    // food item (IsA Food) from inventory; unknown edibles fall back to a
+    // generic meal so the action always produces some satiety.
+    let claimed = accrual.item.map(|concept| (concept, accrual.freshness));
+    let food = claimed.or_else(|| {
+        ctx.inventory
+            .all_items()
+            .find(|item| ctx.mind.is_a(&Node::Concept(item.concept), Concept::Food))
+            .map(|t| (t.concept, t.properties.freshness))
+    });
+    let Some((concept, freshness)) = food else {
+        return;
+    };
+    let macros = food_macros(concept)
+        .unwrap_or(FALLBACK_MEAL)
+        .scaled(share * freshness_factor(freshness));
+    // Only claim the inventory item once metabolism actually accepted a
+    // bite; otherwise a full stomach silently loses the item.
+    if ctx.physical.metabolism.eat(macros) {
+        if accrual.item.is_none() {
             ctx.inventory.remove(concept, 1);
+            accrual.item = Some(concept);
+            accrual.freshness = freshness;
         }
+        ctx.physical.last_ate_tick = ctx.tick;
+    }
+}
+
+/// Share of a meal an item of this freshness delivers. Items that don't
+/// perish (`None`) feed in full; stale ones bottom out at
+/// `STALE_NUTRITION_FLOOR`.
+pub fn freshness_factor(freshness: Option<f32>) -> f32 {
+    match freshness {
+        Some(f) => STALE_NUTRITION_FLOOR + (1.0 - STALE_NUTRITION_FLOOR) * f.clamp(0.0, 1.0),
+        None => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staler_food_feeds_less_down_to_the_floor() {
+        assert_eq!(freshness_factor(None), 1.0);
+        assert_eq!(freshness_factor(Some(1.0)), 1.0);
+        assert_eq!(freshness_factor(Some(0.0)), STALE_NUTRITION_FLOOR);
+        assert!(freshness_factor(Some(0.3)) < freshness_factor(Some(0.8)));
     }
-    ctx.physical.stamina.adjust_aerobic(STAMINA_GAIN);
 }
@@ -3,7 +3,7 @@
 //! Reads:  agent position, world map (water-adjacency gate)
 //! Writes: agent inventory (Fish item with freshness stamp), SimEvent::ActionCompleted
 //! Upstream: rational brain proposing Fish for hunger when meat is far and water is near
-//! Downstream: eat_on_complete (Fish → satiety via `food_macros`), perishable decay
+//! Downstream: eat_on_progress (Fish → satiety via `food_macros`), perishable decay
 
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::{Channel, ChannelUsage, Posture};
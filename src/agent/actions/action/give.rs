@@ -0,0 +1,147 @@
+//! Give action — hand a few of a carried item to a nearby agent.
+//!
+//! Reads:  agent inventory, target inventory (when lent), MindGraph (food
+//!         ontology), SocialGraph affection toward the target
+//! Writes: agent inventory (up to `QUANTITY` items removed), target
+//!         inventory directly or via `SpawnRequest::Handover`
+//! Upstream: emotional brain (`propose_give`) toward a hungry friend in reach
+//! Downstream: psyche::gifts (SocialInteraction + both sides re-perceive
+//!             each other's inventories)
+//!
+//! Share Food's general-purpose sibling: it moves a quantity rather than a
+//! single item, and prefers food but will hand over anything. A living
+//! recipient's `ItemSlots` is never lent as `target_inventory` (execution
+//! iterates the agents mutably), so the handover is deferred to a
+//! [`SpawnRequest::Handover`] that lands before `psyche::gifts` runs.
+
+use crate::agent::actions::ActionType;
+use crate::agent::actions::channel::{Channel, ChannelUsage, Posture};
+use crate::agent::actions::definition::{
+    ActionDefinition, CompletionPredicate, Gate, Hooks, PlanValidity, TargetEffects,
+};
+use crate::agent::actions::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
+use crate::agent::actions::registry::{ActionKind, CompletionContext, SpawnRequest, TargetSource};
+use crate::agent::events::FailureReason;
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::{Concept, MindGraph, Node};
+use crate::constants::actions::give::{DURATION_TICKS, MIN_AFFECTION, QUANTITY, REACH};
+
+const CHANNELS: &[ChannelUsage] = &[ChannelUsage::new(Channel::Manipulation, 0.3)];
+
+pub static GIVE_DEF: ActionDefinition = ActionDefinition {
+    action_type: ActionType::Give,
+    kind: ActionKind::Timed {
+        duration_ticks: DURATION_TICKS,
+    },
+    target_source: TargetSource::Implicit,
+    base_cost: 1.0,
+    primitive: ActionPrimitive::Manipulate,
+    target_selector: TargetSelector::InPlace,
+    intensity: IntensityPolicy::Fixed(0.0),
+    intent: Intent::Social,
+    body_channels: CHANNELS,
+    posture: Some(Posture::Stationary),
+    interruptible: true,
+    start_log: Some("offering a gift"),
+    complete_log: Some("gave a gift"),
+    joy_per_sec: 1.0,
+    stomach_carbs_per_sec: 0.0,
+    preconditions: &[],
+    plan_effects: &[],
+    plan_consumes: &[],
+    target_effects: TargetEffects::Static,
+    plan_validity: PlanValidity::Always,
+    gates: &[
+        Gate::TargetEntity(FailureReason::NoTarget),
+        Gate::InventoryNonEmpty,
+        Gate::TargetWithin(REACH),
+        Gate::TargetAffectionAtLeast(MIN_AFFECTION),
+    ],
+    satiation: None,
+    completion: CompletionPredicate::Never,
+    on_complete_ops: &[],
+    hooks: Hooks {
+        on_complete: Some(give_on_complete),
+        ..Hooks::EMPTY
+    },
+    recipe: None,
+};
+
+/// What the giver hands over: the first food item, else the first item of
+/// any kind. `accepts` filters out concepts the recipient can't hold.
+pub fn gift_concept(
+    inventory: &ItemSlots,
+    mind: &MindGraph,
+    accepts: impl Fn(Concept) -> bool,
+) -> Option<Concept> {
+    let mut candidates = inventory
+        .all_items()
+        .map(|t| t.concept)
+        .filter(|&c| accepts(c));
+    let first = candidates.next()?;
+    if mind.is_a(&Node::Concept(first), Concept::Food) {
+        return Some(first);
+    }
+    candidates
+        .find(|&c| mind.is_a(&Node::Concept(c), Concept::Food))
+        .or(Some(first))
+}
+
+/// Move up to `QUANTITY` of one concept from the giver to the recipient,
+/// properties intact. Deposits straight into a lent `target_inventory`
+/// (rolling back rejects); otherwise queues a handover to the target.
+fn give_on_complete(ctx: &mut CompletionContext) {
+    let Some(recipient) = ctx.target_entity else {
+        return;
+    };
+    if let Some(target_inv) = ctx.target_inventory.as_deref_mut() {
+        let Some(concept) = gift_concept(ctx.inventory, ctx.mind, |c| {
+            target_inv.slots.iter().any(|s| s.can_deposit(c, 1, None))
+        }) else {
+            return;
+        };
+        for _ in 0..QUANTITY {
+            let Some(thing) = ctx.inventory.remove_thing(concept) else {
+                break;
+            };
+            if !target_inv.deposit_thing(thing.clone(), None) {
+                ctx.inventory.add_thing(thing);
+                break;
+            }
+        }
+        return;
+    }
+
+    let Some(concept) = gift_concept(ctx.inventory, ctx.mind, |_| true) else {
+        return;
+    };
+    let things: Vec<_> = (0..QUANTITY)
+        .map_while(|_| ctx.inventory.remove_thing(concept))
+        .collect();
+    if !things.is_empty() {
+        ctx.spawn_requests
+            .push(SpawnRequest::Handover { recipient, things });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::setup_ontology;
+
+    #[test]
+    fn gift_prefers_food_over_whatever_comes_first() {
+        let mind = MindGraph::new(setup_ontology());
+        let mut inventory = ItemSlots::agent_carry();
+        inventory.add(Concept::Stone, 3);
+        inventory.add(Concept::Apple, 1);
+        assert_eq!(
+            gift_concept(&inventory, &mind, |_| true),
+            Some(Concept::Apple)
+        );
+        assert_eq!(
+            gift_concept(&inventory, &mind, |c| c != Concept::Apple),
+            Some(Concept::Stone)
+        );
+    }
+}
@@ -12,8 +12,9 @@ use crate::agent::actions::registry::{ActionKind, LegCompleteContext, LegResult,
 use crate::agent::brains::thinking::{SearchDomain, SearchFilter};
 use crate::agent::mind::explored_tiles::ExploredTiles;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use crate::agent::mind::sight_memory::LAST_KNOWN_SEARCH_RADIUS_TILES;
 use crate::world::entity_positions::WorldEntityPositions;
-use crate::world::map::{CHUNK_SIZE, WorldMap};
+use crate::world::map::{CHUNK_SIZE, TILE_SIZE, WorldMap};
 use bevy::math::IVec2;
 use bevy::prelude::Vec2;
 
@@ -91,6 +92,14 @@ pub fn pick_look_for_target(
         "LookFor dispatched with an empty SearchFilter — derive_search_concept should never return an empty filter"
     );
 
+    // A moving target seen recently is worth checking before any
+    // unexplored chunk: head for where it was last seen.
+    if filter.domain == SearchDomain::WorldEntity
+        && let Some(tile) = nearest_last_known_match(current_pos, mind, &filter)
+    {
+        return Some(world_map.tile_to_world(tile.0, tile.1));
+    }
+
     let hint_chunks = match filter.domain {
         SearchDomain::Inventory => collect_producer_hint_chunks(mind, world_positions, &filter),
         SearchDomain::WorldTile | SearchDomain::WorldEntity => Vec::new(),
@@ -107,6 +116,37 @@ pub fn pick_look_for_target(
     picked.or_else(|| pick_explore_target(current_pos, explored, world_map, current_tick, rng))
 }
 
+/// Nearest `LastKnownAt` tile of an entity matching `filter`, skipping
+/// positions the agent is already standing at (they were searched).
+fn nearest_last_known_match(
+    current_pos: Vec2,
+    mind: &MindGraph,
+    filter: &SearchFilter,
+) -> Option<(i32, i32)> {
+    let here = (
+        (current_pos.x / TILE_SIZE).floor() as i32,
+        (current_pos.y / TILE_SIZE).floor() as i32,
+    );
+    mind.query(None, Some(Predicate::LastKnownAt), None)
+        .into_iter()
+        .filter_map(|triple| {
+            let Value::Tile(tile) = triple.object else {
+                return None;
+            };
+            let Some(Value::Concept(concept)) = mind.get(&triple.subject, Predicate::IsA) else {
+                return None;
+            };
+            filter.matches(*concept, mind).then_some(tile)
+        })
+        .map(|tile| {
+            let (dx, dy) = (tile.0 - here.0, tile.1 - here.1);
+            (dx.abs().max(dy.abs()), dx * dx + dy * dy, tile)
+        })
+        .filter(|&(chebyshev, _, _)| chebyshev > LAST_KNOWN_SEARCH_RADIUS_TILES)
+        .min_by_key(|&(_, dist_sq, tile)| (dist_sq, tile))
+        .map(|(_, _, tile)| tile)
+}
+
 fn collect_producer_hint_chunks(
     mind: &MindGraph,
     world_positions: &WorldEntityPositions,
@@ -279,6 +319,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_search_walks_toward_last_known_position_of_lost_target() {
+        let map = walkable_map();
+        let mut mind = MindGraph::new(make_ontology());
+        let deer = Entity::from_bits(7);
+        let meta = Metadata::semantic(0);
+        mind.assert(Triple::with_meta(
+            Node::Concept(Concept::Deer),
+            Predicate::HasTrait,
+            Value::Concept(Concept::Prey),
+            meta.clone(),
+        ));
+        mind.assert(Triple::with_meta(
+            Node::Entity(deer),
+            Predicate::IsA,
+            Value::Concept(Concept::Deer),
+            meta.clone(),
+        ));
+        let last_seen = (40, 25);
+        mind.assert(Triple::with_meta(
+            Node::Entity(deer),
+            Predicate::LastKnownAt,
+            Value::Tile(last_seen),
+            meta,
+        ));
+
+        let start = Vec2::new(10.0, 10.0);
+        for seed in 0..5u64 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let target = pick_look_for_target(
+                start,
+                &mind,
+                &ExploredTiles::default(),
+                &WorldEntityPositions::default(),
+                &map,
+                0,
+                Some(SearchFilter::entity_trait(Concept::Prey)),
+                &mut rng,
+            );
+            assert_eq!(target, Some(map.tile_to_world(last_seen.0, last_seen.1)));
+        }
+
+        // Standing on the spot: it was searched, so wander instead.
+        let there = map.tile_to_world(last_seen.0, last_seen.1);
+        let mut rng = StdRng::seed_from_u64(0);
+        let target = pick_look_for_target(
+            there,
+            &mind,
+            &ExploredTiles::default(),
+            &WorldEntityPositions::default(),
+            &map,
+            0,
+            Some(SearchFilter::entity_trait(Concept::Prey)),
+            &mut rng,
+        );
+        assert_ne!(target, Some(there));
+    }
+
     #[test]
     fn look_for_target_picker_falls_back_to_explore_with_no_hints() {
         let map = walkable_map();
@@ -5,6 +5,7 @@
 //! as named helper functions referenced through
 //! [`Hooks`](super::definition::Hooks).
 
+pub mod alarm_signal;
 pub mod attack;
 pub mod bite;
 pub mod build;
@@ -19,10 +20,12 @@ pub mod defend_self;
 pub mod deposit;
 pub mod devour;
 pub mod drink;
+pub mod drop;
 pub mod eat;
 pub mod explore;
 pub mod fish;
 pub mod flee;
+pub mod give;
 pub mod graze;
 pub mod harvest;
 pub mod idle;
@@ -47,6 +50,7 @@ pub mod wander;
 pub mod warm_up;
 pub mod wave;
 
+pub use alarm_signal::ALARM_SIGNAL_DEF;
 pub use attack::ATTACK_DEF;
 pub use bite::BITE_DEF;
 pub use build::BUILD_DEF;
@@ -61,10 +65,12 @@ pub use defend_self::DEFEND_SELF_DEF;
 pub use deposit::DEPOSIT_DEF;
 pub use devour::DEVOUR_DEF;
 pub use drink::DRINK_DEF;
+pub use drop::DROP_DEF;
 pub use eat::EAT_DEF;
 pub use explore::EXPLORE_DEF;
 pub use fish::FISH_DEF;
 pub use flee::FLEE_DEF;
+pub use give::GIVE_DEF;
 pub use graze::GRAZE_DEF;
 pub use harvest::HARVEST_DEF;
 pub use idle::IDLE_DEF;
@@ -68,8 +68,12 @@ fn pickup_target_consumes(target: &TargetCandidate, _mind: &MindGraph) -> Vec<Tr
 }
 
 /// Take exactly one extractable Thing — the snappy single-item variant of
-/// Take's drain-the-container loop.
+/// Take's drain-the-container loop. A full carry picks up nothing; the
+/// agent has to Drop something first.
 fn pickup_on_complete(ctx: &mut CompletionContext) {
+    if ctx.inventory.is_full() {
+        return;
+    }
     let Some(target_inv) = ctx.target_inventory.as_deref_mut() else {
         return;
     };
@@ -1,10 +1,9 @@
 //! Tend Wounds action — first-aid stance toward a nearby injured agent.
 //!
-//! v1 ships the action's gating and posture. Mutating the target's `Body`
-//! to accelerate healing requires a separate query-conflict-safe pathway
-//! (ParamSet or post-completion HealRequest queue) and is filed as a
-//! follow-up — same staging strategy that #325 used for Cook before the
-//! palatability hooks landed.
+//! Completing it bandages the target: `combat::bandage_tended_wounds`
+//! picks up the `ActionCompleted` and staunches every bleeding wound, the
+//! same post-completion route combat uses to reach the defender's `Body`.
+//! Accelerating the wounds' healing is still a follow-up.
 
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::{Channel, ChannelUsage, Posture};
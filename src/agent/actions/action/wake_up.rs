@@ -11,7 +11,7 @@ use crate::agent::actions::definition::{
     ActionDefinition, CompletionPredicate, EffectTemplate, Hooks, PlanValidity, TargetEffects,
 };
 use crate::agent::actions::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
-use crate::agent::actions::registry::{ActionKind, TargetSource};
+use crate::agent::actions::registry::{ActionKind, CompletionContext, TargetSource};
 use crate::agent::mind::knowledge::Concept;
 
 const CHANNELS: &[ChannelUsage] = &[ChannelUsage::new(Channel::FullBody, 0.4)];
@@ -42,6 +42,15 @@ pub static WAKE_UP_DEF: ActionDefinition = ActionDefinition {
     satiation: None,
     completion: CompletionPredicate::Never,
     on_complete_ops: &[],
-    hooks: Hooks::EMPTY,
+    hooks: Hooks {
+        on_complete: Some(wake_up_on_complete),
+        ..Hooks::EMPTY
+    },
     recipe: None,
 };
+
+/// Stamp the end of the sleep bout so urgency can tell how long the agent
+/// has been up.
+fn wake_up_on_complete(ctx: &mut CompletionContext) {
+    ctx.physical.last_slept_tick = ctx.tick;
+}
@@ -17,8 +17,8 @@ use super::ActionType;
 use super::channel::{ChannelUsage, Posture};
 use super::motor::{ActionPrimitive, IntensityPolicy, Intent, TargetSelector};
 use super::registry::{
-    ActionContext, ActionKind, CompletionContext, LegCompleteContext, LegResult, TargetCandidate,
-    TargetSource,
+    Accrual, ActionContext, ActionKind, CompletionContext, LegCompleteContext, LegResult,
+    TargetCandidate, TargetSource,
 };
 use crate::agent::body::need::NeedKind;
 use crate::agent::body::needs::{PhysicalNeeds, PsychologicalDrives};
@@ -188,6 +188,11 @@ pub enum Gate {
     /// `threshold`. Used by Share Food. Missing belief = 0.0 affection.
     /// Maps failure to [`FailureReason::Interrupted`].
     TargetAffectionAtLeast(f32),
+    /// `target_position` lies within `reach` px of the agent. Used by
+    /// hand-to-hand actions (Give) that can't start across the camp.
+    /// Missing target position passes — `TargetEntity` owns that case.
+    /// Maps failure to [`FailureReason::TooFar`].
+    TargetWithin(f32),
     /// Target tile is not in the agent's `Unreachable` belief.
     TileReachable,
     /// Target entity has no `(target, EngagedWith, ?)` triple in the
@@ -289,8 +294,8 @@ pub enum RuntimeOp {
 /// `Option<fn>`; unset fields fall through to the declarative interpretation.
 ///
 /// The hooks live adjacent to the static [`ActionDefinition`] in the same
-/// file, so `EAT_DEF` with `hooks: Hooks { on_complete: Some(eat_on_complete), .. }`
-/// is still colocated with `fn eat_on_complete(...)`. One interpreter, named
+/// file, so `EAT_DEF` with `hooks: Hooks { on_progress: Some(eat_on_progress), .. }`
+/// is still colocated with `fn eat_on_progress(...)`. One interpreter, named
 /// helpers — no per-action trait impl.
 #[derive(Debug, Clone, Copy)]
 pub struct Hooks {
@@ -299,6 +304,10 @@ pub struct Hooks {
     /// Runtime `on_complete` effect. Overrides the `on_complete_ops` list
     /// when set (actions usually set *either* ops *or* a hook, not both).
     pub on_complete: Option<fn(&mut CompletionContext)>,
+    /// Per-tick share of a Timed action's effect, for actions that accrue
+    /// it gradually (Eat) instead of landing it all in `on_complete`.
+    /// Gets the fraction of the duration done; see `Action::on_progress`.
+    pub on_progress: Option<fn(&mut CompletionContext, &mut Accrual, f32)>,
     /// `on_leg_complete` for Movement/Ambient actions with custom pickers.
     pub on_leg_complete: Option<fn(&mut LegCompleteContext) -> LegResult>,
     /// Per-target planner precondition builder. Only set for actions whose
@@ -332,6 +341,7 @@ impl Hooks {
     pub const EMPTY: Self = Self {
         can_start: None,
         on_complete: None,
+        on_progress: None,
         on_leg_complete: None,
         target_preconditions: None,
         target_consumes: None,
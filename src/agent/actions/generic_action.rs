@@ -16,7 +16,7 @@ use super::definition::{
 };
 use super::motor::Behavior;
 use super::registry::{
-    Action, ActionContext, ActionKind, CompletionContext, LegCompleteContext, LegResult,
+    Accrual, Action, ActionContext, ActionKind, CompletionContext, LegCompleteContext, LegResult,
     RuntimeEffects, SpawnRequest, TargetCandidate, TargetSource,
 };
 use super::types::ActionType;
@@ -26,6 +26,8 @@ use crate::agent::brains::thinking::TriplePattern;
 use crate::agent::events::FailureReason;
 use crate::agent::item_slots::ItemSlots;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Quantity, Triple, Value};
+use crate::agent::skills::SkillKind;
+use crate::constants::actions::build::HOUSE_MIN_BUILDING_SKILL;
 use crate::world::spatial_index::world_pos_to_tile;
 
 /// Wraps a static [`ActionDefinition`] and implements the [`Action`] trait by
@@ -343,6 +345,16 @@ fn check_gate(gate: &Gate, ctx: &ActionContext) -> Result<(), FailureReason> {
                 Err(FailureReason::Interrupted)
             }
         }
+        Gate::TargetWithin(reach) => {
+            let Some(pos) = ctx.target_position else {
+                return Ok(());
+            };
+            if ctx.agent_position.distance(pos) <= *reach {
+                Ok(())
+            } else {
+                Err(FailureReason::TooFar)
+            }
+        }
         Gate::TileReachable => {
             let Some(pos) = ctx.target_position else {
                 return Ok(());
@@ -721,6 +733,16 @@ impl Action for GenericAction {
         }
     }
 
+    fn required_skill(&self) -> Option<(SkillKind, f32)> {
+        // A house is beyond a first-time builder. Building is trained by
+        // Construct, so labor on lean-tos (or on someone else's house
+        // site) is how an agent earns the right to place one.
+        match self.def.action_type {
+            ActionType::BuildHouse => Some((SkillKind::Building, HOUSE_MIN_BUILDING_SKILL)),
+            _ => None,
+        }
+    }
+
     fn should_complete(&self, physical: &PhysicalNeeds) -> bool {
         match self.def.completion {
             CompletionPredicate::Never => false,
@@ -735,6 +757,11 @@ impl Action for GenericAction {
     }
 
     fn can_start(&self, ctx: &ActionContext) -> Result<(), FailureReason> {
+        if let Some((skill, required)) = self.required_skill()
+            && !self.is_skilled_enough(ctx.skills)
+        {
+            return Err(FailureReason::Unskilled { skill, required });
+        }
         if let Some(custom) = self.def.hooks.can_start {
             return custom(ctx);
         }
@@ -754,6 +781,16 @@ impl Action for GenericAction {
         }
     }
 
+    fn accrues_effects(&self) -> bool {
+        self.def.hooks.on_progress.is_some()
+    }
+
+    fn on_progress(&self, ctx: &mut CompletionContext, accrual: &mut Accrual, done: f32) {
+        if let Some(custom) = self.def.hooks.on_progress {
+            custom(ctx, accrual, done);
+        }
+    }
+
     fn on_leg_complete(&self, ctx: &mut LegCompleteContext) -> LegResult {
         if let Some(custom) = self.def.hooks.on_leg_complete {
             custom(ctx)
@@ -820,6 +857,7 @@ mod tests {
             emotional: None,
             current_tick: 0,
             unreachable_tiles,
+            skills: None,
         }
     }
 
@@ -991,4 +1029,45 @@ mod tests {
             "InitiateConversation must propose toward a target with no EngagedWith belief"
         );
     }
+
+    #[test]
+    fn build_house_refuses_to_start_below_the_building_bar() {
+        use crate::agent::actions::action::BUILD_HOUSE_DEF;
+        use crate::agent::skills::Skills;
+        use crate::constants::actions::build::{HOUSE_STONE_REQUIRED, HOUSE_WOOD_REQUIRED};
+
+        let mut inventory = ItemSlots::agent_carry();
+        inventory.add(Concept::Wood, HOUSE_WOOD_REQUIRED);
+        inventory.add(Concept::Stone, HOUSE_STONE_REQUIRED);
+        let mind = mind();
+        let map = world_map();
+        let physical = PhysicalNeeds::default();
+        let positions = crate::world::entity_positions::WorldEntityPositions::default();
+        let graph = crate::agent::psyche::social_graph::SocialGraph::default();
+        let mut ctx = ctx(
+            &inventory,
+            &mind,
+            &map,
+            &positions,
+            &physical,
+            None,
+            None,
+            &[],
+            &graph,
+        );
+        let build_house = GenericAction::new(&BUILD_HOUSE_DEF);
+
+        assert_eq!(
+            build_house.can_start(&ctx),
+            Err(FailureReason::Unskilled {
+                skill: SkillKind::Building,
+                required: HOUSE_MIN_BUILDING_SKILL,
+            })
+        );
+
+        let mut skills = Skills::default();
+        skills.set_level(SkillKind::Building, HOUSE_MIN_BUILDING_SKILL, 0);
+        ctx.skills = Some(&skills);
+        assert!(build_house.can_start(&ctx).is_ok());
+    }
 }
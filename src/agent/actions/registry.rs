@@ -54,6 +54,9 @@ pub struct ActionContext<'a> {
     /// Hoisted once per agent so `Gate::TileReachable` doesn't re-walk
     /// the MindGraph per proposal.
     pub unreachable_tiles: &'a [(i32, i32)],
+    /// Learned skills. `None` for agents without a `Skills` component,
+    /// which pass only actions with no [`Action::required_skill`].
+    pub skills: Option<&'a crate::agent::skills::Skills>,
 }
 
 // ============================================================================
@@ -166,7 +169,10 @@ impl TargetCandidate {
 pub enum ActionKind {
     /// Instant action (check -> do -> done in one tick)
     Instant,
-    /// Timed action (countdown ticks, then complete)
+    /// Timed action (countdown ticks, then complete). Effects land in
+    /// `on_complete` unless the action accrues them tick by tick through
+    /// [`Action::on_progress`], in which case an interrupted run keeps
+    /// the share it earned.
     Timed { duration_ticks: u32 },
     /// Movement action (move toward target until arrival). On arrival the
     /// action calls `on_leg_complete` — returns Complete by default,
@@ -280,6 +286,20 @@ pub enum SpawnRequest {
         target: crate::agent::mind::knowledge::Concept,
         mode: crate::world::becomes::BecomesMode,
     },
+    /// Set `things` down as a loose `GroundItem` at `position`, properties
+    /// intact. Used by Drop.
+    GroundItem {
+        position: bevy::prelude::Vec2,
+        things: Vec<crate::agent::item_slots::Thing>,
+    },
+    /// Put `things` into `recipient`'s inventory. Used by Give when the
+    /// recipient is a living agent, whose `ItemSlots` execution can't lend
+    /// out as `target_inventory` while it iterates the agents. Whatever the
+    /// recipient can't hold goes back to the giver.
+    Handover {
+        recipient: bevy::prelude::Entity,
+        things: Vec<crate::agent::item_slots::Thing>,
+    },
 }
 
 /// Context provided to actions when they complete
@@ -478,6 +498,22 @@ pub trait Action: Send + Sync + 'static {
         }
     }
 
+    /// Minimum `(skill, level)` an agent needs to attempt this action at
+    /// all. Below the bar the rational brain leaves the action out of its
+    /// planning set and `can_start` fails with
+    /// [`FailureReason::Unskilled`]. The skill itself is trained by other,
+    /// ungated actions (see `skills::skill_for_action`), so an agent works
+    /// its way up to it. Default: no requirement.
+    fn required_skill(&self) -> Option<(crate::agent::skills::SkillKind, f32)> {
+        None
+    }
+
+    /// True when `skills` meets [`Action::required_skill`].
+    fn is_skilled_enough(&self, skills: Option<&crate::agent::skills::Skills>) -> bool {
+        self.required_skill()
+            .is_none_or(|(kind, level)| skills.is_some_and(|s| s.level(kind) >= level))
+    }
+
     /// Diets for which this action is plannable. Empty slice = all diets
     /// allowed. Filters the rational brain so a wolf doesn't enumerate
     /// Graze targets and a deer doesn't plan against carnivore prey.
@@ -590,6 +626,19 @@ pub trait Action: Send + Sync + 'static {
         // Override in actions that have completion effects
     }
 
+    /// True when this Timed action applies its effect gradually through
+    /// [`Action::on_progress`] rather than all at once on completion.
+    /// Default: false.
+    fn accrues_effects(&self) -> bool {
+        false
+    }
+
+    /// Apply the share of the effect between `accrual.applied` and `done`
+    /// (fractions of the duration, 0..=1). Called by the executor each
+    /// tick the countdown advances; the executor then records `done` as
+    /// applied. Only called when [`Action::accrues_effects`] is true.
+    fn on_progress(&self, _ctx: &mut CompletionContext, _accrual: &mut Accrual, _done: f32) {}
+
     // === LOGGING ===
 
     /// Log message when action starts
@@ -746,6 +795,53 @@ pub struct ActionState {
     /// `on_leg_complete` via `LegCompleteContext`. `None` for every
     /// non-search action.
     pub search_filter: Option<crate::agent::brains::thinking::SearchFilter>,
+    /// How much of an accruing Timed action's effect has landed so far.
+    /// Untouched for actions that apply everything on completion.
+    pub accrual: Accrual,
+    /// How close a Movement-class action has got to its target, for
+    /// settling on targets it can't quite reach.
+    pub settle: crate::agent::movement::SettleProgress,
+    /// Waypoints still ahead of a Movement-class action, in travel order,
+    /// before `target_position`. Empty means walk straight at the target.
+    /// Set at start and on each new leg for route-following actions (see
+    /// `movement::follows_route`).
+    pub path: Vec<Vec2>,
+}
+
+/// Progress of a Timed action that applies its effect tick by tick
+/// (see [`Action::accrues_effects`]).
+#[derive(Debug, Clone, Copy, Default, Reflect)]
+pub struct Accrual {
+    /// Fraction of the effect already applied, 0..=1.
+    pub applied: f32,
+    /// Item the action claimed on its first share and keeps working
+    /// through — the berry being eaten leaves the pouch at the first bite.
+    pub item: Option<Concept>,
+    /// Freshness of that item when it was claimed; later shares keep
+    /// scaling by it after the item has left the pouch.
+    pub freshness: Option<f32>,
+    /// Needs before the first share, so completion reports the whole
+    /// action's effect rather than its last tick's.
+    pub baseline: Option<NeedSnapshot>,
+}
+
+/// Hunger and thirst (0..100) and aerobic stamina at one moment; the
+/// before side of an action's `NeedSatisfaction`.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct NeedSnapshot {
+    pub hunger: f32,
+    pub thirst: f32,
+    pub aerobic: f32,
+}
+
+impl NeedSnapshot {
+    pub fn of(physical: &crate::agent::body::needs::PhysicalNeeds) -> Self {
+        Self {
+            hunger: physical.metabolism.hunger_urgency() * 100.0,
+            thirst: physical.hydration.deficit() * 100.0,
+            aerobic: physical.stamina.aerobic,
+        }
+    }
 }
 
 impl ActionState {
@@ -764,6 +860,9 @@ impl ActionState {
             // value via with_locomotion_intensity().
             locomotion_intensity: 0.0,
             search_filter: None,
+            accrual: Accrual::default(),
+            settle: Default::default(),
+            path: Vec::new(),
         }
     }
 
@@ -938,12 +1037,13 @@ impl ActiveActions {
 // ============================================================================
 
 use super::action::{
-    ATTACK_DEF, BITE_DEF, BUILD_DEF, BUILD_HOUSE_DEF, BUILD_LEAN_TO_DEF, BUILD_STORAGE_CHEST_DEF,
-    CONSTRUCT_DEF, CONVERSE_DEF, COOK_DEF, DANCE_DEF, DEFEND_SELF_DEF, DEPOSIT_DEF, DEVOUR_DEF,
-    DRINK_DEF, EAT_DEF, EXPLORE_DEF, FISH_DEF, FLEE_DEF, GRAZE_DEF, HARVEST_DEF, IDLE_DEF,
-    INITIATE_CONVERSATION_DEF, LOOK_FOR_DEF, MOURN_DEF, OBSERVE_DEF, PICKUP_DEF, REST_DEF,
-    REST_IN_SHELTER_DEF, SHARE_FOOD_DEF, SIT_DEF, SLEEP_DEF, STAND_WATCH_DEF, STOCK_CHEST_DEF,
-    TAKE_DEF, TEND_WOUNDS_DEF, WAKE_UP_DEF, WALK_DEF, WANDER_DEF, WARM_UP_DEF, WAVE_DEF,
+    ALARM_SIGNAL_DEF, ATTACK_DEF, BITE_DEF, BUILD_DEF, BUILD_HOUSE_DEF, BUILD_LEAN_TO_DEF,
+    BUILD_STORAGE_CHEST_DEF, CONSTRUCT_DEF, CONVERSE_DEF, COOK_DEF, DANCE_DEF, DEFEND_SELF_DEF,
+    DEPOSIT_DEF, DEVOUR_DEF, DRINK_DEF, DROP_DEF, EAT_DEF, EXPLORE_DEF, FISH_DEF, FLEE_DEF,
+    GIVE_DEF, GRAZE_DEF, HARVEST_DEF, IDLE_DEF, INITIATE_CONVERSATION_DEF, LOOK_FOR_DEF, MOURN_DEF,
+    OBSERVE_DEF, PICKUP_DEF, REST_DEF, REST_IN_SHELTER_DEF, SHARE_FOOD_DEF, SIT_DEF, SLEEP_DEF,
+    STAND_WATCH_DEF, STOCK_CHEST_DEF, TAKE_DEF, TEND_WOUNDS_DEF, WAKE_UP_DEF, WALK_DEF, WANDER_DEF,
+    WARM_UP_DEF, WAVE_DEF,
 };
 
 /// Every [`ActionDefinition`] in the game, in a single slice. Order is not
@@ -983,12 +1083,15 @@ const ALL_DEFS: &[&ActionDefinition] = &[
     &SIT_DEF,
     &FISH_DEF,
     &SHARE_FOOD_DEF,
+    &GIVE_DEF,
     &TEND_WOUNDS_DEF,
     &STAND_WATCH_DEF,
     &DANCE_DEF,
     &MOURN_DEF,
     &PICKUP_DEF,
+    &DROP_DEF,
     &WAVE_DEF,
+    &ALARM_SIGNAL_DEF,
 ];
 
 #[derive(Resource, Default)]
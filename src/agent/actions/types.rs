@@ -9,7 +9,7 @@ use bevy::prelude::*;
 
 /// Defines the objective "verbs" agents can perform.
 /// This separates Intent (Action) from Occurrence (Event).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize, serde::Deserialize)]
 pub enum ActionType {
     // Survival / Biological
     Eat,
@@ -130,6 +130,10 @@ pub enum ActionType {
     /// Hand a food item to a nearby agent. The prosocial counterpart of
     /// Deposit, gated on positive affection toward the recipient.
     ShareFood,
+    /// Hand a few of any carried item to a nearby agent. Unlike Share
+    /// Food it moves a quantity, and both sides re-perceive each other's
+    /// inventories once the handover lands.
+    Give,
     /// First-aid stance: heal a nearby injured agent's wounds.
     TendWounds,
     /// Sentinel posture at night near a campfire. Replaces Sleep for one
@@ -141,6 +145,10 @@ pub enum ActionType {
     /// Stationary grief processing after the agent's MindGraph records
     /// the death of a known agent.
     Mourn,
+    /// Non-verbal alarm call (stomp, snort, bark). Scares nearby members
+    /// of the caller's own species without telling them anything about
+    /// what the threat is.
+    AlarmSignal,
 }
 
 impl std::fmt::Display for ActionType {
@@ -192,10 +200,12 @@ impl ActionType {
             ActionType::Sit => "Sitting",
             ActionType::Fish => "Fishing",
             ActionType::ShareFood => "Sharing food with",
+            ActionType::Give => "Giving to",
             ActionType::TendWounds => "Tending wounds of",
             ActionType::StandWatch => "Standing watch",
             ActionType::Dance => "Dancing",
             ActionType::Mourn => "Mourning",
+            ActionType::AlarmSignal => "Sounding an alarm",
         }
     }
 
@@ -240,10 +250,12 @@ impl ActionType {
             ActionType::Sit => "Sit",
             ActionType::Fish => "Fish",
             ActionType::ShareFood => "ShareFood",
+            ActionType::Give => "Give",
             ActionType::TendWounds => "TendWounds",
             ActionType::StandWatch => "StandWatch",
             ActionType::Dance => "Dance",
             ActionType::Mourn => "Mourn",
+            ActionType::AlarmSignal => "AlarmSignal",
         }
     }
 }
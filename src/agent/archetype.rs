@@ -0,0 +1,148 @@
+//! Agent archetypes: designed starting profiles layered on top of culture.
+//!
+//! Reads: Archetype (added at spawn), Transform, EntityType + ItemSlots (food sources)
+//! Writes: Genome (personality skew, at spawn), Skills, MindGraph (starting beliefs)
+//! Upstream: spawn_human::build_person_logic, testing::spawn (AgentConfig::archetype)
+//! Downstream: phenotype development (skewed genome), rational brain (seeded beliefs)
+//!
+//! Culture is what a whole people knows; an archetype is who one person
+//! already is when the scenario opens — a forager who has walked these
+//! bushes for years, a hermit who would rather be left alone. Genome skew
+//! and skills are applied while the bundles are built. Food-location
+//! memories need the world, so they are seeded by
+//! [`seed_archetype_food_memory`] on the first tick after spawn.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::body::genetics::genome::Genome;
+use crate::agent::body::genetics::phenotype::Phenotype;
+use crate::agent::inventory::EntityType;
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::{Concept, Metadata, MindGraph, Node, Predicate, Triple, Value};
+use crate::agent::skills::{SkillKind, Skills};
+use crate::world::map::TILE_SIZE;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum Archetype {
+    /// Knows where the nearby food grows and is practised at picking it.
+    Forager,
+    /// Withdrawn and disagreeable; keeps to themselves.
+    Hermit,
+}
+
+/// Shift applied to the Big Five scores before the genome is built.
+/// Positive raises the trait, negative lowers it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PersonalitySkew {
+    pub openness: f32,
+    pub conscientiousness: f32,
+    pub extraversion: f32,
+    pub agreeableness: f32,
+    pub neuroticism: f32,
+}
+
+/// Everything an archetype changes about a freshly spawned agent.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchetypeProfile {
+    pub personality: PersonalitySkew,
+    /// Starting skill levels.
+    pub skills: &'static [(SkillKind, f32)],
+    /// Food sources within this many tiles of the spawn point are known
+    /// from the start. `None` = no prior food knowledge.
+    pub food_memory_radius_tiles: Option<f32>,
+}
+
+impl Archetype {
+    pub fn profile(&self) -> ArchetypeProfile {
+        match self {
+            Archetype::Forager => ArchetypeProfile {
+                personality: PersonalitySkew {
+                    openness: 0.15,
+                    conscientiousness: 0.1,
+                    ..Default::default()
+                },
+                skills: &[(SkillKind::Harvesting, 0.4)],
+                food_memory_radius_tiles: Some(20.0),
+            },
+            Archetype::Hermit => ArchetypeProfile {
+                personality: PersonalitySkew {
+                    extraversion: -0.25,
+                    agreeableness: -0.1,
+                    ..Default::default()
+                },
+                skills: &[],
+                food_memory_radius_tiles: None,
+            },
+        }
+    }
+
+    /// Re-derive `genome` with this archetype's personality skew applied.
+    /// Physical traits round-trip unchanged.
+    pub fn skew_genome(&self, genome: &Genome) -> Genome {
+        let skew = self.profile().personality;
+        let mut phenotype = Phenotype::from_genome(genome);
+        phenotype.openness = (phenotype.openness + skew.openness).clamp(0.0, 1.0);
+        phenotype.conscientiousness =
+            (phenotype.conscientiousness + skew.conscientiousness).clamp(0.0, 1.0);
+        phenotype.extraversion = (phenotype.extraversion + skew.extraversion).clamp(0.0, 1.0);
+        phenotype.agreeableness = (phenotype.agreeableness + skew.agreeableness).clamp(0.0, 1.0);
+        phenotype.neuroticism = (phenotype.neuroticism + skew.neuroticism).clamp(0.0, 1.0);
+        Genome::from_phenotype(&phenotype)
+    }
+
+    /// Starting skill set for this archetype.
+    pub fn starting_skills(&self) -> Skills {
+        let mut skills = Skills::default();
+        for &(kind, level) in self.profile().skills {
+            skills.set_level(kind, level, 0);
+        }
+        skills
+    }
+}
+
+/// Bevy system (FixedUpdate): when an archetype with food memory spawns,
+/// record every food-bearing source within its radius as if the agent had
+/// seen it — type, plus what it currently holds.
+pub fn seed_archetype_food_memory(
+    tick: Res<crate::core::TickCount>,
+    mut agents: Query<(&Archetype, &Transform, &mut MindGraph), Added<Archetype>>,
+    sources: Query<(Entity, &EntityType, &ItemSlots, &Transform), Without<Agent>>,
+) {
+    for (archetype, transform, mut mind) in agents.iter_mut() {
+        let Some(radius_tiles) = archetype.profile().food_memory_radius_tiles else {
+            continue;
+        };
+        let origin = transform.translation.truncate();
+        let radius = radius_tiles * TILE_SIZE;
+
+        for (source, kind, stock, source_transform) in sources.iter() {
+            if source_transform.translation.truncate().distance(origin) > radius {
+                continue;
+            }
+            let food: Vec<(Concept, u32)> = stock
+                .group_by_concept()
+                .into_iter()
+                .filter(|(concept, _)| mind.is_a(&Node::Concept(*concept), Concept::Food))
+                .collect();
+            if food.is_empty() {
+                continue;
+            }
+            mind.assert(Triple::with_meta(
+                Node::Entity(source),
+                Predicate::IsA,
+                Value::Concept(kind.0),
+                Metadata::semantic(tick.current),
+            ));
+            for (concept, qty) in food {
+                mind.assert(Triple::with_meta(
+                    Node::Entity(source),
+                    Predicate::Contains,
+                    Value::Item(concept, qty),
+                    Metadata::semantic(tick.current),
+                ));
+            }
+        }
+    }
+}
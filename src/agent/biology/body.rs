@@ -6,13 +6,14 @@
 //! Consumption + Bite while a human's arm offers Manipulation + Carry, without
 //! the action system knowing anything about species.
 //!
-//! Reads: PhysicalNeeds (healing boost, starvation/dehydration checks)
+//! Reads: PhysicalNeeds (healing boost, starvation/dehydration checks), ActiveActions (resting heal boost)
 //! Writes: Body (healing/scarring, deprivation cascade)
 //! Upstream: BiologyPlugin (auto-spawn), per-species spawners
 //! Downstream: channel::ChannelCapacities (capability queries),
 //!             movement::calculate_speed (injury penalty), UI/debug
 
 use crate::agent::actions::channel::Channel;
+use crate::agent::actions::{ActionType, ActiveActions};
 use crate::agent::body::needs::PhysicalNeeds;
 use crate::agent::body::species::Species;
 use crate::agent::events::SimEventKind;
@@ -32,6 +33,10 @@ use bevy::prelude::*;
 pub struct Body {
     #[reflect(ignore)]
     pub parts: Vec<BodyNode>,
+    /// Circulating blood as a fraction of a full volume. Open bleeding
+    /// wounds drain it (`combat::bleed_system`); at zero the body dies of
+    /// blood loss.
+    pub blood: f32,
 }
 
 impl Default for Body {
@@ -70,6 +75,7 @@ impl Body {
                     BodyNode::new(BodyNodeKind::RightFoot, 35.0).with_tags(vec![Stance]),
                 ]),
             ],
+            blood: 1.0,
         }
     }
 
@@ -102,6 +108,7 @@ impl Body {
                     BodyNode::new(BodyNodeKind::RightHindpaw, 25.0).with_tags(vec![Stance]),
                 ]),
             ],
+            blood: 1.0,
         }
     }
 
@@ -134,6 +141,7 @@ impl Body {
                     BodyNode::new(BodyNodeKind::RightHindhoof, 20.0).with_tags(vec![Stance]),
                 ]),
             ],
+            blood: 1.0,
         }
     }
 
@@ -164,6 +172,7 @@ impl Body {
                     .with_tags(vec![FullBody])
                     .with_children(torso_organs()),
             ],
+            blood: 1.0,
         }
     }
 
@@ -220,6 +229,29 @@ impl Body {
         self.parts.iter().map(BodyNode::tree_pain).sum()
     }
 
+    /// True when any node in the tree has a wound still bleeding.
+    pub fn is_bleeding(&self) -> bool {
+        self.parts.iter().any(BodyNode::tree_bleeding)
+    }
+
+    /// Staunch every wound: bleeding stops at once instead of waiting for
+    /// the wounds to clot. The injuries themselves still need to heal.
+    pub fn bandage(&mut self) {
+        fn staunch(node: &mut BodyNode) {
+            for injury in &mut node.injuries {
+                injury.bleed_rate = 0.0;
+            }
+            node.children.iter_mut().for_each(staunch);
+        }
+        self.parts.iter_mut().for_each(staunch);
+    }
+
+    /// Sum of unhealed injury severity across every node in the tree.
+    /// Unlike pain, this stays up until the wounds actually close.
+    pub fn open_wound_severity(&self) -> f32 {
+        self.parts.iter().map(BodyNode::tree_wound_severity).sum()
+    }
+
     /// Any vital node at critically low function.
     pub fn is_incapacitated(&self) -> bool {
         for part in &self.parts {
@@ -300,7 +332,7 @@ impl Body {
     }
 
     /// If any vital organ is destroyed, return the death cause string.
-    /// Priority: heart > brain > both lungs > head > torso.
+    /// Priority: heart > brain > both lungs > head > torso > blood loss.
     pub fn death_cause(&self) -> Option<&'static str> {
         if self
             .node(BodyNodeKind::Heart)
@@ -335,6 +367,9 @@ impl Body {
         {
             return Some("torso destroyed");
         }
+        if self.blood <= 0.0 {
+            return Some("blood loss");
+        }
         None
     }
 
@@ -763,6 +798,32 @@ impl BodyNode {
         pain
     }
 
+    /// Injury severity on this node that has not healed yet.
+    pub fn open_wound_severity(&self) -> f32 {
+        self.injuries
+            .iter()
+            .map(|injury| injury.severity * (1.0 - injury.healed_amount))
+            .sum()
+    }
+
+    /// True when any wound on this node is still bleeding.
+    pub fn is_bleeding(&self) -> bool {
+        self.injuries.iter().any(|i| i.effective_bleed() > 0.0)
+    }
+
+    fn tree_bleeding(&self) -> bool {
+        self.is_bleeding() || self.children.iter().any(BodyNode::tree_bleeding)
+    }
+
+    /// Unhealed severity including all descendants.
+    fn tree_wound_severity(&self) -> f32 {
+        let mut severity = self.open_wound_severity();
+        for child in &self.children {
+            severity += child.tree_wound_severity();
+        }
+        severity
+    }
+
     /// Find a node by kind in this subtree (self + descendants).
     pub fn find(&self, kind: BodyNodeKind) -> Option<&BodyNode> {
         if self.kind == kind {
@@ -827,20 +888,31 @@ fn heal_node(node: &mut BodyNode, dt: f32, condition_mult: f32) {
     node.recalculate_function();
 }
 
+/// Stances that count as resting for healing purposes.
+const RESTING_ACTIONS: [ActionType; 4] = [
+    ActionType::Sit,
+    ActionType::Rest,
+    ActionType::RestInShelter,
+    ActionType::Sleep,
+];
+
 pub fn process_healing(
-    mut query: Query<(&mut Body, Option<&PhysicalNeeds>), With<Alive>>,
+    mut query: Query<(&mut Body, Option<&PhysicalNeeds>, Option<&ActiveActions>), With<Alive>>,
     tick: Res<crate::core::tick::TickCount>,
 ) {
     let dt = tick.dt();
 
-    for (mut body, needs) in query.iter_mut() {
-        let condition_mult = if let Some(physical) = needs
+    for (mut body, needs, active) in query.iter_mut() {
+        let mut condition_mult = if let Some(physical) = needs
             && physical.stamina.aerobic > 80.0
         {
             2.0
         } else {
             1.0
         };
+        if active.is_some_and(|a| RESTING_ACTIONS.iter().any(|&kind| a.contains(kind))) {
+            condition_mult *= crate::constants::biology::RESTING_HEAL_MULTIPLIER;
+        }
 
         for part in body.parts.iter_mut() {
             heal_node(part, dt, condition_mult);
@@ -1,10 +1,11 @@
 //! Combat resolution: hit rolls, damage application, bleeding, severance, death.
 //!
 //! Reads: SimEvent::ActionCompleted, Body (read+write), PhysicalNeeds,
-//!        Consciousness, Skills, Transform, Name, MindGraph, ItemSlots, SimRng
+//!        Consciousness, EmotionalState, Skills, Transform, Name, MindGraph,
+//!        ItemSlots, SimRng, CombatConfig
 //! Writes: Body, PhysicalNeeds, SimEvent::CombatHit/Missed/PartSevered/Death,
 //!         Liquid puddle entities, SeveredPart entities, Becomes (Corpse path)
-//! Upstream: actions::action::attack / bite (emit ActionCompleted), SkillsPlugin
+//! Upstream: actions::action::attack / bite / tend_wounds (emit ActionCompleted), SkillsPlugin
 //! Downstream: event_log, brain (reads pain urgency), world rendering
 //!
 //! # Pipeline
@@ -15,15 +16,18 @@
 //! 2. For each completion, fetch the attacker's snapshot (skill, mind,
 //!    target). Everything needed from the attacker is captured as owned
 //!    data here so the subsequent mutable defender borrow doesn't alias.
-//! 3. Mutate the defender: roll dodge, pick a part, apply an Injury,
-//!    optionally punch damage through to an internal organ.
+//! 3. Mutate the defender: roll dodge (see [`dodge_chance`]), pick a part,
+//!    apply an Injury, optionally punch damage through to an internal organ.
 //! 4. On a kill, queue a Death event, attach `Becomes::InPlace Corpse`,
 //!    and remember to deposit the "first cut" meat into the attacker's
 //!    inventory after the defender borrow drops.
 //! 5. Spawn blood puddles proportional to the damage dealt.
 //!
-//! The separate `bleed_system` drains HP from open wounds every tick and
-//! leaves a blood trail at the bleeder's current position. The separate
+//! The separate `bleed_system` drains HP from open wounds every tick, drains
+//! `Body::blood` in proportion (zero blood is a death in `check_death`), and
+//! leaves a blood trail at the bleeder's current position.
+//! `bandage_tended_wounds` staunches a target's wounds when a `TendWounds`
+//! on it completes. The separate
 //! `severance_system` checks for non-vital parts at 0 HP and drops them
 //! as `SeveredPart` world entities.
 
@@ -38,7 +42,9 @@ use crate::agent::body::needs::Consciousness;
 use crate::agent::events::{SimEvent, SimEventKind};
 use crate::agent::item_slots::ItemSlots;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use crate::agent::psyche::emotions::EmotionalState;
 use crate::agent::skills::{SkillKind, Skills};
+use crate::constants::biology::{BLOOD_LOSS_PER_BLED_HP, BLOOD_REGEN_PER_SEC};
 use crate::core::GameLog;
 use crate::core::sim_rng::SimRng;
 use crate::core::tick::TickCount;
@@ -66,11 +72,6 @@ const BITE_DAMAGE_MAX: f32 = 32.0;
 const SKILL_MULT_BASE: f32 = 0.7;
 const SKILL_MULT_SPAN: f32 = 0.6;
 
-/// Dodge ceiling before skill shave: `alertness × locomotion × DODGE_COEFF`.
-const DODGE_COEFF: f32 = 0.4;
-/// A maxed-Combat attacker roughly halves the dodge ceiling.
-const SKILL_DODGE_SHAVE: f32 = 0.5;
-
 const PIERCE_ORGAN_CHANCE: f32 = 0.4;
 const SLASH_ORGAN_CHANCE: f32 = 0.2;
 const CRUSH_ORGAN_CHANCE: f32 = 0.05;
@@ -95,6 +96,57 @@ const DAZED_DAMAGE_THRESHOLD: f32 = 18.0;
 /// at typical Attack/Bite durations.
 const DAZED_DURATION_TICKS: u32 = GameTime::TICKS_PER_MINUTE as u32;
 
+/// Defense tunables: how likely a strike is to be dodged.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct CombatConfig {
+    /// Dodge ceiling for an untrained, calm defender:
+    /// `alertness × locomotion × dodge_coeff`.
+    pub dodge_coeff: f32,
+    /// Defender Combat skill raises the ceiling by up to this fraction.
+    pub defender_skill_bonus: f32,
+    /// Stress at 100 cuts the ceiling by this fraction.
+    pub stress_penalty: f32,
+    /// A maxed-Combat attacker shaves this fraction off the ceiling.
+    pub attacker_skill_shave: f32,
+    /// Hard cap, so no defender is untouchable.
+    pub max_dodge: f32,
+}
+
+impl Default for CombatConfig {
+    fn default() -> Self {
+        Self {
+            dodge_coeff: 0.4,
+            defender_skill_bonus: 0.75,
+            stress_penalty: 0.5,
+            attacker_skill_shave: 0.5,
+            max_dodge: 0.9,
+        }
+    }
+}
+
+/// The defender's state at the moment a strike arrives.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Defense {
+    pub alertness: f32,
+    /// Locomotion channel capacity; leg injuries drag it down.
+    pub locomotion: f32,
+    /// Combat skill level, 0..1.
+    pub combat_skill: f32,
+    /// `EmotionalState::stress_level`, 0..100.
+    pub stress: f32,
+}
+
+/// Chance the strike misses. An alert, mobile, trained and calm defender
+/// slips more blows; an injured or panicking one takes them.
+pub fn dodge_chance(config: &CombatConfig, attacker_combat_skill: f32, defense: &Defense) -> f32 {
+    let skill = 1.0 + defense.combat_skill.clamp(0.0, 1.0) * config.defender_skill_bonus;
+    let composure = 1.0 - (defense.stress / 100.0).clamp(0.0, 1.0) * config.stress_penalty;
+    let shave = 1.0 - attacker_combat_skill.clamp(0.0, 1.0) * config.attacker_skill_shave;
+    (defense.alertness * defense.locomotion * config.dodge_coeff * skill * composure * shave)
+        .clamp(0.0, config.max_dodge)
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 // DAMAGE HELPERS
 // ════════════════════════════════════════════════════════════════════════════
@@ -167,17 +219,14 @@ struct HitOutcome {
 }
 
 /// Apply a strike to the defender and return what happened. Mutates the
-/// defender's body in-place.
+/// defender's body in-place. `dodge` comes from [`dodge_chance`].
 fn apply_strike(
     rng: &mut impl Rng,
     action: ActionType,
     attacker_combat_skill: f32,
     defender_body: &mut Body,
-    defender_alertness: f32,
-    defender_locomotion: f32,
+    dodge: f32,
 ) -> Resolution {
-    let dodge = (defender_alertness * defender_locomotion * DODGE_COEFF)
-        * (1.0 - attacker_combat_skill * SKILL_DODGE_SHAVE);
     if rng.random::<f32>() < dodge {
         return Resolution::Missed;
     }
@@ -335,11 +384,13 @@ pub fn resolve_combat_hits(
             &Transform,
             Option<&Skills>,
             &MindGraph,
+            Option<&EmotionalState>,
         ),
         With<Agent>,
     >,
     mut liquids: Query<(Entity, &Transform, &mut Liquid)>,
     mapping: Res<TagChannelMapping>,
+    config: Res<CombatConfig>,
 ) {
     // Phase 1: collect combat-relevant completions. The target travels
     // on the event itself so no ActiveActions lookup is needed — by the
@@ -385,7 +436,7 @@ pub fn resolve_combat_hits(
         // Using `.get()` on the mutable query returns immutable refs, so
         // no borrow checker conflict with the later `.get_mut()`.
         let (attacker_skill, prey_drops) = {
-            let Ok((_, _, _, _, skills, mind)) = agents.get(attacker) else {
+            let Ok((_, _, _, _, skills, mind, _)) = agents.get(attacker) else {
                 continue;
             };
             let skill = skills.map(|s| s.level(SkillKind::Combat)).unwrap_or(0.0);
@@ -395,22 +446,27 @@ pub fn resolve_combat_hits(
 
         // Now mutate the defender.
         let (resolution, blood_pos) = {
-            let Ok((mut defender_body, _, consc, def_transform, _, _)) = agents.get_mut(defender)
+            let Ok((mut defender_body, _, consc, def_transform, skills, _, emotions)) =
+                agents.get_mut(defender)
             else {
                 continue;
             };
-            let alertness = consc.map(|c| c.alertness).unwrap_or(1.0);
-            let locomotion = defender_body
-                .channel_capacity(Channel::Locomotion, &mapping)
-                .min(1.5);
+            let defense = Defense {
+                alertness: consc.map(|c| c.alertness).unwrap_or(1.0),
+                locomotion: defender_body
+                    .channel_capacity(Channel::Locomotion, &mapping)
+                    .min(1.5),
+                combat_skill: skills.map(|s| s.level(SkillKind::Combat)).unwrap_or(0.0),
+                stress: emotions.map(|e| e.stress_level).unwrap_or(0.0),
+            };
+            let dodge = dodge_chance(&config, attacker_skill, &defense);
             let pos = def_transform.translation.truncate();
             let res = apply_strike(
                 rng.inner_mut(),
                 action,
                 attacker_skill,
                 &mut defender_body,
-                alertness,
-                locomotion,
+                dodge,
             );
             (res, pos)
         };
@@ -609,6 +665,25 @@ fn bleed_node(node: &mut crate::agent::biology::body::BodyNode, dt: f32) -> f32
     }
 }
 
+/// One tick of bleeding for a whole body: every open wound drains its
+/// node's HP and, in proportion, the body's blood. Blood creeps back while
+/// nothing bleeds. Returns the HP bled, for the blood trail.
+fn bleed_body(body: &mut Body, dt: f32) -> f32 {
+    let mut total_drain = 0.0_f32;
+    for part in body.parts.iter_mut() {
+        total_drain += bleed_node(part, dt);
+        for child in part.children.iter_mut() {
+            total_drain += bleed_node(child, dt);
+        }
+    }
+    if total_drain > 0.0 {
+        body.blood = (body.blood - total_drain * BLOOD_LOSS_PER_BLED_HP).max(0.0);
+    } else if body.blood < 1.0 {
+        body.blood = (body.blood + BLOOD_REGEN_PER_SEC * dt).min(1.0);
+    }
+    total_drain
+}
+
 pub fn bleed_system(
     mut commands: Commands,
     tick: Res<TickCount>,
@@ -623,13 +698,7 @@ pub fn bleed_system(
     let mut drips: Vec<(Vec2, f32)> = Vec::new();
 
     for (mut body, transform) in agents.iter_mut() {
-        let mut total_drain = 0.0_f32;
-        for part in body.parts.iter_mut() {
-            total_drain += bleed_node(part, dt);
-            for child in part.children.iter_mut() {
-                total_drain += bleed_node(child, dt);
-            }
-        }
+        let total_drain = bleed_body(&mut body, dt);
         if total_drain > 0.0 {
             drips.push((transform.translation.truncate(), total_drain));
         }
@@ -647,6 +716,29 @@ pub fn bleed_system(
     }
 }
 
+/// Tending a wounded agent bandages them: every bleeding wound on the
+/// target is staunched when a `TendWounds` completes.
+pub fn bandage_tended_wounds(
+    mut sim_events: MessageReader<SimEvent>,
+    mut bodies: Query<&mut Body, With<Agent>>,
+) {
+    for event in sim_events.read() {
+        if let SimEvent {
+            kind:
+                SimEventKind::ActionCompleted {
+                    action: ActionType::TendWounds,
+                    target: Some(target),
+                    ..
+                },
+            ..
+        } = event
+            && let Ok(mut body) = bodies.get_mut(*target)
+        {
+            body.bandage();
+        }
+    }
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 // SEVERANCE SYSTEM
 // ════════════════════════════════════════════════════════════════════════════
@@ -706,6 +798,37 @@ mod tests {
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
+    #[test]
+    fn bleeding_drains_blood_to_death_and_bandaging_stops_it() {
+        let mut body = Body::human();
+        body.part_mut(BodyNodeKind::LeftArm)
+            .unwrap()
+            .injuries
+            .push(Injury {
+                injury_type: InjuryType::Slash,
+                severity: 0.5,
+                pain: 0.0,
+                healed_amount: 0.0,
+                bleed_rate: 1.0,
+            });
+        assert!(body.is_bleeding());
+
+        let bled = bleed_body(&mut body, 10.0);
+        assert!(bled > 0.0);
+        assert!((body.blood - (1.0 - bled * BLOOD_LOSS_PER_BLED_HP)).abs() < 1e-6);
+
+        body.blood = 0.001;
+        bleed_body(&mut body, 1.0);
+        assert_eq!(body.blood, 0.0);
+        assert_eq!(body.death_cause(), Some("blood loss"));
+
+        body.blood = 0.5;
+        body.bandage();
+        assert!(!body.is_bleeding());
+        assert_eq!(bleed_body(&mut body, 10.0), 0.0);
+        assert!(body.blood > 0.5, "blood recovers once the bleeding stops");
+    }
+
     #[test]
     fn bleed_coefficients_are_ordered() {
         const { assert!(SLASH_BLEED_COEFF > PIERCE_BLEED_COEFF) };
@@ -756,7 +879,7 @@ mod tests {
 
         // Fire a bunch of strikes with max skill so dodge misses are rare.
         for _ in 0..10 {
-            apply_strike(&mut rng, ActionType::Attack, 1.0, &mut body, 0.0, 0.0);
+            apply_strike(&mut rng, ActionType::Attack, 1.0, &mut body, 0.0);
         }
         let total_hp_after: f32 = body.parts.iter().map(|p| p.current_hp).sum();
         assert!(
@@ -779,7 +902,7 @@ mod tests {
         let mut rng = ChaCha8Rng::seed_from_u64(7);
         let mut body = Body::deer();
         for _ in 0..200 {
-            apply_strike(&mut rng, ActionType::Bite, 1.0, &mut body, 0.0, 0.0);
+            apply_strike(&mut rng, ActionType::Bite, 1.0, &mut body, 0.0);
             if body.any_vital_organ_destroyed() || body.is_incapacitated() {
                 return;
             }
@@ -794,10 +917,20 @@ mod tests {
         // guaranteed but over many rolls we should see *some* misses.
         let mut rng = ChaCha8Rng::seed_from_u64(123);
         let mut body = Body::human();
+        let dodge = dodge_chance(
+            &CombatConfig::default(),
+            0.0,
+            &Defense {
+                alertness: 1.0,
+                locomotion: 1.0,
+                ..Default::default()
+            },
+        );
+        assert!((dodge - 0.4).abs() < 1e-6);
         let mut miss_count = 0;
         for _ in 0..100 {
             if matches!(
-                apply_strike(&mut rng, ActionType::Attack, 0.0, &mut body, 1.0, 1.0),
+                apply_strike(&mut rng, ActionType::Attack, 0.0, &mut body, dodge),
                 Resolution::Missed
             ) {
                 miss_count += 1;
@@ -808,4 +941,65 @@ mod tests {
             "dodge should fire noticeably often at max alertness (got {miss_count}/100)"
         );
     }
+
+    #[test]
+    fn skilled_defender_dodges_while_injured_stressed_one_is_hit() {
+        let config = CombatConfig::default();
+        let mapping = TagChannelMapping::default();
+        let attacker_skill = 0.3;
+
+        let skilled = Body::human();
+        let skilled_defense = Defense {
+            alertness: 1.0,
+            locomotion: skilled.channel_capacity(Channel::Locomotion, &mapping),
+            combat_skill: 1.0,
+            stress: 0.0,
+        };
+
+        let mut injured = Body::human();
+        for foot in [BodyNodeKind::LeftFoot, BodyNodeKind::RightFoot] {
+            let part = injured.node_mut(foot).unwrap();
+            part.current_hp = part.max_hp * 0.1;
+            part.recalculate_function();
+        }
+        let injured_defense = Defense {
+            alertness: 1.0,
+            locomotion: injured.channel_capacity(Channel::Locomotion, &mapping),
+            combat_skill: 1.0,
+            stress: 90.0,
+        };
+        assert!(injured_defense.locomotion < skilled_defense.locomotion);
+
+        let skilled_dodge = dodge_chance(&config, attacker_skill, &skilled_defense);
+        let injured_dodge = dodge_chance(&config, attacker_skill, &injured_defense);
+        assert!(
+            injured_dodge < skilled_dodge * 0.5,
+            "injured+stressed {injured_dodge} vs skilled {skilled_dodge}"
+        );
+
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let mut strike = |body: &mut Body, dodge: f32| {
+            (0..200)
+                .filter(|_| {
+                    matches!(
+                        apply_strike(&mut rng, ActionType::Attack, attacker_skill, body, dodge),
+                        Resolution::Missed
+                    )
+                })
+                .count()
+        };
+        let mut skilled_target = Body::human();
+        let skilled_misses = strike(&mut skilled_target, skilled_dodge);
+        let injured_misses = strike(&mut injured, injured_dodge);
+
+        assert!(
+            skilled_misses > 40,
+            "a trained, calm, mobile defender dodges often ({skilled_misses}/200)"
+        );
+        assert!(
+            injured_misses < skilled_misses / 2,
+            "an injured, stressed defender is hit far more ({injured_misses} vs {skilled_misses})"
+        );
+        assert!(injured.parts.iter().any(|p| !p.injuries.is_empty()));
+    }
 }
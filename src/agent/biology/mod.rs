@@ -18,6 +18,8 @@ impl Plugin for BiologyPlugin {
             .register_type::<body::Injury>()
             .register_type::<body::InjuryType>()
             .init_resource::<body::TagChannelMapping>()
+            .register_type::<combat::CombatConfig>()
+            .init_resource::<combat::CombatConfig>()
             .add_systems(
                 FixedUpdate,
                 (
@@ -31,6 +33,8 @@ impl Plugin for BiologyPlugin {
                     combat::resolve_combat_hits
                         .after(crate::agent::nervous_system::execution::tick_actions),
                     combat::bleed_system,
+                    combat::bandage_tended_wounds
+                        .after(crate::agent::nervous_system::execution::tick_actions),
                     combat::severance_system.after(combat::resolve_combat_hits),
                     derive_lameness.after(combat::resolve_combat_hits),
                     expire_dazed,
@@ -338,6 +338,7 @@ pub fn develop_phenotype_system(
             phenotype,
             Vision {
                 range: vision_range,
+                fov_degrees: species.fov_degrees,
             },
             personality,
             values,
@@ -137,6 +137,16 @@ impl FoodMacros {
     pub fn total_mass(&self) -> f32 {
         self.carbs + self.fat
     }
+
+    /// The same food in a `fraction` portion — one bite of a meal.
+    pub fn scaled(self, fraction: f32) -> Self {
+        Self::new(self.carbs * fraction, self.fat * fraction)
+    }
+
+    /// Energy content: fat is more than twice as energy-dense as carbs.
+    pub fn energy(&self) -> f32 {
+        self.carbs * CARB_ENERGY_PER_UNIT + self.fat * FAT_ENERGY_PER_UNIT
+    }
 }
 
 /// Multipliers derived from digestive-organ condition (stomach, liver, gut).
@@ -207,6 +217,26 @@ pub fn food_macros(concept: Concept) -> Option<FoodMacros> {
     }
 }
 
+/// Energy per unit of carbohydrate, relative to fat below.
+pub const CARB_ENERGY_PER_UNIT: f32 = 4.0;
+/// Energy per unit of fat.
+pub const FAT_ENERGY_PER_UNIT: f32 = 9.0;
+/// Energy of the most filling modeled meal (cooked meat). Nutrition values
+/// are expressed as a fraction of it.
+const REFERENCE_MEAL_ENERGY: f32 = 60.0 * FAT_ENERGY_PER_UNIT;
+
+/// How filling a meal of `macros` is, in `[0, 1]` relative to the most
+/// filling modeled meal.
+pub fn meal_nutrition(macros: FoodMacros) -> f32 {
+    (macros.energy() / REFERENCE_MEAL_ENERGY).clamp(0.0, 1.0)
+}
+
+/// Nutrition value of one unit of `concept`. Apples sit near the bottom,
+/// cooked meat at the top. Returns `None` for anything not modeled as food.
+pub fn nutrition_value(concept: Concept) -> Option<f32> {
+    food_macros(concept).map(meal_nutrition)
+}
+
 /// The three-stage nutrient / energy loop. Lives inside `PhysicalNeeds`.
 ///
 /// Starts with `Metabolism::well_fed()` by default — matches spawn behavior
@@ -171,6 +171,14 @@ pub struct PhysicalNeeds {
     /// Drives `UrgencySource::FoodSecurity`, which motivates building
     /// storage chests.
     pub food_security: Need,
+    /// Tick of the agent's last completed Eat. Zero until the first meal,
+    /// so a fresh agent counts from the start of the simulation. Feeds the
+    /// hunger neglect boost in `generate_urgency`.
+    pub last_ate_tick: u64,
+    /// Tick the agent last finished waking up from Sleep. Same
+    /// zero-until-first convention as `last_ate_tick`; feeds the
+    /// sleepiness neglect boost.
+    pub last_slept_tick: u64,
 }
 
 impl PhysicalNeeds {
@@ -195,6 +203,8 @@ impl PhysicalNeeds {
             warmth: Need::full(),
             rest_quality: Need::full(),
             food_security: Need::full(),
+            last_ate_tick: 0,
+            last_slept_tick: 0,
         }
     }
 
@@ -27,6 +27,7 @@ pub enum Diet {
     Carnivore, // Meat only
     #[default]
     Omnivore, // Everything
+    Scavenger, // Carrion — feeds on corpses rather than hunting
 }
 
 /// Defines cognitive and physical parameters for a species.
@@ -64,6 +65,11 @@ pub struct SpeciesProfile {
     /// How far can see
     pub vision_range: f32,
 
+    /// Width of the field of view, in degrees. Prey eyes sit on the sides
+    /// of the head and see almost all the way round; predators trade width
+    /// for a forward overlap.
+    pub fov_degrees: f32,
+
     /// Dietary requirements
     pub diet: Diet,
 
@@ -101,6 +107,7 @@ impl SpeciesProfile {
 
             base_speed: 1.0,
             vision_range: 240.0,
+            fov_degrees: 200.0,
             diet: Diet::Omnivore,
             mass_kg: 70.0,
 
@@ -123,6 +130,7 @@ impl SpeciesProfile {
 
             base_speed: 1.2,
             vision_range: 200.0,
+            fov_degrees: 300.0,
             diet: Diet::Herbivore,
             mass_kg: 80.0,
 
@@ -145,6 +153,7 @@ impl SpeciesProfile {
 
             base_speed: 1.4,
             vision_range: 280.0,
+            fov_degrees: 250.0,
             diet: Diet::Carnivore,
             mass_kg: 40.0,
 
@@ -167,6 +176,7 @@ impl SpeciesProfile {
 
             base_speed: 1.5,
             vision_range: 120.0,
+            fov_degrees: 340.0,
             diet: Diet::Herbivore,
             mass_kg: 2.0,
 
@@ -190,6 +200,7 @@ impl SpeciesProfile {
 
             base_speed: 0.9,
             vision_range: 60.0,
+            fov_degrees: 300.0,
             diet: Diet::Omnivore,
             mass_kg: 0.05,
 
@@ -213,6 +224,7 @@ impl SpeciesProfile {
 
             base_speed: 1.3,
             vision_range: 90.0,
+            fov_degrees: 200.0,
             diet: Diet::Carnivore,
             mass_kg: 4.0,
 
@@ -1,13 +1,14 @@
 //! Brain arbitration: selects the winning brain proposal by urgency and power levels.
 //!
-//! Reads: BrainProposal (from all brains), CentralNervousSystem, Consciousness, EmotionalState, Personality
-//! Writes: BrainPowers, BrainState (chosen action and winner)
+//! Reads: BrainProposal (from all brains), CentralNervousSystem, Consciousness, EmotionalState, Personality, ArbitrationHysteresis
+//! Writes: BrainPowers, BrainState (chosen action, winner, incumbent)
 //! Upstream: survival, emotional, and rational brain systems (proposal.rs)
 //! Downstream: brain_system (consumes arbitrated BrainState), nervous_system execution
 
 use std::collections::HashMap;
 
-use super::proposal::{BrainPowers, BrainProposal, Intent};
+use super::BrainReactionLatency;
+use super::proposal::{BrainPowers, BrainProposal, BrainType, Incumbent, Intent, PendingTakeover};
 use crate::agent::actions::channel::ChannelCapacities;
 use crate::agent::body::needs::Consciousness;
 use crate::agent::engagement::EngagementKind;
@@ -105,35 +106,62 @@ pub fn calculate_brain_powers(
 /// behavior) are passed through untouched, since they don't compete for any
 /// specific drive.
 ///
-/// Order of returned proposals is not specified — the caller is expected to
-/// re-sort by score afterwards.
+/// Survivors keep the position of the first proposal for their intent, so
+/// the output order is the input order minus the dropped duplicates. On a
+/// score tie the earlier proposal stays.
 fn deduplicate_by_intent(
     proposals: Vec<BrainProposal>,
     powers: &BrainPowers,
     registry: &crate::agent::actions::ActionRegistry,
 ) -> Vec<BrainProposal> {
-    let mut by_intent: HashMap<Intent, BrainProposal> = HashMap::new();
-    let mut passthrough: Vec<BrainProposal> = Vec::new();
+    let mut slot_for_intent: HashMap<Intent, usize> = HashMap::new();
+    let mut out: Vec<BrainProposal> = Vec::new();
 
     for prop in proposals {
         if prop.intent == Intent::None {
-            passthrough.push(prop);
+            out.push(prop);
             continue;
         }
-        let score = score_proposal(&prop, powers, registry);
-        match by_intent.get(&prop.intent) {
-            Some(existing) if score_proposal(existing, powers, registry) >= score => {}
-            _ => {
-                by_intent.insert(prop.intent, prop);
+        match slot_for_intent.get(&prop.intent) {
+            Some(&slot) => {
+                let score = score_proposal(&prop, powers, registry);
+                if score > score_proposal(&out[slot], powers, registry) {
+                    out[slot] = prop;
+                }
+            }
+            None => {
+                slot_for_intent.insert(prop.intent, out.len());
+                out.push(prop);
             }
         }
     }
 
-    let mut out: Vec<BrainProposal> = by_intent.into_values().collect();
-    out.extend(passthrough);
     out
 }
 
+/// Rank this tick's proposals for arbitration: deduplicate by intent, score
+/// each survivor (urgency × its brain's power, ambient actions halved),
+/// drop non-positive scores, and sort descending. Equal scores keep
+/// proposal order, so the earlier brain slot wins a tie.
+///
+/// Pure — no body-channel or posture checks. The first entry is the
+/// proposal arbitration would pick if nothing were already running;
+/// [`arbitrate_parallel`] walks the ranking to build the parallel set.
+pub fn score_proposals(
+    proposals: &[Option<BrainProposal>],
+    powers: &BrainPowers,
+    registry: &crate::agent::actions::ActionRegistry,
+) -> Vec<(f32, BrainProposal)> {
+    let collected: Vec<BrainProposal> = proposals.iter().flatten().cloned().collect();
+    let mut scored: Vec<(f32, BrainProposal)> = deduplicate_by_intent(collected, powers, registry)
+        .into_iter()
+        .map(|p| (score_proposal(&p, powers, registry), p))
+        .filter(|(s, _)| *s > 0.0)
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    scored
+}
+
 /// Outcome of a single arbitration cycle. `admitted` is the parallel set
 /// the agent runs this tick. `rejected` lists proposals that lost on a
 /// body-channel conflict (or the single-Movement-per-tick rule) — they
@@ -149,9 +177,9 @@ pub struct ArbitrationResult {
 
 /// Multi-action arbitration: greedy admission of proposals into a parallel set.
 ///
-/// 1. Deduplicate by `Intent` (see [`deduplicate_by_intent`]) so two brains
-///    can't admit competing answers to the same drive.
-/// 2. Sort remaining proposals by score (urgency * brain power), descending.
+/// 1-2. Rank with [`score_proposals`]: deduplicate by `Intent` so two
+///    brains can't admit competing answers to the same drive, then sort by
+///    score (urgency * brain power), descending.
 /// 3. For each proposal in score order, admit it if its body channels do not
 ///    hard-conflict with the already-admitted set, accounting for the agent's
 ///    body capacity (injuries / incapacitation / exhaustion).
@@ -177,15 +205,7 @@ pub fn arbitrate_parallel(
 ) -> ArbitrationResult {
     use crate::agent::actions::channel::ChannelLoad;
 
-    let collected: Vec<BrainProposal> = proposals.iter().flatten().cloned().collect();
-    let deduped = deduplicate_by_intent(collected, powers, registry);
-
-    let mut scored: Vec<(f32, BrainProposal)> = deduped
-        .into_iter()
-        .map(|p| (score_proposal(&p, powers, registry), p))
-        .filter(|(s, _)| *s > 0.0)
-        .collect();
-    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    let scored = score_proposals(proposals, powers, registry);
 
     let mut admitted: Vec<BrainProposal> = Vec::new();
     let mut rejected: Vec<BrainProposal> = Vec::new();
@@ -294,11 +314,86 @@ fn score_proposal(
 /// else is proposed.
 const AMBIENT_SCORE_FACTOR: f32 = 0.5;
 
+/// Reaction-latency gate on a change of controlling brain. Returns true
+/// when `top` (this tick's winning brain) may take control now. A brain
+/// that differs from `current` starts a pending takeover on its first win
+/// and is admitted once `latency.ticks(brain)` ticks have passed; keeping
+/// the same brain, or having nothing to commit, clears any pending entry.
+pub fn takeover_ready(
+    pending: &mut Option<PendingTakeover>,
+    current: Option<BrainType>,
+    top: Option<BrainType>,
+    latency: &BrainReactionLatency,
+    now: u64,
+) -> bool {
+    let Some(brain) = top.filter(|b| Some(*b) != current) else {
+        *pending = None;
+        return true;
+    };
+    let ready_at = match *pending {
+        Some(p) if p.brain == brain => p.ready_at,
+        _ => {
+            let takeover = PendingTakeover {
+                brain,
+                since_tick: now,
+                ready_at: now + latency.ticks(brain),
+            };
+            *pending = Some(takeover);
+            takeover.ready_at
+        }
+    };
+    if now >= ready_at {
+        *pending = None;
+        true
+    } else {
+        false
+    }
+}
+
+/// Stickiness: scale up the incumbent's proposal while its hold lasts,
+/// so a rival has to clearly outscore it to take over. Applied to the
+/// urgency before arbitration, like the aspiration multiplier, so scoring
+/// needs no knowledge of it.
+pub fn apply_incumbent_boost(
+    proposals: &mut [Option<BrainProposal>],
+    incumbent: Option<Incumbent>,
+    boost: f32,
+    now: u64,
+) {
+    let Some(incumbent) = incumbent.filter(|i| now < i.hold_until) else {
+        return;
+    };
+    for proposal in proposals.iter_mut().flatten() {
+        if incumbent.backs(proposal) {
+            proposal.urgency *= boost;
+        }
+    }
+}
+
+/// The incumbent after this tick's decision. The same brain and action
+/// winning again keeps the current hold running down; a new winner
+/// starts a fresh hold of `hold_ticks`; no winner clears it.
+pub fn next_incumbent(
+    current: Option<Incumbent>,
+    top: Option<&BrainProposal>,
+    hold_ticks: u64,
+    now: u64,
+) -> Option<Incumbent> {
+    let top = top?;
+    match current {
+        Some(incumbent) if incumbent.backs(top) => Some(incumbent),
+        _ => Some(Incumbent {
+            brain: top.brain,
+            action_type: top.action.action_type,
+            hold_until: now + hold_ticks,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::agent::actions::{ActionRegistry, ActionType};
-    use crate::agent::brains::proposal::BrainType;
     use crate::agent::brains::thinking::ActionTemplate;
     use crate::agent::nervous_system::urgency::{Urgency, UrgencySource};
     use crate::agent::psyche::emotions::{Emotion, EmotionType};
@@ -443,6 +538,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn score_proposals_ranks_the_most_urgent_proposal_first() {
+        let powers = unit_powers();
+        let eat = make_proposal(
+            BrainType::Survival,
+            ActionType::Eat,
+            80.0,
+            Intent::SatisfyHunger,
+        );
+        let talk = make_proposal(
+            BrainType::Emotional,
+            ActionType::Wave,
+            20.0,
+            Intent::SatisfySocial,
+        );
+
+        let ranked = score_proposals(
+            &[Some(talk), None, Some(eat)],
+            &powers,
+            &ActionRegistry::new(),
+        );
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].1.brain, BrainType::Survival);
+        assert_eq!(ranked[0].1.action.action_type, ActionType::Eat);
+        assert!((ranked[0].0 - 80.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn score_proposals_breaks_ties_by_proposal_order() {
+        let powers = unit_powers();
+        let eat = make_proposal(
+            BrainType::Survival,
+            ActionType::Eat,
+            50.0,
+            Intent::SatisfyHunger,
+        );
+        let talk = make_proposal(
+            BrainType::Emotional,
+            ActionType::Wave,
+            50.0,
+            Intent::SatisfySocial,
+        );
+        let registry = ActionRegistry::new();
+
+        let forward = score_proposals(&[Some(eat.clone()), Some(talk.clone())], &powers, &registry);
+        let reversed = score_proposals(&[Some(talk), Some(eat)], &powers, &registry);
+
+        assert_eq!(forward[0].1.action.action_type, ActionType::Eat);
+        assert_eq!(reversed[0].1.action.action_type, ActionType::Wave);
+    }
+
+    #[test]
+    fn score_proposals_lets_a_powerful_brain_outvote_a_more_urgent_one() {
+        let powers = BrainPowers {
+            survival: 10.0,
+            emotional: 90.0,
+            rational: 10.0,
+        };
+        let eat = make_proposal(
+            BrainType::Survival,
+            ActionType::Eat,
+            0.9,
+            Intent::SatisfyHunger,
+        );
+        let flee = make_proposal(
+            BrainType::Emotional,
+            ActionType::Flee,
+            0.3,
+            Intent::SatisfySafety,
+        );
+
+        let ranked = score_proposals(&[Some(eat), Some(flee)], &powers, &ActionRegistry::new());
+
+        assert_eq!(ranked[0].1.brain, BrainType::Emotional);
+        assert!((ranked[0].0 - 27.0).abs() < 1e-4);
+        assert!((ranked[1].0 - 9.0).abs() < 1e-4);
+    }
+
     #[test]
     fn arbitrate_parallel_resolves_walk_explore_hunger_deadlock() {
         // Original deadlock: Rational proposes Walk(SatisfyHunger),
@@ -788,4 +962,129 @@ mod tests {
         );
         assert_eq!(result.admitted[0].action.action_type, ActionType::Flee);
     }
+
+    #[test]
+    fn rational_takeover_lags_survival_takeover() {
+        let latency = BrainReactionLatency {
+            survival: 0,
+            emotional: 6,
+            rational: 12,
+        };
+
+        // Survival overrides a running rational decision on the spot.
+        let mut pending = None;
+        assert!(takeover_ready(
+            &mut pending,
+            Some(BrainType::Rational),
+            Some(BrainType::Survival),
+            &latency,
+            100,
+        ));
+        assert!(pending.is_none());
+
+        // Rational taking over from survival waits out its latency.
+        let mut pending = None;
+        let mut committed_at = None;
+        for now in 100..200 {
+            if takeover_ready(
+                &mut pending,
+                Some(BrainType::Survival),
+                Some(BrainType::Rational),
+                &latency,
+                now,
+            ) {
+                committed_at = Some(now);
+                break;
+            }
+            assert_eq!(pending.map(|p| p.since_tick), Some(100));
+        }
+        assert_eq!(committed_at, Some(112));
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn pending_takeover_resets_when_a_different_brain_wins() {
+        let latency = BrainReactionLatency::default();
+        let mut pending = None;
+        assert!(!takeover_ready(
+            &mut pending,
+            Some(BrainType::Survival),
+            Some(BrainType::Rational),
+            &latency,
+            10,
+        ));
+        // Emotional wins the next tick: the rational countdown is dropped
+        // and emotional starts its own.
+        let ready = takeover_ready(
+            &mut pending,
+            Some(BrainType::Survival),
+            Some(BrainType::Emotional),
+            &latency,
+            11,
+        );
+        assert_eq!(ready, latency.emotional == 0);
+        if !ready {
+            let p = pending.expect("emotional takeover pending");
+            assert_eq!(p.brain, BrainType::Emotional);
+            assert_eq!(p.since_tick, 11);
+        }
+        // Staying with the current brain clears it.
+        assert!(takeover_ready(
+            &mut pending,
+            Some(BrainType::Survival),
+            Some(BrainType::Survival),
+            &latency,
+            12,
+        ));
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn incumbent_boost_stops_a_near_tie_from_flipping_the_decision() {
+        let powers = unit_powers();
+        let registry = ActionRegistry::new();
+        let walk = make_proposal(
+            BrainType::Rational,
+            ActionType::Walk,
+            50.0,
+            Intent::SatisfyHunger,
+        );
+        let wave = make_proposal(
+            BrainType::Emotional,
+            ActionType::Wave,
+            52.0,
+            Intent::SatisfySocial,
+        );
+        let incumbent = next_incumbent(None, Some(&walk), 30, 100);
+        assert_eq!(
+            incumbent.map(|i| (i.brain, i.action_type, i.hold_until)),
+            Some((BrainType::Rational, ActionType::Walk, 130))
+        );
+
+        let mut proposals = vec![Some(walk.clone()), Some(wave.clone())];
+        apply_incumbent_boost(&mut proposals, incumbent, 1.25, 110);
+        let ranked = score_proposals(&proposals, &powers, &registry);
+        assert_eq!(
+            ranked[0].1.action.action_type,
+            ActionType::Walk,
+            "a slightly stronger rival shouldn't unseat the incumbent"
+        );
+
+        let mut proposals = vec![Some(walk.clone()), Some(wave.clone())];
+        apply_incumbent_boost(&mut proposals, incumbent, 1.25, 130);
+        let ranked = score_proposals(&proposals, &powers, &registry);
+        assert_eq!(
+            ranked[0].1.action.action_type,
+            ActionType::Wave,
+            "once the hold runs out the stronger proposal wins"
+        );
+
+        // Re-winning keeps the hold running down; a new winner restarts it.
+        assert_eq!(next_incumbent(incumbent, Some(&walk), 30, 120), incumbent);
+        assert_eq!(
+            next_incumbent(incumbent, Some(&wave), 30, 130).map(|i| i.hold_until),
+            Some(160)
+        );
+        assert_eq!(next_incumbent(incumbent, None, 30, 130), None);
+    }
 }
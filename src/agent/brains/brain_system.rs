@@ -1,7 +1,7 @@
 //! Three-brains orchestration: runs all brain systems and arbitrates between their proposals each tick.
 //!
 //! Reads: PhysicalNeeds, Consciousness, PsychologicalDrives, EmotionalState, Body, Personality, ItemSlots, VisibleObjects, MindGraph, ActiveActions, WorldMap, BrainHistory, PlanMemory
-//! Writes: BrainState (chosen action, winner, proposals, powers), BrainHistory (active attributions), SimEvent::Decision
+//! Writes: BrainState (chosen action, winner, proposals, powers, pending takeover, incumbent), BrainHistory (active attributions), SimEvent::Decision
 //! Upstream: survival/emotional/rational brain modules, arbitration, perception, knowledge
 //! Downstream: nervous_system::cns (executes the chosen action), SimEvent consumers
 
@@ -13,6 +13,7 @@ use super::proposal::{BrainPowers, BrainState, BrainType};
 use super::rational::rational_brain_propose;
 use super::social_initiation::SocialInitiationCooldowns;
 use super::survival::{SurvivalBrainContext, survival_brain_propose};
+use super::target_enumeration::TargetComponents;
 use crate::agent::biology::body::{Body, TagChannelMapping};
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds, PsychologicalDrives};
 use crate::agent::events::SimEventKind;
@@ -100,6 +101,7 @@ pub fn arbitrate_every_tick(
                 &Personality,
                 &crate::agent::item_slots::ItemSlots,
                 Option<&crate::agent::psyche::aspirations::Aspirations>,
+                Option<&crate::agent::skills::Skills>,
             ),
             // Context
             (
@@ -109,6 +111,7 @@ pub fn arbitrate_every_tick(
                 &crate::agent::actions::ActiveActions,
                 Option<&crate::agent::engagement::Engaged>,
                 Option<&crate::agent::inventory::EntityType>,
+                Option<&crate::agent::lod::LodLevel>,
             ),
         ),
         (
@@ -123,7 +126,7 @@ pub fn arbitrate_every_tick(
     world_map: Res<WorldMap>,
     world_positions: Res<crate::world::entity_positions::WorldEntityPositions>,
     action_registry: Res<crate::agent::actions::ActionRegistry>,
-    _affordances: Query<(
+    affordances: Query<(
         &GlobalTransform,
         Option<&crate::agent::affordance::Affordance>,
         Option<&crate::agent::Dead>,
@@ -145,10 +148,21 @@ pub fn arbitrate_every_tick(
         Query<&crate::agent::engagement::Engaged>,
         Query<&SocialInitiationCooldowns>,
         Res<crate::agent::psyche::social_graph::SocialGraph>,
+        Res<super::BrainReactionLatency>,
+        Res<super::emotional::EmotionalResponseConfig>,
+        Res<super::ArbitrationHysteresis>,
     ),
 ) {
-    let (cornered_query, dazed_query, engaged_query, social_cooldowns_query, social_graph) =
-        side_queries;
+    let (
+        cornered_query,
+        dazed_query,
+        engaged_query,
+        social_cooldowns_query,
+        social_graph,
+        reaction_latency,
+        emotional_responses,
+        hysteresis,
+    ) = side_queries;
     let woken = pending.drain();
 
     for (
@@ -157,8 +171,8 @@ pub fn arbitrate_every_tick(
         mut brain_state,
         (mut plan_memory, cns),
         (physical, consciousness, drives),
-        (emotions, body, personality, inventory, aspirations),
-        (transform, visible, mind, active_actions, engaged, self_entity_type),
+        (emotions, body, personality, inventory, aspirations, skills),
+        (transform, visible, mind, active_actions, engaged, self_entity_type, lod),
     ) in query.iter_mut()
     {
         // Skip agents whose situation didn't change this tick — their
@@ -188,6 +202,7 @@ pub fn arbitrate_every_tick(
             most_feared_entity: closest_dangerous.map(|(e, _)| e),
             pos: agent_pos,
             world_map: &world_map,
+            food_in_view: super::survival::food_in_view(visible, mind, &ontology),
         };
 
         let survival_proposals = survival_brain_propose(
@@ -231,6 +246,9 @@ pub fn arbitrate_every_tick(
             body: all_bodies.get(e).ok(),
         });
         let cornered = cornered_query.contains(entity);
+        let knowledge_gap = plan_memory
+            .in_state(PlanState::Executing)
+            .find_map(|plan| crate::agent::mind::epistemic::location_gap(&plan.goal, mind));
 
         let emotional_inputs = super::emotional::EmotionalInputs {
             emotions,
@@ -242,12 +260,14 @@ pub fn arbitrate_every_tick(
             visible_types: &visible_types,
             physical,
             drives,
+            inventory: Some(inventory),
             engaged,
             self_concept: self_entity_type.map(|t| t.0),
             agent_pos,
             fields: &fields,
             cns,
             action_registry: &action_registry,
+            responses: &emotional_responses,
             personality: Some(&personality.traits),
             body,
             cornered,
@@ -255,12 +275,20 @@ pub fn arbitrate_every_tick(
             visible_engaged_converse: &visible_engaged_converse,
             social_cooldowns,
             current_tick: tick.current,
+            lod: lod.copied().unwrap_or_default(),
+            knowledge_gap,
         };
         let emotional_proposal = emotional_brain_propose(&emotional_inputs);
 
         // Rational brain now surfaces one proposal per Executing plan in
         // `PlanMemory`, so the output is variable-length and joins the
-        let rational_proposals = rational_brain_propose(&plan_memory, cns, mind, &action_registry);
+        let rational_proposals =
+            rational_brain_propose(&plan_memory, cns, mind, &action_registry, |e| {
+                affordances
+                    .get(e)
+                    .ok()
+                    .map(|(_, affordance, dead)| TargetComponents::new(affordance, dead))
+            });
 
         // 2. Calculate brain powers, then apply history-based multiplier
         let base_powers = calculate_brain_powers(cns, consciousness, emotions, personality);
@@ -297,6 +325,7 @@ pub fn arbitrate_every_tick(
             emotional: Some(emotions),
             current_tick: tick.current,
             unreachable_tiles: &unreachable_tiles,
+            skills,
         };
         // Single pass: feasibility filter + aspiration bias on the
         // urgency. Aspiration multiplier runs pre-arbitration so the
@@ -324,6 +353,12 @@ pub fn arbitrate_every_tick(
             );
             proposal.urgency *= mult;
         }
+        super::arbitration::apply_incumbent_boost(
+            &mut proposals,
+            brain_state.incumbent,
+            hysteresis.incumbent_boost,
+            tick.current,
+        );
 
         let capacities = crate::agent::actions::ChannelCapacities::compute(
             body,
@@ -383,6 +418,27 @@ pub fn arbitrate_every_tick(
             }
         }
 
+        // 3b. Reaction latency: a brain wresting control from another has
+        // to wait out its latency before its decision lands. Meanwhile the
+        // body keeps running whatever of the previous decision is still
+        // in progress — finished actions are not restarted — and the
+        // agent is re-woken so the takeover is re-checked next brain tick.
+        if !super::arbitration::takeover_ready(
+            &mut brain_state.pending_takeover,
+            brain_state.winner,
+            admitted.first().map(|p| p.brain),
+            &reaction_latency,
+            tick.current,
+        ) {
+            brain_state
+                .chosen_actions
+                .retain(|a| active_actions.contains(a.action_type));
+            brain_state.proposals = proposals.into_iter().flatten().collect();
+            brain_state.powers = powers;
+            pending.wake(entity);
+            continue;
+        }
+
         // 4. Update attribution map so outcome events can credit the right brain
         if let Ok(mut history) = brain_histories.get_mut(entity) {
             history.active.retain(|at, _| active_actions.contains(*at));
@@ -396,6 +452,12 @@ pub fn arbitrate_every_tick(
         // 5. Store for debugging/UI and execution
         brain_state.proposals = proposals.into_iter().flatten().collect();
         brain_state.powers = powers;
+        brain_state.incumbent = super::arbitration::next_incumbent(
+            brain_state.incumbent,
+            admitted.first(),
+            hysteresis.hold_ticks,
+            tick.current,
+        );
 
         if let Some(top) = admitted.first() {
             brain_state.winner = Some(top.brain);
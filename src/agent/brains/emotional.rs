@@ -1,6 +1,6 @@
 //! Emotional brain: association-driven behavior based on feelings.
 //!
-//! Reads: EmotionalState, MindGraph, VisibleObjects, PsychologicalDrives, Engaged
+//! Reads: EmotionalState, MindGraph, VisibleObjects, PsychologicalDrives, ItemSlots (food to give), Engaged, EmotionalResponseConfig, LodLevel
 //! Writes: BrainProposal
 //! Upstream: perception (VisibleObjects), psyche (EmotionalState)
 //! Downstream: brains::proposal (winner selection)
@@ -17,9 +17,15 @@ use super::social_initiation::SocialInitiationCooldowns;
 use crate::agent::actions::ActionType;
 use crate::agent::body::needs::{PhysicalNeeds, PsychologicalDrives};
 use crate::agent::engagement::Engaged;
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::lod::LodLevel;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
 use crate::agent::mind::perception::VisibleObjects;
 use crate::agent::psyche::emotions::{EmotionType, EmotionalState};
+use crate::constants::actions::give::{
+    MIN_AFFECTION as GIVE_MIN_AFFECTION, REACH as GIVE_REACH,
+    URGENCY_MULTIPLIER as GIVE_URGENCY_MULTIPLIER,
+};
 use crate::constants::brains::emotional::{
     ANGER_ENTITY_THRESHOLD, ANGER_ENTITY_URGENCY_MULTIPLIER, FEAR_ENTITY_THRESHOLD,
     FEAR_ENTITY_URGENCY_MULTIPLIER, FEAR_GENERAL_THRESHOLD, FEAR_GENERAL_URGENCY_MULTIPLIER,
@@ -32,6 +38,75 @@ use crate::world::map::TILE_SIZE;
 use crate::world::spatial_index::world_pos_to_tile;
 use bevy::prelude::*;
 
+/// One emotion → action rule. Entity rules fire on feelings toward a
+/// visible entity; general rules fire on the agent's summed active emotion.
+#[derive(Reflect, Clone, Debug)]
+pub struct EmotionResponse {
+    pub emotion: EmotionType,
+    pub action: ActionType,
+    /// Intensity the emotion must exceed before the rule fires.
+    pub threshold: f32,
+    /// Proposal urgency = intensity × this.
+    pub urgency_multiplier: f32,
+    pub intent: Intent,
+    /// Aim the action at the entity that triggered it. Ignored by general
+    /// rules, which have no entity.
+    pub target_entity: bool,
+}
+
+/// Emotion → action mappings for the emotional brain. Defaults are fear →
+/// Flee, joy → Walk toward, anger → Attack for entities, and general fear
+/// → Flee. Swap entries to give a culture or species a different response
+/// (freeze instead of flee, say). Threat appraisal of a visible Dangerous
+/// entity is separate and not driven by this table.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct EmotionalResponseConfig {
+    pub entity: Vec<EmotionResponse>,
+    pub general: Vec<EmotionResponse>,
+}
+
+impl Default for EmotionalResponseConfig {
+    fn default() -> Self {
+        Self {
+            entity: vec![
+                EmotionResponse {
+                    emotion: EmotionType::Fear,
+                    action: ActionType::Flee,
+                    threshold: FEAR_ENTITY_THRESHOLD,
+                    urgency_multiplier: FEAR_ENTITY_URGENCY_MULTIPLIER,
+                    intent: Intent::SatisfySafety,
+                    target_entity: true,
+                },
+                EmotionResponse {
+                    emotion: EmotionType::Joy,
+                    action: ActionType::Walk,
+                    threshold: JOY_ENTITY_THRESHOLD,
+                    urgency_multiplier: JOY_ENTITY_URGENCY_MULTIPLIER,
+                    intent: Intent::SatisfySocial,
+                    target_entity: true,
+                },
+                EmotionResponse {
+                    emotion: EmotionType::Anger,
+                    action: ActionType::Attack,
+                    threshold: ANGER_ENTITY_THRESHOLD,
+                    urgency_multiplier: ANGER_ENTITY_URGENCY_MULTIPLIER,
+                    intent: Intent::SatisfySafety,
+                    target_entity: true,
+                },
+            ],
+            general: vec![EmotionResponse {
+                emotion: EmotionType::Fear,
+                action: ActionType::Flee,
+                threshold: FEAR_GENERAL_THRESHOLD,
+                urgency_multiplier: FEAR_GENERAL_URGENCY_MULTIPLIER,
+                intent: Intent::SatisfySafety,
+                target_entity: false,
+            }],
+        }
+    }
+}
+
 pub struct EmotionalInputs<'a> {
     pub emotions: &'a EmotionalState,
     pub mind: &'a MindGraph,
@@ -52,12 +127,17 @@ pub struct EmotionalInputs<'a> {
     pub visible_types: &'a [Option<Concept>],
     pub physical: &'a PhysicalNeeds,
     pub drives: Option<&'a PsychologicalDrives>,
+    /// What the agent carries — Give only fires with food in hand.
+    /// `None` in fixtures that don't exercise giving.
+    pub inventory: Option<&'a ItemSlots>,
     pub engaged: Option<&'a Engaged>,
     pub self_concept: Option<Concept>,
     pub agent_pos: Vec2,
     pub fields: &'a FieldGrids,
     pub cns: &'a crate::agent::nervous_system::cns::CentralNervousSystem,
     pub action_registry: &'a crate::agent::actions::ActionRegistry,
+    /// Which action each emotion proposes; see [`EmotionalResponseConfig`].
+    pub responses: &'a EmotionalResponseConfig,
     /// Big Five traits, used by threat appraisal for boldness scoring.
     pub personality: Option<&'a crate::agent::psyche::personality::PersonalityTraits>,
     /// Defender body, used by threat appraisal for combat-power scoring.
@@ -77,6 +157,11 @@ pub struct EmotionalInputs<'a> {
     /// the agent records its first failure.
     pub social_cooldowns: Option<&'a SocialInitiationCooldowns>,
     pub current_tick: u64,
+    /// Reduced-LOD agents don't start conversations nobody is there to see.
+    pub lod: LodLevel,
+    /// What the agent's committed goal needs but it can't locate
+    /// (`epistemic::location_gap`). Pulls it toward someone to ask.
+    pub knowledge_gap: Option<Concept>,
 }
 
 pub struct ClosestThreat<'a> {
@@ -125,6 +210,7 @@ pub fn emotional_brain_propose(inputs: &EmotionalInputs) -> Option<BrainProposal
             entity_type,
             inputs.mind,
             inputs.action_registry,
+            inputs.responses,
             best_urgency,
         ) {
             best = Some(proposal);
@@ -157,6 +243,10 @@ pub fn emotional_brain_propose(inputs: &EmotionalInputs) -> Option<BrainProposal
             anger,
             cornered: inputs.cornered,
             attacker_body: threat.body,
+            power_balance: inputs
+                .social_graph
+                .get(inputs.self_entity, threat.entity)
+                .map_or(0.0, |edge| edge.power_balance),
             dependents_nearby: 0,
             on_home_turf: false,
             prior_experience: 0.0,
@@ -171,9 +261,12 @@ pub fn emotional_brain_propose(inputs: &EmotionalInputs) -> Option<BrainProposal
             best_urgency = proposal.urgency;
             best = Some(proposal);
         }
-    } else if let Some(proposal) =
-        check_general_fear(inputs.emotions, best_urgency, inputs.action_registry)
-    {
+    } else if let Some(proposal) = check_general_emotions(
+        inputs.emotions,
+        best_urgency,
+        inputs.action_registry,
+        inputs.responses,
+    ) {
         // No specific Dangerous entity visible but accumulated general
         // fear — still flee. Covers post-trauma fear, audible-alarm
         // fear, and other no-visible-threat cases.
@@ -185,6 +278,7 @@ pub fn emotional_brain_propose(inputs: &EmotionalInputs) -> Option<BrainProposal
     // engaged because a second engagement mid-chat is silly
     // (channel costs alone can't block it: InitiateConversation is Focus 0).
     if inputs.engaged.is_none()
+        && inputs.lod == LodLevel::Full
         && inputs.self_concept == Some(Concept::Person)
         && let Some(d) = inputs.drives
         && let Some(proposal) =
@@ -194,6 +288,14 @@ pub fn emotional_brain_propose(inputs: &EmotionalInputs) -> Option<BrainProposal
         best = Some(proposal);
     }
 
+    // Giving — feed a hungry friend within reach.
+    if inputs.self_concept == Some(Concept::Person)
+        && let Some(proposal) = propose_give(inputs, best_urgency)
+    {
+        best_urgency = proposal.urgency;
+        best = Some(proposal);
+    }
+
     // Reactive drift — score local tiles per drive, walk toward the best.
     if inputs.engaged.is_none() {
         let drift_ctx = DriftContext {
@@ -350,16 +452,32 @@ fn propose_patrol(
 /// tiles of distance.
 const AFFECTION_RANK_WEIGHT: f32 = 6.0;
 
+/// Respect-plus-trust weight for candidate ranking when the agent is
+/// looking for someone to ask: the partner who seems most knowledgeable
+/// is worth walking this many extra tiles for.
+const KNOWLEDGE_RANK_WEIGHT: f32 = 12.0;
+
+/// Companionship deficit a knowledge gap adds: an agent stuck on "where
+/// do I find X?" goes looking for someone to ask well before loneliness
+/// alone would send it.
+const KNOWLEDGE_GAP_SOCIAL_PULL: f32 = 0.25;
+
 /// Propose `InitiateConversation` toward the best-scoring visible
 /// person. Filters busy / unreachable / cooled-down candidates, then
-/// picks the closest-and-fondest survivor. Strangers are eligible —
-/// the first turn of any conversation is the greeting, owned by
-/// `ConversePlugin`.
+/// picks the closest-and-fondest survivor — or, with a knowledge gap
+/// to fill, the closest-and-most-respected-and-trusted one. Strangers
+/// are eligible — the first turn of any conversation is the greeting,
+/// owned by `ConversePlugin`.
 fn seek_social_initiation(
     social_drive: f32,
     inputs: &EmotionalInputs,
     min_urgency: f32,
 ) -> Option<BrainProposal> {
+    let social_drive = if inputs.knowledge_gap.is_some() {
+        social_drive + KNOWLEDGE_GAP_SOCIAL_PULL
+    } else {
+        social_drive
+    };
     if social_drive <= SOCIAL_SEEK_THRESHOLD {
         return None;
     }
@@ -411,13 +529,14 @@ fn seek_social_initiation(
         // so an unintroduced peer doesn't ride a half-baseline boost
         // over genuinely-bonded peers; the caller compares scores
         // directly via `AFFECTION_RANK_WEIGHT`.
-        let affection = inputs
-            .social_graph
-            .get(inputs.self_entity, entity)
-            .map(|e| e.affection)
-            .unwrap_or(0.0);
+        let edge = inputs.social_graph.get(inputs.self_entity, entity);
         let distance = pos.distance(inputs.agent_pos) / TILE_SIZE;
-        let score = -distance + AFFECTION_RANK_WEIGHT * affection;
+        let score = if inputs.knowledge_gap.is_some() {
+            let standing = edge.map(|e| (e.respect + e.trust) * 0.5).unwrap_or(0.0);
+            -distance + KNOWLEDGE_RANK_WEIGHT * standing
+        } else {
+            -distance + AFFECTION_RANK_WEIGHT * edge.map(|e| e.affection).unwrap_or(0.0)
+        };
 
         if best.map(|(_, prev)| score > prev).unwrap_or(true) {
             best = Some((entity, score));
@@ -425,12 +544,78 @@ fn seek_social_initiation(
     }
 
     let (target, _) = best?;
+    let reasoning = match inputs.knowledge_gap {
+        Some(gap) => format!("I want to ask {target:?} where to find {gap:?}"),
+        None => format!("I want to chat with {target:?} (social: {social_drive:.2})"),
+    };
     Some(BrainProposal {
         brain: BrainType::Emotional,
         action: action.to_template(Some(target)),
         urgency,
         intent: Intent::SatisfySocial,
-        reasoning: format!("I want to chat with {target:?} (social: {social_drive:.2})"),
+        reasoning,
+    })
+}
+
+/// Propose `Give` toward the fondest hungry friend within reach when the
+/// agent carries food. Urgency scales with affection — agents feed the
+/// people they care about, not whoever happens to stand nearby. A friend
+/// the agent already believes carries food (e.g. from a gift just given)
+/// is left to eat it.
+fn propose_give(inputs: &EmotionalInputs, min_urgency: f32) -> Option<BrainProposal> {
+    let inventory = inputs.inventory?;
+    let is_food = |c: Concept| inputs.mind.is_a(&Node::Concept(c), Concept::Food);
+    if !inventory.all_items().any(|t| is_food(t.concept)) {
+        return None;
+    }
+    let action = inputs.action_registry.get(ActionType::Give)?;
+
+    let mut best: Option<(Entity, Vec2, f32)> = None;
+    for (i, &(entity, pos)) in inputs.visible_positions.iter().enumerate() {
+        if inputs.visible_types.get(i).and_then(|c| *c) != Some(Concept::Person)
+            || pos.distance(inputs.agent_pos) > GIVE_REACH
+        {
+            continue;
+        }
+        let affection = inputs
+            .social_graph
+            .get(inputs.self_entity, entity)
+            .map_or(0.0, |e| e.affection);
+        if affection < GIVE_MIN_AFFECTION
+            || !inputs
+                .mind
+                .has_trait(&Node::Entity(entity), Concept::Hungry)
+        {
+            continue;
+        }
+        let carries_food = inputs
+            .mind
+            .query(Some(&Node::Entity(entity)), Some(Predicate::Contains), None)
+            .into_iter()
+            .any(|t| matches!(t.object, Value::Item(c, q) if q > 0 && is_food(c)));
+        if carries_food {
+            continue;
+        }
+        if best.is_none_or(|(_, _, prev)| affection > prev) {
+            best = Some((entity, pos, affection));
+        }
+    }
+
+    let (target, pos, affection) = best?;
+    let urgency = affection * GIVE_URGENCY_MULTIPLIER;
+    if urgency <= min_urgency {
+        return None;
+    }
+    let mut template = action.to_template(Some(target));
+    template.target_position = Some(pos);
+    Some(BrainProposal {
+        brain: BrainType::Emotional,
+        action: template,
+        urgency,
+        intent: Intent::SatisfyCompassion,
+        reasoning: format!(
+            "{target:?} looks hungry — giving them food (affection: {affection:.2})"
+        ),
     })
 }
 
@@ -471,7 +656,7 @@ pub fn find_closest_dangerous(
 /// Per-emotion sums for one entity, including type-inherited contributions
 /// (e.g. an entity-of-type-Wolf inherits the Concept-level Wolf feelings).
 /// Public so the UI can read the agent's feelings toward any entity, not
-/// just the three types `evaluate_entity_emotions` consumes.
+/// just the types the `EmotionalResponseConfig` entity rules consume.
 /// Per-emotion sums toward `entity` including type-inherited contributions
 /// (e.g. an entity-of-type-Wolf inherits the Concept-level Wolf feelings).
 ///
@@ -620,104 +805,69 @@ pub fn add_entity_emotion(
 /// dominating arbitration after dozens of hits.
 pub const MAX_ENTITY_EMOTION_INTENSITY: f32 = 2.0;
 
-/// (fear, joy, anger) intensities from direct and inherited associations.
+/// Summed intensity per emotion type from direct and inherited associations.
 fn collect_entity_feelings(
     entity: Entity,
     entity_type: Option<Concept>,
     mind: &MindGraph,
-) -> (f32, f32, f32) {
-    let feelings = match entity_type {
+) -> Vec<(EmotionType, f32)> {
+    match entity_type {
         Some(et) => entity_feelings_with_type(entity, et, mind),
         None => entity_feelings(entity, mind),
-    };
-    let sum = |target: EmotionType| -> f32 {
-        feelings
-            .iter()
-            .filter(|(e, _)| *e == target)
-            .map(|(_, i)| i)
-            .sum()
-    };
-    (
-        sum(EmotionType::Fear),
-        sum(EmotionType::Joy),
-        sum(EmotionType::Anger),
-    )
+    }
 }
 
 /// Returns the best (proposal, intensity) for a single entity, if above min_urgency.
+/// Rules are tried in config order; a later rule must beat the intensity
+/// of the one before it to replace it.
 fn evaluate_entity_emotions(
     entity: Entity,
     entity_type: Option<Concept>,
     mind: &MindGraph,
     action_registry: &crate::agent::actions::ActionRegistry,
+    responses: &EmotionalResponseConfig,
     min_urgency: f32,
 ) -> Option<(BrainProposal, f32)> {
-    let (fear, joy, anger) = collect_entity_feelings(entity, entity_type, mind);
+    let feelings = collect_entity_feelings(entity, entity_type, mind);
     let mut best: Option<(BrainProposal, f32)> = None;
     let mut threshold = min_urgency;
 
-    if fear > threshold
-        && fear > FEAR_ENTITY_THRESHOLD
-        && let Some(action) = action_registry.get(ActionType::Flee)
-    {
-        let mut template = action.to_template(Some(entity));
-        template.escalate_intensity(fear);
-        best = Some((
-            BrainProposal {
-                brain: BrainType::Emotional,
-                action: template,
-                urgency: fear * FEAR_ENTITY_URGENCY_MULTIPLIER,
-                intent: Intent::SatisfySafety,
-                reasoning: format!("I'm scared of {:?} (fear: {:.2})", entity, fear),
-            },
-            fear,
-        ));
-        threshold = fear;
-    }
-
-    if joy > threshold
-        && joy > JOY_ENTITY_THRESHOLD
-        && let Some(action) = action_registry.get(ActionType::Walk)
-    {
-        let mut template = action.to_template(Some(entity));
-        template.escalate_intensity(joy);
-        best = Some((
-            BrainProposal {
-                brain: BrainType::Emotional,
-                action: template,
-                urgency: joy * JOY_ENTITY_URGENCY_MULTIPLIER,
-                intent: Intent::SatisfySocial,
-                reasoning: format!("I like {:?} (joy: {:.2})", entity, joy),
-            },
-            joy,
-        ));
-        threshold = joy;
-    }
-
-    if anger > threshold
-        && anger > ANGER_ENTITY_THRESHOLD
-        && let Some(action) = action_registry.get(ActionType::Attack)
-    {
-        let mut template = action.to_template(Some(entity));
-        template.escalate_intensity(anger);
-        best = Some((
-            BrainProposal {
-                brain: BrainType::Emotional,
-                action: template,
-                urgency: anger * ANGER_ENTITY_URGENCY_MULTIPLIER,
-                intent: Intent::SatisfySafety,
-                reasoning: format!("I hate {:?}! (anger: {:.2})", entity, anger),
-            },
-            anger,
-        ));
+    for rule in &responses.entity {
+        let intensity: f32 = feelings
+            .iter()
+            .filter(|(e, _)| *e == rule.emotion)
+            .map(|(_, i)| i)
+            .sum();
+        if intensity > threshold
+            && intensity > rule.threshold
+            && let Some(action) = action_registry.get(rule.action)
+        {
+            let target = rule.target_entity.then_some(entity);
+            let mut template = action.to_template(target);
+            template.escalate_intensity(intensity);
+            best = Some((
+                BrainProposal {
+                    brain: BrainType::Emotional,
+                    action: template,
+                    urgency: intensity * rule.urgency_multiplier,
+                    intent: rule.intent,
+                    reasoning: format!(
+                        "{:?} toward {:?} → {:?} ({:.2})",
+                        rule.emotion, entity, rule.action, intensity
+                    ),
+                },
+                intensity,
+            ));
+            threshold = intensity;
+        }
     }
 
     best
 }
 
 /// Convert a [`ThreatResponse`] into a concrete [`BrainProposal`] aimed
-/// at the closest threat. Maps Flee → `Flee`, StandGround → `Idle`,
-/// Fight → species-appropriate combat verb (Wolf → `Bite`, Person →
+/// at the closest threat. Maps Flee → `Flee`, StandGround and Submit →
+/// `Idle`, Fight → species-appropriate combat verb (Wolf → `Bite`, Person →
 /// `DefendSelf`). Replaces the three ad-hoc proposal branches with one
 /// dispatch driven entirely by the appraisal output.
 fn appraise_threat_proposal(
@@ -764,6 +914,19 @@ fn appraise_threat_proposal(
                 reasoning: "Threat appraisal → StandGround (cornered)".to_string(),
             })
         }
+        ThreatResponse::Submit => {
+            let action = action_registry.get(ActionType::Idle)?;
+            let proposal_urgency = STAND_GROUND_BASE_URGENCY.max(best_urgency + 0.1);
+            let mut template = action.to_template(None);
+            template.escalate_intensity(0.2);
+            Some(BrainProposal {
+                brain: BrainType::Emotional,
+                action: template,
+                urgency: proposal_urgency,
+                intent: Intent::SatisfySafety,
+                reasoning: "Threat appraisal → Submit (outranked)".to_string(),
+            })
+        }
         ThreatResponse::Fight { commitment } => {
             let attack_action = match self_concept {
                 Some(Concept::Wolf) => ActionType::Bite,
@@ -789,39 +952,49 @@ fn appraise_threat_proposal(
     }
 }
 
-/// Responds to general (non-entity) fear.
-fn check_general_fear(
+/// Responds to general (non-entity) emotions — the summed intensity of
+/// every active emotion of a rule's type. The strongest firing rule wins.
+fn check_general_emotions(
     emotions: &EmotionalState,
     best_urgency: f32,
     action_registry: &crate::agent::actions::ActionRegistry,
+    responses: &EmotionalResponseConfig,
 ) -> Option<BrainProposal> {
-    let fear_level: f32 = emotions
-        .active_emotions
-        .iter()
-        .filter(|e| e.emotion_type == EmotionType::Fear)
-        .map(|e| e.intensity)
-        .sum();
+    let mut best: Option<BrainProposal> = None;
+    let mut best_urgency = best_urgency;
 
-    if fear_level <= FEAR_GENERAL_THRESHOLD {
-        return None;
-    }
+    for rule in &responses.general {
+        let level: f32 = emotions
+            .active_emotions
+            .iter()
+            .filter(|e| e.emotion_type == rule.emotion)
+            .map(|e| e.intensity)
+            .sum();
+        if level <= rule.threshold {
+            continue;
+        }
 
-    let fear_urgency = fear_level * FEAR_GENERAL_URGENCY_MULTIPLIER;
-    if fear_urgency > best_urgency
-        && let Some(action) = action_registry.get(ActionType::Flee)
-    {
-        let mut template = action.to_template(None);
-        template.escalate_intensity(fear_level);
-        return Some(BrainProposal {
-            brain: BrainType::Emotional,
-            action: template,
-            urgency: fear_urgency,
-            intent: Intent::SatisfySafety,
-            reasoning: format!("I'm terrified! (fear: {:.2})", fear_level),
-        });
+        let urgency = level * rule.urgency_multiplier;
+        if urgency > best_urgency
+            && let Some(action) = action_registry.get(rule.action)
+        {
+            let mut template = action.to_template(None);
+            template.escalate_intensity(level);
+            best_urgency = urgency;
+            best = Some(BrainProposal {
+                brain: BrainType::Emotional,
+                action: template,
+                urgency,
+                intent: rule.intent,
+                reasoning: format!(
+                    "Overwhelmed by {:?} → {:?} ({:.2})",
+                    rule.emotion, rule.action, level
+                ),
+            });
+        }
     }
 
-    None
+    best
 }
 
 #[cfg(test)]
@@ -855,12 +1028,14 @@ mod tests {
             visible_types: &[],
             physical: &PhysicalNeeds::default(),
             drives: None,
+            inventory: None,
             engaged: None,
             self_concept: None,
             agent_pos: Vec2::ZERO,
             fields: &FieldGrids::default(),
             cns: &Default::default(),
             action_registry: &registry,
+            responses: &EmotionalResponseConfig::default(),
             personality: None,
             body: None,
             cornered: false,
@@ -868,6 +1043,8 @@ mod tests {
             visible_engaged_converse: &[],
             social_cooldowns: None,
             current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
         });
 
         assert!(proposal.is_some());
@@ -907,12 +1084,14 @@ mod tests {
             visible_types: &[None],
             physical: &PhysicalNeeds::default(),
             drives: None,
+            inventory: None,
             engaged: None,
             self_concept: None,
             agent_pos: Vec2::ZERO,
             fields: &FieldGrids::default(),
             cns: &Default::default(),
             action_registry: &registry,
+            responses: &EmotionalResponseConfig::default(),
             personality: None,
             body: None,
             cornered: false,
@@ -920,6 +1099,8 @@ mod tests {
             visible_engaged_converse: &[],
             social_cooldowns: None,
             current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
         });
 
         assert!(proposal.is_some());
@@ -957,12 +1138,14 @@ mod tests {
             visible_types: &[None],
             physical: &PhysicalNeeds::default(),
             drives: None,
+            inventory: None,
             engaged: None,
             self_concept: None,
             agent_pos: Vec2::ZERO,
             fields: &FieldGrids::default(),
             cns: &Default::default(),
             action_registry: &registry,
+            responses: &EmotionalResponseConfig::default(),
             personality: None,
             body: None,
             cornered: false,
@@ -970,6 +1153,8 @@ mod tests {
             visible_engaged_converse: &[],
             social_cooldowns: None,
             current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
         });
 
         assert!(proposal.is_some());
@@ -994,12 +1179,14 @@ mod tests {
             visible_types: &[],
             physical: &PhysicalNeeds::default(),
             drives: None,
+            inventory: None,
             engaged: None,
             self_concept: None,
             agent_pos: Vec2::ZERO,
             fields: &FieldGrids::default(),
             cns: &Default::default(),
             action_registry: &registry,
+            responses: &EmotionalResponseConfig::default(),
             personality: None,
             body: None,
             cornered: false,
@@ -1007,6 +1194,8 @@ mod tests {
             visible_engaged_converse: &[],
             social_cooldowns: None,
             current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
         });
 
         assert!(proposal.is_none());
@@ -1035,12 +1224,14 @@ mod tests {
             visible_types: &[],
             physical: &PhysicalNeeds::default(),
             drives: None,
+            inventory: None,
             engaged: None,
             self_concept: None,
             agent_pos: Vec2::ZERO,
             fields: &FieldGrids::default(),
             cns: &Default::default(),
             action_registry: &registry,
+            responses: &EmotionalResponseConfig::default(),
             personality: None,
             body: None,
             cornered: false,
@@ -1048,6 +1239,8 @@ mod tests {
             visible_engaged_converse: &[],
             social_cooldowns: None,
             current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
         })
         .expect("should propose Flee");
 
@@ -1064,6 +1257,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn swapped_fear_response_proposes_the_configured_action() {
+        let state = EmotionalState::default();
+        let entity = Entity::from_bits(42);
+        let mut mind = setup_mind();
+        mind.assert(Triple::with_meta(
+            Node::Entity(entity),
+            Predicate::TriggersEmotion,
+            Value::Emotion(EmotionType::Fear, 0.8),
+            Metadata::default(),
+        ));
+        let mut visible = VisibleObjects::default();
+        visible.entities.push(entity);
+        let visible_positions = [(entity, Vec2::ZERO)];
+
+        let mut registry = crate::agent::actions::ActionRegistry::default();
+        registry.register_def(&crate::agent::actions::action::FLEE_DEF);
+        registry.register_def(&crate::agent::actions::action::IDLE_DEF);
+
+        // A culture that freezes rather than runs.
+        let mut responses = EmotionalResponseConfig::default();
+        let fear = responses
+            .entity
+            .iter_mut()
+            .find(|r| r.emotion == EmotionType::Fear)
+            .unwrap();
+        fear.action = ActionType::Idle;
+        fear.target_entity = false;
+
+        let proposal = emotional_brain_propose(&EmotionalInputs {
+            emotions: &state,
+            mind: &mind,
+            social_graph: &crate::agent::psyche::social_graph::SocialGraph::default(),
+            self_entity: Entity::from_bits(1),
+            visible: &visible,
+            visible_positions: &visible_positions,
+            visible_types: &[None],
+            physical: &PhysicalNeeds::default(),
+            drives: None,
+            inventory: None,
+            engaged: None,
+            self_concept: None,
+            agent_pos: Vec2::ZERO,
+            fields: &FieldGrids::default(),
+            cns: &Default::default(),
+            action_registry: &registry,
+            responses: &responses,
+            personality: None,
+            body: None,
+            cornered: false,
+            closest_threat: None,
+            visible_engaged_converse: &[],
+            social_cooldowns: None,
+            current_tick: 0,
+            lod: LodLevel::Full,
+            knowledge_gap: None,
+        })
+        .expect("fear above threshold should still propose something");
+
+        assert_eq!(proposal.action.action_type, ActionType::Idle);
+        assert_eq!(proposal.intent, Intent::SatisfySafety);
+        assert!(proposal.urgency > 0.0);
+    }
+
     // ─── seek_social_initiation ─────────────────────────────────────────────
 
     use super::super::social_initiation::{
@@ -1086,6 +1343,7 @@ mod tests {
         cns: crate::agent::nervous_system::cns::CentralNervousSystem,
         fields: FieldGrids,
         registry: crate::agent::actions::ActionRegistry,
+        responses: EmotionalResponseConfig,
         social_graph: crate::agent::psyche::social_graph::SocialGraph,
         self_entity: Entity,
     }
@@ -1110,6 +1368,7 @@ mod tests {
                 cns: Default::default(),
                 fields: FieldGrids::default(),
                 registry: social_registry(),
+                responses: EmotionalResponseConfig::default(),
                 social_graph,
                 self_entity: Entity::from_bits(1),
             }
@@ -1133,12 +1392,14 @@ mod tests {
                 visible_types,
                 physical: &self.physical,
                 drives: None,
+                inventory: None,
                 engaged: None,
                 self_concept: Some(Concept::Person),
                 agent_pos: Vec2::ZERO,
                 fields: &self.fields,
                 cns: &self.cns,
                 action_registry: &self.registry,
+                responses: &self.responses,
                 personality: None,
                 body: None,
                 cornered: false,
@@ -1146,6 +1407,8 @@ mod tests {
                 visible_engaged_converse,
                 social_cooldowns,
                 current_tick,
+                lod: LodLevel::Full,
+                knowledge_gap: None,
             }
         }
     }
@@ -1305,4 +1568,112 @@ mod tests {
             "affection should outweigh a few extra tiles of distance"
         );
     }
+
+    #[test]
+    fn knowledge_gap_sends_agent_to_the_most_respected_candidate() {
+        use crate::agent::psyche::social_graph::{RelationshipEdge, SocialGraph};
+
+        let close_friend = Entity::from_bits(20);
+        let far_expert = Entity::from_bits(21);
+        let me = Entity::from_bits(1); // matches SocialFixture::self_entity
+        let mut graph = SocialGraph::default();
+        graph.set(
+            me,
+            close_friend,
+            RelationshipEdge::with_baseline_affection(1.0, 0),
+        );
+        graph.set(
+            me,
+            far_expert,
+            RelationshipEdge {
+                affection: 0.1,
+                trust: 0.9,
+                respect: 0.95,
+                ..Default::default()
+            },
+        );
+        let fixture = SocialFixture::with_graph(MindGraph::default(), graph);
+
+        let visible_positions = [
+            (close_friend, Vec2::new(2.0 * TILE_SIZE, 0.0)),
+            (far_expert, Vec2::new(5.0 * TILE_SIZE, 0.0)),
+        ];
+        let visible_types = [Some(Concept::Person), Some(Concept::Person)];
+        let visible_engaged_converse = [false, false];
+        let mut inputs = fixture.inputs(
+            &visible_positions,
+            &visible_types,
+            &visible_engaged_converse,
+            None,
+            0,
+        );
+
+        // Barely lonely: not enough to go chat for its own sake.
+        let content = SOCIAL_SEEK_THRESHOLD - 0.1;
+        assert!(seek_social_initiation(content, &inputs, 0.0).is_none());
+
+        inputs.knowledge_gap = Some(Concept::Apple);
+        let proposal = seek_social_initiation(content, &inputs, 0.0)
+            .expect("a knowledge gap should send the agent to ask someone");
+        assert_eq!(
+            proposal.action.target_entity,
+            Some(far_expert),
+            "asks the respected, trusted peer over the fond one"
+        );
+    }
+
+    // ─── propose_give ───────────────────────────────────────────────────────
+
+    #[test]
+    fn give_goes_to_a_hungry_friend_in_reach_until_they_carry_food() {
+        use crate::agent::mind::knowledge::setup_ontology;
+        use crate::agent::psyche::social_graph::{RelationshipEdge, SocialGraph};
+
+        let friend = Entity::from_bits(20);
+        let me = Entity::from_bits(1); // matches SocialFixture::self_entity
+        let mut graph = SocialGraph::default();
+        graph.set(
+            me,
+            friend,
+            RelationshipEdge::with_baseline_affection(0.8, 0),
+        );
+        let mut fixture = SocialFixture::with_graph(MindGraph::new(setup_ontology()), graph);
+        fixture
+            .registry
+            .register_def(&crate::agent::actions::action::GIVE_DEF);
+        let mut apples = ItemSlots::agent_carry();
+        apples.add(Concept::Apple, 3);
+
+        let visible_positions = [(friend, Vec2::new(TILE_SIZE, 0.0))];
+        let visible_types = [Some(Concept::Person)];
+        let mut inputs = fixture.inputs(&visible_positions, &visible_types, &[false], None, 0);
+        inputs.inventory = Some(&apples);
+        assert!(
+            propose_give(&inputs, 0.0).is_none(),
+            "a friend who doesn't look hungry gets nothing"
+        );
+
+        fixture.mind.assert(Triple::new(
+            Node::Entity(friend),
+            Predicate::HasTrait,
+            Value::Concept(Concept::Hungry),
+        ));
+        let mut inputs = fixture.inputs(&visible_positions, &visible_types, &[false], None, 0);
+        inputs.inventory = Some(&apples);
+        let proposal = propose_give(&inputs, 0.0).expect("a hungry friend should be fed");
+        assert_eq!(proposal.action.action_type, ActionType::Give);
+        assert_eq!(proposal.action.target_entity, Some(friend));
+
+        fixture.mind.assert(Triple::new(
+            Node::Entity(friend),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 2),
+        ));
+        let mut inputs = fixture.inputs(&visible_positions, &visible_types, &[false], None, 0);
+        inputs.inventory = Some(&apples);
+        assert!(
+            propose_give(&inputs, 0.0).is_none(),
+            "a friend already holding food is left to eat it"
+        );
+    }
 }
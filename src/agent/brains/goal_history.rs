@@ -0,0 +1,252 @@
+//! Goal history: per-agent timeline of which goal the agent was executing and how it ended.
+//!
+//! Reads: PlanMemory (executing plans), SimEvent (PlanAbandoned), TickCount
+//! Writes: GoalHistory (ring buffer of GoalRecord)
+//! Upstream: brains::rational (plan lifecycle), brains::brain_system (promotion to Executing)
+//! Downstream: ui (AgentViewer goal timeline)
+//!
+//! The "current goal" is the goal of the highest-priority Executing plan.
+//! Whenever it changes, the previous record is closed with an outcome and a
+//! new one opened, so thrashing between goals shows up as a run of short
+//! records instead of being invisible behind the latest snapshot.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::plan_memory::{HeldPlan, PlanAbandonReason, PlanId, PlanMemory, PlanState};
+use super::thinking::TriplePattern;
+use crate::agent::events::{SimEvent, SimEventKind};
+use crate::agent::nervous_system::urgency::UrgencySource;
+use crate::core::tick::TickCount;
+
+/// Records kept per agent. Oldest are dropped first.
+pub const GOAL_HISTORY_CAPACITY: usize = 32;
+
+/// How a goal stopped being the current one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalOutcome {
+    /// Still the agent's current goal.
+    Active,
+    /// Its plan ran to the last step and was dropped from memory.
+    Completed,
+    /// Its plan was dropped before finishing.
+    Abandoned(PlanAbandonReason),
+    /// Its plan is still held but no longer executing — displaced by a
+    /// higher-priority goal or paused on a channel conflict.
+    Suspended,
+}
+
+/// One span of the timeline.
+#[derive(Debug, Clone)]
+pub struct GoalRecord {
+    pub started_at: u64,
+    /// `None` while the record is still `Active`.
+    pub ended_at: Option<u64>,
+    pub plan_id: PlanId,
+    pub conditions: Vec<TriplePattern>,
+    /// Goal priority when it became current.
+    pub priority: f32,
+    pub driving_urgency: UrgencySource,
+    pub outcome: GoalOutcome,
+}
+
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct GoalHistory {
+    #[reflect(ignore)]
+    records: VecDeque<GoalRecord>,
+}
+
+impl GoalHistory {
+    /// Oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &GoalRecord> {
+        self.records.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The record for the goal the agent is pursuing right now, if any.
+    pub fn current(&self) -> Option<&GoalRecord> {
+        self.records
+            .back()
+            .filter(|r| r.outcome == GoalOutcome::Active)
+    }
+
+    /// Reconcile the timeline with the plan the agent is executing at
+    /// `now`. `still_held` reports whether a plan id is still in
+    /// `PlanMemory`; `abandoned` yields the reason for plans dropped this
+    /// tick via `PlanAbandoned`.
+    pub fn observe(
+        &mut self,
+        now: u64,
+        executing: Option<&HeldPlan>,
+        still_held: impl Fn(PlanId) -> bool,
+        abandoned: impl Fn(PlanId) -> Option<PlanAbandonReason>,
+    ) {
+        if let Some(plan) = executing
+            && let Some(current) = self.records.back_mut()
+            && current.outcome == GoalOutcome::Active
+            && current.conditions == plan.goal.conditions
+        {
+            // Same goal, possibly re-planned under a new id.
+            current.plan_id = plan.id;
+            return;
+        }
+
+        if let Some(current) = self.records.back_mut()
+            && current.outcome == GoalOutcome::Active
+        {
+            current.ended_at = Some(now);
+            current.outcome = match abandoned(current.plan_id) {
+                Some(reason) => GoalOutcome::Abandoned(reason),
+                None if still_held(current.plan_id) => GoalOutcome::Suspended,
+                None => GoalOutcome::Completed,
+            };
+        }
+
+        let Some(plan) = executing else {
+            return;
+        };
+        if self.records.len() >= GOAL_HISTORY_CAPACITY {
+            self.records.pop_front();
+        }
+        self.records.push_back(GoalRecord {
+            started_at: now,
+            ended_at: None,
+            plan_id: plan.id,
+            conditions: plan.goal.conditions.clone(),
+            priority: plan.goal.priority,
+            driving_urgency: plan.driving_urgency,
+            outcome: GoalOutcome::Active,
+        });
+    }
+}
+
+/// Highest-priority Executing plan — the agent's current goal.
+fn current_plan(memory: &PlanMemory) -> Option<&HeldPlan> {
+    memory
+        .in_state(PlanState::Executing)
+        .max_by(|a, b| a.goal.priority.total_cmp(&b.goal.priority))
+}
+
+/// Bevy system (FixedUpdate, after arbitration): appends a record to each
+/// agent's `GoalHistory` whenever their current goal changes.
+pub fn track_goal_history(
+    tick: Res<TickCount>,
+    mut sim_events: MessageReader<SimEvent>,
+    mut agents: Query<(Entity, &PlanMemory, &mut GoalHistory)>,
+) {
+    let abandoned: Vec<(Entity, PlanId, PlanAbandonReason)> = sim_events
+        .read()
+        .filter_map(|event| match &event.kind {
+            SimEventKind::PlanAbandoned {
+                agent,
+                plan_id,
+                reason,
+                ..
+            } => Some((*agent, PlanId(*plan_id), *reason)),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, memory, mut history) in agents.iter_mut() {
+        history.observe(
+            tick.current,
+            current_plan(memory),
+            |id| memory.get(id).is_some(),
+            |id| {
+                abandoned
+                    .iter()
+                    .find(|(agent, plan, _)| *agent == entity && *plan == id)
+                    .map(|(_, _, reason)| *reason)
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::brains::plan_memory::PlanSource;
+    use crate::agent::brains::proposal::BrainType;
+    use crate::agent::brains::thinking::Goal;
+    use crate::agent::mind::knowledge::{Predicate, Quantity, Value};
+
+    fn plan(id: u64, predicate: Predicate, priority: f32) -> HeldPlan {
+        HeldPlan {
+            id: PlanId(id),
+            goal: Goal {
+                conditions: vec![TriplePattern::self_has(
+                    predicate,
+                    Value::Quantity(Quantity::Exact(0.0)),
+                )],
+                priority,
+            },
+            steps: Vec::new(),
+            state: PlanState::Executing,
+            commitment: 1.0,
+            subjective_cost: 0.0,
+            source: PlanSource::Brain(BrainType::Rational),
+            driving_urgency: UrgencySource::Hunger,
+            created_at_urgency: priority,
+            created_at: 0,
+            last_touched: 0,
+            current_step: 0,
+        }
+    }
+
+    #[test]
+    fn goal_changes_are_recorded_with_timestamps() {
+        let mut history = GoalHistory::default();
+        let eat = plan(1, Predicate::Hunger, 0.6);
+        let drink = plan(2, Predicate::Thirst, 0.9);
+
+        history.observe(10, Some(&eat), |_| true, |_| None);
+        // Same goal on later ticks doesn't add records.
+        history.observe(11, Some(&eat), |_| true, |_| None);
+        // Thirst overtakes hunger; the eat plan is still held.
+        history.observe(40, Some(&drink), |_| true, |_| None);
+        // Drink plan finishes and is dropped; nothing executing.
+        history.observe(90, None, |_| false, |_| None);
+
+        let records: Vec<_> = history.iter().collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].started_at, records[0].ended_at), (10, Some(40)));
+        assert_eq!(records[0].outcome, GoalOutcome::Suspended);
+        assert_eq!(records[0].conditions, eat.goal.conditions);
+        assert_eq!((records[1].started_at, records[1].ended_at), (40, Some(90)));
+        assert_eq!(records[1].outcome, GoalOutcome::Completed);
+        assert!((records[1].priority - 0.9).abs() < f32::EPSILON);
+        assert!(history.current().is_none());
+    }
+
+    #[test]
+    fn abandoned_plan_closes_record_with_reason() {
+        let mut history = GoalHistory::default();
+        history.observe(
+            5,
+            Some(&plan(1, Predicate::Hunger, 0.5)),
+            |_| true,
+            |_| None,
+        );
+        history.observe(
+            20,
+            None,
+            |_| false,
+            |_| Some(PlanAbandonReason::PreconditionsUnmet),
+        );
+
+        assert_eq!(
+            history.iter().next().map(|r| r.outcome),
+            Some(GoalOutcome::Abandoned(
+                PlanAbandonReason::PreconditionsUnmet
+            ))
+        );
+    }
+}
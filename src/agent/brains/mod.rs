@@ -5,6 +5,7 @@ pub mod arbitration;
 pub mod brain_system;
 pub mod drift;
 pub mod emotional;
+pub mod goal_history;
 pub mod history;
 pub mod plan_memory;
 pub mod planner;
@@ -39,6 +40,62 @@ impl BrainTickInterval {
     }
 }
 
+/// Per-brain reaction latency in ticks. A brain that wins arbitration
+/// away from a different brain only takes control once its latency has
+/// elapsed; until then the previous decision keeps running. Staying with
+/// the same brain is never delayed.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct BrainReactionLatency {
+    pub survival: u64,
+    pub emotional: u64,
+    pub rational: u64,
+}
+
+impl Default for BrainReactionLatency {
+    fn default() -> Self {
+        use crate::constants::brains::latency::*;
+        Self {
+            survival: SURVIVAL_TICKS,
+            emotional: EMOTIONAL_TICKS,
+            rational: RATIONAL_TICKS,
+        }
+    }
+}
+
+impl BrainReactionLatency {
+    pub fn ticks(&self, brain: proposal::BrainType) -> u64 {
+        match brain {
+            proposal::BrainType::Survival => self.survival,
+            proposal::BrainType::Emotional => self.emotional,
+            proposal::BrainType::Rational => self.rational,
+        }
+    }
+}
+
+/// Stickiness for arbitration: how much the incumbent decision's urgency
+/// is boosted, and for how many ticks after it takes over. A rival must
+/// beat the boosted score to displace it, which stops near-tied proposals
+/// (Walk vs Talk, Flee vs Attack) from flip-flopping every brain tick.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct ArbitrationHysteresis {
+    /// Urgency multiplier on the incumbent's proposal. 1.0 disables it.
+    pub incumbent_boost: f32,
+    /// Ticks after a switch during which the boost applies.
+    pub hold_ticks: u64,
+}
+
+impl Default for ArbitrationHysteresis {
+    fn default() -> Self {
+        use crate::constants::brains::hysteresis::*;
+        Self {
+            incumbent_boost: INCUMBENT_BOOST,
+            hold_ticks: HOLD_TICKS,
+        }
+    }
+}
+
 fn brain_tick_due(
     tick: Res<crate::core::tick::TickCount>,
     interval: Res<BrainTickInterval>,
@@ -58,8 +115,15 @@ impl Plugin for BrainPlugin {
             .register_type::<proposal::BrainType>()
             .register_type::<proposal::BrainPowers>()
             .register_type::<history::BrainHistory>()
+            .register_type::<goal_history::GoalHistory>()
             .register_type::<social_initiation::SocialInitiationCooldowns>()
             .init_resource::<BrainTickInterval>()
+            .register_type::<BrainReactionLatency>()
+            .init_resource::<BrainReactionLatency>()
+            .register_type::<ArbitrationHysteresis>()
+            .init_resource::<ArbitrationHysteresis>()
+            .register_type::<emotional::EmotionalResponseConfig>()
+            .init_resource::<emotional::EmotionalResponseConfig>()
             .init_resource::<wakeup::PendingBrainWakeups>()
             .init_resource::<trace::TraceConfig>()
             .init_resource::<trace::DecisionTraceBuffer>()
@@ -108,6 +172,15 @@ impl Plugin for BrainPlugin {
                     .run_if(not_paused)
                     .run_if(brain_tick_due),
             )
+            .add_systems(
+                FixedUpdate,
+                goal_history::track_goal_history
+                    .in_set(crate::core::PerfBucket::Brain)
+                    .in_set(crate::core::PerfSubBucket::BrainHistory)
+                    .after(rational::update_rational_planning)
+                    .after(brain_system::arbitrate_every_tick)
+                    .run_if(not_paused),
+            )
             .add_systems(
                 FixedUpdate,
                 brain_system::tick_cognitive_drain
@@ -13,8 +13,10 @@
 //! Suspended; uninteresting background plans get evicted when the
 //! agent's working-memory cap is exceeded.
 
+use crate::agent::actions::ActionType;
 use crate::agent::brains::proposal::BrainType;
 use crate::agent::brains::thinking::{ActionTemplate, Goal};
+use crate::agent::mind::knowledge::{MindGraph, Node as MindNode, Predicate};
 use crate::agent::nervous_system::urgency::UrgencySource;
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -150,6 +152,9 @@ pub enum PlanAbandonReason {
     /// The plan's current-step action failed at runtime or the cursor
     /// advanced into an invalid state.
     StepAdvancedInvalid,
+    /// A remaining step targets an entity that no longer exists in the
+    /// world (despawned mid-plan). The brain replans the same tick.
+    TargetGone,
 }
 
 /// Classification outcome for the stale-plan sweep.
@@ -228,6 +233,7 @@ pub fn max_plans_for(openness: f32, conscientiousness: f32, neuroticism: f32) ->
 /// component instead of owning a single optional plan.
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component)]
+#[require(super::goal_history::GoalHistory)]
 pub struct PlanMemory {
     #[reflect(ignore)]
     pub plans: Vec<HeldPlan>,
@@ -245,6 +251,42 @@ pub struct PlanMemory {
     /// the whole run. Monotonic. Used by tests to assert the planner
     /// stays silent while a live plan covers the current goal.
     pub plans_generated_total: u64,
+    /// Target of the last plan that ran to completion, per driving
+    /// urgency. The next search for the same urgency tries it before
+    /// rescanning every known entity — see [`Self::cached_target`].
+    #[reflect(ignore)]
+    pub target_cache: HashMap<UrgencySource, CachedTarget>,
+    /// Steps of the last plan found per driving urgency, with the goal
+    /// and mind-snapshot hashes it was searched under. A later search
+    /// whose hashes both match reuses the steps instead of rerunning
+    /// `regressive_plan` — see [`Self::cached_plan`].
+    #[reflect(ignore)]
+    pub plan_cache: HashMap<UrgencySource, CachedPlan>,
+}
+
+/// A planner result kept for reuse while nothing it depended on changed.
+#[derive(Debug, Clone)]
+pub struct CachedPlan {
+    pub goal_hash: u64,
+    pub mind_hash: u64,
+    pub steps: Vec<ActionTemplate>,
+}
+
+/// An entity a finished plan acted on, kept so the planner can reuse it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachedTarget {
+    pub action_type: ActionType,
+    pub entity: Entity,
+}
+
+impl CachedTarget {
+    /// Still worth trying first: the agent still holds beliefs about the
+    /// entity and doesn't know it to be empty.
+    pub fn is_believed_usable(&self, mind: &MindGraph) -> bool {
+        let node = MindNode::Entity(self.entity);
+        !mind.is_known_empty(self.entity)
+            && (mind.has_any_items(&node) || mind.get(&node, Predicate::IsA).is_some())
+    }
 }
 
 impl PlanMemory {
@@ -324,6 +366,20 @@ impl PlanMemory {
             .any(|p| p.driving_urgency == source && !p.steps.is_empty())
     }
 
+    /// True when a plan with concrete steps already satisfies every
+    /// condition of `goal` — e.g. a combined plan that took this goal
+    /// along as a companion.
+    pub fn covers_goal(&self, goal: &Goal) -> bool {
+        !goal.conditions.is_empty()
+            && self.plans.iter().any(|p| {
+                !p.steps.is_empty()
+                    && goal
+                        .conditions
+                        .iter()
+                        .all(|c| p.goal.conditions.contains(c))
+            })
+    }
+
     /// Mutable lookup by goal.
     pub fn by_goal_mut(&mut self, goal: &Goal) -> Option<&mut HeldPlan> {
         self.plans.iter_mut().find(|p| &p.goal == goal)
@@ -339,6 +395,75 @@ impl PlanMemory {
     }
 
     /// Remove a plan by id. Returns true if a plan was removed.
+    /// Remember the entity targeted by the last targeted step of a plan
+    /// that just finished, keyed by the plan's driving urgency.
+    pub fn remember_target(&mut self, plan: &HeldPlan) {
+        let Some((action_type, entity)) = plan
+            .steps
+            .iter()
+            .rev()
+            .find_map(|step| step.target_entity.map(|e| (step.action_type, e)))
+        else {
+            return;
+        };
+        self.target_cache.insert(
+            plan.driving_urgency,
+            CachedTarget {
+                action_type,
+                entity,
+            },
+        );
+    }
+
+    /// The cached target for `source`, if the agent's beliefs still
+    /// support it. A stale entry is dropped so the next search rescans.
+    pub fn cached_target(
+        &mut self,
+        source: UrgencySource,
+        mind: &MindGraph,
+    ) -> Option<CachedTarget> {
+        let cached = *self.target_cache.get(&source)?;
+        if cached.is_believed_usable(mind) {
+            Some(cached)
+        } else {
+            self.target_cache.remove(&source);
+            None
+        }
+    }
+
+    /// Steps cached for `source`, if they were planned for the same goal
+    /// against the same mind snapshot.
+    pub fn cached_plan(
+        &self,
+        source: UrgencySource,
+        goal_hash: u64,
+        mind_hash: u64,
+    ) -> Option<Vec<ActionTemplate>> {
+        self.plan_cache
+            .get(&source)
+            .filter(|c| c.goal_hash == goal_hash && c.mind_hash == mind_hash)
+            .map(|c| c.steps.clone())
+    }
+
+    /// Cache a freshly planned `steps` for `source`, replacing any older
+    /// entry.
+    pub fn cache_plan(
+        &mut self,
+        source: UrgencySource,
+        goal_hash: u64,
+        mind_hash: u64,
+        steps: Vec<ActionTemplate>,
+    ) {
+        self.plan_cache.insert(
+            source,
+            CachedPlan {
+                goal_hash,
+                mind_hash,
+                steps,
+            },
+        );
+    }
+
     pub fn remove(&mut self, id: PlanId) -> bool {
         let before = self.plans.len();
         self.plans.retain(|p| p.id != id);
@@ -410,9 +535,8 @@ impl PlanMemory {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::agent::actions::ActionType;
     use crate::agent::brains::thinking::TriplePattern;
-    use crate::agent::mind::knowledge::{Concept, Node as MindNode, Predicate, Value};
+    use crate::agent::mind::knowledge::{Concept, Value};
 
     fn test_template(action_type: ActionType) -> ActionTemplate {
         let registry = crate::agent::actions::ActionRegistry::new();
@@ -450,6 +574,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cached_target_is_reused_until_believed_empty() {
+        use crate::agent::mind::knowledge::Triple;
+
+        let bush = Entity::from_bits(42);
+        let mut mind = MindGraph::default();
+        mind.assert(Triple::new(
+            MindNode::Entity(bush),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 5),
+        ));
+
+        let mut mem = PlanMemory::default();
+        let mut plan = held_plan(
+            1,
+            PlanState::Executing,
+            PlanSource::Brain(BrainType::Rational),
+            1.0,
+        );
+        let mut harvest = test_template(ActionType::Harvest);
+        harvest.target_entity = Some(bush);
+        plan.steps = vec![harvest, test_template(ActionType::Eat)];
+        mem.remember_target(&plan);
+
+        let expected = CachedTarget {
+            action_type: ActionType::Harvest,
+            entity: bush,
+        };
+        for _ in 0..3 {
+            assert_eq!(
+                mem.cached_target(UrgencySource::Hunger, &mind),
+                Some(expected)
+            );
+        }
+        assert_eq!(mem.cached_target(UrgencySource::Thirst, &mind), None);
+
+        mind.assert(Triple::new(
+            MindNode::Entity(bush),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 0),
+        ));
+        assert_eq!(mem.cached_target(UrgencySource::Hunger, &mind), None);
+        assert!(mem.target_cache.is_empty());
+    }
+
+    #[test]
+    fn cached_plan_needs_both_hashes_to_match() {
+        let mut mem = PlanMemory::default();
+        let steps = vec![test_template(ActionType::Harvest)];
+        mem.cache_plan(UrgencySource::Hunger, 1, 2, steps);
+
+        let hit = mem.cached_plan(UrgencySource::Hunger, 1, 2).unwrap();
+        assert_eq!(hit[0].action_type, ActionType::Harvest);
+        assert!(mem.cached_plan(UrgencySource::Hunger, 1, 3).is_none());
+        assert!(mem.cached_plan(UrgencySource::Hunger, 4, 2).is_none());
+        assert!(mem.cached_plan(UrgencySource::Thirst, 1, 2).is_none());
+    }
+
     #[test]
     fn mint_plan_id_is_monotonic_and_unique() {
         let mut mem = PlanMemory::default();
@@ -13,15 +13,17 @@ use crate::agent::body::effort::{self, compute_action_cost};
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds};
 use crate::agent::body::species::SpeciesProfile;
 use crate::agent::mind::knowledge::{
-    Concept, FuzzyOrdering, MindGraph, Node as MindNode, Ontology, Predicate, Quantity, Triple,
-    Value,
+    Concept, MindGraph, Node as MindNode, Ontology, Predicate, Quantity, Triple, Value,
+    compare_nodes, compare_values,
 };
 use crate::agent::movement::intensity_speed_multiplier;
 use crate::agent::psyche::personality::Personality;
 use crate::constants::actions::walk as walk_const;
 use crate::constants::brains::survival::EXHAUSTION_TRIGGER;
-use crate::world::map::TILE_SIZE;
+use crate::world::map::{TILE_SIZE, WorldMap};
+use crate::world::pathfinding;
 use bevy::prelude::*;
+use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
@@ -82,6 +84,14 @@ pub struct PlanCostContext {
     /// reactive species (`max_plan_depth = 1`) will only ever produce
     /// single-action plans. `usize::MAX` disables the cap.
     pub max_plan_depth: usize,
+    /// A* cost charged per unmet goal condition. Higher values make the
+    /// search greedier — fewer iterations, possibly a costlier plan; lower
+    /// values expand more nodes but stay closer to optimal. Set from
+    /// `PlannerConfig::heuristic_weight`.
+    pub heuristic_weight: f32,
+    /// Hunger urgency in [0, 1]. Scales how much the search cares about a
+    /// food's nutrition value versus the walk to reach it.
+    pub hunger: f32,
 }
 
 /// How long a `(Tile, HasTrait, Unreachable)` belief suppresses walk
@@ -128,6 +138,8 @@ impl PlanCostContext {
             stamina_anaerobic: 100.0,
             wakefulness: 1.0,
             max_plan_depth: usize::MAX,
+            heuristic_weight: crate::constants::brains::planner::HEURISTIC_MULTIPLIER,
+            hunger: 0.0,
         }
     }
 
@@ -155,6 +167,8 @@ impl PlanCostContext {
             stamina_anaerobic: physical.stamina.anaerobic,
             wakefulness: physical.wakefulness.value,
             max_plan_depth: species.map(|s| s.max_plan_depth).unwrap_or(usize::MAX),
+            heuristic_weight: crate::constants::brains::planner::HEURISTIC_MULTIPLIER,
+            hunger: physical.hunger_urgency().clamp(0.0, 1.0),
         }
     }
 
@@ -175,6 +189,10 @@ struct PlanCostCache<'a> {
     /// (older than `UNREACHABLE_BELIEF_TTL_TICKS`) are filtered out here
     /// so the planner automatically retries once the belief ages out.
     unreachable_tiles: Vec<(i32, i32)>,
+    /// Terrain for walk costs. `None` falls back to straight-line distance.
+    map: Option<&'a WorldMap>,
+    /// Route lengths already searched this plan, keyed by (from, to).
+    path_costs: RefCell<HashMap<((i32, i32), (i32, i32)), Option<f32>>>,
 }
 
 impl<'a> PlanCostCache<'a> {
@@ -200,14 +218,62 @@ impl<'a> PlanCostCache<'a> {
             ctx,
             dangers,
             unreachable_tiles,
+            map: None,
+            path_costs: RefCell::new(HashMap::new()),
         }
     }
 
+    fn with_map(mut self, map: Option<&'a WorldMap>) -> Self {
+        self.map = map;
+        self
+    }
+
     fn is_unreachable(&self, tile: (i32, i32)) -> bool {
         self.unreachable_tiles.contains(&tile)
     }
+
+    /// Walking distance in tiles, or `None` when the map offers no route.
+    /// A target on unwalkable ground (the water tile a Drink faces) is
+    /// reached through its cheapest walkable neighbour. Without a map, or
+    /// when the agent's own tile reads as blocked, this is the straight
+    /// line the planner used before it knew about terrain.
+    fn walk_distance(&self, from: (i32, i32), to: (i32, i32)) -> Option<f32> {
+        let straight = (((from.0 - to.0).pow(2) + (from.1 - to.1).pow(2)) as f32).sqrt();
+        let Some(map) = self.map else {
+            return Some(straight);
+        };
+        if !pathfinding::walkable(map, from) {
+            return Some(straight);
+        }
+        *self
+            .path_costs
+            .borrow_mut()
+            .entry((from, to))
+            .or_insert_with(|| {
+                if pathfinding::walkable(map, to) {
+                    return map.path_cost(from, to);
+                }
+                NEIGHBOURS
+                    .iter()
+                    .map(|(dx, dy)| ((to.0 + dx, to.1 + dy), ((dx * dx + dy * dy) as f32).sqrt()))
+                    .filter(|(tile, _)| pathfinding::walkable(map, *tile))
+                    .filter_map(|(tile, step)| map.path_cost(from, tile).map(|cost| cost + step))
+                    .min_by(f32::total_cmp)
+            })
+    }
 }
 
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
 /// Uncertainty factor for an explicit action. Grades the planner's confidence
 /// that the target still holds the item the action expects to produce.
 /// Returns 1.0 when no confidence can be graded.
@@ -339,6 +405,38 @@ fn subjective_action_cost(
     base * uncertainty * risk * personality
 }
 
+/// Search-only surcharge for gathering food that won't fill the agent up.
+/// Each step that puts food in the agent's hands pays
+/// `FOOD_VALUE_WEIGHT × hunger × (1 − nutrition)`, so a starving agent will
+/// walk farther for meat than for an apple while a peckish one takes
+/// whatever is closest. Kept out of `estimate_plan_cost` — it steers which
+/// source is chosen, not how much effort the plan is felt to be.
+fn food_value_penalty(action: &ActionTemplate, mind: &MindGraph, ctx: &PlanCostContext) -> f32 {
+    use crate::agent::body::metabolism::{FALLBACK_MEAL, meal_nutrition, nutrition_value};
+    use crate::constants::brains::planner::FOOD_VALUE_WEIGHT;
+
+    if ctx.hunger <= 0.0 {
+        return 0.0;
+    }
+    action
+        .effects
+        .iter()
+        .filter_map(
+            |effect| match (&effect.subject, effect.predicate, &effect.object) {
+                (MindNode::Self_, Predicate::Contains, Value::Item(concept, qty))
+                    if mind.is_a(&MindNode::Concept(*concept), Concept::Food) =>
+                {
+                    // Unmodeled food eats as the fallback meal (see Eat).
+                    let value =
+                        nutrition_value(*concept).unwrap_or_else(|| meal_nutrition(FALLBACK_MEAL));
+                    Some(FOOD_VALUE_WEIGHT * ctx.hunger * (1.0 - value) * (*qty).max(1) as f32)
+                }
+                _ => None,
+            },
+        )
+        .sum()
+}
+
 /// Subjective cost for an implicit walk of `dist` tiles toward `tile`.
 fn subjective_walk_cost(dist: f32, tile: (i32, i32), intensity: f32, cache: &PlanCostCache) -> f32 {
     let base = effort_cost_walk(dist, intensity, cache.ctx);
@@ -686,39 +784,7 @@ fn pattern_matches_triple(
     true
 }
 
-// ─── Custom Comparison / Hashing for Triples (since Value doesn't impl it) ───
-
-fn compare_nodes(a: &MindNode, b: &MindNode) -> Ordering {
-    // Basic heuristic sort
-    match (a, b) {
-        (MindNode::Entity(e1), MindNode::Entity(e2)) => e1.index().cmp(&e2.index()),
-        (MindNode::Concept(c1), MindNode::Concept(c2)) => (*c1 as usize).cmp(&(*c2 as usize)),
-        (MindNode::Tile((x1, y1)), MindNode::Tile((x2, y2))) => x1.cmp(x2).then(y1.cmp(y2)),
-        (MindNode::Self_, MindNode::Self_) => Ordering::Equal,
-        // Cross-variant
-        _ => format!("{:?}", a).cmp(&format!("{:?}", b)), // Fallback but rare comparison
-    }
-}
-
-fn compare_values(a: &Value, b: &Value) -> Ordering {
-    match (a, b) {
-        (Value::Quantity(q1), Value::Quantity(q2)) => match q1.compare(q2) {
-            FuzzyOrdering::Less => Ordering::Less,
-            FuzzyOrdering::Greater => Ordering::Greater,
-            FuzzyOrdering::Equal => Ordering::Equal,
-            // Overlap: fall back to point estimate for deterministic state
-            // ordering. The planner needs a total order even when two fuzzy
-            // beliefs are indistinguishable from an agent's perspective.
-            FuzzyOrdering::Unknown => q1.point_estimate().total_cmp(&q2.point_estimate()),
-        },
-        (Value::Boolean(v1), Value::Boolean(v2)) => v1.cmp(v2),
-        (Value::Concept(c1), Value::Concept(c2)) => (*c1 as usize).cmp(&(*c2 as usize)),
-        (Value::Entity(e1), Value::Entity(e2)) => e1.index().cmp(&e2.index()),
-        (Value::Tile((x1, y1)), Value::Tile((x2, y2))) => x1.cmp(x2).then(y1.cmp(y2)),
-        // Fallbacks
-        _ => format!("{:?}", a).cmp(&format!("{:?}", b)),
-    }
-}
+// ─── Custom Hashing for Triples (since Value doesn't impl it) ───
 
 fn hash_quantity<H: std::hash::Hasher>(q: &Quantity, state: &mut H) {
     std::mem::discriminant(q).hash(state);
@@ -752,6 +818,96 @@ fn hash_value<H: std::hash::Hasher>(v: &Value, state: &mut H) {
     }
 }
 
+// ─── Plan Cache Keys ───
+
+/// Hash of a goal's conditions, for keying the rational brain's plan
+/// cache. Priority is left out: a drifting urgency doesn't change which
+/// plan satisfies the goal.
+pub fn goal_hash(goal: &Goal) -> u64 {
+    hash_one(|h| {
+        for condition in &goal.conditions {
+            hash_pattern(condition, h);
+        }
+    })
+}
+
+/// Hash of the inputs that decide which plan `regressive_plan` returns
+/// for `goal`: the beliefs filed under any predicate the goal or the
+/// candidate actions mention (plus `LocatedAt`, which implicit walks
+/// read), the self inventory, and the candidate actions themselves.
+///
+/// Order-independent — each belief is hashed alone and the results are
+/// summed — so index iteration order can't cause spurious misses.
+/// Belief metadata and the agent's body state are left out: they only
+/// move costs, and a cached plan still passes the feasibility check
+/// before it is adopted.
+pub fn mind_snapshot_hash(
+    mind: &MindGraph,
+    inventory: Option<&crate::agent::item_slots::ItemSlots>,
+    goal: &Goal,
+    actions: &[ActionTemplate],
+) -> u64 {
+    let patterns = goal
+        .conditions
+        .iter()
+        .chain(actions.iter().flat_map(|a| &a.preconditions));
+    let mut predicates = vec![Predicate::LocatedAt];
+    let mut every_predicate = false;
+    for pattern in patterns {
+        match pattern.predicate {
+            Some(p) if !predicates.contains(&p) => predicates.push(p),
+            Some(_) => {}
+            None => every_predicate = true,
+        }
+    }
+    for effect in actions.iter().flat_map(|a| &a.effects) {
+        if !predicates.contains(&effect.predicate) {
+            predicates.push(effect.predicate);
+        }
+    }
+
+    let belief = |t: &Triple| {
+        hash_one(|h| {
+            t.subject.hash(h);
+            t.predicate.hash(h);
+            hash_value(&t.object, h);
+        })
+    };
+    let mut sum = 0u64;
+    if every_predicate {
+        for triple in mind.iter() {
+            sum = sum.wrapping_add(belief(triple));
+        }
+    } else {
+        for predicate in predicates {
+            for triple in mind.query(None, Some(predicate), None) {
+                sum = sum.wrapping_add(belief(triple));
+            }
+        }
+    }
+    for thing in inventory.into_iter().flat_map(|inv| inv.all_items()) {
+        sum = sum.wrapping_add(hash_one(|h| thing.concept.hash(h)));
+    }
+    for action in actions {
+        sum = sum.wrapping_add(hash_one(|h| {
+            action.name.hash(h);
+            action.action_type.hash(h);
+            action.target_entity.hash(h);
+            action
+                .target_position
+                .map(|p| (p.x.to_bits(), p.y.to_bits()))
+                .hash(h);
+        }));
+    }
+    sum
+}
+
+fn hash_one(hash: impl FnOnce(&mut std::hash::DefaultHasher)) -> u64 {
+    let mut hasher = std::hash::DefaultHasher::new();
+    hash(&mut hasher);
+    std::hash::Hasher::finish(&hasher)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // REGRESSIVE PLANNER (BACKWARD) — The primary planner
 // ═══════════════════════════════════════════════════════════════════════════
@@ -856,6 +1012,8 @@ pub struct PlanSearchStats {
     pub exhausted: bool,
     /// Debug-formatted patterns that remained unmet when the search ended (if no plan found).
     pub best_unmet_goals: Vec<String>,
+    /// Wall-clock time the search took, for performance logging.
+    pub elapsed: std::time::Duration,
 }
 
 /// Backward Search: Starts from Goal, finds actions that satisfy unmet goals.
@@ -865,19 +1023,60 @@ pub struct PlanSearchStats {
 /// risk modulation). Use `PlanCostContext::neutral()` for callers that don't
 /// yet supply agent state — it reproduces the old base-cost behaviour.
 ///
+/// `config` bounds the search: it gives up with `None` (and `exhausted` set)
+/// after `max_iterations` expansions or `time_budget_ms`, whichever comes
+/// first.
+///
+/// `world_map` prices implicit walks by their A* route length and drops walk
+/// targets no route reaches, so the agent won't plan a harvest at a tree
+/// across a river. `None` keeps straight-line walk costs.
+///
 /// Returns the plan steps (if found) alongside search telemetry.
 pub fn regressive_plan(
     mind: &MindGraph,
     inventory: Option<&crate::agent::item_slots::ItemSlots>,
     world_positions: &crate::world::entity_positions::WorldEntityPositions,
+    world_map: Option<&WorldMap>,
     goal: &Goal,
     available_actions: &[ActionTemplate],
     ctx: &PlanCostContext,
+    config: &PlannerConfig,
+) -> (Option<Vec<ActionTemplate>>, PlanSearchStats) {
+    regressive_plan_multi(
+        mind,
+        inventory,
+        world_positions,
+        world_map,
+        std::slice::from_ref(goal),
+        available_actions,
+        ctx,
+        config,
+    )
+}
+
+/// [`regressive_plan`] for several goals at once: one search whose start
+/// state is the union of every goal's unmet conditions, so the returned
+/// plan interleaves the steps each goal needs (walk to the tree, harvest,
+/// then walk on to the camp) instead of satisfying one goal and replanning
+/// for the next. Steps still come back in execution order.
+///
+/// Each seed condition's share of the A* heuristic is scaled by its goal's
+/// `priority` relative to the most urgent goal, so the search leans toward
+/// the conditions that matter most. With a single goal every condition
+/// counts in full, exactly as in `regressive_plan`.
+pub fn regressive_plan_multi(
+    mind: &MindGraph,
+    inventory: Option<&crate::agent::item_slots::ItemSlots>,
+    world_positions: &crate::world::entity_positions::WorldEntityPositions,
+    world_map: Option<&WorldMap>,
+    goals: &[Goal],
+    available_actions: &[ActionTemplate],
+    ctx: &PlanCostContext,
+    config: &PlannerConfig,
 ) -> (Option<Vec<ActionTemplate>>, PlanSearchStats) {
-    use crate::constants::brains::planner::{HEURISTIC_MULTIPLIER, MAX_ITERATIONS};
     let start_time = std::time::Instant::now();
     let mut iterations = 0;
-    let cost_cache = PlanCostCache::new(ctx, mind);
+    let cost_cache = PlanCostCache::new(ctx, mind).with_map(world_map);
 
     let mut open_set = BinaryHeap::new();
     let mut came_from: HashMap<RegressiveState, (ActionTemplate, RegressiveState)> = HashMap::new();
@@ -887,9 +1086,9 @@ pub fn regressive_plan(
     // But we first check if they assume anything is already true.
     // Actually, Regressive Planner starts with "All goals unmet".
     // We only remove them if they are true in the CURRENT world (Mind).
-    let initial_goals: Vec<TriplePattern> = goal
-        .conditions
+    let initial_goals: Vec<TriplePattern> = goals
         .iter()
+        .flat_map(|goal| &goal.conditions)
         .filter(|p| !mind_satisfies_pattern(mind, inventory, world_positions, p))
         .cloned()
         .collect();
@@ -902,14 +1101,16 @@ pub fn regressive_plan(
                 iterations: 0,
                 exhausted: false,
                 best_unmet_goals: vec![],
+                elapsed: start_time.elapsed(),
             },
         );
     }
 
+    let heuristic = GoalHeuristic::new(goals, ctx.heuristic_weight);
     let start = RegressiveState::new(initial_goals, vec![]);
     g_score.insert(start.clone(), 0.0);
     open_set.push(RegressiveSearchNode {
-        f_score: start.unmet_goals.len() as f32, // Simple heuristic
+        f_score: heuristic.estimate(&start),
         depth: 0,
         state: start,
     });
@@ -922,19 +1123,31 @@ pub fn regressive_plan(
 
     while let Some(current_node) = open_set.pop() {
         iterations += 1;
-        if iterations > MAX_ITERATIONS {
+        let over_budget = config.time_budget_ms > 0
+            && start_time.elapsed().as_millis() >= u128::from(config.time_budget_ms);
+        if iterations > config.max_iterations || over_budget {
             let mut top_patterns: Vec<&(TriplePattern, usize)> =
                 goal_pattern_counts.values().collect();
             top_patterns.sort_by_key(|p| std::cmp::Reverse(p.1));
             top_patterns.truncate(3);
             let top_readable: Vec<&TriplePattern> =
                 top_patterns.into_iter().map(|(p, _)| p).collect();
-            tracing::warn!(
-                target: "planner",
-                "regressive_plan exhausted {} iterations on goal {:?}",
-                MAX_ITERATIONS,
-                goal
-            );
+            if over_budget {
+                tracing::warn!(
+                    target: "planner",
+                    "regressive_plan exhausted its {} ms budget after {} iterations on goals {:?}",
+                    config.time_budget_ms,
+                    iterations - 1,
+                    goals
+                );
+            } else {
+                tracing::warn!(
+                    target: "planner",
+                    "regressive_plan exhausted {} iterations on goals {:?}",
+                    config.max_iterations,
+                    goals
+                );
+            }
             tracing::warn!(
                 target: "planner",
                 "best frontier node had {} unmet goals: {:?}",
@@ -1031,7 +1244,7 @@ pub fn regressive_plan(
                 new_cost,
                 child_depth,
                 &current_state,
-                HEURISTIC_MULTIPLIER,
+                &heuristic,
                 &mut came_from,
                 &mut g_score,
                 &mut open_set,
@@ -1059,7 +1272,7 @@ pub fn regressive_plan(
                 new_cost,
                 child_depth,
                 &current_state,
-                HEURISTIC_MULTIPLIER,
+                &heuristic,
                 &mut came_from,
                 &mut g_score,
                 &mut open_set,
@@ -1089,7 +1302,7 @@ pub fn regressive_plan(
                 new_cost,
                 child_depth,
                 &current_state,
-                HEURISTIC_MULTIPLIER,
+                &heuristic,
                 &mut came_from,
                 &mut g_score,
                 &mut open_set,
@@ -1097,20 +1310,11 @@ pub fn regressive_plan(
         }
     }
 
-    let elapsed = start_time.elapsed();
-    if elapsed.as_millis() > 1 {
-        println!(
-            "[Performance] [RegressivePlanner] Plan took {:?} ({} iterations, {} explicit actions)",
-            elapsed,
-            iterations,
-            available_actions.len()
-        );
-    }
-
     let stats = PlanSearchStats {
         iterations,
         exhausted,
         best_unmet_goals: best_unmet.iter().map(|p| format!("{p:?}")).collect(),
+        elapsed: start_time.elapsed(),
     };
     (result, stats)
 }
@@ -1381,6 +1585,50 @@ fn hash_pattern<H: std::hash::Hasher>(p: &TriplePattern, state: &mut H) {
 
 // ─── Search Helpers ───
 
+/// A* heuristic: `weight` per unmet condition, with each goal's own
+/// conditions scaled by that goal's priority relative to the most urgent
+/// goal. Conditions the search introduces (action preconditions) count in
+/// full.
+struct GoalHeuristic {
+    weight: f32,
+    seeds: Vec<(TriplePattern, f32)>,
+}
+
+impl GoalHeuristic {
+    fn new(goals: &[Goal], weight: f32) -> Self {
+        let top = goals.iter().map(|g| g.priority).fold(0.0, f32::max);
+        let mut seeds: Vec<(TriplePattern, f32)> = Vec::new();
+        for goal in goals {
+            let scale = if top > 0.0 {
+                (goal.priority / top).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+            for condition in &goal.conditions {
+                match seeds.iter_mut().find(|(p, _)| patterns_eq(p, condition)) {
+                    Some((_, s)) => *s = s.max(scale),
+                    None => seeds.push((condition.clone(), scale)),
+                }
+            }
+        }
+        Self { weight, seeds }
+    }
+
+    fn estimate(&self, state: &RegressiveState) -> f32 {
+        let unmet: f32 = state
+            .unmet_goals
+            .iter()
+            .map(|pattern| {
+                self.seeds
+                    .iter()
+                    .find(|(p, _)| patterns_eq(p, pattern))
+                    .map_or(1.0, |(_, scale)| *scale)
+            })
+            .sum();
+        unmet * self.weight
+    }
+}
+
 /// Updates the search structures if new_cost is better than the current best for next_state.
 fn update_search_candidate(
     action: ActionTemplate,
@@ -1388,7 +1636,7 @@ fn update_search_candidate(
     new_cost: f32,
     next_depth: usize,
     current_state: &RegressiveState,
-    heuristic_multiplier: f32,
+    heuristic: &GoalHeuristic,
     came_from: &mut HashMap<RegressiveState, (ActionTemplate, RegressiveState)>,
     g_score: &mut HashMap<RegressiveState, f32>,
     open_set: &mut BinaryHeap<RegressiveSearchNode>,
@@ -1397,7 +1645,7 @@ fn update_search_candidate(
         came_from.insert(next_state.clone(), (action, current_state.clone()));
         g_score.insert(next_state.clone(), new_cost);
         open_set.push(RegressiveSearchNode {
-            f_score: new_cost + next_state.unmet_goals.len() as f32 * heuristic_multiplier,
+            f_score: new_cost + heuristic.estimate(&next_state),
             depth: next_depth,
             state: next_state,
         });
@@ -1455,8 +1703,9 @@ fn find_explicit_actions_for_goal(
         next_consumed.extend(action.consumes.iter().cloned());
 
         let next_state = RegressiveState::new(new_unmet, next_consumed);
-        let new_cost =
-            current_g + subjective_action_cost(action, cost_cache, mind, world_positions);
+        let new_cost = current_g
+            + subjective_action_cost(action, cost_cache, mind, world_positions)
+            + food_value_penalty(action, mind, cost_cache.ctx);
         candidates.push((action.clone(), next_state, new_cost));
     }
 
@@ -1731,7 +1980,7 @@ fn generate_implicit_walk(
         _ => return None,
     };
 
-    let dist = (((cx - tile.0).pow(2) + (cy - tile.1).pow(2)) as f32).sqrt();
+    let dist = cost_cache.walk_distance((*cx, *cy), tile)?;
     let world_pos = Vec2::new(
         tile.0 as f32 * TILE_SIZE + TILE_SIZE / 2.0,
         tile.1 as f32 * TILE_SIZE + TILE_SIZE / 2.0,
@@ -1813,7 +2062,9 @@ fn generate_concept_near_walks(
             continue;
         }
 
-        let dist = (((cx - tile.0).pow(2) + (cy - tile.1).pow(2)) as f32).sqrt();
+        let Some(dist) = cost_cache.walk_distance((cx, cy), tile) else {
+            continue;
+        };
         let world_pos = Vec2::new(
             tile.0 as f32 * TILE_SIZE + TILE_SIZE / 2.0,
             tile.1 as f32 * TILE_SIZE + TILE_SIZE / 2.0,
@@ -1854,12 +2105,28 @@ fn generate_concept_near_walks(
 pub struct PlannerConfig {
     /// Urgency threshold required to trigger goal formulation (0.0 - 1.0)
     pub goal_formulation_threshold: f32,
+    /// A* cost per unmet goal condition. Raise it to trade plan quality for
+    /// faster searches on large populations; lower it for more optimal plans.
+    pub heuristic_weight: f32,
+    /// Node expansions before a search gives up.
+    pub max_iterations: usize,
+    /// Wall-clock milliseconds before a search gives up; `0` means no limit.
+    /// Off by default: a time cutoff depends on the host machine, so a
+    /// nonzero budget gives up deterministic replays.
+    pub time_budget_ms: u64,
+    /// Most drives the rational brain plans for in one combined search.
+    /// `1` plans every urgency on its own.
+    pub max_combined_goals: usize,
 }
 
 impl Default for PlannerConfig {
     fn default() -> Self {
         Self {
             goal_formulation_threshold: 0.1, // Low threshold to encourage action
+            heuristic_weight: crate::constants::brains::planner::HEURISTIC_MULTIPLIER,
+            max_iterations: crate::constants::brains::planner::MAX_ITERATIONS,
+            time_budget_ms: 0,
+            max_combined_goals: 1,
         }
     }
 }
@@ -2020,9 +2287,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(plan.is_some(), "single gather should produce a valid plan");
         assert!(
@@ -2059,9 +2328,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         if let Some(ref p) = plan {
             let gather_count = p
@@ -2105,9 +2376,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(
             plan.is_some(),
@@ -2136,9 +2409,11 @@ mod tests {
             &mind,
             Some(&inventory),
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &[],
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(plan.is_some(), "goal already satisfied should return Some");
         assert!(
@@ -2213,6 +2488,52 @@ mod tests {
             .expect("Rest must be registered")
     }
 
+    #[test]
+    fn two_goals_plan_one_interleaved_route() {
+        // Hungry and wanting to reach camp: pick the apple up on the way
+        // rather than satisfying one goal and replanning for the other.
+        let food = Entity::from_bits(10);
+        let food_tile = (5i32, 0i32);
+        let camp_tile = (10i32, 0i32);
+        let mind = mind_with_food_and_energy(food, food_tile, 80);
+        let actions = vec![harvest_at_tile(food, Concept::Apple, food_tile)];
+        let reach_camp = Goal {
+            conditions: vec![TriplePattern::new(
+                Some(MindNode::Self_),
+                Some(Predicate::LocatedAt),
+                Some(Value::Tile(camp_tile)),
+            )],
+            priority: 0.5,
+        };
+
+        let (plan, _) = regressive_plan_multi(
+            &mind,
+            None,
+            &WorldEntityPositions::default(),
+            None,
+            &[goal_self_contains(Concept::Apple), reach_camp],
+            &actions,
+            &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
+        );
+
+        let steps: Vec<(ActionType, Option<Vec2>)> = plan
+            .expect("both goals are reachable in one plan")
+            .iter()
+            .map(|a| (a.action_type, a.target_position))
+            .collect();
+        let tile_centre = |(x, y): (i32, i32)| {
+            Some(Vec2::new(
+                x as f32 * TILE_SIZE + TILE_SIZE / 2.0,
+                y as f32 * TILE_SIZE + TILE_SIZE / 2.0,
+            ))
+        };
+        assert_eq!(steps.len(), 3, "walk, harvest, walk on: {steps:?}");
+        assert_eq!(steps[0], (ActionType::Walk, tile_centre(food_tile)));
+        assert_eq!(steps[1].0, ActionType::Harvest);
+        assert_eq!(steps[2], (ActionType::Walk, tile_centre(camp_tile)));
+    }
+
     #[test]
     fn short_walk_with_high_energy_needs_no_rest() {
         // Agent stamina 80, food 10 tiles away — should plan Walk -> Harvest, no Rest.
@@ -2231,9 +2552,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(plan.is_some(), "should produce a valid plan");
         let plan = plan.unwrap();
@@ -2272,9 +2595,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &ctx_with_stamina(20.0),
+            &PlannerConfig::default(),
         );
         assert!(
             plan.is_some(),
@@ -2311,9 +2636,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(
             plan.is_none(),
@@ -2383,9 +2710,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &ctx_with_stamina(20.0),
+            &PlannerConfig::default(),
         );
         assert!(plan.is_some(), "should produce a plan for stone harvest");
         let plan = plan.unwrap();
@@ -2489,9 +2818,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &hunger_goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         assert!(
             plan.is_none(),
@@ -2640,9 +2971,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &[],
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan_opt.expect("planner should produce a Walk plan, not an empty plan");
         assert!(
@@ -2651,6 +2984,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn harvest_at_a_walled_off_tree_is_not_planned_with_a_map() {
+        use crate::world::map::{Chunk, TileType};
+
+        let tree = Entity::from_bits(42);
+        let mut mind = test_mind();
+        mind.add(Triple::new(
+            MindNode::Self_,
+            Predicate::LocatedAt,
+            Value::Tile((1, 1)),
+        ));
+        mind.add(Triple::new(
+            MindNode::Entity(tree),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 1),
+        ));
+        let mut harvest = gather_template(tree, Concept::Apple);
+        harvest.preconditions.push(TriplePattern::self_at((10, 10)));
+
+        // Ring of water around the tree's tile.
+        let mut map = WorldMap::new(16, 16);
+        map.chunks.insert(IVec2::new(0, 0), Chunk::new(0, 0));
+        for x in 8..=12 {
+            for y in 8..=12 {
+                if x == 8 || x == 12 || y == 8 || y == 12 {
+                    map.set_tile(x, y, TileType::Water);
+                }
+            }
+        }
+
+        let plan_with = |map: Option<&WorldMap>| {
+            regressive_plan(
+                &mind,
+                None,
+                &WorldEntityPositions::default(),
+                map,
+                &goal_self_contains(Concept::Apple),
+                std::slice::from_ref(&harvest),
+                &PlanCostContext::neutral(),
+                &PlannerConfig::default(),
+            )
+            .0
+        };
+        assert!(
+            plan_with(None).is_some(),
+            "straight-line costs walk through the water"
+        );
+        assert!(
+            plan_with(Some(&map)).is_none(),
+            "no route to the tree means no harvest plan"
+        );
+    }
+
     #[test]
     fn pattern_matches_triple_respects_specific_subject() {
         let agent_apple = Triple::new(
@@ -2795,9 +3181,11 @@ mod tests {
                 &mind,
                 None,
                 &WorldEntityPositions::default(),
+                None,
                 &goal,
                 &actions,
                 &PlanCostContext::neutral(),
+                &PlannerConfig::default(),
             )
         });
 
@@ -2813,6 +3201,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn search_gives_up_at_the_configured_limits() {
+        // Every gather needs a source nobody believes holds apples, so the
+        // search can only end by running out of nodes or hitting a limit.
+        let mind = test_mind();
+        let actions: Vec<ActionTemplate> = (1..=5_000)
+            .map(|i| gather_template(Entity::from_bits(i), Concept::Apple))
+            .collect();
+        let goal = goal_self_contains(Concept::Apple);
+        let search = |config: &PlannerConfig| {
+            regressive_plan(
+                &mind,
+                None,
+                &WorldEntityPositions::default(),
+                None,
+                &goal,
+                &actions,
+                &PlanCostContext::neutral(),
+                config,
+            )
+        };
+
+        let (plan, stats) = search(&PlannerConfig {
+            max_iterations: 10,
+            ..Default::default()
+        });
+        assert!(plan.is_none());
+        assert!(stats.exhausted);
+        assert_eq!(
+            stats.iterations, 11,
+            "stops on the first expansion past the cap"
+        );
+
+        // Unbounded, the 5 000 dead ends each rescan all 5 000 actions —
+        // far longer than a millisecond.
+        let (plan, stats) = search(&PlannerConfig {
+            max_iterations: usize::MAX,
+            time_budget_ms: 1,
+            ..Default::default()
+        });
+        assert!(plan.is_none());
+        assert!(stats.exhausted, "the time budget must end the search");
+        assert!(
+            stats.iterations < 5_000,
+            "gave up after {}",
+            stats.iterations
+        );
+    }
+
     // ─── Subjective plan cost ─────────────────────────────────────────────────
 
     fn physical_action(target: Entity, concept: Concept, tile: (i32, i32)) -> ActionTemplate {
@@ -3150,9 +3587,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &actions,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan_opt.expect("plan should exist");
 
@@ -3168,6 +3607,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hungry_agent_walks_farther_for_more_filling_food() {
+        // An apple tree 3 tiles away and a meat cache 30 tiles away. A
+        // starving agent should make the trip for the meat; a peckish one
+        // should settle for the nearby apple.
+        let near = Entity::from_bits(10);
+        let far = Entity::from_bits(11);
+        let near_tile = (3i32, 0i32);
+        let far_tile = (30i32, 0i32);
+
+        let mut mind = test_mind();
+        mind.add(Triple::new(
+            MindNode::Self_,
+            Predicate::LocatedAt,
+            Value::Tile((0, 0)),
+        ));
+        stock_entity_at_tile(&mut mind, near, Concept::Apple, near_tile);
+        stock_entity_at_tile(&mut mind, far, Concept::Meat, far_tile);
+
+        let eat = ActionTemplate {
+            name: "Eat".to_string(),
+            action_type: ActionType::Eat,
+            behavior: Default::default(),
+            target_entity: None,
+            target_position: None,
+            preconditions: vec![TriplePattern::self_contains_food()],
+            effects: vec![Triple::new(
+                MindNode::Self_,
+                Predicate::Hunger,
+                Value::Quantity(Quantity::Exact(0.0)),
+            )],
+            consumes: vec![],
+            base_cost: 1.0,
+            locomotion_intensity: 0.0,
+            estimated_duration_ticks: None,
+            search_filter: None,
+        };
+        let actions = vec![
+            eat,
+            harvest_at_tile(near, Concept::Apple, near_tile),
+            harvest_at_tile(far, Concept::Meat, far_tile),
+        ];
+        let hunger_goal = Goal {
+            conditions: vec![TriplePattern::new(
+                Some(MindNode::Self_),
+                Some(Predicate::Hunger),
+                Some(Value::Quantity(Quantity::Exact(0.0))),
+            )],
+            priority: 1.0,
+        };
+
+        let chosen = |hunger: f32| {
+            let ctx = PlanCostContext {
+                hunger,
+                ..PlanCostContext::neutral()
+            };
+            let (plan, _) = regressive_plan(
+                &mind,
+                None,
+                &WorldEntityPositions::default(),
+                None,
+                &hunger_goal,
+                &actions,
+                &ctx,
+                &PlannerConfig::default(),
+            );
+            plan.expect("plan should exist")
+                .iter()
+                .find(|a| a.action_type == ActionType::Harvest)
+                .and_then(|a| a.target_entity)
+                .expect("plan must harvest something")
+        };
+
+        assert_eq!(chosen(0.9), far, "a starving agent should go for the meat");
+        assert_eq!(chosen(0.2), near, "a peckish agent should take the apple");
+    }
+
     #[test]
     fn planner_generates_walk_behavior_for_locomotion() {
         use crate::agent::actions::motor::ActionPrimitive;
@@ -3369,9 +3885,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &gathers,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan.unwrap_or_else(|| {
             panic!(
@@ -3424,9 +3942,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &gathers,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan.unwrap_or_else(|| {
             panic!(
@@ -3485,9 +4005,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &gathers,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan.expect("planner must chain 5 berry harvests");
         let count = plan
@@ -3537,9 +4059,11 @@ mod tests {
             &mind,
             None,
             &WorldEntityPositions::default(),
+            None,
             &goal,
             &gathers,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan.unwrap_or_else(|| {
             panic!(
@@ -3601,8 +4125,16 @@ mod tests {
         };
 
         let positions = crate::world::entity_positions::WorldEntityPositions::default();
-        let (plan, _) =
-            regressive_plan(&mind, None, &positions, &goal, &gathers, &ctx_with_depth(1));
+        let (plan, _) = regressive_plan(
+            &mind,
+            None,
+            &positions,
+            None,
+            &goal,
+            &gathers,
+            &ctx_with_depth(1),
+            &PlannerConfig::default(),
+        );
         assert!(
             plan.is_none(),
             "depth=1 must refuse a 5-step chain; got {plan:?}"
@@ -3642,11 +4174,165 @@ mod tests {
             &mind,
             None,
             &positions,
+            None,
             &goal,
             &gathers,
             &PlanCostContext::neutral(),
+            &PlannerConfig::default(),
         );
         let plan = plan.expect("uncapped planner must still chain harvests");
         assert_eq!(plan.len(), 3, "expected 3-step plan; got {plan:?}");
     }
+
+    /// Self-targeted template that yields one `effect` item after
+    /// `duration_ticks`, optionally needing one `needs` item first. Uses
+    /// Walk's behaviour so duration maps onto a nonzero effort cost.
+    fn timed_template(
+        name: &str,
+        effect: Concept,
+        needs: Option<Concept>,
+        duration_ticks: u32,
+    ) -> ActionTemplate {
+        let behavior = ActionRegistry::new()
+            .get(ActionType::Walk)
+            .unwrap()
+            .to_template(None)
+            .behavior;
+        let item = |concept| {
+            TriplePattern::new(
+                Some(MindNode::Self_),
+                Some(Predicate::Contains),
+                Some(Value::Item(concept, 1)),
+            )
+        };
+        ActionTemplate {
+            name: name.to_string(),
+            action_type: ActionType::Harvest,
+            behavior,
+            target_entity: None,
+            target_position: None,
+            preconditions: needs.map(item).into_iter().collect(),
+            effects: vec![Triple::new(
+                MindNode::Self_,
+                Predicate::Contains,
+                Value::Item(effect, 1),
+            )],
+            consumes: vec![],
+            base_cost: 1.0,
+            locomotion_intensity: 0.0,
+            estimated_duration_ticks: Some(duration_ticks),
+            search_filter: None,
+        }
+    }
+
+    fn ctx_with_heuristic_weight(heuristic_weight: f32) -> PlanCostContext {
+        PlanCostContext {
+            heuristic_weight,
+            ..PlanCostContext::neutral()
+        }
+    }
+
+    /// Benchmark: a slow one-step forage competes with a cheap two-step
+    /// chain (find a stick, then knock berries down). An uninformed search
+    /// expands the chain and finds the cheaper plan; a heavy heuristic
+    /// weight jumps straight to the zero-unmet forage node in fewer
+    /// iterations and settles for the costlier plan.
+    #[test]
+    fn higher_heuristic_weight_searches_fewer_iterations() {
+        let mind = test_mind();
+        let actions = vec![
+            timed_template("Forage", Concept::Berry, None, 1200),
+            timed_template("KnockDown", Concept::Berry, Some(Concept::Stick), 60),
+            timed_template("FindStick", Concept::Stick, None, 60),
+        ];
+        let goal = goal_self_contains(Concept::Berry);
+        let positions = WorldEntityPositions::default();
+
+        let (careful_plan, careful) = regressive_plan(
+            &mind,
+            None,
+            &positions,
+            None,
+            &goal,
+            &actions,
+            &ctx_with_heuristic_weight(0.0),
+            &PlannerConfig::default(),
+        );
+        let (greedy_plan, greedy) = regressive_plan(
+            &mind,
+            None,
+            &positions,
+            None,
+            &goal,
+            &actions,
+            &ctx_with_heuristic_weight(100.0),
+            &PlannerConfig::default(),
+        );
+
+        assert!(
+            greedy.iterations < careful.iterations,
+            "greedier search should expand fewer nodes (greedy={}, careful={})",
+            greedy.iterations,
+            careful.iterations
+        );
+        let names = |plan: Option<Vec<ActionTemplate>>| -> Vec<String> {
+            plan.expect("both weights must find a plan")
+                .into_iter()
+                .map(|a| a.name)
+                .collect()
+        };
+        assert_eq!(names(careful_plan), vec!["FindStick", "KnockDown"]);
+        assert_eq!(names(greedy_plan), vec!["Forage"]);
+    }
+
+    #[test]
+    fn planner_config_default_weight_matches_constant() {
+        assert_eq!(
+            PlannerConfig::default().heuristic_weight,
+            crate::constants::brains::planner::HEURISTIC_MULTIPLIER
+        );
+        assert_eq!(
+            PlanCostContext::neutral().heuristic_weight,
+            PlannerConfig::default().heuristic_weight
+        );
+    }
+
+    #[test]
+    fn plan_cache_keys_track_goal_and_relevant_beliefs_only() {
+        let tree = Entity::from_bits(42);
+        let mut mind = test_mind();
+        mind.add(Triple::new(
+            MindNode::Entity(tree),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 1),
+        ));
+        let actions = vec![gather_template(tree, Concept::Apple)];
+        let goal = goal_self_contains(Concept::Apple);
+        let before = mind_snapshot_hash(&mind, None, &goal, &actions);
+
+        let urgent = Goal {
+            priority: 0.2,
+            ..goal.clone()
+        };
+        assert_eq!(goal_hash(&goal), goal_hash(&urgent));
+        assert_ne!(
+            goal_hash(&goal),
+            goal_hash(&goal_self_contains(Concept::Stick))
+        );
+
+        // Nothing the gather plan reads.
+        mind.add(Triple::new(
+            MindNode::Entity(Entity::from_bits(7)),
+            Predicate::Produces,
+            Value::Concept(Concept::Apple),
+        ));
+        assert_eq!(mind_snapshot_hash(&mind, None, &goal, &actions), before);
+
+        mind.add(Triple::new(
+            MindNode::Entity(Entity::from_bits(8)),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 3),
+        ));
+        assert_ne!(mind_snapshot_hash(&mind, None, &goal, &actions), before);
+    }
 }
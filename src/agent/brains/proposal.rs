@@ -1,11 +1,12 @@
 //! Brain proposal types: BrainProposal, BrainType, BrainPowers, and the BrainState component.
 //!
 //! Reads: ActionTemplate (from thinking), BrainType tag
-//! Writes: BrainProposal, BrainPowers, BrainState (ECS component holding all proposals and the winner)
+//! Writes: BrainProposal, BrainPowers, BrainState (ECS component holding all proposals, the winner, and the incumbent)
 //! Upstream: thinking (ActionTemplate), all brain modules that create proposals
 //! Downstream: arbitration (selects winner), brain_system (reads BrainState result)
 
 use super::thinking::ActionTemplate;
+use crate::agent::actions::ActionType;
 use crate::agent::nervous_system::urgency::UrgencySource;
 use bevy::prelude::*;
 
@@ -25,6 +26,8 @@ pub enum Intent {
     SatisfySafety,
     /// Pain relief: injury-driven behavior (e.g. can't move while hurt).
     SatisfyPainRelief,
+    /// Recovery: rest somewhere safe so injuries heal faster.
+    SatisfyRecovery,
     SatisfyTerritoriality,
     /// Sleep pressure from wakefulness decay, independent of stamina fatigue.
     SatisfySleepiness,
@@ -137,6 +140,40 @@ pub struct BrainState {
     /// entities whose admitted set went empty last tick).
     #[reflect(ignore)]
     pub last_logged: Option<Vec<(BrainType, String)>>,
+    /// A brain that won arbitration but is still inside its reaction
+    /// latency. `winner` / `chosen_actions` keep the previous decision
+    /// until `ready_at`.
+    pub pending_takeover: Option<PendingTakeover>,
+    /// The last winner and its switch cooldown. Its proposal is boosted
+    /// until `hold_until` (see `arbitration::apply_incumbent_boost`).
+    pub incumbent: Option<Incumbent>,
+}
+
+/// The decision arbitration is sticking with: the winning brain and its
+/// lead action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct Incumbent {
+    pub brain: BrainType,
+    pub action_type: ActionType,
+    /// Tick until which the incumbent's proposal gets the urgency boost.
+    pub hold_until: u64,
+}
+
+impl Incumbent {
+    /// Whether `proposal` is the incumbent decision proposed again.
+    pub fn backs(&self, proposal: &BrainProposal) -> bool {
+        proposal.brain == self.brain && proposal.action.action_type == self.action_type
+    }
+}
+
+/// A brain waiting out its reaction latency before taking control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct PendingTakeover {
+    pub brain: BrainType,
+    /// Tick the brain first won arbitration.
+    pub since_tick: u64,
+    /// Tick from which its decision is committed.
+    pub ready_at: u64,
 }
 
 impl BrainState {
@@ -1,21 +1,24 @@
 //! Rational brain: deliberate goal-directed planning via GOAP.
 //!
 //! Reads: PlanMemory, Consciousness, MindGraph, VisibleObjects, CentralNervousSystem, PhysicalNeeds, Personality
-//! Writes: PlanMemory (plan generation, commitment ticks, state transitions, eviction), BrainProposal
+//! Writes: PlanMemory (plan generation, commitment ticks, state transitions, eviction, plan cache), BrainProposal, PerformanceDiagnostics (plan cache hits/misses)
 //! Upstream: cns (current_goal), planner (regressive_plan), mind (MindGraph)
 //! Downstream: brains::proposal (winner selection), brains::plan_memory (state machine)
 
 use crate::agent::Agent;
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::{ChannelCapacities, ChannelLoad};
+use crate::agent::actions::registry::TargetCandidate;
 use crate::agent::biology::body::{Body, TagChannelMapping};
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds};
 use crate::agent::brains::plan_memory::{
-    HeldPlan, PlanAbandonReason, PlanId, PlanMemory, PlanSource, PlanState, RetentionDecision,
-    classify_for_retention, max_plans_for,
+    CachedTarget, HeldPlan, PlanAbandonReason, PlanId, PlanMemory, PlanSource, PlanState,
+    RetentionDecision, classify_for_retention, max_plans_for,
 };
 use crate::agent::brains::proposal::{BrainProposal, BrainType, Intent};
-use crate::agent::brains::target_enumeration::enumerate_targets;
+use crate::agent::brains::target_enumeration::{
+    TargetComponents, admissible_target_position, enumerate_targets,
+};
 use crate::agent::brains::thinking::{ActionTemplate, Goal, TriplePattern, derive_search_concept};
 use crate::agent::events::SimEventKind;
 use crate::agent::mind::knowledge::{MindGraph, Quantity, Value};
@@ -245,6 +248,21 @@ fn are_preconditions_met(action: &ActionTemplate, mind: &MindGraph) -> bool {
     })
 }
 
+/// One goal holding every condition of `goals`, led by the first goal's
+/// priority. A single goal comes back unchanged.
+fn combined_goal(goals: &[Goal]) -> Goal {
+    let mut conditions: Vec<TriplePattern> = Vec::new();
+    for condition in goals.iter().flat_map(|g| &g.conditions) {
+        if !conditions.contains(condition) {
+            conditions.push(condition.clone());
+        }
+    }
+    Goal {
+        conditions,
+        priority: goals.first().map_or(0.0, |g| g.priority),
+    }
+}
+
 pub fn update_rational_planning(
     mut query: Query<
         (
@@ -260,6 +278,7 @@ pub fn update_rational_planning(
             &crate::agent::item_slots::ItemSlots,
             &crate::agent::psyche::personality::Personality,
             Option<&crate::agent::body::species::SpeciesProfile>,
+            Option<&crate::agent::skills::Skills>,
         ),
         (
             With<RationalBrain>,
@@ -283,6 +302,7 @@ pub fn update_rational_planning(
         Option<&crate::agent::Dead>,
     )>,
     agents: Query<(), With<Agent>>,
+    existing: Query<()>,
     mut sim_events_params: ParamSet<(
         MessageReader<crate::agent::events::SimEvent>,
         MessageWriter<crate::agent::events::SimEvent>,
@@ -290,6 +310,8 @@ pub fn update_rational_planning(
     mut pending_wakeups: ResMut<super::wakeup::PendingBrainWakeups>,
     brain_interval: Res<super::BrainTickInterval>,
     mapping: Res<TagChannelMapping>,
+    planner_config: Res<crate::agent::brains::planner::PlannerConfig>,
+    mut diagnostics: Option<ResMut<crate::core::diagnostics::PerformanceDiagnostics>>,
 ) {
     // Plan verification (steps 1-4 below) runs every tick so it can
     // consume single-pass `SimEvent`s before Bevy's message-update clears
@@ -369,6 +391,7 @@ pub fn update_rational_planning(
         inventory,
         personality,
         species,
+        skills,
     ) in query.iter_mut()
     {
         let capacities =
@@ -381,7 +404,9 @@ pub fn update_rational_planning(
         //    the iteration because `plan_memory.remove` can't run while
         //    `iter_mut` borrows `plan_memory.plans`.
         let mut invalid_ids: Vec<PlanId> = Vec::new();
+        let mut invalid_urgencies: Vec<UrgencySource> = Vec::new();
         let mut finished_ids: Vec<PlanId> = Vec::new();
+        let mut target_gone_urgencies: Vec<UrgencySource> = Vec::new();
         for plan in plan_memory.plans.iter_mut() {
             if plan.state != PlanState::Executing {
                 continue;
@@ -408,6 +433,30 @@ pub fn update_rational_planning(
                         PlanAbandonReason::StepAdvancedInvalid,
                     ));
                     invalid_ids.push(plan.id);
+                    invalid_urgencies.push(plan.driving_urgency);
+                    continue;
+                }
+                // A target despawned mid-plan (eaten, burnt, picked up)
+                // can never be reached again. The admission check below
+                // passes unknown entities, so catch it explicitly — for
+                // every remaining step, not just the current one, so a
+                // Walk toward a vanished bush stops now rather than on
+                // arrival. No grace tick: this isn't perception lag.
+                if plan.steps[plan.current_step.min(plan.steps.len())..]
+                    .iter()
+                    .filter_map(|step| step.target_entity)
+                    .any(|target| !existing.contains(target))
+                {
+                    sim_events.write(crate::agent::events::SimEvent::plan_abandoned(
+                        current_tick,
+                        entity,
+                        plan.id,
+                        plan.driving_urgency,
+                        PlanAbandonReason::TargetGone,
+                    ));
+                    invalid_ids.push(plan.id);
+                    invalid_urgencies.push(plan.driving_urgency);
+                    target_gone_urgencies.push(plan.driving_urgency);
                     continue;
                 }
                 // Grace tick on step advance: perception hasn't yet seen
@@ -418,7 +467,12 @@ pub fn update_rational_planning(
                 // plan that produces an artifact.
                 if !step_just_advanced
                     && let Some(action) = plan.current()
-                    && !are_preconditions_met(action, mind)
+                    && (!are_preconditions_met(action, mind)
+                        || !step_target_admitted(action, &action_registry, |e| {
+                            affordances.get(e).ok().map(|(_, affordance, dead)| {
+                                TargetComponents::new(affordance, dead)
+                            })
+                        }))
                 {
                     sim_events.write(crate::agent::events::SimEvent::plan_abandoned(
                         current_tick,
@@ -428,6 +482,7 @@ pub fn update_rational_planning(
                         PlanAbandonReason::PreconditionsUnmet,
                     ));
                     invalid_ids.push(plan.id);
+                    invalid_urgencies.push(plan.driving_urgency);
                     continue;
                 }
             }
@@ -436,15 +491,34 @@ pub fn update_rational_planning(
             }
         }
         let any_plan_change = !invalid_ids.is_empty() || !finished_ids.is_empty();
+        // A plan that ran to the end proved its target; try that target
+        // first next time the same urgency asks for a plan.
+        for id in &finished_ids {
+            if let Some(plan) = plan_memory.get(*id).cloned() {
+                plan_memory.remember_target(&plan);
+            }
+        }
         for id in invalid_ids.iter().chain(finished_ids.iter()) {
             plan_memory.remove(*id);
         }
+        // A plan that broke would come straight back out of the cache
+        // if the snapshot hash happened not to move; force a real search.
+        for source in &invalid_urgencies {
+            plan_memory.plan_cache.remove(source);
+        }
         // A removed plan invalidates BrainState.chosen_actions for this
         // agent — fire a wakeup so arbitration (later this same tick)
         // recomputes instead of leaving the stale entry in place.
         if any_plan_change {
             pending_wakeups.wake(entity);
         }
+        // Lift the per-urgency planning cooldown for plans whose target
+        // vanished so step 5 searches again this tick instead of leaving
+        // the agent idle until the next thinking interval.
+        for source in &target_gone_urgencies {
+            plan_memory.last_plan_attempt.remove(source);
+        }
+        let replan_now = !target_gone_urgencies.is_empty();
 
         // 2. Per-tick commitment accumulation for plans still in
         //    consideration (Background / Considering). Executing plans
@@ -573,7 +647,7 @@ pub fn update_rational_planning(
         //    triggers a GOAP search, throttled by its own per-urgency
         //    cooldown. High-urgency drives get shorter cooldowns so a
         //    desperate agent thinks harder about the worst thing.
-        if !goap_search_due {
+        if !goap_search_due && !replan_now {
             continue;
         }
         if consciousness.alertness < MIN_ALERTNESS_FOR_PLANNING {
@@ -592,20 +666,35 @@ pub fn update_rational_planning(
             continue;
         }
 
-        let urgencies_snapshot: Vec<(UrgencySource, f32)> =
+        let mut urgencies_snapshot: Vec<(UrgencySource, f32)> =
             cns.urgencies.iter().map(|u| (u.source, u.value)).collect();
+        // When drives share a search, the most urgent one leads it and
+        // owns the resulting plan.
+        let combining = planner_config.max_combined_goals > 1;
+        if combining {
+            urgencies_snapshot.sort_by(|a, b| b.1.total_cmp(&a.1));
+        }
+        // Urgencies already planned for this tick as a companion goal.
+        let mut covered: Vec<UrgencySource> = Vec::new();
 
-        for (source, value) in urgencies_snapshot {
-            if value < PLAN_GENERATION_MIN_URGENCY {
+        for &(source, value) in &urgencies_snapshot {
+            if value < PLAN_GENERATION_MIN_URGENCY || covered.contains(&source) {
                 continue;
             }
             let Some(goal) = goal_for_urgency(source, value, plan_memory.as_ref(), mind) else {
                 continue;
             };
-            if !plan_memory.needs_replan_for_urgency(source) {
+            if !plan_memory.needs_replan_for_urgency(source)
+                || (combining && plan_memory.covers_goal(&goal))
+            {
                 continue;
             }
-            let base_interval = ns_config.thinking_interval;
+            // Aroused agents think (and so retry planning) more often.
+            let base_interval = if cns.thinking_interval > 0 {
+                cns.thinking_interval
+            } else {
+                ns_config.thinking_interval
+            };
             let scaled_interval =
                 (base_interval as f32 * (1.0 - value).clamp(0.1, 1.0)).round() as u64;
             let cooldown_ok = plan_memory
@@ -616,7 +705,37 @@ pub fn update_rational_planning(
                 continue;
             }
 
-            let action_candidates = collect_planning_actions(
+            // Hungry and exhausted at once: plan for both in one search so
+            // the agent doesn't flip between two single-drive plans.
+            let companions: Vec<(UrgencySource, Goal)> = if combining {
+                urgencies_snapshot
+                    .iter()
+                    .filter(|(other, v)| {
+                        *other != source
+                            && *v >= PLAN_GENERATION_MIN_URGENCY
+                            && !covered.contains(other)
+                            && plan_memory.needs_replan_for_urgency(*other)
+                    })
+                    .filter_map(|&(other, v)| {
+                        goal_for_urgency(other, v, plan_memory.as_ref(), mind)
+                            .filter(|g| !plan_memory.covers_goal(g))
+                            .map(|g| (other, g))
+                    })
+                    .take(planner_config.max_combined_goals - 1)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let goals: Vec<Goal> = std::iter::once(goal)
+                .chain(companions.iter().map(|(_, g)| g.clone()))
+                .collect();
+            let goal = combined_goal(&goals);
+            for (other, _) in &companions {
+                plan_memory.last_plan_attempt.insert(*other, current_tick);
+            }
+
+            let cached_target = plan_memory.cached_target(source, mind);
+            let mut action_candidates = collect_planning_actions(
                 &action_registry,
                 mind,
                 transform.translation.truncate(),
@@ -627,6 +746,8 @@ pub fn update_rational_planning(
                 &capacities,
                 physical,
                 inventory,
+                skills,
+                cached_target,
             );
 
             // Emit TargetEnumerated for each surviving (action, target) pair.
@@ -648,13 +769,34 @@ pub fn update_rational_planning(
                 ));
             }
 
-            let actions: Vec<crate::agent::brains::thinking::ActionTemplate> =
+            let mut actions: Vec<crate::agent::brains::thinking::ActionTemplate> =
                 action_candidates.into_iter().map(|(t, _)| t).collect();
 
-            plan_attempts += 1;
-            plan_memory.plans_generated_total += 1;
             plan_memory.last_plan_attempt.insert(source, current_tick);
 
+            // Nothing the last search for this urgency read has changed:
+            // reuse its plan instead of searching again.
+            let goal_hash = crate::agent::brains::planner::goal_hash(&goal);
+            let mut mind_hash = crate::agent::brains::planner::mind_snapshot_hash(
+                mind,
+                Some(inventory),
+                &goal,
+                &actions,
+            );
+            let cached_steps = plan_memory.cached_plan(source, goal_hash, mind_hash);
+            let cache_hit = cached_steps.is_some();
+            if let Some(diagnostics) = diagnostics.as_mut() {
+                if cache_hit {
+                    diagnostics.plan_cache_hits += 1;
+                } else {
+                    diagnostics.plan_cache_misses += 1;
+                }
+            }
+            if !cache_hit {
+                plan_attempts += 1;
+                plan_memory.plans_generated_total += 1;
+            }
+
             if perf_logging && actions.len() > 20 {
                 let action_names: Vec<String> = actions.iter().map(|a| a.name.clone()).collect();
                 game_log.performance(format!(
@@ -668,14 +810,17 @@ pub fn update_rational_planning(
 
             // GOAP search drains alertness. Curious (high-openness)
             // agents pay less. The cooldown gate above ensures this
-            // drain fires at most once per interval per urgency.
-            let openness_relief = personality.traits.openness()
-                * crate::constants::brains::cognition::OPENNESS_PLANNING_RELIEF;
-            let plan_drain = crate::constants::brains::rational::PLAN_GENERATION_ALERTNESS_DRAIN
-                * (1.0 - openness_relief);
-            consciousness.alertness = (consciousness.alertness - plan_drain).max(0.0);
-
-            let cost_ctx = crate::agent::brains::planner::PlanCostContext::from_agent(
+            // drain fires at most once per interval per urgency; a
+            // cached plan costs no search and so no drain.
+            if !cache_hit {
+                let openness_relief = personality.traits.openness()
+                    * crate::constants::brains::cognition::OPENNESS_PLANNING_RELIEF;
+                let plan_drain = crate::constants::brains::rational::PLAN_GENERATION_ALERTNESS_DRAIN
+                    * (1.0 - openness_relief);
+                consciousness.alertness = (consciousness.alertness - plan_drain).max(0.0);
+            }
+
+            let mut cost_ctx = crate::agent::brains::planner::PlanCostContext::from_agent(
                 physical,
                 &consciousness,
                 personality,
@@ -683,30 +828,96 @@ pub fn update_rational_planning(
                 body,
                 tick.current,
             );
+            cost_ctx.heuristic_weight = planner_config.heuristic_weight;
             let goal_desc = format!("{:?}", goal.conditions);
-            let (plan_result, search_stats) = crate::agent::brains::planner::regressive_plan(
-                mind,
-                Some(inventory),
-                &world_positions,
-                &goal,
-                &actions,
-                &cost_ctx,
-            );
+            let (mut plan_result, mut search_stats) = match cached_steps {
+                Some(steps) => (
+                    Some(steps),
+                    crate::agent::brains::planner::PlanSearchStats {
+                        iterations: 0,
+                        exhausted: false,
+                        best_unmet_goals: Vec::new(),
+                        elapsed: std::time::Duration::ZERO,
+                    },
+                ),
+                None => crate::agent::brains::planner::regressive_plan_multi(
+                    mind,
+                    Some(inventory),
+                    &world_positions,
+                    Some(&*world_map),
+                    &goals,
+                    &actions,
+                    &cost_ctx,
+                    &planner_config,
+                ),
+            };
+            // The remembered target no longer gets us there — forget it
+            // and search again with every known candidate.
+            if plan_result.is_none() && cached_target.is_some() {
+                plan_memory.target_cache.remove(&source);
+                action_candidates = collect_planning_actions(
+                    &action_registry,
+                    mind,
+                    transform.translation.truncate(),
+                    &world_map,
+                    species,
+                    &affordances,
+                    PlanningMode::Generate,
+                    &capacities,
+                    physical,
+                    inventory,
+                    skills,
+                    None,
+                );
+                actions = action_candidates.into_iter().map(|(t, _)| t).collect();
+                mind_hash = crate::agent::brains::planner::mind_snapshot_hash(
+                    mind,
+                    Some(inventory),
+                    &goal,
+                    &actions,
+                );
+                (plan_result, search_stats) = crate::agent::brains::planner::regressive_plan_multi(
+                    mind,
+                    Some(inventory),
+                    &world_positions,
+                    Some(&*world_map),
+                    &goals,
+                    &actions,
+                    &cost_ctx,
+                    &planner_config,
+                );
+            }
+
+            if search_stats.elapsed.as_millis() > 1 {
+                game_log.performance(format!(
+                    "[RegressivePlanner] Ent {:?} plan took {:?} ({} iterations, {} explicit actions)",
+                    entity,
+                    search_stats.elapsed,
+                    search_stats.iterations,
+                    actions.len()
+                ));
+            }
 
             // Emit GOAP search telemetry.
-            sim_events.write(crate::agent::events::SimEvent::single(
-                current_tick,
-                entity,
-                SimEventKind::GoapSearchTelemetry {
-                    agent: entity,
-                    goal_description: goal_desc.clone(),
-                    iterations: search_stats.iterations,
-                    exhausted: search_stats.exhausted,
-                    best_unmet_goals: search_stats.best_unmet_goals.clone(),
-                },
-            ));
+            if !cache_hit {
+                sim_events.write(crate::agent::events::SimEvent::single(
+                    current_tick,
+                    entity,
+                    SimEventKind::GoapSearchTelemetry {
+                        agent: entity,
+                        goal_description: goal_desc.clone(),
+                        iterations: search_stats.iterations,
+                        exhausted: search_stats.exhausted,
+                        best_unmet_goals: search_stats.best_unmet_goals.clone(),
+                    },
+                ));
+            }
 
             if let Some(steps) = plan_result {
+                covered.extend(companions.iter().map(|(other, _)| *other));
+                if !cache_hit {
+                    plan_memory.cache_plan(source, goal_hash, mind_hash, steps.clone());
+                }
                 let agent_pos = transform.translation.truncate();
 
                 if !crate::agent::brains::planner::check_plan_feasibility(
@@ -831,6 +1042,7 @@ pub fn rational_brain_propose(
     cns: &crate::agent::nervous_system::cns::CentralNervousSystem,
     mind: &MindGraph,
     action_registry: &crate::agent::actions::ActionRegistry,
+    target_components: impl Fn(Entity) -> Option<TargetComponents>,
 ) -> Vec<BrainProposal> {
     let mut out: Vec<BrainProposal> = Vec::new();
     for plan in plan_memory.in_state(PlanState::Executing) {
@@ -840,6 +1052,9 @@ pub fn rational_brain_propose(
         if !are_preconditions_met(action, mind) {
             continue;
         }
+        if !step_target_admitted(action, action_registry, &target_components) {
+            continue;
+        }
         // Urgency score comes from the driving urgency's *current*
         // value in the CNS list (not the stale priority captured at
         // creation time). That way a plan's score tracks how bad the
@@ -923,6 +1138,25 @@ pub fn rational_brain_propose(
     Vec::new()
 }
 
+/// Whether a plan step's target entity still admits the step's action, by
+/// the same rule `enumerate_targets` used to pick it — see
+/// [`TargetComponents::admits`]. Steps without an entity target pass, as
+/// do targets that have left the world (execution reports those as
+/// `TargetGone`).
+fn step_target_admitted(
+    step: &ActionTemplate,
+    action_registry: &crate::agent::actions::ActionRegistry,
+    target_components: impl Fn(Entity) -> Option<TargetComponents>,
+) -> bool {
+    let Some(entity) = step.target_entity else {
+        return true;
+    };
+    let Some(action) = action_registry.get(step.action_type) else {
+        return true;
+    };
+    target_components(entity).is_none_or(|c| c.admits(&action.target_source(), step.action_type))
+}
+
 /// Gating policy used by `collect_planning_actions`. Unifies the old
 /// belief-confidence filter with each action's own `is_plan_valid`:
 /// a candidate is kept if *either* check is satisfied. The
@@ -959,6 +1193,41 @@ impl TargetInclusionReason {
     }
 }
 
+/// Whether `action` belongs in the agent's planning set at all, before any
+/// target is considered. Every check here is about the agent and holds for
+/// the length of a plan: the body can perform it, the need it serves isn't
+/// already met, the diet allows it, and the agent has the skill it takes.
+fn action_is_plannable(
+    action: &dyn crate::agent::actions::Action,
+    capacities: &ChannelCapacities,
+    physical: &PhysicalNeeds,
+    inventory: &crate::agent::item_slots::ItemSlots,
+    species: Option<&crate::agent::body::species::SpeciesProfile>,
+    skills: Option<&crate::agent::skills::Skills>,
+) -> bool {
+    if !action_is_anatomically_feasible(action.body_channels(), capacities) {
+        return false;
+    }
+
+    // Plan-time satiation filter — see `Action::is_plan_time_viable`.
+    if !action.is_plan_time_viable(Some(physical), Some(inventory)) {
+        return false;
+    }
+
+    // Plan-time diet filter. Empty `eligible_diets` = unrestricted.
+    let eligible = action.eligible_diets();
+    if !eligible.is_empty() && !species.is_some_and(|s| eligible.contains(&s.diet)) {
+        return false;
+    }
+
+    // Skill filter — see `Action::required_skill`. Practice can't happen
+    // mid-plan, so an unskilled agent never gets to lean on the action.
+    action.is_skilled_enough(skills)
+}
+
+/// `cached` is a target a previous plan for the same urgency finished
+/// against. When present, its action is specialized to that one entity
+/// instead of rescanning every known candidate.
 fn collect_planning_actions(
     action_registry: &crate::agent::actions::ActionRegistry,
     mind: &MindGraph,
@@ -974,35 +1243,44 @@ fn collect_planning_actions(
     capacities: &ChannelCapacities,
     physical: &PhysicalNeeds,
     inventory: &crate::agent::item_slots::ItemSlots,
+    skills: Option<&crate::agent::skills::Skills>,
+    cached: Option<CachedTarget>,
 ) -> Vec<(ActionTemplate, TargetInclusionReason)> {
     let mut actions = Vec::new();
     let belief_state = crate::agent::mind::belief_state::BeliefState::new(mind);
 
     for action in action_registry.all() {
-        if !action_is_anatomically_feasible(action.body_channels(), capacities) {
-            continue;
-        }
-
-        // Plan-time satiation filter — see `Action::is_plan_time_viable`.
-        if !action.is_plan_time_viable(Some(physical), Some(inventory)) {
-            continue;
-        }
-
-        // Plan-time diet filter. Empty `eligible_diets` = unrestricted.
-        let eligible = action.eligible_diets();
-        if !eligible.is_empty() && !species.is_some_and(|s| eligible.contains(&s.diet)) {
+        if !action_is_plannable(action, capacities, physical, inventory, species, skills) {
             continue;
         }
 
         let source = action.target_source();
-        for candidate in enumerate_targets(
-            &source,
-            action.action_type(),
-            mind,
-            agent_pos,
-            world_map,
-            affordances,
-        ) {
+        let cached_candidate = cached
+            .filter(|c| c.action_type == action.action_type())
+            .and_then(|c| {
+                let pos = admissible_target_position(
+                    c.entity,
+                    &source,
+                    action.action_type(),
+                    affordances,
+                )?;
+                Some(TargetCandidate::Entity {
+                    entity: c.entity,
+                    pos,
+                })
+            });
+        let candidates = match cached_candidate {
+            Some(candidate) => vec![candidate],
+            None => enumerate_targets(
+                &source,
+                action.action_type(),
+                mind,
+                agent_pos,
+                world_map,
+                affordances,
+            ),
+        };
+        for candidate in candidates {
             let reason = match mode {
                 PlanningMode::Generate => {
                     if action.is_plan_valid(&candidate, mind) {
@@ -1142,8 +1420,13 @@ mod tests {
             template("WalkToApple", ActionType::Walk),
         );
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert_eq!(proposals.len(), 1);
         assert_eq!(proposals[0].brain, BrainType::Rational);
@@ -1157,8 +1440,13 @@ mod tests {
         cns.urgencies.push(Urgency::new(UrgencySource::Social, 0.8));
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert!(
             proposals.is_empty(),
@@ -1171,8 +1459,13 @@ mod tests {
         let cns = cns_with_hunger(1.0);
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert_eq!(proposals.len(), 1);
         assert_eq!(
@@ -1195,8 +1488,13 @@ mod tests {
         let cns = cns_with_hunger(weak);
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert!(
             proposals.is_empty(),
@@ -1209,8 +1507,13 @@ mod tests {
         let cns = cns_with_hunger(PLAN_GENERATION_MIN_URGENCY);
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert_eq!(
             proposals.len(),
@@ -1233,8 +1536,13 @@ mod tests {
             template("WalkToApple", ActionType::Walk),
         );
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert_eq!(
             proposals.len(),
@@ -1261,8 +1569,13 @@ mod tests {
         let cns = cns_with_hunger(1.0);
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert!(
             proposals
@@ -1278,8 +1591,13 @@ mod tests {
         let cns = CentralNervousSystem::default();
         let memory = PlanMemory::default();
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert!(
             proposals.is_empty(),
@@ -1303,8 +1621,13 @@ mod tests {
             template("Converse", ActionType::Converse),
         );
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         assert_eq!(
             proposals.len(),
@@ -1459,8 +1782,13 @@ mod tests {
             current_step: 0,
         });
 
-        let proposals =
-            rational_brain_propose(&memory, &cns, &MindGraph::default(), &test_registry());
+        let proposals = rational_brain_propose(
+            &memory,
+            &cns,
+            &MindGraph::default(),
+            &test_registry(),
+            |_| None,
+        );
 
         // Background plans don't propose; the LookFor fallback fires
         // because hunger has no executing plan.
@@ -1508,4 +1836,103 @@ mod tests {
             "human has no Bite channel; Bite must be rejected"
         );
     }
+
+    #[test]
+    fn entity_without_matching_affordance_is_excluded_from_both_paths() {
+        use crate::agent::affordance::Affordance;
+        use crate::agent::mind::knowledge::Triple;
+        use bevy::ecs::system::SystemState;
+
+        let mut world = World::new();
+        let bush = world
+            .spawn((
+                GlobalTransform::default(),
+                Affordance {
+                    action_type: ActionType::Harvest,
+                    cost: 1.0,
+                    distance: 16.0,
+                    risk: 0.0,
+                },
+            ))
+            .id();
+        // Believed to be a bush, but nothing in the world lets it be harvested.
+        let decoy = world.spawn(GlobalTransform::default()).id();
+        let mut mind = MindGraph::default();
+        for entity in [bush, decoy] {
+            mind.assert(Triple::new(
+                MindNode::Entity(entity),
+                Predicate::IsA,
+                Value::Concept(Concept::BerryBush),
+            ));
+        }
+
+        let mut state = SystemState::<
+            Query<(
+                &GlobalTransform,
+                Option<&Affordance>,
+                Option<&crate::agent::Dead>,
+            )>,
+        >::new(&mut world);
+        let affordances = state.get(&world);
+
+        let candidates = enumerate_targets(
+            &crate::agent::actions::TargetSource::EntityAffordance,
+            ActionType::Harvest,
+            &mind,
+            Vec2::ZERO,
+            &WorldMap::new(8, 8),
+            &affordances,
+        );
+        let targets: Vec<_> = candidates.iter().filter_map(|c| c.as_entity()).collect();
+        assert_eq!(targets, vec![bush]);
+
+        let registry = ActionRegistry::new();
+        let propose_harvest = |target: Entity| {
+            let mut memory = PlanMemory::default();
+            let mut step = template("Harvest", ActionType::Harvest);
+            step.target_entity = Some(target);
+            executing_plan(&mut memory, hunger_goal(1.0), step);
+            rational_brain_propose(&memory, &cns_with_hunger(1.0), &mind, &registry, |e| {
+                affordances
+                    .get(e)
+                    .ok()
+                    .map(|(_, affordance, dead)| TargetComponents::new(affordance, dead))
+            })
+        };
+        assert_eq!(propose_harvest(bush).len(), 1);
+        assert!(propose_harvest(decoy).is_empty());
+    }
+
+    #[test]
+    fn skill_gated_action_is_plannable_only_once_the_skill_is_learned() {
+        use crate::agent::skills::{SkillKind, Skills};
+        use crate::constants::actions::build::HOUSE_MIN_BUILDING_SKILL;
+
+        let registry = ActionRegistry::new();
+        let build_house = registry.get(ActionType::BuildHouse).unwrap();
+        let human = crate::agent::body::species::SpeciesProfile::human();
+        let inventory = crate::agent::item_slots::ItemSlots::agent_carry();
+        let physical = PhysicalNeeds::default();
+        let plannable = |skills: Option<&Skills>| {
+            action_is_plannable(
+                build_house,
+                &ChannelCapacities::full(),
+                &physical,
+                &inventory,
+                Some(&human),
+                skills,
+            )
+        };
+
+        let mut skills = Skills::default();
+        assert!(!plannable(None), "no Skills component, no house");
+        assert!(!plannable(Some(&skills)), "a novice can't plan a house");
+
+        // Lean-to labor trains Building and stays plannable throughout.
+        let lean_to = registry.get(ActionType::BuildLeanTo).unwrap();
+        assert!(lean_to.is_skilled_enough(Some(&skills)));
+
+        skills.set_level(SkillKind::Building, HOUSE_MIN_BUILDING_SKILL, 0);
+        assert!(plannable(Some(&skills)), "a practised builder can");
+    }
 }
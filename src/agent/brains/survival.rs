@@ -1,6 +1,7 @@
 //! Survival brain: reflexive responses to physical threats and urgent needs.
 //!
-//! Reads: PhysicalNeeds, CentralNervousSystem (urgencies), ItemSlots, ActiveActions, WorldMap, Transform
+//! Reads: PhysicalNeeds, CentralNervousSystem (urgencies), ItemSlots, ActiveActions, WorldMap, Transform,
+//!        VisibleObjects + MindGraph (food lying in view)
 //! Writes: BrainProposal
 //! Upstream: nervous_system::urgency (produces urgency scores), item_slots
 //! Downstream: brains::proposal (winner selection)
@@ -24,6 +25,30 @@ pub struct SurvivalBrainContext<'a> {
     pub most_feared_entity: Option<Entity>,
     pub pos: Vec2,
     pub world_map: &'a WorldMap,
+    /// The agent can see a ground item it believes holds something edible.
+    /// Lets a hungry agent with full hands drop something to make room.
+    pub food_in_view: bool,
+}
+
+/// True when a visible `GroundItem` is believed to hold edible Things.
+pub fn food_in_view(
+    visible: &crate::agent::mind::perception::VisibleObjects,
+    mind: &crate::agent::mind::knowledge::MindGraph,
+    ontology: &Ontology,
+) -> bool {
+    use crate::agent::mind::knowledge::{Concept, Node, Predicate, Value};
+
+    let Some(items) = visible.by_concept.get(&Concept::GroundItem) else {
+        return false;
+    };
+    items.iter().any(|item| {
+        mind.query(Some(&Node::Entity(*item)), Some(Predicate::Contains), None)
+            .iter()
+            .any(|t| {
+                matches!(t.object, Value::Item(concept, n)
+                    if n > 0 && ontology.has_trait(concept, Concept::Edible))
+            })
+    })
 }
 
 /// Propose a survival action based on the highest urgency drive.
@@ -100,6 +125,24 @@ fn propose_for_source(
                     reasoning: format!("Hunger urgency {:.2} — eating!", value),
                 });
             }
+            // Nothing to eat in hand and no room to pick food up: set the
+            // least valuable stack down first.
+            if !inventory.has_edible(ontology)
+                && inventory.is_full()
+                && context.food_in_view
+                && let Some(action) = action_registry.get(ActionType::Drop)
+            {
+                return Some(BrainProposal {
+                    brain: BrainType::Survival,
+                    action: action.to_template(None),
+                    urgency: urgency_score,
+                    intent,
+                    reasoning: format!(
+                        "Hunger urgency {:.2} — hands full, dropping something for food.",
+                        value
+                    ),
+                });
+            }
         }
         UrgencySource::Thirst => {
             if is_adjacent_to_water(context.pos, context.world_map)
@@ -139,6 +182,20 @@ fn propose_for_source(
                 });
             }
         }
+        UrgencySource::Recovery => {
+            // Only sit down to heal when nothing is there to run from.
+            if context.most_feared_entity.is_none()
+                && let Some(action) = action_registry.get(ActionType::Sit)
+            {
+                return Some(BrainProposal {
+                    brain: BrainType::Survival,
+                    action: action.to_template(None),
+                    urgency: urgency_score,
+                    intent,
+                    reasoning: format!("Recovery urgency {:.2} — resting to heal.", value),
+                });
+            }
+        }
         UrgencySource::Fear => {
             if let Some(action) = action_registry.get(ActionType::Flee) {
                 return Some(BrainProposal {
@@ -265,6 +322,7 @@ mod tests {
             most_feared_entity: None,
             pos,
             world_map,
+            food_in_view: false,
         }
     }
 
@@ -324,6 +382,58 @@ mod tests {
         assert!(find_proposal(&proposals, ActionType::Eat).is_some());
     }
 
+    #[test]
+    fn injured_agent_sits_to_recover_only_when_no_threat_is_in_view() {
+        let ontology = setup_ontology();
+        let physical = PhysicalNeeds::full();
+        let cns = cns_with_top(UrgencySource::Recovery, 0.7);
+        let map = no_water_map();
+        let inventory = crate::agent::item_slots::ItemSlots::agent_carry();
+        let active = ActiveActions::default();
+
+        let mut registry = crate::agent::actions::ActionRegistry::default();
+        registry.register_def(&crate::agent::actions::action::SIT_DEF);
+
+        let context = context_with_urgency(&physical, &cns, Vec2::ZERO, &map);
+        let proposals = survival_brain_propose(context, &inventory, &active, &ontology, &registry);
+        let sit = find_proposal(&proposals, ActionType::Sit).expect("safe agent sits to heal");
+        assert_eq!(sit.intent, Intent::SatisfyRecovery);
+
+        let mut context = context_with_urgency(&physical, &cns, Vec2::ZERO, &map);
+        context.most_feared_entity = Some(Entity::from_bits(99));
+        let proposals = survival_brain_propose(context, &inventory, &active, &ontology, &registry);
+        assert!(find_proposal(&proposals, ActionType::Sit).is_none());
+    }
+
+    #[test]
+    fn hungry_agent_with_full_hands_drops_when_food_in_view() {
+        use crate::agent::mind::knowledge::Concept;
+
+        let ontology = setup_ontology();
+        let physical = needy_for(UrgencySource::Hunger);
+        let cns = cns_with_top(UrgencySource::Hunger, 0.9);
+        let map = no_water_map();
+
+        let mut inventory = crate::agent::item_slots::ItemSlots::agent_carry();
+        inventory.slots[0].capacity = Some(3);
+        inventory.add(Concept::Stone, 3);
+        let active = ActiveActions::default();
+
+        let mut registry = crate::agent::actions::ActionRegistry::default();
+        registry.register_def(&crate::agent::actions::action::EAT_DEF);
+        registry.register_def(&crate::agent::actions::action::DROP_DEF);
+
+        let mut context = context_with_urgency(&physical, &cns, Vec2::ZERO, &map);
+        context.food_in_view = true;
+        let proposals = survival_brain_propose(context, &inventory, &active, &ontology, &registry);
+        assert!(find_proposal(&proposals, ActionType::Drop).is_some());
+
+        // No food around — nothing is worth dropping the stones for.
+        let context = context_with_urgency(&physical, &cns, Vec2::ZERO, &map);
+        let proposals = survival_brain_propose(context, &inventory, &active, &ontology, &registry);
+        assert!(find_proposal(&proposals, ActionType::Drop).is_none());
+    }
+
     #[test]
     fn hunger_with_full_stomach_does_not_propose_eat() {
         // Full stomach but low glucose/reserves: the urgency signal is
@@ -696,6 +806,7 @@ mod tests {
             most_feared_entity: Some(feared),
             pos: Vec2::ZERO,
             world_map: &map,
+            food_in_view: false,
         };
 
         let inventory = crate::agent::item_slots::ItemSlots::agent_carry();
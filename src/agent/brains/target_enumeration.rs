@@ -27,6 +27,56 @@ use crate::world::map::WorldMap;
 /// missing the river one tile past sight.
 const TILE_TRAIT_SEARCH_RADIUS: f32 = 256.0;
 
+/// The parts of a candidate entity's world state that decide whether an
+/// action can be performed against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetComponents {
+    /// Action declared by the entity's `Affordance`, if it has one.
+    pub affordance: Option<ActionType>,
+    pub dead: bool,
+}
+
+impl TargetComponents {
+    pub fn new(affordance: Option<&Affordance>, dead: Option<&Dead>) -> Self {
+        Self {
+            affordance: affordance.map(|a| a.action_type),
+            dead: dead.is_some(),
+        }
+    }
+
+    /// Whether an action whose targets come from `source` can act on this
+    /// entity. Shared by candidate generation (`enumerate_targets`) and
+    /// proposal validation (`rational_brain_propose`) so the planner never
+    /// commits to a target the proposal path would refuse.
+    ///
+    /// Trait- and type-based sources ignore the `Affordance` (prey-ness
+    /// lives in beliefs, and a chest's `Affordance` is claimed by `Take`)
+    /// but still gate on life: Bite/Attack want living targets, Devour
+    /// wants carrion.
+    pub fn admits(&self, source: &TargetSource, action_type: ActionType) -> bool {
+        match source {
+            TargetSource::EntityAffordance => self.affordance == Some(action_type),
+            TargetSource::EntityWithTrait(_) | TargetSource::EntityIsAConcept(_) => !self.dead,
+            TargetSource::DeadEntityWithTrait(_) => self.dead,
+            TargetSource::None | TargetSource::Implicit | TargetSource::TileWithTrait(_) => true,
+        }
+    }
+}
+
+/// Position of `entity` if it is still in the world and
+/// [`TargetComponents::admits`] it for `action_type` under `source`.
+pub fn admissible_target_position(
+    entity: Entity,
+    source: &TargetSource,
+    action_type: ActionType,
+    affordances: &Query<(&GlobalTransform, Option<&Affordance>, Option<&Dead>)>,
+) -> Option<Vec2> {
+    let (transform, affordance, dead) = affordances.get(entity).ok()?;
+    TargetComponents::new(affordance, dead)
+        .admits(source, action_type)
+        .then(|| transform.translation().truncate())
+}
+
 /// Resolve a `TargetSource` into the concrete list of candidates for a given
 /// action and agent mind.
 ///
@@ -56,17 +106,14 @@ pub fn enumerate_targets(
         TargetSource::EntityAffordance => {
             enumerate_entities_with_affordance(action_type, mind, affordances)
         }
-        TargetSource::EntityWithTrait(concept) => {
-            enumerate_entities_with_trait(*concept, mind, affordances, AliveOnly::Yes)
-        }
-        TargetSource::DeadEntityWithTrait(concept) => {
-            enumerate_entities_with_trait(*concept, mind, affordances, AliveOnly::No)
+        TargetSource::EntityWithTrait(concept) | TargetSource::DeadEntityWithTrait(concept) => {
+            enumerate_entities_with_trait(*concept, source, action_type, mind, affordances)
         }
         TargetSource::TileWithTrait(concept) => {
             enumerate_tiles_with_trait(*concept, agent_pos, world_map)
         }
         TargetSource::EntityIsAConcept(concept) => {
-            enumerate_entities_isa_concept(*concept, mind, affordances)
+            enumerate_entities_isa_concept(*concept, action_type, mind, affordances)
         }
     }
 }
@@ -77,6 +124,7 @@ pub fn enumerate_targets(
 /// `StorageChest`, but the chest's `Affordance` is reserved for `Take`).
 fn enumerate_entities_isa_concept(
     concept: Concept,
+    action_type: ActionType,
     mind: &MindGraph,
     affordances: &Query<(&GlobalTransform, Option<&Affordance>, Option<&Dead>)>,
 ) -> Vec<TargetCandidate> {
@@ -90,16 +138,12 @@ fn enumerate_entities_isa_concept(
         if !seen.insert(entity) {
             continue;
         }
-        let Ok((transform, _, dead)) = affordances.get(entity) else {
+        let source = TargetSource::EntityIsAConcept(concept);
+        let Some(pos) = admissible_target_position(entity, &source, action_type, affordances)
+        else {
             continue;
         };
-        if dead.is_some() {
-            continue;
-        }
-        candidates.push(TargetCandidate::Entity {
-            entity,
-            pos: transform.translation().truncate(),
-        });
+        candidates.push(TargetCandidate::Entity { entity, pos });
     }
 
     candidates
@@ -132,16 +176,15 @@ fn enumerate_entities_with_affordance(
         if !seen.insert(entity) {
             return;
         }
-        let Ok((transform, Some(affordance), _dead)) = affordances.get(entity) else {
+        let Some(pos) = admissible_target_position(
+            entity,
+            &TargetSource::EntityAffordance,
+            action_type,
+            affordances,
+        ) else {
             return;
         };
-        if affordance.action_type != action_type {
-            return;
-        }
-        candidates.push(TargetCandidate::Entity {
-            entity,
-            pos: transform.translation().truncate(),
-        });
+        candidates.push(TargetCandidate::Entity { entity, pos });
     };
 
     for triple in mind.query(None, Some(Predicate::Contains), None) {
@@ -159,16 +202,6 @@ fn enumerate_entities_with_affordance(
     candidates
 }
 
-/// Whether a trait-based enumerator should keep dead entities. Bite/Attack
-/// pass `Yes` (corpses are filtered out — a dead deer is not exhibiting
-/// Prey-hood). Devour passes `No` (corpses are exactly the targets — Carrion
-/// implies dead).
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum AliveOnly {
-    Yes,
-    No,
-}
-
 /// Iterate every perceived entity (anything the mind has an `IsA` belief
 /// about) and keep the ones whose ontology trait inheritance includes
 /// `trait_concept`.
@@ -178,16 +211,18 @@ enum AliveOnly {
 /// `mind.has_trait` walks the IsA chain to discover that
 /// `(Deer, HasTrait, Prey)` lives in cultural/intrinsic knowledge.
 ///
-/// `alive_only` controls the Dead-marker filter. Most trait-based actions
-/// want living targets — a corpse still carries `IsA Deer` in observer
-/// minds until belief invalidation (#524) lands, but a dead deer is not
-/// exhibiting Prey-hood in any actionable sense. Devour and other carrion-
-/// targeting actions invert this and want dead entities specifically.
+/// `source` controls the Dead-marker filter via [`TargetComponents::admits`].
+/// Most trait-based actions (`EntityWithTrait`) want living targets — a
+/// corpse still carries `IsA Deer` in observer minds until belief
+/// invalidation (#524) lands, but a dead deer is not exhibiting Prey-hood
+/// in any actionable sense. Devour and other carrion-targeting actions
+/// (`DeadEntityWithTrait`) invert this and want dead entities specifically.
 fn enumerate_entities_with_trait(
     trait_concept: Concept,
+    source: &TargetSource,
+    action_type: ActionType,
     mind: &MindGraph,
     affordances: &Query<(&GlobalTransform, Option<&Affordance>, Option<&Dead>)>,
-    alive_only: AliveOnly,
 ) -> Vec<TargetCandidate> {
     let mut candidates = Vec::new();
     let mut seen = std::collections::HashSet::new();
@@ -203,18 +238,10 @@ fn enumerate_entities_with_trait(
             continue;
         }
 
-        let Ok((transform, _, dead)) = affordances.get(entity) else {
+        let Some(pos) = admissible_target_position(entity, source, action_type, affordances) else {
             continue;
         };
-        match alive_only {
-            AliveOnly::Yes if dead.is_some() => continue,
-            AliveOnly::No if dead.is_none() => continue,
-            _ => {}
-        }
-        candidates.push(TargetCandidate::Entity {
-            entity,
-            pos: transform.translation().truncate(),
-        });
+        candidates.push(TargetCandidate::Entity { entity, pos });
     }
 
     // Predator preference: Lame prey first. Skip the sort entirely
@@ -152,8 +152,9 @@ pub enum SearchDomain {
     /// perception asserts a matching `Tile HasTrait` triple.
     WorldTile,
     /// Agent needs an entity whose concept/trait matches (prey, Person,
-    /// enemy). Like `WorldTile` but for moving things — LookFor wanders
-    /// until perception spots a match.
+    /// enemy). Like `WorldTile` but for moving things — LookFor heads for
+    /// the nearest `LastKnownAt` of a match first, then wanders until
+    /// perception spots one.
     WorldEntity,
 }
 
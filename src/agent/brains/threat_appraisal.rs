@@ -1,6 +1,6 @@
 //! Unified flight-vs-fight threat appraisal.
 //!
-//! Reads: PhysicalNeeds, Body, Personality, Cornered (component)
+//! Reads: PhysicalNeeds, Body, Personality, Cornered (component), SocialGraph power balance
 //! Writes: ThreatResponse (returned by [`appraise_threat`])
 //! Upstream: brains::emotional (consumes appraisal output)
 //! Downstream: brains::emotional proposal layer
@@ -12,9 +12,16 @@ use crate::agent::psyche::personality::PersonalityTraits;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ThreatResponse {
-    Flee { urgency: f32 },
+    Flee {
+        urgency: f32,
+    },
     StandGround,
-    Fight { commitment: f32 },
+    Fight {
+        commitment: f32,
+    },
+    /// Would have fought, but the attacker outranks the defender too
+    /// clearly to raise a hand against.
+    Submit,
 }
 
 pub struct ThreatAppraisalContext<'a> {
@@ -24,6 +31,10 @@ pub struct ThreatAppraisalContext<'a> {
     pub anger: f32,
     pub cornered: bool,
     pub attacker_body: Option<&'a Body>,
+    /// Defender's `power_balance` toward the attacker from the social
+    /// graph: -1.0 = subordinate, +1.0 = dominant, 0.0 for strangers and
+    /// non-agents.
+    pub power_balance: f32,
     /// Stub. Wire up when the Kin/Ward relationship layer lands.
     pub dependents_nearby: u32,
     /// Stub. Wire up when the territoriality system lands.
@@ -47,6 +58,10 @@ const BOLDNESS_FIGHT_BONUS: f32 = 0.60;
 /// `defender_power > 0.05` so hungry deer don't attack wolves.
 const HUNGER_FIGHT_BONUS: f32 = 0.40;
 const CORNERED_FIGHT_BIAS_THRESHOLD: f32 = 0.30;
+/// Dominance over the attacker emboldens, subordination deflates.
+const POWER_BALANCE_FIGHT_BONUS: f32 = 0.40;
+/// Power balance at or below which a would-be fight becomes submission.
+const SUBMIT_POWER_BALANCE: f32 = -0.5;
 
 // ════════════════════════════════════════════════════════════════════════════
 // ENTRY POINT
@@ -79,7 +94,8 @@ pub fn appraise_threat(ctx: &ThreatAppraisalContext) -> ThreatResponse {
         + PRIOR_EXPERIENCE_FIGHT_BONUS * ctx.prior_experience
         + (boldness - 0.5) * BOLDNESS_FIGHT_BONUS
         + desperation * 0.20
-        + hunger * HUNGER_FIGHT_BONUS;
+        + hunger * HUNGER_FIGHT_BONUS
+        + ctx.power_balance.clamp(-1.0, 1.0) * POWER_BALANCE_FIGHT_BONUS;
 
     if ctx.on_home_turf {
         fight_bias += HOME_TURF_FIGHT_BONUS;
@@ -91,7 +107,7 @@ pub fn appraise_threat(ctx: &ThreatAppraisalContext) -> ThreatResponse {
     if ctx.cornered {
         if fight_bias > CORNERED_FIGHT_BIAS_THRESHOLD {
             let commitment = (fight_bias + 0.5).clamp(0.3, 1.0);
-            return ThreatResponse::Fight { commitment };
+            return fight_or_submit(ctx, commitment);
         }
         return ThreatResponse::StandGround;
     }
@@ -101,7 +117,7 @@ pub fn appraise_threat(ctx: &ThreatAppraisalContext) -> ThreatResponse {
     let effective_ratio = power_ratio + fight_bias;
     if effective_ratio >= POWER_RATIO_FIGHT_DEFAULT && defender_power > 0.05 {
         let commitment = (effective_ratio / (POWER_RATIO_FIGHT_DEFAULT * 1.5)).clamp(0.3, 1.0);
-        return ThreatResponse::Fight { commitment };
+        return fight_or_submit(ctx, commitment);
     }
 
     // Flee — urgency rises with how outmatched and how desperate.
@@ -110,6 +126,16 @@ pub fn appraise_threat(ctx: &ThreatAppraisalContext) -> ThreatResponse {
     ThreatResponse::Flee { urgency }
 }
 
+/// A clearly subordinate defender submits instead of fighting, however
+/// angry or cornered it is.
+fn fight_or_submit(ctx: &ThreatAppraisalContext, commitment: f32) -> ThreatResponse {
+    if ctx.power_balance <= SUBMIT_POWER_BALANCE {
+        ThreatResponse::Submit
+    } else {
+        ThreatResponse::Fight { commitment }
+    }
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 // INPUT REDUCTIONS
 // ════════════════════════════════════════════════════════════════════════════
@@ -171,6 +197,7 @@ mod tests {
             anger: 0.0,
             cornered: false,
             attacker_body: None,
+            power_balance: 0.0,
             dependents_nearby: 0,
             on_home_turf: false,
             prior_experience: 0.0,
@@ -228,7 +255,9 @@ mod tests {
         };
         let with_dep_flee_urgency = match with_dependents {
             ThreatResponse::Flee { urgency } => urgency,
-            ThreatResponse::Fight { .. } | ThreatResponse::StandGround => 0.0,
+            ThreatResponse::Fight { .. } | ThreatResponse::StandGround | ThreatResponse::Submit => {
+                0.0
+            }
         };
         assert!(
             with_dep_flee_urgency < baseline_flee_urgency
@@ -284,6 +313,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn subordinate_agent_submits_where_an_equal_would_fight() {
+        let physical = PhysicalNeeds::default();
+        let mut ctx = ctx_template(&physical);
+        ctx.cornered = true;
+        ctx.anger = 1.0;
+        assert!(matches!(
+            appraise_threat(&ctx),
+            ThreatResponse::Fight { .. }
+        ));
+
+        ctx.power_balance = -0.8;
+        assert_eq!(appraise_threat(&ctx), ThreatResponse::Submit);
+    }
+
+    #[test]
+    fn dominance_tips_a_borderline_agent_into_fighting() {
+        let physical = PhysicalNeeds::default();
+        let mut ctx = ctx_template(&physical);
+        // Parity plus a little anger sits just under the fight threshold.
+        ctx.anger = 0.2;
+        assert!(matches!(appraise_threat(&ctx), ThreatResponse::Flee { .. }));
+        ctx.power_balance = 1.0;
+        assert!(matches!(
+            appraise_threat(&ctx),
+            ThreatResponse::Fight { .. }
+        ));
+    }
+
     #[test]
     fn boldness_score_scales_inversely_with_neuroticism() {
         use crate::agent::psyche::personality::{AgreeablenessFacets, NeuroticismFacets};
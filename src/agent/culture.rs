@@ -1,13 +1,35 @@
+//! Cultures: starting knowledge per culture, and how it drifts.
+//!
+//! Reads: Culture + MindGraph (per agent), CultureDriftConfig, TickCount
+//! Writes: each member's cultural `shared_knowledge` block, CultureDrift
+//! Upstream: spawners (`create_cultural_knowledge` seeds one shared Arc per
+//! culture), memory and hearsay (the individual beliefs that drift tallies)
+//! Downstream: every MindGraph read that falls through to shared knowledge
+//!
+//! A culture starts as a static block of `Source::Cultural` triples shared
+//! by Arc. `drift_cultures` lets it follow its people: a concept-level
+//! belief that most members hold confidently on their own is promoted into
+//! the block, and a promoted belief that almost nobody still holds is
+//! dropped again. Seed knowledge is tradition and never drops. A changed
+//! block is swapped in as a fresh Arc for every member, so they keep
+//! sharing one copy.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::agent::Agent;
 use crate::agent::actions::action::{
     BUILD_DEF, BUILD_HOUSE_DEF, BUILD_LEAN_TO_DEF, BUILD_STORAGE_CHEST_DEF, COOK_DEF,
 };
 use crate::agent::actions::definition::{ActionDefinition, Recipe};
 use crate::agent::mind::knowledge::{
-    Concept, MemoryType, Metadata, Node, Predicate, Quantity, Source, Triple, Value,
+    Concept, MemoryType, Metadata, MindGraph, Node, Predicate, Quantity, Source, Triple, Value,
 };
+use crate::core::tick::TickCount;
 use bevy::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
+#[reflect(Component)]
 pub enum Culture {
     #[default]
     Nomad,
@@ -136,6 +158,175 @@ fn recipe_assertions(recipe: &Recipe) -> Vec<(Predicate, Value)> {
     out
 }
 
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct CultureDriftConfig {
+    /// Ticks between drift passes.
+    pub interval_ticks: u64,
+    /// A belief joins the culture once more than this share of its members
+    /// hold it.
+    pub adopt_fraction: f32,
+    /// A member's belief only counts toward adoption at this confidence or
+    /// above.
+    pub min_confidence: f32,
+    /// A promoted belief is dropped once fewer than this share of members
+    /// still hold it.
+    pub drop_fraction: f32,
+    /// Cultures with fewer living members don't drift.
+    pub min_members: usize,
+}
+
+impl Default for CultureDriftConfig {
+    fn default() -> Self {
+        Self {
+            interval_ticks: 3600,
+            adopt_fraction: 0.5,
+            min_confidence: 0.7,
+            drop_fraction: 0.2,
+            min_members: 3,
+        }
+    }
+}
+
+/// Drift bookkeeping: when the last pass ran and which triples each
+/// culture has picked up from its members (the only ones it can drop).
+#[derive(Resource, Default)]
+pub struct CultureDrift {
+    pub last_run: u64,
+    pub promoted: HashMap<Culture, Vec<Triple>>,
+}
+
+fn same_fact(a: &Triple, b: &Triple) -> bool {
+    a.subject == b.subject && a.predicate == b.predicate && a.object == b.object
+}
+
+/// Index of the shared block carrying `mind`'s cultural knowledge.
+fn cultural_block(mind: &MindGraph) -> Option<usize> {
+    mind.shared_knowledge
+        .iter()
+        .position(|block| block.iter().any(|t| t.meta.source == Source::Cultural))
+}
+
+/// Local beliefs about kinds of things (not individuals) that a member
+/// holds on their own authority — what a culture could take up.
+fn drift_candidates<'a>(
+    mind: &'a MindGraph,
+    min_confidence: f32,
+) -> impl Iterator<Item = &'a Triple> {
+    mind.iter().filter(move |t| {
+        matches!(t.subject, Node::Concept(_) | Node::Area(_))
+            && !matches!(t.meta.source, Source::Intrinsic | Source::Cultural)
+            && t.meta.confidence >= min_confidence
+    })
+}
+
+/// Bevy system (FixedUpdate): every `interval_ticks`, promote widely held
+/// beliefs into each culture's shared block and drop promoted ones that
+/// have fallen out of use.
+pub fn drift_cultures(
+    config: Res<CultureDriftConfig>,
+    tick: Res<TickCount>,
+    mut drift: ResMut<CultureDrift>,
+    mut agents: Query<(Entity, &Culture, &mut MindGraph), With<Agent>>,
+) {
+    if tick.current < drift.last_run + config.interval_ticks {
+        return;
+    }
+    drift.last_run = tick.current;
+
+    let mut groups: HashMap<Culture, Vec<Entity>> = HashMap::new();
+    for (entity, culture, _) in agents.iter() {
+        groups.entry(*culture).or_default().push(entity);
+    }
+
+    for (culture, members) in groups {
+        if members.len() < config.min_members {
+            continue;
+        }
+        // (belief, members holding it, summed confidence)
+        let mut tally: Vec<(Triple, usize, f32)> = Vec::new();
+        let mut current: Option<Vec<Triple>> = None;
+        for &member in &members {
+            let Ok((_, _, mind)) = agents.get(member) else {
+                continue;
+            };
+            if current.is_none()
+                && let Some(index) = cultural_block(mind)
+            {
+                current = Some(mind.shared_knowledge[index].as_ref().clone());
+            }
+            for triple in drift_candidates(mind, config.min_confidence) {
+                match tally.iter_mut().find(|(t, _, _)| same_fact(t, triple)) {
+                    Some((_, holders, confidence)) => {
+                        *holders += 1;
+                        *confidence += triple.meta.confidence;
+                    }
+                    None => tally.push((triple.clone(), 1, triple.meta.confidence)),
+                }
+            }
+        }
+
+        let mut block = current.unwrap_or_default();
+        let population = members.len() as f32;
+        let support = |fact: &Triple| {
+            tally
+                .iter()
+                .find(|(t, _, _)| same_fact(t, fact))
+                .map_or(0, |(_, holders, _)| *holders) as f32
+                / population
+        };
+        let promoted = drift.promoted.entry(culture).or_default();
+        let mut changed = false;
+
+        promoted.retain(|fact| {
+            if support(fact) >= config.drop_fraction {
+                return true;
+            }
+            block.retain(|t| !same_fact(t, fact));
+            changed = true;
+            false
+        });
+
+        for (triple, holders, confidence) in &tally {
+            if *holders as f32 / population <= config.adopt_fraction
+                || block.iter().any(|t| same_fact(t, triple))
+            {
+                continue;
+            }
+            let adopted = Triple::with_meta(
+                triple.subject.clone(),
+                triple.predicate,
+                triple.object.clone(),
+                Metadata {
+                    source: Source::Cultural,
+                    memory_type: MemoryType::Cultural,
+                    timestamp: tick.current,
+                    confidence: confidence / *holders as f32,
+                    salience: 0.5,
+                    ..Default::default()
+                },
+            );
+            promoted.push(adopted.clone());
+            block.push(adopted);
+            changed = true;
+        }
+
+        if !changed {
+            continue;
+        }
+        let block = Arc::new(block);
+        for member in members {
+            let Ok((_, _, mut mind)) = agents.get_mut(member) else {
+                continue;
+            };
+            match cultural_block(&mind) {
+                Some(index) => mind.shared_knowledge[index] = block.clone(),
+                None => mind.add_shared_knowledge(block.clone()),
+            }
+        }
+    }
+}
+
 // Helper to convert ActionType to Node
 impl From<crate::agent::actions::ActionType> for Node {
     fn from(action: crate::agent::actions::ActionType) -> Self {
@@ -147,6 +338,7 @@ impl From<crate::agent::actions::ActionType> for Node {
 mod tests {
     use super::*;
     use crate::agent::mind::knowledge::setup_ontology;
+    use bevy::ecs::system::RunSystemOnce;
 
     fn contains(triples: &[Triple], subject: Node, predicate: Predicate, object: Value) -> bool {
         triples
@@ -247,4 +439,82 @@ mod tests {
             assert!(ontology.has_trait(concept, Concept::Harvestable));
         }
     }
+
+    #[test]
+    fn belief_held_by_most_of_a_culture_is_promoted_into_its_shared_block() {
+        let mut world = World::new();
+        world.insert_resource(TickCount {
+            current: 3600,
+            ..Default::default()
+        });
+        world.init_resource::<CultureDriftConfig>();
+        world.init_resource::<CultureDrift>();
+
+        let seed = Arc::new(create_cultural_knowledge(Culture::Nomad));
+        let pike_is_food = |mind: &MindGraph| {
+            mind.query(
+                Some(&Node::Concept(Concept::Pike)),
+                Some(Predicate::IsA),
+                Some(&Value::Concept(Concept::Food)),
+            )
+            .len()
+        };
+        let mut members = Vec::new();
+        for learned in [true, true, true, false] {
+            let mut mind = MindGraph::new(setup_ontology());
+            mind.add_shared_knowledge(seed.clone());
+            if learned {
+                let mut meta = Metadata::semantic(100);
+                meta.source = Source::Experienced;
+                meta.confidence = 0.9;
+                mind.assert(Triple::with_meta(
+                    Node::Concept(Concept::Pike),
+                    Predicate::IsA,
+                    Value::Concept(Concept::Food),
+                    meta,
+                ));
+            }
+            members.push(world.spawn((Agent, Culture::Nomad, mind)).id());
+        }
+        // A Farmer who never learned it doesn't dilute the Nomads' vote
+        // and doesn't pick up their culture.
+        let mut farmer_mind = MindGraph::new(setup_ontology());
+        farmer_mind.add_shared_knowledge(Arc::new(create_cultural_knowledge(Culture::Farmer)));
+        let farmer = world.spawn((Agent, Culture::Farmer, farmer_mind)).id();
+
+        world.run_system_once(drift_cultures).unwrap();
+
+        let newcomer = members[3];
+        let mind = world.get::<MindGraph>(newcomer).unwrap();
+        let block = &mind.shared_knowledge[cultural_block(mind).unwrap()];
+        assert!(
+            block
+                .iter()
+                .any(|t| t.subject == Node::Concept(Concept::Pike)
+                    && t.predicate == Predicate::IsA
+                    && t.meta.source == Source::Cultural),
+            "three of four Nomads know pike is food; the culture should too"
+        );
+        assert_eq!(
+            block.len(),
+            seed.len() + 1,
+            "seed knowledge is kept alongside the promoted belief"
+        );
+        assert!(
+            pike_is_food(mind) > 0,
+            "the one who never learned it now knows"
+        );
+        for &member in &members {
+            let other = world.get::<MindGraph>(member).unwrap();
+            assert!(Arc::ptr_eq(
+                &other.shared_knowledge[cultural_block(other).unwrap()],
+                block
+            ));
+        }
+        assert_eq!(
+            pike_is_food(world.get::<MindGraph>(farmer).unwrap()),
+            0,
+            "other cultures are untouched"
+        );
+    }
 }
@@ -103,6 +103,20 @@ pub const DRIVE_REGISTRY: &[DriveEntry] = &[
         }),
         display_name: "Pain",
     },
+    DriveEntry {
+        urgency: UrgencySource::Recovery,
+        need_kind: None,
+        intent: Intent::SatisfyRecovery,
+        // Proposed directly by the survival brain (Sit), which also owns
+        // the "only when safe" check — no NeedKind or planner goal.
+        satisfier: None,
+        satiation_threshold: 1.0,
+        // Below Fear: a wounded agent still runs from a predator first.
+        survival_weight: 45.0,
+        is_deprivation: false,
+        goal_pattern: None,
+        display_name: "Recovery",
+    },
     DriveEntry {
         urgency: UrgencySource::Warmth,
         need_kind: Some(NeedKind::Warmth),
@@ -277,6 +291,7 @@ mod tests {
             UrgencySource::Hunger,
             UrgencySource::Thirst,
             UrgencySource::Pain,
+            UrgencySource::Recovery,
             UrgencySource::Warmth,
             UrgencySource::RestQuality,
             UrgencySource::FoodSecurity,
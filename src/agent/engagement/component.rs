@@ -6,7 +6,7 @@ use bevy::prelude::*;
 
 use crate::agent::actions::types::ActionType;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect, serde::Serialize)]
 pub struct EngagementId(pub u64);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize)]
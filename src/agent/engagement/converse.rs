@@ -2,9 +2,14 @@
 //! per-tick lifecycle systems. Turn ownership lives on
 //! [`Conversation::turn`], never on a component flag, so there's no
 //! race possible.
+//!
+//! Everything here runs in a replay-stable order: conversations are kept
+//! in id order and same-tick initiations are handled by initiator entity,
+//! so a seeded run mints the same ids and produces the same turns.
 
 use bevy::ecs::world::EntityWorldMut;
 use bevy::prelude::*;
+use rand::Rng;
 use std::collections::HashSet;
 
 use super::component::{Engaged, EngagementEndReason, EngagementId, EngagementKind};
@@ -20,13 +25,17 @@ use crate::agent::brains::thinking::{Goal, TriplePattern};
 use crate::agent::events::{
     ConversationTopic, EngagementBeatPayload, FailureReason, GameEvent, SimEvent, SimEventKind,
 };
+use crate::agent::mind::deception;
+use crate::agent::mind::epistemic::location_gap;
+use crate::agent::mind::hearsay_intake::{HearsayIntake, HearsayIntakeConfig};
 use crate::agent::mind::knowledge::{Concept, Metadata, MindGraph, Node, Predicate, Triple, Value};
 use crate::agent::mind::social_perception::CONVERSATION_RANGE;
 use crate::agent::mind::theory_of_mind::{self, TheoryOfMind};
+use crate::agent::nervous_system::cns::CentralNervousSystem;
 use crate::agent::psyche::emotions::{Emotion, EmotionalState};
 use crate::agent::psyche::personality::Personality;
-use crate::core::not_paused;
 use crate::core::tick::TickCount;
+use crate::core::{SimRng, not_paused};
 
 // ============================================================================
 // Tunables
@@ -37,11 +46,55 @@ pub const STALE_CONVERSATION_TICKS: u64 = 500;
 pub const CHITCHAT_INTERVAL_TICKS: u64 = 30;
 pub const URGENT_INTERVAL_TICKS: u64 = 15;
 pub const FAREWELL_INTERVAL_TICKS: u64 = 15;
+/// Turns before an average pair starts wrapping up. Scaled by the
+/// participants' extraversion; see [`natural_end_turns`].
 pub const NATURAL_END_TURN_COUNT: usize = 6;
+/// Default hard cap on a conversation's length; see [`ConversationConfig`].
+pub const MAX_CONVERSATION_TURNS: usize = 12;
 pub const SOCIAL_DRIVE_PER_TURN: f32 = 0.03;
+/// Triples an average speaker shares per turn. Scaled by the speaker's
+/// extraversion; see [`triples_per_turn`].
 pub const SMALL_TALK_TRIPLES_PER_TURN: usize = 3;
+/// Agreeableness below which a listener pushes back on what was shared
+/// instead of going along with it.
+pub const DISAGREE_AGREEABLENESS: f32 = 0.3;
 pub const DANGER_WARN_SALIENCE: f32 = 0.7;
 pub const DANGER_RECENCY_TICKS: u64 = 600;
+/// Companionship at which a speaker has had enough company and says
+/// goodbye instead of carrying on.
+pub const FAREWELL_COMPANIONSHIP_SATISFIED: f32 = 0.95;
+/// Survival urgency that makes a speaker excuse themselves mid-chat.
+pub const FAREWELL_URGENT_NEED: f32 = 0.7;
+/// Valence each participant feels toward the others when a goodbye is
+/// returned and the conversation closes cleanly.
+pub const POLITE_EXIT_VALENCE: f32 = 0.2;
+/// Valence felt toward someone who walks off without a goodbye.
+pub const ABRUPT_EXIT_VALENCE: f32 = -0.4;
+/// Confidence of hearsay from a speaker the listener respects neutrally.
+/// Scales with respect: admired sources are believed more firmly.
+pub const HEARSAY_BASE_CONFIDENCE: f32 = 0.7;
+/// Hearsay never outranks first-hand knowledge, however admired the source.
+pub const HEARSAY_MAX_CONFIDENCE: f32 = 0.95;
+/// Listeners dismiss what they're told by anyone they respect less than this.
+pub const HEARSAY_DISMISS_RESPECT: f32 = 0.2;
+
+/// Conversation tunables that scenarios may override.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct ConversationConfig {
+    /// Turns after which the speaker says goodbye no matter how much
+    /// company either side still wants, so nobody is monopolized by an
+    /// endless chat. Counts every turn, greetings included.
+    pub max_turns: usize,
+}
+
+impl Default for ConversationConfig {
+    fn default() -> Self {
+        Self {
+            max_turns: MAX_CONVERSATION_TURNS,
+        }
+    }
+}
 
 // ============================================================================
 // Data types
@@ -81,6 +134,8 @@ pub struct Turn {
     pub content: Vec<Triple>,
     pub timestamp: u64,
     pub expects_response: bool,
+    /// The speaker falsified part of `content` (see `mind::deception`).
+    pub deceptive: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, serde::Serialize)]
@@ -136,6 +191,27 @@ impl Conversation {
         self.participants[self.turn]
     }
 
+    /// False when `turn` indexes past the participant list, so nobody
+    /// holds the turn and the conversation would stall until it goes stale.
+    pub fn has_turn_owner(&self) -> bool {
+        self.turn < self.participants.len()
+    }
+
+    /// Give an ownerless turn to a partner of whoever spoke last, so the
+    /// conversation can continue. Returns whether a repair was needed.
+    pub fn repair_turn_owner(&mut self) -> bool {
+        if self.has_turn_owner() || self.participants.is_empty() {
+            return false;
+        }
+        let last_speaker = self.turns.last().map(|t| t.speaker);
+        self.turn = self
+            .participants
+            .iter()
+            .position(|e| Some(*e) != last_speaker)
+            .unwrap_or(0);
+        true
+    }
+
     pub fn listeners_for(&self, speaker: Entity) -> impl Iterator<Item = Entity> + '_ {
         self.participants
             .iter()
@@ -147,6 +223,49 @@ impl Conversation {
         self.listeners_for(self.current_speaker())
     }
 
+    /// Listeners close enough to hear `speaker`. A group can spread wider
+    /// than one voice carries — each member only has to stay near someone
+    /// — so whoever is out of range misses the turn. Anyone whose position
+    /// is unknown is assumed to be listening.
+    pub fn listeners_in_earshot<'a>(
+        &'a self,
+        speaker: Entity,
+        transforms: &'a Query<&Transform>,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        let speaker_pos = transforms
+            .get(speaker)
+            .ok()
+            .map(|t| t.translation.truncate());
+        self.listeners_for(speaker).filter(move |listener| {
+            let listener_pos = transforms
+                .get(*listener)
+                .ok()
+                .map(|t| t.translation.truncate());
+            match (speaker_pos, listener_pos) {
+                (Some(a), Some(b)) => a.distance(b) <= CONVERSATION_RANGE,
+                _ => true,
+            }
+        })
+    }
+
+    /// Whether `speaker` already asked where to find `concept` in this
+    /// conversation.
+    pub fn has_asked_about(&self, speaker: Entity, concept: Concept) -> bool {
+        self.turns.iter().any(|t| {
+            t.speaker == speaker && t.intent == Intent::Ask && t.topic == Topic::Location(concept)
+        })
+    }
+
+    /// The question `speaker` is being asked: the last turn, if it's
+    /// someone else asking where to find something.
+    pub fn pending_question(&self, speaker: Entity) -> Option<Concept> {
+        let last = self.turns.last()?;
+        match (last.intent, last.topic) {
+            (Intent::Ask, Topic::Location(concept)) if last.speaker != speaker => Some(concept),
+            _ => None,
+        }
+    }
+
     pub fn last_turn_expects_response(&self) -> bool {
         self.turns
             .last()
@@ -185,11 +304,12 @@ impl Conversation {
 
 /// Per-kind registry of live Converse engagements. Keyed by
 /// [`EngagementId`] so the generic `Engaged` component on each
-/// participant can find its payload here.
+/// participant can find its payload here. A `BTreeMap` so the per-tick
+/// systems walk conversations in id order rather than hash order.
 #[derive(Resource, Default, Reflect)]
 #[reflect(Resource)]
 pub struct ConverseRegistry {
-    pub conversations: std::collections::HashMap<EngagementId, Conversation>,
+    pub conversations: std::collections::BTreeMap<EngagementId, Conversation>,
 }
 
 impl ConverseRegistry {
@@ -235,6 +355,8 @@ pub struct ConversePlugin;
 impl Plugin for ConversePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ConverseRegistry>()
+            .init_resource::<ConversationConfig>()
+            .register_type::<ConversationConfig>()
             .add_systems(
                 FixedUpdate,
                 (
@@ -249,7 +371,8 @@ impl Plugin for ConversePlugin {
             .add_systems(
                 FixedUpdate,
                 (
-                    select_turn_intent.after(process_initiate_conversation),
+                    sync_conversation_state.after(process_initiate_conversation),
+                    select_turn_intent.after(sync_conversation_state),
                     update_speaker_theory_of_mind.after(select_turn_intent),
                     process_received_communication.after(select_turn_intent),
                     emit_communication_events.after(process_received_communication),
@@ -277,7 +400,7 @@ pub fn process_initiate_conversation(
     mut target_positions: Query<&mut crate::agent::TargetPosition>,
     mut plan_memory_query: Query<&mut PlanMemory>,
 ) {
-    let pairs: Vec<(Entity, Option<Entity>)> = active_actions
+    let mut pairs: Vec<(Entity, Option<Entity>)> = active_actions
         .iter()
         .filter_map(|(entity, active)| {
             active
@@ -285,6 +408,9 @@ pub fn process_initiate_conversation(
                 .map(|state| (entity, state.target_entity))
         })
         .collect();
+    // Who gets the next id, and who joins whom, must not depend on
+    // archetype iteration order.
+    pairs.sort_by_key(|(initiator, _)| *initiator);
 
     for (initiator, partner) in pairs {
         let now = tick.current;
@@ -617,8 +743,20 @@ fn upsert_verbal_commitment(memory: &mut PlanMemory, concept: Concept, listener:
 // 2. Select turn intent
 // ============================================================================
 
+/// Validates turn ownership before anyone tries to speak. A conversation
+/// whose turn has no owner is handed to the last speaker's partner;
+/// otherwise every participant would wait on a turn nobody holds.
+pub fn sync_conversation_state(mut registry: ResMut<ConverseRegistry>) {
+    for conv in registry.conversations.values_mut() {
+        if conv.state != ConversationState::Ended {
+            conv.repair_turn_owner();
+        }
+    }
+}
+
 pub fn select_turn_intent(
     mut registry: ResMut<ConverseRegistry>,
+    config: Res<ConversationConfig>,
     tick: Res<TickCount>,
     minds: Query<&MindGraph>,
     toms: Query<&TheoryOfMind>,
@@ -626,6 +764,9 @@ pub fn select_turn_intent(
     mut plan_memories: Query<&mut PlanMemory>,
     mut consciousnesses: Query<&mut Consciousness>,
     mut drives: Query<&mut PsychologicalDrives>,
+    cns: Query<&CentralNervousSystem>,
+    social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
+    mut sim_rng: ResMut<SimRng>,
     mut sim_events: MessageWriter<SimEvent>,
 ) {
     let now = tick.current;
@@ -658,6 +799,8 @@ pub fn select_turn_intent(
             .and_then(most_committed_goal);
         let goal = speaker_goal.as_ref();
         let personality = personalities.get(speaker).ok();
+        let share_count =
+            triples_per_turn(personality.map(|p| p.traits.extraversion()).unwrap_or(0.5));
 
         let has_deliberate = !crate::agent::mind::deliberate_talk::pick_deliberate_content(
             speaker_mind,
@@ -678,17 +821,24 @@ pub fn select_turn_intent(
         )
         .is_empty();
 
-        let intent = select_intent(
-            conv,
-            speaker_mind,
-            speaker_tom,
-            primary_listener,
-            goal,
-            personality,
-            now,
-            has_deliberate,
-            has_casual,
-        );
+        let leaving = wants_to_leave(drives.get(speaker).ok(), cns.get(speaker).ok());
+        // Past the cap the speaker wraps up regardless of social drive.
+        let intent = if conv.turns.len() >= config.max_turns {
+            Intent::Farewell
+        } else {
+            select_intent(
+                conv,
+                speaker_mind,
+                speaker_tom,
+                primary_listener,
+                goal,
+                personality,
+                now,
+                has_deliberate,
+                has_casual,
+                leaving,
+            )
+        };
 
         let min_interval = intent_interval(intent);
         if min_interval > 0
@@ -698,14 +848,35 @@ pub fn select_turn_intent(
             continue;
         }
 
-        let (mut content, topic) = if matches!(intent, Intent::Share | Intent::Answer) {
+        let question = conv.pending_question(speaker);
+        let answer = match (intent, question) {
+            (Intent::Answer, Some(concept)) => {
+                let (triples, topic) = crate::agent::mind::deliberate_talk::pick_answer_content(
+                    speaker_mind,
+                    concept,
+                    speaker_tom,
+                    primary_listener,
+                    now,
+                    share_count,
+                );
+                (!triples.is_empty()).then_some((triples, topic))
+            }
+            _ => None,
+        };
+        let gap = goal.and_then(|g| location_gap(g, speaker_mind));
+
+        let (mut content, topic) = if let Some(answer) = answer {
+            answer
+        } else if let (Intent::Ask, Some(concept)) = (intent, gap) {
+            (Vec::new(), Topic::Location(concept))
+        } else if matches!(intent, Intent::Share | Intent::Answer) {
             let deliberate = crate::agent::mind::deliberate_talk::pick_deliberate_content(
                 speaker_mind,
                 goal,
                 speaker_tom,
                 primary_listener,
                 now,
-                SMALL_TALK_TRIPLES_PER_TURN,
+                share_count,
             );
             if !deliberate.0.is_empty() {
                 deliberate
@@ -715,7 +886,7 @@ pub fn select_turn_intent(
                     speaker_tom,
                     primary_listener,
                     now,
-                    SMALL_TALK_TRIPLES_PER_TURN,
+                    share_count,
                 );
                 (casual, Topic::General)
             }
@@ -732,6 +903,16 @@ pub fn select_turn_intent(
             (Vec::new(), Topic::General)
         };
 
+        // Only a speaker who might lie draws from the RNG, so honest
+        // conversations leave the seeded stream untouched.
+        let truthfulness = deception::truthfulness(
+            personality.map(|p| p.traits.agreeableness()).unwrap_or(0.5),
+            social_graph.affection(speaker, primary_listener),
+        );
+        let deceptive = truthfulness < 1.0
+            && sim_rng.inner_mut().random::<f32>() >= truthfulness
+            && deception::falsify(&mut content);
+
         if matches!(intent, Intent::Share | Intent::Ask | Intent::Answer)
             && let Some(goal_concept) = goal.and_then(Goal::target_concept)
         {
@@ -746,7 +927,15 @@ pub fn select_turn_intent(
             ));
         }
 
-        let expects_response = matches!(intent, Intent::Greet | Intent::Ask);
+        // A goodbye answering someone else's goodbye closes the
+        // conversation; an opening goodbye waits for the partner's reply.
+        let farewell_returned = intent == Intent::Farewell
+            && conv
+                .turns
+                .last()
+                .is_some_and(|t| t.intent == Intent::Farewell && t.speaker != speaker);
+        let expects_response = matches!(intent, Intent::Greet | Intent::Ask)
+            || (intent == Intent::Farewell && !farewell_returned);
         let listeners: Vec<Entity> = conv.listeners().collect();
         let content_len = content.len();
         let turn = Turn {
@@ -757,6 +946,7 @@ pub fn select_turn_intent(
             content,
             timestamp: now,
             expects_response,
+            deceptive,
         };
         conv.add_turn(turn);
         conv.wants_to_speak.remove(&speaker);
@@ -813,8 +1003,10 @@ pub fn select_turn_intent(
             },
         ));
 
+        let natural_end = natural_end_turns(mean_extraversion(conv, &personalities));
         conv.state = match (conv.state, intent) {
-            (_, Intent::Farewell) => ConversationState::Ended,
+            (_, Intent::Farewell) if farewell_returned => ConversationState::Ended,
+            (_, Intent::Farewell) => ConversationState::Wrapping,
             (ConversationState::Greeting, _) => {
                 if conv.turns.len() >= 2 {
                     ConversationState::Active
@@ -822,7 +1014,7 @@ pub fn select_turn_intent(
                     ConversationState::Greeting
                 }
             }
-            (ConversationState::Active, _) if conv.turns.len() >= NATURAL_END_TURN_COUNT => {
+            (ConversationState::Active, _) if conv.turns.len() >= natural_end => {
                 ConversationState::Wrapping
             }
             (state, _) => state,
@@ -833,48 +1025,88 @@ pub fn select_turn_intent(
     }
 }
 
-pub(crate) fn speak_desire(personality: Option<&Personality>, wants_to_speak: bool) -> f32 {
+/// Talkativeness multiplier: 0.5 for the most reserved, 1.0 for an
+/// average speaker, 1.5 for the most outgoing.
+fn verbosity(extraversion: f32) -> f32 {
+    0.5 + extraversion.clamp(0.0, 1.0)
+}
+
+/// Turns after which an active conversation starts wrapping up. Outgoing
+/// company keeps it going longer; the turn cap still applies.
+pub(crate) fn natural_end_turns(extraversion: f32) -> usize {
+    (NATURAL_END_TURN_COUNT as f32 * verbosity(extraversion)).round() as usize
+}
+
+/// How many triples a speaker shares in one Share or Answer turn.
+pub(crate) fn triples_per_turn(extraversion: f32) -> usize {
+    ((SMALL_TALK_TRIPLES_PER_TURN as f32 * verbosity(extraversion)).round() as usize).max(1)
+}
+
+/// Average extraversion across the participants; 0.5 for anyone without
+/// a personality.
+fn mean_extraversion(conv: &Conversation, personalities: &Query<&Personality>) -> f32 {
+    if conv.participants.is_empty() {
+        return 0.5;
+    }
+    let total: f32 = conv
+        .participants
+        .iter()
+        .map(|e| {
+            personalities
+                .get(*e)
+                .map(|p| p.traits.extraversion())
+                .unwrap_or(0.5)
+        })
+        .sum();
+    total / conv.participants.len() as f32
+}
+
+/// How keen an agent is to jump in when several were just addressed.
+pub(crate) fn speak_desire(personality: Option<&Personality>) -> f32 {
     let extraversion = personality.map(|p| p.traits.extraversion()).unwrap_or(0.5);
     let agreeableness = personality.map(|p| p.traits.agreeableness()).unwrap_or(0.5);
-    let base = 1.0 + extraversion * 2.0 - agreeableness * 0.6;
-    if wants_to_speak { base + 2.5 } else { base }
+    1.0 + extraversion * 2.0 - agreeableness * 0.6
 }
 
+/// Who speaks after the current speaker. Anyone waiting to answer goes
+/// first — the keenest of them if there are several — and otherwise the
+/// turn passes round-robin in join order, so every member of a group
+/// gets the floor.
 pub(crate) fn pick_next_speaker(
     conv: &Conversation,
     personalities: &Query<&Personality>,
 ) -> Entity {
     let speaker = conv.current_speaker();
-    let mut candidates: [Entity; MAX_GROUP_SIZE] = [speaker; MAX_GROUP_SIZE];
-    let mut scores: [f32; MAX_GROUP_SIZE] = [0.0; MAX_GROUP_SIZE];
-    let mut count = 0usize;
-    let mut total = 0.0f32;
-    for entity in conv.listeners() {
-        let p = personalities.get(entity).ok();
-        let wants = conv.wants_to_speak.contains(&entity);
-        let score = speak_desire(p, wants).max(0.01);
-        candidates[count] = entity;
-        scores[count] = score;
-        total += score;
-        count += 1;
-    }
-    if count == 0 {
-        return speaker;
-    }
-    if count == 1 {
-        return candidates[0];
-    }
-
-    let seed = conv.id.0.wrapping_mul(2_654_435_761) ^ (conv.turns.len() as u64);
-    let frac = ((seed % 1_000_000) as f32) / 1_000_000.0;
-    let mut target = frac * total;
-    for i in 0..count {
-        target -= scores[i];
-        if target <= 0.0 {
-            return candidates[i];
-        }
+    let responder = conv
+        .listeners()
+        .filter(|e| conv.wants_to_speak.contains(e))
+        .max_by(|a, b| {
+            speak_desire(personalities.get(*a).ok())
+                .total_cmp(&speak_desire(personalities.get(*b).ok()))
+        });
+    if let Some(responder) = responder {
+        return responder;
     }
-    candidates[count - 1]
+    conv.participants
+        .get((conv.turn + 1) % conv.participants.len())
+        .copied()
+        .unwrap_or(speaker)
+}
+
+/// A speaker wraps up once they've had their fill of company, or when a
+/// survival need is pressing enough to excuse themselves for.
+pub(crate) fn wants_to_leave(
+    drives: Option<&PsychologicalDrives>,
+    cns: Option<&CentralNervousSystem>,
+) -> bool {
+    let satisfied =
+        drives.is_some_and(|d| d.companionship.value >= FAREWELL_COMPANIONSHIP_SATISFIED);
+    let urgent = cns.is_some_and(|c| {
+        c.urgencies
+            .iter()
+            .any(|u| u.source.is_survival() && u.value >= FAREWELL_URGENT_NEED)
+    });
+    satisfied || urgent
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -888,6 +1120,7 @@ pub(crate) fn select_intent(
     now: u64,
     has_deliberate: bool,
     has_casual: bool,
+    wants_to_leave: bool,
 ) -> Intent {
     let neuroticism = personality.map(|p| p.traits.neuroticism()).unwrap_or(0.5);
     let extraversion = personality.map(|p| p.traits.extraversion()).unwrap_or(0.5);
@@ -903,13 +1136,19 @@ pub(crate) fn select_intent(
         return Intent::Farewell;
     }
 
+    if wants_to_leave && conv.state == ConversationState::Active {
+        return Intent::Farewell;
+    }
+
     let warn_threshold = (DANGER_WARN_SALIENCE - (neuroticism - 0.5) * 0.2).clamp(0.3, 1.0);
     if has_danger_to_warn(speaker_mind, speaker_tom, listener, warn_threshold, now) {
         return Intent::Share;
     }
 
-    if let Some(g) = goal
-        && goal_needs_location(g)
+    // A goal stalled on a knowledge gap outranks everything but a warning:
+    // ask about exactly that gap, once per conversation.
+    if let Some(gap) = goal.and_then(|g| location_gap(g, speaker_mind))
+        && !conv.has_asked_about(conv.current_speaker(), gap)
     {
         return Intent::Ask;
     }
@@ -945,6 +1184,13 @@ pub(crate) fn select_intent(
         return Intent::Agree;
     }
 
+    if let Some(last) = other_last
+        && matches!(last.intent, Intent::Share | Intent::Agree)
+        && agreeableness < DISAGREE_AGREEABLENESS
+    {
+        return Intent::Disagree;
+    }
+
     if extraversion > 0.3 && has_casual {
         return Intent::Share;
     }
@@ -979,14 +1225,6 @@ fn has_danger_to_warn(
     })
 }
 
-fn goal_needs_location(goal: &Goal) -> bool {
-    goal.conditions.iter().any(|cond| {
-        cond.predicate
-            .map(|p| matches!(p, Predicate::LocatedAt | Predicate::Contains))
-            .unwrap_or(false)
-    })
-}
-
 // ============================================================================
 // 2b. Update speaker's theory of mind after sharing content
 // ============================================================================
@@ -1045,9 +1283,21 @@ fn fuzzify_hearsay(source: &Triple, tick: u64, speaker: Entity) -> Triple {
     hearsay
 }
 
+/// How firmly a listener believes what a speaker tells them, given the
+/// listener's respect for that speaker. `None` = dismissed outright.
+pub(crate) fn hearsay_confidence(respect: f32) -> Option<f32> {
+    if respect < HEARSAY_DISMISS_RESPECT {
+        return None;
+    }
+    Some((HEARSAY_BASE_CONFIDENCE * (0.5 + respect)).min(HEARSAY_MAX_CONFIDENCE))
+}
+
 pub fn process_received_communication(
     registry: Res<ConverseRegistry>,
-    mut minds: Query<&mut MindGraph>,
+    social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
+    mut hearsay: ResMut<HearsayIntake>,
+    hearsay_config: Res<HearsayIntakeConfig>,
+    minds: Query<(), With<MindGraph>>,
     mut toms: Query<&mut TheoryOfMind>,
     tick: Res<TickCount>,
     mut sim_events: MessageWriter<SimEvent>,
@@ -1063,11 +1313,20 @@ pub fn process_received_communication(
             continue;
         }
         for listener in conv.listeners_for(turn.speaker) {
-            let Ok(mut mind) = minds.get_mut(listener) else {
+            if !minds.contains(listener) {
                 continue;
-            };
-            for triple in &turn.content {
-                mind.assert(fuzzify_hearsay(triple, tick.current, turn.speaker));
+            }
+            if let Some(mut confidence) =
+                hearsay_confidence(social_graph.respect(listener, turn.speaker))
+            {
+                if turn.deceptive {
+                    confidence *= deception::DECEPTIVE_CONFIDENCE_SCALE;
+                }
+                for triple in &turn.content {
+                    let mut heard = fuzzify_hearsay(triple, tick.current, turn.speaker);
+                    heard.meta.confidence = confidence;
+                    hearsay.enqueue(listener, heard, hearsay_config.max_pending);
+                }
             }
 
             if let Ok(mut listener_tom) = toms.get_mut(listener) {
@@ -1103,6 +1362,7 @@ pub fn emit_communication_events(
     social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
     mut events: MessageWriter<GameEvent>,
     agents: Query<(&MindGraph, &EmotionalState, &Personality)>,
+    transforms: Query<&Transform>,
 ) {
     for conv in registry.conversations.values() {
         let Some(turn) = conv.turns.last() else {
@@ -1112,10 +1372,7 @@ pub fn emit_communication_events(
             continue;
         }
         let speaker = turn.speaker;
-        for listener in conv.participants.iter().copied() {
-            if listener == speaker {
-                continue;
-            }
+        for listener in conv.listeners_in_earshot(speaker, &transforms) {
             let valence =
                 compute_interaction_valence(turn, speaker, listener, &agents, &social_graph);
             events.write(GameEvent::SocialInteraction {
@@ -1130,6 +1387,7 @@ pub fn emit_communication_events(
                     speaker,
                     listener,
                     content: turn.content.clone(),
+                    deceptive: turn.deceptive,
                 });
             }
         }
@@ -1296,7 +1554,7 @@ pub fn evaluate_conversation_continuation(
                         target: *other,
                         action: ActionType::Converse,
                         topic: None,
-                        valence: -0.4,
+                        valence: ABRUPT_EXIT_VALENCE,
                     });
                 }
             }
@@ -1314,7 +1572,21 @@ pub fn evaluate_conversation_continuation(
 
     for id in to_finalize {
         if let Some(conv) = registry.conversations.get(&id) {
-            let reason = if conv.turns.last().map(|t| t.intent) == Some(Intent::Farewell) {
+            let said_goodbye = conv.turns.last().map(|t| t.intent) == Some(Intent::Farewell);
+            if said_goodbye {
+                for actor in &conv.participants {
+                    for target in conv.participants.iter().filter(|t| *t != actor) {
+                        game_events.write(GameEvent::SocialInteraction {
+                            actor: *actor,
+                            target: *target,
+                            action: ActionType::Converse,
+                            topic: None,
+                            valence: POLITE_EXIT_VALENCE,
+                        });
+                    }
+                }
+            }
+            let reason = if said_goodbye {
                 EngagementEndReason::Natural
             } else if tick.current.saturating_sub(conv.last_turn_at) > STALE_CONVERSATION_TICKS {
                 EngagementEndReason::Stale
@@ -1390,6 +1662,39 @@ mod tests {
         assert!(conv.turn < conv.participants.len());
     }
 
+    #[test]
+    fn sync_hands_ownerless_turn_to_last_speakers_partner() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut conv = Conversation::new(conv_id(0), vec![e(1), e(2)], 0);
+        conv.add_turn(Turn {
+            speaker: e(1),
+            intent: Intent::Greet,
+            topic: Topic::General,
+            emotion: None,
+            content: Vec::new(),
+            timestamp: 0,
+            expects_response: true,
+            deceptive: false,
+        });
+        conv.turn = 2;
+        assert!(!conv.has_turn_owner());
+
+        let mut registry = ConverseRegistry::default();
+        registry.conversations.insert(conv.id, conv);
+        let mut world = World::new();
+        world.insert_resource(registry);
+        world.run_system_once(sync_conversation_state).unwrap();
+
+        let conv = world
+            .resource::<ConverseRegistry>()
+            .get(conv_id(0))
+            .unwrap();
+        assert!(conv.has_turn_owner());
+        assert_eq!(conv.current_speaker(), e(2));
+        assert_eq!(conv.listeners().collect::<Vec<_>>(), vec![e(1)]);
+    }
+
     #[test]
     fn listeners_excludes_current_speaker() {
         let mut conv = Conversation::new(conv_id(0), vec![e(1), e(2), e(3)], 0);
@@ -1401,6 +1706,55 @@ mod tests {
         assert!(!listeners.contains(&e(2)));
     }
 
+    #[test]
+    fn turns_pass_round_robin_unless_someone_was_addressed() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let mut next = |conv: &Conversation| {
+            let conv = conv.clone();
+            world
+                .run_system_once(move |personalities: Query<&Personality>| {
+                    pick_next_speaker(&conv, &personalities)
+                })
+                .unwrap()
+        };
+        let mut conv = Conversation::new(conv_id(0), vec![e(1), e(2), e(3)], 0);
+        conv.set_speaker(e(1));
+        assert_eq!(next(&conv), e(2));
+        conv.set_speaker(e(3));
+        assert_eq!(
+            next(&conv),
+            e(1),
+            "the floor wraps back to the first member"
+        );
+        conv.wants_to_speak.insert(e(2));
+        assert_eq!(next(&conv), e(2), "an addressed listener answers first");
+    }
+
+    #[test]
+    fn only_listeners_in_earshot_hear_a_group_turn() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let speaker = world.spawn(Transform::from_xyz(0.0, 0.0, 0.0)).id();
+        let near = world
+            .spawn(Transform::from_xyz(CONVERSATION_RANGE * 0.5, 0.0, 0.0))
+            .id();
+        let far = world
+            .spawn(Transform::from_xyz(CONVERSATION_RANGE * 1.5, 0.0, 0.0))
+            .id();
+        let conv = Conversation::new(conv_id(0), vec![speaker, near, far], 0);
+
+        let heard = world
+            .run_system_once(move |transforms: Query<&Transform>| {
+                conv.listeners_in_earshot(speaker, &transforms)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(heard, vec![near]);
+    }
+
     #[test]
     fn hearsay_fuzzifies_exact_quantity_to_around() {
         let speaker = Entity::from_bits(1);
@@ -1460,8 +1814,238 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn respected_speakers_are_believed_more_readily() {
+        let admired = hearsay_confidence(0.9).expect("admired source is heard");
+        let neutral = hearsay_confidence(0.5).expect("neutral source is heard");
+        assert!(admired > neutral);
+        assert!((neutral - HEARSAY_BASE_CONFIDENCE).abs() < 1e-6);
+        assert!(admired <= HEARSAY_MAX_CONFIDENCE);
+        assert_eq!(hearsay_confidence(0.1), None, "disdained source is ignored");
+    }
+
     #[test]
     fn ask_intent_produces_lower_base_valence_than_share() {
         assert!(valence_base(Intent::Ask) < valence_base(Intent::Share));
     }
+
+    fn active_conversation() -> Conversation {
+        let mut conv = Conversation::new(conv_id(0), vec![e(1), e(2)], 0);
+        for (speaker, intent) in [(e(1), Intent::Greet), (e(2), Intent::Answer)] {
+            conv.add_turn(Turn {
+                speaker,
+                intent,
+                topic: Topic::General,
+                emotion: None,
+                content: Vec::new(),
+                timestamp: 0,
+                expects_response: false,
+                deceptive: false,
+            });
+        }
+        conv.state = ConversationState::Active;
+        conv.set_speaker(e(1));
+        conv
+    }
+
+    #[test]
+    fn satisfied_or_needy_speaker_wants_to_leave() {
+        use crate::agent::nervous_system::urgency::{Urgency, UrgencySource};
+
+        let mut drives = PsychologicalDrives::default();
+        assert!(!wants_to_leave(Some(&drives), None));
+        drives.companionship.set(1.0);
+        assert!(wants_to_leave(Some(&drives), None));
+
+        let mut cns = CentralNervousSystem::default();
+        cns.urgencies.push(Urgency::new(UrgencySource::Hunger, 0.9));
+        assert!(wants_to_leave(None, Some(&cns)));
+    }
+
+    #[test]
+    fn agreeableness_decides_between_agreeing_and_pushing_back() {
+        use crate::agent::psyche::personality::AgreeablenessFacets;
+
+        let mut conv = active_conversation();
+        conv.set_speaker(e(2));
+        conv.add_turn(Turn {
+            speaker: e(1),
+            intent: Intent::Share,
+            topic: Topic::General,
+            emotion: None,
+            content: Vec::new(),
+            timestamp: 0,
+            expects_response: false,
+            deceptive: false,
+        });
+        let mind = MindGraph::default();
+        let reply = |agreeableness: f32| {
+            let mut personality = Personality::default();
+            personality.traits.agreeableness = AgreeablenessFacets::uniform(agreeableness);
+            select_intent(
+                &conv,
+                &mind,
+                None,
+                e(1),
+                None,
+                Some(&personality),
+                0,
+                false,
+                false,
+                false,
+            )
+        };
+        assert_eq!(reply(0.9), Intent::Agree);
+        assert_eq!(reply(0.1), Intent::Disagree);
+    }
+
+    #[test]
+    fn agent_asks_about_exactly_the_gap_its_goal_is_stuck_on() {
+        use crate::agent::brains::plan_memory::PlanId;
+        use crate::agent::brains::proposal::BrainType;
+        use crate::agent::mind::knowledge::Source;
+        use crate::agent::nervous_system::urgency::UrgencySource;
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let known = |subject: Entity, concept: Concept| {
+            let mut meta = Metadata::semantic(0);
+            meta.source = Source::Observed;
+            Triple::with_meta(
+                Node::Entity(subject),
+                Predicate::Contains,
+                Value::Item(concept, 3),
+                meta,
+            )
+        };
+        let wants_berries = Goal {
+            conditions: vec![TriplePattern::new(
+                Some(Node::Self_),
+                Some(Predicate::Contains),
+                Some(Value::Item(Concept::Berry, 1)),
+            )],
+            priority: 1.0,
+        };
+
+        let mut world = World::new();
+        world.insert_resource(TickCount {
+            current: 100,
+            ..Default::default()
+        });
+        world.init_resource::<ConversationConfig>();
+        world.init_resource::<Messages<SimEvent>>();
+        world.init_resource::<crate::agent::psyche::social_graph::SocialGraph>();
+        world.init_resource::<SimRng>();
+        let tree = world.spawn_empty().id();
+        let bush = world.spawn_empty().id();
+
+        // Knows where apples grow, not berries — and berries are what it wants.
+        let mut asker_mind = MindGraph::default();
+        asker_mind.assert(known(tree, Concept::Apple));
+        let mut memory = PlanMemory::default();
+        memory.insert(HeldPlan {
+            id: PlanId(1),
+            goal: wants_berries.clone(),
+            steps: Vec::new(),
+            state: PlanState::Executing,
+            commitment: 1.0,
+            subjective_cost: 0.0,
+            source: PlanSource::Brain(BrainType::Rational),
+            driving_urgency: UrgencySource::Hunger,
+            created_at_urgency: 0.5,
+            created_at: 0,
+            last_touched: 0,
+            current_step: 0,
+        });
+        let asker = world.spawn((asker_mind, memory)).id();
+        let mut expert_mind = MindGraph::default();
+        expert_mind.assert(known(tree, Concept::Apple));
+        expert_mind.assert(known(bush, Concept::Berry));
+        let expert = world.spawn(expert_mind).id();
+
+        let mut conv = Conversation::new(conv_id(0), vec![asker, expert], 0);
+        for speaker in [asker, expert] {
+            conv.add_turn(Turn {
+                speaker,
+                intent: Intent::Greet,
+                topic: Topic::General,
+                emotion: None,
+                content: Vec::new(),
+                timestamp: 0,
+                expects_response: false,
+                deceptive: false,
+            });
+        }
+        conv.state = ConversationState::Active;
+        conv.set_speaker(asker);
+        let mut registry = ConverseRegistry::default();
+        registry.conversations.insert(conv.id, conv);
+        world.insert_resource(registry);
+
+        world.run_system_once(select_turn_intent).unwrap();
+        let conv = &world.resource::<ConverseRegistry>().conversations[&conv_id(0)];
+        let question = conv.turns.last().unwrap();
+        assert_eq!(question.speaker, asker);
+        assert_eq!(question.intent, Intent::Ask);
+        assert_eq!(question.topic, Topic::Location(Concept::Berry));
+        assert!(question.expects_response);
+        assert_eq!(conv.current_speaker(), expert);
+
+        world.resource_mut::<TickCount>().current += URGENT_INTERVAL_TICKS;
+        world.run_system_once(select_turn_intent).unwrap();
+        let conv = &world.resource::<ConverseRegistry>().conversations[&conv_id(0)];
+        let answer = conv.turns.last().unwrap();
+        assert_eq!(answer.speaker, expert);
+        assert_eq!(answer.intent, Intent::Answer);
+        assert_eq!(answer.topic, Topic::Location(Concept::Berry));
+        assert!(
+            answer
+                .content
+                .iter()
+                .all(|t| t.object == Value::Item(Concept::Berry, 3)),
+            "answers with where berries are, not everything it knows: {:?}",
+            answer.content
+        );
+        assert!(!answer.content.is_empty());
+        // Asked once; the asker doesn't repeat the question.
+        assert!(conv.has_asked_about(asker, Concept::Berry));
+    }
+
+    #[test]
+    fn extraverts_talk_longer_and_share_more_per_turn() {
+        assert_eq!(natural_end_turns(0.5), NATURAL_END_TURN_COUNT);
+        assert_eq!(triples_per_turn(0.5), SMALL_TALK_TRIPLES_PER_TURN);
+        assert!(natural_end_turns(0.9) > natural_end_turns(0.1));
+        assert!(triples_per_turn(0.9) > triples_per_turn(0.1));
+        assert!(triples_per_turn(0.0) >= 1, "everyone says something");
+    }
+
+    #[test]
+    fn leaving_speaker_says_farewell_once_conversation_is_active() {
+        let conv = active_conversation();
+        let mind = MindGraph::default();
+        let pick = |conv: &Conversation, leaving| {
+            select_intent(
+                conv,
+                &mind,
+                None,
+                e(2),
+                None,
+                None,
+                0,
+                false,
+                false,
+                leaving,
+            )
+        };
+        assert_eq!(pick(&conv, true), Intent::Farewell);
+        assert_ne!(pick(&conv, false), Intent::Farewell);
+
+        let greeting = Conversation::new(conv_id(1), vec![e(1), e(2)], 0);
+        assert_eq!(
+            pick(&greeting, true),
+            Intent::Greet,
+            "nobody leaves before saying hello"
+        );
+    }
 }
@@ -1,6 +1,9 @@
 //! Engagement-id minter shared across kinds. Each kind owns its own
 //! payload registry (e.g. [`super::converse::ConverseRegistry`]); this
 //! resource just hands out ids so two kinds can't collide.
+//!
+//! Ids come from a plain counter, so two runs that start the same
+//! engagements in the same order mint the same ids — replays rely on it.
 
 use bevy::prelude::*;
 
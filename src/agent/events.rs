@@ -49,6 +49,10 @@ pub enum GameEvent {
         action: ActionType,
         target: Option<Entity>,
         location: Option<Vec2>,
+        /// How far the interaction carries as sound (world pixels). Agents
+        /// inside this radius perceive it even without line of sight.
+        /// `None` for silent interactions.
+        sound_radius: Option<f32>,
     },
 
     /// A social interaction between agents
@@ -68,6 +72,8 @@ pub enum GameEvent {
         listener: Entity,
         /// The knowledge being shared (as Triples)
         content: Vec<crate::agent::mind::knowledge::Triple>,
+        /// The speaker falsified part of `content`; heard at lower confidence.
+        deceptive: bool,
     },
 }
 
@@ -153,6 +159,11 @@ pub enum FailureReason {
     /// The partner's conversation group is already full (capacity reached)
     /// or the partner is otherwise unavailable to join/add to a conversation.
     ConversationFull,
+    /// The agent's skill is below the action's `required_skill` bar.
+    Unskilled {
+        skill: crate::agent::skills::SkillKind,
+        required: f32,
+    },
 }
 
 /// Event for communicating action outcomes to belief update system
@@ -345,6 +356,16 @@ pub enum SimEventKind {
         new_value: f32,
     },
 
+    /// `agent` now classifies `other` as a Friend, Rival or Enemy — a
+    /// relationship category beyond Acquaintance it didn't hold before.
+    RelationshipFormed {
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
+        agent: Entity,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
+        other: Entity,
+        category: Concept,
+    },
+
     /// An emotion was triggered or reinforced.
     EmotionTriggered {
         #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
@@ -384,6 +405,9 @@ pub enum SimEventKind {
         actor: Entity,
         #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
         target: Entity,
+        /// Always `Greet` today; carried so log consumers can treat passing
+        /// greetings and conversation turns uniformly.
+        intent: ConverseIntent,
     },
 
     /// Knowledge was shared between agents.
@@ -264,6 +264,7 @@ mod tests {
                 emotion_type: EmotionType::Joy,
                 intensity: 0.5,
                 fuel: -1.0,
+                numbing: 0.0,
             });
         }
         assert_invariants(world.app_mut().world_mut());
@@ -1,6 +1,7 @@
 //! ItemSlots: universal storage primitive for agents, chests, furnaces, construction sites, equipment.
 //!
-//! Reads: Concept (item type vocabulary from knowledge), Ontology (trait membership for filters)
+//! Reads: Concept (item type vocabulary from knowledge), Ontology (trait membership for filters),
+//!        SpoilageConfig (decay rate scale)
 //! Writes: ItemSlots (deposit/extract/add/remove items), SimEvent (freshness decay transitions)
 //! Upstream: action execution systems (deposit/extract on success), world entity spawning
 //! Downstream: brain_system (slots influence action choices), belief_updater (syncs MindGraph beliefs)
@@ -404,6 +405,19 @@ impl ItemSlots {
         self.first_edible(ontology).is_some()
     }
 
+    /// Returns `true` when every Free slot has a capacity and is at it —
+    /// nothing more can be carried until something is dropped. Unlimited
+    /// carries (the `agent_carry` default) are never full.
+    pub fn is_full(&self) -> bool {
+        let mut free = self
+            .slots
+            .iter()
+            .filter(|s| s.role == SlotRole::Free)
+            .peekable();
+        free.peek().is_some()
+            && free.all(|s| s.capacity.is_some_and(|cap| s.total_quantity() >= cap))
+    }
+
     /// Iterate over every Thing across all slots.
     pub fn all_items(&self) -> impl Iterator<Item = &Thing> {
         self.slots.iter().flat_map(|s| s.contents.iter())
@@ -521,9 +535,29 @@ impl ItemSlots {
 // FRESHNESS DECAY SYSTEM
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Designer knobs for how fast carried food goes off.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct SpoilageConfig {
+    /// Scales every concept's [`perishable_decay_rate`]. 0 freezes
+    /// freshness (no spoilage); 2 rots food twice as fast, making hoards
+    /// worth less and scarcity bite harder.
+    pub decay_multiplier: f32,
+}
+
+impl Default for SpoilageConfig {
+    fn default() -> Self {
+        Self {
+            decay_multiplier: 1.0,
+        }
+    }
+}
+
 /// Runs every 100 ticks. Decrements freshness on perishable Things in all
-/// `ItemSlots`. When freshness reaches 0, the concept changes to its rotten
-/// variant (e.g. Apple → RottenApple) and a `SimEvent::ItemSpoiled` fires.
+/// `ItemSlots` by their decay rate times [`SpoilageConfig::decay_multiplier`].
+/// When freshness reaches 0, the concept changes to its rotten variant
+/// (e.g. Apple → RottenApple) and a `SimEvent::ItemSpoiled` fires. Items
+/// with no rotten variant (meat, fish) rot away and are discarded.
 ///
 /// Items with `freshness = None` are skipped — that sentinel marks "still
 /// attached to the source" (berries on a bush, apples on a tree). A berry
@@ -536,35 +570,39 @@ impl ItemSlots {
 pub fn freshness_decay_system(
     mut query: Query<(Entity, &mut ItemSlots)>,
     tick: Res<crate::core::tick::TickCount>,
+    config: Res<SpoilageConfig>,
     mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
 ) {
     for (owner, mut slots) in &mut query {
         for slot in &mut slots.slots {
-            for thing in &mut slot.contents {
+            slot.contents.retain_mut(|thing| {
                 let Some(rate) = perishable_decay_rate(thing.concept) else {
-                    continue;
+                    return true;
                 };
                 let Some(freshness) = thing.properties.freshness.as_mut() else {
-                    continue;
+                    return true;
                 };
-                *freshness = (*freshness - rate).max(0.0);
-                if *freshness == 0.0
-                    && let Some(rotten) = rotten_variant(thing.concept)
-                {
-                    let from = thing.concept;
-                    thing.concept = rotten;
-                    thing.properties.freshness = None;
-                    sim_events.write(crate::agent::events::SimEvent::single(
-                        tick.current,
-                        owner,
-                        SimEventKind::ItemSpoiled {
-                            agent: owner,
-                            from,
-                            to: rotten,
-                        },
-                    ));
+                *freshness = (*freshness - rate * config.decay_multiplier).max(0.0);
+                if *freshness > 0.0 {
+                    return true;
                 }
-            }
+                let Some(rotten) = rotten_variant(thing.concept) else {
+                    return false;
+                };
+                let from = thing.concept;
+                thing.concept = rotten;
+                thing.properties.freshness = None;
+                sim_events.write(crate::agent::events::SimEvent::single(
+                    tick.current,
+                    owner,
+                    SimEventKind::ItemSpoiled {
+                        agent: owner,
+                        from,
+                        to: rotten,
+                    },
+                ));
+                true
+            });
         }
     }
 }
@@ -700,6 +738,20 @@ mod tests {
         assert_eq!(slots.count(Concept::Apple), 5);
     }
 
+    #[test]
+    fn carry_is_full_only_at_capacity() {
+        let mut slots = ItemSlots::agent_carry();
+        slots.add(Concept::Stone, 10);
+        assert!(!slots.is_full(), "unlimited carry is never full");
+
+        slots.slots[0].capacity = Some(11);
+        assert!(!slots.is_full());
+        slots.add(Concept::Stone, 1);
+        assert!(slots.is_full());
+        slots.remove(Concept::Stone, 1);
+        assert!(!slots.is_full());
+    }
+
     #[test]
     fn capacity_slot_accepts_exact_maximum() {
         let mut slots = ItemSlots {
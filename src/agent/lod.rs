@@ -0,0 +1,199 @@
+//! Simulation level of detail: cheaper updates for agents nobody is watching.
+//!
+//! Reads: LodConfig, Camera (GlobalTransform), Transform, PlayerControlled
+//! Writes: LodLevel (per agent), LodClock
+//! Upstream: ui::camera (camera position)
+//! Downstream: perception (visual scan + mind writes on the coarse cadence),
+//!             nervous_system::metabolism (batched needs), emotional brain
+//!             (no new conversations at Reduced)
+//!
+//! With hundreds of agents, running full perception and per-tick needs for
+//! someone at the far edge of the map buys nothing the player can see.
+//! Agents farther than `full_detail_radius` from every camera drop to
+//! [`LodLevel::Reduced`]: they look around and update their needs only once
+//! every `batch_cycles` cycles (staggered by entity so the work spreads
+//! out), and their emotional brain stops starting conversations. Needs are
+//! not skipped, only deferred — a batch applies all the time that passed.
+//! With no camera (headless runs, tests) every agent stays at Full.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::player::PlayerControlled;
+use crate::world::map::TILE_SIZE;
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct LodConfig {
+    /// Off = every agent runs at full fidelity.
+    pub enabled: bool,
+    /// Agents within this distance (pixels) of a camera stay at Full.
+    pub full_detail_radius: f32,
+    /// A Reduced agent does its detailed work once every this many cycles.
+    pub batch_cycles: u32,
+}
+
+impl Default for LodConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            full_detail_radius: 48.0 * TILE_SIZE,
+            batch_cycles: 10,
+        }
+    }
+}
+
+/// How much simulation an agent gets this cycle.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[reflect(Component)]
+#[require(DeferredNeeds)]
+pub enum LodLevel {
+    #[default]
+    Full,
+    /// Coarse perception and batched needs; no new conversations.
+    Reduced,
+}
+
+/// Metabolism time owed to a Reduced agent since its last batch.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct DeferredNeeds {
+    /// Game-time (in `TickCount::dt` units) not yet applied.
+    pub dt: f32,
+    /// Cycles folded into `dt`.
+    pub cycles: u32,
+}
+
+/// Cycle counter that staggers Reduced agents' batches.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LodClock {
+    pub cycle: u64,
+    /// Copied from `LodConfig` each cycle so gated systems only need the clock.
+    pub batch_cycles: u32,
+}
+
+impl LodClock {
+    /// Whether `entity` does its detailed work this cycle: always at Full
+    /// (or with no `LodLevel` yet), once every `batch_cycles` at Reduced.
+    pub fn detail_due(&self, entity: Entity, level: Option<&LodLevel>) -> bool {
+        match level {
+            Some(LodLevel::Reduced) => (self.cycle + u64::from(entity.index_u32()))
+                .is_multiple_of(u64::from(self.batch_cycles.max(1))),
+            _ => true,
+        }
+    }
+}
+
+/// Bevy system (FixedUpdate): advance the LOD clock and give every agent
+/// the level its distance to the nearest camera calls for. The
+/// player-controlled agent is always Full.
+pub fn assign_lod_levels(
+    mut commands: Commands,
+    config: Res<LodConfig>,
+    mut clock: ResMut<LodClock>,
+    cameras: Query<&GlobalTransform, With<Camera>>,
+    agents: Query<(Entity, &Transform, Option<&LodLevel>, Has<PlayerControlled>), With<Agent>>,
+) {
+    clock.cycle += 1;
+    clock.batch_cycles = config.batch_cycles;
+
+    let observers: Vec<Vec2> = cameras.iter().map(|t| t.translation().truncate()).collect();
+    let radius_sq = config.full_detail_radius * config.full_detail_radius;
+
+    for (entity, transform, current, player) in agents.iter() {
+        let pos = transform.translation.truncate();
+        let watched = observers.is_empty()
+            || observers
+                .iter()
+                .any(|o| o.distance_squared(pos) <= radius_sq);
+        let level = if !config.enabled || player || watched {
+            LodLevel::Full
+        } else {
+            LodLevel::Reduced
+        };
+        if current != Some(&level) {
+            commands.entity(entity).insert(level);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::Alive;
+    use crate::agent::body::needs::{Consciousness, PhysicalNeeds};
+    use crate::agent::nervous_system::metabolism::{NeedsConfig, tick_metabolism};
+    use crate::core::TickCount;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_agent(world: &mut World, x: f32) -> Entity {
+        world
+            .spawn((
+                Agent,
+                Alive,
+                Transform::from_xyz(x, 0.0, 0.0),
+                PhysicalNeeds::default(),
+                Consciousness::default(),
+            ))
+            .id()
+    }
+
+    fn hydration(world: &World, entity: Entity) -> f32 {
+        world.get::<PhysicalNeeds>(entity).unwrap().hydration.value
+    }
+
+    #[test]
+    fn distant_agents_batch_their_needs_while_near_ones_update_every_cycle() {
+        let mut world = World::new();
+        let config = LodConfig::default();
+        let batch = config.batch_cycles as usize;
+        world.insert_resource(config.clone());
+        world.init_resource::<LodClock>();
+        world.init_resource::<NeedsConfig>();
+        world.insert_resource(TickCount::default());
+        world.spawn((Camera::default(), GlobalTransform::default()));
+        let near = spawn_agent(&mut world, 2.0 * TILE_SIZE);
+        let far = spawn_agent(&mut world, config.full_detail_radius * 3.0);
+
+        let cycles = batch * 4;
+        let mut near_updates = 0;
+        let mut far_updates = 0;
+        let mut per_cycle_drain = 0.0;
+        for _ in 0..cycles {
+            world.run_system_once(assign_lod_levels).unwrap();
+            let (near_before, far_before) = (hydration(&world, near), hydration(&world, far));
+            world.run_system_once(tick_metabolism).unwrap();
+            per_cycle_drain = near_before - hydration(&world, near);
+            near_updates += usize::from(hydration(&world, near) != near_before);
+            far_updates += usize::from(hydration(&world, far) != far_before);
+        }
+
+        assert_eq!(world.get::<LodLevel>(near), Some(&LodLevel::Full));
+        assert_eq!(world.get::<LodLevel>(far), Some(&LodLevel::Reduced));
+        assert_eq!(near_updates, cycles, "near agent runs every cycle");
+        assert!(
+            (cycles / batch..=cycles / batch + 1).contains(&far_updates),
+            "far agent updated {far_updates} times in {cycles} cycles"
+        );
+
+        // Deferred, not skipped: the far agent lags by at most one batch.
+        let lag = hydration(&world, far) - hydration(&world, near);
+        assert!(lag >= -1e-5, "batched needs must not overshoot ({lag})");
+        assert!(
+            lag <= per_cycle_drain * batch as f32 + 1e-5,
+            "far agent lags {lag}, more than one batch of drain"
+        );
+    }
+
+    #[test]
+    fn without_a_camera_everyone_stays_at_full_detail() {
+        let mut world = World::new();
+        world.insert_resource(LodConfig::default());
+        world.init_resource::<LodClock>();
+        let far = spawn_agent(&mut world, 10_000.0);
+
+        world.run_system_once(assign_lod_levels).unwrap();
+
+        assert_eq!(world.get::<LodLevel>(far), Some(&LodLevel::Full));
+    }
+}
@@ -0,0 +1,111 @@
+//! Milestones: the stable, high-level event stream for code embedding the sim.
+//!
+//! Reads: SimEvent (Death, RelationshipFormed, EngagementStarted/Ended for Converse)
+//! Writes: Milestone messages
+//! Upstream: biology::body::die, recognition::check_recognition, engagement::converse
+//! Downstream: external observers — UIs, analytics, replay tooling layered on the crate
+//!
+//! `SimEvent` is the internal observability bus: dozens of fine-grained
+//! variants (decisions, perceptions, combat hits) whose shape changes as
+//! the simulation does. Code built on top of the crate usually wants a
+//! handful of life events and a promise that they stay put. Every
+//! `SimEvent` that matters at that level is re-published once as a
+//! [`Milestone`]; read them with `MessageReader<Milestone>`.
+//!
+//! Variants are only ever added. Births will join once reproduction lands.
+
+use bevy::prelude::*;
+
+use crate::agent::engagement::{EngagementEndReason, EngagementKind};
+use crate::agent::events::{SimEvent, SimEventKind};
+use crate::agent::mind::knowledge::Concept;
+
+/// A significant simulation occurrence. `tick` is the game-second it
+/// happened on.
+#[derive(Event, Message, Debug, Clone, PartialEq, serde::Serialize)]
+pub enum Milestone {
+    /// An agent died.
+    Died {
+        tick: u64,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
+        agent: Entity,
+        cause: String,
+    },
+    /// `agent` came to see `other` as a Friend, Rival or Enemy. Directed:
+    /// the other side forms its own view separately.
+    RelationshipFormed {
+        tick: u64,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
+        agent: Entity,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity")]
+        other: Entity,
+        category: Concept,
+    },
+    /// A conversation began. `id` stays the same for its whole life.
+    ConversationStarted {
+        tick: u64,
+        id: u64,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity_vec")]
+        participants: Vec<Entity>,
+    },
+    /// A conversation closed.
+    ConversationEnded {
+        tick: u64,
+        id: u64,
+        #[serde(serialize_with = "crate::core::entity_serde::serialize_entity_vec")]
+        participants: Vec<Entity>,
+        reason: EngagementEndReason,
+    },
+}
+
+impl Milestone {
+    /// The milestone `event` stands for, if it is one.
+    pub fn from_sim_event(event: &SimEvent) -> Option<Self> {
+        let tick = event.tick;
+        match &event.kind {
+            SimEventKind::Death { agent, cause } => Some(Self::Died {
+                tick,
+                agent: *agent,
+                cause: cause.clone(),
+            }),
+            SimEventKind::RelationshipFormed {
+                agent,
+                other,
+                category,
+            } => Some(Self::RelationshipFormed {
+                tick,
+                agent: *agent,
+                other: *other,
+                category: *category,
+            }),
+            SimEventKind::EngagementStarted {
+                kind: EngagementKind::Converse,
+                engagement_id,
+                participants,
+            } => Some(Self::ConversationStarted {
+                tick,
+                id: engagement_id.0,
+                participants: participants.clone(),
+            }),
+            SimEventKind::EngagementEnded {
+                kind: EngagementKind::Converse,
+                engagement_id,
+                participants,
+                reason,
+            } => Some(Self::ConversationEnded {
+                tick,
+                id: engagement_id.0,
+                participants: participants.clone(),
+                reason: *reason,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Bevy system (Last): re-publish this frame's milestone-worthy SimEvents.
+pub fn publish_milestones(mut events: MessageReader<SimEvent>, mut out: MessageWriter<Milestone>) {
+    for milestone in events.read().filter_map(Milestone::from_sim_event) {
+        out.write(milestone);
+    }
+}
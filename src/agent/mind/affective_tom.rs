@@ -1,6 +1,6 @@
 //! Affective theory of mind: per-agent store of last-observed mood / emotion.
 //!
-//! Reads: VisibleObjects, MindGraph (sentience filter), EmotionalState, TickCount
+//! Reads: VisibleObjects, Transform, MindGraph (sentience filter), EmotionalState, TickCount
 //! Writes: AffectiveToM, SimEvent
 //! Upstream: mind::social_perception
 //! Downstream: psyche::appraisal, prosocial behaviors, other-regarding drives
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use crate::agent::Agent;
 use crate::agent::events::{SimEvent, SimEventKind};
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node};
-use crate::agent::mind::perception::VisibleObjects;
+use crate::agent::mind::perception::{VisibleObjects, visual_confidence};
 use crate::agent::psyche::emotions::{EmotionType, EmotionalState};
 use crate::core::GameTime;
 use crate::core::tick::{TICK_RARE_PERIOD, TickCount};
@@ -39,8 +39,8 @@ const DISTRESS_THRESHOLD: f32 = 0.3;
 const NEGATIVE_EMOTION_DISTRESS: f32 = 0.7;
 
 /// Snapshot of another agent's affective state at a single observation.
-/// Confidence is derived from `observed_at` via `confidence_at(now)` —
-/// not stored, to avoid stale-by-default state.
+/// Confidence is derived from `read_confidence` and `observed_at` via
+/// `confidence_at(now)` — not stored, to avoid stale-by-default state.
 #[derive(Debug, Clone, Copy)]
 pub struct PerceivedMood {
     pub dominant_emotion: Option<EmotionType>,
@@ -49,13 +49,17 @@ pub struct PerceivedMood {
     /// Stress at observation, range 0..=100.
     pub stress: f32,
     pub observed_at: u64,
+    /// How clearly the mood was read at observation time, from
+    /// `perception::visual_confidence` — a distant face is a guess.
+    pub read_confidence: f32,
 }
 
 impl PerceivedMood {
-    /// Linearly-decayed confidence at `now`, clamped to [0, 1].
+    /// Read confidence, linearly decayed by age at `now`, clamped to [0, 1].
     pub fn confidence_at(&self, now: u64) -> f32 {
         let age = now.saturating_sub(self.observed_at) as f32;
-        (1.0 - age / CONFIDENCE_DECAY_TICKS as f32).clamp(0.0, 1.0)
+        let freshness = (1.0 - age / CONFIDENCE_DECAY_TICKS as f32).clamp(0.0, 1.0);
+        (self.read_confidence * freshness).clamp(0.0, 1.0)
     }
 
     /// Continuous distress signal in [0, 1]. Max-of-three shape lets any
@@ -82,8 +86,9 @@ pub struct AffectiveToM {
 }
 
 impl AffectiveToM {
-    /// Record what `target` looked like emotionally at `tick`. Refreshes
-    /// the existing entry; on capacity overflow evicts the oldest target.
+    /// Record what `target` looked like emotionally at `tick`, read with
+    /// `read_confidence`. Refreshes the existing entry; on capacity
+    /// overflow evicts the oldest target.
     pub fn record_observation(
         &mut self,
         target: Entity,
@@ -91,12 +96,14 @@ impl AffectiveToM {
         mood: f32,
         stress: f32,
         tick: u64,
+        read_confidence: f32,
     ) {
         let entry = PerceivedMood {
             dominant_emotion,
             mood,
             stress,
             observed_at: tick,
+            read_confidence,
         };
 
         if let Some(existing) = self.beliefs.get_mut(&target) {
@@ -156,23 +163,35 @@ impl AffectiveToM {
 /// SimEvents are gated to dominant-emotion changes so the log isn't
 /// flooded with no-ops.
 pub fn update_affective_tom(
-    mut observers: Query<(Entity, &VisibleObjects, &MindGraph, &mut AffectiveToM), With<Agent>>,
-    targets: Query<&EmotionalState, With<Agent>>,
+    mut observers: Query<
+        (
+            Entity,
+            &Transform,
+            &VisibleObjects,
+            &MindGraph,
+            &mut AffectiveToM,
+        ),
+        With<Agent>,
+    >,
+    targets: Query<(&Transform, &EmotionalState), With<Agent>>,
     tick: Res<TickCount>,
     mut sim_events: MessageWriter<SimEvent>,
 ) {
     let now = tick.current;
 
-    for (observer, visible, mind, mut tom) in observers.iter_mut() {
+    for (observer, observer_transform, visible, mind, mut tom) in observers.iter_mut() {
+        let observer_pos = observer_transform.translation.truncate();
         for visible_entity in
             visible.iter_by_concept(|c| mind.has_trait(&Node::Concept(c), Concept::Sentient))
         {
             if visible_entity == observer {
                 continue;
             }
-            let Ok(state) = targets.get(visible_entity) else {
+            let Ok((target_transform, state)) = targets.get(visible_entity) else {
                 continue;
             };
+            let read_confidence =
+                visual_confidence(observer_pos.distance(target_transform.translation.truncate()));
 
             // Change-detection: only emit a SimEvent when the dominant
             // emotion flips. Mood / stress drift quietly under the live
@@ -188,6 +207,7 @@ pub fn update_affective_tom(
                 state.current_mood,
                 state.stress_level,
                 now,
+                read_confidence,
             );
 
             if new_emotion != prev_emotion {
@@ -235,7 +255,7 @@ mod tests {
         let mut state = EmotionalState::default();
         state.add_emotion(Emotion::new(EmotionType::Sadness, 0.7));
 
-        tom.record_observation(alice, state.dominant_emotion(), -0.4, 30.0, 100);
+        tom.record_observation(alice, state.dominant_emotion(), -0.4, 30.0, 100, 1.0);
 
         let mood = tom.perceived_mood(alice).expect("must record observation");
         assert_eq!(mood.dominant_emotion, Some(EmotionType::Sadness));
@@ -254,7 +274,7 @@ mod tests {
     fn confidence_decays_linearly_with_age() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, None, 0.0, 0.0, 0);
+        tom.record_observation(alice, None, 0.0, 0.0, 0, 1.0);
 
         let mood = tom.perceived_mood(alice).unwrap();
         let halfway = mood.confidence_at(CONFIDENCE_DECAY_TICKS / 2);
@@ -269,7 +289,7 @@ mod tests {
     fn decay_evicts_entries_below_threshold() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, None, 0.0, 0.0, 0);
+        tom.record_observation(alice, None, 0.0, 0.0, 0, 1.0);
 
         let evicted = tom.decay(CONFIDENCE_DECAY_TICKS + 1);
         assert_eq!(evicted, 1);
@@ -281,12 +301,12 @@ mod tests {
         let mut tom = AffectiveToM::default();
         // Entity::from_bits(0) is invalid in Bevy 0.18 — start ids at 1.
         for i in 1..=MAX_AFFECTIVE_TARGETS {
-            tom.record_observation(test_entity(i as u32), None, 0.0, 0.0, i as u64);
+            tom.record_observation(test_entity(i as u32), None, 0.0, 0.0, i as u64, 1.0);
         }
         assert_eq!(tom.target_count(), MAX_AFFECTIVE_TARGETS);
 
         let newcomer = test_entity(999);
-        tom.record_observation(newcomer, None, 0.0, 0.0, 1000);
+        tom.record_observation(newcomer, None, 0.0, 0.0, 1000, 1.0);
         assert_eq!(tom.target_count(), MAX_AFFECTIVE_TARGETS);
         assert!(tom.perceived_mood(test_entity(1)).is_none());
         assert!(tom.perceived_mood(newcomer).is_some());
@@ -296,8 +316,8 @@ mod tests {
     fn re_observing_same_target_refreshes_in_place() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, Some(EmotionType::Sadness), -0.5, 40.0, 100);
-        tom.record_observation(alice, Some(EmotionType::Joy), 0.6, 5.0, 500);
+        tom.record_observation(alice, Some(EmotionType::Sadness), -0.5, 40.0, 100, 1.0);
+        tom.record_observation(alice, Some(EmotionType::Joy), 0.6, 5.0, 500, 1.0);
 
         assert_eq!(tom.target_count(), 1);
         let mood = tom.perceived_mood(alice).unwrap();
@@ -309,7 +329,7 @@ mod tests {
     fn has_seen_distressed_fires_on_negative_dominant_emotion() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, Some(EmotionType::Sadness), 0.0, 30.0, 0);
+        tom.record_observation(alice, Some(EmotionType::Sadness), 0.0, 30.0, 0, 1.0);
         assert!(tom.has_seen_distressed(alice));
     }
 
@@ -317,7 +337,7 @@ mod tests {
     fn has_seen_distressed_fires_on_low_mood_without_emotion() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, None, -0.6, 10.0, 0);
+        tom.record_observation(alice, None, -0.6, 10.0, 0, 1.0);
         assert!(tom.has_seen_distressed(alice));
     }
 
@@ -325,7 +345,7 @@ mod tests {
     fn has_seen_distressed_quiet_for_neutral_target() {
         let mut tom = AffectiveToM::default();
         let alice = test_entity(1);
-        tom.record_observation(alice, Some(EmotionType::Joy), 0.4, 10.0, 0);
+        tom.record_observation(alice, Some(EmotionType::Joy), 0.4, 10.0, 0, 1.0);
         assert!(!tom.has_seen_distressed(alice));
     }
 }
@@ -0,0 +1,144 @@
+//! Area beliefs: what each biome holds, learned by seeing it there.
+//!
+//! Reads: BiomeMap, VisibleObjects (`by_concept`), Transform (of seen resources)
+//! Writes: MindGraph (`(Area, Contains, Concept)` semantic beliefs)
+//! Upstream: perception::update_visual_perception, world::biome (BiomeMap)
+//! Downstream: planning and conversation over areas ("the forest has wood")
+//!
+//! Per-entity beliefs say where one tree is; they do not add up to knowing
+//! that the forest is where trees are. When an agent sees a resource with
+//! a home biome standing in a biome, it records that the area contains that
+//! kind of thing. The belief outlives every individual sighting, so an agent
+//! who has never seen a particular tree still knows where to look.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::mind::knowledge::{
+    AreaId, Concept, Metadata, MindGraph, Node, Predicate, Source, Triple, Value,
+};
+use crate::agent::mind::perception::VisibleObjects;
+use crate::core::tick::TickCount;
+use crate::world::biome::{Biome, BiomeMap};
+
+/// Concepts `area` is believed to contain.
+pub fn known_area_contents(mind: &MindGraph, area: &AreaId) -> Vec<Concept> {
+    mind.query(
+        Some(&Node::Area(area.clone())),
+        Some(Predicate::Contains),
+        None,
+    )
+    .into_iter()
+    .filter_map(|t| t.object.as_concept())
+    .collect()
+}
+
+/// Bevy system (FixedUpdate): for every visible resource kind with a home
+/// biome, believe the area it stands in contains that kind. No-op without
+/// a `BiomeMap` (scenario maps, Uniform layouts).
+pub fn note_area_contents(
+    biomes: Option<Res<BiomeMap>>,
+    tick: Res<TickCount>,
+    mut agents: Query<(&VisibleObjects, &mut MindGraph), With<Agent>>,
+    positions: Query<&Transform>,
+) {
+    let Some(biomes) = biomes else {
+        return;
+    };
+    for (visible, mut mind) in agents.iter_mut() {
+        let mut seen: Vec<(Biome, Concept)> = Vec::new();
+        for (concept, entities) in &visible.by_concept {
+            if Biome::home_of(*concept).is_none() {
+                continue;
+            }
+            for entity in entities {
+                let Ok(transform) = positions.get(*entity) else {
+                    continue;
+                };
+                if let Some(biome) = biomes.biome_at(transform.translation.truncate())
+                    && !seen.contains(&(biome, *concept))
+                {
+                    seen.push((biome, *concept));
+                }
+            }
+        }
+        for (biome, concept) in seen {
+            let area = Node::Area(biome.area());
+            let object = Value::Concept(concept);
+            if mind.has(&area, Predicate::Contains, &object) {
+                continue;
+            }
+            let mut meta = Metadata::semantic(tick.current);
+            meta.source = Source::Observed;
+            mind.assert(Triple::with_meta(area, Predicate::Contains, object, meta));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::setup_ontology;
+    use crate::world::biome::BiomeConfig;
+    use crate::world::map::{CHUNK_SIZE, Chunk, TILE_SIZE, WorldMap};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn seeing_a_tree_in_the_forest_teaches_that_the_forest_has_trees() {
+        let size = CHUNK_SIZE * 4;
+        let mut map = WorldMap::new(size, size);
+        for cx in 0..4 {
+            for cy in 0..4 {
+                map.chunks.insert(IVec2::new(cx, cy), Chunk::new(cx, cy));
+            }
+        }
+        let biomes = BiomeMap::generate(
+            &map,
+            &BiomeConfig {
+                region_tiles: 8.0,
+                ..Default::default()
+            },
+        );
+        let forest_tile = (0..size * size)
+            .map(|i| (i % size, i / size))
+            .find(|&(x, y)| biomes.biome_at_tile(x, y) == Some(Biome::Forest))
+            .expect("some forest on the map");
+
+        let mut world = World::new();
+        world.insert_resource(TickCount::default());
+        world.insert_resource(biomes);
+        let tree = world
+            .spawn(Transform::from_xyz(
+                (forest_tile.0 as f32 + 0.5) * TILE_SIZE,
+                (forest_tile.1 as f32 + 0.5) * TILE_SIZE,
+                0.0,
+            ))
+            .id();
+        let mut visible = VisibleObjects {
+            entities: vec![tree],
+            ..Default::default()
+        };
+        visible
+            .by_concept
+            .entry(Concept::AppleTree)
+            .or_default()
+            .push(tree);
+        let agent = world
+            .spawn((Agent, visible, MindGraph::new(setup_ontology())))
+            .id();
+
+        world.run_system_once(note_area_contents).unwrap();
+
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        assert_eq!(
+            known_area_contents(mind, &Biome::Forest.area()),
+            vec![Concept::AppleTree]
+        );
+        assert!(known_area_contents(mind, &Biome::Plains.area()).is_empty());
+
+        // Seeing it again doesn't stack duplicate beliefs.
+        world.run_system_once(note_area_contents).unwrap();
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        assert_eq!(known_area_contents(mind, &Biome::Forest.area()).len(), 1);
+    }
+}
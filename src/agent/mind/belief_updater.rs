@@ -1,16 +1,24 @@
 //! Belief updater: updates MindGraph from action outcomes; generates need-satisfaction emotions.
 //!
-//! Reads: ActionOutcomeEvent (success/failure, need satisfaction, items, targets), Time, PhysicalNeeds
-//! Writes: MindGraph (inventory counts, resource depletion), EmotionalState (joy/frustration), SimEvent
+//! Reads: ActionOutcomeEvent (success/failure, need satisfaction, items, targets), Time, PhysicalNeeds,
+//!        RelationshipConfig (hearsay contradiction trust penalty)
+//! Writes: MindGraph (inventory counts, per-FailureReason belief revisions), EmotionalState (joy/frustration),
+//!         SocialGraph (trust lost in informants first-hand evidence contradicts), SimEvent
 //! Upstream: agent::events (ActionOutcomeEvent emitted by execution systems)
 //! Downstream: mind::knowledge (MindGraph updated), psyche::emotions (EmotionalState updated)
 
 use crate::agent::body::needs::PhysicalNeeds;
-use crate::agent::events::{ActionOutcome, ActionOutcomeEvent, FailureReason};
-use crate::agent::mind::knowledge::{Concept, Metadata, MindGraph, Node, Predicate, Triple, Value};
+use crate::agent::events::{
+    ActionOutcome, ActionOutcomeEvent, FailureReason, RelationshipDimension, SimEvent, SimEventKind,
+};
+use crate::agent::mind::knowledge::{
+    Concept, Metadata, MindGraph, Node, Predicate, Source, Triple, Value,
+};
 use crate::agent::psyche::emotions::{
     Emotion, EmotionType, EmotionalState, add_emotion_with_event,
 };
+use crate::agent::psyche::relationships::RelationshipConfig;
+use crate::agent::psyche::social_graph::SocialGraph;
 use bevy::prelude::*;
 
 pub fn process_action_outcomes(
@@ -20,7 +28,9 @@ pub fn process_action_outcomes(
     >,
     mut outcome_events: MessageReader<ActionOutcomeEvent>,
     tick: Res<crate::core::tick::TickCount>,
-    mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
+    mut social_graph: ResMut<SocialGraph>,
+    relationship_config: Res<RelationshipConfig>,
+    mut sim_events: MessageWriter<SimEvent>,
 ) {
     let current_time = tick.current;
 
@@ -47,7 +57,16 @@ pub fn process_action_outcomes(
                 }
 
                 ActionOutcome::Failed { target, reason, .. } => {
-                    handle_failure_outcome(&mut mind, target, reason, current_time);
+                    let contradicted =
+                        handle_failure_outcome(&mut mind, target, reason, current_time);
+                    discredit_informants(
+                        &mut social_graph,
+                        &relationship_config,
+                        event.actor,
+                        &contradicted,
+                        current_time,
+                        &mut sim_events,
+                    );
                     if let Some(needs) = physical {
                         generate_failure_frustration(
                             reason,
@@ -101,47 +120,69 @@ fn handle_success_outcome(
     }
 }
 
+/// How a failed action revises the agent's beliefs. Every
+/// `FailureReason` maps to exactly one revision in [`belief_revision`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BeliefRevision {
+    /// The failure says nothing lasting about the world.
+    Unchanged,
+    /// The target holds nothing: zero every `Contains` belief about it.
+    TargetEmpty,
+    /// The agent is not carrying this item.
+    SelfLacks(Concept),
+    /// The agent is carrying no edible food.
+    SelfLacksFood,
+    /// The tile can't be walked to.
+    TileUnreachable((i32, i32)),
+}
+
+/// The belief revision a failure implies. Exhaustive on purpose — no
+/// wildcard arm — so a new `FailureReason` has to decide what it teaches
+/// before it compiles.
+pub fn belief_revision(reason: &FailureReason) -> BeliefRevision {
+    match reason {
+        FailureReason::ResourceDepleted => BeliefRevision::TargetEmpty,
+        FailureReason::MissingItem(concept) => BeliefRevision::SelfLacks(*concept),
+        FailureReason::NoEdibleFood => BeliefRevision::SelfLacksFood,
+        FailureReason::PathBlocked { target_tile } => BeliefRevision::TileUnreachable(*target_tile),
+        // Perception forgets despawned entities on its own.
+        FailureReason::TargetGone => BeliefRevision::Unchanged,
+        FailureReason::NoTarget => BeliefRevision::Unchanged,
+        // About the agent's own momentary state, not the world.
+        FailureReason::AlreadySatiated { .. } => BeliefRevision::Unchanged,
+        // The target is fine; the agent just isn't next to it yet.
+        FailureReason::TooFar => BeliefRevision::Unchanged,
+        FailureReason::Interrupted => BeliefRevision::Unchanged,
+        FailureReason::AlreadyDone => BeliefRevision::Unchanged,
+        // Water tiles are read from the map, not from beliefs.
+        FailureReason::NoWaterNearby => BeliefRevision::Unchanged,
+        // Inventory beliefs are refreshed by the next self-perception.
+        FailureReason::MissingMaterials => BeliefRevision::Unchanged,
+        FailureReason::ConversationFull => BeliefRevision::Unchanged,
+        // About the agent's own competence, not the world.
+        FailureReason::Unskilled { .. } => BeliefRevision::Unchanged,
+    }
+}
+
+/// Apply the failure's belief revision. Returns the informants whose
+/// hearsay the revision contradicted.
 fn handle_failure_outcome(
     mind: &mut MindGraph,
     target: &Option<Entity>,
     reason: &FailureReason,
     current_time: u64,
-) {
-    match reason {
-        FailureReason::ResourceDepleted => {
-            // Zero out every `Contains` belief the agent held about this
-            // target. Before #416 this hardcoded `Apple` — so Harvest
-            // failures against BerryBush/Corpse/WoodLog left the stale
-            // belief untouched and the planner kept regenerating the same
-            // doomed plan. Clearing per-concept reflects the semantics of
-            // "this target is empty" regardless of what it was storing.
+) -> Vec<Entity> {
+    match belief_revision(reason) {
+        BeliefRevision::Unchanged => {}
+        BeliefRevision::TargetEmpty => {
             if let Some(target_entity) = target {
-                let existing: Vec<Value> = mind
-                    .query(
-                        Some(&Node::Entity(*target_entity)),
-                        Some(Predicate::Contains),
-                        None,
-                    )
-                    .into_iter()
-                    .map(|t| t.object.clone())
-                    .collect();
-                for value in existing {
-                    if let Value::Item(concept, _) = value {
-                        mind.assert(Triple::with_meta(
-                            Node::Entity(*target_entity),
-                            Predicate::Contains,
-                            Value::Item(concept, 0),
-                            Metadata::experience(current_time),
-                        ));
-                    }
-                }
+                return mark_target_empty(mind, *target_entity, current_time);
             }
         }
-        FailureReason::MissingItem(concept) => {
-            mind.perceive_self(Predicate::Contains, Value::Item(*concept, 0), current_time);
+        BeliefRevision::SelfLacks(concept) => {
+            mind.perceive_self(Predicate::Contains, Value::Item(concept, 0), current_time);
         }
-        FailureReason::TargetGone => {}
-        FailureReason::NoEdibleFood => {
+        BeliefRevision::SelfLacksFood => {
             for food_concept in [Concept::Apple, Concept::Berry] {
                 mind.perceive_self(
                     Predicate::Contains,
@@ -150,18 +191,182 @@ fn handle_failure_outcome(
                 );
             }
         }
-        FailureReason::PathBlocked { target_tile } => {
+        BeliefRevision::TileUnreachable(target_tile) => {
             // Record the blocked target so the planner stops picking it.
             // TTL-checked on read in `generate_implicit_walk` via the
             // triple's metadata timestamp — no explicit decay needed.
             mind.assert(Triple::with_meta(
-                Node::Tile(*target_tile),
+                Node::Tile(target_tile),
                 Predicate::HasTrait,
                 Value::Concept(Concept::Unreachable),
                 Metadata::experience(current_time),
             ));
         }
-        _ => {}
+    }
+    Vec::new()
+}
+
+/// Zero out every `Contains` belief the agent held about `target`. Before
+/// #416 this hardcoded `Apple` — so Harvest failures against
+/// BerryBush/Corpse/WoodLog left the stale belief untouched and the
+/// planner kept regenerating the same doomed plan. When the agent planned
+/// from type-level `Produces` knowledge alone there is no `Contains` to
+/// zero, so the produced items are recorded as empty instead; either way
+/// `is_known_empty` turns true and the target drops out of planning.
+/// Returns the informants who had said it was stocked.
+fn mark_target_empty(mind: &mut MindGraph, target: Entity, current_time: u64) -> Vec<Entity> {
+    let mut emptied: Vec<Concept> = mind
+        .query(Some(&Node::Entity(target)), Some(Predicate::Contains), None)
+        .into_iter()
+        .filter_map(|t| match t.object {
+            Value::Item(concept, _) => Some(concept),
+            _ => None,
+        })
+        .collect();
+    if emptied.is_empty() {
+        // Same lookup as Harvest plan validity: entity-level `Produces`,
+        // then whatever its kinds produce.
+        let mut producers = vec![Node::Entity(target)];
+        producers.extend(
+            mind.query(Some(&Node::Entity(target)), Some(Predicate::IsA), None)
+                .into_iter()
+                .filter_map(|t| t.object.as_concept())
+                .map(Node::Concept),
+        );
+        for producer in producers {
+            emptied.extend(
+                mind.query(Some(&producer), Some(Predicate::Produces), None)
+                    .into_iter()
+                    .filter_map(|t| match t.object {
+                        Value::Item(concept, _) => Some(concept),
+                        _ => None,
+                    }),
+            );
+        }
+    }
+    let mut contradicted = Vec::new();
+    for concept in emptied {
+        contradicted.extend(assert_observed(
+            mind,
+            Triple::with_meta(
+                Node::Entity(target),
+                Predicate::Contains,
+                Value::Item(concept, 0),
+                Metadata::experience(current_time),
+            ),
+        ));
+    }
+    contradicted.sort();
+    contradicted.dedup();
+    contradicted
+}
+
+/// How recently a hearsay `Contains` belief must have been heard for a
+/// contradicting observation to count against its informant. Older tips
+/// may just have gone stale.
+pub const HEARSAY_CONTRADICTION_WINDOW_TICKS: u64 = 1200;
+
+/// Informants whose recent hearsay the first-hand `Contains` observation
+/// `observed` contradicts: they said the stash was stocked and it is
+/// empty, or the reverse. Exact counts drift as others harvest, so only
+/// stocked-versus-empty counts as a contradiction.
+pub fn contradicted_informants(mind: &MindGraph, observed: &Triple) -> Vec<Entity> {
+    let Value::Item(concept, quantity) = observed.object else {
+        return Vec::new();
+    };
+    if observed.predicate != Predicate::Contains {
+        return Vec::new();
+    }
+    let mut informants: Vec<Entity> = mind
+        .query(Some(&observed.subject), Some(Predicate::Contains), None)
+        .into_iter()
+        .filter(|t| {
+            t.meta.source == Source::Hearsay
+                && matches!(t.object, Value::Item(c, q) if c == concept && (q > 0) != (quantity > 0))
+                && observed.meta.timestamp.saturating_sub(t.meta.timestamp)
+                    <= HEARSAY_CONTRADICTION_WINDOW_TICKS
+        })
+        .filter_map(|t| t.meta.informant)
+        .collect();
+    informants.sort();
+    informants.dedup();
+    informants
+}
+
+/// Assert a first-hand `Contains` observation, settling any clash with
+/// hearsay in its favour: when it overturns what someone said, the agent
+/// holds it at full confidence. Returns the informants it contradicted.
+pub fn assert_observed(mind: &mut MindGraph, mut observed: Triple) -> Vec<Entity> {
+    let contradicted = contradicted_informants(mind, &observed);
+    if !contradicted.is_empty() {
+        observed.meta.confidence = 1.0;
+    }
+    // Seeing it supersedes the hearsay even when the two agree — a
+    // confirmed tip still tagged as hearsay would be blamed when the
+    // stash later runs dry.
+    if let Value::Item(concept, _) = observed.object {
+        let heard: Vec<Value> = mind
+            .query(Some(&observed.subject), Some(Predicate::Contains), None)
+            .into_iter()
+            .filter(|t| {
+                t.meta.source == Source::Hearsay
+                    && matches!(t.object, Value::Item(c, _) if c == concept)
+            })
+            .map(|t| t.object.clone())
+            .collect();
+        for object in heard {
+            mind.remove(&observed.subject, Predicate::Contains, &object);
+        }
+    }
+    mind.assert(observed);
+    contradicted
+}
+
+/// Lower `observer`'s trust in `informant` after their hearsay was
+/// contradicted. Returns the trust before and after, or `None` if they
+/// have no relationship.
+pub fn discredit(
+    graph: &mut SocialGraph,
+    config: &RelationshipConfig,
+    observer: Entity,
+    informant: Entity,
+    now: u64,
+) -> Option<(f32, f32)> {
+    let edge = graph.get_mut(observer, informant)?;
+    let before = edge.trust;
+    edge.trust = (before - config.hearsay_contradiction_trust_loss).max(0.0);
+    edge.last_interaction_tick = now;
+    Some((before, edge.trust))
+}
+
+/// [`discredit`] every contradicted informant, logging each trust change.
+pub fn discredit_informants(
+    graph: &mut SocialGraph,
+    config: &RelationshipConfig,
+    observer: Entity,
+    informants: &[Entity],
+    now: u64,
+    sim_events: &mut MessageWriter<SimEvent>,
+) {
+    for &informant in informants {
+        let Some((old_value, new_value)) = discredit(graph, config, observer, informant, now)
+        else {
+            continue;
+        };
+        if (new_value - old_value).abs() > f32::EPSILON {
+            sim_events.write(SimEvent::pair(
+                now,
+                observer,
+                informant,
+                SimEventKind::RelationshipChanged {
+                    agent: observer,
+                    other: informant,
+                    dimension: RelationshipDimension::Trust,
+                    old_value,
+                    new_value,
+                },
+            ));
+        }
     }
 }
 
@@ -172,7 +377,7 @@ fn generate_satisfaction_joy(
     state: &mut EmotionalState,
     agent: Entity,
     tick: u64,
-    sim_events: &mut MessageWriter<crate::agent::events::SimEvent>,
+    sim_events: &mut MessageWriter<SimEvent>,
 ) {
     let hunger_joy = (sat.hunger_reduced / 100.0) * (sat.pre_hunger / 100.0);
     let thirst_joy = (sat.thirst_reduced / 100.0) * (sat.pre_thirst / 100.0);
@@ -196,7 +401,7 @@ fn generate_failure_frustration(
     state: &mut EmotionalState,
     agent: Entity,
     tick: u64,
-    sim_events: &mut MessageWriter<crate::agent::events::SimEvent>,
+    sim_events: &mut MessageWriter<SimEvent>,
 ) {
     let urgency = match reason {
         FailureReason::NoEdibleFood | FailureReason::MissingItem(_) => needs.hunger_urgency(),
@@ -290,4 +495,137 @@ mod tests {
             "low urgency ({urgency}) should not trigger frustration"
         );
     }
+
+    fn bush_mind(bush: Entity) -> MindGraph {
+        let mut mind = MindGraph::new(crate::agent::mind::knowledge::setup_ontology());
+        mind.assert(Triple::with_meta(
+            Node::Entity(bush),
+            Predicate::IsA,
+            Value::Concept(Concept::BerryBush),
+            Metadata::perception(0),
+        ));
+        mind.assert(Triple::with_meta(
+            Node::Concept(Concept::BerryBush),
+            Predicate::Produces,
+            Value::Item(Concept::Berry, 1),
+            Metadata::semantic(0),
+        ));
+        mind
+    }
+
+    #[test]
+    fn harvest_empty_failure_marks_target_known_empty() {
+        use crate::agent::actions::GenericAction;
+        use crate::agent::actions::action::HARVEST_DEF;
+        use crate::agent::actions::registry::{Action, TargetCandidate};
+
+        let bush = Entity::from_bits(42);
+        let mut mind = bush_mind(bush);
+        let harvest = GenericAction::new(&HARVEST_DEF);
+        let candidate = TargetCandidate::Entity {
+            entity: bush,
+            pos: Vec2::ZERO,
+        };
+        assert!(harvest.is_plan_valid(&candidate, &mind));
+
+        handle_failure_outcome(&mut mind, &Some(bush), &FailureReason::ResourceDepleted, 10);
+
+        assert!(mind.is_known_empty(bush));
+        assert!(
+            !harvest.is_plan_valid(&candidate, &mind),
+            "a bush that just came up empty should not be re-planned"
+        );
+    }
+
+    #[test]
+    fn too_far_failure_leaves_target_beliefs_alone() {
+        let bush = Entity::from_bits(42);
+        let mut mind = bush_mind(bush);
+
+        assert_eq!(
+            belief_revision(&FailureReason::TooFar),
+            BeliefRevision::Unchanged
+        );
+        handle_failure_outcome(&mut mind, &Some(bush), &FailureReason::TooFar, 10);
+
+        assert!(!mind.is_known_empty(bush));
+        assert!(
+            mind.query(Some(&Node::Entity(bush)), Some(Predicate::Contains), None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn contradicted_hearsay_costs_the_informant_trust() {
+        use crate::agent::psyche::social_graph::RelationshipEdge;
+
+        let bush = Entity::from_bits(42);
+        let observer = Entity::from_bits(1);
+        let informant = Entity::from_bits(9);
+        let config = RelationshipConfig::default();
+        let mut graph = SocialGraph::default();
+        graph.set(observer, informant, RelationshipEdge::default());
+
+        // Told the bush is full...
+        let mut mind = bush_mind(bush);
+        mind.assert(Triple::with_meta(
+            Node::Entity(bush),
+            Predicate::Contains,
+            Value::Item(Concept::Berry, 5),
+            Metadata::hearsay(100, informant),
+        ));
+        // ...then sees it bare.
+        let contradicted = assert_observed(
+            &mut mind,
+            Triple::with_meta(
+                Node::Entity(bush),
+                Predicate::Contains,
+                Value::Item(Concept::Berry, 0),
+                Metadata::experience(200),
+            ),
+        );
+        assert_eq!(contradicted, vec![informant]);
+        assert!(mind.is_known_empty(bush));
+
+        let before = graph.trust(observer, informant);
+        for informant in contradicted {
+            discredit(&mut graph, &config, observer, informant, 200);
+        }
+        let after = graph.trust(observer, informant);
+        assert!(
+            (before - after - config.hearsay_contradiction_trust_loss).abs() < 1e-6,
+            "trust should drop by the configured penalty ({before} -> {after})"
+        );
+    }
+
+    #[test]
+    fn stale_or_agreeing_hearsay_is_not_a_contradiction() {
+        let bush = Entity::from_bits(42);
+        let informant = Entity::from_bits(9);
+        let mut mind = bush_mind(bush);
+        mind.assert(Triple::with_meta(
+            Node::Entity(bush),
+            Predicate::Contains,
+            Value::Item(Concept::Berry, 5),
+            Metadata::hearsay(100, informant),
+        ));
+        let seen = |quantity, tick| {
+            Triple::with_meta(
+                Node::Entity(bush),
+                Predicate::Contains,
+                Value::Item(Concept::Berry, quantity),
+                Metadata::experience(tick),
+            )
+        };
+
+        assert!(contradicted_informants(&mind, &seen(2, 200)).is_empty());
+        assert!(
+            contradicted_informants(
+                &mind,
+                &seen(0, 100 + HEARSAY_CONTRADICTION_WINDOW_TICKS + 1)
+            )
+            .is_empty(),
+            "a stale tip may just be out of date"
+        );
+    }
 }
@@ -0,0 +1,214 @@
+//! Population-level belief census: how one (subject, predicate) belief is
+//! distributed across every agent's MindGraph.
+//!
+//! Reads: MindGraph (every agent passed in)
+//! Writes: nothing — returns a `BeliefCensus` for export
+//! Upstream: headless inspection (`--belief-census`), `TestWorld::belief_census`
+//! Downstream: JSON lines for offline analysis of information spread
+//!
+//! Where `--dump-mind` shows everything one agent believes, a census shows
+//! one belief across everyone: which objects agents hold for the pair, what
+//! fraction of the population holds each, and how confidently. Each agent
+//! counts at most once per object, at the highest confidence it holds it
+//! with. Agents holding nothing for the pair still count toward the
+//! population, so a fraction is prevalence across the whole population.
+
+use bevy::reflect::{DynamicEnum, DynamicVariant, FromReflect, TypeInfo, Typed};
+use serde_json::{Value as Json, json};
+
+use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+
+/// One object held for the censused pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectShare {
+    pub object: Value,
+    /// Agents holding this object.
+    pub holders: usize,
+    /// `holders / population`.
+    pub fraction: f32,
+    /// Mean confidence across the holders.
+    pub mean_confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeliefCensus {
+    pub subject: Node,
+    pub predicate: Predicate,
+    /// Minds examined.
+    pub population: usize,
+    /// Agents holding any object for the pair.
+    pub holders: usize,
+    /// Most widely held first; ties keep first-seen order.
+    pub objects: Vec<ObjectShare>,
+}
+
+/// Tally `(subject, predicate, ?)` across `minds`.
+pub fn belief_census<'a>(
+    subject: &Node,
+    predicate: Predicate,
+    minds: impl IntoIterator<Item = &'a MindGraph>,
+) -> BeliefCensus {
+    let mut population = 0;
+    let mut holders = 0;
+    // (object, holders, summed confidence)
+    let mut tally: Vec<(Value, usize, f32)> = Vec::new();
+
+    for mind in minds {
+        population += 1;
+        let mut held: Vec<(&Value, f32)> = Vec::new();
+        for triple in mind.query(Some(subject), Some(predicate), None) {
+            match held
+                .iter_mut()
+                .find(|(object, _)| **object == triple.object)
+            {
+                Some((_, confidence)) => *confidence = confidence.max(triple.meta.confidence),
+                None => held.push((&triple.object, triple.meta.confidence)),
+            }
+        }
+        if !held.is_empty() {
+            holders += 1;
+        }
+        for (object, confidence) in held {
+            match tally.iter_mut().find(|(o, ..)| o == object) {
+                Some((_, count, sum)) => {
+                    *count += 1;
+                    *sum += confidence;
+                }
+                None => tally.push((object.clone(), 1, confidence)),
+            }
+        }
+    }
+
+    let mut objects: Vec<ObjectShare> = tally
+        .into_iter()
+        .map(|(object, count, sum)| ObjectShare {
+            object,
+            holders: count,
+            fraction: count as f32 / population as f32,
+            mean_confidence: sum / count as f32,
+        })
+        .collect();
+    objects.sort_by(|a, b| b.holders.cmp(&a.holders));
+
+    BeliefCensus {
+        subject: subject.clone(),
+        predicate,
+        population,
+        holders,
+        objects,
+    }
+}
+
+impl BeliefCensus {
+    /// One JSON object per census, with nodes and values in their `Debug`
+    /// form (the same spelling `--dump-mind` prints).
+    pub fn to_json(&self, tick: u64) -> Json {
+        let objects: Vec<Json> = self
+            .objects
+            .iter()
+            .map(|share| {
+                json!({
+                    "object": format!("{:?}", share.object),
+                    "holders": share.holders,
+                    "fraction": share.fraction,
+                    "mean_confidence": share.mean_confidence,
+                })
+            })
+            .collect();
+        json!({
+            "tick": tick,
+            "subject": format!("{:?}", self.subject),
+            "predicate": format!("{:?}", self.predicate),
+            "population": self.population,
+            "holders": self.holders,
+            "objects": objects,
+        })
+    }
+}
+
+/// Parse a census subject that isn't an entity: a concept name
+/// (case-insensitive, e.g. `berry`) or a tile as `x,y`. Entities are
+/// resolved by the caller, which knows the world.
+pub fn parse_subject(text: &str) -> Option<Node> {
+    if let Some((x, y)) = text.split_once(',') {
+        return Some(Node::Tile((x.trim().parse().ok()?, y.trim().parse().ok()?)));
+    }
+    parse_unit_variant::<Concept>(text).map(Node::Concept)
+}
+
+/// Parse a predicate name, case-insensitively (`locatedat`, `HasTrait`).
+pub fn parse_predicate(text: &str) -> Option<Predicate> {
+    parse_unit_variant(text)
+}
+
+fn parse_unit_variant<T: Typed + FromReflect>(name: &str) -> Option<T> {
+    let TypeInfo::Enum(info) = T::type_info() else {
+        return None;
+    };
+    let variant = info.iter().find(|v| v.name().eq_ignore_ascii_case(name))?;
+    T::from_reflect(&DynamicEnum::new(variant.name(), DynamicVariant::Unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::{Metadata, Triple};
+    use bevy::prelude::Entity;
+
+    fn mind_with(beliefs: &[(Value, f32)]) -> MindGraph {
+        let mut mind = MindGraph::default();
+        let bush = Node::Entity(Entity::from_bits(7));
+        for (object, confidence) in beliefs {
+            mind.assert(Triple::with_meta(
+                bush.clone(),
+                Predicate::LocatedAt,
+                object.clone(),
+                Metadata {
+                    confidence: *confidence,
+                    ..Metadata::default()
+                },
+            ));
+        }
+        mind
+    }
+
+    #[test]
+    fn a_fact_known_by_some_agents_reports_its_prevalence() {
+        let here = Value::Tile((3, 4));
+        let there = Value::Tile((9, 9));
+        let minds = [
+            mind_with(&[(here.clone(), 0.9)]),
+            mind_with(&[(here.clone(), 0.5)]),
+            mind_with(&[(there.clone(), 0.4)]),
+            mind_with(&[]),
+        ];
+
+        let census = belief_census(
+            &Node::Entity(Entity::from_bits(7)),
+            Predicate::LocatedAt,
+            &minds,
+        );
+
+        assert_eq!(census.population, 4);
+        assert_eq!(census.holders, 3);
+        assert_eq!(census.objects.len(), 2);
+        let top = &census.objects[0];
+        assert_eq!(top.object, here);
+        assert_eq!(top.holders, 2);
+        assert!((top.fraction - 0.5).abs() < 1e-6);
+        assert!((top.mean_confidence - 0.7).abs() < 1e-6);
+        assert!((census.objects[1].fraction - 0.25).abs() < 1e-6);
+
+        let exported = census.to_json(10);
+        assert_eq!(exported["holders"], 3);
+        assert_eq!(exported["objects"][0]["holders"], 2);
+    }
+
+    #[test]
+    fn parses_concept_and_tile_subjects_and_predicates() {
+        assert_eq!(parse_subject("berry"), Some(Node::Concept(Concept::Berry)));
+        assert_eq!(parse_subject("3, -2"), Some(Node::Tile((3, -2))));
+        assert_eq!(parse_subject("nonsense"), None);
+        assert_eq!(parse_predicate("locatedat"), Some(Predicate::LocatedAt));
+    }
+}
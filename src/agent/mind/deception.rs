@@ -0,0 +1,104 @@
+//! Deception: when a speaker lies and what the lie says.
+//!
+//! Reads: Personality (speaker agreeableness), SocialGraph (speaker → listener affection)
+//! Writes: Turn content (one falsified triple)
+//! Upstream: engagement::converse::select_turn_intent (builds the turn)
+//! Downstream: converse::process_received_communication + memory (deceptive content heard at lower confidence),
+//!             belief_updater (first-hand contradiction costs the informant trust)
+//!
+//! Only a disagreeable speaker talking to someone they dislike ever lies,
+//! and only some of the time. The lie contradicts what the speaker believes
+//! about a stash: a stocked bush is reported empty, an empty one stocked.
+//! A liar's delivery gives them away a little, so listeners take deceptive
+//! content at a fraction of the usual hearsay confidence. When a listener
+//! later sees the stash for themselves, `belief_updater::assert_observed`
+//! catches the contradiction and the informant loses some of their trust.
+
+use crate::agent::mind::knowledge::{Predicate, Triple, Value};
+use crate::agent::psyche::social_graph::NEUTRAL;
+
+/// Lie chance for the most disagreeable speaker facing someone they loathe.
+pub const MAX_LIE_CHANCE: f32 = 0.5;
+/// Quantity a liar claims an empty stash holds.
+pub const LIE_CLAIMED_QUANTITY: u32 = 3;
+/// Share of the usual hearsay confidence a deceptive turn is believed at.
+pub const DECEPTIVE_CONFIDENCE_SCALE: f32 = 0.5;
+
+/// How reliably a speaker tells the truth, in `[1 - MAX_LIE_CHANCE, 1]`.
+/// Agreeable speakers and anyone talking to a friend or neutral party are
+/// fully truthful; lying takes both a disagreeable nature and dislike.
+pub fn truthfulness(agreeableness: f32, affection: f32) -> f32 {
+    let disagreeable = ((NEUTRAL - agreeableness) / NEUTRAL).clamp(0.0, 1.0);
+    let hostile = ((NEUTRAL - affection) / NEUTRAL).clamp(0.0, 1.0);
+    1.0 - MAX_LIE_CHANCE * disagreeable * hostile
+}
+
+/// Turn the first stash claim in `content` into its opposite: a stocked
+/// `Contains` becomes empty and an empty one stocked. Returns whether
+/// anything was falsified — content with no stash claim stays honest.
+pub fn falsify(content: &mut [Triple]) -> bool {
+    let Some(triple) = content
+        .iter_mut()
+        .find(|t| t.predicate == Predicate::Contains && matches!(t.object, Value::Item(..)))
+    else {
+        return false;
+    };
+    if let Value::Item(concept, quantity) = triple.object {
+        let claimed = if quantity > 0 {
+            0
+        } else {
+            LIE_CLAIMED_QUANTITY
+        };
+        triple.object = Value::Item(concept, claimed);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::{Concept, Node};
+    use bevy::prelude::Entity;
+
+    fn stash(quantity: u32) -> Triple {
+        Triple::new(
+            Node::Entity(Entity::from_bits(7)),
+            Predicate::Contains,
+            Value::Item(Concept::Berry, quantity),
+        )
+    }
+
+    #[test]
+    fn only_disagreeable_speakers_lie_and_only_to_people_they_dislike() {
+        assert_eq!(truthfulness(0.8, 0.1), 1.0);
+        assert_eq!(truthfulness(0.1, 0.8), 1.0);
+        assert_eq!(truthfulness(0.5, 0.5), 1.0);
+        let liar = truthfulness(0.0, 0.0);
+        assert!((liar - (1.0 - MAX_LIE_CHANCE)).abs() < 1e-6);
+        assert!(truthfulness(0.2, 0.2) > liar);
+    }
+
+    #[test]
+    fn falsify_flips_the_first_stash_claim() {
+        let mut content = vec![
+            Triple::new(
+                Node::Entity(Entity::from_bits(7)),
+                Predicate::IsA,
+                Value::Concept(Concept::BerryBush),
+            ),
+            stash(5),
+        ];
+        assert!(falsify(&mut content));
+        assert_eq!(content[1].object, Value::Item(Concept::Berry, 0));
+
+        let mut empty = vec![stash(0)];
+        assert!(falsify(&mut empty));
+        assert_eq!(
+            empty[0].object,
+            Value::Item(Concept::Berry, LIE_CLAIMED_QUANTITY)
+        );
+
+        let mut nothing_to_twist = vec![content[0].clone()];
+        assert!(!falsify(&mut nothing_to_twist));
+    }
+}
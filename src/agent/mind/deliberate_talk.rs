@@ -82,6 +82,40 @@ pub fn pick_deliberate_content(
     (triples, topic)
 }
 
+/// Pick up to `n` triples from `speaker_mind` that answer "where can I find
+/// `concept`?": sources holding it, places it's been seen, areas said to
+/// contain it. Scored like deliberate content, minus the goal bonus — the
+/// question sets the subject, not the answerer's own plans.
+pub fn pick_answer_content(
+    speaker_mind: &MindGraph,
+    concept: Concept,
+    speaker_tom: Option<&TheoryOfMind>,
+    listener: Entity,
+    now: u64,
+    n: usize,
+) -> (Vec<Triple>, Topic) {
+    let mut scored: Vec<(f32, &Triple)> = speaker_mind
+        .iter()
+        .filter(|t| is_deliberate_shareable(t) && answers_location_of(t, concept))
+        .map(|t| (score_deliberate(t, None, speaker_tom, listener, now), t))
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let triples: Vec<Triple> = scored.into_iter().take(n).map(|(_, t)| t.clone()).collect();
+    (triples, Topic::Location(concept))
+}
+
+fn answers_location_of(triple: &Triple, concept: Concept) -> bool {
+    match triple.predicate {
+        Predicate::Contains => {
+            triple.subject != Node::Self_
+                && matches!(triple.object, Value::Item(c, _) | Value::Concept(c) if c == concept)
+        }
+        Predicate::LocatedAt => triple.subject == Node::Concept(concept),
+        _ => false,
+    }
+}
+
 // ============================================================================
 // Filtering
 // ============================================================================
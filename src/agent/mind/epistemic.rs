@@ -1,4 +1,4 @@
-use crate::agent::brains::thinking::TriplePattern;
+use crate::agent::brains::thinking::{Goal, TriplePattern};
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
 use bevy::prelude::*;
 
@@ -9,6 +9,32 @@ pub enum EpistemicGoal {
     KnowItemAt(Entity),      // "What is in this container?"
 }
 
+/// Every knowledge gap blocking `goal`: conditions the agent can't work
+/// toward because it holds no belief about where to satisfy them.
+/// Deduplicated, in condition order.
+pub fn knowledge_gaps(goal: &Goal, mind: &MindGraph) -> Vec<EpistemicGoal> {
+    let mut gaps = Vec::new();
+    for condition in &goal.conditions {
+        if let Some(gap) = identify_knowledge_gap(condition, mind)
+            && !gaps.contains(&gap)
+        {
+            gaps.push(gap);
+        }
+    }
+    gaps
+}
+
+/// The first thing `goal` needs that the agent doesn't know where to find —
+/// what it should be asking others about.
+pub fn location_gap(goal: &Goal, mind: &MindGraph) -> Option<Concept> {
+    knowledge_gaps(goal, mind)
+        .into_iter()
+        .find_map(|gap| match gap {
+            EpistemicGoal::KnowLocationOf(concept) => Some(concept),
+            EpistemicGoal::KnowItemAt(_) => None,
+        })
+}
+
 /// Detect what knowledge is missing for a goal
 pub fn identify_knowledge_gap(goal: &TriplePattern, mind: &MindGraph) -> Option<EpistemicGoal> {
     // Case 1: Goal is "Have Item(X)" but we don't know where X is
@@ -55,3 +81,49 @@ pub fn identify_knowledge_gap(goal: &TriplePattern, mind: &MindGraph) -> Option<
 fn is_possession_goal(pattern: &TriplePattern) -> bool {
     pattern.subject == Some(Node::Self_) && pattern.predicate == Some(Predicate::Contains)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::{Metadata, Triple};
+
+    fn wants(concept: Concept) -> Goal {
+        Goal {
+            conditions: vec![TriplePattern::new(
+                Some(Node::Self_),
+                Some(Predicate::Contains),
+                Some(Value::Item(concept, 1)),
+            )],
+            priority: 1.0,
+        }
+    }
+
+    #[test]
+    fn a_goal_item_with_no_known_source_is_a_gap() {
+        let mut mind = MindGraph::default();
+        let tree = Entity::from_bits(7);
+        mind.assert(Triple::with_meta(
+            Node::Entity(tree),
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 3),
+            Metadata::default(),
+        ));
+        // Carrying a berry isn't knowing where to get more.
+        mind.assert(Triple::with_meta(
+            Node::Self_,
+            Predicate::Contains,
+            Value::Item(Concept::Berry, 1),
+            Metadata::default(),
+        ));
+
+        assert_eq!(location_gap(&wants(Concept::Apple), &mind), None);
+        assert_eq!(
+            knowledge_gaps(&wants(Concept::Berry), &mind),
+            vec![EpistemicGoal::KnowLocationOf(Concept::Berry)]
+        );
+        assert_eq!(
+            location_gap(&wants(Concept::Berry), &mind),
+            Some(Concept::Berry)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Hearsay intake: paces how fast an agent takes in what it is told.
+//!
+//! Reads: HearsayIntakeConfig, TickCount
+//! Writes: HearsayIntake (per-listener queues), MindGraph (hearsay triples, a few per window)
+//! Upstream: engagement::converse::process_received_communication, memory::process_working_memory (KnowledgeShared)
+//! Downstream: rational brain (plans against integrated beliefs), conversation (listeners can only pass on what they've integrated)
+//!
+//! Asserting hearsay the moment it was heard let one popular fact ripple
+//! through a whole camp in a single tick: everyone within earshot learned
+//! it, then told their neighbours on the same tick. Heard triples now land
+//! in a per-listener queue and [`integrate_hearsay`] moves at most
+//! `max_per_window` of them into the MindGraph per window, so cultural
+//! knowledge spreads at the pace of people actually digesting it. A normal
+//! conversation turn fits inside one window; only floods are throttled.
+
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::agent::mind::knowledge::{MindGraph, Triple};
+use crate::core::tick::TickCount;
+
+/// Rate limit on hearsay integration, per listener.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct HearsayIntakeConfig {
+    /// Heard triples integrated per window.
+    pub max_per_window: u32,
+    /// Window length in ticks.
+    pub window_ticks: u64,
+    /// Triples a listener can have waiting. Anything heard past this is
+    /// forgotten before it sinks in.
+    pub max_pending: usize,
+}
+
+impl Default for HearsayIntakeConfig {
+    fn default() -> Self {
+        Self {
+            max_per_window: 4,
+            window_ticks: 10,
+            max_pending: 32,
+        }
+    }
+}
+
+/// One listener's backlog of heard-but-not-yet-believed triples.
+#[derive(Debug, Clone, Default)]
+pub struct ListenerQueue {
+    pub pending: VecDeque<Triple>,
+    pub window_start: u64,
+    pub integrated_in_window: u32,
+}
+
+/// Heard triples waiting to be integrated, keyed by listener.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HearsayIntake {
+    pub queues: HashMap<Entity, ListenerQueue>,
+}
+
+impl HearsayIntake {
+    /// Queue `triple` for `listener`. A triple already waiting with the same
+    /// content and informant is not queued twice, and a full queue drops the
+    /// new arrival. Returns whether it was queued.
+    pub fn enqueue(&mut self, listener: Entity, triple: Triple, max_pending: usize) -> bool {
+        let queue = self.queues.entry(listener).or_default();
+        let duplicate = queue.pending.iter().any(|t| {
+            t.subject == triple.subject
+                && t.predicate == triple.predicate
+                && t.object == triple.object
+                && t.meta.informant == triple.meta.informant
+        });
+        if duplicate || queue.pending.len() >= max_pending {
+            return false;
+        }
+        queue.pending.push_back(triple);
+        true
+    }
+
+    /// Triples still waiting for `listener`.
+    pub fn pending(&self, listener: Entity) -> usize {
+        self.queues.get(&listener).map_or(0, |q| q.pending.len())
+    }
+}
+
+/// Bevy system (FixedUpdate): move each listener's queued hearsay into its
+/// MindGraph, at most `max_per_window` per window. Queues of despawned or
+/// mindless listeners are dropped, as are drained queues whose window has
+/// closed.
+pub fn integrate_hearsay(
+    config: Res<HearsayIntakeConfig>,
+    tick: Res<TickCount>,
+    mut intake: ResMut<HearsayIntake>,
+    mut minds: Query<&mut MindGraph>,
+) {
+    let now = tick.current;
+    intake.queues.retain(|listener, queue| {
+        let Ok(mut mind) = minds.get_mut(*listener) else {
+            return false;
+        };
+        let window_closed = now >= queue.window_start + config.window_ticks;
+        if window_closed {
+            if queue.pending.is_empty() {
+                return false;
+            }
+            queue.window_start = now;
+            queue.integrated_in_window = 0;
+        }
+        while queue.integrated_in_window < config.max_per_window {
+            let Some(triple) = queue.pending.pop_front() else {
+                break;
+            };
+            mind.assert(triple);
+            queue.integrated_in_window += 1;
+        }
+        true
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::{
+        Concept, Metadata, Node, Predicate, Source, Value, setup_ontology,
+    };
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn hearsay_count(world: &World, listener: Entity) -> usize {
+        world
+            .get::<MindGraph>(listener)
+            .unwrap()
+            .iter()
+            .filter(|t| t.meta.source == Source::Hearsay)
+            .count()
+    }
+
+    #[test]
+    fn flood_of_shared_facts_is_integrated_over_several_windows() {
+        let mut world = World::new();
+        let config = HearsayIntakeConfig::default();
+        world.insert_resource(config.clone());
+        world.insert_resource(TickCount::default());
+        let listener = world.spawn(MindGraph::new(setup_ontology())).id();
+        let speaker = world.spawn_empty().id();
+
+        // Ten different places the speaker says hold apples.
+        let flood = 10u32;
+        let mut intake = HearsayIntake::default();
+        for i in 0..flood {
+            let heard = Triple::with_meta(
+                Node::Entity(Entity::from_bits(100 + u64::from(i))),
+                Predicate::Contains,
+                Value::Item(Concept::Apple, 3),
+                Metadata::hearsay(0, speaker),
+            );
+            assert!(intake.enqueue(listener, heard.clone(), config.max_pending));
+            assert!(
+                !intake.enqueue(listener, heard, config.max_pending),
+                "a repeated fact is queued once"
+            );
+        }
+        world.insert_resource(intake);
+
+        world.run_system_once(integrate_hearsay).unwrap();
+        let cap = config.max_per_window as usize;
+        assert_eq!(hearsay_count(&world, listener), cap);
+
+        // Same window: nothing more sinks in.
+        world.resource_mut::<TickCount>().current = config.window_ticks - 1;
+        world.run_system_once(integrate_hearsay).unwrap();
+        assert_eq!(hearsay_count(&world, listener), cap);
+
+        let mut windows = 1;
+        while world.resource::<HearsayIntake>().pending(listener) > 0 {
+            world.resource_mut::<TickCount>().current += config.window_ticks;
+            world.run_system_once(integrate_hearsay).unwrap();
+            windows += 1;
+            assert!(hearsay_count(&world, listener) <= cap * windows);
+        }
+        assert_eq!(hearsay_count(&world, listener), flood as usize);
+        assert!(windows >= 3, "flood took {windows} windows");
+    }
+}
@@ -10,12 +10,48 @@ use smallvec::SmallVec;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Serde adapter writing an `Entity` as its `to_bits()` number, so a saved
+/// MindGraph stores each reference as one plain integer.
+mod entity_bits {
+    use bevy::prelude::Entity;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(entity: &Entity, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(entity.to_bits())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Entity, D::Error> {
+        u64::deserialize(deserializer).map(Entity::from_bits)
+    }
+
+    pub mod option {
+        use bevy::prelude::Entity;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            entity: &Option<Entity>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match entity {
+                Some(e) => serializer.serialize_some(&e.to_bits()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Entity>, D::Error> {
+            Ok(Option::<u64>::deserialize(deserializer)?.map(Entity::from_bits))
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // NODES — What can be subject or object in a triple
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// A named area (e.g., "Forest", "River Bank").
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize)]
 pub struct AreaId(pub String);
 
 impl std::fmt::Display for AreaId {
@@ -25,7 +61,7 @@ impl std::fmt::Display for AreaId {
 }
 
 /// A typed agent name — prevents accidental comparison against arbitrary strings.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize)]
 pub struct AgentName(pub String);
 
 impl std::fmt::Display for AgentName {
@@ -35,7 +71,9 @@ impl std::fmt::Display for AgentName {
 }
 
 /// Cardinal/ordinal direction for imprecise perception (hearing, smell).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize,
+)]
 pub enum CardinalDirection {
     North,
     NorthEast,
@@ -67,10 +105,10 @@ impl CardinalDirection {
 }
 
 /// A node in the knowledge graph — can be a subject or object
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize)]
 pub enum Node {
     /// A specific game entity (Tree42, Alice)
-    Entity(Entity),
+    Entity(#[serde(with = "entity_bits")] Entity),
     /// Abstract concept (Food, Friendly)
     Concept(Concept),
     /// A tile location
@@ -93,7 +131,9 @@ pub enum Node {
 // CONCEPTS — Unified enum for all describable things
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize, serde::Deserialize,
+)]
 pub enum Concept {
     // ─── Base categories ───
     #[default]
@@ -130,6 +170,8 @@ pub enum Concept {
     Fish,
     Corpse,
     SeveredPart,
+    /// Loose items dropped on the ground, collectable with Pickup.
+    GroundItem,
 
     // ─── Episodic event subjects ───
     /// Marker concept for death events recorded in episodic memory:
@@ -223,6 +265,9 @@ pub enum Concept {
     /// Predator target enumeration weighs Lame entities higher — wolves
     /// pick the limping deer over the healthy one.
     Lame,
+    /// Set on agents whose hunger shows — gaunt, eyeing others' food.
+    /// The emotional brain offers Give to hungry friends.
+    Hungry,
 
     // ─── Apparent Moods (visible expressions) ───
     HappyMood,
@@ -243,7 +288,9 @@ pub enum Concept {
 // PREDICATES — Relationships between nodes
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize,
+)]
 pub enum Predicate {
     // ─── Classification ───
     IsA,      // (Apple, IsA, Food)
@@ -252,6 +299,11 @@ pub enum Predicate {
     // ─── Spatial ───
     LocatedAt, // (Tree42, LocatedAt, Tile(5,3))
     Contains,  // (Tree42, Contains, Apple(3))
+    /// `(Bob, LastKnownAt, Tile(x,y))` — where a mobile entity was last
+    /// seen before it left view. Semantic and lower-confidence than the
+    /// `LocatedAt` perception it was copied from; written and cleared by
+    /// `mind::sight_memory`.
+    LastKnownAt,
     /// `(Self, Near, Concept(X))` — self is at the same tile as some known
     /// entity of concept `X`. Pure planner-level abstraction: never stored
     /// in the mindgraph; resolved at plan time by checking whether a known
@@ -327,6 +379,7 @@ pub enum Predicate {
     // ─── Sensory ───
     ProducedSound, // (Direction::North, ProducedSound, Concept(Howl)) — heard a sound
     EmitsHeat,     // (Tile(x,y), EmitsHeat, Float(intensity)) — felt warmth
+    Heard,         // (Bob, Heard, Action(Attack)) — heard an action out of sight
 }
 
 impl Predicate {
@@ -334,6 +387,7 @@ impl Predicate {
         matches!(
             self,
             Predicate::LocatedAt
+                | Predicate::LastKnownAt
                 | Predicate::Hunger
                 | Predicate::Thirst
                 | Predicate::Stamina
@@ -373,7 +427,9 @@ impl Predicate {
 /// Each bucket covers a range of normalized 0..1 values. Brains that read a
 /// qualitative belief use `lower_bound` / `upper_bound` to decide whether the
 /// underlying value clears a threshold, instead of pretending it's exact.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize,
+)]
 pub enum Magnitude {
     Trace,    // ~0.05 — "barely any"
     Low,      // ~0.25 — "a little"
@@ -431,7 +487,7 @@ const AROUND_TOLERANCE: f32 = 0.25;
 /// the last rung the triple is forgotten. Agents read via `at_least`, `compare`,
 /// and `point_estimate` instead of destructuring raw floats so every brain path
 /// handles every variant uniformly.
-#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, serde::Serialize, serde::Deserialize)]
 pub enum Quantity {
     /// Self-sensed or freshly counted. The agent has a ground-truth number.
     Exact(f32),
@@ -537,12 +593,12 @@ pub enum FuzzyOrdering {
 // VALUES — What predicates evaluate to
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, PartialEq, Reflect)]
+#[derive(Debug, Clone, PartialEq, Reflect, serde::Serialize, serde::Deserialize)]
 pub enum Value {
     Boolean(bool),      // Truth value
     Quantity(Quantity), // Precision-aware numeric belief
     Concept(Concept),
-    Entity(Entity),
+    Entity(#[serde(with = "entity_bits")] Entity),
     Tile((i32, i32)),
     Action(crate::agent::actions::ActionType),
     Emotion(crate::agent::psyche::emotions::EmotionType, f32),
@@ -601,13 +657,25 @@ impl Value {
             _ => self == other,
         }
     }
+
+    /// Returns `true` when two stored values describe the same fact for
+    /// compaction purposes. Emotion readings of the same type are one fact
+    /// at different intensities; every other variant uses strict equality.
+    pub fn is_equivalent(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Emotion(ea, _), Value::Emotion(eb, _)) => ea == eb,
+            _ => self == other,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 // MEMORY TYPES
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize, serde::Deserialize,
+)]
 pub enum MemoryType {
     /// Universal truths (laws of physics, logic)
     /// Never decays. Shared across all agents.
@@ -639,7 +707,9 @@ pub enum MemoryType {
 // METADATA — Information about the knowledge
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, serde::Serialize, serde::Deserialize,
+)]
 pub enum Source {
     #[default]
     Intrinsic, // Laws of the universe
@@ -674,7 +744,9 @@ impl Source {
 // SENSE — Which perceptual channel produced a triple
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize,
+)]
 pub enum Sense {
     /// Requires line-of-sight, high precision, medium range (~256px)
     Sight,
@@ -686,7 +758,7 @@ pub enum Sense {
     Smell,
 }
 
-#[derive(Debug, Clone, Reflect)]
+#[derive(Debug, Clone, PartialEq, Reflect, serde::Serialize, serde::Deserialize)]
 pub struct Metadata {
     /// How did I learn this?
     pub source: Source,
@@ -701,6 +773,7 @@ pub struct Metadata {
     pub confidence: f32,
 
     /// Who told me? (for communicated knowledge)
+    #[serde(with = "entity_bits::option")]
     pub informant: Option<Entity>,
 
     /// What events support this belief?
@@ -836,19 +909,47 @@ impl Metadata {
         }
     }
 
+    /// Knowledge picked up by watching another agent do something. The
+    /// demonstrator is recorded as the informant; confidence sits between
+    /// hearsay and first-hand experience.
+    pub fn observed(timestamp: u64, demonstrator: Entity) -> Self {
+        Self {
+            source: Source::Observed,
+            memory_type: MemoryType::Semantic,
+            timestamp,
+            confidence: 0.85,
+            informant: Some(demonstrator),
+            evidence: Vec::new(),
+            salience: 0.0,
+            source_sense: None,
+            strength: 0.85,
+        }
+    }
+
     /// Reinforce and refresh from a re-assertion of the same fact.
     pub fn refresh_from(&mut self, incoming: &Metadata) {
         reinforce(self, incoming.timestamp);
         self.timestamp = incoming.timestamp;
         self.confidence = incoming.confidence;
     }
+
+    /// Fold a redundant copy's bookkeeping into this one during compaction:
+    /// union the evidence and keep the deeper encoding and stronger
+    /// emotional tag of the two.
+    fn absorb(&mut self, other: Metadata) {
+        self.evidence.extend(other.evidence);
+        self.evidence.sort_unstable();
+        self.evidence.dedup();
+        self.strength = self.strength.max(other.strength);
+        self.salience = self.salience.max(other.salience);
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 // TRIPLE — A single piece of knowledge
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Reflect)]
+#[derive(Debug, Clone, PartialEq, Reflect, serde::Serialize, serde::Deserialize)]
 pub struct Triple {
     pub subject: Node,
     pub predicate: Predicate,
@@ -895,6 +996,65 @@ fn reinforce(existing: &mut Metadata, incoming_timestamp: u64) {
     }
 }
 
+/// Compaction tie-break: does `a` deserve to survive over its equivalent `b`?
+/// Functional facts supersede by recency; non-functional facts by confidence.
+/// Exact ties keep the incumbent.
+fn outranks(a: &Triple, b: &Triple) -> bool {
+    let by_confidence = a.meta.confidence.total_cmp(&b.meta.confidence);
+    let by_recency = a.meta.timestamp.cmp(&b.meta.timestamp);
+    let order = if a.predicate.is_functional() {
+        by_recency.then(by_confidence)
+    } else {
+        by_confidence.then(by_recency)
+    };
+    order == std::cmp::Ordering::Greater
+}
+
+// ─── Canonical triple ordering (Value has no Ord) ─────────────────────────
+
+pub fn compare_nodes(a: &Node, b: &Node) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    // Basic heuristic sort
+    match (a, b) {
+        (Node::Entity(e1), Node::Entity(e2)) => e1.index().cmp(&e2.index()),
+        (Node::Concept(c1), Node::Concept(c2)) => (*c1 as usize).cmp(&(*c2 as usize)),
+        (Node::Tile((x1, y1)), Node::Tile((x2, y2))) => x1.cmp(x2).then(y1.cmp(y2)),
+        (Node::Self_, Node::Self_) => Ordering::Equal,
+        // Cross-variant
+        _ => format!("{:?}", a).cmp(&format!("{:?}", b)), // Fallback but rare comparison
+    }
+}
+
+pub fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (a, b) {
+        (Value::Quantity(q1), Value::Quantity(q2)) => match q1.compare(q2) {
+            FuzzyOrdering::Less => Ordering::Less,
+            FuzzyOrdering::Greater => Ordering::Greater,
+            FuzzyOrdering::Equal => Ordering::Equal,
+            // Overlap: fall back to point estimate for deterministic state
+            // ordering. The planner needs a total order even when two fuzzy
+            // beliefs are indistinguishable from an agent's perspective.
+            FuzzyOrdering::Unknown => q1.point_estimate().total_cmp(&q2.point_estimate()),
+        },
+        (Value::Boolean(v1), Value::Boolean(v2)) => v1.cmp(v2),
+        (Value::Concept(c1), Value::Concept(c2)) => (*c1 as usize).cmp(&(*c2 as usize)),
+        (Value::Entity(e1), Value::Entity(e2)) => e1.index().cmp(&e2.index()),
+        (Value::Tile((x1, y1)), Value::Tile((x2, y2))) => x1.cmp(x2).then(y1.cmp(y2)),
+        // Fallbacks
+        _ => format!("{:?}", a).cmp(&format!("{:?}", b)),
+    }
+}
+
+/// Subject → predicate → object. Metadata is ignored, so two copies of the
+/// same fact from different tiers compare equal and keep their tier order
+/// under a stable sort.
+pub fn compare_triples(a: &Triple, b: &Triple) -> std::cmp::Ordering {
+    compare_nodes(&a.subject, &b.subject)
+        .then_with(|| (a.predicate as usize).cmp(&(b.predicate as usize)))
+        .then_with(|| compare_values(&a.object, &b.object))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MINDGRAPH — Triple store with subject / predicate / (subject,predicate) indexes
 // ═══════════════════════════════════════════════════════════════════════════
@@ -905,6 +1065,47 @@ fn reinforce(existing: &mut Metadata, incoming_timestamp: u64) {
 type IdxList = SmallVec<[usize; 8]>;
 type SubjPredIdxList = SmallVec<[usize; 4]>;
 
+/// Hashable bucket for a `Value` in the by-object index. Deliberately
+/// coarser than the value itself: float payloads (quantities, emotion
+/// intensities, attitudes) and item counts are dropped, so
+///
+/// - every stored value that `satisfies_pattern` a query value shares its
+///   key (`Item(Wood, 3)` answers a query for `Item(Wood, 1)`), and
+/// - decay rewriting a quantity's precision or an item count in place
+///   never leaves the triple filed under a stale key.
+///
+/// `query` still runs the exact matcher over the bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ObjectKey {
+    Boolean(bool),
+    Quantity,
+    Concept(Concept),
+    Entity(Entity),
+    Tile((i32, i32)),
+    Action(crate::agent::actions::ActionType),
+    Emotion(crate::agent::psyche::emotions::EmotionType),
+    Item(Concept),
+    Attitude,
+    Text(AgentName),
+}
+
+impl ObjectKey {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Boolean(b) => ObjectKey::Boolean(*b),
+            Value::Quantity(_) => ObjectKey::Quantity,
+            Value::Concept(c) => ObjectKey::Concept(*c),
+            Value::Entity(e) => ObjectKey::Entity(*e),
+            Value::Tile(t) => ObjectKey::Tile(*t),
+            Value::Action(a) => ObjectKey::Action(*a),
+            Value::Emotion(e, _) => ObjectKey::Emotion(*e),
+            Value::Item(c, _) => ObjectKey::Item(*c),
+            Value::Attitude(_) => ObjectKey::Attitude,
+            Value::Text(name) => ObjectKey::Text(name.clone()),
+        }
+    }
+}
+
 #[derive(Component, Clone, Reflect, Default)]
 #[reflect(Component)]
 pub struct MindGraph {
@@ -958,6 +1159,17 @@ pub struct MindGraph {
     /// (Subject, Predicate) → live triple ids. Most brain queries hit this one.
     #[reflect(ignore)]
     by_subject_predicate: HashMap<(Node, Predicate), SubjPredIdxList>,
+    /// Object → live triple ids, keyed coarsely by [`ObjectKey`]. Serves
+    /// reverse lookups ("who is LocatedAt this tile", "who do I feel an
+    /// Attitude toward") that would otherwise walk every triple.
+    #[reflect(ignore)]
+    by_object: HashMap<ObjectKey, IdxList>,
+
+    /// Sort `query` results by [`compare_triples`]. The local indexes and
+    /// the perception/inventory stores are HashMaps, so without this the
+    /// order within a tier varies from run to run. Set from
+    /// [`MindQueryConfig`] for deterministic (record/replay) runs.
+    stable_query_order: bool,
 }
 
 /// Flat (subject, predicate) → Triple store for short-lived perception
@@ -1014,6 +1226,12 @@ impl PerceptionStore {
             .retain(|_, triple| triple.meta.timestamp >= cutoff);
         before - self.entries.len()
     }
+
+    fn retain(&mut self, mut f: impl FnMut(&Triple) -> bool) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|_, triple| f(triple));
+        before - self.entries.len()
+    }
 }
 
 /// `(subject, concept) → Triple` store for `Contains` beliefs. Holds both
@@ -1123,6 +1341,8 @@ impl MindGraph {
             by_subject: HashMap::new(),
             by_predicate: HashMap::new(),
             by_subject_predicate: HashMap::new(),
+            by_object: HashMap::new(),
+            stable_query_order: false,
         }
     }
 
@@ -1132,6 +1352,15 @@ impl MindGraph {
 
     // ─── Accessors ──────────────────────────────────────────────────────────
 
+    pub fn stable_query_order(&self) -> bool {
+        self.stable_query_order
+    }
+
+    /// Turn canonical ordering of `query` results on or off.
+    pub fn set_stable_query_order(&mut self, stable: bool) {
+        self.stable_query_order = stable;
+    }
+
     pub fn len(&self) -> usize {
         (self.triples.len() - self.tombstone_count)
             + self.perception_store.len()
@@ -1171,7 +1400,8 @@ impl MindGraph {
 
     // ─── Index bookkeeping ──────────────────────────────────────────────────
 
-    fn index_insert(&mut self, idx: usize, subject: &Node, predicate: Predicate) {
+    fn index_insert(&mut self, idx: usize, triple: &Triple) {
+        let (subject, predicate) = (&triple.subject, triple.predicate);
         self.by_subject
             .entry(subject.clone())
             .or_default()
@@ -1181,9 +1411,14 @@ impl MindGraph {
             .entry((subject.clone(), predicate))
             .or_default()
             .push(idx);
+        self.by_object
+            .entry(ObjectKey::of(&triple.object))
+            .or_default()
+            .push(idx);
     }
 
-    fn index_remove(&mut self, idx: usize, subject: &Node, predicate: Predicate) {
+    fn index_remove(&mut self, idx: usize, triple: &Triple) {
+        let (subject, predicate) = (&triple.subject, triple.predicate);
         if let Some(list) = self.by_subject.get_mut(subject) {
             list.retain(|i| *i != idx);
             if list.is_empty() {
@@ -1203,6 +1438,13 @@ impl MindGraph {
                 self.by_subject_predicate.remove(&key);
             }
         }
+        let key = ObjectKey::of(&triple.object);
+        if let Some(list) = self.by_object.get_mut(&key) {
+            list.retain(|i| *i != idx);
+            if list.is_empty() {
+                self.by_object.remove(&key);
+            }
+        }
     }
 
     /// Tombstone the slot at `idx`. Assumes it is currently live.
@@ -1217,14 +1459,17 @@ impl MindGraph {
                 format!("{:?}", triple.object),
             ));
             self.tombstone_count += 1;
-            self.index_remove(idx, &triple.subject, triple.predicate);
+            self.index_remove(idx, &triple);
         }
     }
 
-    /// Rebuild indexes and drop tombstoned slots. Triple ids are invalidated.
-    pub fn compact(&mut self) {
+    /// Fold semantically equivalent triples into a single survivor, then
+    /// drop tombstoned slots and rebuild indexes. Triple ids are invalidated.
+    /// Returns the number of redundant triples merged away.
+    pub fn compact(&mut self) -> usize {
+        let merged = self.merge_equivalent();
         if self.tombstone_count == 0 {
-            return;
+            return merged;
         }
         let mut new_triples: Vec<Option<Triple>> = Vec::with_capacity(self.len());
         for slot in self.triples.drain(..) {
@@ -1235,6 +1480,54 @@ impl MindGraph {
         self.triples = new_triples;
         self.tombstone_count = 0;
         self.rebuild_indexes();
+        merged
+    }
+
+    /// Walk every (subject, predicate) bucket and fold equivalent triples
+    /// together. Functional predicates hold one fact per bucket, so every
+    /// entry is equivalent and the most recent wins; non-functional buckets
+    /// group by `Value::is_equivalent` and the most confident wins. Losers
+    /// are tombstoned without a mutation record — the fact itself survives,
+    /// so brains have nothing to wake up for.
+    fn merge_equivalent(&mut self) -> usize {
+        let buckets: Vec<SubjPredIdxList> = self
+            .by_subject_predicate
+            .values()
+            .filter(|ids| ids.len() > 1)
+            .cloned()
+            .collect();
+        let mut merged = 0;
+        for ids in buckets {
+            let mut survivors: SubjPredIdxList = SmallVec::new();
+            for idx in ids {
+                let Some(Some(incoming)) = self.triples.get(idx) else {
+                    continue;
+                };
+                let functional = incoming.predicate.is_functional();
+                let twin = survivors.iter().position(|&s| {
+                    functional
+                        || matches!(&self.triples[s], Some(t) if t.object.is_equivalent(&incoming.object))
+                });
+                let Some(pos) = twin else {
+                    survivors.push(idx);
+                    continue;
+                };
+                let kept = survivors[pos];
+                let (winner, loser) = match (&self.triples[idx], &self.triples[kept]) {
+                    (Some(a), Some(b)) if outranks(a, b) => (idx, kept),
+                    _ => (kept, idx),
+                };
+                if let Some(dropped) = self.triples[loser].take()
+                    && let Some(survivor) = self.triples[winner].as_mut()
+                {
+                    survivor.meta.absorb(dropped.meta);
+                    self.tombstone_count += 1;
+                    merged += 1;
+                }
+                survivors[pos] = winner;
+            }
+        }
+        merged
     }
 
     /// Rebuild the subject / predicate / (subject, predicate) indexes from the
@@ -1243,6 +1536,7 @@ impl MindGraph {
         self.by_subject.clear();
         self.by_predicate.clear();
         self.by_subject_predicate.clear();
+        self.by_object.clear();
         for (i, slot) in self.triples.iter().enumerate() {
             if let Some(triple) = slot {
                 self.by_subject
@@ -1257,6 +1551,10 @@ impl MindGraph {
                     .entry((triple.subject.clone(), triple.predicate))
                     .or_default()
                     .push(i);
+                self.by_object
+                    .entry(ObjectKey::of(&triple.object))
+                    .or_default()
+                    .push(i);
             }
         }
     }
@@ -1273,7 +1571,7 @@ impl MindGraph {
             return;
         }
         let idx = self.triples.len();
-        self.index_insert(idx, &triple.subject, triple.predicate);
+        self.index_insert(idx, &triple);
         self.pending_mutations.push((
             "Add".to_string(),
             format!("{:?}", triple.subject),
@@ -1331,6 +1629,16 @@ impl MindGraph {
         self.perception_store.prune_older_than(now, max_age)
     }
 
+    /// Drop every belief that mentions `entity` as subject or object.
+    /// Called when the entity leaves the world for good so minds don't
+    /// keep planning toward (or remembering facts about) a dangling id.
+    /// Returns the number of triples removed.
+    pub fn forget_entity(&mut self, entity: Entity) -> usize {
+        let node = Node::Entity(entity);
+        let mentions = |t: &Triple| t.subject == node || t.object == Value::Entity(entity);
+        self.perception_store.retain(|t| !mentions(t)) + self.retain(|t| !mentions(t))
+    }
+
     pub fn perception_store_len(&self) -> usize {
         self.perception_store.len()
     }
@@ -1549,19 +1857,37 @@ impl MindGraph {
 
         // Pick the tightest index for LOCAL triples.
         // Pick the tightest index that fits the query pattern. None means
-        // "no useful index" — fall back to a live-triple scan.
-        let ids: Option<&[usize]> = match (subject, predicate) {
-            (Some(sub), Some(pred)) => self
+        // "no useful index" — fall back to a live-triple scan. Without a
+        // subject, an object bucket is usually far smaller than a predicate
+        // bucket (one tile vs every LocatedAt), so take the shorter of the two.
+        let by_object = object.map(|o| {
+            self.by_object
+                .get(&ObjectKey::of(o))
+                .map_or(&[][..], |v| v.as_slice())
+        });
+        let ids: Option<&[usize]> = match (subject, predicate, by_object) {
+            (Some(sub), Some(pred), _) => self
                 .by_subject_predicate
                 .get(&(sub.clone(), pred))
                 .map(|v| v.as_slice()),
-            (Some(sub), None) => self.by_subject.get(sub).map(|v| v.as_slice()),
-            (None, Some(pred)) => self.by_predicate.get(&pred).map(|v| v.as_slice()),
-            (None, None) => None,
+            (Some(sub), None, _) => self.by_subject.get(sub).map(|v| v.as_slice()),
+            (None, Some(pred), Some(obj_ids)) => {
+                let pred_ids = self
+                    .by_predicate
+                    .get(&pred)
+                    .map_or(&[][..], |v| v.as_slice());
+                Some(if obj_ids.len() < pred_ids.len() {
+                    obj_ids
+                } else {
+                    pred_ids
+                })
+            }
+            (None, Some(pred), None) => self.by_predicate.get(&pred).map(|v| v.as_slice()),
+            (None, None, obj_ids) => obj_ids,
         };
         let local_iter: Box<dyn Iterator<Item = &Triple>> = match (ids, subject, predicate) {
             (Some(ids), _, _) => Box::new(self.live_at(ids).filter(|t| matcher(t))),
-            // (None, None) — no index usable, walk the main triple vec
+            // Nothing specified — no index usable, walk the main triple vec
             // (perception + inventory tiers are chained separately below).
             (None, None, None) => Box::new(
                 self.triples
@@ -1603,7 +1929,8 @@ impl MindGraph {
         };
 
         // Combine sources: Ontology -> Shared -> Local -> Perception -> Inventory
-        self.ontology
+        let mut results: Vec<&Triple> = self
+            .ontology
             .triples
             .iter()
             .filter(|t| matcher(t))
@@ -1615,7 +1942,11 @@ impl MindGraph {
             .chain(local_iter)
             .chain(perception_iter)
             .chain(inventory_iter)
-            .collect()
+            .collect();
+        if self.stable_query_order {
+            results.sort_by(|a, b| compare_triples(a, b));
+        }
+        results
     }
 
     // ─── Diagnostics / inspection ──────────────────────────────────────────
@@ -1632,6 +1963,10 @@ impl MindGraph {
         self.by_subject_predicate.len()
     }
 
+    pub fn by_object_len(&self) -> usize {
+        self.by_object.len()
+    }
+
     // ─── Inheritance queries ───
 
     pub fn is_a(&self, subject: &Node, target: Concept) -> bool {
@@ -1824,6 +2159,50 @@ impl MindGraph {
             Metadata::perception_sense_conf(timestamp, confidence, sense),
         ));
     }
+
+    // ─── Persistence ────────────────────────────────────────────────────────
+
+    /// Write this agent's personal triples (all three tiers, with full
+    /// metadata) to `path` as JSON. The ontology and shared cultural blocks
+    /// are not written — they are `Arc`s shared with every other agent.
+    pub fn save_to_path(&self, path: &std::path::Path) -> Result<(), String> {
+        let saved = SavedMindGraph {
+            triples: self.triples.iter().flatten().cloned().collect(),
+            perception: self.perception_store.iter().cloned().collect(),
+            inventory: self.inventory_store.iter().cloned().collect(),
+        };
+        let text = serde_json::to_string_pretty(&saved).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| e.to_string())
+    }
+
+    /// Read a graph written by [`MindGraph::save_to_path`]. The ontology is
+    /// rebuilt from [`setup_ontology`]; shared cultural knowledge is not
+    /// restored, so callers re-attach it with `add_shared_knowledge`. Each
+    /// triple goes back into the tier it was saved from, bypassing
+    /// `assert` so no metadata is reinforced and no mutations are queued.
+    pub fn load_from_path(path: &std::path::Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let saved: SavedMindGraph = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+        let mut mind = MindGraph::new(setup_ontology());
+        mind.triples = saved.triples.into_iter().map(Some).collect();
+        for triple in saved.perception {
+            mind.perception_store.insert(triple);
+        }
+        for triple in saved.inventory {
+            mind.inventory_store.upsert(triple);
+        }
+        mind.rebuild_indexes();
+        Ok(mind)
+    }
+}
+
+/// On-disk form of one agent's MindGraph: the live triples of each storage
+/// tier. Indexes are derived and rebuilt on load.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedMindGraph {
+    triples: Vec<Triple>,
+    perception: Vec<Triple>,
+    inventory: Vec<Triple>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1915,6 +2294,33 @@ impl Ontology {
         // No cache rebuild needed — production triples don't affect trait/parent caches
     }
 
+    /// Assert how many seconds `producer` takes to regrow one unit of its
+    /// yield (e.g. AppleTree → 10.0). `RegenerationRate` is functional, so a
+    /// different existing rate is replaced rather than duplicated.
+    /// Idempotent — calling twice with the same arguments is a no-op.
+    pub fn ensure_regeneration_rate(&mut self, producer: Concept, seconds_per_unit: f32) {
+        let rate = Value::Quantity(Quantity::Exact(seconds_per_unit));
+        let is_rate = |t: &Triple| {
+            t.subject == Node::Concept(producer) && t.predicate == Predicate::RegenerationRate
+        };
+        if self.triples.iter().any(|t| is_rate(t) && t.object == rate) {
+            return;
+        }
+        let mut triples: Vec<Triple> = self
+            .triples
+            .iter()
+            .filter(|t| !is_rate(t))
+            .cloned()
+            .collect();
+        triples.push(Triple::new(
+            Node::Concept(producer),
+            Predicate::RegenerationRate,
+            rate,
+        ));
+        self.triples = Arc::new(triples);
+        // No cache rebuild needed — rates don't affect trait/parent caches
+    }
+
     /// Build caches from triples
     fn build_caches(&mut self) {
         let mut parent_map: HashMap<Concept, Vec<Concept>> = HashMap::new();
@@ -1975,6 +2381,16 @@ impl Ontology {
     }
 }
 
+/// The default ontology, built once per process. Cloning an `Ontology`
+/// only bumps its `Arc`s, so every world (including parallel headless
+/// runs on other threads) can start from this copy; runtime additions via
+/// `ensure_trait` / `ensure_production` copy-on-write and stay local to the
+/// world that made them.
+pub fn shared_ontology() -> Ontology {
+    static SHARED: std::sync::OnceLock<Ontology> = std::sync::OnceLock::new();
+    SHARED.get_or_init(setup_ontology).clone()
+}
+
 pub fn setup_ontology() -> Ontology {
     // println!("Running setup_ontology...");
     let mut triples = Vec::new();
@@ -2044,6 +2460,8 @@ pub fn setup_ontology() -> Ontology {
     add(c(StoneNode), IsA, v(Object));
     add(c(StoneNode), IsA, v(Resource));
 
+    add(c(GroundItem), IsA, v(Object));
+
     // ─── Properties (HasTrait) ───
     add(c(Food), HasTrait, v(Edible));
     add(c(Water), HasTrait, v(Drinkable));
@@ -2096,6 +2514,28 @@ pub fn setup_ontology() -> Ontology {
 
 // NOTE: Perception systems are now consolidated in cognition/perception.rs
 
+// ─── Query ordering ──────────────────────────────────────────────────────────
+
+/// Deterministic-mode switch for `MindGraph::query` result order. Off by
+/// default: the sort costs a little on every query and live play doesn't
+/// need it. Headless record/replay turns it on so two runs from the same
+/// seed see beliefs in the same order.
+#[derive(Resource, Reflect, Clone, Debug, Default)]
+#[reflect(Resource)]
+pub struct MindQueryConfig {
+    pub stable_order: bool,
+}
+
+/// Bevy system: keep every `MindGraph`'s stable-order flag in step with
+/// `MindQueryConfig`, including graphs spawned after the config was set.
+pub fn apply_mind_query_config(config: Res<MindQueryConfig>, mut minds: Query<&mut MindGraph>) {
+    for mut mind in minds.iter_mut() {
+        if mind.stable_query_order() != config.stable_order {
+            mind.set_stable_query_order(config.stable_order);
+        }
+    }
+}
+
 // ─── Mutation drain system ───────────────────────────────────────────────────
 
 /// Bevy system: drains `MindGraph::pending_mutations` into `SimEvent::MindGraphMutation`.
@@ -2419,6 +2859,47 @@ mod tests {
         assert_eq!(sort_by_ptr(indexed), sort_by_ptr(reference));
     }
 
+    #[test]
+    fn query_by_object_matches_linear_scan() {
+        let mind = populated_graph(200);
+        let tile = Value::Tile((7, 21));
+        let tree = Value::Concept(Concept::AppleTree);
+
+        let indexed = mind.query(None, None, Some(&tile));
+        let reference = linear_scan(&mind, None, None, Some(&tile));
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(sort_by_ptr(indexed), sort_by_ptr(reference));
+
+        let indexed = mind.query(None, Some(Predicate::LocatedAt), Some(&tile));
+        assert_eq!(indexed.len(), 1);
+        assert!(
+            mind.query(None, Some(Predicate::IsA), Some(&tile))
+                .is_empty()
+        );
+
+        let indexed = mind.query(None, Some(Predicate::IsA), Some(&tree));
+        let reference = linear_scan(&mind, None, Some(Predicate::IsA), Some(&tree));
+        assert_eq!(indexed.len(), 200);
+        assert_eq!(sort_by_ptr(indexed), sort_by_ptr(reference));
+    }
+
+    #[test]
+    fn object_index_tracks_removal_and_compaction() {
+        let mut mind = populated_graph(20);
+        let tile = Value::Tile((4, 12));
+        assert_eq!(mind.query(None, None, Some(&tile)).len(), 1);
+
+        mind.retain(|t| t.object != tile);
+        assert!(mind.query(None, None, Some(&tile)).is_empty());
+
+        mind.compact();
+        assert!(mind.query(None, None, Some(&tile)).is_empty());
+        let other = Value::Tile((5, 15));
+        assert_eq!(mind.query(None, None, Some(&other)).len(), 1);
+        // 19 tiles left plus the shared AppleTree concept.
+        assert_eq!(mind.by_object_len(), 20);
+    }
+
     #[test]
     fn query_with_all_none_returns_all_live_triples() {
         let mind = populated_graph(10);
@@ -2515,6 +2996,50 @@ mod tests {
         assert_eq!(mind.by_subject_predicate_len(), 0);
     }
 
+    #[test]
+    fn removing_from_the_middle_keeps_every_index_in_step() {
+        let mut mind = populated_graph(300);
+        let slots = mind.total_slots();
+        for i in 100..200 {
+            let e = Node::Entity(Entity::from_bits(1000 + i as u64));
+            mind.remove(
+                &e,
+                Predicate::LocatedAt,
+                &Value::Tile((i as i32, (i * 3) as i32)),
+            );
+        }
+
+        // Removal tombstones in place: slot ids after the gap are untouched,
+        // so no index needed a rebuild.
+        assert_eq!(mind.total_slots(), slots);
+        assert_eq!(mind.tombstone_count(), 100);
+
+        for i in [0u64, 99, 100, 150, 199, 200, 299] {
+            let e = Node::Entity(Entity::from_bits(1000 + i));
+            let tile = Value::Tile((i as i32, (i * 3) as i32));
+            let removed = (100..200).contains(&i);
+            for (subject, predicate, object) in [
+                (Some(&e), None, None),
+                (Some(&e), Some(Predicate::LocatedAt), None),
+                (None, Some(Predicate::LocatedAt), Some(&tile)),
+                (None, None, Some(&tile)),
+            ] {
+                let indexed = mind.query(subject, predicate, object);
+                let reference = linear_scan(&mind, subject, predicate, object);
+                assert_eq!(sort_by_ptr(indexed), sort_by_ptr(reference));
+            }
+            assert_eq!(
+                mind.get(&e, Predicate::LocatedAt).is_none(),
+                removed,
+                "entity {i}"
+            );
+        }
+        assert_eq!(
+            mind.query(None, Some(Predicate::LocatedAt), None).len(),
+            200
+        );
+    }
+
     #[test]
     fn compact_reclaims_tombstoned_slots() {
         let mut mind = MindGraph::default();
@@ -2545,6 +3070,74 @@ mod tests {
         assert_eq!(results.len(), 5);
     }
 
+    #[test]
+    fn compact_folds_redundant_triples_without_losing_distinct_facts() {
+        use crate::agent::psyche::emotions::EmotionType;
+
+        let mut mind = MindGraph::default();
+        let wolf = Node::Entity(Entity::from_bits(1));
+        let tree = Node::Entity(Entity::from_bits(2));
+        let fear = |intensity, confidence, timestamp, evidence: Vec<u64>| {
+            let mut meta = Metadata::inference(timestamp, confidence);
+            meta.evidence = evidence;
+            Triple::with_meta(
+                wolf.clone(),
+                Predicate::TriggersEmotion,
+                Value::Emotion(EmotionType::Fear, intensity),
+                meta,
+            )
+        };
+        // Three readings of the same fear, one distinct emotion about the
+        // same subject, and a stale + fresh location for the tree.
+        mind.add(fear(0.6, 0.5, 10, vec![1]));
+        mind.add(fear(0.7, 0.9, 20, vec![2]));
+        mind.add(fear(0.65, 0.4, 30, vec![1, 3]));
+        mind.add(Triple::with_meta(
+            wolf.clone(),
+            Predicate::TriggersEmotion,
+            Value::Emotion(EmotionType::Anger, 0.3),
+            Metadata::inference(10, 0.5),
+        ));
+        mind.add(Triple::with_meta(
+            tree.clone(),
+            Predicate::LocatedAt,
+            Value::Tile((1, 1)),
+            Metadata::semantic(5),
+        ));
+        mind.add(Triple::with_meta(
+            tree.clone(),
+            Predicate::LocatedAt,
+            Value::Tile((2, 2)),
+            Metadata::semantic(50),
+        ));
+        assert_eq!(mind.len(), 6);
+
+        let merged = mind.compact();
+
+        assert_eq!(merged, 3);
+        assert_eq!(mind.len(), 3);
+        assert_eq!(mind.tombstone_count(), 0);
+
+        let emotions = mind.query(Some(&wolf), Some(Predicate::TriggersEmotion), None);
+        assert_eq!(emotions.len(), 2, "fear and anger stay distinct");
+        let fear_fact = emotions
+            .iter()
+            .find(|t| matches!(t.object, Value::Emotion(EmotionType::Fear, _)))
+            .expect("fear survives compaction");
+        assert_eq!(
+            fear_fact.object,
+            Value::Emotion(EmotionType::Fear, 0.7),
+            "highest-confidence reading wins"
+        );
+        assert_eq!(fear_fact.meta.evidence, vec![1, 2, 3]);
+
+        assert_eq!(
+            mind.get(&tree, Predicate::LocatedAt),
+            Some(&Value::Tile((2, 2))),
+            "functional facts keep the most recent value"
+        );
+    }
+
     #[test]
     fn retain_tombstones_filtered_triples() {
         let mut mind = MindGraph::default();
@@ -3050,4 +3643,134 @@ mod tests {
         );
         assert_eq!(results[0].meta.source, Source::Experienced);
     }
+
+    /// Fill a graph with entity facts in all three local tiers: semantic
+    /// `IsA` (main store), perceived `LocatedAt` (perception store) and
+    /// `Contains` (inventory store). `reversed` inserts in the opposite
+    /// order so the two graphs' hash layouts diverge as much as they can.
+    fn populated_mind(reversed: bool) -> MindGraph {
+        let mut mind = MindGraph::new(setup_ontology());
+        let mut ids: Vec<u64> = (1..=40).collect();
+        if reversed {
+            ids.reverse();
+        }
+        for i in ids {
+            let entity = Node::Entity(Entity::from_bits(i));
+            mind.assert(Triple::with_meta(
+                entity.clone(),
+                Predicate::IsA,
+                Value::Concept(Concept::AppleTree),
+                Metadata::semantic(10),
+            ));
+            mind.assert(Triple::with_meta(
+                entity.clone(),
+                Predicate::LocatedAt,
+                Value::Tile((i as i32, -(i as i32))),
+                Metadata::perception(10),
+            ));
+            mind.assert(Triple::with_meta(
+                entity,
+                Predicate::Contains,
+                Value::Item(Concept::Apple, i as u32),
+                Metadata::semantic(10),
+            ));
+        }
+        mind
+    }
+
+    fn facts(results: &[&Triple]) -> Vec<(Node, Predicate, Value)> {
+        results
+            .iter()
+            .map(|t| (t.subject.clone(), t.predicate, t.object.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn stable_query_order_is_canonical_across_calls_and_runs() {
+        let mut first = populated_mind(false);
+        let mut second = populated_mind(true);
+        first.set_stable_query_order(true);
+        second.set_stable_query_order(true);
+
+        let patterns: [(Option<Node>, Option<Predicate>); 4] = [
+            (None, None),
+            (None, Some(Predicate::LocatedAt)),
+            (None, Some(Predicate::Contains)),
+            (Some(Node::Entity(Entity::from_bits(7))), None),
+        ];
+        for (subject, predicate) in patterns {
+            let a = facts(&first.query(subject.as_ref(), predicate, None));
+            let again = facts(&first.query(subject.as_ref(), predicate, None));
+            let b = facts(&second.query(subject.as_ref(), predicate, None));
+            assert!(!a.is_empty());
+            assert_eq!(
+                a, again,
+                "repeated call reordered {subject:?} {predicate:?}"
+            );
+            assert_eq!(a, b, "separate runs disagree on {subject:?} {predicate:?}");
+
+            let sorted = first.query(subject.as_ref(), predicate, None);
+            assert!(
+                sorted
+                    .windows(2)
+                    .all(|w| compare_triples(w[0], w[1]) != std::cmp::Ordering::Greater),
+                "results not in canonical order for {subject:?} {predicate:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn mind_graph_round_trips_through_save_and_load() {
+        let alice = Entity::from_bits(42);
+        let mut mind = MindGraph::new(setup_ontology());
+        mind.assert(Triple::with_meta(
+            Node::Entity(alice),
+            Predicate::LocatedAt,
+            Value::Tile((3, -4)),
+            Metadata {
+                evidence: vec![7, 9],
+                salience: 0.4,
+                ..Metadata::hearsay(120, Entity::from_bits(5))
+            },
+        ));
+        mind.assert(Triple::with_meta(
+            Node::Concept(Concept::Berry),
+            Predicate::Satisfies,
+            Value::Quantity(Quantity::Around(0.6)),
+            Metadata::experience(80),
+        ));
+        mind.assert(Triple::with_meta(
+            Node::Self_,
+            Predicate::Hunger,
+            Value::Quantity(Quantity::Exact(0.3)),
+            Metadata::perception(200),
+        ));
+        mind.assert(Triple::new(
+            Node::Self_,
+            Predicate::Contains,
+            Value::Item(Concept::Apple, 3),
+        ));
+
+        let path = std::env::temp_dir().join(format!(
+            "worldsim_mind_graph_test_{}.json",
+            std::process::id()
+        ));
+        mind.save_to_path(&path).expect("save");
+        let loaded = MindGraph::load_from_path(&path).expect("load");
+        let _ = std::fs::remove_file(&path);
+
+        let mut before: Vec<&Triple> = mind.iter().collect();
+        let mut after: Vec<&Triple> = loaded.iter().collect();
+        before.sort_by(|a, b| compare_triples(a, b));
+        after.sort_by(|a, b| compare_triples(a, b));
+        assert_eq!(before, after);
+        // Indexes were rebuilt: lookups through them find the same facts.
+        assert_eq!(
+            loaded.query(Some(&Node::Entity(alice)), Some(Predicate::LocatedAt), None)[0]
+                .meta
+                .informant,
+            Some(Entity::from_bits(5))
+        );
+        assert_eq!(loaded.count_of(&Node::Self_, Concept::Apple), 3);
+    }
 }
@@ -1,9 +1,9 @@
 //! Working memory buffer and strength-based knowledge decay.
 //!
-//! Reads: GameEvent messages, TickCount, MemoryDecayConfig, MindGraph
-//! Writes: WorkingMemory (event buffer), MindGraph (episodic triples, decay/forget)
+//! Reads: GameEvent messages, TickCount, MemoryDecayConfig, SalienceConfig, MindGraph
+//! Writes: WorkingMemory (event buffer), MindGraph (episodic triples, decay/forget), HearsayIntake (shared knowledge)
 //! Upstream: perception (GameEvents), action execution (GameEvents), conversation (KnowledgeShared)
-//! Downstream: consolidation (reads episodic triples), brain systems (reads MindGraph)
+//! Downstream: consolidation (reads episodic triples), brain systems (reads MindGraph), hearsay_intake (integrates shared knowledge)
 
 use bevy::prelude::*;
 use std::collections::VecDeque;
@@ -11,11 +11,72 @@ use std::collections::VecDeque;
 use crate::agent::actions::ActionType;
 use crate::agent::mind::knowledge::MemoryType;
 
+/// Items `WorkingMemory` holds by default before its overflow policy kicks in.
+pub const DEFAULT_WORKING_MEMORY_CAPACITY: usize = 20;
+
+/// What `WorkingMemory` drops when a push takes it over capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum OverflowPolicy {
+    /// Drop the oldest item regardless of importance.
+    EvictOldest,
+    /// Drop already-processed items first (they only linger for the UI),
+    /// then the least-salient unprocessed item, oldest first on ties. A
+    /// flood of trivial events can't push out the one that matters.
+    #[default]
+    EvictLeastSalient,
+}
+
 // WorkingMemory structs
-#[derive(Component, Debug, Clone, Reflect, Default)]
+#[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
 pub struct WorkingMemory {
     pub buffer: VecDeque<WorkingMemoryItem>,
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for WorkingMemory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_WORKING_MEMORY_CAPACITY, OverflowPolicy::default())
+    }
+}
+
+impl WorkingMemory {
+    pub fn with_capacity(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self {
+            buffer: VecDeque::with_capacity(capacity + 1),
+            capacity,
+            overflow,
+        }
+    }
+
+    /// Append an item, evicting per `overflow` until back within `capacity`.
+    pub fn push(&mut self, item: WorkingMemoryItem) {
+        self.buffer.push_back(item);
+        while self.buffer.len() > self.capacity {
+            self.evict_one();
+        }
+    }
+
+    fn evict_one(&mut self) {
+        let victim = match self.overflow {
+            OverflowPolicy::EvictOldest => Some(0),
+            OverflowPolicy::EvictLeastSalient => {
+                self.buffer.iter().position(|i| i.processed).or_else(|| {
+                    // `min_by` keeps the first of equal elements, so ties
+                    // resolve to the oldest item.
+                    self.buffer
+                        .iter()
+                        .enumerate()
+                        .min_by(|(_, a), (_, b)| a.salience.total_cmp(&b.salience))
+                        .map(|(idx, _)| idx)
+                })
+            }
+        };
+        if let Some(idx) = victim {
+            self.buffer.remove(idx);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Reflect)]
@@ -23,6 +84,77 @@ pub struct WorkingMemoryItem {
     pub event: crate::agent::events::GameEvent,
     pub timestamp: u64,
     pub processed: bool,
+    /// How much this event matters to the holder, from `event_salience`.
+    /// Same scale as the episodic `Metadata::salience` it will be recorded
+    /// with, so eviction and episodic recording agree on what's trivial.
+    pub salience: f32,
+}
+
+/// Emotion an interaction evokes in an observer, and its intensity. `None`
+/// for mundane actions (walking, eating) that never become episodic memories.
+fn interaction_emotion(
+    action: &ActionType,
+) -> Option<(crate::agent::psyche::emotions::EmotionType, f32)> {
+    use crate::agent::psyche::emotions::EmotionType;
+    // TODO: Query ontology instead of hardcoding (see docs/todo.md)
+    match action {
+        ActionType::Attack | ActionType::Flee => Some((EmotionType::Fear, 0.8)),
+        ActionType::Wave | ActionType::Converse => Some((EmotionType::Joy, 0.5)),
+        _ => None,
+    }
+}
+
+/// Salience of `event` from `observer`'s point of view. Mirrors what the
+/// `record_*` helpers below store as episodic salience; zero for events
+/// that won't be recorded at all.
+pub fn event_salience(
+    observer: Entity,
+    event: &crate::agent::events::GameEvent,
+    config: &SalienceConfig,
+) -> f32 {
+    use crate::agent::events::GameEvent;
+    match event {
+        GameEvent::Interaction {
+            actor,
+            action,
+            target,
+            ..
+        } => {
+            let Some((_, intensity)) = interaction_emotion(action) else {
+                return 0.0;
+            };
+            let involved = *actor == observer || *target == Some(observer);
+            config.base(intensity, involved)
+        }
+        GameEvent::SocialInteraction { valence, .. } => config.base(valence.abs(), true),
+        GameEvent::KnowledgeShared { .. } => 0.5,
+    }
+}
+
+/// How unexpected it is to see `actor` perform `action`, in `0..=1`: 1.0
+/// the first time, falling off with each episodic memory of the same
+/// actor doing the same thing.
+pub fn event_surprise(
+    mind: &crate::agent::mind::knowledge::MindGraph,
+    actor: Entity,
+    action: ActionType,
+) -> f32 {
+    use crate::agent::mind::knowledge::{Predicate, Value};
+    let action_value = Value::Action(action);
+    let seen = mind
+        .query(None, Some(Predicate::Actor), Some(&Value::Entity(actor)))
+        .into_iter()
+        .filter(|t| {
+            !mind
+                .query(
+                    Some(&t.subject),
+                    Some(Predicate::Action),
+                    Some(&action_value),
+                )
+                .is_empty()
+        })
+        .count();
+    1.0 / (1.0 + seen as f32)
 }
 
 pub fn process_perception(
@@ -35,10 +167,16 @@ pub fn process_perception(
     )>,
     transforms: Query<&Transform>, // To look up actor positions
     current_tick: Res<crate::core::TickCount>,
+    salience_config: Res<SalienceConfig>,
 ) {
     for event in events.read() {
         match event {
-            crate::agent::events::GameEvent::Interaction { actor, target, .. } => {
+            crate::agent::events::GameEvent::Interaction {
+                actor,
+                target,
+                sound_radius,
+                ..
+            } => {
                 if let Ok(actor_transform) = transforms.get(*actor) {
                     let actor_pos = actor_transform.translation.truncate();
 
@@ -49,19 +187,16 @@ pub fn process_perception(
                         let is_actor = observer_entity == *actor;
                         let is_target = target.is_some_and(|t| t == observer_entity);
                         let is_witness = distance <= vision.range;
+                        // Loud interactions reach listeners who can't see them.
+                        let is_listener = sound_radius.is_some_and(|r| distance <= r);
 
-                        if is_actor || is_target || is_witness {
-                            // Add to Working Memory
-                            wm.buffer.push_back(WorkingMemoryItem {
+                        if is_actor || is_target || is_witness || is_listener {
+                            wm.push(WorkingMemoryItem {
                                 event: event.clone(),
                                 timestamp: current_tick.current,
                                 processed: false,
+                                salience: event_salience(observer_entity, event, &salience_config),
                             });
-
-                            // Limit WM size (keep recent 20, even if processed, for UI)
-                            if wm.buffer.len() > 20 {
-                                wm.buffer.pop_front();
-                            }
                         }
                     }
                 }
@@ -72,15 +207,12 @@ pub fn process_perception(
                 // Both participants remember the interaction
                 for (observer_entity, _observer_transform, _vision, mut wm) in agents.iter_mut() {
                     if observer_entity == *actor || observer_entity == *target {
-                        wm.buffer.push_back(WorkingMemoryItem {
+                        wm.push(WorkingMemoryItem {
                             event: event.clone(),
                             timestamp: current_tick.current,
                             processed: false,
+                            salience: event_salience(observer_entity, event, &salience_config),
                         });
-
-                        if wm.buffer.len() > 20 {
-                            wm.buffer.pop_front();
-                        }
                     }
                 }
             }
@@ -89,15 +221,12 @@ pub fn process_perception(
             crate::agent::events::GameEvent::KnowledgeShared { listener, .. } => {
                 for (observer_entity, _observer_transform, _vision, mut wm) in agents.iter_mut() {
                     if observer_entity == *listener {
-                        wm.buffer.push_back(WorkingMemoryItem {
+                        wm.push(WorkingMemoryItem {
                             event: event.clone(),
                             timestamp: current_tick.current,
                             processed: false,
+                            salience: event_salience(observer_entity, event, &salience_config),
                         });
-
-                        if wm.buffer.len() > 20 {
-                            wm.buffer.pop_front();
-                        }
                     }
                 }
             }
@@ -115,6 +244,9 @@ pub fn process_working_memory(
         With<crate::agent::Agent>,
     >,
     mut game_log: ResMut<crate::core::GameLog>,
+    salience_config: Res<SalienceConfig>,
+    mut hearsay: ResMut<crate::agent::mind::hearsay_intake::HearsayIntake>,
+    hearsay_config: Res<crate::agent::mind::hearsay_intake::HearsayIntakeConfig>,
 ) {
     for (entity, mut wm, mut mind) in query.iter_mut() {
         for item in wm.buffer.iter_mut() {
@@ -137,6 +269,7 @@ pub fn process_working_memory(
                         action,
                         target,
                         &mut mind,
+                        &salience_config,
                         &mut game_log,
                     );
                 }
@@ -149,7 +282,14 @@ pub fn process_working_memory(
                     ..
                 } => {
                     record_social_interaction(
-                        entity, item, actor, target, action, *valence, &mut mind,
+                        entity,
+                        item,
+                        actor,
+                        target,
+                        action,
+                        *valence,
+                        &mut mind,
+                        &salience_config,
                     );
                 }
 
@@ -157,6 +297,7 @@ pub fn process_working_memory(
                     speaker,
                     listener,
                     content,
+                    deceptive,
                 } => {
                     record_knowledge_shared(
                         entity,
@@ -164,7 +305,9 @@ pub fn process_working_memory(
                         speaker,
                         listener,
                         content,
-                        &mut mind,
+                        *deceptive,
+                        &mut hearsay,
+                        &hearsay_config,
                         &mut game_log,
                     );
                 }
@@ -180,36 +323,19 @@ fn record_interaction_event(
     action: &ActionType,
     target: &Option<Entity>,
     mind: &mut crate::agent::mind::knowledge::MindGraph,
+    salience_config: &SalienceConfig,
     game_log: &mut crate::core::GameLog,
 ) {
-    use crate::agent::mind::knowledge::{
-        Concept, Metadata, Node, Predicate, Quantity, Triple, Value,
-    };
-
-    let mut concepts = vec![];
-    match action {
-        ActionType::Wave | ActionType::Converse => concepts.push(Concept::SocialAction),
-        ActionType::Attack | ActionType::Flee => concepts.push(Concept::ViolentAction),
-        ActionType::Eat | ActionType::Sleep | ActionType::Drink => {
-            concepts.push(Concept::SurvivalAction)
-        }
-        ActionType::Walk | ActionType::Wander => concepts.push(Concept::MovementAction),
-        _ => {}
-    }
+    use crate::agent::mind::knowledge::{Metadata, Node, Predicate, Quantity, Triple, Value};
 
     // Only record emotionally significant events — movement, eating, etc. don't create episodic memories
-    // TODO: Query ontology instead of hardcoding (see docs/todo.md)
-    let (emotion, intensity) = if concepts.contains(&Concept::ViolentAction) {
-        (crate::agent::psyche::emotions::EmotionType::Fear, 0.8)
-    } else if concepts.contains(&Concept::SocialAction) {
-        (crate::agent::psyche::emotions::EmotionType::Joy, 0.5)
-    } else {
+    let Some((emotion, intensity)) = interaction_emotion(action) else {
         return;
     };
-
     let is_self = *actor == entity || *target == Some(entity);
-    let importance = if is_self { 1.0 } else { 0.5 };
-    let salience = intensity * importance;
+    // Surprise is judged against memories recorded before this event.
+    let salience =
+        salience_config.with_surprise(item.salience, event_surprise(mind, *actor, *action));
 
     let target_idx = target.map_or(0, |t| t.index_u32());
     let event_id = item.timestamp + (*actor).index_u32() as u64 + target_idx as u64;
@@ -278,6 +404,7 @@ fn record_social_interaction(
     action: &ActionType,
     valence: f32,
     mind: &mut crate::agent::mind::knowledge::MindGraph,
+    salience_config: &SalienceConfig,
 ) {
     use crate::agent::mind::knowledge::{Metadata, Node, Predicate, Triple, Value};
 
@@ -295,6 +422,8 @@ fn record_social_interaction(
     };
 
     let event_id = item.timestamp + (*actor).index_u32() as u64 + (*target).index_u32() as u64;
+    let salience =
+        salience_config.with_surprise(item.salience, event_surprise(mind, *actor, *action));
 
     let meta = Metadata {
         source: crate::agent::mind::knowledge::Source::Experienced,
@@ -303,7 +432,7 @@ fn record_social_interaction(
         confidence: 1.0,
         informant: None,
         evidence: Vec::new(),
-        salience: intensity,
+        salience,
         source_sense: None,
         strength: 1.0,
     };
@@ -340,7 +469,9 @@ fn record_knowledge_shared(
     speaker: &Entity,
     listener: &Entity,
     content: &[crate::agent::mind::knowledge::Triple],
-    mind: &mut crate::agent::mind::knowledge::MindGraph,
+    deceptive: bool,
+    hearsay: &mut crate::agent::mind::hearsay_intake::HearsayIntake,
+    hearsay_config: &crate::agent::mind::hearsay_intake::HearsayIntakeConfig,
     game_log: &mut crate::core::GameLog,
 ) {
     use crate::agent::mind::knowledge::Metadata;
@@ -349,12 +480,18 @@ fn record_knowledge_shared(
         return;
     }
 
+    // A liar's delivery gives them away a little.
+    let confidence = if deceptive {
+        0.7 * crate::agent::mind::deception::DECEPTIVE_CONFIDENCE_SCALE
+    } else {
+        0.7
+    };
     for triple in content {
         let hearsay_meta = Metadata {
             source: crate::agent::mind::knowledge::Source::Hearsay,
             memory_type: MemoryType::Semantic,
             timestamp: item.timestamp,
-            confidence: 0.7,
+            confidence,
             informant: Some(*speaker),
             evidence: Vec::new(),
             salience: 0.5,
@@ -362,16 +499,21 @@ fn record_knowledge_shared(
             strength: 0.7,
         };
 
-        mind.assert(crate::agent::mind::knowledge::Triple::with_meta(
-            triple.subject.clone(),
-            triple.predicate,
-            triple.object.clone(),
-            hearsay_meta,
-        ));
+        // Queued, not asserted: hearsay_intake paces how fast it sinks in.
+        hearsay.enqueue(
+            *listener,
+            crate::agent::mind::knowledge::Triple::with_meta(
+                triple.subject.clone(),
+                triple.predicate,
+                triple.object.clone(),
+                hearsay_meta,
+            ),
+            hearsay_config.max_pending,
+        );
     }
 
     game_log.log_debug(format!(
-        "{:?} learned {} facts from {:?}",
+        "{:?} heard {} facts from {:?}",
         listener,
         content.len(),
         speaker
@@ -387,6 +529,12 @@ fn record_knowledge_shared(
 ///   categories decay faster
 /// - **Forgotten** when strength drops below `forget_threshold`
 ///
+/// With `decay_confidence` on, Episodic and Perception triples also fade:
+/// confidence follows a half-life curve from the last time the fact was
+/// asserted, and it is confidence rather than strength that must cross
+/// `forget_threshold` before they are dropped. Re-perceiving a fact resets
+/// its timestamp and confidence via `MindGraph::assert`.
+///
 /// Intrinsic, Cultural, and Procedural memories never decay.
 pub fn decay_stale_knowledge(
     mut agents: Query<
@@ -452,6 +600,12 @@ pub fn decay_stale_knowledge(
             }
 
             triple.meta.strength = triple.meta.strength.max(0.0);
+            if decay_config.decay_confidence
+                && let Some(keep) =
+                    fade_confidence(triple, &decay_config, current_time, salience_resist)
+            {
+                return keep;
+            }
             step_precision_or_drop(triple, &decay_config)
         });
 
@@ -460,20 +614,20 @@ pub fn decay_stale_knowledge(
             enforce_episodic_capacity(&mut mind, decay_config.episodic_capacity);
         }
 
-        if decayed_count > 0 {
-            if mind.tombstone_count() * 2 > mind.total_slots() {
-                mind.compact();
-            }
+        let compaction_due = (entity.index_u32() as u64 + current_time)
+            .is_multiple_of(decay_config.compaction_interval);
+        if compaction_due || mind.tombstone_count() * 2 > mind.total_slots() {
+            mind.compact();
+        }
 
-            if decayed_count > 10 {
-                game_log.log_debug(format!(
-                    "Memory decay: {} forgot {} triples ({} -> {})",
-                    entity.index(),
-                    decayed_count,
-                    initial_count,
-                    mind.len()
-                ));
-            }
+        if decayed_count > 10 {
+            game_log.log_debug(format!(
+                "Memory decay: {} forgot {} triples ({} -> {})",
+                entity.index(),
+                decayed_count,
+                initial_count,
+                mind.len()
+            ));
         }
     }
 }
@@ -509,6 +663,28 @@ fn step_precision_or_drop(
     false
 }
 
+/// Confidence-decay step for Episodic and Perception triples: confidence
+/// becomes `0.5^(age / half_life)`, never rising above what the triple
+/// already holds, with the half-life stretched by the same salience and
+/// conscientiousness resistance that slows strength decay. Returns whether
+/// to keep the triple, or `None` for memory types that don't fade this way.
+fn fade_confidence(
+    triple: &mut crate::agent::mind::knowledge::Triple,
+    config: &MemoryDecayConfig,
+    now: u64,
+    resist: f32,
+) -> Option<bool> {
+    let half_life = match triple.meta.memory_type {
+        MemoryType::Perception => config.perception_half_life_ticks,
+        MemoryType::Episodic => config.episodic_half_life_ticks,
+        _ => return None,
+    };
+    let age = now.saturating_sub(triple.meta.timestamp) as f32;
+    let faded = 0.5_f32.powf(age / (half_life.max(1) as f32 * resist));
+    triple.meta.confidence = triple.meta.confidence.min(faded);
+    Some(triple.meta.confidence >= config.forget_threshold)
+}
+
 /// Remove the weakest episodic events when the total event count exceeds capacity.
 /// An "event" is a group of triples sharing the same `Node::Event(eid)` subject.
 fn enforce_episodic_capacity(mind: &mut crate::agent::mind::knowledge::MindGraph, capacity: usize) {
@@ -541,6 +717,54 @@ fn enforce_episodic_capacity(mind: &mut crate::agent::mind::knowledge::MindGraph
     });
 }
 
+// =============================================================================
+// SALIENCE CONFIG
+// =============================================================================
+
+/// Weights for how much an event matters to the agent who experiences it.
+/// Salience sets an episodic memory's resistance to decay (see
+/// `MemoryDecayConfig::salience_decay_resistance`) and its priority in a
+/// full `WorkingMemory`.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct SalienceConfig {
+    /// Weight on the event's emotional intensity.
+    pub emotion_weight: f32,
+    /// How much being the actor or target matters. Witnessed events are
+    /// scaled by `1.0 - self_involvement_weight`.
+    pub self_involvement_weight: f32,
+    /// Extra salience at full surprise, as a fraction of the base.
+    pub surprise_weight: f32,
+}
+
+impl Default for SalienceConfig {
+    fn default() -> Self {
+        Self {
+            emotion_weight: 1.0,
+            self_involvement_weight: 0.5,
+            surprise_weight: 0.5,
+        }
+    }
+}
+
+impl SalienceConfig {
+    /// Salience before surprise: emotional intensity, discounted for
+    /// events the agent only witnessed.
+    pub fn base(&self, intensity: f32, involved: bool) -> f32 {
+        let involvement = if involved {
+            1.0
+        } else {
+            1.0 - self.self_involvement_weight
+        };
+        self.emotion_weight * intensity * involvement
+    }
+
+    /// Boost `base` by `surprise` (`0..=1`, see [`event_surprise`]).
+    pub fn with_surprise(&self, base: f32, surprise: f32) -> f32 {
+        base * (1.0 + self.surprise_weight * surprise.clamp(0.0, 1.0))
+    }
+}
+
 // =============================================================================
 // MEMORY DECAY CONFIG
 // =============================================================================
@@ -571,6 +795,18 @@ pub struct MemoryDecayConfig {
     /// so an entity that stays in view never expires; one that leaves
     /// view ages out within this window.
     pub perception_expiry_ticks: u64,
+    /// Ticks between routine `MindGraph::compact` sweeps per agent, which
+    /// fold redundant triples even when forgetting hasn't left enough
+    /// tombstones to trigger a compaction on its own. Should be a multiple
+    /// of `decay_interval` — compaction rides the staggered decay pass.
+    pub compaction_interval: u64,
+    /// Fade Episodic and Perception confidence with age and forget on
+    /// confidence instead of strength (see [`decay_stale_knowledge`]).
+    pub decay_confidence: bool,
+    /// Ticks for an unrefreshed Perception triple to fall to half confidence.
+    pub perception_half_life_ticks: u64,
+    /// Ticks for an unrefreshed Episodic triple to fall to half confidence.
+    pub episodic_half_life_ticks: u64,
 }
 
 impl Default for MemoryDecayConfig {
@@ -586,6 +822,12 @@ impl Default for MemoryDecayConfig {
             episodic_capacity: 200,
             decay_interval: 60,
             perception_expiry_ticks: 60,
+            compaction_interval: 3600,
+            // Roughly where strength decay alone would forget a neutral
+            // memory: ~4.3 half-lives take confidence below 0.05.
+            decay_confidence: true,
+            perception_half_life_ticks: 800,
+            episodic_half_life_ticks: 14_000,
         }
     }
 }
@@ -670,7 +912,8 @@ mod tests {
         ]
     }
 
-    /// Run the same decay formula used by `decay_stale_knowledge`, minus ECS wiring.
+    /// Run the same decay formula used by `decay_stale_knowledge`, minus ECS wiring
+    /// and the `decay_confidence` step (see `fade_confidence` tests for that).
     ///
     /// `resist_mult` mirrors the personality conscientiousness factor: pass `1.0` for
     /// a neutral agent, `1.0 + conscientiousness` to simulate a specific personality.
@@ -1011,6 +1254,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn confidence_fades_by_half_life_and_reperception_restores_it() {
+        let config = MemoryDecayConfig::default();
+        let half_life = config.perception_half_life_ticks;
+        // Non-functional, so it lives in the main store the decay pass sweeps.
+        let seen_at = |tick| {
+            Triple::with_meta(
+                Node::Entity(bevy::prelude::Entity::from_bits(1)),
+                Predicate::IsA,
+                Value::Concept(Concept::Person),
+                Metadata::perception(tick),
+            )
+        };
+        let mut mind = MindGraph::default();
+        mind.assert(seen_at(0));
+
+        mind.decay_pass(|t| fade_confidence(t, &config, half_life, 1.0).unwrap());
+        let conf = mind.iter().next().unwrap().meta.confidence;
+        assert!((conf - 0.5).abs() < 1e-4, "one half-life → 0.5, got {conf}");
+
+        // Seen again: assert refreshes the timestamp and confidence.
+        mind.assert(seen_at(half_life));
+        mind.decay_pass(|t| fade_confidence(t, &config, half_life, 1.0).unwrap());
+        assert!((mind.iter().next().unwrap().meta.confidence - 1.0).abs() < 1e-4);
+
+        // Five more half-lives unrefreshed: 1/32 is below the forget line.
+        let removed = mind.decay_pass(|t| fade_confidence(t, &config, half_life * 6, 1.0).unwrap());
+        assert_eq!(removed, 1);
+        assert!(mind.is_empty());
+    }
+
+    #[test]
+    fn introduced_agent_stays_known_after_leaving_vision() {
+        use crate::agent::mind::knowledge::AgentName;
+        use crate::agent::mind::social_identity::SocialIdentity;
+        use bevy::prelude::Entity;
+
+        let config = MemoryDecayConfig::default();
+        let mut mind = MindGraph::default();
+        let mut identity = SocialIdentity::default();
+        let partner = Entity::from_bits(7);
+
+        // Meeting at t=0: introduction, species seen through perception,
+        // relationship category from recognition, and where they stood.
+        identity.introduce(partner, AgentName("Bob".into()), 0);
+        mind.perceive_entity(
+            partner,
+            Predicate::IsA,
+            Value::Concept(Concept::Person),
+            0,
+            0.9,
+        );
+        mind.assert(Triple::with_meta(
+            Node::Entity(partner),
+            Predicate::IsA,
+            Value::Concept(Concept::Acquaintance),
+            Metadata::semantic(0),
+        ));
+        mind.perceive_entity(partner, Predicate::LocatedAt, Value::Tile((3, 4)), 0, 0.9);
+
+        // Partner walks out of view: no more refreshes. Run well past the
+        // point where an unreinforced perception is gone.
+        mind.prune_expired_perception(1_000, config.perception_expiry_ticks);
+        for _ in 0..200 {
+            run_decay_pass(&mut mind, &config, 1.0);
+        }
+
+        let partner_node = Node::Entity(partner);
+        assert!(identity.knows(partner));
+        assert_eq!(identity.name_of(partner).map(|n| n.0.as_str()), Some("Bob"));
+        assert!(
+            mind.has(
+                &partner_node,
+                Predicate::IsA,
+                &Value::Concept(Concept::Person)
+            ),
+            "species should persist as semantic memory"
+        );
+        assert!(
+            mind.has(
+                &partner_node,
+                Predicate::IsA,
+                &Value::Concept(Concept::Acquaintance)
+            ),
+            "relationship category should persist as semantic memory"
+        );
+        assert_eq!(
+            mind.get(&partner_node, Predicate::LocatedAt),
+            None,
+            "transient position should have decayed"
+        );
+    }
+
     #[test]
     fn conscientious_agent_holds_precision_longer_than_sloppy_one() {
         use crate::agent::mind::knowledge::Quantity;
@@ -1105,4 +1441,128 @@ mod tests {
             "stagger should spread {N} agents over {INTERVAL} ticks evenly, got max {max_concurrent} on one tick"
         );
     }
+
+    fn observed(action: ActionType, actor: u64, observer: Entity) -> WorkingMemoryItem {
+        let event = crate::agent::events::GameEvent::Interaction {
+            actor: bevy::prelude::Entity::from_bits(actor),
+            action,
+            target: None,
+            location: None,
+            sound_radius: None,
+        };
+        WorkingMemoryItem {
+            salience: event_salience(observer, &event, &SalienceConfig::default()),
+            event,
+            timestamp: 0,
+            processed: false,
+        }
+    }
+
+    fn holds_attack(wm: &WorkingMemory) -> bool {
+        wm.buffer.iter().any(|item| {
+            matches!(
+                item.event,
+                crate::agent::events::GameEvent::Interaction {
+                    action: ActionType::Attack,
+                    ..
+                }
+            )
+        })
+    }
+
+    #[test]
+    fn salient_event_survives_flood_of_trivial_ones() {
+        let observer = bevy::prelude::Entity::from_bits(99);
+        let flood = |wm: &mut WorkingMemory| {
+            wm.push(observed(ActionType::Walk, 1, observer));
+            wm.push(observed(ActionType::Attack, 2, observer));
+            for _ in 0..50 {
+                wm.push(observed(ActionType::Walk, 1, observer));
+            }
+        };
+
+        let mut wm = WorkingMemory::with_capacity(8, OverflowPolicy::EvictLeastSalient);
+        flood(&mut wm);
+        assert_eq!(wm.buffer.len(), 8);
+        assert!(
+            holds_attack(&wm),
+            "witnessed attack should outlive 50 trivial walks"
+        );
+
+        let mut fifo = WorkingMemory::with_capacity(8, OverflowPolicy::EvictOldest);
+        flood(&mut fifo);
+        assert!(
+            !holds_attack(&fifo),
+            "oldest-first eviction loses the attack under the same flood"
+        );
+    }
+
+    #[test]
+    fn surprising_event_is_more_salient_and_decays_slower() {
+        let config = SalienceConfig::default();
+        let decay = MemoryDecayConfig::default();
+        let observer = bevy::prelude::Entity::from_bits(99);
+        let familiar = bevy::prelude::Entity::from_bits(1);
+        let stranger = bevy::prelude::Entity::from_bits(2);
+        let mut game_log = crate::core::GameLog::new(10);
+        let mut mind = MindGraph::default();
+
+        // The observer has watched `familiar` attack three times before.
+        for tick in [100, 200, 300] {
+            let mut item = observed(ActionType::Attack, 1, observer);
+            item.timestamp = tick;
+            record_interaction_event(
+                observer,
+                &item,
+                &familiar,
+                &ActionType::Attack,
+                &None,
+                &mut mind,
+                &config,
+                &mut game_log,
+            );
+        }
+
+        let mut record = |actor: Entity, tick: u64| {
+            let mut item = observed(ActionType::Attack, actor.to_bits(), observer);
+            item.timestamp = tick;
+            record_interaction_event(
+                observer,
+                &item,
+                &actor,
+                &ActionType::Attack,
+                &None,
+                &mut mind,
+                &config,
+                &mut game_log,
+            );
+            mind.query(
+                Some(&Node::Event(tick + actor.index_u32() as u64)),
+                Some(Predicate::Action),
+                None,
+            )[0]
+            .meta
+            .salience
+        };
+        let expected = record(familiar, 1_000);
+        let surprising = record(stranger, 2_000);
+        assert!(
+            surprising > expected,
+            "first attack by a stranger ({surprising}) should outweigh another attack by a familiar aggressor ({expected})"
+        );
+
+        // Same emotional intensity, so salience alone separates their decay.
+        let strength_after = |salience: f32| {
+            let mut mind = MindGraph::default();
+            for mut triple in episodic_event_triples(1, 1.0) {
+                triple.meta.salience = salience;
+                mind.add(triple);
+            }
+            for _ in 0..50 {
+                run_decay_pass(&mut mind, &decay, 1.0);
+            }
+            mind.iter().map(|t| t.meta.strength).fold(0.0, f32::max)
+        };
+        assert!(strength_after(surprising) > strength_after(expected));
+    }
 }
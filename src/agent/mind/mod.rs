@@ -1,14 +1,20 @@
 pub mod affective_tom;
+pub mod area_beliefs;
 pub mod belief_state;
 pub mod belief_updater;
+pub mod census;
 pub mod consolidation;
+pub mod deception;
 pub mod deliberate_talk;
 pub mod epistemic;
 pub mod explored_tiles;
+pub mod hearsay_intake;
 pub mod knowledge;
 pub mod memory;
+pub mod observational_learning;
 pub mod perception;
 pub mod recognition;
+pub mod sight_memory;
 pub mod small_talk;
 pub mod social_identity;
 pub mod social_perception;
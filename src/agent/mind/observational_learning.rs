@@ -0,0 +1,87 @@
+//! Observational learning: witnesses pick up affordances by watching others.
+//!
+//! Reads: SimEvent (ActionCompleted), VisibleObjects, EntityType + ItemSlots (of the harvested target)
+//! Writes: MindGraph (`(Type, Affords, Harvest)` and `(Type, Produces, Item)` tagged `Source::Observed`)
+//! Upstream: nervous_system::execution (emits ActionCompleted), perception (VisibleObjects)
+//! Downstream: rational brain (Harvest plan validity reads type-level `Produces`)
+//!
+//! Cultural transmission by example: an agent who sees a neighbour
+//! successfully harvest a kind of thing it didn't know was harvestable
+//! learns that the kind affords Harvest — and what it yields — so it can
+//! imitate the neighbour later without ever having been taught.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::actions::ActionType;
+use crate::agent::events::{SimEvent, SimEventKind};
+use crate::agent::inventory::EntityType;
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::{Metadata, MindGraph, Node, Predicate, Triple, Value};
+use crate::agent::mind::perception::VisibleObjects;
+
+/// Bevy system (FixedUpdate): for every completed Harvest, each agent that
+/// could see the harvester and doesn't yet believe the target's type
+/// affords Harvest records the affordance, plus the yield if it didn't
+/// already know one.
+pub fn learn_from_observed_harvests(
+    tick: Res<crate::core::TickCount>,
+    mut events: MessageReader<SimEvent>,
+    mut observers: Query<(Entity, &VisibleObjects, &mut MindGraph), With<Agent>>,
+    targets: Query<(&EntityType, Option<&ItemSlots>)>,
+) {
+    let demonstrations: Vec<(Entity, Entity)> = events
+        .read()
+        .filter_map(|event| match event.kind {
+            SimEventKind::ActionCompleted {
+                agent,
+                action: ActionType::Harvest,
+                target: Some(target),
+            } => Some((agent, target)),
+            _ => None,
+        })
+        .collect();
+
+    for (demonstrator, target) in demonstrations {
+        let Ok((kind, stock)) = targets.get(target) else {
+            continue;
+        };
+        let kind = kind.0;
+        // What the harvester pulled is what Harvest takes next: the first
+        // item left in the target.
+        let yield_concept = stock.and_then(|s| s.all_items().next().map(|t| t.concept));
+
+        for (observer, visible, mut mind) in observers.iter_mut() {
+            if observer == demonstrator || !visible.entities.contains(&demonstrator) {
+                continue;
+            }
+            let affords = Value::Action(ActionType::Harvest);
+            let subject = Node::Concept(kind);
+            if !mind
+                .query(Some(&subject), Some(Predicate::Affords), Some(&affords))
+                .is_empty()
+            {
+                continue;
+            }
+            let metadata = Metadata::observed(tick.current, demonstrator);
+            mind.assert(Triple::with_meta(
+                subject.clone(),
+                Predicate::Affords,
+                affords,
+                metadata.clone(),
+            ));
+            if let Some(concept) = yield_concept
+                && mind
+                    .query(Some(&subject), Some(Predicate::Produces), None)
+                    .is_empty()
+            {
+                mind.assert(Triple::with_meta(
+                    subject,
+                    Predicate::Produces,
+                    Value::Item(concept, 1),
+                    metadata,
+                ));
+            }
+        }
+    }
+}
@@ -1,18 +1,25 @@
 //! Perception: multi-sense detection of nearby entities and environmental signals.
 //!
-//! Reads: Transform, Vision, LightLevel, Physical entities, body state components, TickCount, SpatialIndex, HeatSource, SoundSource
-//! Writes: VisibleObjects (entity list), PerceptionCache (chunk-bucket query cache), MindGraph (triples tagged with source_sense), SimEvent::{EntityPerceived, WarmthPerceived, SoundPerceived}
-//! Upstream: world::map (tile/chunk data), world::environment (LightLevel), world::sense_sources, agent body state
+//! Reads: Transform, Vision, Facing (view cone), DayCycle (night shortens sight), Weather (rain and storms shorten sight), LodLevel + LodClock (Reduced agents look on the coarse cadence), PerceptionConfig + Consciousness (re-perception interval, field of view), WorldMap (edge policy, opaque tiles for line of sight), Physical entities, body state components, TickCount, SpatialIndex, HeatSource, SoundSource, CentralNervousSystem (goal focus for the attention cap), RelationshipConfig (hearsay contradiction penalty), GameEvent::Interaction (sound radius)
+//! Writes: VisibleObjects (entity list), PerceptionCache (chunk-bucket query cache), MindGraph (triples tagged with source_sense), SocialGraph (trust in informants a sighting contradicts), SimEvent::{EntityPerceived, WarmthPerceived, SoundPerceived, RelationshipChanged}, GameEvent::Interaction (loud actions, with sound radius), PerformanceDiagnostics (perception candidate counts)
+//! Upstream: world::map (tile/chunk data), world::environment (DayCycle), world::sense_sources, agent body state
 //! Downstream: brain_system (reads VisibleObjects), knowledge (MindGraph updated with percepts), SimEvent consumers
 
 use crate::agent::Agent;
+use crate::agent::body::needs::Consciousness;
 use crate::agent::events::SimEventKind;
+use crate::agent::lod::{LodClock, LodLevel};
+use crate::agent::mind::belief_updater::{
+    assert_observed, contradicted_informants, discredit_informants,
+};
 use crate::agent::mind::knowledge::{
     CardinalDirection, Concept, Metadata, MindGraph, Node, Predicate, Sense, Triple, Value,
 };
+use crate::agent::nervous_system::cns::CentralNervousSystem;
+use crate::agent::nervous_system::urgency::UrgencySource;
 use crate::core::GameLog;
 use crate::core::tick::TickCount;
-use crate::world::environment::LightLevel;
+use crate::world::environment::DayCycle;
 use crate::world::map::{CHUNK_SIZE, TILE_SIZE};
 use crate::world::property::HeatSource;
 use crate::world::sense_sources::SoundSource;
@@ -25,11 +32,41 @@ use std::collections::HashMap;
 // VISION COMPONENTS
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Component, Reflect, Default)]
+#[derive(Component, Reflect)]
 #[reflect(Component)]
 #[require(PerceptionCache, DangerScanCache)]
 pub struct Vision {
     pub range: f32,
+    /// Width of the view cone centred on the agent's `Facing`, in degrees.
+    /// Only applies while `PerceptionConfig::field_of_view` is on; 360 sees
+    /// all the way round.
+    pub fov_degrees: f32,
+}
+
+impl Default for Vision {
+    fn default() -> Self {
+        Self {
+            range: 0.0,
+            fov_degrees: 360.0,
+        }
+    }
+}
+
+impl Vision {
+    /// Whether something `offset` away from the agent falls inside the view
+    /// cone around `facing`. Anything within `peripheral_radius` counts
+    /// regardless of direction — a hand on your shoulder is noticed. Range
+    /// is the caller's check.
+    pub fn in_cone(&self, facing: Vec2, offset: Vec2, peripheral_radius: f32) -> bool {
+        if self.fov_degrees >= 360.0 || offset.length() <= peripheral_radius {
+            return true;
+        }
+        let Some(facing) = facing.try_normalize() else {
+            return true;
+        };
+        let half_fov = (self.fov_degrees * 0.5).to_radians();
+        facing.angle_to(offset).abs() <= half_fov
+    }
 }
 
 /// Cached audible-threat count for `react_to_danger`. The visible scan
@@ -115,6 +152,66 @@ impl PerceptionCache {
     }
 }
 
+/// How often agents take a fresh look. Between looks `VisibleObjects` and
+/// the percepts already written to the mind stand as they were, so an
+/// agent acts on what it last saw.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct PerceptionConfig {
+    /// An agent re-perceives once every this many cycles. 1 = every tick.
+    pub interval_ticks: u32,
+    /// Off = every agent uses `interval_ticks`. On = a drowsy agent looks
+    /// less often, stretching toward `interval_ticks * drowsy_factor` as
+    /// alertness falls to zero.
+    pub alertness_scaled: bool,
+    pub drowsy_factor: f32,
+    /// On = agents only see inside the `Vision::fov_degrees` cone around
+    /// their `Facing`, plus anything within `peripheral_radius`. Off = the
+    /// vision range is a full circle.
+    pub field_of_view: bool,
+    /// Pixels. Entities this close are seen whichever way the agent faces.
+    pub peripheral_radius: f32,
+    /// Fraction of `Vision::range` left in full darkness. Sight ramps back
+    /// to full range with `DayCycle::daylight`; 1.0 turns night blindness off.
+    pub night_vision: f32,
+}
+
+impl Default for PerceptionConfig {
+    fn default() -> Self {
+        Self {
+            interval_ticks: 1,
+            alertness_scaled: false,
+            drowsy_factor: 3.0,
+            field_of_view: false,
+            peripheral_radius: 1.5 * TILE_SIZE,
+            night_vision: 0.3,
+        }
+    }
+}
+
+impl PerceptionConfig {
+    /// Cycles between looks for an agent at `alertness` (absent = fully
+    /// alert).
+    pub fn interval_for(&self, alertness: Option<f32>) -> u64 {
+        let base = self.interval_ticks.max(1) as f32;
+        let interval = match alertness {
+            Some(alertness) if self.alertness_scaled => {
+                let drowsiness = 1.0 - alertness.clamp(0.0, 1.0);
+                base * (1.0 + drowsiness * (self.drowsy_factor - 1.0).max(0.0))
+            }
+            _ => base,
+        };
+        interval.round().max(1.0) as u64
+    }
+
+    /// Whether `entity` looks this `cycle`. Staggered by entity so a
+    /// population's looks spread across the interval instead of landing
+    /// on the same tick.
+    pub fn due(&self, entity: Entity, cycle: u64, alertness: Option<f32>) -> bool {
+        (cycle + u64::from(entity.index_u32())).is_multiple_of(self.interval_for(alertness))
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // VISUAL PERCEPTION — Detect entities in range
 // ═══════════════════════════════════════════════════════════════════════════
@@ -127,22 +224,46 @@ pub fn update_visual_perception(
             &Vision,
             &mut VisibleObjects,
             &mut PerceptionCache,
+            Option<&LodLevel>,
+            Option<&Consciousness>,
+            Option<&crate::agent::movement::Facing>,
         ),
         With<Agent>,
     >,
     transforms: Query<&Transform, With<crate::world::Physical>>,
     entity_types: Query<&crate::agent::inventory::EntityType>,
     spatial_index: Res<SpatialIndex>,
-    light_level: Res<LightLevel>,
+    world_map: Res<crate::world::map::WorldMap>,
+    day: Res<DayCycle>,
+    weather: Res<crate::world::weather::Weather>,
     mut _game_log: ResMut<GameLog>,
     tick: Res<TickCount>,
+    lod_clock: Res<LodClock>,
+    config: Res<PerceptionConfig>,
     mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
     mut previous_buf: Local<Vec<Entity>>,
+    mut diagnostics: Option<ResMut<crate::core::diagnostics::PerformanceDiagnostics>>,
 ) {
     let _start = std::time::Instant::now();
 
-    for (agent_entity, agent_transform, vision, mut visible_objects, mut cache) in agents.iter_mut()
+    for (
+        agent_entity,
+        agent_transform,
+        vision,
+        mut visible_objects,
+        mut cache,
+        lod,
+        consc,
+        facing,
+    ) in agents.iter_mut()
     {
+        // Reduced-LOD agents, and agents between looks, keep the last
+        // look's view.
+        if !lod_clock.detail_due(agent_entity, lod)
+            || !config.due(agent_entity, lod_clock.cycle, consc.map(|c| c.alertness))
+        {
+            continue;
+        }
         // Swap the previous-tick visible list out without allocating; both buffers stabilise
         // at their max size after a warmup tick or two.
         std::mem::swap(&mut *previous_buf, &mut visible_objects.entities);
@@ -152,17 +273,33 @@ pub fn update_visual_perception(
         }
 
         let agent_pos = agent_transform.translation.truncate();
-        let view_range = vision.range * light_level.0;
+        let view_range = vision.range
+            * (config.night_vision + (1.0 - config.night_vision) * day.daylight)
+            * weather.vision_multiplier();
+        let facing = facing.copied().unwrap_or_default().0;
 
         let agent_chunk = world_pos_to_chunk(agent_pos);
         let chunk_radius = chunk_radius_for(view_range);
         if cache.is_stale(agent_chunk, chunk_radius, tick.current) {
             cache.cached = spatial_index.entities_near(agent_pos, view_range);
+            // On a wrapping map, entities just across an edge are in view too.
+            for image in world_map.wrapped_images(agent_pos, view_range) {
+                for entity in spatial_index.entities_near(image, view_range) {
+                    if !cache.cached.contains(&entity) {
+                        cache.cached.push(entity);
+                    }
+                }
+            }
             cache.last_chunk = Some(agent_chunk);
             cache.last_chunk_radius = chunk_radius;
             cache.last_query_tick = tick.current;
         }
 
+        if let Some(diagnostics) = diagnostics.as_mut() {
+            diagnostics.perception_candidates += cache.cached.len() as u64;
+            diagnostics.perception_brute_force_candidates += spatial_index.len() as u64;
+        }
+
         // Precise distance pass against the cached candidates. Despawned entities fall
         // out here because `transforms.get` returns Err for them.
         for &entity in &cache.cached {
@@ -172,7 +309,12 @@ pub fn update_visual_perception(
 
             if let Ok(target_transform) = transforms.get(entity) {
                 let target_pos = target_transform.translation.truncate();
-                if agent_pos.distance(target_pos) <= view_range {
+                let offset = world_map.delta(agent_pos, target_pos);
+                if offset.length() <= view_range
+                    && (!config.field_of_view
+                        || vision.in_cone(facing, offset, config.peripheral_radius))
+                    && world_map.line_of_sight(agent_pos, target_pos)
+                {
                     visible_objects.entities.push(entity);
                     if let Ok(entity_type) = entity_types.get(entity) {
                         visible_objects
@@ -271,22 +413,121 @@ pub fn update_body_perception(
 // EXTERNAL PERCEPTION
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Most visible entities an agent writes beliefs about per tick. A crowded
+/// view (forest edge, busy camp) holds more than an agent can take in;
+/// whatever falls past the cap simply isn't refreshed this tick.
+pub const ATTENTION_CAP: usize = 24;
+
+/// Concept the agent's current goal makes salient: anything that is, has
+/// the trait of, or holds an item of this concept gets attended first.
+/// Drives without a physical object to look for return `None` and leave
+/// attention purely distance-ordered.
+pub fn goal_focus(source: UrgencySource) -> Option<Concept> {
+    match source {
+        UrgencySource::Hunger | UrgencySource::FoodSecurity => Some(Concept::Food),
+        UrgencySource::Thirst => Some(Concept::Drinkable),
+        UrgencySource::Fear => Some(Concept::Dangerous),
+        UrgencySource::Social => Some(Concept::Sentient),
+        _ => None,
+    }
+}
+
+/// Whether an entity of `concept` carrying `contents` serves `focus` —
+/// a berry bush serves Food through the berries it holds, not its type.
+pub fn serves_focus(
+    mind: &MindGraph,
+    focus: Concept,
+    concept: Option<Concept>,
+    mut contents: impl Iterator<Item = Concept>,
+) -> bool {
+    let matches = |c: Concept| {
+        let node = Node::Concept(c);
+        mind.is_a(&node, focus) || mind.has_trait(&node, focus)
+    };
+    concept.is_some_and(matches) || contents.any(matches)
+}
+
+/// Order `(entity, goal_relevant, distance)` candidates by attention —
+/// goal-relevant first, then nearest — and keep the first `cap`.
+pub fn attend(mut candidates: Vec<(Entity, bool, f32)>, cap: usize) -> Vec<Entity> {
+    candidates.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    candidates.truncate(cap);
+    candidates
+        .into_iter()
+        .map(|(entity, _, _)| entity)
+        .collect()
+}
+
 pub fn write_perceptions_to_mind(
-    mut agents: Query<(Entity, &Name, &Transform, &VisibleObjects, &mut MindGraph), With<Agent>>,
+    mut agents: Query<
+        (
+            Entity,
+            &Name,
+            &Transform,
+            &VisibleObjects,
+            &mut MindGraph,
+            Option<&CentralNervousSystem>,
+            Option<&LodLevel>,
+            Option<&Consciousness>,
+        ),
+        With<Agent>,
+    >,
     transforms: Query<&Transform>,
     mobile_entities: Query<(), With<Agent>>,
     inventories: Query<&crate::agent::item_slots::ItemSlots>,
     entity_types: Query<&crate::agent::inventory::EntityType>,
     becomes_components: Query<&crate::world::becomes::Becomes>,
     lame_entities: Query<(), With<crate::agent::Lame>>,
+    needs: Query<&crate::agent::body::needs::PhysicalNeeds>,
     tick: Res<TickCount>,
+    lod_clock: Res<LodClock>,
+    config: Res<PerceptionConfig>,
+    mut social_graph: ResMut<crate::agent::psyche::social_graph::SocialGraph>,
+    relationship_config: Res<crate::agent::psyche::relationships::RelationshipConfig>,
+    mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
 ) {
     let current_time = tick.current;
 
-    for (_agent_entity, _, agent_transform, visible, mut mind) in agents.iter_mut() {
+    for (agent_entity, _, agent_transform, visible, mut mind, cns, lod, consc) in agents.iter_mut()
+    {
+        if !lod_clock.detail_due(agent_entity, lod)
+            || !config.due(agent_entity, lod_clock.cycle, consc.map(|c| c.alertness))
+        {
+            continue;
+        }
         let agent_pos = agent_transform.translation.truncate();
 
-        for &entity in &visible.entities {
+        // Urgencies are sorted highest-first, so the head is the drive the
+        // agent is currently acting on.
+        let focus = cns
+            .and_then(|cns| cns.urgencies.first())
+            .and_then(|urgency| goal_focus(urgency.source));
+        let candidates = visible
+            .entities
+            .iter()
+            .map(|&entity| {
+                let relevant = focus.is_some_and(|focus| {
+                    serves_focus(
+                        &mind,
+                        focus,
+                        entity_types.get(entity).ok().map(|t| t.0),
+                        inventories
+                            .get(entity)
+                            .into_iter()
+                            .flat_map(|slots| slots.all_items().map(|thing| thing.concept)),
+                    )
+                });
+                let distance = transforms
+                    .get(entity)
+                    .map_or(f32::MAX, |t| agent_pos.distance(t.translation.truncate()));
+                (entity, relevant, distance)
+            })
+            .collect();
+
+        for entity in attend(candidates, ATTENTION_CAP) {
             let confidence = calc_confidence(agent_pos, transforms.get(entity).ok());
 
             // 1. Perceive Location — only for mobile entities (#756).
@@ -313,14 +554,27 @@ pub fn write_perceptions_to_mind(
 
             // 2. Perceive Inventory
             if let Ok(inventory) = inventories.get(entity) {
-                perceive_inventory(
+                let expected = entity_types
+                    .get(entity)
+                    .map(|t| expected_yield(&mind, t.0))
+                    .unwrap_or_default();
+                let contradicted = perceive_inventory(
                     entity,
                     inventory,
+                    &expected,
                     &mut mind,
                     current_time,
                     confidence,
                     false,
                 );
+                discredit_informants(
+                    &mut social_graph,
+                    &relationship_config,
+                    agent_entity,
+                    &contradicted,
+                    current_time,
+                    &mut sim_events,
+                );
             }
 
             // 3. Perceive Type
@@ -359,6 +613,26 @@ pub fn write_perceptions_to_mind(
                     confidence,
                 );
             }
+
+            // 6. Perceive visible hunger. Unlike Lame it comes and goes
+            // with every meal, so a sated look retracts the trait.
+            if let Ok(physical) = needs.get(entity) {
+                if physical.hunger_urgency() >= VISIBLE_HUNGER_URGENCY {
+                    mind.perceive_entity(
+                        entity,
+                        Predicate::HasTrait,
+                        Value::Concept(Concept::Hungry),
+                        current_time,
+                        confidence,
+                    );
+                } else {
+                    mind.remove(
+                        &Node::Entity(entity),
+                        Predicate::HasTrait,
+                        &Value::Concept(Concept::Hungry),
+                    );
+                }
+            }
         }
 
         // Self-inventory used to be mirrored here as `(Self_, Contains, ...)`
@@ -371,21 +645,57 @@ pub fn write_perceptions_to_mind(
 
 // --- HELPERS ---
 
+/// Hunger urgency at which an agent looks hungry to those who see it.
+pub const VISIBLE_HUNGER_URGENCY: f32 = 0.6;
+
+/// Distance (px) over which sight-based confidence falls from 1.0 to the floor.
+pub const VISUAL_CONFIDENCE_FALLOFF: f32 = 256.0;
+
+/// Lowest confidence a visual read can carry — anything actually in view
+/// is at least glimpsed.
+pub const VISUAL_CONFIDENCE_FLOOR: f32 = 0.3;
+
+/// Confidence of anything read by sight at `distance` px. Shared by object,
+/// species, and mood perception so every visual read follows one policy.
+pub fn visual_confidence(distance: f32) -> f32 {
+    (1.0 - (distance / VISUAL_CONFIDENCE_FALLOFF).min(1.0)).max(VISUAL_CONFIDENCE_FLOOR)
+}
+
 fn calc_confidence(agent_pos: Vec2, targeted_transform: Option<&Transform>) -> f32 {
     targeted_transform.map_or(0.5, |t| {
-        let dist = agent_pos.distance(t.translation.truncate());
-        (1.0 - (dist / 256.0).min(1.0)).max(0.3)
+        visual_confidence(agent_pos.distance(t.translation.truncate()))
     })
 }
 
-fn perceive_inventory(
+/// What the observer expects a harvestable `concept` to hold: the items
+/// it believes that kind produces. Empty for anything not harvestable, so
+/// a deer that merely "produces" meat is never read as an empty resource.
+fn expected_yield(mind: &MindGraph, concept: Concept) -> SmallVec<[Concept; 2]> {
+    let kind = Node::Concept(concept);
+    if !mind.has_trait(&kind, Concept::Harvestable) {
+        return SmallVec::new();
+    }
+    mind.query(Some(&kind), Some(Predicate::Produces), None)
+        .into_iter()
+        .filter_map(|t| match t.object {
+            Value::Item(item, _) => Some(item),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Record what `entity` is seen carrying. Returns the informants whose
+/// hearsay about it the sighting contradicts.
+pub(crate) fn perceive_inventory(
     entity: Entity,
     inventory: &crate::agent::item_slots::ItemSlots,
+    expected: &[Concept],
     mind: &mut MindGraph,
     time: u64,
     confidence: f32,
     is_self: bool,
-) {
+) -> Vec<Entity> {
+    let mut contradicted = Vec::new();
     let subject_node = if is_self {
         Node::Self_
     } else {
@@ -398,17 +708,24 @@ fn perceive_inventory(
     // until overwritten by fresh observation.
     for (concept, qty) in inventory.group_by_concept() {
         observed_concepts.insert(concept);
-        let meta = if is_self {
-            Metadata::perception_with_conf(time, confidence)
+        if is_self {
+            mind.assert(Triple::with_meta(
+                subject_node.clone(),
+                Predicate::Contains,
+                Value::Item(concept, qty),
+                Metadata::perception_with_conf(time, confidence),
+            ));
         } else {
-            Metadata::semantic(time)
-        };
-        mind.assert(Triple::with_meta(
-            subject_node.clone(),
-            Predicate::Contains,
-            Value::Item(concept, qty),
-            meta,
-        ));
+            contradicted.extend(assert_observed(
+                mind,
+                Triple::with_meta(
+                    subject_node.clone(),
+                    Predicate::Contains,
+                    Value::Item(concept, qty),
+                    Metadata::semantic(time),
+                ),
+            ));
+        }
     }
 
     // 2. Clear what IS NOT there (but used to be).
@@ -440,20 +757,56 @@ fn perceive_inventory(
         .collect();
 
     for (concept, old_qty) in stale {
+        let emptied = Triple::with_meta(
+            subject_node.clone(),
+            Predicate::Contains,
+            Value::Item(concept, 0),
+            Metadata::semantic(time),
+        );
+        // Checked before the stale belief goes, or there'd be nothing to
+        // contradict.
+        if !is_self {
+            contradicted.extend(contradicted_informants(mind, &emptied));
+        }
         mind.remove(
             &subject_node,
             Predicate::Contains,
             &Value::Item(concept, old_qty),
         );
         if !is_self {
-            mind.assert(Triple::with_meta(
-                subject_node.clone(),
-                Predicate::Contains,
-                Value::Item(concept, 0),
-                Metadata::semantic(time),
-            ));
+            mind.assert(emptied);
+        }
+    }
+
+    // 3. A harvestable seen bare on first sight. There was no stale belief
+    // to zero, but seeing a bush without berries is just as much first-person
+    // evidence as watching it run dry: record "known empty" so planning skips
+    // it instead of treating it like one never looked at.
+    if is_self {
+        return contradicted;
+    }
+    for &concept in expected {
+        if observed_concepts.contains(&concept)
+            || !mind
+                .query(
+                    Some(&subject_node),
+                    Some(Predicate::Contains),
+                    Some(&Value::Item(concept, 0)),
+                )
+                .is_empty()
+        {
+            continue;
         }
+        mind.assert(Triple::with_meta(
+            subject_node.clone(),
+            Predicate::Contains,
+            Value::Item(concept, 0),
+            Metadata::semantic(time),
+        ));
     }
+    contradicted.sort();
+    contradicted.dedup();
+    contradicted
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -795,28 +1148,216 @@ pub fn perceive_hearing(
 // ALARM EMISSION — Fleeing agents broadcast their distress
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Inserts a transient [`SoundSource`] on every agent who started a
-/// Flee action this tick. `perceive_hearing` writes direction-based
-/// Dangerous beliefs into listeners; that belief feeds existing fear
-/// → herd flees together off one alarm.
+/// Inserts a transient [`SoundSource`] on every agent who sounded an
+/// alarm this tick: an explicit `AlarmSignal`, or an animal starting to
+/// flee (the startle stomp comes with the bolt). The call is tagged with
+/// the caller's species so `hear_alarm_signals` only frightens its herd.
 pub fn emit_alarm_calls(
     mut commands: Commands,
     mut events: MessageReader<crate::agent::events::SimEvent>,
+    callers: Query<(&crate::agent::inventory::EntityType, &MindGraph)>,
 ) {
+    use crate::agent::actions::ActionType;
+
     for event in events.read() {
-        if let SimEventKind::ActionStarted { agent, action, .. } = &event.kind
-            && matches!(action, crate::agent::actions::ActionType::Flee)
-        {
+        let SimEventKind::ActionStarted { agent, action, .. } = &event.kind else {
+            continue;
+        };
+        let Ok((species, mind)) = callers.get(*agent) else {
+            continue;
+        };
+        let sounds_alarm = match action {
+            ActionType::AlarmSignal => true,
+            ActionType::Flee => mind.is_a(&Node::Concept(species.0), Concept::Animal),
+            _ => false,
+        };
+        if sounds_alarm {
             commands
                 .entity(*agent)
                 .insert(crate::world::sense_sources::SoundSource {
                     kind: crate::world::sense_sources::SoundKind::AlarmCall,
                     intensity: 1.0,
+                    species: Some(species.0),
                 });
         }
     }
 }
 
+/// Same-species listeners of an alarm call get their fear topped up to
+/// `LISTENER_FEAR`; the emotional brain's general fear response then
+/// turns that into Flee. This is emotional contagion, not communication —
+/// nothing is written to the listener's MindGraph about the threat itself.
+pub fn hear_alarm_signals(
+    mut listeners: Query<
+        (
+            Entity,
+            &Transform,
+            &crate::agent::inventory::EntityType,
+            &mut crate::agent::psyche::emotions::EmotionalState,
+        ),
+        With<Agent>,
+    >,
+    sound_sources: Query<(Entity, &Transform, &SoundSource)>,
+) {
+    use crate::agent::psyche::emotions::{Emotion, EmotionType};
+    use crate::constants::actions::alarm_signal::LISTENER_FEAR;
+    use crate::world::sense_sources::SoundKind;
+
+    for (source_entity, source_transform, sound) in sound_sources.iter() {
+        if sound.kind != SoundKind::AlarmCall {
+            continue;
+        }
+        let Some(caller_species) = sound.species else {
+            continue;
+        };
+        let source_pos = source_transform.translation.truncate();
+        let effective_range = HEARING_SENSE_RANGE * sound.intensity;
+
+        for (listener, transform, species, mut emotions) in listeners.iter_mut() {
+            if listener == source_entity
+                || species.0 != caller_species
+                || transform.translation.truncate().distance(source_pos) > effective_range
+            {
+                continue;
+            }
+            let current_fear: f32 = emotions
+                .active_emotions
+                .iter()
+                .filter(|e| e.emotion_type == EmotionType::Fear)
+                .map(|e| e.intensity)
+                .sum();
+            if current_fear < LISTENER_FEAR {
+                emotions.add_emotion(Emotion::new(
+                    EmotionType::Fear,
+                    LISTENER_FEAR - current_fear,
+                ));
+            }
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LOUD ACTIONS — Fights carry to listeners who can't see them
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// How far `action` carries as sound, or `None` if nobody out of sight
+/// would notice it. Extend this when louder verbs (e.g. Shout) land.
+pub fn loud_action_radius(action: crate::agent::actions::ActionType) -> Option<f32> {
+    use crate::agent::actions::ActionType;
+    use crate::constants::actions::attack::SOUND_RADIUS;
+
+    match action {
+        ActionType::Attack | ActionType::Bite | ActionType::DefendSelf => Some(SOUND_RADIUS),
+        _ => None,
+    }
+}
+
+/// Announces every loud action started this tick as a
+/// `GameEvent::Interaction` carrying its sound radius, so working memory
+/// and `perceive_sound` can reach listeners beyond line of sight.
+pub fn emit_loud_actions(
+    mut events: MessageReader<crate::agent::events::SimEvent>,
+    positions: Query<&Transform>,
+    mut game_events: MessageWriter<crate::agent::events::GameEvent>,
+) {
+    for event in events.read() {
+        let SimEventKind::ActionStarted {
+            agent,
+            action,
+            target,
+            ..
+        } = &event.kind
+        else {
+            continue;
+        };
+        let Some(radius) = loud_action_radius(*action) else {
+            continue;
+        };
+        game_events.write(crate::agent::events::GameEvent::Interaction {
+            actor: *agent,
+            action: *action,
+            target: *target,
+            location: positions.get(*agent).ok().map(|t| t.translation.truncate()),
+            sound_radius: Some(radius),
+        });
+    }
+}
+
+/// Listeners within a loud interaction's sound radius learn that the
+/// actor did it — `(Actor, Heard, Action)` — and, for violent actions,
+/// that danger lies in that direction. Nothing here checks line of sight,
+/// so fear from a fight spreads around corners. The listener's
+/// `DangerScanCache` is invalidated so `react_to_danger` picks the threat
+/// up this tick instead of waiting for its next staggered scan.
+pub fn perceive_sound(
+    mut game_events: MessageReader<crate::agent::events::GameEvent>,
+    mut listeners: Query<(Entity, &Transform, &mut MindGraph, &mut DangerScanCache), With<Agent>>,
+    tick: Res<TickCount>,
+    mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
+) {
+    use crate::world::sense_sources::SoundKind;
+
+    let current_time = tick.current;
+
+    for event in game_events.read() {
+        let crate::agent::events::GameEvent::Interaction {
+            actor,
+            action,
+            target,
+            location: Some(source_pos),
+            sound_radius: Some(radius),
+        } = event
+        else {
+            continue;
+        };
+
+        for (listener, transform, mut mind, mut scan) in listeners.iter_mut() {
+            if listener == *actor || *target == Some(listener) {
+                continue;
+            }
+            let dir = *source_pos - transform.translation.truncate();
+            let distance = dir.length();
+            if distance > *radius || dir.length_squared() < 0.01 {
+                continue;
+            }
+
+            let distance_factor = 1.0 - (distance / radius).clamp(0.0, 1.0);
+            let confidence = (distance_factor * 0.5).clamp(0.1, 0.5);
+
+            mind.perceive_via_sense(
+                Node::Entity(*actor),
+                Predicate::Heard,
+                Value::Action(*action),
+                current_time,
+                confidence,
+                Sense::Hearing,
+            );
+
+            if mind.is_a(&Node::Action(*action), Concept::ViolentAction) {
+                mind.perceive_via_sense(
+                    Node::Direction(CardinalDirection::from_vec2(dir)),
+                    Predicate::HasTrait,
+                    Value::Concept(Concept::Dangerous),
+                    current_time,
+                    confidence * 0.6,
+                    Sense::Hearing,
+                );
+                scan.last_scan_tick = None;
+            }
+
+            sim_events.write(crate::agent::events::SimEvent::single(
+                current_time,
+                listener,
+                SimEventKind::SoundPerceived {
+                    agent: listener,
+                    source: *actor,
+                    kind: SoundKind::Combat,
+                },
+            ));
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // SOUND SOURCE CLEANUP — Remove transient SoundSource after one perception tick
 // ═══════════════════════════════════════════════════════════════════════════
@@ -995,3 +1536,94 @@ mod cache_tests {
         assert!(cache.is_stale(IVec2::new(3, 4), 2, 101));
     }
 }
+
+#[cfg(test)]
+mod attention_tests {
+    use super::*;
+    use crate::agent::mind::knowledge::shared_ontology;
+
+    /// Forty rocks crowd the view, all nearer than the one berry bush.
+    /// Returns the candidate list and the bush entity.
+    fn crowded_view(
+        mind: &MindGraph,
+        focus: Option<Concept>,
+    ) -> (Vec<(Entity, bool, f32)>, Entity) {
+        let bush = Entity::from_bits(1000);
+        let mut candidates: Vec<(Entity, bool, f32)> = (0..40)
+            .map(|i| {
+                let relevant = focus.is_some_and(|f| {
+                    serves_focus(mind, f, Some(Concept::StoneNode), std::iter::empty())
+                });
+                (Entity::from_bits(i + 1), relevant, 10.0 + i as f32)
+            })
+            .collect();
+        let bush_relevant = focus.is_some_and(|f| {
+            serves_focus(
+                mind,
+                f,
+                Some(Concept::BerryBush),
+                [Concept::Berry, Concept::Berry].into_iter(),
+            )
+        });
+        candidates.push((bush, bush_relevant, 200.0));
+        (candidates, bush)
+    }
+
+    #[test]
+    fn hungry_agent_attends_to_food_source_in_crowded_view() {
+        let mind = MindGraph::new(shared_ontology());
+        let focus = goal_focus(UrgencySource::Hunger);
+        assert_eq!(focus, Some(Concept::Food));
+
+        let (candidates, bush) = crowded_view(&mind, focus);
+        let attended = attend(candidates, ATTENTION_CAP);
+
+        assert_eq!(attended.len(), ATTENTION_CAP);
+        assert_eq!(
+            attended.first(),
+            Some(&bush),
+            "the berry bush should be perceived ahead of nearer rocks"
+        );
+    }
+
+    #[test]
+    fn unfocused_agent_attends_by_distance_alone() {
+        let mind = MindGraph::new(shared_ontology());
+        let focus = goal_focus(UrgencySource::Curiosity);
+        assert_eq!(focus, None);
+
+        let (candidates, bush) = crowded_view(&mind, focus);
+        let attended = attend(candidates, ATTENTION_CAP);
+
+        assert!(
+            !attended.contains(&bush),
+            "with no goal focus the distant bush falls past the cap"
+        );
+    }
+}
+
+#[cfg(test)]
+mod field_of_view_tests {
+    use super::*;
+
+    #[test]
+    fn cone_hides_what_is_behind_except_within_peripheral_radius() {
+        let vision = Vision {
+            range: 200.0,
+            fov_degrees: 180.0,
+        };
+        let facing = Vec2::X;
+        let peripheral = 1.5 * TILE_SIZE;
+
+        assert!(vision.in_cone(facing, Vec2::new(100.0, 0.0), peripheral));
+        assert!(vision.in_cone(facing, Vec2::new(10.0, 90.0), peripheral));
+        assert!(!vision.in_cone(facing, Vec2::new(-100.0, 0.0), peripheral));
+        assert!(vision.in_cone(facing, Vec2::new(-TILE_SIZE, 0.0), peripheral));
+
+        let all_round = Vision {
+            fov_degrees: 360.0,
+            ..vision
+        };
+        assert!(all_round.in_cone(facing, Vec2::new(-100.0, 0.0), peripheral));
+    }
+}
@@ -5,7 +5,8 @@
 //! 2. Marks strangers so the social brain can propose introductions
 //! 3. Tracks familiarity levels
 //!
-//! Emits SimEvent::StrangerDetected on first encounter.
+//! Emits SimEvent::StrangerDetected on first encounter and
+//! SimEvent::RelationshipFormed when a Friend / Rival / Enemy bond forms.
 
 use std::collections::VecDeque;
 
@@ -73,11 +74,14 @@ pub fn check_recognition(
                     Predicate::IsA,
                     &Value::Concept(Concept::Stranger),
                 ) {
+                    // Semantic, like the relationship categories below:
+                    // whether we've met someone is identity, not a
+                    // sighting, and shouldn't lapse once they walk off.
                     mind.assert(Triple::with_meta(
                         target_node.clone(),
                         Predicate::IsA,
                         Value::Concept(Concept::Stranger),
-                        Metadata::perception(current_time),
+                        Metadata::semantic(current_time),
                     ));
                     // Drop any stale relationship category from a previous
                     // acquaintance we've since forgotten about.
@@ -104,7 +108,20 @@ pub fn check_recognition(
                     _ => continue,
                 };
                 let log = history.get(partner);
-                update_relationship_category(&mut mind, &target_node, log, current_time);
+                if let Some(category) =
+                    update_relationship_category(&mut mind, &target_node, log, current_time)
+                {
+                    sim_events.write(crate::agent::events::SimEvent::pair(
+                        current_time,
+                        observer_entity,
+                        partner,
+                        SimEventKind::RelationshipFormed {
+                            agent: observer_entity,
+                            other: partner,
+                            category,
+                        },
+                    ));
+                }
             }
         }
     }
@@ -113,14 +130,17 @@ pub fn check_recognition(
 /// Classify a relationship from the interaction log rather than trust/affection
 /// thresholds. Requires a minimum history before any classification beyond
 /// Acquaintance — two agents who just met cannot be "Friends" regardless of
-/// initial trust values.
+/// initial trust values. Returns the category when it is a bond (Friend,
+/// Rival, Enemy) the observer didn't already hold.
 fn update_relationship_category(
     mind: &mut MindGraph,
     target: &Node,
     log: &VecDeque<InteractionRecord>,
     timestamp: u64,
-) {
+) -> Option<Concept> {
     let category = classify_from_history(log);
+    let newly_formed = category != Concept::Acquaintance
+        && !mind.has(target, Predicate::IsA, &Value::Concept(category));
 
     // Remove old categories.
     for old_cat in [
@@ -140,6 +160,7 @@ fn update_relationship_category(
         Value::Concept(category),
         Metadata::semantic(timestamp),
     ));
+    newly_formed.then_some(category)
 }
 
 /// Classify a relationship from the interaction log.
@@ -0,0 +1,195 @@
+//! Sight memory: where an entity was last seen after it leaves view.
+//!
+//! Reads: SightMemoryConfig, VisibleObjects, Transform, MindGraph (`LocatedAt` perception)
+//! Writes: MindGraph (`(Entity, LastKnownAt, Tile)` semantic beliefs, added and cleared)
+//! Upstream: perception::write_perceptions_to_mind (mobile `LocatedAt` perception)
+//! Downstream: look_for (WorldEntity searches head for the last-known tile first)
+//!
+//! A mobile entity's `LocatedAt` lives in the perception store and expires
+//! a few dozen ticks after it was last refreshed, so an agent that lost
+//! sight of prey or a friend forgot where it was almost at once. When a
+//! tracked entity leaves view, its last perceived tile is copied into a
+//! longer-lived, lower-confidence `LastKnownAt` belief. Reaching that tile
+//! without seeing the entity clears the belief — it has moved on.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::mind::knowledge::{
+    MemoryType, Metadata, MindGraph, Node, Predicate, Source, Triple, Value,
+};
+use crate::agent::mind::perception::VisibleObjects;
+use crate::world::map::TILE_SIZE;
+
+/// An agent within this many tiles (Chebyshev) of a last-known position
+/// has looked there.
+pub const LAST_KNOWN_SEARCH_RADIUS_TILES: i32 = 1;
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct SightMemoryConfig {
+    /// Off = entities are forgotten when their perception expires.
+    pub enabled: bool,
+    /// `LastKnownAt` confidence as a fraction of the sighting's.
+    pub confidence_factor: f32,
+}
+
+impl Default for SightMemoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            confidence_factor: 0.6,
+        }
+    }
+}
+
+/// Where `entity` is believed to be: the live `LocatedAt` if the agent
+/// still has one, otherwise the last-known tile.
+pub fn last_known_tile(mind: &MindGraph, entity: Entity) -> Option<(i32, i32)> {
+    let node = Node::Entity(entity);
+    [Predicate::LocatedAt, Predicate::LastKnownAt]
+        .into_iter()
+        .find_map(|predicate| match mind.get(&node, predicate) {
+            Some(Value::Tile(tile)) => Some(*tile),
+            _ => None,
+        })
+}
+
+fn within_search_radius(a: (i32, i32), b: (i32, i32)) -> bool {
+    (a.0 - b.0).abs().max((a.1 - b.1).abs()) <= LAST_KNOWN_SEARCH_RADIUS_TILES
+}
+
+/// Bevy system (FixedUpdate): copy the last sighting of every out-of-view
+/// mobile entity into `LastKnownAt`, and drop last-known positions the
+/// agent is standing at without seeing the entity.
+pub fn remember_last_seen(
+    config: Res<SightMemoryConfig>,
+    mut agents: Query<(&VisibleObjects, &Transform, &mut MindGraph), With<Agent>>,
+) {
+    if !config.enabled {
+        return;
+    }
+    for (visible, transform, mut mind) in agents.iter_mut() {
+        let in_view = |entity: &Entity| visible.entities.contains(entity);
+
+        let departed: Vec<(Entity, (i32, i32), u64, f32)> = mind
+            .query(None, Some(Predicate::LocatedAt), None)
+            .into_iter()
+            .filter(|t| t.meta.memory_type == MemoryType::Perception)
+            .filter_map(|t| match (&t.subject, &t.object) {
+                (Node::Entity(entity), Value::Tile(tile)) if !in_view(entity) => {
+                    Some((*entity, *tile, t.meta.timestamp, t.meta.confidence))
+                }
+                _ => None,
+            })
+            .collect();
+        for (entity, tile, seen_at, confidence) in departed {
+            let node = Node::Entity(entity);
+            let already_known = mind
+                .query(Some(&node), Some(Predicate::LastKnownAt), None)
+                .first()
+                .is_some_and(|t| t.meta.timestamp >= seen_at);
+            if already_known {
+                continue;
+            }
+            let mut meta = Metadata::semantic(seen_at);
+            meta.source = Source::Perception;
+            meta.confidence = confidence * config.confidence_factor;
+            mind.assert(Triple::with_meta(
+                node,
+                Predicate::LastKnownAt,
+                Value::Tile(tile),
+                meta,
+            ));
+        }
+
+        let pos = transform.translation.truncate();
+        let here = (
+            (pos.x / TILE_SIZE).floor() as i32,
+            (pos.y / TILE_SIZE).floor() as i32,
+        );
+        let searched: Vec<(Node, Value)> = mind
+            .query(None, Some(Predicate::LastKnownAt), None)
+            .into_iter()
+            .filter(|t| match (&t.subject, &t.object) {
+                (Node::Entity(entity), Value::Tile(tile)) => {
+                    !in_view(entity) && within_search_radius(here, *tile)
+                }
+                _ => false,
+            })
+            .map(|t| (t.subject.clone(), t.object.clone()))
+            .collect();
+        for (node, tile) in searched {
+            // The stale sighting goes too, or it would be copied straight back.
+            mind.remove(&node, Predicate::LastKnownAt, &tile);
+            mind.remove(&node, Predicate::LocatedAt, &tile);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::setup_ontology;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn leaving_view_leaves_a_last_known_position_until_searched() {
+        let mut world = World::new();
+        world.insert_resource(SightMemoryConfig::default());
+        let deer = world.spawn_empty().id();
+        let mut mind = MindGraph::new(setup_ontology());
+        mind.perceive_entity(deer, Predicate::LocatedAt, Value::Tile((10, 4)), 5, 0.9);
+        let agent = world
+            .spawn((
+                Agent,
+                VisibleObjects {
+                    entities: vec![deer],
+                    ..Default::default()
+                },
+                Transform::from_xyz(0.5 * TILE_SIZE, 0.5 * TILE_SIZE, 0.0),
+                mind,
+            ))
+            .id();
+
+        // Still in view: nothing to remember yet.
+        world.run_system_once(remember_last_seen).unwrap();
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        assert!(
+            mind.get(&Node::Entity(deer), Predicate::LastKnownAt)
+                .is_none()
+        );
+
+        world
+            .get_mut::<VisibleObjects>(agent)
+            .unwrap()
+            .entities
+            .clear();
+        world.run_system_once(remember_last_seen).unwrap();
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        let remembered = mind.query(
+            Some(&Node::Entity(deer)),
+            Some(Predicate::LastKnownAt),
+            None,
+        );
+        assert_eq!(remembered.len(), 1);
+        assert_eq!(remembered[0].object, Value::Tile((10, 4)));
+        assert_eq!(remembered[0].meta.memory_type, MemoryType::Semantic);
+        assert!(remembered[0].meta.confidence < 0.9);
+
+        // Perception expiry no longer erases where the deer was.
+        world
+            .get_mut::<MindGraph>(agent)
+            .unwrap()
+            .prune_expired_perception(1_000, 60);
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        assert_eq!(last_known_tile(mind, deer), Some((10, 4)));
+
+        // Walk there, find nothing: the belief is dropped.
+        world.get_mut::<Transform>(agent).unwrap().translation =
+            Vec3::new(10.5 * TILE_SIZE, 4.5 * TILE_SIZE, 0.0);
+        world.run_system_once(remember_last_seen).unwrap();
+        let mind = world.get::<MindGraph>(agent).unwrap();
+        assert_eq!(last_known_tile(mind, deer), None);
+    }
+}
@@ -1,14 +1,14 @@
 //! Social Perception - what species visible agents are.
 //!
 //! Reads: VisibleObjects, EntityType
-//! Writes: MindGraph (Entity, IsA, Concept) — observed species.
+//! Writes: MindGraph (Entity, IsA, Concept) — observed species, as semantic memory.
 //! Upstream: perception (VisibleObjects)
 //! Downstream: brain target enumeration, react_to_danger.
 
 use crate::agent::Agent;
 use crate::agent::inventory::EntityType;
-use crate::agent::mind::knowledge::{Concept, Metadata, MindGraph, Node, Predicate, Triple, Value};
-use crate::agent::mind::perception::VisibleObjects;
+use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use crate::agent::mind::perception::{VisibleObjects, visual_confidence};
 use crate::core::tick::TickCount;
 use bevy::prelude::*;
 
@@ -16,8 +16,10 @@ use bevy::prelude::*;
 pub const CONVERSATION_RANGE: f32 = 32.0;
 
 /// Per-tick: write `(visible_entity, IsA, Species)` for every other agent
-/// in view. Distance-weighted confidence so far-away observations decay
-/// faster.
+/// in view. Species is identity, not a transient observation, so it goes
+/// through `perceive_entity` and lands in semantic memory — a perception
+/// write here would outrank and overwrite the semantic belief (source
+/// priority), letting the agent forget who someone is once they walk off.
 pub fn perceive_other_agents(
     mut observers: Query<(Entity, &Transform, &VisibleObjects, &mut MindGraph), With<Agent>>,
     observable_agents: Query<(Entity, &Transform, &EntityType), With<Agent>>,
@@ -39,13 +41,13 @@ pub fn perceive_other_agents(
                 continue;
             };
             let distance = observer_pos.distance(target_transform.translation.truncate());
-            let confidence = (1.0 - (distance / 256.0).min(1.0)).max(0.3);
-            mind.assert(Triple::with_meta(
-                Node::Entity(visible_entity),
+            mind.perceive_entity(
+                visible_entity,
                 Predicate::IsA,
                 Value::Concept(entity_type.0),
-                Metadata::perception_with_conf(current_time, confidence),
-            ));
+                current_time,
+                visual_confidence(distance),
+            );
         }
     }
 }
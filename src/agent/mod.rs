@@ -1,5 +1,6 @@
 pub mod actions;
 pub mod affordance;
+pub mod archetype;
 pub mod biology;
 pub mod body;
 pub mod brains;
@@ -10,6 +11,8 @@ pub mod events;
 pub mod invariants;
 pub mod inventory;
 pub mod item_slots;
+pub mod lod;
+pub mod milestones;
 pub mod mind;
 pub mod movement;
 pub mod naming;
@@ -104,10 +107,17 @@ impl Plugin for AgentPlugin {
             .register_type::<TargetPosition>()
             .register_type::<player::PlayerControlled>()
             .register_type::<movement::MovementState>()
+            .register_type::<movement::Facing>()
+            .register_type::<movement::AutoFacingConfig>()
+            .init_resource::<movement::AutoFacingConfig>()
+            .register_type::<movement::MoveSettleConfig>()
+            .init_resource::<movement::MoveSettleConfig>()
             .register_type::<affordance::Affordance>()
             .register_type::<item_slots::ItemSlots>()
             .register_type::<item_slots::Thing>()
             .register_type::<item_slots::ThingProperties>()
+            .register_type::<item_slots::SpoilageConfig>()
+            .init_resource::<item_slots::SpoilageConfig>()
             .register_type::<inventory::EntityType>()
             .register_type::<psyche::personality::Personality>()
             .register_type::<psyche::values::Values>()
@@ -127,7 +137,28 @@ impl Plugin for AgentPlugin {
             .register_type::<psyche::emotions::EmotionalState>()
             .register_type::<psyche::emotions::EmotionConfig>()
             .init_resource::<psyche::emotions::EmotionConfig>()
+            .register_type::<psyche::emotion_journal::EmotionJournal>()
+            .register_type::<psyche::emotion_journal::EmotionJournalConfig>()
+            .init_resource::<psyche::emotion_journal::EmotionJournalConfig>()
+            .register_type::<mind::perception::PerceptionConfig>()
+            .init_resource::<mind::perception::PerceptionConfig>()
+            .register_type::<culture::Culture>()
+            .register_type::<culture::CultureDriftConfig>()
+            .init_resource::<culture::CultureDriftConfig>()
+            .init_resource::<culture::CultureDrift>()
             .register_type::<mind::knowledge::MindGraph>()
+            .register_type::<mind::knowledge::MindQueryConfig>()
+            .init_resource::<mind::knowledge::MindQueryConfig>()
+            .register_type::<mind::hearsay_intake::HearsayIntakeConfig>()
+            .init_resource::<mind::hearsay_intake::HearsayIntakeConfig>()
+            .init_resource::<mind::hearsay_intake::HearsayIntake>()
+            .register_type::<mind::sight_memory::SightMemoryConfig>()
+            .init_resource::<mind::sight_memory::SightMemoryConfig>()
+            .register_type::<lod::LodLevel>()
+            .register_type::<lod::DeferredNeeds>()
+            .register_type::<lod::LodConfig>()
+            .init_resource::<lod::LodConfig>()
+            .init_resource::<lod::LodClock>()
             .register_type::<skills::Skills>()
             .register_type::<skills::SkillsConfig>()
             .init_resource::<skills::SkillsConfig>()
@@ -138,6 +169,8 @@ impl Plugin for AgentPlugin {
             .add_message::<events::GameEvent>()
             .add_message::<events::ActionOutcomeEvent>()
             .add_message::<events::SimEvent>()
+            .add_message::<milestones::Milestone>()
+            .add_systems(Last, milestones::publish_milestones)
             .add_plugins(biology::BiologyPlugin)
             .add_plugins(brains::BrainPlugin)
             .add_plugins(nervous_system::NervousSystemPlugin)
@@ -156,10 +189,18 @@ impl Plugin for AgentPlugin {
                 (
                     nervous_system::execution::start_actions
                         .after(brains::brain_system::arbitrate_every_tick),
+                    movement::face_interaction_targets
+                        .after(nervous_system::execution::start_actions)
+                        .before(nervous_system::execution::tick_actions),
                     nervous_system::execution::tick_actions
                         .after(nervous_system::execution::start_actions),
                     nervous_system::execution::apply_action_effects
                         .after(nervous_system::execution::tick_actions),
+                    movement::face_movement_direction
+                        .after(nervous_system::execution::tick_actions)
+                        .before(movement::separate_crowded_agents),
+                    movement::separate_crowded_agents
+                        .after(nervous_system::execution::tick_actions),
                 )
                     .in_set(crate::core::PerfBucket::Action)
                     .in_set(crate::core::PerfSubBucket::ActionExecution)
@@ -179,6 +220,15 @@ impl Plugin for AgentPlugin {
                     .in_set(crate::core::PerfSubBucket::ActionWorldMutation)
                     .run_if(not_paused),
             )
+            .add_systems(
+                FixedUpdate,
+                lod::assign_lod_levels
+                    .before(mind::perception::update_visual_perception)
+                    .before(nervous_system::metabolism::tick_metabolism)
+                    .before(brains::brain_system::arbitrate_every_tick)
+                    .in_set(crate::core::PerfBucket::Perception)
+                    .run_if(not_paused),
+            )
             // Visual perception is N² across visible entities — the dominant cost
             // inside the Perception bucket. Kept isolated so regressions here
             // show up under its own sub-bucket instead of hiding in a combined
@@ -198,14 +248,22 @@ impl Plugin for AgentPlugin {
                 FixedUpdate,
                 (
                     mind::perception::update_body_perception,
+                    archetype::seed_archetype_food_memory,
                     mind::perception::perceive_temperature,
-                    mind::perception::perceive_hearing,
+                    // Hearing runs after the emitter so a call inserted this
+                    // tick is applied (auto sync point) before anyone listens.
+                    mind::perception::perceive_hearing.after(mind::perception::emit_alarm_calls),
                     mind::perception::emit_alarm_calls,
+                    mind::perception::hear_alarm_signals.after(mind::perception::emit_alarm_calls),
                     mind::perception::cleanup_sound_sources
                         .after(mind::perception::perceive_hearing)
+                        .after(mind::perception::hear_alarm_signals)
                         .after(mind::perception::emit_alarm_calls),
+                    mind::perception::emit_loud_actions,
+                    mind::perception::perceive_sound.after(mind::perception::emit_loud_actions),
                     mind::perception::react_to_danger
-                        .after(mind::perception::write_perceptions_to_mind),
+                        .after(mind::perception::write_perceptions_to_mind)
+                        .after(mind::perception::perceive_sound),
                 )
                     .in_set(crate::core::PerfBucket::Perception)
                     .in_set(crate::core::PerfSubBucket::PerceptionSensory)
@@ -224,6 +282,10 @@ impl Plugin for AgentPlugin {
                         .after(mind::social_perception::perceive_other_agents),
                     mind::affective_tom::decay_affective_tom
                         .after(mind::affective_tom::update_affective_tom),
+                    mind::sight_memory::remember_last_seen
+                        .after(mind::perception::write_perceptions_to_mind),
+                    mind::area_beliefs::note_area_contents
+                        .after(mind::perception::update_visual_perception),
                 )
                     .in_set(crate::core::PerfBucket::Perception)
                     .in_set(crate::core::PerfSubBucket::PerceptionSocial)
@@ -233,8 +295,12 @@ impl Plugin for AgentPlugin {
                 FixedUpdate,
                 (
                     crate::agent::mind::belief_updater::process_action_outcomes,
+                    mind::observational_learning::learn_from_observed_harvests,
                     mind::memory::process_perception,
                     mind::memory::process_working_memory,
+                    mind::hearsay_intake::integrate_hearsay
+                        .after(mind::memory::process_working_memory)
+                        .after(crate::agent::engagement::converse::process_received_communication),
                     mind::memory::decay_stale_knowledge,
                 )
                     .in_set(crate::core::PerfBucket::Memory)
@@ -243,11 +309,15 @@ impl Plugin for AgentPlugin {
             )
             .add_systems(
                 FixedUpdate,
-                mind::consolidation::consolidate_knowledge
+                (
+                    mind::consolidation::consolidate_knowledge,
+                    culture::drift_cultures,
+                )
                     .in_set(crate::core::PerfBucket::Memory)
                     .in_set(crate::core::PerfSubBucket::MemoryConsolidation)
                     .run_if(not_paused),
             )
+            .add_systems(FixedFirst, mind::knowledge::apply_mind_query_config)
             .add_systems(
                 FixedUpdate,
                 mind::knowledge::drain_mindgraph_mutations
@@ -267,9 +337,16 @@ impl Plugin for AgentPlugin {
                 (
                     psyche::emotions::decay_emotions,
                     psyche::emotions::update_mood,
+                    psyche::emotions::emotional_contagion.after(psyche::emotions::update_mood),
                     psyche::emotions::update_stress,
                     psyche::emotions::react_to_events,
                     psyche::emotions::react_to_combat_hit,
+                    psyche::emotions::react_to_pain.after(psyche::emotions::decay_emotions),
+                    psyche::emotion_journal::record_emotion_journal
+                        .after(psyche::emotions::decay_emotions)
+                        .after(psyche::emotions::react_to_events)
+                        .after(psyche::emotions::react_to_combat_hit)
+                        .after(psyche::emotions::react_to_pain),
                 )
                     .in_set(crate::core::PerfBucket::Psyche)
                     .in_set(crate::core::PerfSubBucket::PsycheEmotions)
@@ -293,6 +370,7 @@ impl Plugin for AgentPlugin {
                         .after(brains::brain_system::arbitrate_every_tick),
                     psyche::greetings::social_acknowledgments
                         .after(psyche::flocking::decay_social_from_proximity),
+                    psyche::gifts::acknowledge_gifts.after(nervous_system::execution::tick_actions),
                 )
                     .in_set(crate::core::PerfBucket::Psyche)
                     .in_set(crate::core::PerfSubBucket::PsycheSocial)
@@ -1,23 +1,219 @@
-//! Movement utilities: tick-based position stepping toward a target with speed modifiers for fatigue and injury.
+//! Movement utilities: tick-based position stepping toward a target with speed modifiers for fatigue, injury and weather.
 //!
-//! Reads: MovementState (last_tick), TickCount, PhysicalNeeds (stamina for speed penalty), Body (injury mobility), WorldMap (walkability)
-//! Writes: Transform (position), MovementState (last_tick updated), MoveResult (Arrived/Moving/Blocked)
-//! Upstream: constants::movement (speed/threshold values), world::map (walkability checks), body::needs (fatigue)
+//! Reads: MovementState (last_tick), MoveSettleConfig, PathfindingConfig (route waypoints), TickCount, PhysicalNeeds (stamina for speed penalty), Body (injury mobility), Weather (wet-ground slowdown), WorldMap (walkability, edge policy), ActiveActions + AutoFacingConfig (interaction facing), SpatialIndex + TargetPosition (tile crowding)
+//! Writes: Transform (position), MovementState (last_tick, last_position), MoveResult (Arrived/Moving/Blocked), Facing (interaction target, direction of travel)
+//! Upstream: constants::movement (speed/threshold values), world::map (walkability checks), world::pathfinding (A* routes), world::spatial_index (occupancy candidates), body::needs (fatigue)
 //! Downstream: action execution systems (call move_toward each tick), nervous_system (movement completes actions)
 
 use crate::constants::movement::{
-    BASE_SPEED_PER_TICK, EXHAUSTED_SPEED_MULTIPLIER, EXHAUSTED_STAMINA_THRESHOLD,
-    INJURY_MOBILITY_RANGE, MIN_INJURY_MOBILITY, TIRED_SPEED_MULTIPLIER, TIRED_STAMINA_THRESHOLD,
+    BASE_SPEED_PER_TICK, CROWDING_NUDGE_PER_TICK, EXHAUSTED_SPEED_MULTIPLIER,
+    EXHAUSTED_STAMINA_THRESHOLD, INJURY_MOBILITY_RANGE, MAX_AGENTS_PER_TILE, MIN_INJURY_MOBILITY,
+    TIRED_SPEED_MULTIPLIER, TIRED_STAMINA_THRESHOLD,
 };
+use crate::world::pathfinding::{PathfindingConfig, find_path, has_line_of_sight};
+use crate::world::spatial_index::{SpatialIndex, tile_center_px, world_pos_to_tile};
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// Tracks movement timing for tick-based movement
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct MovementState {
     pub last_tick: u64,
+    /// Position at the end of the previous action tick, for deriving the
+    /// direction of travel.
+    pub last_position: Option<Vec2>,
 }
 
+/// Unit vector the agent is facing. Inserted the first time the agent
+/// moves or turns toward something; read by directional sprites and
+/// vision cones.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct Facing(pub Vec2);
+
+impl Default for Facing {
+    fn default() -> Self {
+        Self(Vec2::X)
+    }
+}
+
+/// Auto-facing for entity-targeted actions: while such an action runs and
+/// the target is within `range`, the agent turns to face it.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct AutoFacingConfig {
+    pub enabled: bool,
+    /// Pixels. Targets farther away than this are left alone, so an
+    /// agent still walking over to a bush doesn't crab-walk toward it.
+    pub range: f32,
+}
+
+impl Default for AutoFacingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            range: 3.0 * crate::world::map::TILE_SIZE,
+        }
+    }
+}
+
+/// Bevy system (FixedUpdate, after `start_actions`): points each agent's
+/// `Facing` at the target of its first in-range, entity-targeted,
+/// non-locomotion action. Runs every tick, so the facing holds for the
+/// whole interaction.
+pub fn face_interaction_targets(
+    mut commands: Commands,
+    config: Res<AutoFacingConfig>,
+    map: Res<crate::world::map::WorldMap>,
+    mut agents: Query<(
+        Entity,
+        &Transform,
+        &crate::agent::actions::ActiveActions,
+        Option<&mut Facing>,
+    )>,
+    targets: Query<&Transform>,
+) {
+    if !config.enabled {
+        return;
+    }
+    for (entity, transform, active, facing) in agents.iter_mut() {
+        let pos = transform.translation.truncate();
+        let direction = active
+            .iter()
+            .filter(|action| action.locomotion_intensity <= 0.0)
+            .filter_map(|action| action.target_entity)
+            .filter(|&target| target != entity)
+            .filter_map(|target| targets.get(target).ok())
+            .map(|target| map.delta(pos, target.translation.truncate()))
+            .find(|delta| delta.length() <= config.range && *delta != Vec2::ZERO);
+        let Some(direction) = direction else {
+            continue;
+        };
+        let direction = direction.normalize();
+        match facing {
+            Some(mut facing) => {
+                if facing.0 != direction {
+                    facing.0 = direction;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(Facing(direction));
+            }
+        }
+    }
+}
+
+/// Bevy system (FixedUpdate, after `tick_actions`): turns each agent's
+/// `Facing` toward the way it moved this tick. Standing still keeps the
+/// last facing, so an agent that stops to harvest keeps looking ahead.
+pub fn face_movement_direction(
+    mut commands: Commands,
+    map: Res<crate::world::map::WorldMap>,
+    mut agents: Query<(Entity, &Transform, &mut MovementState, Option<&mut Facing>)>,
+) {
+    for (entity, transform, mut movement, facing) in agents.iter_mut() {
+        let pos = transform.translation.truncate();
+        let Some(last) = movement.last_position.replace(pos) else {
+            continue;
+        };
+        let Some(direction) = map.delta(last, pos).try_normalize() else {
+            continue;
+        };
+        match facing {
+            Some(mut facing) => {
+                if facing.0 != direction {
+                    facing.0 = direction;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(Facing(direction));
+            }
+        }
+    }
+}
+
+/// Bevy system (FixedUpdate, after `tick_actions`): soft collision between
+/// settled agents. When more than `MAX_AGENTS_PER_TILE` agents with no
+/// movement target share a tile, every one but the lowest entity drifts
+/// toward the least crowded walkable neighbour tile until it crosses over.
+/// Agents still walking are neither nudged nor counted, so paths can pass
+/// through an occupied tile.
+pub fn separate_crowded_agents(
+    index: Res<SpatialIndex>,
+    map: Res<crate::world::map::WorldMap>,
+    mut agents: Query<
+        (Entity, &mut Transform, &crate::agent::TargetPosition),
+        With<crate::agent::Alive>,
+    >,
+) {
+    let settled: HashMap<Entity, Vec2> = agents
+        .iter()
+        .filter(|(_, _, target)| target.0.is_none())
+        .map(|(entity, transform, _)| (entity, transform.translation.truncate()))
+        .collect();
+
+    let mut nudges: Vec<(Entity, Vec2)> = Vec::new();
+    for (&entity, &pos) in &settled {
+        let tile = world_pos_to_tile(pos);
+        let nearby = index.entities_near(pos, 2.0 * crate::world::map::TILE_SIZE);
+        let occupancy = |tile: IVec2| {
+            nearby
+                .iter()
+                .filter_map(|e| settled.get(e).map(|p| (*e, *p)))
+                .filter(|(_, p)| world_pos_to_tile(*p) == tile)
+                .map(|(e, _)| e)
+                .collect::<Vec<_>>()
+        };
+        let here = occupancy(tile);
+        if here.len() <= MAX_AGENTS_PER_TILE || here.iter().all(|&e| e >= entity) {
+            continue;
+        }
+        let destination = NEIGHBOUR_OFFSETS
+            .iter()
+            .map(|offset| tile + *offset)
+            .filter(|n| map.is_walkable(map.wrap_position(tile_center_px(*n))))
+            .map(|n| (n, occupancy(n).len()))
+            .filter(|(_, count)| *count < MAX_AGENTS_PER_TILE)
+            .min_by(|(a, count_a), (b, count_b)| {
+                count_a.cmp(count_b).then_with(|| {
+                    pos.distance_squared(tile_center_px(*a))
+                        .total_cmp(&pos.distance_squared(tile_center_px(*b)))
+                })
+            });
+        if let Some((neighbour, _)) = destination {
+            nudges.push((entity, tile_center_px(neighbour)));
+        }
+    }
+
+    for (entity, destination) in nudges {
+        let Ok((_, mut transform, _)) = agents.get_mut(entity) else {
+            continue;
+        };
+        let pos = transform.translation.truncate();
+        let step = map
+            .delta(pos, destination)
+            .clamp_length_max(CROWDING_NUDGE_PER_TICK);
+        let next = map.wrap_position(pos + step);
+        if map.is_walkable(next) {
+            transform.translation.x = next.x;
+            transform.translation.y = next.y;
+        }
+    }
+}
+
+/// Orthogonal neighbours first so ties prefer a side step over a diagonal.
+const NEIGHBOUR_OFFSETS: [IVec2; 8] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+    IVec2::new(1, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(1, -1),
+    IVec2::new(-1, -1),
+];
+
 /// Consistent arrival threshold for all movement types
 pub const ARRIVAL_THRESHOLD: f32 = 2.0;
 
@@ -47,8 +243,11 @@ pub fn move_toward(
     map: &crate::world::map::WorldMap,
     transform: &mut Transform,
 ) -> MoveResult {
-    let direction = target_pos - current_pos;
+    // Under a wrapping map the shortest way to the target may cross an edge;
+    // the target itself may also lie past one, so snap to its on-map image.
+    let direction = map.delta(current_pos, target_pos);
     let distance = direction.length();
+    let target_pos = map.wrap_position(target_pos);
 
     if distance < ARRIVAL_THRESHOLD {
         // Already at destination — snap to exact position so the perceived tile
@@ -70,11 +269,11 @@ pub fn move_toward(
     let new_pos = if move_dist >= distance {
         target_pos
     } else {
-        current_pos + direction.normalize() * move_dist
+        map.wrap_position(current_pos + direction.normalize() * move_dist)
     };
 
     if map.is_walkable(new_pos) {
-        let arrived = new_pos.distance(target_pos) < ARRIVAL_THRESHOLD;
+        let arrived = map.distance(new_pos, target_pos) < ARRIVAL_THRESHOLD;
         // Snap to exact target on arrival so the perceived tile always matches
         // the Walk effect's tile (prevents is_step_complete from staying false).
         let set_pos = if arrived { target_pos } else { new_pos };
@@ -91,6 +290,43 @@ pub fn move_toward(
     }
 }
 
+/// Action types that walk a computed route instead of a straight line.
+pub fn follows_route(action_type: crate::agent::actions::ActionType) -> bool {
+    use crate::agent::actions::ActionType;
+    matches!(
+        action_type,
+        ActionType::Walk | ActionType::Explore | ActionType::Wander
+    )
+}
+
+/// Intermediate waypoints (tile centres, in travel order) for a walk from
+/// `from` to `to`, excluding the target itself. Empty when the straight
+/// line is already clear, or when no route exists — the straight-line step
+/// then reports `Blocked` as it always has.
+pub fn route_waypoints(
+    map: &crate::world::map::WorldMap,
+    from: Vec2,
+    to: Vec2,
+    config: &PathfindingConfig,
+) -> Vec<Vec2> {
+    let tile_of = |pos: Vec2| {
+        let (x, y) = map.world_to_tile(map.wrap_position(pos));
+        (x as i32, y as i32)
+    };
+    let (start, goal) = (tile_of(from), tile_of(to));
+    if start == goal || has_line_of_sight(map, start, goal) {
+        return Vec::new();
+    }
+    let Some(path) = find_path(map, start, goal, config) else {
+        return Vec::new();
+    };
+    let inner = path.len().saturating_sub(1);
+    path[1.min(inner)..inner]
+        .iter()
+        .map(|&(x, y)| map.tile_to_world(x, y))
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MoveResult {
     Moving,
@@ -98,9 +334,88 @@ pub enum MoveResult {
     Blocked,
 }
 
-/// Calculates movement speed based on stamina levels and body condition.
+/// When a movement target the agent can get near but never onto counts as
+/// reached: a point just inside an obstacle, or a tile the crowding nudge
+/// keeps pushing it off. Without this the agent vibrates around the target
+/// (or fails the move a step short of it) for as long as the action runs.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct MoveSettleConfig {
+    pub enabled: bool,
+    /// Pixels. Stalling within this distance of the target counts toward
+    /// arrival; stalling farther out is left to the usual Blocked handling.
+    pub close_enough: f32,
+    /// Pixels. A tick that doesn't beat the closest approach so far by at
+    /// least this much made no progress.
+    pub min_progress: f32,
+    /// Consecutive no-progress ticks within `close_enough` before the move
+    /// is accepted as arrived.
+    pub settle_ticks: u32,
+}
+
+impl Default for MoveSettleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            close_enough: crate::world::map::TILE_SIZE,
+            min_progress: 0.1,
+            settle_ticks: 10,
+        }
+    }
+}
+
+/// How close one movement action has got to its target. Lives on the
+/// action's `ActionState` and resets with it.
+#[derive(Debug, Clone, Copy, Default, Reflect)]
+pub struct SettleProgress {
+    /// Closest distance to the target so far; `None` before the first step.
+    pub closest: Option<f32>,
+    /// Consecutive no-progress ticks within `close_enough`.
+    pub stalled_ticks: u32,
+}
+
+/// Post-process one [`move_toward`] step. Near the target, a step that
+/// makes no progress (or is blocked) is a stall; once `settle_ticks`
+/// stalls in a row have piled up the move settles as `Arrived` where the
+/// agent stands. A blocked step near the target holds position while
+/// stalling rather than failing the move outright.
+pub fn settle_move(
+    result: MoveResult,
+    distance: f32,
+    progress: &mut SettleProgress,
+    config: &MoveSettleConfig,
+) -> MoveResult {
+    if !config.enabled || result == MoveResult::Arrived {
+        return result;
+    }
+    let improved = progress
+        .closest
+        .is_none_or(|closest| closest - distance >= config.min_progress);
+    progress.closest = Some(progress.closest.map_or(distance, |c| c.min(distance)));
+
+    if distance > config.close_enough {
+        progress.stalled_ticks = 0;
+        return result;
+    }
+    if improved && result == MoveResult::Moving {
+        progress.stalled_ticks = 0;
+        return result;
+    }
+    progress.stalled_ticks += 1;
+    if progress.stalled_ticks >= config.settle_ticks {
+        MoveResult::Arrived
+    } else {
+        MoveResult::Moving
+    }
+}
+
+/// Calculates movement speed based on stamina levels, body condition and weather.
 /// Returns pixels per tick (assuming 60 ticks/sec equivalent).
-pub fn calculate_speed(stamina: f32, body: Option<&crate::agent::biology::body::Body>) -> f32 {
+pub fn calculate_speed(
+    stamina: f32,
+    body: Option<&crate::agent::biology::body::Body>,
+    weather: crate::world::weather::Weather,
+) -> f32 {
     // Speed = pixels per tick (at 60 ticks/sec, 1.0 = 60 px/sec equivalent)
     // FATIGUE PENALTY
     let mut speed_modifier = 1.0;
@@ -128,7 +443,10 @@ pub fn calculate_speed(stamina: f32, body: Option<&crate::agent::biology::body::
         injury_modifier = MIN_INJURY_MOBILITY + (clamped * INJURY_MOBILITY_RANGE);
     }
 
-    BASE_SPEED_PER_TICK * speed_modifier * injury_modifier
+    // WEATHER PENALTY — rain and storms make the footing slow going.
+    let weather_modifier = weather.speed_multiplier();
+
+    BASE_SPEED_PER_TICK * speed_modifier * injury_modifier * weather_modifier
 }
 
 /// Maps a locomotion intensity in [0, 1] to a speed multiplier applied on
@@ -215,6 +533,16 @@ mod intensity_tests {
         assert_eq!(effective_intensity(0.5, &s), 0.3);
     }
 
+    #[test]
+    fn rain_slows_walking_and_storm_slows_it_more() {
+        use crate::world::weather::Weather;
+        let clear = calculate_speed(100.0, None, Weather::Clear);
+        let rain = calculate_speed(100.0, None, Weather::Rain);
+        let storm = calculate_speed(100.0, None, Weather::Storm);
+        assert_eq!(calculate_speed(100.0, None, Weather::Overcast), clear);
+        assert!(rain < clear && storm < rain, "{clear} / {rain} / {storm}");
+    }
+
     #[test]
     fn effective_intensity_does_not_upgrade() {
         // A walk-intensity desired never gets boosted.
@@ -222,3 +550,57 @@ mod intensity_tests {
         assert_eq!(effective_intensity(0.25, &s), 0.25);
     }
 }
+
+#[cfg(test)]
+mod route_tests {
+    use super::*;
+    use crate::world::map::{Chunk, TileType, WorldMap};
+
+    fn map_with_wall() -> WorldMap {
+        let mut map = WorldMap::new(16, 16);
+        map.chunks.insert(IVec2::new(0, 0), Chunk::new(0, 0));
+        // North-south wall at x = 8 with a gap at y = 14.
+        for y in 0..14 {
+            map.set_tile(8, y, TileType::Water);
+        }
+        map
+    }
+
+    #[test]
+    fn clear_line_needs_no_waypoints() {
+        let map = map_with_wall();
+        let config = PathfindingConfig::default();
+        let from = map.tile_to_world(2, 2);
+        assert!(route_waypoints(&map, from, map.tile_to_world(6, 5), &config).is_empty());
+    }
+
+    #[test]
+    fn walled_off_target_routes_through_the_gap() {
+        let map = map_with_wall();
+        let config = PathfindingConfig::default();
+        let (from, to) = (map.tile_to_world(4, 4), map.tile_to_world(12, 4));
+
+        let waypoints = route_waypoints(&map, from, to, &config);
+
+        assert!(!waypoints.is_empty());
+        assert!(
+            waypoints.iter().all(|&w| w != to && map.is_walkable(w)),
+            "waypoints stop short of the target and stay on land"
+        );
+        let mut legs = vec![from];
+        legs.extend(&waypoints);
+        legs.push(to);
+        for leg in legs.windows(2) {
+            let tile = |p: Vec2| {
+                let (x, y) = map.world_to_tile(p);
+                (x as i32, y as i32)
+            };
+            assert!(
+                has_line_of_sight(&map, tile(leg[0]), tile(leg[1])),
+                "leg {:?} -> {:?} crosses the wall",
+                leg[0],
+                leg[1]
+            );
+        }
+    }
+}
@@ -19,6 +19,10 @@ pub struct CentralNervousSystem {
     /// biological wake pathway sees the raw signal, not the alertness-
     /// dampened urgency.
     pub sleep_wake_trigger: Option<UrgencySource>,
+    /// Ticks between urgency updates for this agent, refreshed every tick
+    /// from `NervousSystemConfig::effective_thinking_interval`. Zero until
+    /// the urgency system first sees the agent.
+    pub thinking_interval: u64,
 }
 
 impl CentralNervousSystem {
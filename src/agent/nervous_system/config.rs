@@ -1,12 +1,14 @@
 //! Nervous system configuration: response curves, personality modifiers, and urgency drive definitions.
 //!
-//! Reads: Personality traits, UrgencySource tags
+//! Reads: Personality traits, UrgencySource tags, EmotionalState (stress/fear for the thinking interval)
 //! Writes: ResponseCurve, PersonalityMod, ModifierOp, UrgencyDriveConfig (loaded as Bevy resources)
 //! Upstream: psyche::personality (trait values used in PersonalityMod), nervous_system::urgency (UrgencySource)
-//! Downstream: nervous_system::urgency (applies curves and modifiers to compute urgency scores)
+//! Downstream: nervous_system::urgency (applies curves and modifiers to compute urgency scores; thinking cadence), brains::rational (replan cooldown)
 
 use crate::agent::nervous_system::urgency::UrgencySource;
+use crate::agent::psyche::emotions::{EmotionType, EmotionalState};
 use crate::agent::psyche::personality::PersonalityTrait;
+use crate::constants::brains::neglect;
 use bevy::prelude::*;
 
 /// Curve type for mapping input (0-1) to output (0-1)
@@ -93,6 +95,30 @@ pub struct ContextModifier {
     pub factor: f32,
 }
 
+/// Time-since-satisfied multiplier for a drive. Once the drive's satisfier
+/// hasn't run for `grace_ticks`, the score climbs linearly to
+/// `1 + max_boost` over the next `ramp_ticks` — an agent who skipped
+/// breakfast and lunch feels it more than one at the same hunger level
+/// who just ate.
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct NeglectBoost {
+    /// Ticks since last satisfied before any boost applies.
+    pub grace_ticks: u64,
+    /// Ticks past the grace period for the boost to reach `max_boost`.
+    pub ramp_ticks: u64,
+    /// Extra score fraction at full neglect.
+    pub max_boost: f32,
+}
+
+impl NeglectBoost {
+    /// Score multiplier after `elapsed_ticks` without satisfaction.
+    pub fn multiplier(&self, elapsed_ticks: u64) -> f32 {
+        let overdue = elapsed_ticks.saturating_sub(self.grace_ticks) as f32;
+        let ramp = (overdue / self.ramp_ticks.max(1) as f32).min(1.0);
+        1.0 + self.max_boost * ramp
+    }
+}
+
 /// Configuration for a single drive/urgency source
 #[derive(Debug, Clone, Reflect)]
 pub struct DriveConfig {
@@ -130,6 +156,10 @@ pub struct DriveConfig {
     /// against the pre-gated `normalized_input`, so it works even for drives
     /// whose urgency is dampened by low alertness.
     pub sleep_wake_threshold: Option<f32>,
+
+    /// Boost from time since the drive was last satisfied. Only drives
+    /// with a tracked satisfaction tick (Hunger, Sleepiness) use it.
+    pub neglect: Option<NeglectBoost>,
 }
 
 impl Default for DriveConfig {
@@ -144,6 +174,7 @@ impl Default for DriveConfig {
             min_threshold: 0.01,
             bypasses_gating: false,
             sleep_wake_threshold: None,
+            neglect: None,
         }
     }
 }
@@ -167,8 +198,15 @@ pub struct NervousSystemConfig {
     pub interoception: SensoryChannelConfig,
     pub exteroception: SensoryChannelConfig,
     pub proprioception: SensoryChannelConfig,
-    /// Tick interval for running expensive thinking/urgency updates
+    /// Baseline tick interval for running expensive thinking/urgency
+    /// updates. Calm agents think at this rate; aroused ones faster — see
+    /// [`Self::effective_thinking_interval`].
     pub thinking_interval: u64,
+    /// Thinking interval of a fully alarmed agent (max stress or fear).
+    pub alarmed_thinking_interval: u64,
+    /// Arousal (the larger of stress and fear, 0..1) below which an agent
+    /// thinks at the baseline `thinking_interval`.
+    pub arousal_threshold: f32,
     /// Tick interval for running perception updates (default: 10 ticks = 6 Hz)
     pub perception_interval: u64,
 }
@@ -194,6 +232,35 @@ impl Default for NervousSystemConfig {
                     // Dedicated nociceptive wake pathway: significant injury
                     // (body pain >= 60/100) rouses a sleeper.
                     sleep_wake_threshold: Some(0.6),
+                    neglect: None,
+                },
+                // RECOVERY: rest-to-heal pull. Sigmoid lights up around a
+                // third of the way to a saturated wound load, so scrapes
+                // are shrugged off but a real injury is worth sitting out.
+                // Fear dampens it — nobody sits down to heal with a wolf
+                // in sight.
+                DriveConfig {
+                    name: "Recovery".to_string(),
+                    source: UrgencySource::Recovery,
+                    base_constant: 0.0,
+                    curve: ResponseCurve::Sigmoid {
+                        k: 10.0,
+                        midpoint: 0.3,
+                    },
+                    sensitivity: PersonalityMod {
+                        trait_type: PersonalityTrait::Conscientiousness,
+                        base: 0.8,
+                        scale: 0.3,
+                    },
+                    modifiers: vec![ContextModifier {
+                        input_source: UrgencySource::Fear,
+                        operation: ModifierOp::DampenByHigh,
+                        factor: 1.0,
+                    }],
+                    min_threshold: crate::constants::brains::recovery::MIN_URGENCY_THRESHOLD,
+                    bypasses_gating: false,
+                    sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // THIRST
                 DriveConfig {
@@ -218,6 +285,7 @@ impl Default for NervousSystemConfig {
                     // ~15), which kills the 6-8h sleep bout well before it
                     // meets the rested-wake `wakefulness >= 0.95` condition.
                     sleep_wake_threshold: Some(0.95),
+                    neglect: None,
                 },
                 // HUNGER
                 DriveConfig {
@@ -238,6 +306,11 @@ impl Default for NervousSystemConfig {
                     bypasses_gating: false,
                     // Starvation (cortisol surge) eventually overrides sleep.
                     sleep_wake_threshold: Some(0.9),
+                    neglect: Some(NeglectBoost {
+                        grace_ticks: neglect::HUNGER_GRACE_TICKS,
+                        ramp_ticks: neglect::HUNGER_RAMP_TICKS,
+                        max_boost: neglect::HUNGER_MAX_BOOST,
+                    }),
                 },
                 // STAMINA (Fatigue): Note inputs are inverted logic in urgency.rs if needed,
                 // but config just defines the response curve.
@@ -260,6 +333,7 @@ impl Default for NervousSystemConfig {
                     // Fatigue is the reason to sleep, not the reason to wake.
                     // Rested-wake is handled separately by the aerobic check.
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // SOCIAL
                 DriveConfig {
@@ -283,6 +357,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: 0.01,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // FEAR
                 DriveConfig {
@@ -304,6 +379,7 @@ impl Default for NervousSystemConfig {
                     // Amygdala wake pathway: a clearly visible threat
                     // (fear emotion >= ~0.44) rouses a sleeper.
                     sleep_wake_threshold: Some(0.44),
+                    neglect: None,
                 },
                 // TERRITORIALITY — raised externally by update_territoriality when
                 // intruders are perceived on owned tiles. Dampened by fear so
@@ -333,6 +409,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: 0.01,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // CURIOSITY — desire for novel stimulation. Wired to the
                 // real `drives.curiosity` state (rises during unstimulating
@@ -354,6 +431,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: 0.05,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // SLEEPINESS — homeostatic sleep pressure from wakefulness
                 // decay. Independent of Stamina: an idle desk worker still
@@ -391,6 +469,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: crate::constants::brains::warmth::MIN_URGENCY_THRESHOLD,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // REST QUALITY: sleep-comfort deficit. Same shape as Warmth —
                 // sigmoid lights up once `1 - rest_quality` crosses ~0.7,
@@ -414,6 +493,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: crate::constants::brains::rest_quality::MIN_URGENCY_THRESHOLD,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 // FOOD SECURITY: stockpile-access deficit. Sigmoid lights
                 // up once `1 - food_security` crosses ~0.7. High hunger
@@ -440,6 +520,7 @@ impl Default for NervousSystemConfig {
                     min_threshold: crate::constants::brains::food_security::MIN_URGENCY_THRESHOLD,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: None,
                 },
                 DriveConfig {
                     name: "Sleepiness".to_string(),
@@ -469,6 +550,11 @@ impl Default for NervousSystemConfig {
                     min_threshold: 0.3,
                     bypasses_gating: false,
                     sleep_wake_threshold: None,
+                    neglect: Some(NeglectBoost {
+                        grace_ticks: neglect::SLEEP_GRACE_TICKS,
+                        ramp_ticks: neglect::SLEEP_RAMP_TICKS,
+                        max_boost: neglect::SLEEP_MAX_BOOST,
+                    }),
                 },
             ],
             momentum_bonus: 1.5,
@@ -476,6 +562,7 @@ impl Default for NervousSystemConfig {
                 sources: vec![
                     UrgencySource::Hunger,
                     UrgencySource::Pain,
+                    UrgencySource::Recovery,
                     UrgencySource::Thirst,
                     UrgencySource::Warmth,
                     UrgencySource::RestQuality,
@@ -498,12 +585,30 @@ impl Default for NervousSystemConfig {
                 ],
             },
             thinking_interval: 60,
+            alarmed_thinking_interval: 15,
+            arousal_threshold: 0.5,
             perception_interval: 10,
         }
     }
 }
 
 impl NervousSystemConfig {
+    /// Thinking interval for an agent in the given emotional state.
+    /// Interpolates from `thinking_interval` at `arousal_threshold` down to
+    /// `alarmed_thinking_interval` at full arousal, so stressed or
+    /// frightened agents re-evaluate their urgencies and replan sooner.
+    pub fn effective_thinking_interval(&self, emotions: &EmotionalState) -> u64 {
+        let arousal = (emotions.stress_level / 100.0)
+            .max(emotions.get_emotion_intensity(EmotionType::Fear))
+            .clamp(0.0, 1.0);
+        let t = ((arousal - self.arousal_threshold)
+            / (1.0 - self.arousal_threshold).max(f32::EPSILON))
+        .clamp(0.0, 1.0);
+        let base = self.thinking_interval as f32;
+        let alarmed = self.alarmed_thinking_interval.min(self.thinking_interval) as f32;
+        ((base + (alarmed - base) * t).round() as u64).max(1)
+    }
+
     /// Get config for a specific drive source
     pub fn get_drive(&self, source: UrgencySource) -> Option<&DriveConfig> {
         self.drives.iter().find(|d| d.source == source)
@@ -514,6 +619,40 @@ impl NervousSystemConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn alarmed_agent_thinks_more_often_than_calm_one() {
+        let config = NervousSystemConfig::default();
+        let calm = EmotionalState::default();
+        let alarmed = EmotionalState {
+            stress_level: 100.0,
+            ..Default::default()
+        };
+
+        let calm_interval = config.effective_thinking_interval(&calm);
+        let alarmed_interval = config.effective_thinking_interval(&alarmed);
+        assert_eq!(calm_interval, config.thinking_interval);
+        assert_eq!(alarmed_interval, config.alarmed_thinking_interval);
+
+        // Thinking cycles over ten game-minutes, gated the way the urgency
+        // system gates them.
+        let cycles = |interval: u64| (0..600u64).filter(|t| t % interval == 0).count();
+        assert!(cycles(alarmed_interval) > cycles(calm_interval) * 3);
+    }
+
+    #[test]
+    fn neglect_boost_ramps_after_grace_and_caps() {
+        let boost = NeglectBoost {
+            grace_ticks: 100,
+            ramp_ticks: 200,
+            max_boost: 0.5,
+        };
+        assert_eq!(boost.multiplier(0), 1.0);
+        assert_eq!(boost.multiplier(100), 1.0);
+        assert!((boost.multiplier(200) - 1.25).abs() < 1e-6);
+        assert!((boost.multiplier(300) - 1.5).abs() < 1e-6);
+        assert!((boost.multiplier(10_000) - 1.5).abs() < 1e-6);
+    }
+
     /// A drive curve must satisfy the contract: zero input → zero output.
     /// Otherwise the drive carries a phantom baseline urgency that can win
     /// arbitration when no real stimulus is present (see #175).
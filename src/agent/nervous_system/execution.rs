@@ -1,6 +1,6 @@
 //! Parallel action execution - ticks every running action independently.
 //!
-//! Reads: BrainState (chosen actions), PhysicalNeeds, Inventory, WorldMap, Body, Skills, Phenotype
+//! Reads: BrainState (chosen actions), PhysicalNeeds, Inventory, WorldMap, PathfindingConfig, Body, Skills, Phenotype
 //! Writes: ActiveActions, PhysicalNeeds, Inventory, TargetPosition, ActionOutcomeEvent, SimEvent
 //! Upstream: brains::arbitration (BrainState), actions::registry (Action definitions)
 //! Downstream: mind::belief_updater (ActionOutcomeEvent), ui (GameLog), SimEvent consumers
@@ -9,8 +9,8 @@ use crate::agent::TargetPosition;
 use crate::agent::actions::ActionType;
 use crate::agent::actions::channel::ChannelCapacities;
 use crate::agent::actions::registry::{
-    ActionContext, ActionKind, ActionRegistry, ActionState, ActiveActions, LegCompleteContext,
-    LegResult,
+    Action, ActionContext, ActionKind, ActionRegistry, ActionState, ActiveActions,
+    CompletionContext, LegCompleteContext, LegResult, NeedSnapshot,
 };
 use crate::agent::biology::body::{Body, TagChannelMapping};
 use crate::agent::body::genetics::phenotype::Phenotype;
@@ -24,13 +24,14 @@ use crate::agent::item_slots::ItemSlots;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Node};
 use crate::agent::mind::perception::VisibleObjects;
 use crate::agent::movement::{
-    ARRIVAL_THRESHOLD, MoveResult, calculate_speed, effective_intensity,
-    intensity_speed_multiplier, move_toward,
+    ARRIVAL_THRESHOLD, MoveResult, MoveSettleConfig, calculate_speed, effective_intensity,
+    follows_route, intensity_speed_multiplier, move_toward, route_waypoints, settle_move,
 };
 use crate::core::SimRng;
 use crate::core::tick::TickCount;
 use crate::ui::hud::GameLog;
 use crate::world::map::{TILE_SIZE, WorldMap};
+use crate::world::pathfinding::PathfindingConfig;
 use bevy::prelude::*;
 use rand::Rng;
 
@@ -50,6 +51,7 @@ pub fn start_actions(
     registry: Res<ActionRegistry>,
     tick: Res<TickCount>,
     world_map: Res<WorldMap>,
+    pathfinding: Res<PathfindingConfig>,
     social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
     world_positions: Res<crate::world::entity_positions::WorldEntityPositions>,
     mut sim_rng: ResMut<SimRng>,
@@ -70,6 +72,7 @@ pub fn start_actions(
             Option<&crate::agent::body::needs::PsychologicalDrives>,
             Option<&crate::agent::psyche::emotions::EmotionalState>,
             Option<&Consciousness>,
+            Option<&crate::agent::skills::Skills>,
         ),
         Option<&PlanMemory>,
         Option<&VisibleObjects>,
@@ -90,7 +93,7 @@ pub fn start_actions(
         mind,
         explored,
         inventory,
-        (body, physical, drives, emotional, consciousness),
+        (body, physical, drives, emotional, consciousness, skills),
         plan_memory,
         visible,
         flee_momentum,
@@ -150,6 +153,7 @@ pub fn start_actions(
                 emotional,
                 current_tick: tick.current,
                 unreachable_tiles: &unreachable_tiles,
+                skills,
             };
 
             // Defense-in-depth satiation gate. Survival brain pre-filters
@@ -386,6 +390,9 @@ pub fn start_actions(
                 if let Some(tp) = new_target {
                     new_state = new_state.with_target_position(tp);
                     target.0 = Some(tp);
+                    if follows_route(wanted_action) {
+                        new_state.path = route_waypoints(&world_map, pos, tp, &pathfinding);
+                    }
                 }
             }
 
@@ -419,6 +426,23 @@ pub fn start_actions(
     }
 }
 
+/// Point a movement action at its next leg, routing around terrain for
+/// the action types that follow waypoints.
+fn retarget_leg(
+    state: &mut ActionState,
+    from: Vec2,
+    next: Vec2,
+    map: &WorldMap,
+    config: &PathfindingConfig,
+) {
+    state.target_position = Some(next);
+    state.path = if follows_route(state.action_type) {
+        route_waypoints(map, from, next, config)
+    } else {
+        Vec::new()
+    };
+}
+
 /// Tick every running action independently.
 pub fn tick_actions(
     mut commands: Commands,
@@ -454,7 +478,13 @@ pub fn tick_actions(
     mut target_inventories: Query<&mut ItemSlots, Without<crate::agent::Alive>>,
     living_entities: Query<()>,
     mapping: Res<TagChannelMapping>,
+    movement_env: (
+        Res<MoveSettleConfig>,
+        Res<PathfindingConfig>,
+        Res<crate::world::weather::Weather>,
+    ),
 ) {
+    let (settle_config, pathfinding, weather) = movement_env;
     let current_tick = tick.current;
 
     for (
@@ -535,6 +565,30 @@ pub fn tick_actions(
                             action_state.progress_accumulator -= 1.0;
                             action_state.ticks_remaining -= 1;
                         }
+                        if action_def.accrues_effects() {
+                            let mut target_inv = action_state
+                                .target_entity
+                                .and_then(|e| target_inventories.get_mut(e).ok());
+                            let mut spawn_requests = Vec::new();
+                            let mut ctx = CompletionContext {
+                                physical: &mut physical,
+                                inventory: &mut inventory,
+                                drives: drives.as_deref_mut(),
+                                mind,
+                                skills,
+                                target_inventory: target_inv.as_deref_mut(),
+                                target_entity: action_state.target_entity,
+                                tick: current_tick,
+                                agent_position: transform.translation.truncate(),
+                                spawn_requests: &mut spawn_requests,
+                            };
+                            accrue_timed_effects(
+                                action_def,
+                                action_state,
+                                duration_ticks,
+                                &mut ctx,
+                            );
+                        }
                         action_state.ticks_remaining == 0
                     }
                 }
@@ -542,10 +596,11 @@ pub fn tick_actions(
                     None => true,
                     Some(target_position) => {
                         let current_pos = transform.translation.truncate();
-                        if current_pos.distance(target_position) < ARRIVAL_THRESHOLD {
+                        if world_map.distance(current_pos, target_position) < ARRIVAL_THRESHOLD {
                             // Snap to exact target so perceived tile matches Walk effect.
-                            transform.translation.x = target_position.x;
-                            transform.translation.y = target_position.y;
+                            let snapped = world_map.wrap_position(target_position);
+                            transform.translation.x = snapped.x;
+                            transform.translation.y = snapped.y;
                             // Ask the action what to do now that this leg is done.
                             let rng = sim_rng.inner_mut();
                             let leg_ctx = LegCompleteContext {
@@ -564,7 +619,13 @@ pub fn tick_actions(
                             let mut leg_ctx = leg_ctx;
                             match action_def.on_leg_complete(&mut leg_ctx) {
                                 LegResult::NextLeg(next) => {
-                                    action_state.target_position = Some(next);
+                                    retarget_leg(
+                                        action_state,
+                                        current_pos,
+                                        next,
+                                        &world_map,
+                                        &pathfinding,
+                                    );
                                     target_pos.0 = Some(next);
                                     false
                                 }
@@ -583,7 +644,13 @@ pub fn tick_actions(
                                             rng2,
                                         ) {
                                             Some(next) => {
-                                                action_state.target_position = Some(next);
+                                                retarget_leg(
+                                                    action_state,
+                                                    current_pos,
+                                                    next,
+                                                    &world_map,
+                                                    &pathfinding,
+                                                );
                                                 target_pos.0 = Some(next);
                                                 false
                                             }
@@ -619,24 +686,55 @@ pub fn tick_actions(
                                 // or slower individuals deviate from the species baseline.
                                 let species_speed = species.map(|s| s.base_speed).unwrap_or(1.0);
                                 let genetic_speed = phenotype.map(|p| p.speed).unwrap_or(1.0);
-                                let speed = calculate_speed(physical.stamina.aerobic, None)
-                                    * species_speed
-                                    * genetic_speed
-                                    * degradation
-                                    * intensity_mult;
+                                let speed =
+                                    calculate_speed(physical.stamina.aerobic, None, *weather)
+                                        * species_speed
+                                        * genetic_speed
+                                        * degradation
+                                        * intensity_mult;
 
                                 // Stamina and energy drain now route through the
                                 // effort model in `apply_action_effects` via
                                 // `compute_action_cost`. The effective intensity
                                 // is stored on the ActionState and read there.
 
-                                match move_toward(
+                                // Steer at the next waypoint while a route
+                                // remains, popping each one on arrival.
+                                let waypoint = action_state.path.first().copied();
+                                let mut step = move_toward(
                                     current_pos,
-                                    target_position,
+                                    waypoint.unwrap_or(target_position),
                                     speed,
                                     ticks,
                                     &world_map,
                                     &mut transform,
+                                );
+                                if waypoint.is_some() && step == MoveResult::Arrived {
+                                    action_state.path.remove(0);
+                                    step = MoveResult::Moving;
+                                }
+                                // Terrain changed under the route (or the
+                                // straight line was never clear): search
+                                // again before giving up on the move.
+                                if step == MoveResult::Blocked && follows_route(action_type) {
+                                    let fresh = route_waypoints(
+                                        &world_map,
+                                        current_pos,
+                                        target_position,
+                                        &pathfinding,
+                                    );
+                                    if !fresh.is_empty() && fresh != action_state.path {
+                                        action_state.path = fresh;
+                                        step = MoveResult::Moving;
+                                    }
+                                }
+                                let distance = world_map
+                                    .distance(transform.translation.truncate(), target_position);
+                                match settle_move(
+                                    step,
+                                    distance,
+                                    &mut action_state.settle,
+                                    &settle_config,
                                 ) {
                                     MoveResult::Moving => false,
                                     MoveResult::Arrived => {
@@ -660,7 +758,13 @@ pub fn tick_actions(
                                         let mut leg_ctx = leg_ctx;
                                         match action_def.on_leg_complete(&mut leg_ctx) {
                                             LegResult::NextLeg(next) => {
-                                                action_state.target_position = Some(next);
+                                                retarget_leg(
+                                                    action_state,
+                                                    arrived_pos,
+                                                    next,
+                                                    &world_map,
+                                                    &pathfinding,
+                                                );
                                                 target_pos.0 = Some(next);
                                                 false
                                             }
@@ -674,8 +778,13 @@ pub fn tick_actions(
                                                         rng2,
                                                     ) {
                                                         Some(next) => {
-                                                            action_state.target_position =
-                                                                Some(next);
+                                                            retarget_leg(
+                                                                action_state,
+                                                                arrived_pos,
+                                                                next,
+                                                                &world_map,
+                                                                &pathfinding,
+                                                            );
                                                             target_pos.0 = Some(next);
                                                             false
                                                         }
@@ -732,9 +841,13 @@ pub fn tick_actions(
             // Hunger is derived from the metabolism pools rather than a raw
             // field, so we snapshot the urgency (0..1) as "pre_hunger" on a
             // 0..100 scale to preserve the outcome event semantics.
-            let pre_hunger = physical.metabolism.hunger_urgency() * 100.0;
-            let pre_thirst = physical.hydration.deficit() * 100.0;
-            let pre_aerobic = physical.stamina.aerobic;
+            // Actions that accrued their effect tick by tick snapshotted
+            // before their first share instead.
+            let pre = snapshot
+                .accrual
+                .baseline
+                .unwrap_or_else(|| NeedSnapshot::of(&physical));
+            let (pre_hunger, pre_thirst, pre_aerobic) = (pre.hunger, pre.thirst, pre.aerobic);
             // Snapshot inventory-total so we can detect Harvest/Take
             // completions that yielded nothing (target was empty). Before
             // #416 this was invisible: Harvest's on_complete silently
@@ -815,6 +928,18 @@ pub fn tick_actions(
                         }
                         commands.entity(entity).insert(becomes);
                     }
+                    SpawnRequest::GroundItem { position, things } => {
+                        crate::world::ground_item::spawn_ground_item(
+                            &mut commands,
+                            position,
+                            things,
+                        );
+                    }
+                    SpawnRequest::Handover { recipient, things } => {
+                        commands.queue(move |world: &mut World| {
+                            hand_over(world, entity, recipient, things);
+                        });
+                    }
                 }
             }
 
@@ -973,11 +1098,64 @@ pub fn tick_actions(
     }
 }
 
+/// Land the share of an accruing Timed action's effect earned since the
+/// last call: the countdown's progress (`1 - ticks_remaining / duration`)
+/// minus what was already applied. No-op for actions that apply
+/// everything in `on_complete`, and for indefinite durations.
+pub(crate) fn accrue_timed_effects(
+    action_def: &dyn Action,
+    state: &mut ActionState,
+    duration_ticks: u32,
+    ctx: &mut CompletionContext,
+) {
+    if !action_def.accrues_effects() || duration_ticks == 0 || duration_ticks == u32::MAX {
+        return;
+    }
+    let done = 1.0 - state.ticks_remaining as f32 / duration_ticks as f32;
+    if done <= state.accrual.applied {
+        return;
+    }
+    state
+        .accrual
+        .baseline
+        .get_or_insert_with(|| NeedSnapshot::of(ctx.physical));
+    action_def.on_progress(ctx, &mut state.accrual, done);
+    state.accrual.applied = done;
+}
+
+/// Apply a [`SpawnRequest::Handover`](crate::agent::actions::registry::SpawnRequest::Handover):
+/// deposit `things` into `recipient`, handing back to `giver` whatever the
+/// recipient can't hold — or everything, if the recipient is gone.
+fn hand_over(
+    world: &mut World,
+    giver: Entity,
+    recipient: Entity,
+    things: Vec<crate::agent::item_slots::Thing>,
+) {
+    let mut rejected = Vec::new();
+    match world.get_mut::<ItemSlots>(recipient) {
+        Some(mut slots) => {
+            for thing in things {
+                if !slots.deposit_thing(thing.clone(), None) {
+                    rejected.push(thing);
+                }
+            }
+        }
+        None => rejected = things,
+    }
+    if let Some(mut slots) = world.get_mut::<ItemSlots>(giver) {
+        for thing in rejected {
+            slots.add_thing(thing);
+        }
+    }
+}
+
 /// Per-tick stat drain summed across every running action.
 ///
 /// Physical costs (stamina, energy) are derived from each action's
 /// `EffortProfile` via `compute_action_cost`. Behavioural side effects
 /// (alertness, ingestion, social, curiosity) come from `RuntimeEffects`.
+/// Activity cost, rest recovery and grazing are scaled by `NeedsConfig`.
 pub fn apply_action_effects(
     registry: Res<ActionRegistry>,
     tick: Res<TickCount>,
@@ -992,6 +1170,7 @@ pub fn apply_action_effects(
         Option<&crate::agent::body::genetics::phenotype::Phenotype>,
     )>,
     mapping: Res<TagChannelMapping>,
+    needs: Res<crate::agent::nervous_system::metabolism::NeedsConfig>,
 ) {
     use crate::agent::body::effort::{self, DEFAULT_BODY_MASS, compute_action_cost};
     use crate::agent::movement::effective_intensity as cap_intensity;
@@ -1052,16 +1231,34 @@ pub fn apply_action_effects(
 
             let cost = compute_action_cost(&profile, body_mass, lung_condition);
 
-            physical
-                .stamina
-                .adjust_aerobic(-cost.aerobic_drain * dt * degradation * bmr_mult);
+            // Negative drain is recovery (Rest, Sleep); NeedsConfig scales
+            // the two directions separately.
+            let stamina_scale = |drain: f32| {
+                if drain < 0.0 {
+                    needs.rest_recovery_scale
+                } else {
+                    needs.activity_drain_scale
+                }
+            };
+            physical.stamina.adjust_aerobic(
+                -cost.aerobic_drain
+                    * stamina_scale(cost.aerobic_drain)
+                    * dt
+                    * degradation
+                    * bmr_mult,
+            );
             physical.stamina.anaerobic = (physical.stamina.anaerobic
-                - cost.anaerobic_drain * dt * degradation * bmr_mult)
+                - cost.anaerobic_drain
+                    * stamina_scale(cost.anaerobic_drain)
+                    * dt
+                    * degradation
+                    * bmr_mult)
                 .clamp(0.0, physical.stamina.anaerobic_max);
 
             // Energy cost: split between glucose and reserves via fuel
             // partitioning (intensity-keyed, reserves-availability-aware).
-            let energy_drain = cost.energy * dt * degradation * bmr_mult;
+            let energy_drain =
+                cost.energy * needs.activity_drain_scale * dt * degradation * bmr_mult;
             if energy_drain != 0.0 {
                 let gluc_frac = effort::effective_glucose_fraction(
                     profile.peak_intensity(),
@@ -1085,7 +1282,8 @@ pub fn apply_action_effects(
             }
 
             // --- Ingestion side effect (Graze) ---
-            let carbs_fill = effects.stomach_carbs_per_sec * dt * degradation;
+            let carbs_fill =
+                effects.stomach_carbs_per_sec * needs.eating_rate_scale * dt * degradation;
             if carbs_fill > 0.0 {
                 physical
                     .metabolism
@@ -1654,6 +1852,72 @@ mod tests {
         ActionRegistry::new()
     }
 
+    #[test]
+    fn interrupted_eat_gives_a_share_of_the_meal_proportional_to_ticks_elapsed() {
+        use crate::agent::body::metabolism::{Metabolism, food_macros};
+        use crate::agent::mind::knowledge::setup_ontology;
+        use crate::constants::actions::eat::DURATION_TICKS;
+
+        let registry = build_registry();
+        let eat = registry.get(ActionType::Eat).unwrap();
+        assert!(eat.accrues_effects());
+        let mind = MindGraph::new(setup_ontology());
+        let berry = food_macros(Concept::Berry).unwrap().total_mass();
+
+        // Eat one berry for `ticks` of its duration, then get interrupted.
+        // Returns (stomach mass gained, hunger relief, berries left).
+        let eat_for = |ticks: u32| {
+            let mut physical = PhysicalNeeds::default();
+            physical.metabolism = Metabolism {
+                stomach_carbs: 0.0,
+                stomach_fat: 0.0,
+                glucose: 5.0,
+                reserves: 10.0,
+            };
+            let hunger_before = physical.metabolism.hunger_urgency();
+            let mut inventory = ItemSlots::agent_carry();
+            inventory.add(Concept::Berry, 1);
+            let mut state = ActionState::new(ActionType::Eat, 0).with_duration(DURATION_TICKS);
+            let mut spawn_requests = Vec::new();
+            for _ in 0..ticks {
+                state.ticks_remaining -= 1;
+                let mut ctx = CompletionContext {
+                    physical: &mut physical,
+                    inventory: &mut inventory,
+                    drives: None,
+                    mind: &mind,
+                    skills: None,
+                    target_inventory: None,
+                    target_entity: None,
+                    tick: 0,
+                    agent_position: Vec2::ZERO,
+                    spawn_requests: &mut spawn_requests,
+                };
+                accrue_timed_effects(eat, &mut state, DURATION_TICKS, &mut ctx);
+            }
+            let stomach = physical.metabolism.stomach_carbs + physical.metabolism.stomach_fat;
+            let relief = hunger_before - physical.metabolism.hunger_urgency();
+            (stomach, relief, inventory.count(Concept::Berry))
+        };
+
+        let (full, full_relief, _) = eat_for(DURATION_TICKS);
+        assert!((full - berry).abs() < 1e-3, "a whole Eat is a whole berry");
+
+        let quarter_ticks = DURATION_TICKS / 4;
+        let (partial, partial_relief, left) = eat_for(quarter_ticks);
+        let share = quarter_ticks as f32 / DURATION_TICKS as f32;
+        assert_eq!(left, 0, "the first bite takes the berry out of the pouch");
+        assert!(
+            (partial - berry * share).abs() < 1e-3,
+            "{quarter_ticks} of {DURATION_TICKS} ticks should eat {share} of the berry, ate {partial} of {berry}"
+        );
+        assert!(partial_relief > 0.0);
+        assert!(
+            (partial_relief - full_relief * share).abs() < 1e-4,
+            "hunger relief {partial_relief} should be {share} of {full_relief}"
+        );
+    }
+
     #[test]
     fn walk_and_eat_run_in_parallel() {
         let registry = build_registry();
@@ -2,10 +2,14 @@
 //! metabolism update that runs for every living agent independent of what
 //! they are doing.
 //!
-//! Reads: PhysicalNeeds, Consciousness, Body, Phenotype, TickCount
-//! Writes: PhysicalNeeds (metabolism pools, aerobic/anaerobic recovery)
-//! Upstream: core::tick (TickCount)
+//! Reads: PhysicalNeeds, Consciousness, Body, Phenotype, TickCount, LodLevel, LodClock, NeedsConfig
+//! Writes: PhysicalNeeds (metabolism pools, aerobic/anaerobic recovery), DeferredNeeds
+//! Upstream: core::tick (TickCount), lod::assign_lod_levels
 //! Downstream: nervous_system::urgency (reads updated needs to recalculate urgencies)
+//!
+//! [`NeedsConfig`] holds the baseline rates — how fast an idle agent gets
+//! hungry and thirsty, and how strongly activity, rest and eating move the
+//! pools — so survival difficulty can be balanced from the Resources tab.
 
 use crate::agent::Alive;
 use crate::agent::biology::body::Body;
@@ -15,28 +19,81 @@ use crate::agent::body::metabolism::{
     HYDRATION_SLEEP_FLOOR, sleep_drain_multiplier,
 };
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds};
+use crate::agent::lod::{DeferredNeeds, LodClock, LodLevel};
 use crate::core::TickCount;
 use bevy::prelude::*;
 
+/// Baseline need rates. Defaults match the body model's constants; the
+/// scales multiply the per-action rates in `execution::apply_action_effects`.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct NeedsConfig {
+    /// Glucose an idle, awake agent burns per second (hunger gain).
+    pub glucose_drain_per_sec: f32,
+    /// Hydration an idle, awake agent loses per second (thirst gain).
+    pub hydration_drain_per_sec: f32,
+    /// Anaerobic stamina refilled per cycle whatever the agent is doing.
+    pub anaerobic_refill_per_cycle: f32,
+    /// Multiplier on the energy and stamina actions cost.
+    pub activity_drain_scale: f32,
+    /// Multiplier on the stamina resting and sleeping restore.
+    pub rest_recovery_scale: f32,
+    /// Multiplier on how fast grazing fills the stomach.
+    pub eating_rate_scale: f32,
+}
+
+impl Default for NeedsConfig {
+    fn default() -> Self {
+        Self {
+            glucose_drain_per_sec: BMR_GLUCOSE_DRAIN_PER_SEC,
+            hydration_drain_per_sec: BMR_HYDRATION_DRAIN_PER_SEC,
+            anaerobic_refill_per_cycle: 0.02,
+            activity_drain_scale: 1.0,
+            rest_recovery_scale: 1.0,
+            eating_rate_scale: 1.0,
+        }
+    }
+}
+
 /// Per-tick metabolism update for **every** agent with `PhysicalNeeds`.
 ///
 /// Runs the basal drain (BMR scaled by consciousness and phenotype),
 /// digests the stomach, and does a slow anaerobic passive refill so a
 /// Flee sprint doesn't leave the pool stuck at 0.
+///
+/// Reduced-LOD agents bank their time in `DeferredNeeds` and apply it in
+/// one batch when their turn comes round.
 pub fn tick_metabolism(
     tick: Res<TickCount>,
+    lod_clock: Res<LodClock>,
+    needs: Res<NeedsConfig>,
     mut query: Query<
         (
+            Entity,
             &mut PhysicalNeeds,
             &Consciousness,
             Option<&Body>,
             Option<&Phenotype>,
+            Option<&LodLevel>,
+            Option<&mut DeferredNeeds>,
         ),
         With<Alive>,
     >,
 ) {
-    let dt = tick.dt();
-    for (mut physical, consciousness, body, phenotype) in query.iter_mut() {
+    for (entity, mut physical, consciousness, body, phenotype, lod, deferred) in query.iter_mut() {
+        let (dt, cycles) = match deferred {
+            Some(mut deferred) => {
+                deferred.dt += tick.dt();
+                deferred.cycles += 1;
+                if !lod_clock.detail_due(entity, lod) {
+                    continue;
+                }
+                let owed = (deferred.dt, deferred.cycles);
+                *deferred = DeferredNeeds::default();
+                owed
+            }
+            None => (tick.dt(), 1),
+        };
         let mut organ_mods = body.map(Body::organ_mods).unwrap_or_default();
         let digestion_mult = phenotype.map(|p| p.digestion).unwrap_or(1.0);
         organ_mods.stomach *= digestion_mult;
@@ -46,7 +103,7 @@ pub fn tick_metabolism(
             sleep_drain_multiplier(GLUCOSE_SLEEP_FLOOR, consciousness.alertness);
         physical.metabolism.tick_with_mods(
             dt,
-            BMR_GLUCOSE_DRAIN_PER_SEC * bmr_mult * glucose_sleep_mult,
+            needs.glucose_drain_per_sec * bmr_mult * glucose_sleep_mult,
             0.0,
             organ_mods,
         );
@@ -55,7 +112,7 @@ pub fn tick_metabolism(
             sleep_drain_multiplier(HYDRATION_SLEEP_FLOOR, consciousness.alertness);
         physical
             .hydration
-            .drain(BMR_HYDRATION_DRAIN_PER_SEC * hydration_sleep_mult * dt);
+            .drain(needs.hydration_drain_per_sec * hydration_sleep_mult * dt);
 
         // Slow passive anaerobic refill so a Flee sprint doesn't leave
         // the pool stuck at 0 forever. The rate is low enough that the
@@ -63,7 +120,55 @@ pub fn tick_metabolism(
         // propose Rest/Sleep — removing the signal entirely made agents
         // skip every fatigue cycle and burn surplus glucose into
         // early starvation.
-        physical.stamina.anaerobic =
-            (physical.stamina.anaerobic + 0.02).min(physical.stamina.anaerobic_max);
+        physical.stamina.anaerobic = (physical.stamina.anaerobic
+            + needs.anaerobic_refill_per_cycle * cycles as f32)
+            .min(physical.stamina.anaerobic_max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// Hunger urgency of an idle agent with an empty stomach after
+    /// `cycles` of metabolism under `needs`.
+    fn hunger_after(needs: NeedsConfig, cycles: usize) -> f32 {
+        let mut world = World::new();
+        world.insert_resource(TickCount::default());
+        world.init_resource::<LodClock>();
+        world.insert_resource(needs);
+        let mut physical = PhysicalNeeds::default();
+        physical.metabolism.stomach_carbs = 0.0;
+        physical.metabolism.stomach_fat = 0.0;
+        physical.metabolism.glucose = 60.0;
+        let agent = world
+            .spawn((Alive, physical, Consciousness::default()))
+            .id();
+        for _ in 0..cycles {
+            world.run_system_once(tick_metabolism).unwrap();
+        }
+        world
+            .get::<PhysicalNeeds>(agent)
+            .unwrap()
+            .metabolism
+            .hunger_urgency()
+    }
+
+    #[test]
+    fn raising_the_baseline_hunger_rate_makes_idle_agents_hungry_faster() {
+        let baseline = NeedsConfig::default();
+        let harsh = NeedsConfig {
+            glucose_drain_per_sec: baseline.glucose_drain_per_sec * 10.0,
+            ..baseline.clone()
+        };
+
+        let normal = hunger_after(baseline, 600);
+        let hungry = hunger_after(harsh, 600);
+
+        assert!(
+            hungry > normal,
+            "10x basal burn should leave the agent hungrier ({hungry} vs {normal})"
+        );
     }
 }
@@ -20,9 +20,14 @@ impl Plugin for NervousSystemPlugin {
             .register_type::<Goal>()
             .register_type::<TriplePattern>()
             .register_type::<ActionTemplate>()
+            // Registered so the Resources tab can tune it live.
+            .register_type::<config::NervousSystemConfig>()
             .init_resource::<config::NervousSystemConfig>()
+            .register_type::<metabolism::NeedsConfig>()
+            .init_resource::<metabolism::NeedsConfig>()
             .init_resource::<crate::agent::brains::planner::PlannerConfig>()
             .init_resource::<crate::agent::mind::memory::MemoryDecayConfig>()
+            .init_resource::<crate::agent::mind::memory::SalienceConfig>()
             .insert_resource({
                 let mut channels = other_regarding::OtherRegardingChannels::default();
                 other_regarding::register_default_channels(&mut channels);
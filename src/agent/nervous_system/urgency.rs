@@ -1,6 +1,6 @@
 //! Urgency generation: maps physical/emotional state to drive urgencies.
 //!
-//! Reads: PhysicalNeeds, Consciousness, PsychologicalDrives, EmotionalState, Body, ActiveActions
+//! Reads: PhysicalNeeds (incl. last_ate/last_slept ticks), Consciousness, PsychologicalDrives, EmotionalState, Body, ActiveActions, DayCycle (sleepiness damped by daylight)
 //! Writes: CentralNervousSystem.urgencies
 //! Upstream: body (needs), psyche (emotions), nervous_system::config
 //! Downstream: nervous_system::cns (urgency ranking)
@@ -59,6 +59,12 @@ pub enum UrgencySource {
     /// in follow-ups once perceived-injury and perceived-hunger
     /// channels land.
     Compassion,
+    /// Pull to hold still and heal. Reads the larger of body pain and the
+    /// severity of wounds not yet healed, so it lingers after the sting
+    /// fades. Unlike Pain (a reflex freeze), the survival brain only acts
+    /// on it when no threat is in view — it then proposes Sit, and a
+    /// resting stance speeds `process_healing`.
+    Recovery,
 }
 
 impl UrgencySource {
@@ -136,12 +142,21 @@ use crate::agent::body::needs::{Consciousness, PhysicalNeeds, PsychologicalDrive
 use crate::agent::nervous_system::cns::CentralNervousSystem;
 use crate::agent::psyche::emotions::EmotionalState;
 
+/// Tick the drive's satisfier last completed, for drives that track one.
+pub fn last_satisfied_tick(physical: &PhysicalNeeds, source: UrgencySource) -> Option<u64> {
+    match source {
+        UrgencySource::Hunger => Some(physical.last_ate_tick),
+        UrgencySource::Sleepiness => Some(physical.last_slept_tick),
+        _ => None,
+    }
+}
+
 /// Fully data-driven urgency generation
 /// Iterates over DriveConfigs and reads from Components directly
 pub fn generate_urgency(
     ns_config: Res<NervousSystemConfig>,
     tick: Res<crate::core::tick::TickCount>,
-    light: Res<crate::world::environment::LightLevel>,
+    day: Res<crate::world::environment::DayCycle>,
     channels: Res<crate::agent::nervous_system::other_regarding::OtherRegardingChannels>,
     social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
     mut query: Query<
@@ -182,7 +197,8 @@ pub fn generate_urgency(
         // been ticked here (fresh spawn, save reload). Run once immediately
         // so the brain's first arbitration sees populated urgencies instead
         // of idling for up to `thinking_interval` ticks.
-        if !cns.urgencies.is_empty() && !tick.should_run(entity, ns_config.thinking_interval) {
+        cns.thinking_interval = ns_config.effective_thinking_interval(emotions);
+        if !cns.urgencies.is_empty() && !tick.should_run(entity, cns.thinking_interval) {
             continue;
         }
 
@@ -214,6 +230,14 @@ pub fn generate_urgency(
                     let pain = body.map(|b| b.total_pain()).unwrap_or(0.0);
                     (pain / 100.0).clamp(0.0, 1.0)
                 }
+                // Deficit already: how much is still hurt or unhealed.
+                UrgencySource::Recovery => body
+                    .map(|b| {
+                        let pain = b.total_pain() / crate::constants::brains::recovery::PAIN_SCALE;
+                        pain.max(b.open_wound_severity())
+                    })
+                    .unwrap_or(0.0)
+                    .clamp(0.0, 1.0),
 
                 // Psychological drives are all satisfaction now — invert
                 // each to get "how much the agent wants more of X."
@@ -312,6 +336,14 @@ pub fn generate_urgency(
             let sensitivity = drive_config.sensitivity.compute(personality);
             let mut score = curved * sensitivity;
 
+            // 3b. Neglect boost: long stretches since the drive was last
+            //     satisfied raise urgency even at the same need level.
+            if let Some(neglect) = drive_config.neglect
+                && let Some(last) = last_satisfied_tick(physical, drive_config.source)
+            {
+                score *= neglect.multiplier(tick.current.saturating_sub(last));
+            }
+
             // 4. Apply context modifiers
             for modifier in &drive_config.modifiers {
                 // Modifiers also read from Sources now
@@ -337,17 +369,16 @@ pub fn generate_urgency(
             }
 
             // 5. Circadian dampening for Sleepiness. At full daylight
-            //    (light = 1.0) the score is multiplied by `1 -
+            //    (daylight = 1.0) the score is multiplied by `1 -
             //    SLEEPINESS_DAYLIGHT_DAMPEN` (= 0.5 by default), so a
             //    rested-but-tiring agent doesn't fall asleep mid-harvest
-            //    at noon. At full darkness (light = 0.3) the dampening
+            //    at noon. At full darkness (daylight = 0.0) the dampening
             //    vanishes and the full sigmoid score fires. Anchors
             //    sleep to the late-night window in concert with the
             //    sigmoid midpoint at 0.85 and the stronger
             //    `ADENOSINE_RATE` / slower `SLEEP_RESTORE_RATE`.
             if drive_config.source == UrgencySource::Sleepiness {
-                let normalized_light = ((light.0 - 0.3) / 0.7).clamp(0.0, 1.0);
-                let dampen = normalized_light
+                let dampen = day.daylight
                     * crate::constants::brains::wakefulness::SLEEPINESS_DAYLIGHT_DAMPEN;
                 score *= 1.0 - dampen;
             }
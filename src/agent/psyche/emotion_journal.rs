@@ -0,0 +1,137 @@
+//! Emotion journal: optional per-agent time series of felt emotions for offline analysis.
+//!
+//! Reads: EmotionJournalConfig, EmotionalState, TickCount
+//! Writes: EmotionJournal (bounded ring of EmotionSample)
+//! Upstream: psyche::emotions (EmotionalState after decay and event reactions)
+//! Downstream: external plotting via `EmotionJournal::to_csv`
+//!
+//! Off by default. When enabled, every `interval` ticks each agent's active
+//! emotions are sampled as `(tick, EmotionType, intensity)` rows. The
+//! journal is inserted on first sample and keeps at most `capacity` rows,
+//! dropping the oldest first, so long runs stay bounded.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::emotions::{EmotionType, EmotionalState};
+use crate::core::tick::TickCount;
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct EmotionJournalConfig {
+    pub enabled: bool,
+    /// Ticks between samples.
+    pub interval: u64,
+    /// Samples kept per agent.
+    pub capacity: usize,
+}
+
+impl Default for EmotionJournalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: 60,
+            capacity: 4096,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmotionSample {
+    pub tick: u64,
+    pub emotion: EmotionType,
+    pub intensity: f32,
+}
+
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct EmotionJournal {
+    #[reflect(ignore)]
+    samples: VecDeque<EmotionSample>,
+}
+
+impl EmotionJournal {
+    /// Append every active emotion in `state`, evicting the oldest rows
+    /// past `capacity`.
+    pub fn record(&mut self, tick: u64, state: &EmotionalState, capacity: usize) {
+        for emotion in &state.active_emotions {
+            self.samples.push_back(EmotionSample {
+                tick,
+                emotion: emotion.emotion_type,
+                intensity: emotion.intensity,
+            });
+        }
+        while self.samples.len() > capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &EmotionSample> {
+        self.samples.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// `tick,emotion,intensity` header followed by one row per sample.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("tick,emotion,intensity\n");
+        for sample in &self.samples {
+            csv.push_str(&format!(
+                "{},{:?},{:.4}\n",
+                sample.tick, sample.emotion, sample.intensity
+            ));
+        }
+        csv
+    }
+}
+
+/// Bevy system (FixedUpdate, after emotion updates): samples each agent's
+/// emotions into its `EmotionJournal` every `interval` ticks while enabled.
+pub fn record_emotion_journal(
+    mut commands: Commands,
+    config: Res<EmotionJournalConfig>,
+    tick: Res<TickCount>,
+    mut agents: Query<(Entity, &EmotionalState, Option<&mut EmotionJournal>)>,
+) {
+    if !config.enabled || !tick.current.is_multiple_of(config.interval.max(1)) {
+        return;
+    }
+    for (entity, state, journal) in agents.iter_mut() {
+        match journal {
+            Some(mut journal) => journal.record(tick.current, state, config.capacity),
+            None => {
+                let mut journal = EmotionJournal::default();
+                journal.record(tick.current, state, config.capacity);
+                commands.entity(entity).insert(journal);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::psyche::emotions::Emotion;
+
+    #[test]
+    fn journal_drops_oldest_samples_past_capacity() {
+        let mut state = EmotionalState::default();
+        state.add_emotion(Emotion::new(EmotionType::Joy, 0.5));
+        let mut journal = EmotionJournal::default();
+
+        for tick in 0..5 {
+            journal.record(tick, &state, 3);
+        }
+
+        let ticks: Vec<u64> = journal.iter().map(|s| s.tick).collect();
+        assert_eq!(ticks, vec![2, 3, 4]);
+        assert_eq!(journal.to_csv().lines().nth(1), Some("2,Joy,0.5000"));
+    }
+}
@@ -1,15 +1,16 @@
 //! Emotional state: active emotions, mood, stress, and event-driven emotion triggers.
 //!
-//! Reads: GameEvent, PhysicalNeeds, Body, Personality, MindGraph, TickCount
+//! Reads: GameEvent, PhysicalNeeds, Body, Personality, MindGraph, TickCount, Weather, VisibleObjects + AffectiveToM (moods of the people in view), SocialGraph
 //! Writes: EmotionalState, SimEvent
-//! Upstream: events (GameEvent), nervous_system::urgency (stress inputs)
+//! Upstream: events (GameEvent), nervous_system::urgency (stress inputs), biology::body (pain), mind::affective_tom (perceived moods)
 //! Downstream: brains::arbitration (mood/stress influence), nervous_system::urgency
 
 use crate::agent::actions::ActionType;
 use crate::agent::events::SimEventKind;
+use crate::world::weather::{DayPhase, Weather};
 use bevy::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, serde::Serialize, serde::Deserialize)]
 pub enum EmotionType {
     Joy,
     Sadness,
@@ -24,6 +25,9 @@ pub struct Emotion {
     pub emotion_type: EmotionType,
     pub intensity: f32, // 0.0 to 1.0 - Current felt strength (used for mood)
     pub fuel: f32,      // 0.0+ - Accumulated reservoir (determines duration)
+    /// 0.0 to `EmotionConfig::max_numbing`. Share of each new trigger of
+    /// this type that is ignored; builds while the emotion stays saturated.
+    pub numbing: f32,
 }
 
 impl Emotion {
@@ -33,6 +37,7 @@ impl Emotion {
             emotion_type,
             intensity,
             fuel: intensity,
+            numbing: 0.0,
         }
     }
 }
@@ -51,6 +56,46 @@ pub struct EmotionConfig {
     pub stress_emotion_weight: f32,
     pub stress_recovery_bonus: f32,
     pub stress_decay_base: f32,
+    /// Mood offsets from the sky and time of day, added to the mood
+    /// target in `update_mood`. Sunshine lifts, storms and darkness weigh.
+    /// `storm_mood` is for an average-neuroticism agent; see
+    /// [`environment_mood`].
+    pub clear_sky_mood: f32,
+    pub overcast_mood: f32,
+    pub rain_mood: f32,
+    pub storm_mood: f32,
+    pub daylight_mood: f32,
+    pub night_mood: f32,
+    /// Most fuel one emotion can hold, so repeated triggers can't stretch
+    /// it out indefinitely. Enforced on each decay step.
+    pub fuel_cap: f32,
+    /// Fraction of `fuel_cap` above which an emotion counts as saturated
+    /// and starts numbing the agent to more of the same.
+    pub numbing_onset: f32,
+    /// Numbing gained per second while saturated, and lost per second
+    /// otherwise.
+    pub numbing_rate: f32,
+    pub numbing_recovery: f32,
+    /// Ceiling on numbing: triggers always land at least `1 - max_numbing`.
+    pub max_numbing: f32,
+    /// Rise in `Body::total_pain` within one tick that counts as a fresh
+    /// injury and triggers a burst of fear.
+    pub pain_spike_threshold: f32,
+    /// Fear (and, for disagreeable agents, anger) per unit of that rise,
+    /// capped at `pain_emotion_max`.
+    pub pain_fear_per_unit: f32,
+    pub pain_anger_per_unit: f32,
+    pub pain_emotion_max: f32,
+    /// Agents with agreeableness below this also lash out in anger when hurt.
+    pub pain_anger_max_agreeableness: f32,
+    /// Per-second pull of an agent's mood toward the perceived moods of
+    /// the people in view, for an average-neuroticism observer in full
+    /// company. Competes with `update_mood`'s own pull toward the
+    /// agent's inner target, so a crowd shifts mood without owning it.
+    pub contagion_rate: f32,
+    /// How much a stranger's mood counts next to a close friend's (1.0).
+    /// Affection scales the weight between this floor and 1.0.
+    pub contagion_stranger_weight: f32,
 }
 
 impl Default for EmotionConfig {
@@ -66,6 +111,24 @@ impl Default for EmotionConfig {
             stress_emotion_weight: 0.15,
             stress_recovery_bonus: 2.0,
             stress_decay_base: 0.5,
+            clear_sky_mood: 0.05,
+            overcast_mood: -0.05,
+            rain_mood: -0.1,
+            storm_mood: -0.25,
+            daylight_mood: 0.05,
+            night_mood: -0.05,
+            fuel_cap: 4.0,
+            numbing_onset: 0.75,
+            numbing_rate: 0.1,
+            numbing_recovery: 0.05,
+            max_numbing: 0.8,
+            pain_spike_threshold: 1.0,
+            pain_fear_per_unit: 0.15,
+            pain_anger_per_unit: 0.1,
+            pain_emotion_max: 0.8,
+            pain_anger_max_agreeableness: 0.4,
+            contagion_rate: 0.3,
+            contagion_stranger_weight: 0.25,
         }
     }
 }
@@ -76,6 +139,9 @@ pub struct EmotionalState {
     pub current_mood: f32, // -1.0 (Depressed) to 1.0 (Ecstatic)
     pub stress_level: f32, // 0.0 to 100.0
     pub active_emotions: Vec<Emotion>,
+    /// `Body::total_pain` at the end of the last tick, so `react_to_pain`
+    /// can tell a fresh injury from pain that was already there.
+    pub last_pain: f32,
 }
 
 impl EmotionalState {
@@ -85,7 +151,7 @@ impl EmotionalState {
             .iter_mut()
             .find(|e| e.emotion_type == new_emotion.emotion_type)
         {
-            existing.fuel += new_emotion.intensity;
+            existing.fuel += new_emotion.intensity * (1.0 - existing.numbing);
             existing.intensity = existing.fuel.min(1.0);
         } else {
             let mut emotion = new_emotion;
@@ -130,11 +196,19 @@ impl EmotionalState {
             .map(|e| e.emotion_type)
     }
 
-    /// Advance emotion decay by `dt` seconds. Each emotion's fuel drains at a
-    /// rate driven by `EmotionConfig`, with intensity tracking fuel directly.
-    /// Emotions whose fuel falls below the removal threshold are dropped.
+    /// Advance emotion decay by `dt` seconds. Fuel is first clamped to
+    /// `fuel_cap`, then drains at a rate driven by `EmotionConfig`, with
+    /// intensity tracking fuel directly. Numbing builds while the emotion
+    /// sits near the cap and wears off below it. Emotions whose fuel falls
+    /// below the removal threshold are dropped.
     pub fn decay_tick(&mut self, dt: f32, config: &EmotionConfig) {
         self.active_emotions.retain_mut(|e| {
+            e.fuel = e.fuel.min(config.fuel_cap);
+            if e.fuel >= config.fuel_cap * config.numbing_onset {
+                e.numbing = (e.numbing + config.numbing_rate * dt).min(config.max_numbing);
+            } else {
+                e.numbing = (e.numbing - config.numbing_recovery * dt).max(0.0);
+            }
             let decay_rate = config.decay_base_rate + (e.fuel * config.decay_fuel_factor).min(0.1);
             e.fuel -= decay_rate * dt;
             e.fuel = e.fuel.max(0.0);
@@ -296,6 +370,29 @@ pub fn compute_target_mood(
     (mood_sum / weight_sum).clamp(-1.0, 1.0)
 }
 
+/// Mood offset contributed by the environment: the current `Weather` plus
+/// the `DayPhase`. Dawn and dusk are neutral. A storm weighs on anxious
+/// agents more: neuroticism scales it from 0.5× to 1.5×.
+pub fn environment_mood(
+    weather: Weather,
+    phase: DayPhase,
+    neuroticism: f32,
+    config: &EmotionConfig,
+) -> f32 {
+    let sky = match weather {
+        Weather::Clear => config.clear_sky_mood,
+        Weather::Overcast => config.overcast_mood,
+        Weather::Rain => config.rain_mood,
+        Weather::Storm => config.storm_mood * (0.5 + neuroticism),
+    };
+    let light = match phase {
+        DayPhase::Day => config.daylight_mood,
+        DayPhase::Night => config.night_mood,
+        DayPhase::Dawn | DayPhase::Dusk => 0.0,
+    };
+    sky + light
+}
+
 pub fn update_mood(
     mut agents: Query<
         (
@@ -306,16 +403,91 @@ pub fn update_mood(
         With<crate::agent::Agent>,
     >,
     tick: Res<crate::core::tick::TickCount>,
+    weather: Res<Weather>,
+    config: Res<EmotionConfig>,
 ) {
     let dt = tick.dt();
+    let phase = DayPhase::at_tick(tick.current);
 
     for (mut emotional_state, personality, body) in agents.iter_mut() {
-        let target_mood = compute_target_mood(&emotional_state, personality, body);
+        let ambient = environment_mood(*weather, phase, personality.traits.neuroticism(), &config);
+        let target_mood =
+            (compute_target_mood(&emotional_state, personality, body) + ambient).clamp(-1.0, 1.0);
         emotional_state.current_mood += (target_mood - emotional_state.current_mood) * dt * 0.5;
         emotional_state.current_mood = emotional_state.current_mood.clamp(-1.0, 1.0);
     }
 }
 
+/// Mood change from emotional contagion over `dt` seconds. `perceived`
+/// yields `(mood, weight)` for each agent in view, weight being read
+/// confidence times closeness. The pull aims at the weighted mean mood,
+/// with strength saturating at a total weight of 1.0 — a lone glimpse of
+/// a stranger barely registers, a close crowd pulls fully. Neuroticism
+/// scales susceptibility around 1.0 at the average of 0.5.
+pub fn contagion_delta(
+    current_mood: f32,
+    perceived: impl IntoIterator<Item = (f32, f32)>,
+    neuroticism: f32,
+    config: &EmotionConfig,
+    dt: f32,
+) -> f32 {
+    let (mut mood_sum, mut weight_sum) = (0.0f32, 0.0f32);
+    for (mood, weight) in perceived {
+        mood_sum += mood * weight;
+        weight_sum += weight;
+    }
+    if weight_sum <= 0.0 {
+        return 0.0;
+    }
+    let crowd_mood = mood_sum / weight_sum;
+    let susceptibility = 0.5 + neuroticism;
+    (crowd_mood - current_mood) * config.contagion_rate * weight_sum.min(1.0) * susceptibility * dt
+}
+
+/// Emotional contagion: nudge each agent's mood toward the moods it sees
+/// on the people around it. Reads the observer's own `AffectiveToM`
+/// records rather than the others' `EmotionalState`, so only what was
+/// actually perceived — at the confidence it was read — spreads. Closer
+/// friends (SocialGraph affection) and neurotic observers feel it more.
+pub fn emotional_contagion(
+    mut agents: Query<
+        (
+            Entity,
+            &mut EmotionalState,
+            &crate::agent::psyche::personality::Personality,
+            &crate::agent::mind::perception::VisibleObjects,
+            &crate::agent::mind::affective_tom::AffectiveToM,
+        ),
+        With<crate::agent::Agent>,
+    >,
+    social_graph: Res<crate::agent::psyche::social_graph::SocialGraph>,
+    tick: Res<crate::core::tick::TickCount>,
+    config: Res<EmotionConfig>,
+) {
+    let dt = tick.dt();
+    let now = tick.current;
+
+    for (observer, mut emotional_state, personality, visible, tom) in agents.iter_mut() {
+        let perceived = visible.entities.iter().filter_map(|&other| {
+            let seen = tom.perceived_mood(other)?;
+            let affection = social_graph
+                .get(observer, other)
+                .map_or(0.0, |e| e.affection);
+            let closeness = config.contagion_stranger_weight
+                + (1.0 - config.contagion_stranger_weight) * affection.clamp(0.0, 1.0);
+            Some((seen.mood, seen.confidence_at(now) * closeness))
+        });
+        let delta = contagion_delta(
+            emotional_state.current_mood,
+            perceived,
+            personality.traits.neuroticism(),
+            &config,
+            dt,
+        );
+        emotional_state.current_mood = (emotional_state.current_mood + delta).clamp(-1.0, 1.0);
+    }
+}
+
 /// Compute stress accumulation rate (per second) from current conditions and personality.
 ///
 /// Personality scaling:
@@ -551,6 +723,55 @@ pub fn react_to_events(
 /// is now seen, not felt-only. SimEvent reader and writer share one
 /// ParamSet because Bevy's system-param checker rejects a plain reader
 /// + writer pair against the same message type.
+/// Bevy system (FixedUpdate): a sudden rise in pain — a fresh injury from
+/// any source — is frightening, and makes disagreeable agents angry. Pain
+/// that was already there only feeds stress and mood (`update_stress`,
+/// `compute_target_mood`).
+pub fn react_to_pain(
+    mut agents: Query<
+        (
+            Entity,
+            &mut EmotionalState,
+            &crate::agent::biology::body::Body,
+            Option<&crate::agent::psyche::personality::Personality>,
+        ),
+        With<crate::agent::Agent>,
+    >,
+    config: Res<EmotionConfig>,
+    tick: Res<crate::core::tick::TickCount>,
+    mut sim_events: MessageWriter<crate::agent::events::SimEvent>,
+) {
+    for (entity, mut state, body, personality) in agents.iter_mut() {
+        let pain = body.total_pain();
+        let rise = pain - state.last_pain;
+        state.last_pain = pain;
+        if rise < config.pain_spike_threshold {
+            continue;
+        }
+
+        let fear = (rise * config.pain_fear_per_unit).min(config.pain_emotion_max);
+        add_emotion_with_event(
+            &mut state,
+            &mut sim_events,
+            entity,
+            tick.current,
+            Emotion::new(EmotionType::Fear, fear),
+        );
+        let disagreeable = personality
+            .is_some_and(|p| p.traits.agreeableness() < config.pain_anger_max_agreeableness);
+        if disagreeable {
+            let anger = (rise * config.pain_anger_per_unit).min(config.pain_emotion_max);
+            add_emotion_with_event(
+                &mut state,
+                &mut sim_events,
+                entity,
+                tick.current,
+                Emotion::new(EmotionType::Anger, anger),
+            );
+        }
+    }
+}
+
 pub fn react_to_combat_hit(
     mut sim_events: ParamSet<(
         MessageReader<crate::agent::events::SimEvent>,
@@ -694,6 +915,41 @@ pub fn react_to_combat_hit(
 mod tests {
     use super::*;
 
+    #[test]
+    fn fearful_crowd_makes_a_calm_bystander_uneasy() {
+        let config = EmotionConfig::default();
+        let crowd = [(-0.6, 0.5), (-0.7, 0.5), (-0.5, 0.5)];
+
+        let mut mood = 0.0;
+        for _ in 0..600 {
+            mood += contagion_delta(mood, crowd, 0.5, &config, 0.1);
+        }
+        assert!(
+            mood < -0.3,
+            "a minute among the frightened should sour mood, got {mood}"
+        );
+        assert!(
+            mood > -0.7,
+            "contagion pulls toward the crowd, not past it, got {mood}"
+        );
+    }
+
+    #[test]
+    fn friends_and_neurotic_observers_catch_moods_faster() {
+        let config = EmotionConfig::default();
+        let stranger = [(-0.8, config.contagion_stranger_weight)];
+        let friend = [(-0.8, 1.0)];
+
+        let from_stranger = contagion_delta(0.0, stranger, 0.5, &config, 1.0);
+        let from_friend = contagion_delta(0.0, friend, 0.5, &config, 1.0);
+        assert!(from_friend < from_stranger && from_stranger < 0.0);
+
+        let stable = contagion_delta(0.0, friend, 0.1, &config, 1.0);
+        let neurotic = contagion_delta(0.0, friend, 0.9, &config, 1.0);
+        assert!(neurotic < stable, "neurotic={neurotic} stable={stable}");
+        assert_eq!(contagion_delta(0.3, [], 0.5, &config, 1.0), 0.0);
+    }
+
     #[test]
     fn emotion_intensity_decreases_after_decay_tick() {
         let config = EmotionConfig::default();
@@ -759,6 +1015,7 @@ mod tests {
             emotion_type: EmotionType::Anger,
             intensity: 1.0,
             fuel: 5.0,
+            numbing: 0.0,
         });
 
         let low_before = low.active_emotions[0].fuel;
@@ -776,6 +1033,93 @@ mod tests {
         );
     }
 
+    fn seconds_to_fade(state: &mut EmotionalState, config: &EmotionConfig) -> f32 {
+        let mut seconds = 0.0;
+        while state.get_emotion_intensity(EmotionType::Fear) > 0.0 {
+            state.decay_tick(0.1, config);
+            seconds += 0.1;
+        }
+        seconds
+    }
+
+    /// Terror kept up for 30 seconds, one strong trigger every decay step.
+    fn flooded_with_fear(config: &EmotionConfig) -> EmotionalState {
+        let mut state = EmotionalState::default();
+        for _ in 0..300 {
+            state.add_emotion(Emotion::new(EmotionType::Fear, 1.0));
+            state.decay_tick(0.1, config);
+            assert!(state.active_emotions[0].fuel <= config.fuel_cap);
+        }
+        state
+    }
+
+    #[test]
+    fn storms_weigh_hardest_on_neurotic_agents() {
+        let config = EmotionConfig::default();
+        let storm = |n: f32| environment_mood(Weather::Storm, DayPhase::Day, n, &config);
+        assert!(storm(0.9) < storm(0.5) && storm(0.5) < storm(0.1));
+        assert!(
+            storm(0.1) < 0.0,
+            "even the steadiest agent dislikes a storm"
+        );
+
+        let rain = environment_mood(Weather::Rain, DayPhase::Day, 0.5, &config);
+        let clear = environment_mood(Weather::Clear, DayPhase::Day, 0.5, &config);
+        assert!(storm(0.5) < rain && rain < clear);
+    }
+
+    #[test]
+    fn repeated_fear_stops_extending_its_duration_at_the_cap() {
+        let config = EmotionConfig::default();
+
+        let mut at_cap = EmotionalState::default();
+        while at_cap
+            .active_emotions
+            .first()
+            .is_none_or(|e| e.fuel < config.fuel_cap)
+        {
+            at_cap.add_emotion(Emotion::new(EmotionType::Fear, 1.0));
+        }
+        let cap_duration = seconds_to_fade(&mut at_cap, &config);
+
+        let flooded_duration = seconds_to_fade(&mut flooded_with_fear(&config), &config);
+        assert!(
+            (flooded_duration - cap_duration).abs() < 1.0,
+            "300 triggers should fade like a full reservoir ({flooded_duration}s vs {cap_duration}s)"
+        );
+
+        let uncapped = EmotionConfig {
+            fuel_cap: f32::INFINITY,
+            ..EmotionConfig::default()
+        };
+        let uncapped_duration = seconds_to_fade(&mut flooded_with_fear(&uncapped), &uncapped);
+        assert!(
+            uncapped_duration > cap_duration * 2.0,
+            "without a cap the same triggers should last far longer ({uncapped_duration}s vs {cap_duration}s)"
+        );
+    }
+
+    #[test]
+    fn sustained_saturation_numbs_further_triggers() {
+        let config = EmotionConfig::default();
+        let mut state = flooded_with_fear(&config);
+        let numbing = state.active_emotions[0].numbing;
+        assert!((numbing - config.max_numbing).abs() < 1e-5);
+
+        state.drain_emotion(EmotionType::Fear, config.fuel_cap - 1.0);
+        let before = state.active_emotions[0].fuel;
+        state.add_emotion(Emotion::new(EmotionType::Fear, 1.0));
+        let gained = state.active_emotions[0].fuel - before;
+        assert!(
+            (gained - (1.0 - config.max_numbing)).abs() < 1e-5,
+            "a numbed agent should feel only part of a new scare, gained {gained}"
+        );
+
+        // Below the onset, sensitivity comes back.
+        state.decay_tick(5.0, &config);
+        assert!(state.active_emotions[0].numbing < numbing);
+    }
+
     // ── compute_target_mood / emotion_valence tests ──────────────────────────
 
     fn personality_with(
@@ -0,0 +1,66 @@
+//! Gifts — the social and perceptual aftermath of a completed Give.
+//!
+//! Reads: SimEvent (ActionCompleted for Give), ItemSlots, TickCount
+//! Writes: GameEvent (SocialInteraction), MindGraph (Contains beliefs about the other party)
+//! Upstream: execution (Give completion; the handover lands before this runs)
+//! Downstream: relationships (consumes SocialInteraction), memory, emotions
+//!
+//! A gift is an exchange both parties watch happen: the giver sees the
+//! items arrive in the recipient's hands and the recipient sees what the
+//! giver has left, so each re-perceives the other's inventory at full
+//! confidence. The recipient also warms to the giver through a friendly
+//! `SocialInteraction`.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::actions::ActionType;
+use crate::agent::events::{GameEvent, SimEvent, SimEventKind};
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::MindGraph;
+use crate::agent::mind::perception::perceive_inventory;
+use crate::constants::actions::give::VALENCE;
+use crate::core::tick::TickCount;
+
+pub fn acknowledge_gifts(
+    tick: Res<TickCount>,
+    mut sim_events: MessageReader<SimEvent>,
+    inventories: Query<&ItemSlots, With<Agent>>,
+    mut minds: Query<&mut MindGraph, With<Agent>>,
+    mut game_events: MessageWriter<GameEvent>,
+) {
+    for event in sim_events.read() {
+        let SimEventKind::ActionCompleted {
+            agent: giver,
+            action: ActionType::Give,
+            target: Some(recipient),
+        } = event.kind
+        else {
+            continue;
+        };
+
+        for (observer, observed) in [(giver, recipient), (recipient, giver)] {
+            if let (Ok(inventory), Ok(mut mind)) =
+                (inventories.get(observed), minds.get_mut(observer))
+            {
+                perceive_inventory(
+                    observed,
+                    inventory,
+                    &[],
+                    &mut mind,
+                    tick.current,
+                    1.0,
+                    false,
+                );
+            }
+        }
+
+        game_events.write(GameEvent::SocialInteraction {
+            actor: giver,
+            target: recipient,
+            action: ActionType::Give,
+            topic: None,
+            valence: VALENCE,
+        });
+    }
+}
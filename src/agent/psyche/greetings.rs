@@ -1,15 +1,17 @@
 //! Social acknowledgments — lightweight passing greetings between agents.
 //!
-//! Reads: VisibleObjects, MindGraph, PsychologicalDrives, EmotionalState, Engaged
+//! Reads: VisibleObjects, SocialGraph, PsychologicalDrives, EmotionalState, Engaged
 //! Writes: PsychologicalDrives (companionship bump), GameEvent (SocialInteraction), SimEvent (SocialAcknowledgment)
 //! Upstream: perception (VisibleObjects), recognition (relationship initialization)
 //! Downstream: relationships (consumes SocialInteraction), flocking (reads companionship)
 //!
-//! When two agents who know each other pass within perception range, this
-//! system fires a brief social acknowledgment — a nod, wave, or "hey!" —
-//! without stopping or entering the conversation state machine. This is the
-//! most common form of social contact in real life and gives agents a steady
-//! trickle of companionship satisfaction between full conversations.
+//! When two agents who know each other come back into perception range after
+//! a while apart, this system fires a brief social acknowledgment — a wave
+//! with `Intent::Greet` — without stopping or entering the conversation state
+//! machine. This is the most common form of social contact in real life and
+//! gives agents a steady trickle of companionship satisfaction and mild
+//! affection between full conversations. Agents who stay in sight of each
+//! other don't keep re-greeting; only a reunion re-arms the greeting.
 
 use bevy::prelude::*;
 
@@ -17,10 +19,12 @@ use crate::agent::Agent;
 use crate::agent::actions::ActionType;
 use crate::agent::body::needs::PsychologicalDrives;
 use crate::agent::engagement::Engaged;
+use crate::agent::engagement::converse::Intent;
 use crate::agent::events::{GameEvent, SimEvent, SimEventKind};
 use crate::agent::mind::perception::VisibleObjects;
 use crate::agent::psyche::emotions::EmotionalState;
 use crate::core::tick::TickCount;
+use crate::core::time::GameTime;
 
 /// Minimum affection to count as "knows this person well enough to greet."
 const FAMILIARITY_THRESHOLD: f32 = 0.1;
@@ -31,24 +35,49 @@ const ACQUAINTANCE_COMPANIONSHIP_BUMP: f32 = 0.003;
 /// Companionship bump for greeting a friend (affection > 0.5).
 const FRIEND_COMPANIONSHIP_BUMP: f32 = 0.008;
 
-/// Ticks between acknowledgments for the same pair. At 60 tps this is ~5
-/// seconds — you don't wave at someone you just waved at.
+/// Ticks between acknowledgments for the same pair — you don't wave at
+/// someone you just waved at.
 const GREETING_COOLDOWN_TICKS: u64 = 300;
 
-/// Base valence of a greeting interaction. Friendly but mild.
+/// How long an acquaintance must have been out of sight before seeing them
+/// again counts as a reunion worth greeting.
+const REUNION_GAP_TICKS: u64 = GameTime::TICKS_PER_MINUTE * 10;
+
+/// Base valence of a greeting interaction. Friendly but mild — feeds a
+/// small affection gain through `update_relationships`.
 const GREETING_VALENCE: f32 = 0.2;
 
 /// Run the greeting check every N ticks per agent, staggered. Greetings
 /// are not urgent — checking once per second is plenty.
 const CHECK_INTERVAL: u64 = 60;
 
-/// Tracks recent greetings to enforce per-pair cooldowns.
+/// Tracks recent greetings to enforce per-pair cooldowns, and when each
+/// agent last saw each acquaintance so only reunions trigger a greeting.
 #[derive(Resource, Default)]
 pub struct GreetingCooldowns {
     entries: Vec<(Entity, Entity, u64)>,
+    /// `(observer, other, tick)` — directed: Alice seeing Bob says nothing
+    /// about whether Bob has seen Alice.
+    last_seen: Vec<(Entity, Entity, u64)>,
 }
 
 impl GreetingCooldowns {
+    /// True if `observer` has not seen `other` within [`REUNION_GAP_TICKS`]
+    /// (including never having seen them at all).
+    fn was_apart(&self, observer: Entity, other: Entity, now: u64) -> bool {
+        self.last_seen
+            .iter()
+            .find(|&&(o, e, _)| o == observer && e == other)
+            .is_none_or(|&(_, _, tick)| now.saturating_sub(tick) >= REUNION_GAP_TICKS)
+    }
+
+    fn mark_seen(&mut self, observer: Entity, other: Entity, now: u64) {
+        self.last_seen.retain(|&(o, e, tick)| {
+            !(o == observer && e == other) && now.saturating_sub(tick) < REUNION_GAP_TICKS
+        });
+        self.last_seen.push((observer, other, now));
+    }
+
     fn is_on_cooldown(&self, a: Entity, b: Entity, now: u64) -> bool {
         self.entries.iter().any(|&(ea, eb, tick)| {
             ((ea == a && eb == b) || (ea == b && eb == a))
@@ -89,23 +118,28 @@ pub fn social_acknowledgments(
             continue;
         }
 
-        // Agents already mid-engagement (any kind) don't need passing greetings.
-        if engaged.is_some() {
-            continue;
-        }
-
         for &other in &visible.entities {
             if other == agent {
                 continue;
             }
 
-            if cooldowns.is_on_cooldown(agent, other, now) {
+            let Some(affection) = social_graph.get(agent, other).map(|e| e.affection) else {
+                continue;
+            };
+
+            // Track sightings even while engaged or on cooldown — time spent
+            // together is exactly what disarms the next greeting.
+            let reunited = cooldowns.was_apart(agent, other, now);
+            cooldowns.mark_seen(agent, other, now);
+
+            // Agents already mid-engagement (any kind) don't need passing greetings.
+            if !reunited || engaged.is_some() {
                 continue;
             }
 
-            let Some(affection) = social_graph.get(agent, other).map(|e| e.affection) else {
+            if cooldowns.is_on_cooldown(agent, other, now) {
                 continue;
-            };
+            }
 
             if affection < FAMILIARITY_THRESHOLD {
                 continue;
@@ -134,7 +168,7 @@ pub fn social_acknowledgments(
         game_events.write(GameEvent::SocialInteraction {
             actor,
             target,
-            action: ActionType::Idle,
+            action: ActionType::Wave,
             topic: None,
             valence: GREETING_VALENCE,
         });
@@ -143,7 +177,11 @@ pub fn social_acknowledgments(
             now,
             actor,
             target,
-            SimEventKind::SocialAcknowledgment { actor, target },
+            SimEventKind::SocialAcknowledgment {
+                actor,
+                target,
+                intent: Intent::Greet,
+            },
         ));
     }
 }
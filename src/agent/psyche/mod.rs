@@ -1,7 +1,9 @@
 pub mod aspiration_modulator;
 pub mod aspirations;
+pub mod emotion_journal;
 pub mod emotions;
 pub mod flocking;
+pub mod gifts;
 pub mod greetings;
 pub mod personality;
 pub mod relationships;
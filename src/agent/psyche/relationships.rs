@@ -2,7 +2,7 @@
 //! from `GameEvent::SocialInteraction` and decays edges on a slow tick.
 //!
 //! Reads: GameEvent, Personality, RelationshipConfig
-//! Writes: SocialGraph (canonical edges), RelationshipHistory (per-agent log),
+//! Writes: SocialGraph (canonical edges; warmth flows both ways), RelationshipHistory (per-agent log),
 //!         SocialIdentity (introductions), SimEvent::RelationshipChanged
 //! Upstream: events (SocialInteraction), psyche::social_graph (resource shape)
 //! Downstream: every reader of affection/trust/respect
@@ -79,6 +79,14 @@ pub struct RelationshipConfig {
     pub negative_affection_loss: f32,
     /// Respect gain when witnessing competence
     pub competence_respect_gain: f32,
+    /// Share of a positive interaction's trust/affection gain the actor
+    /// feels back toward the target. 1.0 keeps warmth symmetric; lower
+    /// values let it run one way. Negative interactions are never
+    /// reciprocated, so a betrayal can open a wide gap.
+    pub positive_reciprocity: f32,
+    /// Trust loss toward an informant whose hearsay first-hand
+    /// observation contradicts (see `belief_updater::assert_observed`)
+    pub hearsay_contradiction_trust_loss: f32,
     /// Half-life (in game days) for the weakest bonds — acquaintances.
     /// Short half-life → casual ties fade within a week.
     pub weak_bond_half_life_days: f32,
@@ -107,6 +115,8 @@ impl Default for RelationshipConfig {
             negative_trust_loss: 0.15, // 3x larger than positive - negativity bias!
             negative_affection_loss: 0.10,
             competence_respect_gain: 0.02,
+            positive_reciprocity: 1.0,
+            hearsay_contradiction_trust_loss: 0.15,
             // Decay timescales chosen so that, at the default 60-day game year:
             //   acquaintance (strength=0.1): ~4 day half-life → gone in a week
             //   close friend  (strength=1.0): ~60 day half-life → lasts a full year
@@ -178,61 +188,96 @@ pub fn update_relationships(
             );
         }
 
-        if !graph.knows(*target, *actor) {
-            graph.set(
-                *target,
-                *actor,
-                RelationshipEdge::with_baseline_affection(NEUTRAL, now),
-            );
-        }
-
-        let edge_before = graph
-            .get(*target, *actor)
-            .copied()
-            .unwrap_or_else(RelationshipEdge::default);
         let (trust_delta, affection_delta) =
             valence_to_deltas(*valence, *topic, &config, &personality.traits);
+        shift_edge(
+            &mut graph,
+            *target,
+            *actor,
+            (trust_delta, affection_delta),
+            now,
+            &mut sim_events,
+        );
 
-        let new_trust = (edge_before.trust + trust_delta).clamp(0.0, 1.0);
-        let new_affection = (edge_before.affection + affection_delta).clamp(0.0, 1.0);
-
-        if let Some(edge) = graph.get_mut(*target, *actor) {
-            edge.trust = new_trust;
-            edge.affection = new_affection;
-            edge.last_interaction_tick = now;
-        }
-
-        if (new_trust - edge_before.trust).abs() > f32::EPSILON {
-            sim_events.write(crate::agent::events::SimEvent::pair(
-                now,
-                *target,
+        // The actor warms back by the same event. Hostility isn't returned
+        // — whoever wronged someone doesn't think less of them for it.
+        if *valence > 0.0 && config.positive_reciprocity > 0.0 {
+            shift_edge(
+                &mut graph,
                 *actor,
-                SimEventKind::RelationshipChanged {
-                    agent: *target,
-                    other: *actor,
-                    dimension: crate::agent::events::RelationshipDimension::Trust,
-                    old_value: edge_before.trust,
-                    new_value: new_trust,
-                },
-            ));
-        }
-        if (new_affection - edge_before.affection).abs() > f32::EPSILON {
-            sim_events.write(crate::agent::events::SimEvent::pair(
-                now,
                 *target,
-                *actor,
-                SimEventKind::RelationshipChanged {
-                    agent: *target,
-                    other: *actor,
-                    dimension: crate::agent::events::RelationshipDimension::Affection,
-                    old_value: edge_before.affection,
-                    new_value: new_affection,
-                },
-            ));
+                (
+                    trust_delta * config.positive_reciprocity,
+                    affection_delta * config.positive_reciprocity,
+                ),
+                now,
+                &mut sim_events,
+            );
         }
     }
 }
 
+/// Move `observer`'s edge toward `other` by `(trust, affection)`,
+/// introducing them first if needed, and log each dimension that changed.
+fn shift_edge(
+    graph: &mut SocialGraph,
+    observer: Entity,
+    other: Entity,
+    (trust_delta, affection_delta): (f32, f32),
+    now: u64,
+    sim_events: &mut MessageWriter<crate::agent::events::SimEvent>,
+) {
+    if !graph.knows(observer, other) {
+        graph.set(
+            observer,
+            other,
+            RelationshipEdge::with_baseline_affection(NEUTRAL, now),
+        );
+    }
+
+    let edge_before = graph
+        .get(observer, other)
+        .copied()
+        .unwrap_or_else(RelationshipEdge::default);
+    let new_trust = (edge_before.trust + trust_delta).clamp(0.0, 1.0);
+    let new_affection = (edge_before.affection + affection_delta).clamp(0.0, 1.0);
+
+    if let Some(edge) = graph.get_mut(observer, other) {
+        edge.trust = new_trust;
+        edge.affection = new_affection;
+        edge.last_interaction_tick = now;
+    }
+
+    if (new_trust - edge_before.trust).abs() > f32::EPSILON {
+        sim_events.write(crate::agent::events::SimEvent::pair(
+            now,
+            observer,
+            other,
+            SimEventKind::RelationshipChanged {
+                agent: observer,
+                other,
+                dimension: crate::agent::events::RelationshipDimension::Trust,
+                old_value: edge_before.trust,
+                new_value: new_trust,
+            },
+        ));
+    }
+    if (new_affection - edge_before.affection).abs() > f32::EPSILON {
+        sim_events.write(crate::agent::events::SimEvent::pair(
+            now,
+            observer,
+            other,
+            SimEventKind::RelationshipChanged {
+                agent: observer,
+                other,
+                dimension: crate::agent::events::RelationshipDimension::Affection,
+                old_value: edge_before.affection,
+                new_value: new_affection,
+            },
+        ));
+    }
+}
+
 /// Convert raw `(valence, topic, personality)` into the per-step trust
 /// and affection deltas. Negative interactions hit harder than positive
 /// ones do (negativity bias), and the topic biases which dimension
@@ -405,4 +450,78 @@ mod tests {
             "fraction at neutral should be finite and non-negative, got {fraction}"
         );
     }
+
+    #[test]
+    fn positive_interactions_converge_both_sides_toward_mutual_warmth() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<SocialGraph>();
+        world.init_resource::<RelationshipConfig>();
+        world.init_resource::<TickCount>();
+        world.init_resource::<Messages<GameEvent>>();
+        world.init_resource::<Messages<crate::agent::events::SimEvent>>();
+        let alice = world
+            .spawn((Agent, Name::new("alice"), Personality::default()))
+            .id();
+        let bob = world
+            .spawn((Agent, Name::new("bob"), Personality::default()))
+            .id();
+
+        // Only alice is ever nice to bob.
+        for _ in 0..5 {
+            world
+                .resource_mut::<Messages<GameEvent>>()
+                .write(GameEvent::SocialInteraction {
+                    actor: alice,
+                    target: bob,
+                    action: crate::agent::actions::ActionType::Converse,
+                    topic: None,
+                    valence: 1.0,
+                });
+        }
+        world.run_system_once(update_relationships).unwrap();
+
+        let graph = world.resource::<SocialGraph>();
+        for (observer, other) in [(bob, alice), (alice, bob)] {
+            assert!(graph.trust(observer, other) > NEUTRAL);
+            assert!(graph.affection(observer, other) > NEUTRAL);
+        }
+        assert!(
+            (graph.trust(bob, alice) - graph.trust(alice, bob)).abs() < 1e-6,
+            "default reciprocity keeps warmth symmetric"
+        );
+    }
+
+    #[test]
+    fn betrayal_is_not_reciprocated() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<SocialGraph>();
+        world.init_resource::<RelationshipConfig>();
+        world.init_resource::<TickCount>();
+        world.init_resource::<Messages<GameEvent>>();
+        world.init_resource::<Messages<crate::agent::events::SimEvent>>();
+        let alice = world
+            .spawn((Agent, Name::new("alice"), Personality::default()))
+            .id();
+        let bob = world
+            .spawn((Agent, Name::new("bob"), Personality::default()))
+            .id();
+        world
+            .resource_mut::<Messages<GameEvent>>()
+            .write(GameEvent::SocialInteraction {
+                actor: alice,
+                target: bob,
+                action: crate::agent::actions::ActionType::Attack,
+                topic: None,
+                valence: -1.0,
+            });
+        world.run_system_once(update_relationships).unwrap();
+
+        let graph = world.resource::<SocialGraph>();
+        assert!(graph.trust(bob, alice) < NEUTRAL);
+        assert!(!graph.knows(alice, bob), "the betrayer's view is untouched");
+    }
 }
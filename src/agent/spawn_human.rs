@@ -1,8 +1,9 @@
 //! Shared logic-only spawn helper for human (Person) agents.
 //!
-//! Reads: Genome, Ontology, cultural knowledge triples
+//! Reads: Genome, Ontology, cultural knowledge triples, Archetype profile
 //! Writes: PersonCoreBundle, PersonPerceptionBundle, PersonBrainBundle
-//! Upstream: world::human::spawn_person (real game), testing::spawn::spawn_test_person (TestWorld)
+//! Upstream: world::human::spawn_person (real game), testing::spawn::spawn_test_person (TestWorld),
+//!           spawn_agent (scripted scenarios, from an `AgentSpec`)
 //! Downstream: brain pipeline (any system that queries Person logic components)
 //!
 //! Both spawn paths must produce identical brain-relevant components or
@@ -20,6 +21,7 @@ use bevy::prelude::*;
 
 use crate::agent::actions::ActiveActions;
 use crate::agent::affordance::Affordance;
+use crate::agent::archetype::Archetype;
 use crate::agent::body::genetics::genome::Genome;
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds, PsychologicalDrives};
 use crate::agent::body::species::SpeciesProfile;
@@ -27,6 +29,7 @@ use crate::agent::brains::history::BrainHistory;
 use crate::agent::brains::plan_memory::PlanMemory;
 use crate::agent::brains::proposal::BrainState;
 use crate::agent::brains::rational::RationalBrain;
+use crate::agent::culture::Culture;
 use crate::agent::inventory::EntityType;
 use crate::agent::item_slots::ItemSlots;
 use crate::agent::mind::knowledge::{Concept, MindGraph, Ontology, Triple};
@@ -57,6 +60,7 @@ pub struct PersonCoreBundle {
     pub movement_state: MovementState,
     pub inventory: ItemSlots,
     pub genome: Genome,
+    pub culture: Culture,
     pub personality: Personality,
     pub transform: Transform,
     pub global_transform: GlobalTransform,
@@ -105,12 +109,18 @@ pub struct PersonInit {
     pub genome: Genome,
     /// Initial physical needs (hunger, thirst, stamina, health).
     pub physical_needs: PhysicalNeeds,
+    /// The culture the agent is raised in. Groups agents for cultural drift.
+    pub culture: Culture,
     /// Cultural knowledge triples shared across an agent's culture. The
     /// real spawner sources this from `create_cultural_knowledge(culture)`;
     /// the test spawner uses the default culture.
     pub cultural_knowledge: Arc<Vec<Triple>>,
     /// Per-agent knowledge triples to assert after cultural knowledge.
     pub extra_knowledge: Vec<Triple>,
+    /// Optional designed starting profile. Skews the genome's personality
+    /// and seeds skills here; the spawner inserts the `Archetype` component
+    /// so its food memories are seeded on the first tick.
+    pub archetype: Option<Archetype>,
 }
 
 /// Adds innate biological knowledge all humans have regardless of culture.
@@ -181,6 +191,18 @@ pub fn build_person_logic(
     for triple in init.extra_knowledge {
         mind.assert(triple);
     }
+    // Pre-loaded knowledge can overlap the innate set; fold it down once
+    // up front instead of waiting for the first periodic sweep.
+    mind.compact();
+
+    let genome = match init.archetype {
+        Some(archetype) => archetype.skew_genome(&init.genome),
+        None => init.genome,
+    };
+    let skills = init
+        .archetype
+        .map(|archetype| archetype.starting_skills())
+        .unwrap_or_default();
 
     let core = PersonCoreBundle {
         name: Name::new(init.name),
@@ -193,7 +215,8 @@ pub fn build_person_logic(
         target_position: TargetPosition::default(),
         movement_state: MovementState::default(),
         inventory: ItemSlots::agent_carry(),
-        genome: init.genome,
+        genome,
+        culture: init.culture,
         personality: Personality::default(),
         transform: Transform::from_translation(init.position.extend(3.0)),
         global_transform: GlobalTransform::default(),
@@ -204,7 +227,10 @@ pub fn build_person_logic(
         mind,
         explored_tiles: crate::agent::mind::explored_tiles::ExploredTiles::default(),
         social_identity: crate::agent::mind::social_identity::SocialIdentity::default(),
-        vision: Vision { range: 100.0 },
+        vision: Vision {
+            range: 100.0,
+            fov_degrees: SpeciesProfile::human().fov_degrees,
+        },
         visible: VisibleObjects::default(),
         affective_tom: crate::agent::mind::affective_tom::AffectiveToM::default(),
     };
@@ -223,8 +249,271 @@ pub fn build_person_logic(
         brain_history: BrainHistory::default(),
         relationships: RelationshipHistory::default(),
         theory_of_mind: TheoryOfMind::default(),
-        skills: Skills::default(),
+        skills,
     };
 
     (core, perception, brain)
 }
+
+// ─── Scripted spawning ───────────────────────────────────────────────────
+
+/// Big Five scores (0..1, 0.5 = species average) requested for a spawned
+/// agent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BigFive {
+    pub openness: f32,
+    pub conscientiousness: f32,
+    pub extraversion: f32,
+    pub agreeableness: f32,
+    pub neuroticism: f32,
+}
+
+impl Default for BigFive {
+    fn default() -> Self {
+        Self {
+            openness: 0.5,
+            conscientiousness: 0.5,
+            extraversion: 0.5,
+            agreeableness: 0.5,
+            neuroticism: 0.5,
+        }
+    }
+}
+
+/// A fully-specified Person for scenario setup: where it stands, who it is,
+/// how its body is doing, what it carries, and what it already believes.
+/// Every field defaults to a plain, well-rested, empty-handed member of the
+/// default culture, so callers set only what the scenario is about.
+#[derive(Debug, Clone)]
+pub struct AgentSpec {
+    pub position: Vec2,
+    /// `None` draws the next name from `NameCounters`.
+    pub name: Option<String>,
+    pub genome: Genome,
+    pub needs: PhysicalNeeds,
+    pub culture: crate::agent::culture::Culture,
+    /// Carried items. The agent also starts believing it carries them.
+    pub inventory: Vec<(Concept, u32)>,
+    /// Asserted on top of innate and cultural knowledge.
+    pub beliefs: Vec<Triple>,
+    pub archetype: Option<Archetype>,
+}
+
+impl Default for AgentSpec {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            name: None,
+            genome: Genome::default(),
+            needs: PhysicalNeeds::full(),
+            culture: crate::agent::culture::Culture::default(),
+            inventory: Vec::new(),
+            beliefs: Vec::new(),
+            archetype: None,
+        }
+    }
+}
+
+impl AgentSpec {
+    pub fn at(position: Vec2) -> Self {
+        Self {
+            position,
+            ..Self::default()
+        }
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Rewrite the genome so the phenotype develops these personality
+    /// scores. Physical traits are kept. Scores saturate at the range
+    /// [`Genome::from_phenotype`] can represent (roughly 0.25..0.75).
+    pub fn with_personality(mut self, scores: BigFive) -> Self {
+        use crate::agent::body::genetics::phenotype::Phenotype;
+
+        let mut phenotype = Phenotype::from_genome(&self.genome);
+        phenotype.openness = scores.openness.clamp(0.0, 1.0);
+        phenotype.conscientiousness = scores.conscientiousness.clamp(0.0, 1.0);
+        phenotype.extraversion = scores.extraversion.clamp(0.0, 1.0);
+        phenotype.agreeableness = scores.agreeableness.clamp(0.0, 1.0);
+        phenotype.neuroticism = scores.neuroticism.clamp(0.0, 1.0);
+        self.genome = Genome::from_phenotype(&phenotype);
+        self
+    }
+
+    pub fn with_genome(mut self, genome: Genome) -> Self {
+        self.genome = genome;
+        self
+    }
+
+    pub fn with_needs(mut self, needs: PhysicalNeeds) -> Self {
+        self.needs = needs;
+        self
+    }
+
+    pub fn with_culture(mut self, culture: crate::agent::culture::Culture) -> Self {
+        self.culture = culture;
+        self
+    }
+
+    pub fn with_item(mut self, concept: Concept, quantity: u32) -> Self {
+        self.inventory.push((concept, quantity));
+        self
+    }
+
+    pub fn with_belief(mut self, triple: Triple) -> Self {
+        self.beliefs.push(triple);
+        self
+    }
+
+    pub fn with_beliefs(mut self, triples: impl IntoIterator<Item = Triple>) -> Self {
+        self.beliefs.extend(triples);
+        self
+    }
+
+    pub fn with_archetype(mut self, archetype: Archetype) -> Self {
+        self.archetype = Some(archetype);
+        self
+    }
+}
+
+/// Spawn a logic-only Person (no sprite, no name tag) from `spec`. The id
+/// is reserved immediately; components land when the commands flush, so
+/// the shared `Ontology` and `NameCounters` are read at that point.
+pub fn spawn_agent(commands: &mut Commands, spec: AgentSpec) -> Entity {
+    let entity = commands.spawn_empty().id();
+    commands.queue(move |world: &mut World| build_spec_agent(world, entity, spec));
+    entity
+}
+
+fn build_spec_agent(world: &mut World, entity: Entity, spec: AgentSpec) {
+    let ontology = world
+        .get_resource::<Ontology>()
+        .cloned()
+        .unwrap_or_else(crate::agent::mind::knowledge::setup_ontology);
+    let name = spec.name.unwrap_or_else(|| {
+        world
+            .get_resource_mut::<crate::agent::naming::NameCounters>()
+            .map_or_else(|| "Person".to_string(), |mut names| names.next_human())
+    });
+
+    let (mut core, mut perception, brain) = build_person_logic(
+        PersonInit {
+            name,
+            position: spec.position,
+            genome: spec.genome,
+            physical_needs: spec.needs,
+            culture: spec.culture,
+            cultural_knowledge: Arc::new(crate::agent::culture::create_cultural_knowledge(
+                spec.culture,
+            )),
+            extra_knowledge: spec.beliefs,
+            archetype: spec.archetype,
+        },
+        ontology,
+    );
+    for (concept, quantity) in spec.inventory {
+        core.inventory.add(concept, quantity);
+        let count = core.inventory.count(concept);
+        perception.mind.perceive_self(
+            crate::agent::mind::knowledge::Predicate::Contains,
+            crate::agent::mind::knowledge::Value::Item(concept, count),
+            0,
+        );
+    }
+
+    let Ok(mut agent) = world.get_entity_mut(entity) else {
+        return;
+    };
+    // Body is pre-inserted for the same reason as the test spawner: so the
+    // first brain pass sees it without waiting for `setup_biology`.
+    agent
+        .insert(core)
+        .insert(perception)
+        .insert(brain)
+        .insert(crate::agent::biology::body::Body::human());
+    if let Some(archetype) = spec.archetype {
+        agent.insert(archetype);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::body::genetics::phenotype::Phenotype;
+    use crate::agent::mind::knowledge::{Metadata, Node, Predicate, Value, setup_ontology};
+
+    #[test]
+    fn spawn_agent_applies_every_field_of_the_spec() {
+        let mut world = World::new();
+        world.insert_resource(setup_ontology());
+        world.insert_resource(crate::agent::naming::NameCounters::default());
+        let friend = world.spawn_empty().id();
+
+        let scores = BigFive {
+            openness: 0.55,
+            conscientiousness: 0.4,
+            extraversion: 0.3,
+            agreeableness: 0.6,
+            neuroticism: 0.65,
+        };
+        let spec = AgentSpec::at(Vec2::new(120.0, 80.0))
+            .named("Ada")
+            .with_personality(scores)
+            .with_needs(PhysicalNeeds::full().with_hydration(0.25))
+            .with_item(Concept::Berry, 3)
+            .with_item(Concept::Stick, 1)
+            .with_belief(Triple::with_meta(
+                Node::Entity(friend),
+                Predicate::LocatedAt,
+                Value::Tile((4, 9)),
+                Metadata::semantic(0),
+            ))
+            .with_archetype(Archetype::Forager);
+        let agent = spawn_agent(&mut world.commands(), spec);
+        world.flush();
+
+        let entity = world.entity(agent);
+        assert_eq!(entity.get::<Name>().unwrap().as_str(), "Ada");
+        assert!(entity.contains::<Person>());
+        assert_eq!(
+            entity.get::<Transform>().unwrap().translation.truncate(),
+            Vec2::new(120.0, 80.0)
+        );
+        assert!((entity.get::<PhysicalNeeds>().unwrap().hydration.value - 0.25).abs() < 1e-6);
+
+        // Personality is developed from the genome; the forager skew lands
+        // on top of the requested openness.
+        let phenotype = Phenotype::from_genome(entity.get::<Genome>().unwrap());
+        let skew = Archetype::Forager.profile().personality;
+        assert!((phenotype.openness - (scores.openness + skew.openness)).abs() < 0.05);
+        assert!((phenotype.extraversion - scores.extraversion).abs() < 0.05);
+        assert!((phenotype.neuroticism - scores.neuroticism).abs() < 0.05);
+        assert_eq!(entity.get::<Archetype>(), Some(&Archetype::Forager));
+
+        let slots = entity.get::<ItemSlots>().unwrap();
+        assert_eq!(slots.count(Concept::Berry), 3);
+        assert_eq!(slots.count(Concept::Stick), 1);
+
+        let mind = entity.get::<MindGraph>().unwrap();
+        assert_eq!(mind.count_of(&Node::Self_, Concept::Berry), 3);
+        assert_eq!(
+            mind.get(&Node::Entity(friend), Predicate::LocatedAt),
+            Some(&Value::Tile((4, 9)))
+        );
+    }
+
+    #[test]
+    fn unnamed_specs_draw_distinct_names() {
+        let mut world = World::new();
+        world.insert_resource(crate::agent::naming::NameCounters::default());
+        let a = spawn_agent(&mut world.commands(), AgentSpec::default());
+        let b = spawn_agent(&mut world.commands(), AgentSpec::default());
+        world.flush();
+
+        let name = |e| world.get::<Name>(e).unwrap().as_str().to_string();
+        assert_ne!(name(a), name(b));
+    }
+}
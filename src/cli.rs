@@ -14,7 +14,7 @@ use crate::core::{
     EventLogConfig, EventLogOutput, FieldLoggerConfig, FieldLoggerFormat, FieldLoggerOutput,
     expand_fields, parse_agent_selector, parse_log_filter, parse_on_change_spec,
 };
-use crate::headless::{HeadlessConfig, InspectConfig, InspectQuery, WhyQuery};
+use crate::headless::{CensusQuery, HeadlessConfig, InspectConfig, InspectQuery, WhyQuery};
 use crate::world::spawn_config::WorldSpawnConfig;
 
 /// Command-line arguments accepted by the worldsim binary.
@@ -161,6 +161,16 @@ pub struct CliArgs {
     #[arg(long = "dump-all")]
     pub dump_all: Vec<String>,
 
+    /// Print how one belief is spread across the population at --at-tick,
+    /// as a JSON line: for each object held, the fraction of agents holding
+    /// it and their mean confidence. Format: "<subject> <predicate>", where
+    /// subject is a concept name, an `x,y` tile, or an agent selector.
+    /// Can be repeated.
+    ///
+    /// Example: --belief-census "alice hastrait"
+    #[arg(long = "belief-census")]
+    pub belief_census: Vec<String>,
+
     /// Tick(s) at which to perform inspection. If not specified, inspects at
     /// the final tick (after --ticks). Can be repeated to inspect at multiple
     /// points in a single run (e.g. `--at-tick 500 --at-tick 5000`).
@@ -250,6 +260,34 @@ pub struct CliArgs {
     /// when `--perf` is not set.
     #[arg(long = "perf-every", default_value_t = 500)]
     pub perf_every: u64,
+
+    /// Record every action transition, GameEvent, per-agent state hash, and
+    /// per-tick RNG position of a headless run to this JSONL file. Feed the
+    /// file back through `--replay` to reproduce the run.
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Re-run a `--record` file with its recorded seed and population and
+    /// report the first tick where the fresh run diverges from the
+    /// recording. Ignores every other run flag.
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Save the run's replay stream every N ticks to rotating
+    /// `autosave-<slot>.jsonl` files, recoverable with `--replay`. Off
+    /// when unset.
+    #[arg(long = "autosave-every", value_name = "TICKS")]
+    pub autosave_every: Option<u64>,
+
+    /// Number of autosave files rotated through. Ignored without
+    /// `--autosave-every`.
+    #[arg(long = "autosave-slots", default_value_t = 3)]
+    pub autosave_slots: u32,
+
+    /// Directory the autosave files are written to. Ignored without
+    /// `--autosave-every`.
+    #[arg(long = "autosave-dir", value_name = "DIR", default_value = "autosave")]
+    pub autosave_dir: String,
 }
 
 impl CliArgs {
@@ -287,6 +325,15 @@ impl CliArgs {
             perf: self.perf.then_some(crate::headless::PerfReportConfig {
                 print_every: self.perf_every.max(1),
             }),
+            record: self.record.clone(),
+            autosave: self
+                .autosave_every
+                .map(|interval_ticks| crate::core::AutosaveConfig {
+                    enabled: true,
+                    interval_ticks,
+                    slots: self.autosave_slots.max(1),
+                    directory: self.autosave_dir.clone(),
+                }),
         }
     }
 
@@ -421,6 +468,18 @@ impl CliArgs {
             .filter_map(|s| s.strip_prefix("agent:").map(|n| n.to_string()))
             .collect();
 
+        let belief_censuses: Vec<CensusQuery> = self
+            .belief_census
+            .iter()
+            .filter_map(|s| {
+                let (subject, predicate) = s.split_once(' ')?;
+                Some(CensusQuery {
+                    subject: subject.to_string(),
+                    predicate: predicate.trim().to_string(),
+                })
+            })
+            .collect();
+
         InspectConfig {
             at_ticks,
             inspect_agents,
@@ -430,6 +489,7 @@ impl CliArgs {
             dump_channels_agents,
             dump_perception_agents,
             dump_all_agents,
+            belief_censuses,
         }
     }
 
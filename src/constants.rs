@@ -136,6 +136,26 @@ pub mod world {
     pub const PIKE_SPAWN_COUNT: usize = 4;
 }
 
+/// Weather cycle tuning. The sky steps one rung at a time along
+/// Clear → Overcast → Rain → Storm, so a storm always builds and clears
+/// through the milder states in between.
+pub mod weather {
+    /// Shortest and longest a spell of weather lasts before the next roll
+    /// (2 to 8 game hours at 3,600 ticks per hour).
+    pub const MIN_SPELL_TICKS: u64 = 7_200;
+    pub const MAX_SPELL_TICKS: u64 = 28_800;
+    /// Chance each roll steps the sky one rung heavier rather than one
+    /// rung lighter. Below 0.5 so clear skies dominate over a long run.
+    pub const WORSEN_CHANCE: f64 = 0.4;
+    /// Walking speed multiplier on wet ground.
+    pub const RAIN_SPEED_MULTIPLIER: f32 = 0.85;
+    pub const STORM_SPEED_MULTIPLIER: f32 = 0.7;
+    /// Sight range multiplier under cloud, rain and storm.
+    pub const OVERCAST_VISION_MULTIPLIER: f32 = 0.9;
+    pub const RAIN_VISION_MULTIPLIER: f32 = 0.7;
+    pub const STORM_VISION_MULTIPLIER: f32 = 0.5;
+}
+
 /// Agent movement parameters
 pub mod movement {
     /// Pixels per tick at normal stamina. Visually-tuned for the 60x wallclock
@@ -152,12 +172,28 @@ pub mod movement {
     pub const MIN_INJURY_MOBILITY: f32 = 0.1;
     /// Upper range of leg-function contribution to movement (maps 0..1 → MIN..MIN+RANGE)
     pub const INJURY_MOBILITY_RANGE: f32 = 0.9;
+    /// Settled agents a single tile holds before extras are nudged onto a
+    /// neighbouring tile. Soft collision only — walkability is separate.
+    pub const MAX_AGENTS_PER_TILE: usize = 1;
+    /// Pixels per tick an over-cap agent drifts toward a free neighbour tile.
+    pub const CROWDING_NUDGE_PER_TICK: f32 = 1.0;
 }
 
 pub mod biology {
     /// HP fraction at or below which a leg `BodyNode` flips the agent to
     /// `Lame`. Predator target enumeration prefers Lame prey.
     pub const LAMENESS_HP_FRACTION: f32 = 0.5;
+    /// Injury healing rate multiplier while the agent holds a resting
+    /// stance (Sit, Rest, RestInShelter, Sleep). Stacks with the
+    /// well-rested stamina bonus in `process_healing`.
+    pub const RESTING_HEAL_MULTIPLIER: f32 = 2.0;
+    /// Fraction of `Body::blood` lost per HP a wound bleeds. A half-severity
+    /// slash bleeds ~150 HP before it clots — three quarters of the blood —
+    /// so one deep cut is survivable but two untreated ones are not.
+    pub const BLOOD_LOSS_PER_BLED_HP: f32 = 0.005;
+    /// `Body::blood` regained per game-second while nothing bleeds: about
+    /// an hour to make up a full volume.
+    pub const BLOOD_REGEN_PER_SEC: f32 = 1.0 / 3600.0;
 }
 
 /// Display thresholds shared by overhead status icons and the
@@ -177,6 +213,11 @@ pub mod actions {
         /// full precondition (`Eat::can_start`) blocks further Eat starts.
         pub const DURATION_TICKS: u32 = 20;
         pub const STAMINA_GAIN: f32 = 10.0;
+        /// Share of a meal's macros a fully stale (freshness 0) item still
+        /// delivers. Freshness scales linearly between this and 1.0, so a
+        /// week-old apple feeds less than a fresh one but more than the
+        /// RottenApple it is about to become.
+        pub const STALE_NUTRITION_FLOOR: f32 = 0.5;
     }
 
     pub mod drink {
@@ -235,6 +276,9 @@ pub mod actions {
         pub const HOUSE_STONE_REQUIRED: u32 = 6;
         /// Labor ticks needed to finish a house construction site.
         pub const HOUSE_LABOR_TICKS: u32 = 400;
+        /// Building skill needed to place a house site. Four or so
+        /// finished Construct shifts from scratch.
+        pub const HOUSE_MIN_BUILDING_SKILL: f32 = 0.15;
         /// Wood required to start a storage chest construction site.
         pub const STORAGE_CHEST_WOOD_REQUIRED: u32 = 4;
         /// Labor ticks needed to finish a storage chest construction site.
@@ -264,6 +308,25 @@ pub mod actions {
         pub const MIN_AFFECTION: f32 = 0.4;
     }
 
+    pub mod give {
+        pub const DURATION_TICKS: u32 = 15;
+        /// Lower bound on the giver's affection toward the recipient —
+        /// matches Share Food so gifts stay among friends.
+        pub const MIN_AFFECTION: f32 = 0.4;
+        /// Most items handed over per Give. Enough to matter to a hungry
+        /// recipient without emptying the giver's pack in one go.
+        pub const QUANTITY: u32 = 2;
+        /// Giver and recipient must be within arm's reach — two tiles.
+        pub const REACH: f32 = 32.0;
+        /// Valence of the `SocialInteraction` a completed gift emits. Well
+        /// above a greeting's: being fed by someone is remembered.
+        pub const VALENCE: f32 = 0.5;
+        /// Emotional-brain urgency per unit of affection toward a hungry
+        /// recipient. A close friend (affection 1.0) outranks idle drift
+        /// but not the giver's own pressing needs.
+        pub const URGENCY_MULTIPLIER: f32 = 35.0;
+    }
+
     pub mod tend_wounds {
         pub const DURATION_TICKS: u32 = 60;
     }
@@ -293,6 +356,19 @@ pub mod actions {
         pub const RECENT_DEATH_WINDOW_TICKS: u64 = 86_400;
     }
 
+    pub mod alarm_signal {
+        /// A single stomp-and-snort — short enough to run alongside Flee.
+        pub const DURATION_TICKS: u32 = 15;
+        /// Fear a same-species listener is topped up to on hearing the
+        /// call. Above `FEAR_GENERAL_THRESHOLD` so the herd bolts.
+        pub const LISTENER_FEAR: f32 = 0.8;
+    }
+
+    pub mod drop {
+        /// Setting a stack down is as quick as picking one up.
+        pub const DURATION_TICKS: u32 = 10;
+    }
+
     pub mod pickup {
         /// Quick pickup of a ground item — short enough to feel snappy in
         /// supply-chain hauling.
@@ -307,6 +383,9 @@ pub mod actions {
     pub mod attack {
         pub const DURATION_TICKS: u32 = 30;
         pub const BASE_COST: f32 = 10.0;
+        /// How far a fight carries as sound (world pixels). Listeners
+        /// inside this radius hear it around corners and through walls.
+        pub const SOUND_RADIUS: f32 = 384.0;
     }
 
     pub mod defend_self {
@@ -430,6 +509,23 @@ pub mod actions {
 
 /// Brain behavior thresholds and urgency scores
 pub mod brains {
+    /// Reaction latency, in ticks, before a brain that newly wins
+    /// arbitration actually takes control. Reflexes are instant; emotion
+    /// takes a beat; deliberate thought is slowest.
+    pub mod latency {
+        pub const SURVIVAL_TICKS: u64 = 0;
+        pub const EMOTIONAL_TICKS: u64 = 6;
+        pub const RATIONAL_TICKS: u64 = 12;
+    }
+
+    /// Arbitration hysteresis. A decision that just took over has its
+    /// proposal's urgency scaled by `INCUMBENT_BOOST` for `HOLD_TICKS`,
+    /// so a near-tied rival can't flip it straight back.
+    pub mod hysteresis {
+        pub const INCUMBENT_BOOST: f32 = 1.25;
+        pub const HOLD_TICKS: u64 = 30;
+    }
+
     pub mod survival {
         /// Stamina level at which a sleeping agent wakes up fully rested.
         /// Legacy absolute threshold — still used by the planner's
@@ -500,6 +596,15 @@ pub mod brains {
         pub const MIN_URGENCY_THRESHOLD: f32 = 0.05;
     }
 
+    /// Recovery drive: the pull to hold still and heal while injured.
+    pub mod recovery {
+        /// Total body pain that saturates the drive's input (same scale
+        /// as the Pain drive).
+        pub const PAIN_SCALE: f32 = 100.0;
+        /// Minimum urgency below which the drive is suppressed.
+        pub const MIN_URGENCY_THRESHOLD: f32 = 0.05;
+    }
+
     /// Warmth drive: thermal comfort drain and recovery.
     pub mod warmth {
         /// Baseline satisfaction drain per rate-second in neutral conditions
@@ -561,6 +666,21 @@ pub mod brains {
         pub const SLEEPINESS_DAYLIGHT_DAMPEN: f32 = 0.5;
     }
 
+    /// Time-since-satisfied boosts on drive urgency (`NeglectBoost`).
+    pub mod neglect {
+        use crate::core::time::GameTime;
+        /// Normal meal spacing: no hunger boost within 6 game hours of eating.
+        pub const HUNGER_GRACE_TICKS: u64 = 6 * GameTime::TICKS_PER_HOUR;
+        /// A further 18 hours (a full day without food) reaches the max boost.
+        pub const HUNGER_RAMP_TICKS: u64 = 18 * GameTime::TICKS_PER_HOUR;
+        pub const HUNGER_MAX_BOOST: f32 = 0.5;
+        /// A normal waking day: no sleepiness boost for 18 game hours.
+        pub const SLEEP_GRACE_TICKS: u64 = 18 * GameTime::TICKS_PER_HOUR;
+        /// Pulling an all-nighter reaches the max boost a day later.
+        pub const SLEEP_RAMP_TICKS: u64 = 24 * GameTime::TICKS_PER_HOUR;
+        pub const SLEEP_MAX_BOOST: f32 = 0.3;
+    }
+
     /// Emotional brain urgency scores and emotion intensity thresholds
     pub mod emotional {
         pub const FEAR_ENTITY_THRESHOLD: f32 = 0.3;
@@ -650,7 +770,13 @@ pub mod brains {
     /// GOAP planner search parameters
     pub mod planner {
         pub const MAX_ITERATIONS: usize = 200;
-        /// Cost per unmet goal condition in the A* heuristic
+        /// Default cost per unmet goal condition in the A* heuristic
+        /// (`PlannerConfig::heuristic_weight`)
         pub const HEURISTIC_MULTIPLIER: f32 = 5.0;
+        /// Search cost a fully hungry agent charges for gathering food with
+        /// zero nutrition value. Scaled down by hunger and by the food's
+        /// nutrition, so at full hunger meat (~0.67) beats an apple (~0.24)
+        /// by ~3.4, worth a few dozen extra tiles of walking.
+        pub const FOOD_VALUE_WEIGHT: f32 = 8.0;
     }
 }
@@ -1,15 +1,18 @@
 use bevy::prelude::*;
 
+pub mod autosave;
 pub mod diagnostics;
 pub mod entity_serde;
 pub mod event_log;
 pub mod field_logger;
 pub mod log;
 pub mod perf;
+pub mod replay;
 pub mod sim_rng;
 pub mod tick;
 pub mod time;
 
+pub use autosave::{AutosaveConfig, AutosaveSource, AutosaveState};
 pub use diagnostics::DiagnosticsPlugin;
 pub use event_log::{
     EventLogBuffer, EventLogConfig, EventLogFilter, EventLogOutput, collect_event_log,
@@ -38,11 +41,15 @@ impl Plugin for CorePlugin {
             .register_type::<TickCount>()
             .register_type::<GameTime>()
             .register_type::<GameLog>()
+            .register_type::<AutosaveConfig>()
             .insert_resource(TickCount::new(60.0)) // 60 ticks per second
             .insert_resource(GameLog::new(100))
             .init_resource::<GameTime>()
             .init_resource::<SimRng>()
+            .init_resource::<AutosaveConfig>()
+            .init_resource::<AutosaveState>()
             .add_systems(FixedUpdate, tick::tick_system)
+            .add_systems(FixedLast, tick::finish_step)
             .add_systems(Update, time_controls);
     }
 }
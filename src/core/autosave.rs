@@ -0,0 +1,139 @@
+//! Periodic autosave of a recorded run to a rotating set of replay files.
+//!
+//! Reads: AutosaveConfig, AutosaveSource, ReplayRecorder, TickCount
+//! Writes: AutosaveState, `autosave-<slot>.jsonl` files in `AutosaveConfig::directory`
+//! Upstream: core::replay (record_replay fills the recorder earlier in `Last`)
+//! Downstream: headless::replay_headless (`--replay` any slot to rebuild the run up to its tick)
+//!
+//! The sim is deterministic from its seed and population, so a run is
+//! saved by its replay stream: the header, with `ticks` set to the tick of
+//! the save, plus every entry recorded so far. Replaying a slot rebuilds
+//! the world to that tick and checks it against the recording on the way.
+//! The entries are cloned on the sim thread and written on a background
+//! thread into a temporary file that is renamed over the slot, so a crash
+//! mid-write leaves the previous save in that slot intact. Slots are
+//! reused round-robin. Without an `AutosaveSource` (the windowed game has
+//! no replay header to rebuild from) the system does nothing.
+
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+
+use bevy::prelude::*;
+
+use crate::core::TickCount;
+use crate::core::replay::{ReplayHeader, ReplayRecorder, write_replay};
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct AutosaveConfig {
+    pub enabled: bool,
+    /// Ticks between saves. 0 = never.
+    pub interval_ticks: u64,
+    /// Files rotated through before the oldest is overwritten.
+    pub slots: u32,
+    /// Directory the slot files are written to; created on first save.
+    pub directory: String,
+}
+
+impl Default for AutosaveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_ticks: 36_000,
+            slots: 3,
+            directory: "autosave".to_string(),
+        }
+    }
+}
+
+impl AutosaveConfig {
+    pub fn slot_path(&self, slot: u32) -> PathBuf {
+        Path::new(&self.directory).join(format!("autosave-{slot}.jsonl"))
+    }
+}
+
+/// How to rebuild the running world from scratch. Present only when the
+/// run can be replayed (headless runs with autosave on).
+#[derive(Resource, Clone, Debug)]
+pub struct AutosaveSource(pub ReplayHeader);
+
+#[derive(Resource, Default)]
+pub struct AutosaveState {
+    /// Slot the next save goes to.
+    pub next_slot: u32,
+    /// Tick of the most recent save started.
+    pub last_saved_tick: u64,
+    /// Every save started so far, oldest first: (tick, file).
+    pub saved: Vec<(u64, PathBuf)>,
+    pending: Option<JoinHandle<std::io::Result<()>>>,
+}
+
+impl AutosaveState {
+    /// Wait for the in-flight write, if any. Called before the next save
+    /// starts and when a run ends.
+    pub fn finish(&mut self) {
+        if let Some(handle) = self.pending.take() {
+            match handle.join() {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!("autosave: write failed: {e}"),
+                Err(_) => warn!("autosave: writer thread panicked"),
+            }
+        }
+    }
+}
+
+/// Write `entries` to `path` via a sibling temp file, so readers never see
+/// a half-written slot.
+fn write_slot(
+    path: &Path,
+    header: &ReplayHeader,
+    entries: &[crate::core::replay::ReplayEntry],
+) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("jsonl.tmp");
+    write_replay(&tmp, header, entries)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Bevy system (Last schedule, after `record_replay`): once the tick
+/// crosses a multiple of `interval_ticks`, hand a snapshot of the replay
+/// stream to a background writer for the next slot.
+pub fn autosave(
+    config: Res<AutosaveConfig>,
+    tick: Res<TickCount>,
+    source: Option<Res<AutosaveSource>>,
+    recorder: Option<Res<ReplayRecorder>>,
+    mut state: ResMut<AutosaveState>,
+) {
+    let (Some(source), Some(recorder)) = (source, recorder) else {
+        return;
+    };
+    if !config.enabled || config.interval_ticks == 0 || config.slots == 0 {
+        return;
+    }
+    let interval = config.interval_ticks;
+    if tick.current / interval <= state.last_saved_tick / interval {
+        return;
+    }
+
+    // The previous write has had a whole interval to finish; this only
+    // blocks if the disk is slower than the sim.
+    state.finish();
+
+    let slot = state.next_slot % config.slots;
+    let path = config.slot_path(slot);
+    let header = ReplayHeader {
+        ticks: tick.current,
+        ..source.0.clone()
+    };
+    let entries = recorder.entries.clone();
+    let target = path.clone();
+    state.pending = Some(std::thread::spawn(move || {
+        write_slot(&target, &header, &entries)
+    }));
+    state.next_slot = (slot + 1) % config.slots;
+    state.last_saved_tick = tick.current;
+    state.saved.push((tick.current, path));
+}
@@ -20,6 +20,16 @@ pub struct PerformanceDiagnostics {
     pub initial_counts: std::collections::HashMap<u32, usize>,
     /// Track if we've captured initial state
     pub initialized: bool,
+    /// Rational-brain plan searches answered from `PlanMemory::plan_cache`
+    pub plan_cache_hits: u64,
+    /// Rational-brain plan searches that had to run the planner
+    pub plan_cache_misses: u64,
+    /// Entities visual perception distance-checked after the
+    /// `SpatialIndex` bucket query, summed over every look
+    pub perception_candidates: u64,
+    /// Entities a full scan would have checked over the same looks
+    /// (indexed entity count per look)
+    pub perception_brute_force_candidates: u64,
 }
 
 impl PerformanceDiagnostics {
@@ -30,6 +40,10 @@ impl PerformanceDiagnostics {
             last_detailed_log: 0,
             initial_counts: std::collections::HashMap::new(),
             initialized: false,
+            plan_cache_hits: 0,
+            plan_cache_misses: 0,
+            perception_candidates: 0,
+            perception_brute_force_candidates: 0,
         }
     }
 }
@@ -130,6 +144,17 @@ pub fn profile_mindgraph_growth(
         }
     ));
     game_log.performance(format!("Growth rate: {:.2} triples/sec", growth_rate));
+    game_log.performance(format!(
+        "Plan cache: {} hits, {} misses",
+        diagnostics.plan_cache_hits, diagnostics.plan_cache_misses
+    ));
+    game_log.performance(format!(
+        "Perception candidates: {} via spatial index vs {} brute force ({:.1}%)",
+        diagnostics.perception_candidates,
+        diagnostics.perception_brute_force_candidates,
+        100.0 * diagnostics.perception_candidates as f64
+            / diagnostics.perception_brute_force_candidates.max(1) as f64
+    ));
     game_log.performance(format!(
         "Index sizes: by_subject={}, by_subject_pred={}, by_predicate={}",
         total_by_subject, total_by_subject_pred, total_by_predicate
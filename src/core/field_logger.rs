@@ -775,6 +775,7 @@ fn concept_by_name(name: &str) -> Option<Concept> {
         Concept::Meat,
         Concept::Corpse,
         Concept::SeveredPart,
+        Concept::GroundItem,
         Concept::RottenApple,
         Concept::RottenBerry,
         Concept::Campfire,
@@ -843,6 +844,7 @@ fn urgency_source_by_lowercase_name(s: &str) -> Option<UrgencySource> {
         "fun" => Some(UrgencySource::Fun),
         "fear" => Some(UrgencySource::Fear),
         "pain" => Some(UrgencySource::Pain),
+        "recovery" => Some(UrgencySource::Recovery),
         "curiosity" => Some(UrgencySource::Curiosity),
         "territoriality" => Some(UrgencySource::Territoriality),
         "sleepiness" => Some(UrgencySource::Sleepiness),
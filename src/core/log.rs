@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use chrono::Local;
 use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// Directory the Log tab's "Save Log" button writes into.
+pub const LOG_EXPORT_DIR: &str = "logs";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // LOG CATEGORIES
@@ -71,6 +75,44 @@ pub struct LogEntry {
     pub entity: Option<Entity>,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// LOG EXPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogExportFormat {
+    /// One line per entry, as the Log tab shows it.
+    #[default]
+    Text,
+    /// `timestamp,category,message,count` with a header row.
+    Csv,
+}
+
+impl LogExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            LogExportFormat::Text => "txt",
+            LogExportFormat::Csv => "csv",
+        }
+    }
+
+    /// `logs/worldsim-log-<date>-<time>.<ext>`, so repeated saves from one
+    /// session or from different runs never overwrite each other.
+    pub fn timestamped_path(&self) -> PathBuf {
+        let stamp = Local::now().format("%Y%m%d-%H%M%S");
+        Path::new(LOG_EXPORT_DIR).join(format!("worldsim-log-{stamp}.{}", self.extension()))
+    }
+}
+
+/// Quote a CSV field when it holds a separator, quote or newline.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // GAME LOG RESOURCE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -157,6 +199,13 @@ impl GameLog {
         !self.entity_filter.is_empty()
     }
 
+    /// "Everything about these agents": filter to exactly `entities` and
+    /// enable every category.
+    pub fn focus_on(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        self.entity_filter = entities.into_iter().collect();
+        self.enabled = LogCategory::all();
+    }
+
     // ─── Core logging ───
 
     fn log_internal(&mut self, category: LogCategory, message: String, entity: Option<Entity>) {
@@ -291,18 +340,17 @@ impl GameLog {
 
     // ─── Filtered view for UI ───
 
-    /// Get entries filtered by currently enabled categories and entity filter
+    /// Get entries that are in an enabled category AND, when an entity
+    /// filter is set, belong to one of the filtered entities. Entries with
+    /// no entity (world events) are hidden while filtering.
     pub fn visible_entries(&self) -> Vec<&LogEntry> {
         self.entries
             .iter()
             .filter(|e| self.enabled.contains(&e.category))
             .filter(|e| {
-                // If no entity filter, show all; otherwise only show matching entities
                 self.entity_filter.is_empty()
-                    || e.entity.is_none()
                     || e.entity
-                        .map(|ent| self.entity_filter.contains(&ent))
-                        .unwrap_or(false)
+                        .is_some_and(|ent| self.entity_filter.contains(&ent))
             })
             .collect()
     }
@@ -311,4 +359,124 @@ impl GameLog {
     pub fn all_entries(&self) -> impl Iterator<Item = &LogEntry> {
         self.entries.iter()
     }
+
+    // ─── Export ───
+
+    /// Render the log in `format`: every stored entry, or only what the Log
+    /// tab currently shows when `visible_only` is set.
+    pub fn render_export(&self, format: LogExportFormat, visible_only: bool) -> String {
+        let entries: Vec<&LogEntry> = if visible_only {
+            self.visible_entries()
+        } else {
+            self.entries.iter().collect()
+        };
+        let mut out = String::new();
+        if format == LogExportFormat::Csv {
+            out.push_str("timestamp,category,message,count\n");
+        }
+        for entry in entries {
+            let line = match format {
+                LogExportFormat::Text if entry.count > 1 => format!(
+                    "[{}] {} {} (x{})",
+                    entry.timestamp,
+                    entry.category.prefix(),
+                    entry.message,
+                    entry.count
+                ),
+                LogExportFormat::Text => format!(
+                    "[{}] {} {}",
+                    entry.timestamp,
+                    entry.category.prefix(),
+                    entry.message
+                ),
+                LogExportFormat::Csv => format!(
+                    "{},{:?},{},{}",
+                    entry.timestamp,
+                    entry.category,
+                    csv_field(&entry.message),
+                    entry.count
+                ),
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Write [`Self::render_export`] to `path`, creating its directory.
+    pub fn export_to_path(
+        &self,
+        path: impl AsRef<Path>,
+        format: LogExportFormat,
+        visible_only: bool,
+    ) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.render_export(format, visible_only))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_filter_and_categories_intersect() {
+        let mut log = GameLog::new(100);
+        log.quiet = true;
+        let alice = Entity::from_bits(1);
+        let bob = Entity::from_bits(2);
+        log.perception("Alice", "saw a deer", Some(alice));
+        log.action("Alice", "Eat", None, Some(alice));
+        log.perception("Bob", "saw a wolf", Some(bob));
+        log.event("A storm rolls in");
+
+        log.filter_by_entity(alice);
+        log.enabled = HashSet::from([LogCategory::Perception]);
+        let visible: Vec<_> = log.visible_entries().iter().map(|e| &e.message).collect();
+        assert_eq!(visible, vec!["[Alice] saw a deer"]);
+
+        log.focus_on([alice]);
+        let visible: Vec<_> = log.visible_entries().iter().map(|e| &e.message).collect();
+        assert_eq!(visible, vec!["[Alice] saw a deer", "[Alice] Eat"]);
+
+        log.clear_entity_filter();
+        assert_eq!(log.visible_entries().len(), 4);
+    }
+
+    #[test]
+    fn export_writes_every_entry_or_only_visible_ones() {
+        let mut log = GameLog::new(100);
+        log.quiet = true;
+        let alice = Entity::from_bits(1);
+        log.action("Alice", "Eat", None, Some(alice));
+        log.action("Alice", "Eat", None, Some(alice));
+        log.event("Rain, then \"sun\"");
+        log.filter_by_entity(alice);
+
+        let text = log.render_export(LogExportFormat::Text, true);
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.ends_with("[Action] [Alice] Eat (x2)\n"), "{text}");
+
+        let csv = log.render_export(LogExportFormat::Csv, false);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows[0], "timestamp,category,message,count");
+        assert!(rows[1].ends_with(",Action,[Alice] Eat,2"), "{}", rows[1]);
+        assert!(
+            rows[2].ends_with(",Event,\"Rain, then \"\"sun\"\"\",1"),
+            "{}",
+            rows[2]
+        );
+
+        let path = std::env::temp_dir().join(format!(
+            "worldsim_log_export_test_{}.csv",
+            std::process::id()
+        ));
+        log.export_to_path(&path, LogExportFormat::Csv, false)
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), csv);
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -0,0 +1,249 @@
+//! Run recorder and replay verifier for reproducing "agent did something weird" reports.
+//!
+//! Reads: SimEvent (action lifecycle + state hashes), GameEvent, SimRng stream position
+//! Writes: ReplayRecorder resource (ordered ReplayEntry stream), replay files (JSONL)
+//! Upstream: agent::events (SimEvent, GameEvent), core::sim_rng
+//! Downstream: headless::run_headless (`--record`), headless::replay_headless (`--replay`)
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::agent::events::{GameEvent, SimEvent, SimEventKind};
+use crate::core::{SimRng, TickCount};
+
+/// Everything needed to rebuild the run from scratch. Written as the first
+/// line of a replay file; the remaining lines are `ReplayEntry` values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub seed: u64,
+    pub ticks: u64,
+    pub humans: usize,
+    pub berry_bushes: usize,
+    pub apple_trees: usize,
+    pub deer: usize,
+    pub wolves: usize,
+    pub game_defaults: bool,
+}
+
+/// Which `ActionState` lifecycle edge an entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionTransition {
+    Started,
+    Completed,
+    Preempted,
+    Failed,
+}
+
+/// One recorded observation. Entities and payloads are stored in their
+/// `Debug` form — stable across identical runs and cheap to diff by eye.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ReplayEntry {
+    /// An action entered or left an agent's running set.
+    Action {
+        tick: u64,
+        agent: String,
+        transition: ActionTransition,
+        action: String,
+    },
+    /// A `GameEvent` crossed the agent message bus.
+    GameEvent { tick: u64, event: String },
+    /// Per-agent observable-state hash at the end of the tick.
+    StateHash { tick: u64, agent: String, hash: u64 },
+    /// `SimRng` stream position at the end of the tick. Any extra or
+    /// missing draw shifts it, so this pins down RNG divergence to a tick.
+    Rng { tick: u64, word_pos: u128 },
+}
+
+impl ReplayEntry {
+    pub fn tick(&self) -> u64 {
+        match self {
+            ReplayEntry::Action { tick, .. }
+            | ReplayEntry::GameEvent { tick, .. }
+            | ReplayEntry::StateHash { tick, .. }
+            | ReplayEntry::Rng { tick, .. } => *tick,
+        }
+    }
+}
+
+/// Accumulates the entry stream during a recorded run.
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    pub entries: Vec<ReplayEntry>,
+}
+
+/// The first point where a replayed run stopped matching its recording.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayDivergence {
+    /// Index into the entry stream (header excluded).
+    pub index: usize,
+    pub expected: Option<ReplayEntry>,
+    pub actual: Option<ReplayEntry>,
+}
+
+impl ReplayDivergence {
+    /// Tick of the first mismatching entry, whichever side has one.
+    pub fn tick(&self) -> Option<u64> {
+        self.expected
+            .as_ref()
+            .or(self.actual.as_ref())
+            .map(ReplayEntry::tick)
+    }
+}
+
+/// Bevy system (Last schedule): appends this tick's action transitions,
+/// game events, state hashes, and RNG position to `ReplayRecorder`.
+pub fn record_replay(
+    tick: Res<TickCount>,
+    rng: Res<SimRng>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut sim_events: MessageReader<SimEvent>,
+    mut game_events: MessageReader<GameEvent>,
+) {
+    for event in sim_events.read() {
+        if let Some(entry) = entry_from_sim_event(event) {
+            recorder.entries.push(entry);
+        }
+    }
+    for event in game_events.read() {
+        recorder.entries.push(ReplayEntry::GameEvent {
+            tick: tick.current,
+            event: format!("{event:?}"),
+        });
+    }
+    recorder.entries.push(ReplayEntry::Rng {
+        tick: tick.current,
+        word_pos: rng.word_pos(),
+    });
+}
+
+fn entry_from_sim_event(event: &SimEvent) -> Option<ReplayEntry> {
+    let action = |agent: Entity, transition, action: String| ReplayEntry::Action {
+        tick: event.tick,
+        agent: format!("{agent:?}"),
+        transition,
+        action,
+    };
+    match &event.kind {
+        SimEventKind::ActionStarted {
+            agent,
+            action: a,
+            target,
+            ..
+        } => Some(action(
+            *agent,
+            ActionTransition::Started,
+            format!("{a:?} -> {target:?}"),
+        )),
+        SimEventKind::ActionCompleted {
+            agent, action: a, ..
+        } => Some(action(
+            *agent,
+            ActionTransition::Completed,
+            format!("{a:?}"),
+        )),
+        SimEventKind::ActionPreempted {
+            agent,
+            preempted_action,
+        } => Some(action(
+            *agent,
+            ActionTransition::Preempted,
+            format!("{preempted_action:?}"),
+        )),
+        SimEventKind::ActionFailed {
+            agent,
+            action: a,
+            reason,
+        } => Some(action(
+            *agent,
+            ActionTransition::Failed,
+            format!("{a:?}: {reason:?}"),
+        )),
+        SimEventKind::AgentStateHash { agent, hash } => Some(ReplayEntry::StateHash {
+            tick: event.tick,
+            agent: format!("{agent:?}"),
+            hash: *hash,
+        }),
+        _ => None,
+    }
+}
+
+/// Write a header line followed by one JSON line per entry. Overwrites `path`.
+pub fn write_replay(
+    path: &Path,
+    header: &ReplayHeader,
+    entries: &[ReplayEntry],
+) -> std::io::Result<()> {
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+    writeln!(writer, "{}", serde_json::to_string(header)?)?;
+    for entry in entries {
+        writeln!(writer, "{}", serde_json::to_string(entry)?)?;
+    }
+    writer.flush()
+}
+
+/// Parse a file produced by `write_replay`.
+pub fn read_replay(path: &Path) -> std::io::Result<(ReplayHeader, Vec<ReplayEntry>)> {
+    let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+    let mut lines = reader.lines();
+    let header_line = lines
+        .next()
+        .ok_or_else(|| std::io::Error::other("replay file is empty"))??;
+    let header: ReplayHeader = serde_json::from_str(&header_line)?;
+    let mut entries = Vec::new();
+    for line in lines {
+        entries.push(serde_json::from_str(&line?)?);
+    }
+    Ok((header, entries))
+}
+
+/// Compare a recording against a fresh run, entry by entry. Returns `None`
+/// when the streams are identical.
+pub fn first_divergence(
+    expected: &[ReplayEntry],
+    actual: &[ReplayEntry],
+) -> Option<ReplayDivergence> {
+    let len = expected.len().max(actual.len());
+    (0..len)
+        .find(|&i| expected.get(i) != actual.get(i))
+        .map(|index| ReplayDivergence {
+            index,
+            expected: expected.get(index).cloned(),
+            actual: actual.get(index).cloned(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rng(tick: u64, word_pos: u128) -> ReplayEntry {
+        ReplayEntry::Rng { tick, word_pos }
+    }
+
+    #[test]
+    fn identical_streams_have_no_divergence() {
+        let stream = vec![rng(1, 4), rng(2, 8)];
+        assert_eq!(first_divergence(&stream, &stream.clone()), None);
+    }
+
+    #[test]
+    fn divergence_reports_first_mismatching_tick() {
+        let expected = vec![rng(1, 4), rng(2, 8), rng(3, 12)];
+        let actual = vec![rng(1, 4), rng(2, 9), rng(3, 12)];
+        let divergence = first_divergence(&expected, &actual).expect("streams differ");
+        assert_eq!(divergence.index, 1);
+        assert_eq!(divergence.tick(), Some(2));
+    }
+
+    #[test]
+    fn truncated_replay_diverges_at_missing_entry() {
+        let expected = vec![rng(1, 4), rng(2, 8)];
+        let actual = vec![rng(1, 4)];
+        let divergence = first_divergence(&expected, &actual).expect("streams differ");
+        assert_eq!(divergence.index, 1);
+        assert_eq!(divergence.actual, None);
+    }
+}
@@ -30,6 +30,13 @@ impl SimRng {
     pub fn inner_mut(&mut self) -> &mut ChaCha8Rng {
         &mut self.0
     }
+
+    /// Position in the keystream, in 32-bit words consumed since seeding.
+    /// Two runs that made the same draws report the same position, so the
+    /// replay recorder logs this per tick to catch RNG divergence.
+    pub fn word_pos(&self) -> u128 {
+        self.0.get_word_pos()
+    }
 }
 
 impl Default for SimRng {
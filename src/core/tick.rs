@@ -21,6 +21,9 @@ pub struct TickCount {
     pub game_seconds_per_cycle: u64,
     /// Whether simulation is paused
     pub paused: bool,
+    /// Run exactly one FixedMain cycle while paused. `not_paused` lets the
+    /// cycle through and `finish_step` clears the flag at its end.
+    pub stepping: bool,
 }
 
 impl Default for TickCount {
@@ -30,6 +33,7 @@ impl Default for TickCount {
             ticks_per_second: 60.0,
             game_seconds_per_cycle: 1,
             paused: false,
+            stepping: false,
         }
     }
 }
@@ -112,10 +116,11 @@ pub const TICK_LONG_PERIOD: u64 = 2000;
 // RUN CONDITIONS - Use these with `.run_if()` on systems
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Run condition: Only run when simulation is NOT paused
+/// Run condition: Only run when simulation is NOT paused, or during a
+/// single-step cycle.
 /// Usage: `.run_if(not_paused)`
 pub fn not_paused(tick: Res<TickCount>) -> bool {
-    !tick.paused
+    !tick.paused || tick.stepping
 }
 
 /// Run condition: Only run every N ticks (not staggered by entity)
@@ -125,10 +130,17 @@ pub fn every_n_ticks(n: u64) -> impl Fn(Res<TickCount>) -> bool {
 }
 
 pub fn tick_system(mut tick: ResMut<TickCount>, mut game_time: ResMut<super::GameTime>) {
-    if tick.paused {
+    if tick.paused && !tick.stepping {
         return;
     }
     let step = tick.game_seconds_per_cycle;
     tick.current += step;
     game_time.update_from_tick(tick.current);
 }
+
+/// FixedLast: end a single-step cycle so the next one stays paused.
+pub fn finish_step(mut tick: ResMut<TickCount>) {
+    if tick.stepping {
+        tick.stepping = false;
+    }
+}
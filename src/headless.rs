@@ -1,20 +1,29 @@
 //! Headless simulation runner: spins up a TestWorld, populates it, runs N ticks at max speed, and emits a JSON report.
 //!
-//! Reads: testing::TestWorld, agent components (PhysicalNeeds, EmotionalState, Body, ConverseRegistry), DecisionTraceBuffer
-//! Writes: HeadlessReport (serializable summary), spawn entities via TestWorld, trace output to stderr/file
+//! Reads: testing::TestWorld, agent components (PhysicalNeeds, EmotionalState, Body, ConverseRegistry), DecisionTraceBuffer, ReplayRecorder
+//! Writes: HeadlessReport (serializable summary), spawn entities via TestWorld, trace output to stderr/file, replay and autosave files
 //! Upstream: cli (CliArgs), main (binary entry point)
-//! Downstream: stdout (JSON report), statistical tests, regression baselines, trace output
+//! Downstream: stdout (JSON report), statistical tests, regression baselines, trace output, batch parameter sweeps (run_headless_batch)
 
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use bevy::ecs::entity::Entity;
+use bevy::prelude::IntoScheduleConfigs;
 use serde::Serialize;
 
 use crate::agent::biology::body::Body;
 use crate::agent::body::needs::{Consciousness, PhysicalNeeds};
 use crate::agent::brains::trace::{DecisionTraceBuffer, TraceConfig, dump_trace};
 use crate::agent::engagement::converse::ConverseRegistry;
+use crate::agent::mind::census::{parse_predicate, parse_subject};
+use crate::agent::mind::knowledge::Node;
 use crate::agent::psyche::emotions::{EmotionType, EmotionalState};
+use crate::core::autosave::{AutosaveConfig, AutosaveSource, AutosaveState, autosave};
+use crate::core::replay::{
+    ReplayDivergence, ReplayEntry, ReplayHeader, ReplayRecorder, first_divergence, read_replay,
+    record_replay, write_replay,
+};
 use crate::core::{
     EventLogBuffer, EventLogConfig, FieldLoggerBuffer, FieldLoggerConfig, PerfPlugin, PerfSnapshot,
     PerfTracker, collect_event_log, collect_field_log, dump_event_log, dump_field_log,
@@ -39,6 +48,16 @@ pub struct WhyQuery {
     pub metric: String,
 }
 
+/// A population belief census: how `(subject, predicate, ?)` is held
+/// across every agent.
+#[derive(Debug, Clone)]
+pub struct CensusQuery {
+    /// Concept name, `x,y` tile, or agent selector.
+    pub subject: String,
+    /// Predicate name, case-insensitive.
+    pub predicate: String,
+}
+
 /// Configuration for post-run inspection commands.
 #[derive(Debug, Clone, Default)]
 pub struct InspectConfig {
@@ -60,6 +79,8 @@ pub struct InspectConfig {
     pub dump_perception_agents: Vec<String>,
     /// Print every available diagnostic for these agents.
     pub dump_all_agents: Vec<String>,
+    /// Print a population census for each of these beliefs.
+    pub belief_censuses: Vec<CensusQuery>,
 }
 
 impl InspectConfig {
@@ -72,6 +93,7 @@ impl InspectConfig {
             || !self.dump_channels_agents.is_empty()
             || !self.dump_perception_agents.is_empty()
             || !self.dump_all_agents.is_empty()
+            || !self.belief_censuses.is_empty()
     }
 }
 
@@ -108,6 +130,12 @@ pub struct HeadlessConfig {
     pub field_logger: Option<FieldLoggerConfig>,
     /// Per-system tick timer. `None` disables perf measurement.
     pub perf: Option<PerfReportConfig>,
+    /// Record action transitions, game events, state hashes, and RNG
+    /// position to this file for later `--replay`. `None` disables it.
+    pub record: Option<PathBuf>,
+    /// Periodically save the replay stream to rotating slot files so a
+    /// long run can be recovered with `--replay`. `None` disables it.
+    pub autosave: Option<AutosaveConfig>,
 }
 
 /// Configuration for `--perf` output. Populated when the user passes
@@ -154,6 +182,39 @@ impl Default for HeadlessConfig {
             inspect: InspectConfig::default(),
             field_logger: None,
             perf: None,
+            record: None,
+            autosave: None,
+        }
+    }
+}
+
+impl HeadlessConfig {
+    /// The subset of this config a replay needs to rebuild the same run.
+    pub fn replay_header(&self) -> ReplayHeader {
+        ReplayHeader {
+            seed: self.seed,
+            ticks: self.ticks,
+            humans: self.humans,
+            berry_bushes: self.berry_bushes,
+            apple_trees: self.apple_trees,
+            deer: self.deer,
+            wolves: self.wolves,
+            game_defaults: self.game_defaults,
+        }
+    }
+
+    /// Rebuild a plain (no logging, no inspection) config from a replay header.
+    pub fn from_replay_header(header: &ReplayHeader) -> Self {
+        Self {
+            seed: header.seed,
+            ticks: header.ticks,
+            humans: header.humans,
+            berry_bushes: header.berry_bushes,
+            apple_trees: header.apple_trees,
+            deer: header.deer,
+            wolves: header.wolves,
+            game_defaults: header.game_defaults,
+            ..Self::default()
         }
     }
 }
@@ -217,8 +278,68 @@ pub struct EmotionStats {
 ///
 /// If `config.trace.is_enabled()`, decision trace records are collected during
 /// the run and dumped to stderr (text) or the configured file (JSONL) when the
-/// run completes.
+/// run completes. If `config.record` is set, the replay stream is written there.
 pub fn run_headless(config: HeadlessConfig) -> HeadlessReport {
+    let record = config.record.is_some() || config.autosave.is_some();
+    let (report, recorded) = run_recorded(&config, record);
+    if let (Some(path), Some(entries)) = (&config.record, recorded)
+        && let Err(e) = write_replay(path, &config.replay_header(), &entries)
+    {
+        eprintln!("record: could not write {}: {e}", path.display());
+    }
+    report
+}
+
+/// Run several headless simulations in parallel, one thread per config,
+/// for parameter sweeps. Each run builds its own `App` on its own thread;
+/// they share only the process-wide ontology (see
+/// [`crate::agent::mind::knowledge::shared_ontology`]). Reports come back
+/// in the order of `configs`, and each matches what `run_headless` returns
+/// for the same config.
+pub fn run_headless_batch(configs: Vec<HeadlessConfig>) -> Vec<HeadlessReport> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = configs
+            .into_iter()
+            .map(|config| scope.spawn(move || run_headless(config)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("headless batch run panicked"))
+            .collect()
+    })
+}
+
+/// Result of re-running a recorded headless simulation.
+#[derive(Debug, Clone)]
+pub struct ReplayOutcome {
+    pub header: ReplayHeader,
+    /// First mismatch between the recording and the fresh run, or `None`
+    /// when the run reproduced exactly.
+    pub divergence: Option<ReplayDivergence>,
+    pub report: HeadlessReport,
+}
+
+/// Rebuild the run described by a `--record` file and check the fresh run
+/// against the recorded stream, tick by tick. The seeded RNG re-feeds the
+/// same draws; the recording is the oracle that says whether it did.
+pub fn replay_headless(path: &Path) -> std::io::Result<ReplayOutcome> {
+    let (header, expected) = read_replay(path)?;
+    let config = HeadlessConfig::from_replay_header(&header);
+    let (report, actual) = run_recorded(&config, true);
+    let divergence = first_divergence(&expected, &actual.unwrap_or_default());
+    Ok(ReplayOutcome {
+        header,
+        divergence,
+        report,
+    })
+}
+
+/// Shared body of `run_headless` and `replay_headless`. Returns the report
+/// plus the replay stream when `record` is set.
+fn run_recorded(
+    config: &HeadlessConfig,
+    record: bool,
+) -> (HeadlessReport, Option<Vec<ReplayEntry>>) {
     let mut world = if config.game_defaults {
         TestWorld::with_game_map(config.seed)
     } else {
@@ -259,6 +380,28 @@ pub fn run_headless(config: HeadlessConfig) -> HeadlessReport {
             .add_systems(bevy::app::Last, print_perf_table);
     }
 
+    if record {
+        // Recording and replaying both need query results in a canonical
+        // order, or HashMap iteration can steer the two runs apart.
+        world
+            .app_mut()
+            .insert_resource(crate::agent::mind::knowledge::MindQueryConfig { stable_order: true });
+        world.app_mut().init_resource::<ReplayRecorder>();
+        world.app_mut().add_systems(bevy::app::Last, record_replay);
+    }
+
+    // Autosave snapshots the replay stream, so it rides on recording.
+    if let Some(autosave_config) = &config.autosave {
+        world.app_mut().insert_resource(autosave_config.clone());
+        world
+            .app_mut()
+            .insert_resource(AutosaveSource(config.replay_header()));
+        world.app_mut().init_resource::<AutosaveState>();
+        world
+            .app_mut()
+            .add_systems(bevy::app::Last, autosave.after(record_replay));
+    }
+
     // Suppress GameLog stdout noise when inspection flags are active,
     // so --inspect / --why output isn't buried under brain traces.
     if config.inspect.is_active() {
@@ -269,7 +412,7 @@ pub fn run_headless(config: HeadlessConfig) -> HeadlessReport {
             .quiet = true;
     }
 
-    let spawned = populate(&mut world, &config);
+    let spawned = populate(&mut world, config);
 
     let start = Instant::now();
 
@@ -321,7 +464,28 @@ pub fn run_headless(config: HeadlessConfig) -> HeadlessReport {
         run_inspection(&mut world, &config.inspect);
     }
 
-    collect_report(&mut world, &config, spawned, elapsed)
+    if let Some(mut state) = world
+        .app_mut()
+        .world_mut()
+        .get_resource_mut::<AutosaveState>()
+    {
+        state.finish();
+    }
+
+    let recorded = record.then(|| {
+        std::mem::take(
+            &mut world
+                .app_mut()
+                .world_mut()
+                .resource_mut::<ReplayRecorder>()
+                .entries,
+        )
+    });
+
+    (
+        collect_report(&mut world, config, spawned, elapsed),
+        recorded,
+    )
 }
 
 /// Execute all inspection commands against the current world state.
@@ -407,6 +571,21 @@ fn run_inspection(world: &mut TestWorld, inspect: &InspectConfig) {
             None => eprintln!("dump-all: no agent matching {selector:?} found"),
         }
     }
+
+    for q in &inspect.belief_censuses {
+        let subject =
+            parse_subject(&q.subject).or_else(|| world.find_agent(&q.subject).map(Node::Entity));
+        let (Some(subject), Some(predicate)) = (subject, parse_predicate(&q.predicate)) else {
+            eprintln!(
+                "belief-census: can't resolve {:?} {:?}",
+                q.subject, q.predicate
+            );
+            continue;
+        };
+        let tick = world.current_tick();
+        let census = world.belief_census(&subject, predicate);
+        eprintln!("{}", census.to_json(tick));
+    }
 }
 
 /// Spawns the configured population into the TestWorld using `WorldSpawnConfig`.
@@ -440,6 +619,7 @@ fn populate(world: &mut TestWorld, config: &HeadlessConfig) -> u64 {
         spawn_config.compute_layout(map)
     };
     world.apply_spawn_layout(&layout);
+    world.app_mut().insert_resource(spawn_config.ambient);
 
     (config.humans + config.deer + config.wolves) as u64
 }
@@ -635,6 +815,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn batch_runs_differ_by_config_and_match_sequential_runs() {
+        let small = HeadlessConfig {
+            ticks: 60,
+            seed: 3,
+            humans: 2,
+            deer: 0,
+            berry_bushes: 2,
+            apple_trees: 0,
+            ..Default::default()
+        };
+        let large = HeadlessConfig {
+            seed: 4,
+            humans: 5,
+            deer: 2,
+            ..small.clone()
+        };
+
+        let reports = run_headless_batch(vec![small.clone(), large]);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].agents.spawned, 2);
+        assert_eq!(reports[1].agents.spawned, 7);
+        assert_eq!((reports[0].seed, reports[1].seed), (3, 4));
+
+        // Running on a worker thread alongside another world changes nothing.
+        let sequential = run_headless(small);
+        assert_eq!(reports[0].agents.alive, sequential.agents.alive);
+        assert_eq!(
+            reports[0].physical_means.hunger,
+            sequential.physical_means.hunger
+        );
+    }
+
+    #[test]
+    fn recorded_run_replays_to_identical_final_state() {
+        let path = std::env::temp_dir().join(format!(
+            "worldsim-replay-{}-{}.jsonl",
+            std::process::id(),
+            line!()
+        ));
+        let config = HeadlessConfig {
+            ticks: 120,
+            seed: 7,
+            humans: 3,
+            deer: 1,
+            berry_bushes: 2,
+            apple_trees: 1,
+            record: Some(path.clone()),
+            ..Default::default()
+        };
+        let original = run_headless(config);
+
+        let outcome = replay_headless(&path).expect("replay file should load");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(outcome.header.seed, 7);
+        assert_eq!(outcome.divergence, None);
+        assert_eq!(outcome.report.agents.alive, original.agents.alive);
+        assert_eq!(
+            outcome.report.physical_means.hunger,
+            original.physical_means.hunger
+        );
+    }
+
+    #[test]
+    fn autosave_rotates_slots_at_the_interval_and_each_slot_replays() {
+        let dir = std::env::temp_dir().join(format!(
+            "worldsim-autosave-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        let autosave_config = AutosaveConfig {
+            enabled: true,
+            interval_ticks: 40,
+            slots: 2,
+            directory: dir.to_string_lossy().into_owned(),
+        };
+        let config = HeadlessConfig {
+            ticks: 130,
+            seed: 11,
+            humans: 2,
+            deer: 1,
+            berry_bushes: 2,
+            apple_trees: 1,
+            autosave: Some(autosave_config.clone()),
+            ..Default::default()
+        };
+        run_headless(config);
+
+        // Saves at 40, 80, 120 over two slots: slot 0 was overwritten at 120.
+        let mut saved = Vec::new();
+        for slot in 0..2 {
+            let path = autosave_config.slot_path(slot);
+            let (header, entries) = read_replay(&path).expect("autosave slot should load");
+            assert!(entries.iter().all(|e| e.tick() <= header.ticks));
+            let outcome = replay_headless(&path).expect("autosave slot should replay");
+            assert_eq!(outcome.divergence, None, "slot {slot} diverged");
+            saved.push(header.ticks);
+        }
+        let leftovers = std::fs::read_dir(&dir).unwrap().count();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(saved, vec![120, 80]);
+        assert_eq!(leftovers, 2, "only the slot files, no temp files");
+    }
+
     #[test]
     fn perf_flag_populates_perf_stats_in_report() {
         let config = HeadlessConfig {
@@ -17,6 +17,7 @@ pub mod outline;
 pub mod palette;
 pub mod particles;
 pub mod silhouette;
+pub mod sim;
 pub mod testing;
 pub mod ui;
 pub mod world;
@@ -64,6 +64,32 @@ fn main() {
         return;
     }
 
+    if let Some(path) = &args.replay {
+        match headless::replay_headless(path) {
+            Ok(outcome) => match outcome.divergence {
+                None => println!(
+                    "Replay matched: {} ticks reproduced exactly (seed {})",
+                    outcome.header.ticks, outcome.header.seed
+                ),
+                Some(divergence) => {
+                    eprintln!(
+                        "Replay diverged at entry {} (tick {:?})\n  expected: {:?}\n  actual:   {:?}",
+                        divergence.index,
+                        divergence.tick(),
+                        divergence.expected,
+                        divergence.actual
+                    );
+                    std::process::exit(1);
+                }
+            },
+            Err(e) => {
+                eprintln!("--replay failed: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     if args.headless {
         let report = headless::run_headless(args.to_headless_config());
         if args.report {
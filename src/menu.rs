@@ -10,6 +10,7 @@ use bevy::prelude::*;
 use bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext, egui};
 use rand::Rng;
 
+use crate::agent::engagement::EngagementRegistry;
 use crate::agent::engagement::converse::ConverseRegistry;
 use crate::core::{SimRng, TickCount};
 use crate::world::spatial_index::SpatialIndex;
@@ -159,14 +160,20 @@ fn close_pause_menu(mut pause: ResMut<PauseMenuOpen>, tick: Option<ResMut<TickCo
 /// doesn't access stale generations from the previous sim. Without this, a
 /// second "New Simulation" crashes because systems like
 /// `evaluate_conversation_continuation` try to apply commands against
-/// despawned agents whose IDs were still in `ConverseRegistry`.
+/// despawned agents whose IDs were still in `ConverseRegistry`. The
+/// engagement id counter restarts too, so a replayed run mints the same
+/// conversation ids as the original.
 fn reset_sim_resources(
     mut conversations: Option<ResMut<ConverseRegistry>>,
+    mut engagement_ids: Option<ResMut<EngagementRegistry>>,
     mut spatial: Option<ResMut<SpatialIndex>>,
 ) {
     if let Some(conversations) = conversations.as_mut() {
         **conversations = ConverseRegistry::default();
     }
+    if let Some(engagement_ids) = engagement_ids.as_mut() {
+        **engagement_ids = EngagementRegistry::default();
+    }
     if let Some(spatial) = spatial.as_mut() {
         **spatial = SpatialIndex::default();
     }
@@ -627,16 +634,16 @@ mod tests {
     fn reset_sim_resources_clears_stale_conversation_entities() {
         let mut app = App::new();
         app.init_resource::<ConverseRegistry>();
+        app.init_resource::<EngagementRegistry>();
         app.init_resource::<SpatialIndex>();
 
         // Seed the conversation manager with a fake participant — after
         // DespawnOnExit runs, its Entity id would be stale.
         let fake_entity = app.world_mut().spawn_empty().id();
         {
-            use crate::agent::engagement::EngagementId;
             use crate::agent::engagement::converse::Conversation;
+            let id = app.world_mut().resource_mut::<EngagementRegistry>().mint();
             let mut conv = app.world_mut().resource_mut::<ConverseRegistry>();
-            let id = EngagementId(0);
             conv.conversations
                 .insert(id, Conversation::new(id, vec![fake_entity], 0));
         }
@@ -655,5 +662,10 @@ mod tests {
                 .is_empty(),
             "ConverseRegistry should be empty after reset"
         );
+        assert_eq!(
+            app.world_mut().resource_mut::<EngagementRegistry>().mint(),
+            crate::agent::engagement::EngagementId(0),
+            "engagement ids should restart after reset"
+        );
     }
 }
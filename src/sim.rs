@@ -0,0 +1,8 @@
+//! Read-only facade over the running simulation, for tests and scripts.
+//!
+//! Reads: agent components, SocialGraph, MindGraph (via `query`)
+//! Writes: nothing
+//! Upstream: agent (components and resources the facade wraps)
+//! Downstream: integration tests, scripted scenario assertions
+
+pub mod query;
@@ -0,0 +1,55 @@
+//! Query helpers: ask the world about agents without knowing which
+//! component or resource holds the answer.
+//!
+//! Reads: Agent, PlanMemory, MindGraph, SocialGraph
+//! Writes: nothing
+//! Upstream: brains::plan_memory, mind::knowledge, psyche::social_graph
+//! Downstream: integration tests (`TestWorld::app().world()`), scripting
+//!
+//! Every function takes a plain Bevy `World`, so the same calls work on a
+//! `TestWorld` and on a live app. Signatures here are meant to stay put
+//! while the components underneath move around.
+
+use bevy::prelude::*;
+
+use crate::agent::Agent;
+use crate::agent::brains::plan_memory::PlanMemory;
+use crate::agent::brains::thinking::Goal;
+use crate::agent::mind::knowledge::{MindGraph, Node, Predicate, Value};
+use crate::agent::psyche::social_graph::SocialGraph;
+
+/// Agents holding at least one plan — in any state — whose goal matches
+/// `predicate`. Sorted so callers can compare against a fixed list.
+pub fn agents_with_goal(world: &mut World, predicate: impl Fn(&Goal) -> bool) -> Vec<Entity> {
+    let mut query = world.query_filtered::<(Entity, &PlanMemory), With<Agent>>();
+    let mut agents: Vec<Entity> = query
+        .iter(world)
+        .filter(|(_, memory)| memory.plans.iter().any(|plan| predicate(&plan.goal)))
+        .map(|(entity, _)| entity)
+        .collect();
+    agents.sort();
+    agents
+}
+
+/// `(trust, affection)` on the directed edge `a → b`. Pairs that never met
+/// read as the social graph's neutral defaults.
+pub fn relationship(world: &World, a: Entity, b: Entity) -> (f32, f32) {
+    let graph = world.resource::<SocialGraph>();
+    (graph.trust(a, b), graph.affection(a, b))
+}
+
+/// True when `entity`'s MindGraph holds the exact triple
+/// `(subject, predicate, object)`. False for entities without a mind.
+pub fn belief_exists(
+    world: &World,
+    entity: Entity,
+    subject: &Node,
+    predicate: Predicate,
+    object: &Value,
+) -> bool {
+    world.get::<MindGraph>(entity).is_some_and(|mind| {
+        !mind
+            .query(Some(subject), Some(predicate), Some(object))
+            .is_empty()
+    })
+}
@@ -5,6 +5,7 @@
 //! Upstream: nothing
 //! Downstream: testing::world::TestWorld::spawn_agent
 
+use crate::agent::archetype::Archetype;
 use crate::agent::body::genetics::genome::Genome;
 use crate::agent::body::metabolism::Metabolism;
 use crate::agent::culture::Culture;
@@ -61,6 +62,9 @@ pub struct AgentConfig {
     /// carry whatever metadata the caller put on them — `Source::Experienced`,
     /// `Source::Reported`, etc.
     pub knowledge: Vec<Triple>,
+    /// Optional designed starting profile (personality skew, skills,
+    /// food-location memories). `None` spawns a plain member of `culture`.
+    pub archetype: Option<Archetype>,
 }
 
 impl Default for AgentConfig {
@@ -79,6 +83,7 @@ impl Default for AgentConfig {
             genome: Genome::default(),
             culture: Culture::default(),
             knowledge: Vec::new(),
+            archetype: None,
         }
     }
 }
@@ -152,6 +157,11 @@ impl AgentConfig {
         self
     }
 
+    pub fn with_archetype(mut self, archetype: Archetype) -> Self {
+        self.archetype = Some(archetype);
+        self
+    }
+
     /// Starve the agent: empty stomach and depleted blood glucose so
     /// Hunger urgency dominates. Equivalent to `with_metabolism(Metabolism::empty())`.
     pub fn hungry(self) -> Self {
@@ -11,6 +11,7 @@ use std::ops::Index;
 use bevy::math::Vec2;
 use bevy::prelude::*;
 
+use crate::agent::archetype::Archetype;
 use crate::agent::body::genetics::genome::Genome;
 use crate::agent::mind::knowledge::Triple;
 use crate::testing::config::AgentConfig;
@@ -19,11 +20,13 @@ use crate::world::map::{TileType, WorldMap};
 
 // ─── RelBuilder ───────────────────────────────────────────────────────────
 
-/// Fluent builder for relationship dimensions. All dimensions start at 0.5.
+/// Fluent builder for relationship dimensions. Trust / affection / respect
+/// start at 0.5, power balance at 0.0 (equals).
 pub struct RelBuilder {
     pub trust: f32,
     pub affection: f32,
     pub respect: f32,
+    pub power_balance: f32,
 }
 
 impl Default for RelBuilder {
@@ -32,6 +35,7 @@ impl Default for RelBuilder {
             trust: 0.5,
             affection: 0.5,
             respect: 0.5,
+            power_balance: 0.0,
         }
     }
 }
@@ -51,6 +55,12 @@ impl RelBuilder {
         self.respect = v.clamp(0.0, 1.0);
         self
     }
+
+    /// -1.0 = subordinate to the other agent, +1.0 = dominant over them.
+    pub fn power_balance(mut self, v: f32) -> Self {
+        self.power_balance = v.clamp(-1.0, 1.0);
+        self
+    }
 }
 
 // ─── Internal config structs ───────────────────────────────────────────────
@@ -65,6 +75,7 @@ struct AgentSpec {
     social_drive: Option<f32>,
     group: Option<String>,
     knowledge: Vec<Triple>,
+    archetype: Option<Archetype>,
 }
 
 struct GroupSpec {
@@ -83,6 +94,7 @@ struct RelationshipSpec {
     trust: f32,
     affection: f32,
     respect: f32,
+    power_balance: f32,
 }
 
 struct TileEdit {
@@ -169,6 +181,12 @@ impl AgentBuilder {
         self
     }
 
+    /// Spawn this agent with a designed starting profile.
+    pub fn archetype(mut self, archetype: Archetype) -> Self {
+        self.spec.archetype = Some(archetype);
+        self
+    }
+
     /// Finish agent configuration and return to the parent `ScenarioBuilder`.
     pub fn done(mut self) -> ScenarioBuilder {
         self.parent.agents.push(self.spec);
@@ -333,6 +351,7 @@ impl ScenarioBuilder {
                 social_drive: None,
                 group: None,
                 knowledge: Vec::new(),
+                archetype: None,
             },
         }
     }
@@ -354,6 +373,7 @@ impl ScenarioBuilder {
             trust: rel.trust,
             affection: rel.affection,
             respect: rel.respect,
+            power_balance: rel.power_balance,
         });
         self
     }
@@ -549,6 +569,7 @@ fn spawn_named_agent(world: &mut TestWorld, spec: &AgentSpec) -> Entity {
         social_drive: spec.social_drive,
         genome: spec.genome.clone().unwrap_or_default(),
         knowledge: spec.knowledge.clone(),
+        archetype: spec.archetype,
         ..Default::default()
     };
     world.spawn_agent(config)
@@ -608,6 +629,7 @@ fn apply_relationship(world: &mut TestWorld, a: Entity, b: Entity, spec: &Relati
                 affection: spec.affection,
                 trust: spec.trust,
                 respect: spec.respect,
+                power_balance: spec.power_balance,
                 ..Default::default()
             },
         );
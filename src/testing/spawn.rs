@@ -77,8 +77,10 @@ pub(super) fn spawn_test_person(
                 .with_warmth(config.warmth)
                 .with_rest_quality(config.rest_quality)
                 .with_food_security(config.food_security),
+            culture: config.culture,
             cultural_knowledge,
             extra_knowledge,
+            archetype: config.archetype,
         },
         ontology,
     );
@@ -96,6 +98,9 @@ pub(super) fn spawn_test_person(
     if let Some(v) = social_drive_override {
         world.entity_mut(entity).insert(SocialDriveOverride(v));
     }
+    if let Some(archetype) = config.archetype {
+        world.entity_mut(entity).insert(archetype);
+    }
 
     entity
 }
@@ -142,6 +147,7 @@ pub(super) fn spawn_test_deer(
             crate::agent::mind::explored_tiles::ExploredTiles::default(),
             Vision {
                 range: SpeciesProfile::deer().vision_range,
+                fov_degrees: SpeciesProfile::deer().fov_degrees,
             },
             VisibleObjects::default(),
         ))
@@ -204,6 +210,7 @@ pub(super) fn spawn_test_wolf(
             crate::agent::mind::explored_tiles::ExploredTiles::default(),
             Vision {
                 range: SpeciesProfile::wolf().vision_range,
+                fov_degrees: SpeciesProfile::wolf().fov_degrees,
             },
             VisibleObjects::default(),
         ))
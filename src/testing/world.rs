@@ -18,7 +18,10 @@ use crate::agent::brains::proposal::BrainState;
 use crate::agent::engagement::Engaged;
 use crate::agent::engagement::converse::ConverseRegistry;
 use crate::agent::events::{SimEvent, SimEventKind};
-use crate::agent::mind::knowledge::{Concept, MindGraph, Ontology, setup_ontology};
+use crate::agent::mind::census::{BeliefCensus, belief_census};
+use crate::agent::mind::knowledge::{
+    Concept, MindGraph, Node, Ontology, Predicate, shared_ontology,
+};
 use crate::agent::psyche::emotions::EmotionalState;
 use crate::core::tick::TickCount;
 use crate::core::{GameLog, GameTime};
@@ -262,6 +265,19 @@ fn format_sim_event(event: &SimEvent) -> String {
              dim={dimension:?} {old_value:.3}->{new_value:.3}"
         ),
 
+        SimEvent {
+            tick,
+            kind:
+                SimEventKind::RelationshipFormed {
+                    agent,
+                    other,
+                    category,
+                },
+            ..
+        } => format!(
+            "[t{tick}] RelationshipFormed agent={agent:?} other={other:?} category={category:?}"
+        ),
+
         SimEvent {
             tick,
             kind:
@@ -758,6 +774,7 @@ impl TestWorld {
             config.compute_layout(map)
         };
         world.apply_spawn_layout(&layout);
+        world.app_mut().insert_resource(config.ambient);
         world
     }
 
@@ -784,13 +801,14 @@ impl TestWorld {
         // Resources normally provided by plugins we deliberately exclude:
         // - SpawnerPlugin (Ontology, plus startup population we don't want)
         // - MapPlugin (WorldMap, plus tile sprite spawning)
-        // - EnvironmentPlugin (LightLevel, plus ClearColor manipulation)
+        // - EnvironmentPlugin (LightLevel/DayCycle, plus ClearColor manipulation)
         // - CorePlugin (TickCount/GameLog/GameTime, plus keyboard time controls)
         app.insert_resource(Time::<Fixed>::from_hz(60.0));
-        app.insert_resource(setup_ontology());
+        app.insert_resource(shared_ontology());
         app.insert_resource(map);
         app.insert_resource(LightLevel(1.0));
         app.init_resource::<crate::world::environment::ColorTint>();
+        app.init_resource::<crate::world::environment::DayCycle>();
         app.add_plugins(crate::palette::PalettePlugin);
         app.add_systems(FixedUpdate, crate::world::environment::update_light_level);
         app.insert_resource(TickCount::new(60.0));
@@ -803,16 +821,29 @@ impl TestWorld {
         app.add_systems(Last, collect_sim_events_into_log);
 
         app.add_systems(FixedFirst, deterministic_tick);
+        app.add_systems(FixedLast, crate::core::tick::finish_step);
 
         app.add_plugins(AgentPlugin);
 
         app.add_systems(FixedUpdate, crate::world::apple_tree::regenerate_resources);
         app.add_systems(FixedUpdate, crate::world::sapling::grow_saplings);
+        app.init_resource::<crate::world::ambient_spawn::AmbientSpawnConfig>();
+        app.add_systems(FixedUpdate, crate::world::ambient_spawn::ambient_spawn);
 
         app.add_plugins(crate::world::property::OntologyDerivationPlugin);
         app.add_plugins(crate::world::field_grid_plugin::FieldGridPlugin);
+        app.add_plugins(crate::world::ground_item::GroundItemPlugin);
+        app.add_plugins(crate::world::corpse::CorpsePlugin);
+        app.add_plugins(crate::world::weather::WeatherPlugin);
+        // Scenarios run under a fixed sky so weather rolls don't perturb
+        // their outcomes; tests that exercise weather unlock it.
+        app.insert_resource(crate::world::weather::WeatherConfig {
+            locked: true,
+            ..default()
+        });
         app.init_resource::<crate::world::forecast::WorldForecast>();
         app.init_resource::<crate::world::entity_positions::WorldEntityPositions>();
+        app.init_resource::<crate::world::pathfinding::PathfindingConfig>();
         app.add_systems(
             FixedUpdate,
             crate::world::entity_positions::update_world_entity_positions,
@@ -1020,6 +1051,19 @@ impl TestWorld {
         spawn_test_wood_log(self.app.world_mut(), pos, wood)
     }
 
+    /// Drops `count` fresh Things of `concept` on the ground at `pos`.
+    pub fn spawn_ground_item(&mut self, pos: Vec2, concept: Concept, count: u32) -> Entity {
+        let things = (0..count)
+            .map(|_| crate::agent::item_slots::Thing::new(concept))
+            .collect();
+        self.app
+            .world_mut()
+            .spawn(crate::world::ground_item::ground_item_components(
+                pos, things,
+            ))
+            .id()
+    }
+
     /// Spawns a campfire (logic-only) at the given position. Includes LightSource and HeatSource.
     pub fn spawn_campfire(&mut self, pos: Vec2) -> Entity {
         self.app
@@ -1066,7 +1110,11 @@ impl TestWorld {
             .world_mut()
             .spawn((
                 crate::world::Physical,
-                crate::world::sense_sources::SoundSource { kind, intensity },
+                crate::world::sense_sources::SoundSource {
+                    kind,
+                    intensity,
+                    species: None,
+                },
                 Transform::from_translation(pos.extend(0.0)),
                 GlobalTransform::default(),
             ))
@@ -1144,6 +1192,9 @@ impl TestWorld {
         for &(pos, wood) in &layout.wood_log_positions {
             self.spawn_wood_log(pos, wood);
         }
+        if let Some(biomes) = &layout.biomes {
+            self.app.world_mut().insert_resource(biomes.clone());
+        }
     }
 
     /// Sets a tile type at the given tile coordinates.
@@ -2152,6 +2203,14 @@ impl TestWorld {
         print_section_footer();
     }
 
+    /// How the `(subject, predicate, ?)` belief is distributed across every
+    /// agent's MindGraph. See [`crate::agent::mind::census`].
+    pub fn belief_census(&mut self, subject: &Node, predicate: Predicate) -> BeliefCensus {
+        let world = self.app.world_mut();
+        let mut query = world.query_filtered::<&MindGraph, With<Agent>>();
+        belief_census(subject, predicate, query.iter(world))
+    }
+
     /// Search the agent's full MindGraph (ontology + shared + personal) for
     /// triples whose subject, predicate, or object Debug representation contains
     /// `query` (case-insensitive substring match). Returns formatted strings.
@@ -2491,7 +2550,7 @@ pub(super) fn make_walkable_map(width: u32, height: u32) -> WorldMap {
 /// `game_seconds_per_cycle` game-seconds per FixedMain cycle, regardless of
 /// real-time delta. Also drives GameTime.
 fn deterministic_tick(mut tick: ResMut<TickCount>, mut game_time: ResMut<GameTime>) {
-    if tick.paused {
+    if tick.paused && !tick.stepping {
         return;
     }
     let step = tick.game_seconds_per_cycle;
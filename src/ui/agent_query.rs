@@ -0,0 +1,380 @@
+//! Agent query overlay: highlight every agent matching a typed query.
+//!
+//! Reads: AgentQueryState (query text), Agent (PhysicalNeeds, EmotionalState,
+//!        MindGraph, Transform)
+//! Writes: AgentQueryState (parsed query, parse error, matching agents), gizmos
+//! Upstream: the query bar in the controls panel and Settings dock tab
+//! Downstream: Bevy renderer (highlight rings)
+//!
+//! The game view shows where agents are, not what they are thinking. A
+//! query like `knows food and hunger > 0.5` is parsed into clauses over
+//! the agent's needs, emotions and beliefs, re-evaluated every frame, and
+//! every agent it matches gets a ring. Clauses are joined with `and`, each
+//! optionally prefixed with `not`:
+//!
+//! - `hungry`, `stressed`, `afraid` — shorthands for the comparisons below
+//! - `hunger|mood|stress|fear <op> <number>` with `<`, `<=`, `>`, `>=`
+//! - `knows <concept>` (or `knows where <concept> is`) — believes where
+//!   some `<concept>`, or something that produces one, is located
+
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, DynamicVariant, FromReflect, TypeInfo, Typed};
+use bevy_egui::egui;
+
+use crate::agent::Agent;
+use crate::agent::body::needs::PhysicalNeeds;
+use crate::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use crate::agent::psyche::emotions::{EmotionType, EmotionalState};
+use crate::world::map::TILE_SIZE;
+
+const HIGHLIGHT_COLOR: Color = Color::srgba(1.0, 0.2, 0.9, 0.9);
+const HIGHLIGHT_RADIUS: f32 = TILE_SIZE * 0.75;
+
+pub struct AgentQueryPlugin;
+
+impl Plugin for AgentQueryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AgentQueryState>().add_systems(
+            Update,
+            (update_query_matches, draw_query_highlights).chain(),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    /// `PhysicalNeeds::hunger_urgency`, 0..1.
+    Hunger,
+    /// `EmotionalState::current_mood`, -1..1.
+    Mood,
+    /// `EmotionalState::stress_level`, 0..100.
+    Stress,
+    /// Fear intensity, 0..1.
+    Fear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Clause {
+    Compare(Field, Comparison, f32),
+    KnowsWhere(Concept),
+    Not(Box<Clause>),
+}
+
+/// A parsed query: every clause must hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentQuery(pub Vec<Clause>);
+
+/// What an agent looks like to a query. Missing components fail any
+/// clause that needs them.
+pub struct QuerySubject<'a> {
+    pub needs: Option<&'a PhysicalNeeds>,
+    pub emotions: Option<&'a EmotionalState>,
+    pub mind: Option<&'a MindGraph>,
+}
+
+impl AgentQuery {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let lowered = text.trim().to_lowercase();
+        if lowered.is_empty() {
+            return Err("empty query".to_string());
+        }
+        lowered
+            .split(" and ")
+            .map(|clause| parse_clause(&clause.split_whitespace().collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(AgentQuery)
+    }
+
+    pub fn matches(&self, subject: &QuerySubject) -> bool {
+        self.0.iter().all(|clause| clause.holds(subject))
+    }
+}
+
+impl Clause {
+    fn holds(&self, subject: &QuerySubject) -> bool {
+        match self {
+            Clause::Compare(field, comparison, threshold) => {
+                let value = match field {
+                    Field::Hunger => subject.needs.map(PhysicalNeeds::hunger_urgency),
+                    Field::Mood => subject.emotions.map(|e| e.current_mood),
+                    Field::Stress => subject.emotions.map(|e| e.stress_level),
+                    Field::Fear => subject
+                        .emotions
+                        .map(|e| e.get_emotion_intensity(EmotionType::Fear)),
+                };
+                value.is_some_and(|value| match comparison {
+                    Comparison::Less => value < *threshold,
+                    Comparison::LessOrEqual => value <= *threshold,
+                    Comparison::Greater => value > *threshold,
+                    Comparison::GreaterOrEqual => value >= *threshold,
+                })
+            }
+            Clause::KnowsWhere(concept) => {
+                subject.mind.is_some_and(|mind| knows_where(mind, *concept))
+            }
+            Clause::Not(inner) => !inner.holds(subject),
+        }
+    }
+}
+
+fn parse_clause(words: &[&str]) -> Result<Clause, String> {
+    let at_least = |field, threshold| {
+        Ok(Clause::Compare(
+            field,
+            Comparison::GreaterOrEqual,
+            threshold,
+        ))
+    };
+    match words {
+        ["not", rest @ ..] => Ok(Clause::Not(Box::new(parse_clause(rest)?))),
+        ["hungry"] => at_least(Field::Hunger, 0.5),
+        ["stressed"] => at_least(Field::Stress, 50.0),
+        ["afraid"] => at_least(Field::Fear, 0.5),
+        ["knows", concept] | ["knows", "where", concept, "is" | "are"] => parse_concept(concept)
+            .map(Clause::KnowsWhere)
+            .ok_or_else(|| format!("unknown concept '{concept}'")),
+        [field, op, number] => {
+            let field = match *field {
+                "hunger" => Field::Hunger,
+                "mood" => Field::Mood,
+                "stress" => Field::Stress,
+                "fear" => Field::Fear,
+                other => return Err(format!("unknown field '{other}'")),
+            };
+            let comparison = match *op {
+                "<" => Comparison::Less,
+                "<=" => Comparison::LessOrEqual,
+                ">" => Comparison::Greater,
+                ">=" => Comparison::GreaterOrEqual,
+                other => return Err(format!("unknown comparison '{other}'")),
+            };
+            let threshold = number
+                .parse()
+                .map_err(|_| format!("'{number}' is not a number"))?;
+            Ok(Clause::Compare(field, comparison, threshold))
+        }
+        [] => Err("empty clause".to_string()),
+        other => Err(format!("can't read '{}'", other.join(" "))),
+    }
+}
+
+/// Look a `Concept` variant up by name, ignoring case.
+fn parse_concept(name: &str) -> Option<Concept> {
+    let TypeInfo::Enum(info) = Concept::type_info() else {
+        return None;
+    };
+    let variant = info.iter().find(|v| v.name().eq_ignore_ascii_case(name))?;
+    Concept::from_reflect(&DynamicEnum::new(variant.name(), DynamicVariant::Unit))
+}
+
+/// Whether `mind` locates some entity that is a `concept`, or whose kind
+/// produces one (a berry bush is where food is).
+fn knows_where(mind: &MindGraph, concept: Concept) -> bool {
+    mind.query(None, Some(Predicate::LocatedAt), None)
+        .into_iter()
+        .any(|located| {
+            if !matches!(located.subject, Node::Entity(_)) {
+                return false;
+            }
+            mind.is_a(&located.subject, concept)
+                || mind.all_types(&located.subject).into_iter().any(|kind| {
+                    mind.query(Some(&Node::Concept(kind)), Some(Predicate::Produces), None)
+                        .into_iter()
+                        .any(|t| match t.object {
+                            Value::Item(item, _) | Value::Concept(item) => {
+                                mind.is_a(&Node::Concept(item), concept)
+                            }
+                            _ => false,
+                        })
+                })
+        })
+}
+
+#[derive(Resource, Default)]
+pub struct AgentQueryState {
+    /// Text in the query bar. Empty = no highlighting.
+    pub text: String,
+    pub error: Option<String>,
+    /// Agents matching the last successfully parsed query.
+    pub matches: Vec<Entity>,
+    parsed: Option<(String, AgentQuery)>,
+}
+
+/// Bevy system (Update): re-parse the query when its text changed and
+/// re-evaluate it against every agent.
+pub fn update_query_matches(
+    mut state: ResMut<AgentQueryState>,
+    agents: Query<
+        (
+            Entity,
+            Option<&PhysicalNeeds>,
+            Option<&EmotionalState>,
+            Option<&MindGraph>,
+        ),
+        With<Agent>,
+    >,
+) {
+    let state = &mut *state;
+    if state.text.trim().is_empty() {
+        state.parsed = None;
+        state.error = None;
+        state.matches.clear();
+        return;
+    }
+    if state
+        .parsed
+        .as_ref()
+        .is_none_or(|(text, _)| *text != state.text)
+    {
+        match AgentQuery::parse(&state.text) {
+            Ok(query) => {
+                state.parsed = Some((state.text.clone(), query));
+                state.error = None;
+            }
+            Err(e) => {
+                // Keep highlighting by the last good query while typing.
+                state.error = Some(e);
+                return;
+            }
+        }
+    }
+    let Some((_, query)) = &state.parsed else {
+        return;
+    };
+    state.matches = agents
+        .iter()
+        .filter(|(_, needs, emotions, mind)| {
+            query.matches(&QuerySubject {
+                needs: *needs,
+                emotions: *emotions,
+                mind: *mind,
+            })
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+}
+
+fn draw_query_highlights(
+    mut gizmos: Gizmos,
+    state: Res<AgentQueryState>,
+    transforms: Query<&Transform, With<Agent>>,
+) {
+    for transform in transforms.iter_many(&state.matches) {
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            HIGHLIGHT_RADIUS,
+            HIGHLIGHT_COLOR,
+        );
+    }
+}
+
+/// Render the query bar. Shared by the left controls panel and the
+/// Settings dock tab.
+pub fn query_bar(ui: &mut egui::Ui, state: &mut AgentQueryState) {
+    ui.add(egui::TextEdit::singleline(&mut state.text).hint_text("e.g. knows food and hungry"));
+    if let Some(error) = &state.error {
+        ui.colored_label(egui::Color32::LIGHT_RED, error);
+    } else if !state.text.trim().is_empty() {
+        ui.label(format!("{} matching", state.matches.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::body::metabolism::Metabolism;
+    use crate::agent::mind::knowledge::{Metadata, Triple, setup_ontology};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn highlighted_agents_are_exactly_those_matching_the_query() {
+        let mut ontology = setup_ontology();
+        ontology.ensure_production(Concept::BerryBush, Concept::Berry);
+        let mut world = World::new();
+        let bush = world.spawn_empty().id();
+        let rock = world.spawn_empty().id();
+
+        let mut agents = Vec::new();
+        for (knows, sees, hungry) in [
+            (true, bush, true),
+            (true, bush, false),
+            (false, rock, true),
+            (false, rock, false),
+        ] {
+            let mut mind = MindGraph::new(ontology.clone());
+            let kind = if knows {
+                Concept::BerryBush
+            } else {
+                Concept::Stone
+            };
+            mind.assert(Triple::with_meta(
+                Node::Entity(sees),
+                Predicate::IsA,
+                Value::Concept(kind),
+                Metadata::perception(0),
+            ));
+            mind.assert(Triple::with_meta(
+                Node::Entity(sees),
+                Predicate::LocatedAt,
+                Value::Tile((3, 4)),
+                Metadata::perception(0),
+            ));
+            let urgency = if hungry { 0.8 } else { 0.1 };
+            let needs = PhysicalNeeds::default().with_metabolism(Metabolism::at_urgency(urgency));
+            agents.push(world.spawn((Agent, mind, needs)).id());
+        }
+        // Not an agent: never highlighted, whatever it believes.
+        world.spawn((PhysicalNeeds::default(), MindGraph::new(setup_ontology())));
+
+        let mut state = AgentQueryState {
+            text: "knows where food is and hungry".to_string(),
+            ..Default::default()
+        };
+        world.insert_resource(state);
+        world.run_system_once(update_query_matches).unwrap();
+
+        let expected: Vec<Entity> = world
+            .query_filtered::<(Entity, &PhysicalNeeds, &MindGraph), With<Agent>>()
+            .iter(&world)
+            .filter(|(_, needs, mind)| {
+                knows_where(mind, Concept::Food) && needs.hunger_urgency() >= 0.5
+            })
+            .map(|(e, ..)| e)
+            .collect();
+        state = world.remove_resource::<AgentQueryState>().unwrap();
+        assert_eq!(state.error, None);
+        assert_eq!(state.matches, expected);
+        assert_eq!(state.matches, vec![agents[0]]);
+
+        state.text = "not knows food".to_string();
+        world.insert_resource(state);
+        world.run_system_once(update_query_matches).unwrap();
+        let state = world.resource::<AgentQueryState>();
+        assert_eq!(state.matches, vec![agents[2], agents[3]]);
+    }
+
+    #[test]
+    fn malformed_queries_report_an_error() {
+        assert!(AgentQuery::parse("hunger ~ 3").is_err());
+        assert!(AgentQuery::parse("knows unicorns").is_err());
+        assert_eq!(
+            AgentQuery::parse("Mood < -0.2 and not afraid"),
+            Ok(AgentQuery(vec![
+                Clause::Compare(Field::Mood, Comparison::Less, -0.2),
+                Clause::Not(Box::new(Clause::Compare(
+                    Field::Fear,
+                    Comparison::GreaterOrEqual,
+                    0.5
+                ))),
+            ]))
+        );
+    }
+}
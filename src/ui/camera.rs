@@ -58,28 +58,44 @@ pub fn cursor_to_world(
     camera.viewport_to_world_2d(camera_transform, cursor).ok()
 }
 
+/// Keeps the camera centred on the single selected entity. Toggled from
+/// the Settings tab or with `F`; idle whenever zero or several entities are
+/// selected, so clearing the selection hands the camera back.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct CameraFollow {
+    pub enabled: bool,
+}
+
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                camera_zoom,
-                camera_drag,
-                touchpad_pinch_zoom,
-                touchpad_pan,
-                // Follow runs after the manual pan/zoom systems so a held
-                // middle-click still wins the frame; releasing the button
-                // lets the lerp pull the camera back toward the player.
-                camera_follow_player.after(camera_drag).after(touchpad_pan),
+        app.register_type::<CameraFollow>()
+            .init_resource::<CameraFollow>()
+            .add_systems(
+                Update,
+                (
+                    camera_zoom,
+                    camera_drag,
+                    touchpad_pinch_zoom,
+                    touchpad_pan,
+                    toggle_camera_follow,
+                    // Follow runs after the manual pan/zoom systems so a held
+                    // middle-click still wins the frame; releasing the button
+                    // lets the lerp pull the camera back toward the player.
+                    camera_follow_player.after(camera_drag).after(touchpad_pan),
+                    camera_follow_selection
+                        .after(camera_drag)
+                        .after(touchpad_pan)
+                        .after(toggle_camera_follow),
+                )
+                    .run_if(crate::menu::sim_interactive),
             )
-                .run_if(crate::menu::sim_interactive),
-        )
-        .add_systems(
-            OnEnter(crate::menu::AppState::InSim),
-            apply_adventure_default_zoom.run_if(in_adventure_mode),
-        );
+            .add_systems(
+                OnEnter(crate::menu::AppState::InSim),
+                apply_adventure_default_zoom.run_if(in_adventure_mode),
+            );
     }
 }
 
@@ -126,6 +142,58 @@ fn camera_follow_player(
     camera.translation.y = next.y;
 }
 
+/// `F` flips `CameraFollow`, unless egui has keyboard focus (typing `f`
+/// into the highlight query shouldn't move the camera).
+fn toggle_camera_follow(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut follow: ResMut<CameraFollow>,
+    mut egui_ctxs: Query<&mut EguiContext, With<PrimaryEguiContext>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    if egui_ctxs
+        .single_mut()
+        .is_ok_and(|mut ctx| ctx.get_mut().wants_keyboard_input())
+    {
+        return;
+    }
+    follow.enabled = !follow.enabled;
+}
+
+/// Lerp the camera toward the single selected entity. The camera's
+/// viewport is clipped to the dock's game-view rect (`set_camera_viewport`),
+/// so centring the camera transform centres the entity in the game area,
+/// not the whole window. Same middle-click escape hatch as
+/// `camera_follow_player`.
+fn camera_follow_selection(
+    follow: Res<CameraFollow>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    ui_state: Option<Res<UiState>>,
+    targets: Query<&Transform, Without<Camera>>,
+    mut camera_q: Query<&mut Transform, With<Camera>>,
+) {
+    if !follow.enabled || buttons.pressed(MouseButton::Middle) {
+        return;
+    }
+    let Some(ui_state) = ui_state else {
+        return;
+    };
+    let &[selected] = ui_state.selected_entities.as_slice() else {
+        return;
+    };
+    let Ok(target) = targets.get(selected) else {
+        return;
+    };
+    let Ok(mut camera) = camera_q.single_mut() else {
+        return;
+    };
+    let current = camera.translation.truncate();
+    let next = follow_position(current, target.translation.truncate(), FOLLOW_ALPHA);
+    camera.translation.x = next.x;
+    camera.translation.y = next.y;
+}
+
 // Scroll Wheel Zoom (mouse only - skips trackpad pixel scrolling)
 fn camera_zoom(
     mut events: MessageReader<MouseWheel>,
@@ -37,6 +37,7 @@ use crate::agent::skills::{SkillKind, Skills};
 use crate::core::GameLog;
 use crate::core::tick::TickCount;
 
+use super::visual_style::{VisualStyle, visual_style};
 use super::{DebugUiEnabled, UiState};
 
 // ============================================================================
@@ -999,7 +1000,8 @@ fn render_overview(ui: &mut egui::Ui, world: &World, entity: Entity) {
             });
             ui.horizontal_wrapped(|ui| {
                 for emo in sorted.iter().take(4) {
-                    let (label, color) = emotion_label_color(emo.emotion_type, emo.intensity);
+                    let (label, color) =
+                        emotion_label_color(visual_style(world), emo.emotion_type, emo.intensity);
                     ui.colored_label(color, format!("{} {:.0}%", label, emo.intensity * 100.0));
                 }
             });
@@ -1056,7 +1058,7 @@ fn render_entity_feelings(ui: &mut egui::Ui, world: &World, entity: Entity) {
             let mut entries: Vec<_> = sums.into_iter().collect();
             entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
             for (etype, intensity) in entries {
-                let (label, color) = emotion_label_color(etype, intensity);
+                let (label, color) = emotion_label_color(visual_style(world), etype, intensity);
                 ui.colored_label(
                     color,
                     egui::RichText::new(format!("{} {:.0}%", label, intensity * 100.0)).small(),
@@ -2524,15 +2526,16 @@ fn render_inventory(ui: &mut egui::Ui, world: &World, entity: Entity) {
     // Things are now per-instance — group by concept for the count column,
     // and average freshness when present so the player sees "how good" the
     // pile is at a glance.
-    let mut grouped: std::collections::BTreeMap<String, (u32, f32, u32)> =
+    let style = visual_style(world);
+    let mut grouped: std::collections::BTreeMap<String, (Concept, u32, f32, u32)> =
         std::collections::BTreeMap::new();
     for thing in slots.all_items() {
         let key = format!("{:?}", thing.concept);
-        let entry = grouped.entry(key).or_insert((0, 0.0, 0));
-        entry.0 += 1;
+        let entry = grouped.entry(key).or_insert((thing.concept, 0, 0.0, 0));
+        entry.1 += 1;
         if let Some(f) = thing.properties.freshness {
-            entry.1 += f;
-            entry.2 += 1;
+            entry.2 += f;
+            entry.3 += 1;
         }
     }
 
@@ -2548,8 +2551,11 @@ fn render_inventory(ui: &mut egui::Ui, world: &World, entity: Entity) {
             ui.strong("Qty");
             ui.strong("Freshness");
             ui.end_row();
-            for (concept, (qty, fresh_sum, fresh_count)) in grouped {
-                ui.label(concept);
+            for (name, (concept, qty, fresh_sum, fresh_count)) in grouped {
+                ui.colored_label(
+                    style.concept_color(concept),
+                    format!("{} {name}", style.concept_icon(concept)),
+                );
                 ui.label(format!("{}", qty));
                 if fresh_count > 0 {
                     let avg = fresh_sum / fresh_count as f32;
@@ -2677,6 +2683,7 @@ fn activity_category_color(cat: crate::core::log::LogCategory) -> Color32 {
 ///   player can see "Survival wants Eat at 76, Emotional wants
 ///   Converse at 22, Rational wants Harvest at 60, Survival won".
 fn render_plans(ui: &mut egui::Ui, world: &World, entity: Entity) {
+    let style = visual_style(world);
     let brain = world.get::<BrainState>(entity);
     let memory = world.get::<PlanMemory>(entity);
     let personality = world.get::<Personality>(entity);
@@ -2686,14 +2693,14 @@ fn render_plans(ui: &mut egui::Ui, world: &World, entity: Entity) {
         ui.label(egui::RichText::new("Brain powers").strong());
         let p = &brain.powers;
         let total = (p.survival + p.emotional + p.rational).max(1.0);
-        power_bar(ui, BrainType::Survival, p.survival, total);
-        power_bar(ui, BrainType::Emotional, p.emotional, total);
-        power_bar(ui, BrainType::Rational, p.rational, total);
+        power_bar(ui, style, BrainType::Survival, p.survival, total);
+        power_bar(ui, style, BrainType::Emotional, p.emotional, total);
+        power_bar(ui, style, BrainType::Rational, p.rational, total);
 
         ui.horizontal(|ui| {
             ui.label(egui::RichText::new("Winner:").strong());
             if let Some(winner) = brain.winner {
-                ui.colored_label(brain_color(winner), winner.display_name());
+                ui.colored_label(style.brain(winner), winner.display_name());
             } else {
                 ui.label(egui::RichText::new("(none)").italics().color(Color32::GRAY));
             }
@@ -2788,7 +2795,7 @@ fn render_plans(ui: &mut egui::Ui, world: &World, entity: Entity) {
                     },
                     mark,
                 );
-                ui.colored_label(brain_color(prop.brain), prop.brain.display_name());
+                ui.colored_label(style.brain(prop.brain), prop.brain.display_name());
                 ui.label(egui::RichText::new(&prop.action.name).color(if admitted {
                     Color32::WHITE
                 } else {
@@ -2817,22 +2824,14 @@ fn render_plans(ui: &mut egui::Ui, world: &World, entity: Entity) {
     }
 }
 
-fn brain_color(brain: BrainType) -> Color32 {
-    match brain {
-        BrainType::Survival => Color32::from_rgb(230, 120, 100),
-        BrainType::Emotional => Color32::from_rgb(255, 160, 210),
-        BrainType::Rational => Color32::from_rgb(140, 200, 255),
-    }
-}
-
-fn power_bar(ui: &mut egui::Ui, brain: BrainType, value: f32, total: f32) {
+fn power_bar(ui: &mut egui::Ui, style: &VisualStyle, brain: BrainType, value: f32, total: f32) {
     let frac = (value / total).clamp(0.0, 1.0);
     ui.horizontal(|ui| {
         ui.add_sized([80.0, 0.0], egui::Label::new(brain.display_name()));
         ui.add(
             egui::ProgressBar::new(frac)
                 .desired_width(180.0)
-                .fill(brain_color(brain))
+                .fill(style.brain(brain))
                 .text(format!("{:.1}", value)),
         );
     });
@@ -2965,6 +2964,7 @@ fn render_brain(ui: &mut egui::Ui, world: &World, entity: Entity) {
         placeholder(ui, "(no brain state on this entity)");
         return;
     };
+    let style = visual_style(world);
     ui.heading("Arbitration Powers");
     ui.columns(3, |cols| {
         let p = &brain.powers;
@@ -2985,25 +2985,15 @@ fn render_brain(ui: &mut egui::Ui, world: &World, entity: Entity) {
     if let Some(winner) = brain.winner {
         ui.horizontal(|ui| {
             ui.label("Winner:");
-            let color = match winner {
-                BrainType::Survival => Color32::RED,
-                BrainType::Emotional => Color32::from_rgb(255, 105, 180),
-                BrainType::Rational => Color32::CYAN,
-            };
-            ui.colored_label(color, winner.display_name());
+            ui.colored_label(style.brain(winner), winner.display_name());
         });
     }
 
     ui.separator();
     ui.heading("Proposals");
     for prop in &brain.proposals {
-        let color = match prop.brain {
-            BrainType::Survival => Color32::LIGHT_RED,
-            BrainType::Emotional => Color32::from_rgb(255, 182, 193),
-            BrainType::Rational => Color32::LIGHT_BLUE,
-        };
         ui.colored_label(
-            color,
+            style.brain_proposal(prop.brain),
             format!(
                 "• {}: {} (urgency {:.1}) — {}",
                 prop.brain.display_name(),
@@ -3115,28 +3105,45 @@ fn render_overview_actions(ui: &mut egui::Ui, world: &World, entity: Entity) {
     });
 }
 
-fn emotion_label_color(e: EmotionType, intensity: f32) -> (&'static str, Color32) {
+fn emotion_label_color(
+    style: &VisualStyle,
+    e: EmotionType,
+    intensity: f32,
+) -> (&'static str, Color32) {
     let strong = intensity > 0.7;
-    match e {
-        EmotionType::Joy => (
-            if strong { "Joyful" } else { "Happy" },
-            Color32::from_rgb(255, 220, 80),
-        ),
-        EmotionType::Sadness => (
-            if strong { "Miserable" } else { "Sad" },
-            Color32::from_rgb(100, 140, 220),
-        ),
-        EmotionType::Fear => (
-            if strong { "Terrified" } else { "Scared" },
-            Color32::from_rgb(180, 120, 220),
-        ),
-        EmotionType::Anger => (
-            if strong { "Furious" } else { "Angry" },
-            Color32::from_rgb(220, 80, 60),
-        ),
-        EmotionType::Disgust => ("Disgusted", Color32::from_rgb(140, 200, 80)),
-        EmotionType::Surprise => ("Surprised", Color32::from_rgb(255, 180, 100)),
-    }
+    let label = match e {
+        EmotionType::Joy => {
+            if strong {
+                "Joyful"
+            } else {
+                "Happy"
+            }
+        }
+        EmotionType::Sadness => {
+            if strong {
+                "Miserable"
+            } else {
+                "Sad"
+            }
+        }
+        EmotionType::Fear => {
+            if strong {
+                "Terrified"
+            } else {
+                "Scared"
+            }
+        }
+        EmotionType::Anger => {
+            if strong {
+                "Furious"
+            } else {
+                "Angry"
+            }
+        }
+        EmotionType::Disgust => "Disgusted",
+        EmotionType::Surprise => "Surprised",
+    };
+    (label, style.emotion(e))
 }
 
 fn mood_text(mood: f32) -> &'static str {
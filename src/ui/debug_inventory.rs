@@ -0,0 +1,96 @@
+//! Debug inventory editing for the AgentViewer.
+//!
+//! Reads: TickCount
+//! Writes: ItemSlots, MindGraph (`(Self, Contains, Item)` belief for the edited concept)
+//! Upstream: ui::agent_viewer_ui_for_agent (the "🎒 Inventory" section's +/− controls)
+//! Downstream: rational brain (plans against the synced self-inventory belief)
+//!
+//! Setting up a scenario by hand means giving an agent things it never
+//! harvested. Editing `ItemSlots` alone would leave the agent's own belief
+//! about what it carries stale until the next action outcome touches that
+//! concept, so every edit rewrites the self `Contains` belief with the new
+//! count — exactly what a completed action would have done.
+
+use bevy::prelude::*;
+
+use crate::agent::item_slots::ItemSlots;
+use crate::agent::mind::knowledge::{Concept, MindGraph, Predicate, Value};
+
+/// Concepts offered by the viewer's "Add item" picker.
+pub const DEBUG_ITEM_CHOICES: &[Concept] = &[
+    Concept::Apple,
+    Concept::Berry,
+    Concept::Meat,
+    Concept::CookedMeat,
+    Concept::Fish,
+    Concept::Water,
+    Concept::Wood,
+    Concept::Stick,
+    Concept::Stone,
+    Concept::LargeLeaves,
+];
+
+/// Add (`delta > 0`) or remove (`delta < 0`) items of `concept` on `entity`
+/// and sync its self `Contains` belief. Removal clamps at zero. Returns the
+/// new count; an entity without `ItemSlots` is left untouched and reports 0.
+pub fn adjust_inventory(world: &mut World, entity: Entity, concept: Concept, delta: i32) -> u32 {
+    let tick = world
+        .get_resource::<crate::core::TickCount>()
+        .map_or(0, |t| t.current);
+
+    let Some(mut slots) = world.get_mut::<ItemSlots>(entity) else {
+        return 0;
+    };
+    if delta > 0 {
+        slots.add(concept, delta as u32);
+    } else if delta < 0 {
+        let take = delta.unsigned_abs().min(slots.count(concept));
+        slots.remove(concept, take);
+    }
+    let count = slots.count(concept);
+
+    if let Some(mut mind) = world.get_mut::<MindGraph>(entity) {
+        mind.perceive_self(Predicate::Contains, Value::Item(concept, count), tick);
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::mind::knowledge::{Node, setup_ontology};
+
+    fn agent(world: &mut World) -> Entity {
+        world
+            .spawn((ItemSlots::agent_carry(), MindGraph::new(setup_ontology())))
+            .id()
+    }
+
+    #[test]
+    fn ui_adjustment_updates_inventory_and_self_belief() {
+        let mut world = World::new();
+        let entity = agent(&mut world);
+
+        assert_eq!(adjust_inventory(&mut world, entity, Concept::Berry, 3), 3);
+        assert_eq!(
+            world
+                .get::<ItemSlots>(entity)
+                .unwrap()
+                .count(Concept::Berry),
+            3
+        );
+        let mind = world.get::<MindGraph>(entity).unwrap();
+        assert_eq!(mind.count_of(&Node::Self_, Concept::Berry), 3);
+
+        assert_eq!(adjust_inventory(&mut world, entity, Concept::Berry, -5), 0);
+        assert_eq!(
+            world
+                .get::<ItemSlots>(entity)
+                .unwrap()
+                .count(Concept::Berry),
+            0
+        );
+        let mind = world.get::<MindGraph>(entity).unwrap();
+        assert_eq!(mind.count_of(&Node::Self_, Concept::Berry), 0);
+    }
+}
@@ -108,6 +108,7 @@ pub fn render_mind_inspector(
     // We need to query the world for the agent's MindGraph
     // Since we are in an exclusive system param (World), we can get it directly
     if let Some(mind) = world.get::<MindGraph>(target_entity) {
+        let style = crate::ui::visual_style::visual_style(world);
         let filtered_triples = mind.query(
             state.filter_subject.as_ref(),
             state.filter_predicate,
@@ -236,30 +237,7 @@ pub fn render_mind_inspector(
 
                         // SOURCE
                         let source_text = format!("{:?}", triple.meta.source);
-                        let source_color = match triple.meta.source {
-                            crate::agent::mind::knowledge::Source::Perception => {
-                                Color32::LIGHT_BLUE
-                            }
-                            crate::agent::mind::knowledge::Source::Inferred => {
-                                Color32::LIGHT_YELLOW
-                            }
-                            crate::agent::mind::knowledge::Source::Intrinsic => Color32::LIGHT_GRAY,
-                            crate::agent::mind::knowledge::Source::Cultural => {
-                                Color32::from_rgb(255, 200, 150)
-                            }
-                            crate::agent::mind::knowledge::Source::Communicated => {
-                                Color32::LIGHT_GREEN
-                            }
-                            crate::agent::mind::knowledge::Source::Observed => {
-                                Color32::from_rgb(150, 200, 255)
-                            }
-                            crate::agent::mind::knowledge::Source::Experienced => {
-                                Color32::from_rgb(255, 150, 200)
-                            }
-                            crate::agent::mind::knowledge::Source::Hearsay => {
-                                Color32::from_rgb(200, 150, 255) // Purple for hearsay
-                            }
-                        };
+                        let source_color = style.source(triple.meta.source);
                         ui.colored_label(source_color, source_text);
 
                         // AGE (time since creation)
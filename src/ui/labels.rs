@@ -0,0 +1,156 @@
+//! Observer labels: user-chosen nicknames ("the troublemaker") pinned to
+//! agents during long observation sessions.
+//!
+//! Reads: Name + SpeciesProfile (to build each agent's `AgentId`), AgentLabels
+//! Writes: AgentLabels (AgentViewer edits), name-tag `Text2d` (label appended)
+//! Upstream: AgentViewer tab (label field), ui::layout (loads saved labels)
+//! Downstream: ui::layout (saves labels with the UI config), name tags
+//!
+//! Labels are keyed by [`AgentId`] rather than `Entity`: entity indices are
+//! reassigned every launch, but spawners hand out names deterministically
+//! from the seed and spawn order, so species + name picks out the same agent
+//! the next time the same world is loaded. Purely an observer aid — no
+//! simulation system reads a label.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use super::sprite_animation::NameTag;
+use crate::agent::body::species::{Species, SpeciesProfile};
+
+/// Restart-stable agent key: species plus generated display name. Species
+/// is included because the name pools overlap (a human and a wolf can both
+/// be "Sable").
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AgentId(String);
+
+impl AgentId {
+    pub fn new(species: Species, name: &str) -> Self {
+        Self(format!("{species:?}/{name}"))
+    }
+
+    /// The id of `entity`, if it has a name. Agents without a
+    /// `SpeciesProfile` are treated as human, matching `Species::default`.
+    pub fn of(world: &World, entity: Entity) -> Option<Self> {
+        let name = world.get::<Name>(entity)?;
+        let species = world
+            .get::<SpeciesProfile>(entity)
+            .map(|p| p.species)
+            .unwrap_or_default();
+        Some(Self::new(species, name.as_str()))
+    }
+}
+
+/// Observer-assigned labels, persisted alongside the dock layout.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AgentLabels(BTreeMap<AgentId, String>);
+
+impl AgentLabels {
+    pub fn get(&self, id: &AgentId) -> Option<&str> {
+        self.0.get(id).map(String::as_str)
+    }
+
+    /// Set or replace a label. A blank label removes it. Stored as typed —
+    /// the editor writes on every keystroke, so trimming here would eat
+    /// the space before the next word.
+    pub fn set(&mut self, id: AgentId, label: &str) {
+        if label.trim().is_empty() {
+            self.0.remove(&id);
+        } else {
+            self.0.insert(id, label.to_string());
+        }
+    }
+
+    /// `name`, followed by the label in quotes when one is set.
+    pub fn display_name(&self, species: Species, name: &str) -> String {
+        match self.get(&AgentId::new(species, name)) {
+            Some(label) => format!("{name} \"{}\"", label.trim()),
+            None => name.to_string(),
+        }
+    }
+}
+
+/// Keep every floating name tag in step with the label table. Only writes
+/// when the text actually differs so `Text2d` change detection stays quiet.
+pub fn sync_name_tag_labels(
+    labels: Res<AgentLabels>,
+    roots: Query<(&Name, Option<&SpeciesProfile>)>,
+    mut tags: Query<(&NameTag, &mut Text2d)>,
+) {
+    for (tag, mut text) in tags.iter_mut() {
+        let Ok((name, profile)) = roots.get(tag.root) else {
+            continue;
+        };
+        let species = profile.map(|p| p.species).unwrap_or_default();
+        let wanted = labels.display_name(species, name.as_str());
+        if text.0 != wanted {
+            text.0 = wanted;
+        }
+    }
+}
+
+/// Label editor row for the AgentViewer header.
+pub fn label_editor_ui(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    let Some(id) = AgentId::of(world, entity) else {
+        return;
+    };
+    let Some(mut labels) = world.get_resource_mut::<AgentLabels>() else {
+        return;
+    };
+    let mut text = labels.get(&id).unwrap_or_default().to_string();
+    ui.horizontal(|ui| {
+        ui.label("Label:");
+        if ui.text_edit_singleline(&mut text).changed() {
+            labels.set(id, &text);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn name_tag_shows_the_label_of_its_own_agent_only() {
+        let mut world = World::new();
+        let alice = world.spawn(Name::new("Alice")).id();
+        let bram = world.spawn(Name::new("Bram")).id();
+        let alice_tag = world
+            .spawn((Text2d::new("Alice"), NameTag::new(alice, 16.0)))
+            .id();
+        let bram_tag = world
+            .spawn((Text2d::new("Bram"), NameTag::new(bram, 16.0)))
+            .id();
+        let mut labels = AgentLabels::default();
+        labels.set(AgentId::new(Species::Human, "Alice"), "the troublemaker");
+        world.insert_resource(labels);
+
+        world.run_system_once(sync_name_tag_labels).unwrap();
+
+        assert_eq!(
+            world.get::<Text2d>(alice_tag).unwrap().0,
+            "Alice \"the troublemaker\""
+        );
+        assert_eq!(world.get::<Text2d>(bram_tag).unwrap().0, "Bram");
+    }
+
+    #[test]
+    fn blank_label_clears_and_species_keeps_shared_names_apart() {
+        let mut labels = AgentLabels::default();
+        labels.set(AgentId::new(Species::Wolf, "Sable"), "alpha");
+        assert_eq!(labels.display_name(Species::Human, "Sable"), "Sable");
+        assert_eq!(
+            labels.display_name(Species::Wolf, "Sable"),
+            "Sable \"alpha\""
+        );
+
+        labels.set(AgentId::new(Species::Wolf, "Sable"), "  ");
+        assert_eq!(labels, AgentLabels::default());
+    }
+}
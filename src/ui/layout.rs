@@ -0,0 +1,180 @@
+//! Persisted UI layout: the debug dock tree, overlay toggles, and observer
+//! agent labels.
+//!
+//! Reads: UiLayoutConfig (file path), AppExit
+//! Writes: UiState::dock_state, OverlayState, AgentLabels (on startup, from the saved file)
+//! Upstream: ui (UiState default layout), overlays (OverlayState), labels (AgentLabels)
+//! Downstream: ui_system (renders whatever dock tree was restored)
+//!
+//! The layout is loaded once at startup and written back when the app exits,
+//! so a rearranged dock survives restarts. A missing or unreadable file falls
+//! back to the built-in `UiState::default` layout.
+
+use std::path::{Path, PathBuf};
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use egui_dock::DockState;
+use serde::{Deserialize, Serialize};
+
+use super::labels::AgentLabels;
+use super::overlays::OverlayState;
+use super::{Tab, UiState};
+
+/// Default location of the saved layout, relative to the working directory.
+pub const DEFAULT_LAYOUT_PATH: &str = "ui_layout.ron";
+
+/// Where the UI layout is read from and written to. Insert a different
+/// value before `UiPlugin` builds to relocate the file.
+#[derive(Resource, Debug, Clone)]
+pub struct UiLayoutConfig {
+    pub path: PathBuf,
+}
+
+impl Default for UiLayoutConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from(DEFAULT_LAYOUT_PATH),
+        }
+    }
+}
+
+/// On-disk form of the layout.
+#[derive(Serialize, Deserialize)]
+pub(super) struct SavedLayout {
+    pub(super) dock: DockState<Tab>,
+    pub(super) overlays: OverlayState,
+    /// Absent from layouts saved before labels existed.
+    #[serde(default)]
+    pub(super) labels: AgentLabels,
+}
+
+pub(super) fn save_layout(path: &Path, layout: &SavedLayout) -> Result<(), String> {
+    let text = ron::ser::to_string_pretty(layout, ron::ser::PrettyConfig::default())
+        .map_err(|e| e.to_string())?;
+    std::fs::write(path, text).map_err(|e| e.to_string())
+}
+
+pub(super) fn load_layout(path: &Path) -> Result<SavedLayout, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    ron::from_str(&text).map_err(|e| e.to_string())
+}
+
+/// Startup: replace the default dock and overlay toggles with the saved ones.
+pub fn load_ui_layout(
+    config: Res<UiLayoutConfig>,
+    mut ui_state: ResMut<UiState>,
+    mut overlays: ResMut<OverlayState>,
+    mut labels: ResMut<AgentLabels>,
+) {
+    if !config.path.exists() {
+        return;
+    }
+    match load_layout(&config.path) {
+        Ok(layout) => {
+            ui_state.dock_state = layout.dock;
+            *overlays = layout.overlays;
+            *labels = layout.labels;
+        }
+        Err(e) => warn!(
+            "ui layout: could not load {}, using default: {e}",
+            config.path.display()
+        ),
+    }
+}
+
+/// Write the current layout once the app is exiting.
+pub fn save_ui_layout_on_exit(
+    mut exits: MessageReader<AppExit>,
+    config: Res<UiLayoutConfig>,
+    ui_state: Res<UiState>,
+    overlays: Res<OverlayState>,
+    labels: Res<AgentLabels>,
+) {
+    if exits.read().next().is_none() {
+        return;
+    }
+    let layout = SavedLayout {
+        dock: ui_state.dock_state.clone(),
+        overlays: overlays.clone(),
+        labels: labels.clone(),
+    };
+    if let Err(e) = save_layout(&config.path, &layout) {
+        warn!("ui layout: could not write {}: {e}", config.path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modified_layout_round_trips_through_save_and_load() {
+        let mut dock = UiState::default().dock_state;
+        let social = dock
+            .find_tab(&Tab::Social)
+            .expect("default layout has Social");
+        dock.remove_tab(social);
+        let overlays = OverlayState {
+            show_vision: true,
+            show_intent: false,
+            show_temperature: true,
+            show_occlusion: false,
+        };
+
+        let path = std::env::temp_dir().join(format!(
+            "worldsim_ui_layout_test_{}.ron",
+            std::process::id()
+        ));
+        save_layout(
+            &path,
+            &SavedLayout {
+                dock,
+                overlays,
+                labels: AgentLabels::default(),
+            },
+        )
+        .expect("save");
+        let loaded = load_layout(&path).expect("load");
+        let _ = std::fs::remove_file(&path);
+
+        assert!(loaded.dock.find_tab(&Tab::Social).is_none());
+        assert!(loaded.dock.find_tab(&Tab::Inspector).is_some());
+        assert_eq!(
+            loaded.dock.iter_all_tabs().count(),
+            UiState::default().dock_state.iter_all_tabs().count() - 1
+        );
+        assert!(loaded.overlays.show_vision);
+        assert!(!loaded.overlays.show_intent);
+        assert!(loaded.overlays.show_temperature);
+    }
+
+    #[test]
+    fn agent_label_round_trips_and_displays_for_the_same_agent() {
+        use super::super::labels::AgentId;
+        use crate::agent::body::species::Species;
+
+        let mut labels = AgentLabels::default();
+        labels.set(AgentId::new(Species::Human, "Cora"), "the troublemaker");
+        let layout = SavedLayout {
+            dock: UiState::default().dock_state,
+            overlays: OverlayState::default(),
+            labels,
+        };
+
+        let path = std::env::temp_dir().join(format!(
+            "worldsim_ui_labels_test_{}.ron",
+            std::process::id()
+        ));
+        save_layout(&path, &layout).expect("save");
+        let loaded = load_layout(&path).expect("load");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.labels, layout.labels);
+        assert_eq!(
+            loaded.labels.display_name(Species::Human, "Cora"),
+            "Cora \"the troublemaker\""
+        );
+        assert_eq!(loaded.labels.display_name(Species::Human, "Dax"), "Dax");
+    }
+}
@@ -1,6 +1,9 @@
 pub mod adventure_menu;
+pub mod agent_query;
 pub mod camera;
 pub mod hud;
+pub mod labels;
+pub mod layout;
 pub mod overlays;
 pub mod perf_overlay;
 pub mod status_icons;
@@ -22,17 +25,22 @@ use egui::Color32;
 use egui_dock::{DockArea, DockState, NodeIndex, Style};
 use hud::GameLog;
 
+use crate::core::log::LogExportFormat;
 use crate::menu::{AppState, sim_interactive};
 
 pub mod character_sheet;
+pub mod debug_inventory;
 pub mod debug_knowledge;
 pub mod sprite_animation;
+pub mod visual_style;
+pub mod world_stats;
 
 use sprite_animation::VisualOffset;
 
 /// Toggle for the developer debug dock (Hierarchy, Inspector, AgentViewer,
-/// MindInspector, Social, Log, Settings, Time, Resources). Disabled by default
-/// so players see only the clean character sheet UI. Press F12 to toggle.
+/// MindInspector, Social, Log, Settings, Time, Resources, WorldStats).
+/// Disabled by default so players see only the clean character sheet UI.
+/// Press F12 to toggle.
 #[derive(Resource, Default)]
 pub struct DebugUiEnabled(pub bool);
 
@@ -43,14 +51,24 @@ impl Plugin for UiPlugin {
         app.add_plugins(EguiPlugin::default())
             .add_plugins(DefaultInspectorConfigPlugin)
             .add_plugins(overlays::OverlayPlugin)
+            .add_plugins(agent_query::AgentQueryPlugin)
             .add_plugins(sprite_animation::SpriteAnimationPlugin)
             .add_plugins(status_icons::StatusIconPlugin)
             .add_plugins(character_sheet::CharacterSheetPlugin)
             .add_plugins(perf_overlay::PerfOverlayPlugin)
             .add_plugins(adventure_menu::AdventureMenuPlugin)
             .init_resource::<UiState>()
+            .init_resource::<visual_style::VisualStyle>()
             .init_resource::<DebugUiEnabled>()
             .init_resource::<debug_knowledge::KnowledgeInspectorState>()
+            .init_resource::<layout::UiLayoutConfig>()
+            .init_resource::<labels::AgentLabels>()
+            .register_type::<world_stats::WorldStatsConfig>()
+            .register_type::<world_stats::WorldStats>()
+            .init_resource::<world_stats::WorldStatsConfig>()
+            .init_resource::<world_stats::WorldStats>()
+            .add_systems(Startup, layout::load_ui_layout)
+            .add_systems(Last, layout::save_ui_layout_on_exit)
             .add_systems(
                 EguiPrimaryContextPass,
                 controls_panel_system.run_if(sim_interactive),
@@ -68,7 +86,23 @@ impl Plugin for UiPlugin {
             )
             .add_systems(
                 Update,
-                (toggle_debug_ui, handle_game_click, draw_selection_gizmos).run_if(sim_interactive),
+                (
+                    toggle_debug_ui,
+                    handle_game_click,
+                    draw_selection_gizmos,
+                    apply_step_request,
+                )
+                    .run_if(sim_interactive),
+            )
+            .add_systems(
+                Update,
+                labels::sync_name_tag_labels.run_if(in_state(AppState::InSim)),
+            )
+            .add_systems(
+                Update,
+                world_stats::update_world_stats
+                    .run_if(debug_ui_enabled)
+                    .run_if(in_state(AppState::InSim)),
             );
     }
 }
@@ -99,6 +133,9 @@ fn controls_panel_system(world: &mut World) {
             if let Some(game_time) = world.get_resource::<crate::core::GameTime>() {
                 ui.strong(game_time.format());
             }
+            if let Some(cycle) = world.get_resource::<crate::world::environment::DayCycle>() {
+                ui.label(format!("{:?}", cycle.phase));
+            }
 
             let (paused, speed) =
                 if let Some(tick_res) = world.get_resource::<crate::core::TickCount>() {
@@ -156,6 +193,15 @@ fn controls_panel_system(world: &mut World) {
             if let Some(mut overlay_state) = world.get_resource_mut::<OverlayState>() {
                 overlays::overlay_checkboxes(ui, &mut overlay_state);
             }
+
+            ui.separator();
+
+            // Agent query highlighting
+            ui.strong("Highlight");
+            if let Some(mut query_state) = world.get_resource_mut::<agent_query::AgentQueryState>()
+            {
+                agent_query::query_bar(ui, &mut query_state);
+            }
         });
 }
 
@@ -187,11 +233,50 @@ pub struct UiState {
     /// Time control commands - applied by apply_time_controls system
     pub toggle_pause: bool,
     pub set_speed: Option<f32>,
+    /// "⏭ Step" was clicked; `apply_step_request` turns it into one
+    /// `TickCount::stepping` cycle.
+    pub step_once: bool,
+    /// Repeated clicks on one spot step through stacked entities.
+    pub click_cycle: ClickCycle,
+}
+
+/// World-space distance within which a click counts as "the same spot" for
+/// cycling through overlapping entities.
+const CLICK_CYCLE_RADIUS: f32 = 4.0;
+
+/// Remembers where the last pick landed and which candidate it chose, so a
+/// crowded tile can be inspected one occupant at a time instead of always
+/// re-selecting the topmost sprite.
+#[derive(Debug, Clone, Default)]
+pub struct ClickCycle {
+    last: Option<(Vec2, usize)>,
+}
+
+impl ClickCycle {
+    /// Pick from `candidates`, which must already be in priority order. A
+    /// click near the previous one advances to the next candidate (wrapping);
+    /// a click anywhere else starts over at the top.
+    pub fn pick(&mut self, position: Vec2, candidates: &[Entity]) -> Option<Entity> {
+        if candidates.is_empty() {
+            self.last = None;
+            return None;
+        }
+        // Keep the anchor from the first click of the cycle so small
+        // mouse drift between clicks doesn't walk the spot away.
+        let (anchor, index) = match self.last {
+            Some((anchor, last_index)) if anchor.distance(position) <= CLICK_CYCLE_RADIUS => {
+                (anchor, (last_index + 1) % candidates.len())
+            }
+            _ => (position, 0),
+        };
+        self.last = Some((anchor, index));
+        Some(candidates[index])
+    }
 }
 
 use overlays::OverlayState;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 enum Tab {
     GameView,
     Hierarchy,
@@ -203,6 +288,7 @@ enum Tab {
     AgentViewer,
     MindInspector,
     Social,
+    WorldStats,
 }
 
 impl Default for UiState {
@@ -232,7 +318,7 @@ impl Default for UiState {
         tree.split_below(
             _hierarchy,
             0.60,
-            vec![Tab::Settings, Tab::Log, Tab::Resources],
+            vec![Tab::Settings, Tab::Log, Tab::Resources, Tab::WorldStats],
         );
 
         Self {
@@ -241,6 +327,8 @@ impl Default for UiState {
             viewport_rect: egui::Rect::NOTHING,
             toggle_pause: false,
             set_speed: None,
+            step_once: false,
+            click_cycle: ClickCycle::default(),
         }
     }
 }
@@ -270,11 +358,24 @@ impl UiState {
                     world,
                     selected_entities: &mut self.selected_entities,
                     viewport_rect: &mut self.viewport_rect,
+                    step_once: &mut self.step_once,
                 },
             );
     }
 }
 
+/// Turn a Time-tab "⏭ Step" click into one single-step FixedMain cycle.
+/// Ignored unless paused — a running sim has nothing to step.
+fn apply_step_request(mut ui_state: ResMut<UiState>, mut tick: ResMut<crate::core::TickCount>) {
+    if !ui_state.step_once {
+        return;
+    }
+    ui_state.step_once = false;
+    if tick.paused {
+        tick.stepping = true;
+    }
+}
+
 fn set_camera_viewport(
     ui_state: Res<UiState>,
     windows: Query<&Window, With<PrimaryWindow>>,
@@ -364,16 +465,19 @@ fn handle_game_click(
             }
         }
 
-        // Sort by z (highest first), then by distance (closest first)
+        // Sort by z (highest first), then by distance (closest first), then
+        // by entity so stacked candidates keep a stable cycling order.
         candidates.sort_by(|a, b| {
             b.1.partial_cmp(&a.1) // Higher z first
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .then_with(|| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal)) // Closer first
+                .then_with(|| a.0.cmp(&b.0))
         });
 
-        if let Some((entity, _z, _dist)) = candidates.first() {
+        let ordered: Vec<Entity> = candidates.iter().map(|(entity, _, _)| *entity).collect();
+        if let Some(entity) = ui_state.click_cycle.pick(world_position, &ordered) {
             let add = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ShiftLeft]);
-            ui_state.selected_entities.select_maybe_add(*entity, add);
+            ui_state.selected_entities.select_maybe_add(entity, add);
         }
     }
 }
@@ -401,6 +505,7 @@ struct UiViewer<'a> {
     world: &'a mut World,
     selected_entities: &'a mut SelectedEntities,
     viewport_rect: &'a mut egui::Rect,
+    step_once: &'a mut bool,
 }
 
 // ... (ui_system same) ...
@@ -469,8 +574,43 @@ impl<'a> egui_dock::TabViewer for UiViewer<'a> {
                                 game_log.add_entity_to_filter(entity);
                             }
                         }
+                        if !self.selected_entities.as_slice().is_empty()
+                            && ui
+                                .button("Everything about Selected")
+                                .on_hover_text("Filter to the selection and enable all categories")
+                                .clicked()
+                        {
+                            game_log.focus_on(self.selected_entities.as_slice().iter().copied());
+                        }
                     });
 
+                    // Export row: format, filter scope, and the last save's outcome.
+                    let export_id = ui.id().with("log_export");
+                    let (mut format, mut visible_only, mut status) = ui.data(|d| {
+                        d.get_temp::<(LogExportFormat, bool, String)>(export_id)
+                            .unwrap_or_default()
+                    });
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_salt(export_id)
+                            .selected_text(format.extension())
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut format, LogExportFormat::Text, "txt");
+                                ui.selectable_value(&mut format, LogExportFormat::Csv, "csv");
+                            });
+                        ui.checkbox(&mut visible_only, "Export visible only");
+                        if ui.button("💾 Save Log").clicked() {
+                            let path = format.timestamped_path();
+                            status = match game_log.export_to_path(&path, format, visible_only) {
+                                Ok(()) => format!("Saved {}", path.display()),
+                                Err(e) => format!("Save failed: {e}"),
+                            };
+                        }
+                        if !status.is_empty() {
+                            ui.weak(&status);
+                        }
+                    });
+                    ui.data_mut(|d| d.insert_temp(export_id, (format, visible_only, status)));
+
                     ui.separator();
 
                     egui::ScrollArea::vertical()
@@ -505,6 +645,69 @@ impl<'a> egui_dock::TabViewer for UiViewer<'a> {
                 } else {
                     ui.label("OverlayState not found.");
                 }
+                ui.separator();
+                ui.heading("Camera");
+                if let Some(mut follow) = self.world.get_resource_mut::<camera::CameraFollow>() {
+                    ui.checkbox(&mut follow.enabled, "Follow selected agent (F)");
+                }
+                ui.separator();
+                ui.heading("Highlight");
+                if let Some(mut query_state) = self
+                    .world
+                    .get_resource_mut::<agent_query::AgentQueryState>()
+                {
+                    agent_query::query_bar(ui, &mut query_state);
+                } else {
+                    ui.label("AgentQueryState not found.");
+                }
+                ui.separator();
+                ui.heading("Weather");
+                if let Some(mut config) = self
+                    .world
+                    .get_resource_mut::<crate::world::weather::WeatherConfig>()
+                {
+                    ui.checkbox(&mut config.locked, "Lock weather");
+                }
+                if let Some(mut weather) = self
+                    .world
+                    .get_resource_mut::<crate::world::weather::Weather>()
+                {
+                    ui.horizontal(|ui| {
+                        for option in crate::world::weather::Weather::ALL {
+                            if ui
+                                .selectable_label(*weather == option, format!("{option:?}"))
+                                .clicked()
+                            {
+                                *weather = option;
+                            }
+                        }
+                    });
+                } else {
+                    ui.label("Weather not found.");
+                }
+                ui.separator();
+                ui.heading("Autosave");
+                let recoverable = self
+                    .world
+                    .contains_resource::<crate::core::AutosaveSource>();
+                if let Some(mut autosave) =
+                    self.world.get_resource_mut::<crate::core::AutosaveConfig>()
+                {
+                    ui.checkbox(&mut autosave.enabled, "Enabled");
+                    ui.horizontal(|ui| {
+                        ui.label("Interval (ticks)");
+                        ui.add(egui::DragValue::new(&mut autosave.interval_ticks).speed(60));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Slots");
+                        ui.add(egui::DragValue::new(&mut autosave.slots).range(1..=32));
+                    });
+                    if !recoverable {
+                        ui.label("Only recorded headless runs can be autosaved.");
+                    }
+                } else {
+                    ui.label("AutosaveConfig not found.");
+                }
             }
             Tab::Time => {
                 if let Some(game_time) = self.world.get_resource::<crate::core::GameTime>() {
@@ -535,6 +738,13 @@ impl<'a> egui_dock::TabViewer for UiViewer<'a> {
                     {
                         tick_res.paused = !tick_res.paused;
                     }
+                    if ui
+                        .add_enabled(paused, egui::Button::new("⏭ Step"))
+                        .on_hover_text("Advance exactly one tick")
+                        .clicked()
+                    {
+                        *self.step_once = true;
+                    }
                     if paused {
                         ui.colored_label(egui::Color32::RED, "PAUSED");
                     }
@@ -606,6 +816,9 @@ impl<'a> egui_dock::TabViewer for UiViewer<'a> {
             Tab::Social => {
                 render_social_ui(self.world, ui, self.selected_entities.as_slice());
             }
+            Tab::WorldStats => {
+                world_stats::render_world_stats(ui, self.world);
+            }
         }
     }
 
@@ -624,6 +837,7 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
         }
         ui.label(format!("(ID: {:?})", entity));
     });
+    labels::label_editor_ui(world, entity, ui);
 
     if let Some(active) = world.get::<crate::agent::actions::ActiveActions>(entity) {
         ui.label(format!("Active Actions ({}):", active.len()));
@@ -710,32 +924,41 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
                     ui.horizontal(|ui| {
                         ui.label("Controller:");
                         let text = format!("{:?}", winner).to_uppercase();
-                        let color = match winner {
-                            crate::agent::brains::proposal::BrainType::Survival => Color32::RED,
-                            crate::agent::brains::proposal::BrainType::Emotional => {
-                                Color32::from_rgb(255, 105, 180)
-                            } // Hot Pink
-                            crate::agent::brains::proposal::BrainType::Rational => Color32::CYAN,
-                        };
+                        let color = visual_style::visual_style(world).brain(winner);
                         ui.colored_label(color, egui::RichText::new(text).strong());
                     });
                 }
 
+                // Reaction latency: a brain that won but hasn't taken over yet
+                if let Some(takeover) = brain_state.pending_takeover {
+                    let now = world
+                        .get_resource::<crate::core::TickCount>()
+                        .map_or(takeover.since_tick, |t| t.current);
+                    ui.colored_label(
+                        Color32::GRAY,
+                        format!(
+                            "{} reacting… {}/{} ticks",
+                            takeover.brain.display_name(),
+                            now.saturating_sub(takeover.since_tick),
+                            takeover.ready_at - takeover.since_tick
+                        ),
+                    );
+                }
+
                 // Proposals
                 ui.label("Proposals:");
                 for prop in &brain_state.proposals {
-                    let color = match prop.brain {
-                        crate::agent::brains::proposal::BrainType::Survival => Color32::LIGHT_RED,
-                        crate::agent::brains::proposal::BrainType::Emotional => {
-                            Color32::from_rgb(255, 182, 193)
-                        }
-                        crate::agent::brains::proposal::BrainType::Rational => Color32::LIGHT_BLUE,
+                    let color = visual_style::visual_style(world).brain_proposal(prop.brain);
+                    let incumbent = if brain_state.incumbent.is_some_and(|i| i.backs(prop)) {
+                        " [incumbent]"
+                    } else {
+                        ""
                     };
                     ui.colored_label(
                         color,
                         format!(
-                            "• {:?}: {} ({:.1}) - {}",
-                            prop.brain, prop.action.name, prop.urgency, prop.reasoning
+                            "• {:?}: {} ({:.1}){} - {}",
+                            prop.brain, prop.action.name, prop.urgency, incumbent, prop.reasoning
                         ),
                     );
                 }
@@ -835,6 +1058,51 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
                     ui.label(format!("{bg} plan(s) in background"));
                 }
             }
+
+            // D. Goal history — newest first, so thrashing shows up as a
+            // stack of short spans at the top.
+            if let Some(history) =
+                world.get::<crate::agent::brains::goal_history::GoalHistory>(entity)
+                && !history.is_empty()
+            {
+                use crate::agent::brains::goal_history::GoalOutcome;
+                ui.separator();
+                ui.heading("Goal History");
+                let now = world
+                    .get_resource::<crate::core::TickCount>()
+                    .map_or(0, |t| t.current);
+                egui::ScrollArea::vertical()
+                    .id_salt("goal_history")
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for record in history.iter().rev() {
+                            let end = record.ended_at.unwrap_or(now);
+                            let (color, outcome) = match record.outcome {
+                                GoalOutcome::Active => (Color32::YELLOW, "active".to_string()),
+                                GoalOutcome::Completed => (Color32::GREEN, "completed".to_string()),
+                                GoalOutcome::Suspended => (Color32::GRAY, "suspended".to_string()),
+                                GoalOutcome::Abandoned(reason) => {
+                                    (Color32::LIGHT_RED, format!("abandoned: {reason:?}"))
+                                }
+                            };
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    "t{}–{} ({} ticks)",
+                                    record.started_at,
+                                    end,
+                                    end.saturating_sub(record.started_at)
+                                ));
+                                ui.label(format!(
+                                    "{:?} p{:.2}",
+                                    record.driving_urgency, record.priority
+                                ));
+                                ui.colored_label(color, outcome);
+                            })
+                            .response
+                            .on_hover_text(format!("{:?}", record.conditions));
+                        }
+                    });
+            }
         });
 
     ui.separator();
@@ -864,6 +1132,23 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
                 );
                 ui.end_row();
 
+                let now = world
+                    .get_resource::<crate::core::TickCount>()
+                    .map_or(0, |t| t.current);
+                ui.label("Last meal");
+                ui.label(format!(
+                    "{} ticks ago",
+                    now.saturating_sub(physical.last_ate_tick)
+                ));
+                ui.end_row();
+
+                ui.label("Last slept");
+                ui.label(format!(
+                    "{} ticks ago",
+                    now.saturating_sub(physical.last_slept_tick)
+                ));
+                ui.end_row();
+
                 if let Some(consciousness) =
                     world.get::<crate::agent::body::needs::Consciousness>(entity)
                 {
@@ -919,13 +1204,34 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
                         };
                         ui.add(egui::ProgressBar::new(hp).fill(color));
 
-                        if part.injuries.is_empty() {
-                            ui.label("OK");
-                        } else {
-                            ui.colored_label(Color32::RED, format!("{} Inj", part.injuries.len()));
-                        }
+                        ui.horizontal(|ui| {
+                            if part.injuries.is_empty() {
+                                ui.label("OK");
+                            } else {
+                                ui.colored_label(
+                                    Color32::RED,
+                                    format!("{} Inj", part.injuries.len()),
+                                );
+                            }
+                            if part.is_bleeding() || part.children.iter().any(|c| c.is_bleeding()) {
+                                ui.colored_label(Color32::RED, "Bleeding");
+                            }
+                        });
                         ui.end_row();
                     }
+
+                    ui.label("Blood");
+                    let blood_color = if body.blood < 0.5 {
+                        Color32::RED
+                    } else {
+                        Color32::GREEN
+                    };
+                    ui.add(
+                        egui::ProgressBar::new(body.blood)
+                            .text(format!("{:.0}%", body.blood * 100.0))
+                            .fill(blood_color),
+                    );
+                    ui.end_row();
                 });
         }
     });
@@ -1092,8 +1398,12 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
     ui.separator();
 
     // --- 6. Inventory ---
+    // Debug +/− edits are collected here and applied once the read borrow
+    // of ItemSlots has ended.
+    let mut inventory_edit: Option<(crate::agent::mind::knowledge::Concept, i32)> = None;
     egui::CollapsingHeader::new("🎒 Inventory").show(ui, |ui| {
         if let Some(inventory) = world.get::<crate::agent::item_slots::ItemSlots>(entity) {
+            let style = visual_style::visual_style(world);
             let counts = inventory.group_by_concept();
             if counts.is_empty() {
                 ui.label("Empty");
@@ -1102,13 +1412,74 @@ fn agent_viewer_ui_for_agent(world: &mut World, entity: Entity, ui: &mut egui::U
                 sorted.sort_by_key(|(c, _)| format!("{c:?}"));
                 for (concept, qty) in sorted {
                     ui.horizontal(|ui| {
-                        ui.label(format!("{concept:?}"));
+                        ui.colored_label(
+                            style.concept_color(concept),
+                            format!("{} {concept:?}", style.concept_icon(concept)),
+                        );
                         ui.strong(format!("x{qty}"));
+                        if ui.small_button("+").clicked() {
+                            inventory_edit = Some((concept, 1));
+                        }
+                        if ui.small_button("−").clicked() {
+                            inventory_edit = Some((concept, -1));
+                        }
                     });
                 }
             }
+
+            let choices = debug_inventory::DEBUG_ITEM_CHOICES;
+            let choice_id = ui.id().with(("debug_inventory_choice", entity));
+            let mut choice = ui.data(|d| d.get_temp::<usize>(choice_id).unwrap_or(0));
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt(choice_id).show_index(
+                    ui,
+                    &mut choice,
+                    choices.len(),
+                    |i| format!("{} {:?}", style.concept_icon(choices[i]), choices[i]),
+                );
+                if ui.small_button("Add item").clicked() {
+                    inventory_edit = Some((choices[choice], 1));
+                }
+            });
+            ui.data_mut(|d| d.insert_temp(choice_id, choice));
         }
     });
+    if let Some((concept, delta)) = inventory_edit {
+        debug_inventory::adjust_inventory(world, entity, concept, delta);
+    }
+}
+
+/// Everyone but `entity` who took part in `conv`, comma-separated. Turn
+/// speakers are included so members who already left still show up.
+fn other_participant_names(
+    world: &World,
+    conv: &crate::agent::engagement::converse::Conversation,
+    entity: Entity,
+) -> String {
+    let mut others: Vec<Entity> = Vec::new();
+    for e in conv
+        .participants
+        .iter()
+        .copied()
+        .chain(conv.turns.iter().map(|t| t.speaker))
+    {
+        if e != entity && !others.contains(&e) {
+            others.push(e);
+        }
+    }
+    if others.is_empty() {
+        return "Unknown".to_string();
+    }
+    others
+        .iter()
+        .map(|e| {
+            world
+                .get::<Name>(*e)
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| format!("{e:?}"))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Render the Social UI showing relationships and conversations
@@ -1137,86 +1508,57 @@ fn render_social_ui(world: &mut World, ui: &mut egui::Ui, selected_entities: &[E
             .map(|s| s.known_entities().collect())
             .unwrap_or_default();
 
-        if let Some(mind) = world.get::<crate::agent::mind::knowledge::MindGraph>(entity) {
-            use crate::agent::mind::knowledge::{Node, Predicate};
-
-            if known.is_empty() {
-                ui.label("No known relationships yet.");
-            } else {
-                egui::Grid::new("relationships_grid")
-                    .striped(true)
-                    .min_col_width(80.0)
-                    .show(ui, |ui| {
-                        ui.strong("Person");
-                        ui.strong("Trust");
-                        ui.strong("Affection");
-                        ui.end_row();
-
-                        for other_entity in known {
-                            {
-                                // Get their name
-                                let other_name = world
-                                    .get::<Name>(other_entity)
-                                    .map(|n| n.to_string())
-                                    .unwrap_or_else(|| format!("{:?}", other_entity));
-
-                                // Get trust level
-                                let trust = mind
-                                    .query(
-                                        Some(&Node::Entity(other_entity)),
-                                        Some(Predicate::Trust),
-                                        None,
-                                    )
-                                    .first()
-                                    .and_then(|t| t.object.as_quantity())
-                                    .map(|q| q.point_estimate())
-                                    .unwrap_or(0.0);
-
-                                // Get affection level
-                                let affection = mind
-                                    .query(
-                                        Some(&Node::Entity(other_entity)),
-                                        Some(Predicate::Affection),
-                                        None,
-                                    )
-                                    .first()
-                                    .and_then(|t| t.object.as_quantity())
-                                    .map(|q| q.point_estimate())
-                                    .unwrap_or(0.0);
-
-                                ui.label(&other_name);
-
-                                // Color trust bar
-                                let trust_color = if trust > 0.0 {
-                                    Color32::from_rgb(100, 200, 100)
-                                } else {
-                                    Color32::from_rgb(200, 100, 100)
-                                };
-                                ui.add(
-                                    egui::ProgressBar::new((trust + 1.0) / 2.0) // normalize -1..1 to 0..1
-                                        .fill(trust_color)
-                                        .text(format!("{:.2}", trust)),
-                                );
-
-                                // Color affection bar
-                                let affection_color = if affection > 0.0 {
-                                    Color32::from_rgb(200, 150, 200)
-                                } else {
-                                    Color32::from_rgb(150, 150, 200)
-                                };
-                                ui.add(
-                                    egui::ProgressBar::new((affection + 1.0) / 2.0)
-                                        .fill(affection_color)
-                                        .text(format!("{:.2}", affection)),
-                                );
+        if known.is_empty() {
+            ui.label("No known relationships yet.");
+        } else if let Some(graph) =
+            world.get_resource::<crate::agent::psyche::social_graph::SocialGraph>()
+        {
+            egui::Grid::new("relationships_grid")
+                .striped(true)
+                .min_col_width(80.0)
+                .show(ui, |ui| {
+                    ui.strong("Person");
+                    ui.strong("Trust");
+                    ui.strong("Affection");
+                    ui.strong("Respect");
+                    ui.strong("Power");
+                    ui.end_row();
 
-                                ui.end_row();
-                            }
+                    for other_entity in known {
+                        let other_name = world
+                            .get::<Name>(other_entity)
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|| format!("{:?}", other_entity));
+                        let edge = graph.get_or_default(entity, other_entity);
+
+                        ui.label(&other_name);
+                        // Trust / affection / respect live in 0..1.
+                        for (value, color) in [
+                            (edge.trust, Color32::from_rgb(100, 200, 100)),
+                            (edge.affection, Color32::from_rgb(200, 150, 200)),
+                            (edge.respect, Color32::from_rgb(200, 180, 100)),
+                        ] {
+                            ui.add(
+                                egui::ProgressBar::new(value.clamp(0.0, 1.0))
+                                    .fill(color)
+                                    .text(format!("{value:.2}")),
+                            );
                         }
-                    });
-            }
-        } else {
-            ui.label("No MindGraph component.");
+                        // Power balance is -1 (subordinate) .. +1 (dominant).
+                        let power = edge.power_balance;
+                        let power_color = if power >= 0.0 {
+                            Color32::from_rgb(100, 150, 220)
+                        } else {
+                            Color32::from_rgb(200, 100, 100)
+                        };
+                        ui.add(
+                            egui::ProgressBar::new(((power + 1.0) / 2.0).clamp(0.0, 1.0))
+                                .fill(power_color)
+                                .text(format!("{power:+.2}")),
+                        );
+                        ui.end_row();
+                    }
+                });
         }
     });
 
@@ -1235,16 +1577,12 @@ fn render_social_ui(world: &mut World, ui: &mut egui::Ui, selected_entities: &[E
                     ui.label("No active conversations.");
                 } else {
                     for conv in my_conversations {
-                        let partner = conv.participants.iter().find(|&&p| p != entity).copied();
-
-                        let partner_name = partner
-                            .and_then(|p| inner_world.get::<Name>(p).map(|n| n.to_string()))
-                            .unwrap_or_else(|| "Unknown".to_string());
+                        let others = other_participant_names(inner_world, conv, entity);
 
                         ui.group(|ui| {
                             ui.label(format!(
                                 "Conversation with {} ({} turns)",
-                                partner_name,
+                                others,
                                 conv.turns.len()
                             ));
 
@@ -1305,17 +1643,13 @@ fn render_social_ui(world: &mut World, ui: &mut egui::Ui, selected_entities: &[E
                         .max_height(300.0)
                         .show(ui, |ui| {
                             for conv in ended_conversations.iter().rev().take(10) {
-                                let partner = conv.participants.iter().find(|&&p| p != entity).copied();
-
-                                let partner_name = partner
-                                    .and_then(|p| inner_world.get::<Name>(p).map(|n| n.to_string()))
-                                    .unwrap_or_else(|| "Unknown".to_string());
+                                let others = other_participant_names(inner_world, conv, entity);
 
                                 ui.group(|ui| {
                                     ui.horizontal(|ui| {
                                         ui.label(format!(
                                             "With {} ({} turns)",
-                                            partner_name,
+                                            others,
                                             conv.turns.len()
                                         ));
                                         ui.label(format!("ID: {:?}", conv.id));
@@ -1394,3 +1728,35 @@ fn render_social_ui(world: &mut World, ui: &mut egui::Ui, selected_entities: &[E
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_clicks_cycle_through_stacked_entities() {
+        let stacked = [
+            Entity::from_bits(1),
+            Entity::from_bits(2),
+            Entity::from_bits(3),
+        ];
+        let mut cycle = ClickCycle::default();
+        let spot = Vec2::new(100.0, 50.0);
+
+        let picks: Vec<Entity> = (0..4)
+            .map(|i| {
+                // Slight drift between clicks still counts as the same spot.
+                let position = spot + Vec2::splat(i as f32 * 0.5);
+                cycle.pick(position, &stacked).unwrap()
+            })
+            .collect();
+        assert_eq!(picks, vec![stacked[0], stacked[1], stacked[2], stacked[0]]);
+
+        // Clicking elsewhere restarts at the top candidate.
+        assert_eq!(
+            cycle.pick(spot + Vec2::new(200.0, 0.0), &stacked),
+            Some(stacked[0])
+        );
+        assert_eq!(cycle.pick(spot, &stacked), Some(stacked[0]));
+    }
+}
@@ -1,14 +1,18 @@
-use crate::agent::mind::perception::{VisibleObjects, Vision};
+use crate::agent::brains::proposal::BrainState;
+use crate::agent::mind::perception::{PerceptionConfig, VisibleObjects, Vision};
+use crate::agent::movement::Facing;
 use crate::agent::{Agent, TargetPosition};
 use crate::ui::UiState;
 use crate::ui::camera::cursor_to_world;
+use crate::ui::visual_style::{VisualStyle, to_bevy_color};
 use crate::world::field_grid::FIELD_CHUNK_SIZE;
 use crate::world::field_grid_plugin::FieldGrids;
-use crate::world::map::TILE_SIZE;
+use crate::world::map::{TILE_SIZE, WorldMap};
 use crate::world::spatial_index::world_pos_to_tile;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext, egui};
+use serde::{Deserialize, Serialize};
 
 pub struct OverlayPlugin;
 
@@ -22,12 +26,16 @@ impl Plugin for OverlayPlugin {
     }
 }
 
-#[derive(Resource, Reflect, Default)]
+#[derive(Resource, Reflect, Default, Clone, Serialize, Deserialize)]
 #[reflect(Resource)]
 pub struct OverlayState {
     pub show_vision: bool,
     pub show_intent: bool,
     pub show_temperature: bool,
+    /// Shade tiles inside each agent's vision range that rock hides from
+    /// it. Absent from layouts saved before occlusion existed.
+    #[serde(default)]
+    pub show_occlusion: bool,
 }
 
 /// Render the overlay toggles. Shared by the left controls panel and the
@@ -36,6 +44,7 @@ pub fn overlay_checkboxes(ui: &mut egui::Ui, state: &mut OverlayState) {
     ui.checkbox(&mut state.show_vision, "Vision Range");
     ui.checkbox(&mut state.show_intent, "Agent Intent");
     ui.checkbox(&mut state.show_temperature, "Temperature");
+    ui.checkbox(&mut state.show_occlusion, "Vision Occlusion");
 }
 
 #[derive(Component)]
@@ -48,20 +57,44 @@ const OVERLAY_MAX_ALPHA: f32 = 0.75;
 const OVERLAY_MIN_ALPHA: f32 = 0.25;
 const OVERLAY_SKIP_THRESHOLD_C: f32 = 0.1;
 const OVERLAY_Z: f32 = 50.0;
+/// Arc segments per full turn when drawing a vision cone.
+const CONE_SEGMENTS_PER_TURN: f32 = 48.0;
 
 fn draw_overlays(
     mut gizmos: Gizmos,
     overlay_state: Res<OverlayState>,
-    agents: Query<(&Transform, &Vision, &VisibleObjects, &TargetPosition), With<Agent>>,
+    style: Res<VisualStyle>,
+    perception: Res<PerceptionConfig>,
+    map: Res<WorldMap>,
+    agents: Query<
+        (
+            &Transform,
+            &Vision,
+            &VisibleObjects,
+            &TargetPosition,
+            Option<&BrainState>,
+            Option<&Facing>,
+        ),
+        With<Agent>,
+    >,
 ) {
-    for (transform, vision, visible_objects, target) in agents.iter() {
+    for (transform, vision, visible_objects, target, brain, facing) in agents.iter() {
         let pos = transform.translation.truncate();
         let _pos3 = transform.translation;
 
         // Vision Overlay
         if overlay_state.show_vision {
-            // Draw Range Circle
-            gizmos.circle_2d(pos, vision.range, Color::srgba(0.0, 0.0, 1.0, 0.3));
+            let color = Color::srgba(0.0, 0.0, 1.0, 0.3);
+            if perception.field_of_view && vision.fov_degrees < 360.0 {
+                let facing = facing.copied().unwrap_or_default().0;
+                draw_vision_cone(&mut gizmos, pos, facing, vision, color);
+                gizmos.circle_2d(pos, perception.peripheral_radius, color);
+            } else {
+                gizmos.circle_2d(pos, vision.range, color);
+            }
+            if overlay_state.show_occlusion {
+                draw_occluded_tiles(&mut gizmos, &map, pos, vision.range);
+            }
 
             // Draw Lines to Visible Objects
             for &_entity in visible_objects.entities.iter() {
@@ -77,25 +110,68 @@ fn draw_overlays(
         if overlay_state.show_intent
             && let Some(target_pos) = target.0
         {
-            // Draw line to target
-            gizmos.line_2d(pos, target_pos, Color::srgba(1.0, 0.5, 0.0, 0.8));
+            // Draw line to target, tinted by whichever brain is in control
+            let color = brain
+                .and_then(|b| b.winner)
+                .map_or(Color::srgba(1.0, 0.5, 0.0, 0.8), |winner| {
+                    to_bevy_color(style.brain(winner), 0.8)
+                });
+            gizmos.line_2d(pos, target_pos, color);
 
             // Draw target X
             let x_size = 5.0;
             gizmos.line_2d(
                 target_pos + Vec2::new(-x_size, -x_size),
                 target_pos + Vec2::new(x_size, x_size),
-                Color::srgba(1.0, 0.5, 0.0, 0.8),
+                color,
             );
             gizmos.line_2d(
                 target_pos + Vec2::new(-x_size, x_size),
                 target_pos + Vec2::new(x_size, -x_size),
-                Color::srgba(1.0, 0.5, 0.0, 0.8),
+                color,
             );
         }
     }
 }
 
+/// Shade every tile within `range` of `pos` that `WorldMap::line_of_sight`
+/// says is hidden.
+fn draw_occluded_tiles(gizmos: &mut Gizmos, map: &WorldMap, pos: Vec2, range: f32) {
+    let color = Color::srgba(0.0, 0.0, 0.0, 0.35);
+    let reach = (range / TILE_SIZE).ceil() as i32;
+    let center = world_pos_to_tile(pos);
+    for dy in -reach..=reach {
+        for dx in -reach..=reach {
+            let tile = map.tile_to_world(center.x + dx, center.y + dy);
+            if map.distance(pos, tile) <= range && !map.line_of_sight(pos, tile) {
+                gizmos.rect_2d(
+                    Isometry2d::from_translation(tile),
+                    Vec2::splat(TILE_SIZE),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Outline of the `Vision` cone: out along one edge, round the arc at full
+/// range, and back along the other edge.
+fn draw_vision_cone(gizmos: &mut Gizmos, pos: Vec2, facing: Vec2, vision: &Vision, color: Color) {
+    let half_fov = (vision.fov_degrees * 0.5).to_radians();
+    let heading = facing.to_angle();
+    let segments = ((vision.fov_degrees / 360.0) * CONE_SEGMENTS_PER_TURN)
+        .ceil()
+        .max(1.0) as usize;
+    let arc = (0..=segments).map(|i| {
+        let angle = heading - half_fov + 2.0 * half_fov * i as f32 / segments as f32;
+        pos + Vec2::from_angle(angle) * vision.range
+    });
+    gizmos.linestrip_2d(
+        std::iter::once(pos).chain(arc).chain(std::iter::once(pos)),
+        color,
+    );
+}
+
 fn draw_temperature_overlay(
     mut commands: Commands,
     overlay_state: Res<OverlayState>,
@@ -0,0 +1,245 @@
+//! Shared UI colors and icons for simulation concepts.
+//!
+//! Reads: nothing
+//! Writes: VisualStyle resource (defaults; replace or edit it to re-theme)
+//! Upstream: UiPlugin (init_resource)
+//! Downstream: ui (AgentViewer, Social), character_sheet, debug_knowledge (MindInspector), overlays
+//!
+//! Brain, emotion, knowledge-source and item colors used to be hardcoded at
+//! every call site, so the same brain showed up red in one panel and salmon
+//! in the next. Every panel now asks the `VisualStyle` resource instead.
+//! Panels that only have a `&World` go through [`visual_style`], which
+//! falls back to the defaults when the resource isn't inserted.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use bevy::prelude::*;
+use bevy_egui::egui::Color32;
+
+use crate::agent::brains::proposal::BrainType;
+use crate::agent::mind::knowledge::{Concept, Source};
+use crate::agent::psyche::emotions::EmotionType;
+
+/// Color and icon for one concept (items in inventories, mostly).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConceptStyle {
+    pub color: Color32,
+    pub icon: &'static str,
+}
+
+/// Every themable UI color. Lookups for an unmapped key return `fallback`
+/// (and `FALLBACK_ICON` for concept icons) rather than panicking, so a
+/// partial user theme still renders.
+#[derive(Resource, Debug, Clone)]
+pub struct VisualStyle {
+    /// Strong brain color: power bars, winner labels, intent overlay.
+    pub brains: HashMap<BrainType, Color32>,
+    /// Softer brain color for the per-proposal lines.
+    pub brain_proposals: HashMap<BrainType, Color32>,
+    pub emotions: HashMap<EmotionType, Color32>,
+    pub sources: HashMap<Source, Color32>,
+    pub concepts: HashMap<Concept, ConceptStyle>,
+    pub fallback: Color32,
+}
+
+pub const FALLBACK_ICON: &str = "•";
+
+impl VisualStyle {
+    pub fn brain(&self, brain: BrainType) -> Color32 {
+        self.brains.get(&brain).copied().unwrap_or(self.fallback)
+    }
+
+    pub fn brain_proposal(&self, brain: BrainType) -> Color32 {
+        self.brain_proposals
+            .get(&brain)
+            .copied()
+            .unwrap_or(self.fallback)
+    }
+
+    pub fn emotion(&self, emotion: EmotionType) -> Color32 {
+        self.emotions
+            .get(&emotion)
+            .copied()
+            .unwrap_or(self.fallback)
+    }
+
+    pub fn source(&self, source: Source) -> Color32 {
+        self.sources.get(&source).copied().unwrap_or(self.fallback)
+    }
+
+    pub fn concept_color(&self, concept: Concept) -> Color32 {
+        self.concepts
+            .get(&concept)
+            .map_or(self.fallback, |style| style.color)
+    }
+
+    pub fn concept_icon(&self, concept: Concept) -> &'static str {
+        self.concepts
+            .get(&concept)
+            .map_or(FALLBACK_ICON, |style| style.icon)
+    }
+}
+
+impl Default for VisualStyle {
+    fn default() -> Self {
+        let brains = HashMap::from([
+            (BrainType::Survival, Color32::from_rgb(230, 120, 100)),
+            (BrainType::Emotional, Color32::from_rgb(255, 160, 210)),
+            (BrainType::Rational, Color32::from_rgb(140, 200, 255)),
+        ]);
+        let brain_proposals = HashMap::from([
+            (BrainType::Survival, Color32::LIGHT_RED),
+            (BrainType::Emotional, Color32::from_rgb(255, 182, 193)),
+            (BrainType::Rational, Color32::LIGHT_BLUE),
+        ]);
+        let emotions = HashMap::from([
+            (EmotionType::Joy, Color32::from_rgb(255, 220, 80)),
+            (EmotionType::Sadness, Color32::from_rgb(100, 140, 220)),
+            (EmotionType::Fear, Color32::from_rgb(180, 120, 220)),
+            (EmotionType::Anger, Color32::from_rgb(220, 80, 60)),
+            (EmotionType::Disgust, Color32::from_rgb(140, 200, 80)),
+            (EmotionType::Surprise, Color32::from_rgb(255, 180, 100)),
+        ]);
+        let sources = HashMap::from([
+            (Source::Perception, Color32::LIGHT_BLUE),
+            (Source::Inferred, Color32::LIGHT_YELLOW),
+            (Source::Intrinsic, Color32::LIGHT_GRAY),
+            (Source::Cultural, Color32::from_rgb(255, 200, 150)),
+            (Source::Communicated, Color32::LIGHT_GREEN),
+            (Source::Observed, Color32::from_rgb(150, 200, 255)),
+            (Source::Experienced, Color32::from_rgb(255, 150, 200)),
+            (Source::Hearsay, Color32::from_rgb(200, 150, 255)),
+        ]);
+        let concept = |r, g, b, icon| ConceptStyle {
+            color: Color32::from_rgb(r, g, b),
+            icon,
+        };
+        let concepts = HashMap::from([
+            (Concept::Apple, concept(220, 70, 60, "🍎")),
+            (Concept::Berry, concept(150, 90, 200, "🫐")),
+            (Concept::Meat, concept(200, 90, 90, "🥩")),
+            (Concept::CookedMeat, concept(180, 110, 60, "🍖")),
+            (Concept::Fish, concept(120, 170, 210, "🐟")),
+            (Concept::Water, concept(80, 150, 230, "💧")),
+            (Concept::Wood, concept(150, 110, 70, "🪵")),
+            (Concept::Stick, concept(170, 130, 90, "🥢")),
+            (Concept::Stone, concept(150, 150, 150, "🪨")),
+            (Concept::LargeLeaves, concept(90, 170, 80, "🍃")),
+        ]);
+        Self {
+            brains,
+            brain_proposals,
+            emotions,
+            sources,
+            concepts,
+            fallback: Color32::GRAY,
+        }
+    }
+}
+
+static DEFAULT_STYLE: LazyLock<VisualStyle> = LazyLock::new(VisualStyle::default);
+
+/// The active style, or the defaults when `VisualStyle` isn't inserted
+/// (headless tools rendering a panel, tests with a bare `World`).
+pub fn visual_style(world: &World) -> &VisualStyle {
+    world
+        .get_resource::<VisualStyle>()
+        .unwrap_or_else(|| &DEFAULT_STYLE)
+}
+
+/// Convert a style color for gizmo/sprite drawing.
+pub fn to_bevy_color(color: Color32, alpha: f32) -> Color {
+    Color::srgba_u8(
+        color.r(),
+        color.g(),
+        color.b(),
+        (alpha * 255.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_style_gives_every_brain_emotion_and_source_its_own_color() {
+        let style = VisualStyle::default();
+        let brains = [
+            BrainType::Survival,
+            BrainType::Emotional,
+            BrainType::Rational,
+        ];
+        let emotions = [
+            EmotionType::Joy,
+            EmotionType::Sadness,
+            EmotionType::Fear,
+            EmotionType::Anger,
+            EmotionType::Disgust,
+            EmotionType::Surprise,
+        ];
+        let sources = [
+            Source::Intrinsic,
+            Source::Cultural,
+            Source::Communicated,
+            Source::Hearsay,
+            Source::Observed,
+            Source::Experienced,
+            Source::Inferred,
+            Source::Perception,
+        ];
+
+        for colors in [
+            brains.map(|b| style.brain(b)).to_vec(),
+            brains.map(|b| style.brain_proposal(b)).to_vec(),
+            emotions.map(|e| style.emotion(e)).to_vec(),
+            sources.map(|s| style.source(s)).to_vec(),
+        ] {
+            for (i, color) in colors.iter().enumerate() {
+                assert_ne!(*color, style.fallback, "unmapped entry at {i}");
+                assert!(
+                    !colors[i + 1..].contains(color),
+                    "two entries share {color:?}"
+                );
+            }
+        }
+
+        // Stable: a second default is the same theme.
+        let again = VisualStyle::default();
+        assert!(brains.iter().all(|&b| style.brain(b) == again.brain(b)));
+        assert!(sources.iter().all(|&s| style.source(s) == again.source(s)));
+        assert_eq!(
+            style.concept_icon(Concept::Apple),
+            again.concept_icon(Concept::Apple)
+        );
+        assert_eq!(style.concept_icon(Concept::AppleTree), FALLBACK_ICON);
+    }
+
+    #[test]
+    fn panels_read_the_inserted_style_and_fall_back_to_defaults() {
+        let mut world = World::new();
+        assert_eq!(
+            visual_style(&world).brain(BrainType::Rational),
+            VisualStyle::default().brain(BrainType::Rational)
+        );
+
+        let mut custom = VisualStyle::default();
+        custom.brains.insert(BrainType::Rational, Color32::GOLD);
+        custom.concepts.insert(
+            Concept::Apple,
+            ConceptStyle {
+                color: Color32::GREEN,
+                icon: "A",
+            },
+        );
+        world.insert_resource(custom);
+
+        let style = visual_style(&world);
+        assert_eq!(style.brain(BrainType::Rational), Color32::GOLD);
+        assert_eq!(style.concept_icon(Concept::Apple), "A");
+        assert_eq!(
+            to_bevy_color(style.brain(BrainType::Rational), 1.0),
+            Color::srgba_u8(255, 215, 0, 255)
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! World stats: one dashboard of aggregate world health.
+//!
+//! Reads: Agent (EmotionalState, PhysicalNeeds, MindGraph), ConverseRegistry,
+//! TickCount, Time<Real>, DiagnosticsStore (FPS), WorldStatsConfig
+//! Writes: WorldStats (refreshed every `refresh_secs` of wall-clock time)
+//! Upstream: the simulation plugins that own the components above
+//! Downstream: the `WorldStats` dock tab in `ui::mod`
+//!
+//! Per-agent panels answer "how is this one doing"; nothing answered "how
+//! is the world doing". `update_world_stats` folds the existing queries
+//! into a handful of numbers — population, average mood and hunger, live
+//! conversations, total beliefs held, and how fast ticks are actually
+//! advancing — and the tab just paints the last sample.
+
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::agent::Agent;
+use crate::agent::body::needs::PhysicalNeeds;
+use crate::agent::engagement::converse::ConverseRegistry;
+use crate::agent::mind::knowledge::MindGraph;
+use crate::agent::psyche::emotions::EmotionalState;
+use crate::core::tick::TickCount;
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct WorldStatsConfig {
+    /// Wall-clock seconds between samples. 0 = every frame.
+    pub refresh_secs: f32,
+}
+
+impl Default for WorldStatsConfig {
+    fn default() -> Self {
+        Self { refresh_secs: 0.5 }
+    }
+}
+
+/// The latest aggregate sample. Averages are over agents that carry the
+/// component; with none they stay at zero.
+#[derive(Resource, Reflect, Clone, Debug, Default)]
+#[reflect(Resource)]
+pub struct WorldStats {
+    pub population: usize,
+    /// Mean `EmotionalState::current_mood`, -1..1.
+    pub average_mood: f32,
+    /// Mean `PhysicalNeeds::hunger_urgency`, 0..1.
+    pub average_hunger: f32,
+    pub active_conversations: usize,
+    /// Triples summed across every agent's MindGraph.
+    pub total_triples: usize,
+    /// Ticks actually advanced per wall-clock second since the last sample.
+    pub measured_tick_rate: f32,
+    /// Requested rate from `TickCount::ticks_per_second`.
+    pub target_tick_rate: f32,
+    pub fps: Option<f64>,
+    /// Tick of this sample.
+    pub tick: u64,
+    /// Wall-clock seconds at this sample; `None` until the first one.
+    pub sampled_at_secs: Option<f64>,
+}
+
+/// Bevy system (Update): re-sample `WorldStats` once `refresh_secs` have
+/// passed since the previous sample.
+#[allow(clippy::too_many_arguments)]
+pub fn update_world_stats(
+    config: Res<WorldStatsConfig>,
+    tick: Res<TickCount>,
+    time: Option<Res<Time<Real>>>,
+    diagnostics: Option<Res<DiagnosticsStore>>,
+    conversations: Option<Res<ConverseRegistry>>,
+    agents: Query<
+        (
+            Option<&EmotionalState>,
+            Option<&PhysicalNeeds>,
+            Option<&MindGraph>,
+        ),
+        With<Agent>,
+    >,
+    mut stats: ResMut<WorldStats>,
+) {
+    let now = time.map_or(0.0, |t| t.elapsed_secs_f64());
+    if let Some(last) = stats.sampled_at_secs
+        && now - last < f64::from(config.refresh_secs)
+    {
+        return;
+    }
+
+    let mut population = 0;
+    let (mut mood_sum, mut moods) = (0.0, 0);
+    let (mut hunger_sum, mut hungers) = (0.0, 0);
+    let mut total_triples = 0;
+    for (emotions, needs, mind) in agents.iter() {
+        population += 1;
+        if let Some(emotions) = emotions {
+            mood_sum += emotions.current_mood;
+            moods += 1;
+        }
+        if let Some(needs) = needs {
+            hunger_sum += needs.hunger_urgency();
+            hungers += 1;
+        }
+        if let Some(mind) = mind {
+            total_triples += mind.len();
+        }
+    }
+
+    let measured_tick_rate = match stats.sampled_at_secs {
+        Some(last) if now > last => {
+            (tick.current.saturating_sub(stats.tick) as f64 / (now - last)) as f32
+        }
+        _ => 0.0,
+    };
+
+    *stats = WorldStats {
+        population,
+        average_mood: if moods > 0 {
+            mood_sum / moods as f32
+        } else {
+            0.0
+        },
+        average_hunger: if hungers > 0 {
+            hunger_sum / hungers as f32
+        } else {
+            0.0
+        },
+        active_conversations: conversations.map_or(0, |c| c.active().count()),
+        total_triples,
+        measured_tick_rate,
+        target_tick_rate: tick.ticks_per_second,
+        fps: diagnostics.and_then(|d| {
+            d.get(&FrameTimeDiagnosticsPlugin::FPS)
+                .and_then(|fps| fps.average())
+        }),
+        tick: tick.current,
+        sampled_at_secs: Some(now),
+    };
+}
+
+/// Paint the last sample into the `WorldStats` dock tab.
+pub fn render_world_stats(ui: &mut egui::Ui, world: &World) {
+    let Some(stats) = world.get_resource::<WorldStats>() else {
+        ui.label("WorldStats not found.");
+        return;
+    };
+    egui::Grid::new("world_stats_grid")
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            let rows = [
+                ("Population", stats.population.to_string()),
+                ("Average mood", format!("{:+.2}", stats.average_mood)),
+                ("Average hunger", format!("{:.2}", stats.average_hunger)),
+                (
+                    "Active conversations",
+                    stats.active_conversations.to_string(),
+                ),
+                ("Total triples", stats.total_triples.to_string()),
+                (
+                    "Tick rate",
+                    format!(
+                        "{:.1} / {:.0} target",
+                        stats.measured_tick_rate, stats.target_tick_rate
+                    ),
+                ),
+                (
+                    "FPS",
+                    stats
+                        .fps
+                        .map_or_else(|| "–".to_string(), |fps| format!("{fps:.0}")),
+                ),
+                ("Sampled at tick", stats.tick.to_string()),
+            ];
+            for (label, value) in rows {
+                ui.label(label);
+                ui.monospace(value);
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::engagement::EngagementRegistry;
+    use crate::agent::engagement::converse::ConversationState;
+    use crate::agent::mind::knowledge::{Concept, Node, Predicate, Triple, Value, setup_ontology};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn aggregates_match_a_small_constructed_world() {
+        let mut world = World::new();
+        world.insert_resource(TickCount {
+            current: 120,
+            ..Default::default()
+        });
+        world.init_resource::<WorldStatsConfig>();
+        world.init_resource::<WorldStats>();
+
+        let mut hungers = Vec::new();
+        let mut agents = Vec::new();
+        for (mood, extra_triples) in [(0.6, 0u64), (-0.2, 2)] {
+            let mut mind = MindGraph::new(setup_ontology());
+            for i in 0..extra_triples {
+                mind.assert(Triple::new(
+                    Node::Entity(Entity::from_bits(i + 1)),
+                    Predicate::IsA,
+                    Value::Concept(Concept::Food),
+                ));
+            }
+            let needs = PhysicalNeeds::just_woke_up();
+            hungers.push(needs.hunger_urgency());
+            let emotions = EmotionalState {
+                current_mood: mood,
+                ..Default::default()
+            };
+            agents.push(world.spawn((Agent, emotions, needs, mind)).id());
+        }
+        // Not an agent: must not count toward anything.
+        world.spawn(EmotionalState {
+            current_mood: -1.0,
+            ..Default::default()
+        });
+
+        let mut ids = EngagementRegistry::default();
+        let mut registry = ConverseRegistry::default();
+        registry.start(&mut ids, agents.clone(), 0);
+        let ended = registry.start(&mut ids, agents.clone(), 0);
+        registry.conversations.get_mut(&ended).unwrap().state = ConversationState::Ended;
+        world.insert_resource(registry);
+
+        let expected_triples: usize = agents
+            .iter()
+            .map(|&a| world.get::<MindGraph>(a).unwrap().len())
+            .sum();
+
+        world.run_system_once(update_world_stats).unwrap();
+
+        let stats = world.resource::<WorldStats>();
+        assert_eq!(stats.population, 2);
+        assert!((stats.average_mood - 0.2).abs() < 1e-5);
+        assert!((stats.average_hunger - (hungers[0] + hungers[1]) / 2.0).abs() < 1e-5);
+        assert_eq!(stats.active_conversations, 1);
+        assert_eq!(stats.total_triples, expected_triples);
+        assert_eq!(stats.tick, 120);
+        assert_eq!(stats.target_tick_rate, 60.0);
+        assert_eq!(stats.sampled_at_secs, Some(0.0));
+    }
+}
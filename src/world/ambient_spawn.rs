@@ -0,0 +1,128 @@
+//! Ambient spawning: newcomers wander in at the map edge when the human
+//! population falls below a floor.
+//!
+//! Reads: AmbientSpawnConfig, TickCount, WorldMap, Ontology, Person + Alive markers
+//! Writes: Person entities (via `spawn_person`), SimRng (edge tile + culture draws)
+//! Upstream: world::spawn_config (`WorldSpawnConfig::ambient`), agent death (population loss)
+//! Downstream: agent systems consume the newcomer like any initial spawn
+//!
+//! Without this a long run only ever loses people. Disabled by default so
+//! existing scenarios keep a closed population; a world-gen config that
+//! turns it on gets at most one newcomer per `interval_ticks` while the
+//! living human count sits below `population_floor`. Every draw goes
+//! through `SimRng`, so the same seed brings the same newcomers to the
+//! same tiles.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::agent::culture::{Culture, create_cultural_knowledge};
+use crate::agent::mind::knowledge::Ontology;
+use crate::agent::{Alive, Person};
+use crate::core::{SimRng, TickCount};
+use crate::world::human::spawn_person;
+use crate::world::map::WorldMap;
+
+/// Cultures a newcomer can arrive with — the same roll as the first
+/// settlement group.
+const NEWCOMER_CULTURES: [Culture; 2] = [Culture::Nomad, Culture::Farmer];
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct AmbientSpawnConfig {
+    pub enabled: bool,
+    /// Newcomers arrive only while fewer living humans than this remain.
+    pub population_floor: usize,
+    /// Minimum ticks between two arrivals, so a collapse refills gradually.
+    pub interval_ticks: u64,
+}
+
+impl Default for AmbientSpawnConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            population_floor: 4,
+            interval_ticks: 600,
+        }
+    }
+}
+
+/// Every walkable tile on the outer ring of the map, row-major.
+pub fn walkable_edge_tiles(map: &WorldMap) -> Vec<(u32, u32)> {
+    let mut tiles = Vec::new();
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let on_edge = x == 0 || y == 0 || x + 1 == map.width || y + 1 == map.height;
+            if on_edge && map.get_tile(x, y).is_some_and(|t| t.is_walkable()) {
+                tiles.push((x, y));
+            }
+        }
+    }
+    tiles
+}
+
+/// Spawns one newcomer at a random walkable edge tile when the living human
+/// population is below the floor and the last arrival is old enough.
+pub fn ambient_spawn(
+    mut commands: Commands,
+    config: Res<AmbientSpawnConfig>,
+    tick: Res<TickCount>,
+    map: Res<WorldMap>,
+    ontology: Res<Ontology>,
+    mut sim_rng: ResMut<SimRng>,
+    living: Query<(), (With<Person>, With<Alive>)>,
+    everyone: Query<(), With<Person>>,
+    mut last_arrival: Local<Option<u64>>,
+) {
+    if !config.enabled || living.iter().count() >= config.population_floor {
+        return;
+    }
+    if last_arrival.is_some_and(|last| tick.current < last + config.interval_ticks) {
+        return;
+    }
+    let edge = walkable_edge_tiles(&map);
+    if edge.is_empty() {
+        return;
+    }
+
+    let rng = sim_rng.inner_mut();
+    let (x, y) = edge[rng.random_range(0..edge.len())];
+    let culture = NEWCOMER_CULTURES[rng.random_range(0..NEWCOMER_CULTURES.len())];
+    let knowledge = Arc::new(create_cultural_knowledge(culture));
+    // Corpses keep `Person` until they rot away, so counting everyone
+    // usually hands the newcomer a name no one nearby has worn.
+    let index = everyone.iter().count();
+    let pos = map.tile_to_world(x as i32, y as i32);
+    spawn_person(
+        &mut commands,
+        ontology.clone(),
+        pos,
+        index,
+        culture,
+        knowledge,
+        rng,
+    );
+    *last_arrival = Some(tick.current);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::map::{Chunk, TileType};
+
+    #[test]
+    fn edge_tiles_skip_the_interior_and_water() {
+        let mut map = WorldMap::new(4, 4);
+        map.chunks.insert(IVec2::new(0, 0), Chunk::new(0, 0));
+        map.set_tile(0, 0, TileType::Water);
+
+        let edge = walkable_edge_tiles(&map);
+
+        assert_eq!(edge.len(), 11);
+        assert!(!edge.contains(&(0, 0)));
+        assert!(!edge.contains(&(1, 1)));
+        assert!(!edge.contains(&(2, 2)));
+    }
+}
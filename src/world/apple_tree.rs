@@ -17,13 +17,17 @@ pub struct VisualLeaves;
 #[derive(Component)]
 pub struct VisualApple;
 
-/// Component for resources that regenerate over time.
+/// Component for resources that regenerate over time. Each plant carries
+/// its own max yield and regrowth rate; the rate is mirrored into the
+/// ontology as `(kind, RegenerationRate, interval)`.
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct ResourceRegeneration {
     pub timer: f32,
+    /// Seconds to regrow one unit of `item`.
     pub interval: f32,
     pub item: Concept,
+    /// Most units the plant holds; regrowth pauses at this yield.
     pub max_amount: u32,
 }
 
@@ -0,0 +1,266 @@
+//! Biome regions: forest, plains and water bodies laid over the terrain.
+//!
+//! Reads: WorldMap (tile types), BiomeConfig
+//! Writes: BiomeMap (built with the spawn layout, inserted as a resource by the spawners)
+//! Upstream: world::spawn_config (compute_layout)
+//! Downstream: spawn_config (resources placed in their home biome),
+//!             mind::area_beliefs (what each area holds, as `Node::Area` beliefs)
+//!
+//! Terrain alone reads as one field of grass with everything scattered
+//! evenly across it. A low-frequency noise field splits dry land into
+//! forest and plains regions; water tiles form water bodies. Resources are
+//! mostly placed in their home biome — apple trees and wood in the forest,
+//! berry bushes on the plains — so the world has places worth knowing
+//! about. Each biome is a named area (`Node::Area`), letting agents hold
+//! beliefs like "the forest has wood".
+
+use bevy::prelude::*;
+use noise::{NoiseFn, Simplex};
+use rand::Rng;
+
+use crate::agent::mind::knowledge::{AreaId, Concept};
+use crate::world::map::{TILE_SIZE, TileType, WorldMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum Biome {
+    Forest,
+    Plains,
+    WaterBody,
+}
+
+impl Biome {
+    pub const ALL: [Biome; 3] = [Biome::Forest, Biome::Plains, Biome::WaterBody];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Biome::Forest => "Forest",
+            Biome::Plains => "Plains",
+            Biome::WaterBody => "Water",
+        }
+    }
+
+    /// The area this biome is known as in agents' minds.
+    pub fn area(self) -> AreaId {
+        AreaId(self.name().to_string())
+    }
+
+    /// The biome a resource of `concept` characteristically grows or lives
+    /// in, if any.
+    pub fn home_of(concept: Concept) -> Option<Biome> {
+        match concept {
+            Concept::AppleTree | Concept::WoodLog => Some(Biome::Forest),
+            Concept::BerryBush => Some(Biome::Plains),
+            Concept::Minnow | Concept::Pike => Some(Biome::WaterBody),
+            _ => None,
+        }
+    }
+}
+
+/// How biome regions are laid out and how strongly resources stick to them.
+#[derive(Reflect, Clone, Debug)]
+pub struct BiomeConfig {
+    /// Off = no biome map; resources scatter by terrain alone.
+    pub enabled: bool,
+    /// Rough width of one forest or plains region, in tiles.
+    pub region_tiles: f32,
+    /// Land whose region noise (-1..1) is above this is forest, the rest
+    /// plains. 0.0 splits land roughly in half.
+    pub forest_threshold: f32,
+    /// Chance a resource is placed in its home biome rather than anywhere
+    /// its terrain allows.
+    pub resource_clustering: f32,
+    pub seed: u32,
+}
+
+impl Default for BiomeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            region_tiles: 48.0,
+            forest_threshold: 0.0,
+            resource_clustering: 0.85,
+            seed: 211,
+        }
+    }
+}
+
+/// Per-tile biome for the whole map.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BiomeMap {
+    pub width: u32,
+    pub height: u32,
+    tiles: Vec<Biome>,
+}
+
+impl BiomeMap {
+    pub fn generate(map: &WorldMap, config: &BiomeConfig) -> Self {
+        let noise = Simplex::new(config.seed);
+        let scale = f64::from(config.region_tiles.max(1.0));
+        let mut tiles = Vec::with_capacity((map.width * map.height) as usize);
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let biome = match map.get_tile(x, y) {
+                    Some(tile) if tile.is_water() => Biome::WaterBody,
+                    _ => {
+                        let region = noise.get([f64::from(x) / scale, f64::from(y) / scale]);
+                        if region as f32 > config.forest_threshold {
+                            Biome::Forest
+                        } else {
+                            Biome::Plains
+                        }
+                    }
+                };
+                tiles.push(biome);
+            }
+        }
+        Self {
+            width: map.width,
+            height: map.height,
+            tiles,
+        }
+    }
+
+    pub fn biome_at_tile(&self, x: u32, y: u32) -> Option<Biome> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.tiles.get((y * self.width + x) as usize).copied()
+    }
+
+    /// Biome at a world position, or `None` off the map.
+    pub fn biome_at(&self, pos: Vec2) -> Option<Biome> {
+        if pos.x < 0.0 || pos.y < 0.0 {
+            return None;
+        }
+        self.biome_at_tile(
+            (pos.x / TILE_SIZE).floor() as u32,
+            (pos.y / TILE_SIZE).floor() as u32,
+        )
+    }
+
+    pub fn tile_count(&self, biome: Biome) -> usize {
+        self.tiles.iter().filter(|b| **b == biome).count()
+    }
+
+    /// A random tile of an allowed terrain type inside `biome`. Returns its
+    /// world position (tile center), or `None` after `max_attempts` misses.
+    pub fn find_tile(
+        &self,
+        map: &WorldMap,
+        rng: &mut impl Rng,
+        biome: Biome,
+        allowed: &[TileType],
+        max_attempts: usize,
+    ) -> Option<Vec2> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        for _ in 0..max_attempts {
+            let x = rng.random_range(0..self.width);
+            let y = rng.random_range(0..self.height);
+            if self.biome_at_tile(x, y) != Some(biome) {
+                continue;
+            }
+            if map.get_tile(x, y).is_some_and(|t| allowed.contains(&t)) {
+                return Some(map.tile_to_world(x as i32, y as i32));
+            }
+        }
+        None
+    }
+
+    /// Roll `config.resource_clustering` and, on success, find a tile for
+    /// a `concept` resource in its home biome. `None` means the caller
+    /// places it by terrain alone.
+    pub fn home_tile(
+        &self,
+        map: &WorldMap,
+        rng: &mut impl Rng,
+        config: &BiomeConfig,
+        concept: Concept,
+        allowed: &[TileType],
+        max_attempts: usize,
+    ) -> Option<Vec2> {
+        let home = Biome::home_of(concept)?;
+        if rng.random::<f32>() >= config.resource_clustering {
+            return None;
+        }
+        self.find_tile(map, rng, home, allowed, max_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::map::{CHUNK_SIZE, Chunk};
+    use crate::world::spawn_config::WorldSpawnConfig;
+
+    fn grass_map(chunks: i32) -> WorldMap {
+        let size = CHUNK_SIZE * chunks as u32;
+        let mut map = WorldMap::new(size, size);
+        for cx in 0..chunks {
+            for cy in 0..chunks {
+                map.chunks.insert(IVec2::new(cx, cy), Chunk::new(cx, cy));
+            }
+        }
+        map
+    }
+
+    fn share_in(biomes: &BiomeMap, positions: &[(Vec2, u32)], biome: Biome) -> f32 {
+        let inside = positions
+            .iter()
+            .filter(|(pos, _)| biomes.biome_at(*pos) == Some(biome))
+            .count();
+        inside as f32 / positions.len() as f32
+    }
+
+    #[test]
+    fn resources_cluster_in_their_home_biome() {
+        let mut map = grass_map(8);
+        for x in 0..10 {
+            for y in 0..10 {
+                map.set_tile(x, y, TileType::Water);
+            }
+        }
+        let config = WorldSpawnConfig {
+            map_size: (map.width, map.height),
+            humans: 0,
+            second_humans: 0,
+            deer: 0,
+            wolves: 0,
+            minnows: 0,
+            pikes: 0,
+            berry_bushes: 80,
+            apple_trees: 40,
+            stone_nodes: 0,
+            wood_logs: 40,
+            seed: 5,
+            ..WorldSpawnConfig::game_defaults()
+        };
+
+        let layout = config.compute_layout(&map);
+        let biomes = layout.biomes.as_ref().expect("biomes are on by default");
+
+        assert_eq!(biomes.biome_at_tile(3, 3), Some(Biome::WaterBody));
+        let land = biomes.tile_count(Biome::Forest) + biomes.tile_count(Biome::Plains);
+        let forest_share = biomes.tile_count(Biome::Forest) as f32 / land as f32;
+        assert!(
+            (0.05..0.95).contains(&forest_share),
+            "land should split into forest and plains, forest is {forest_share}"
+        );
+
+        for (name, positions, home) in [
+            ("apple trees", &layout.apple_tree_positions, Biome::Forest),
+            ("wood", &layout.wood_log_positions, Biome::Forest),
+            ("berry bushes", &layout.berry_bush_positions, Biome::Plains),
+        ] {
+            assert!(!positions.is_empty(), "no {name} placed");
+            let land_share = biomes.tile_count(home) as f32 / land as f32;
+            let share = share_in(biomes, positions, home);
+            assert!(
+                share >= 0.7 && share > land_share,
+                "{name}: only {share} in the {}, which is {land_share} of the land",
+                home.name()
+            );
+        }
+    }
+}
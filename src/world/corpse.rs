@@ -1,8 +1,9 @@
 //! Corpse: the post-kill state of a slain creature.
 //!
-//! Reads: nothing
+//! Reads: CorpseConfig (decay timer)
 //! Writes: corpse entities — strips living components from the source entity
-//!         and adds Affordance::Harvest plus meat to its existing ItemSlots.
+//!         and adds Affordance::Harvest plus meat to its existing ItemSlots;
+//!         despawns rotted corpses and prunes every MindGraph's beliefs about them
 //! Upstream: Becomes substrate (InPlace mode), called from Attack/Bite kills
 //! Downstream: agent perception, Harvest action (scavengers + the killer)
 //!
@@ -10,13 +11,15 @@
 //! stripped and its body's meat exposed for harvesting. We do not despawn
 //! and respawn — preserving the entity ID keeps episodic memory and
 //! relationship triples (e.g. "wolf_pack_member_3 IsA Friend") pointing at
-//! a meaningful entity even after death.
+//! a meaningful entity even after death — until the corpse rots away after
+//! `CorpseConfig::decay_ticks`, at which point it is despawned and every
+//! mind forgets it so nobody keeps planning toward a dangling id.
 
 use crate::agent::actions::ActionType;
 use crate::agent::affordance::Affordance;
 use crate::agent::inventory::EntityType;
 use crate::agent::item_slots::ItemSlots;
-use crate::agent::mind::knowledge::Concept;
+use crate::agent::mind::knowledge::{Concept, MindGraph};
 use crate::ui::sprite_animation::SpriteBody;
 use crate::world::property::HarvestableComponent;
 use bevy::ecs::world::CommandQueue;
@@ -27,6 +30,30 @@ use bevy::prelude::*;
 /// prey species drops the same flat amount.
 pub const DEFAULT_CORPSE_MEAT: u32 = 10;
 
+/// How long corpses last before rotting away.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct CorpseConfig {
+    /// Ticks from death until the corpse is despawned. Six game hours by
+    /// default — long enough for the killer and scavengers to feed.
+    pub decay_ticks: u64,
+}
+
+impl Default for CorpseConfig {
+    fn default() -> Self {
+        Self {
+            decay_ticks: 6 * crate::core::GameTime::TICKS_PER_HOUR,
+        }
+    }
+}
+
+/// Marks a dead body and tracks how long it has been rotting.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Corpse {
+    pub age_ticks: u64,
+}
+
 /// Component bundle for a freshly-spawned corpse with no prior identity.
 /// Used by `spawn_concept_entity` for the rare case where a corpse is
 /// summoned standalone (e.g. tests, future "old bones" world generation).
@@ -38,6 +65,7 @@ pub fn corpse_components(position: Vec2, meat_qty: u32) -> impl Bundle {
 
     (
         Name::new("Corpse"),
+        Corpse::default(),
         EntityType(Concept::Corpse),
         crate::world::Physical,
         Transform::from_translation(position.extend(1.0)),
@@ -126,6 +154,7 @@ pub fn kill_into_corpse(commands: &mut Commands, entity: Entity, meat_qty: u32)
         // Swap identity and add the corpse-specific affordance.
         entity_mut.insert(EntityType(Concept::Corpse));
         entity_mut.insert(Name::new("Corpse"));
+        entity_mut.insert(Corpse::default());
         entity_mut.insert(Affordance {
             action_type: ActionType::Harvest,
             cost: 2.0,
@@ -144,3 +173,34 @@ pub fn kill_into_corpse(commands: &mut Commands, entity: Entity, meat_qty: u32)
     });
     commands.append(&mut queue);
 }
+
+/// Bevy system (FixedUpdate): ages corpses and despawns those past
+/// `CorpseConfig::decay_ticks`, pruning every mind's beliefs about them.
+pub fn decay_corpses(
+    mut commands: Commands,
+    config: Res<CorpseConfig>,
+    mut corpses: Query<(Entity, &mut Corpse)>,
+    mut minds: Query<&mut MindGraph>,
+) {
+    for (entity, mut corpse) in corpses.iter_mut() {
+        corpse.age_ticks += 1;
+        if corpse.age_ticks < config.decay_ticks {
+            continue;
+        }
+        for mut mind in minds.iter_mut() {
+            mind.forget_entity(entity);
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct CorpsePlugin;
+
+impl Plugin for CorpsePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Corpse>()
+            .register_type::<CorpseConfig>()
+            .init_resource::<CorpseConfig>()
+            .add_systems(FixedUpdate, decay_corpses);
+    }
+}
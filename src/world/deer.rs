@@ -183,6 +183,7 @@ pub fn spawn_deer<R: Rng>(
             // × phenotype.vision; this placeholder is overwritten before first perception tick.
             crate::agent::mind::perception::Vision {
                 range: SpeciesProfile::deer().vision_range,
+                fov_degrees: SpeciesProfile::deer().fov_degrees,
             },
             crate::agent::mind::perception::VisibleObjects::default(),
             Visibility::default(),
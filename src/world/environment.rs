@@ -1,5 +1,6 @@
 use crate::core::GameTime;
 use crate::world::property::LightSource;
+use crate::world::weather::{DayPhase, Weather};
 use bevy::asset::RenderAssetUsages;
 use bevy::image::Image;
 use bevy::prelude::*;
@@ -10,11 +11,13 @@ pub struct EnvironmentPlugin;
 impl Plugin for EnvironmentPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<LightLevel>()
+            .register_type::<DayCycle>()
             .register_type::<ColorTint>()
             .register_type::<BaseColor>()
             .register_type::<AgentBodySprite>()
             .register_type::<CampfireGlowSprite>()
             .init_resource::<LightLevel>()
+            .init_resource::<DayCycle>()
             .init_resource::<ColorTint>()
             .add_systems(Startup, init_campfire_glow_texture)
             .add_systems(FixedUpdate, update_light_level)
@@ -95,6 +98,33 @@ impl Default for LightLevel {
     }
 }
 
+/// Light level in full darkness; `compute_light_level` never dips below it.
+pub const NIGHT_LIGHT_LEVEL: f32 = 0.3;
+
+/// Where the day stands, updated with `LightLevel`. `daylight` rescales the
+/// light level so full night is 0.0 and full day 1.0 — the sim scales its
+/// night behaviour by it. `phase` is the coarse band the UI shows.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct DayCycle {
+    pub daylight: f32,
+    pub phase: DayPhase,
+}
+
+impl Default for DayCycle {
+    fn default() -> Self {
+        Self {
+            daylight: 1.0,
+            phase: DayPhase::Day,
+        }
+    }
+}
+
+/// Rescale a light level to 0.0 (full night) ..= 1.0 (full day).
+pub fn daylight(light: f32) -> f32 {
+    ((light - NIGHT_LIGHT_LEVEL) / (1.0 - NIGHT_LIGHT_LEVEL)).clamp(0.0, 1.0)
+}
+
 /// Global color temperature tint applied on top of the light level.
 /// Components are RGB multipliers where `Vec3::ONE` means neutral (no tint).
 #[derive(Resource, Debug, Clone, Reflect)]
@@ -129,15 +159,15 @@ pub struct CampfireGlowSprite;
 /// Night: 0.3, Dawn (5–7): 0.3→1.0, Day (7–18): 1.0, Dusk (18–20): 1.0→0.3.
 pub fn compute_light_level(hour: f32) -> f32 {
     if hour < 5.0 {
-        0.3
+        NIGHT_LIGHT_LEVEL
     } else if hour < 7.0 {
-        hour_lerp(0.3, 1.0, hour, 5.0, 7.0)
+        hour_lerp(NIGHT_LIGHT_LEVEL, 1.0, hour, 5.0, 7.0)
     } else if hour < 18.0 {
         1.0
     } else if hour < 20.0 {
-        hour_lerp(1.0, 0.3, hour, 18.0, 20.0)
+        hour_lerp(1.0, NIGHT_LIGHT_LEVEL, hour, 18.0, 20.0)
     } else {
-        0.3
+        NIGHT_LIGHT_LEVEL
     }
 }
 
@@ -158,21 +188,32 @@ fn apply_light_tint(base: &Color, light: f32, tint: Vec3) -> Color {
     )
 }
 
+/// Fraction of the gap to the current weather's tint closed each tick, so
+/// the view greys over as a storm builds instead of snapping.
+const WEATHER_TINT_BLEND: f32 = 0.01;
+
 pub fn update_light_level(
     time: Res<GameTime>,
+    weather: Res<Weather>,
     mut light: ResMut<LightLevel>,
+    mut cycle: ResMut<DayCycle>,
     mut tint: ResMut<ColorTint>,
+    mut sky_tint: Local<Option<Vec3>>,
 ) {
     let hour = time.hours as f32 + (time.minutes as f32 / 60.0);
 
     light.0 = compute_light_level(hour);
+    *cycle = DayCycle {
+        daylight: daylight(light.0),
+        phase: DayPhase::at_hour(hour),
+    };
 
     let neutral = Vec3::ONE;
     let warm_dawn = Vec3::new(1.15, 0.88, 0.68);
     let warm_dusk = Vec3::new(1.1, 0.72, 0.52);
     let cool_night = Vec3::new(0.75, 0.85, 1.1);
 
-    tint.0 = if hour < 5.0 {
+    let day_tint = if hour < 5.0 {
         cool_night
     } else if hour < 6.0 {
         neutral.lerp(warm_dawn, hour - 5.0)
@@ -187,15 +228,18 @@ pub fn update_light_level(
     } else {
         cool_night
     };
+
+    let target = weather.tint();
+    let sky = sky_tint.get_or_insert(target);
+    *sky = sky.lerp(target, WEATHER_TINT_BLEND);
+    tint.0 = day_tint * *sky;
 }
 
 fn apply_visual_lighting(light: Res<LightLevel>, mut clear_color: ResMut<ClearColor>) {
     let day_color = Vec3::new(0.4, 0.6, 0.9);
     let night_color = Vec3::new(0.05, 0.05, 0.2);
 
-    // Map 0.3 (night) → 1.0 (day) to 0.0..1.0 for lerp
-    let t = ((light.0 - 0.3) / 0.7).clamp(0.0, 1.0);
-    let current = night_color.lerp(day_color, t);
+    let current = night_color.lerp(day_color, daylight(light.0));
     clear_color.0 = Color::srgb(current.x, current.y, current.z);
 }
 
@@ -285,4 +329,12 @@ mod tests {
     fn night_before_midnight_is_dim() {
         assert_eq!(compute_light_level(23.0), 0.3);
     }
+
+    #[test]
+    fn daylight_runs_from_zero_at_night_to_one_at_noon() {
+        assert_eq!(daylight(compute_light_level(0.0)), 0.0);
+        assert_eq!(daylight(compute_light_level(12.0)), 1.0);
+        let dusk = daylight(compute_light_level(19.0));
+        assert!((dusk - 0.5).abs() < 1e-4, "dusk daylight was {dusk}");
+    }
 }
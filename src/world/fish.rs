@@ -264,6 +264,7 @@ pub fn spawn_minnow<R: Rng>(
     let heading = random_unit_vec(rng);
     let speed = species_profile.base_speed * variant.speed_jitter;
     let vision_range = species_profile.vision_range;
+    let fov_degrees = species_profile.fov_degrees;
 
     commands
         .spawn((
@@ -295,6 +296,7 @@ pub fn spawn_minnow<R: Rng>(
             mind,
             crate::agent::mind::perception::Vision {
                 range: vision_range,
+                fov_degrees,
             },
             crate::agent::mind::perception::VisibleObjects::default(),
             Visibility::default(),
@@ -340,6 +342,7 @@ pub fn spawn_pike<R: Rng>(
     let heading = random_unit_vec(rng);
     let speed = species_profile.base_speed * variant.speed_jitter;
     let vision_range = species_profile.vision_range;
+    let fov_degrees = species_profile.fov_degrees;
 
     commands
         .spawn((
@@ -368,6 +371,7 @@ pub fn spawn_pike<R: Rng>(
             mind,
             crate::agent::mind::perception::Vision {
                 range: vision_range,
+                fov_degrees,
             },
             crate::agent::mind::perception::VisibleObjects::default(),
             Visibility::default(),
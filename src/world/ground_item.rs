@@ -0,0 +1,88 @@
+//! Loose items lying on the ground.
+//!
+//! Reads: ItemSlots (to notice when a ground item has been emptied)
+//! Writes: GroundItem entities (EntityType, ItemSlots, Affordance → Pickup); despawns empty ones
+//! Upstream: anything that drops items into the world (spawn_ground_item callers)
+//! Downstream: brains::target_enumeration (Pickup targets via Affordance), actions::pickup
+//!
+//! A ground item is a tiny container: one Free slot holding whatever was
+//! dropped, plus a `Pickup` affordance so the planner treats it like any
+//! other resource the agent can walk to and collect. Once its last Thing
+//! has been picked up the entity is despawned.
+
+use bevy::prelude::*;
+
+use crate::agent::actions::ActionType;
+use crate::agent::affordance::Affordance;
+use crate::agent::inventory::EntityType;
+use crate::agent::item_slots::{Access, ItemSlots, Slot, Thing};
+use crate::agent::mind::knowledge::Concept;
+use crate::world::Physical;
+
+/// Marker for a loose-item entity on the ground.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct GroundItem;
+
+/// Logic-only components for a ground item holding `things` at `position`.
+pub fn ground_item_components(position: Vec2, things: Vec<Thing>) -> impl Bundle {
+    // Anyone may take what's lying on the ground.
+    let mut slot = Slot::free();
+    slot.extract_access = Access::Public;
+    slot.contents = things;
+    let slots = ItemSlots { slots: vec![slot] };
+
+    (
+        Name::new("Ground Item"),
+        GroundItem,
+        EntityType(Concept::GroundItem),
+        Physical,
+        Transform::from_translation(position.extend(0.6)),
+        GlobalTransform::default(),
+        slots,
+        Affordance {
+            action_type: ActionType::Pickup,
+            cost: 1.0,
+            distance: 16.0,
+            risk: 0.0,
+        },
+    )
+}
+
+/// Drop `things` on the ground at `position`. Returns `None` when there is
+/// nothing to drop.
+pub fn spawn_ground_item(
+    commands: &mut Commands,
+    position: Vec2,
+    things: Vec<Thing>,
+) -> Option<Entity> {
+    if things.is_empty() {
+        return None;
+    }
+    Some(
+        commands
+            .spawn(ground_item_components(position, things))
+            .id(),
+    )
+}
+
+/// Bevy system (FixedUpdate): despawns ground items that have been emptied.
+pub fn despawn_empty_ground_items(
+    mut commands: Commands,
+    items: Query<(Entity, &ItemSlots), (With<GroundItem>, Changed<ItemSlots>)>,
+) {
+    for (entity, slots) in items.iter() {
+        if slots.all_items().next().is_none() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct GroundItemPlugin;
+
+impl Plugin for GroundItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GroundItem>()
+            .add_systems(FixedUpdate, despawn_empty_ground_items);
+    }
+}
@@ -117,7 +117,7 @@ pub fn spawn_person<R: Rng>(
     ontology: Ontology,
     position: Vec2,
     index: usize,
-    _culture: crate::agent::culture::Culture,
+    culture: crate::agent::culture::Culture,
     cultural_knowledge: std::sync::Arc<Vec<crate::agent::mind::knowledge::Triple>>,
     rng: &mut R,
 ) -> Entity {
@@ -138,8 +138,10 @@ pub fn spawn_person<R: Rng>(
             // a full night's sleep — empty stomach, moderate thirst. Tests
             // that want fresh-well-fed agents still use `PhysicalNeeds::default()`.
             physical_needs: PhysicalNeeds::just_woke_up(),
+            culture,
             cultural_knowledge,
             extra_knowledge: Vec::new(),
+            archetype: None,
         },
         ontology,
     );
@@ -3,6 +3,7 @@ use crate::menu::{AppState, SimConfig};
 use crate::outline::OUTLINE_COLOR;
 use crate::palette::{Palette, PaletteColor};
 use crate::world::environment::BaseColor;
+use crate::world::pathfinding::{GridTile, PathfindingConfig, find_path, path_length};
 use bevy::prelude::*;
 use noise::{NoiseFn, Simplex};
 use std::collections::HashMap;
@@ -72,6 +73,12 @@ impl TileType {
         !matches!(self, TileType::Water)
     }
 
+    /// Whether this tile blocks sight. Rock outcrops stand taller than an
+    /// agent; open ground and water do not.
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, TileType::Rock)
+    }
+
     /// Whether this terrain type satisfies a concept-level trait (e.g. grass
     /// is `Grazable`, water is `Drinkable`). Lets the rational brain query
     /// "find tiles with trait X" directly against the world state instead of
@@ -173,11 +180,23 @@ impl Chunk {
     }
 }
 
+/// What happens at the edge of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgePolicy {
+    /// Edges are impassable: positions outside the map are not walkable.
+    #[default]
+    Wall,
+    /// The map is a torus: leaving one edge enters the opposite one, and
+    /// distances are measured the short way around.
+    Wrap,
+}
+
 #[derive(Resource)]
 pub struct WorldMap {
     pub width: u32,
     pub height: u32,
     pub chunks: HashMap<IVec2, Chunk>,
+    pub edge_policy: EdgePolicy,
 }
 
 impl WorldMap {
@@ -186,9 +205,69 @@ impl WorldMap {
             width,
             height,
             chunks: HashMap::new(),
+            edge_policy: EdgePolicy::Wall,
+        }
+    }
+
+    /// Bring a world position back onto the map under `Wrap`. Identity
+    /// under `Wall`.
+    pub fn wrap_position(&self, pos: Vec2) -> Vec2 {
+        match self.edge_policy {
+            EdgePolicy::Wall => pos,
+            EdgePolicy::Wrap => {
+                let (w, h) = self.pixel_bounds();
+                Vec2::new(pos.x.rem_euclid(w), pos.y.rem_euclid(h))
+            }
+        }
+    }
+
+    /// Shortest displacement from `from` to `to`. Under `Wrap` this may
+    /// cross an edge.
+    pub fn delta(&self, from: Vec2, to: Vec2) -> Vec2 {
+        let d = to - from;
+        match self.edge_policy {
+            EdgePolicy::Wall => d,
+            EdgePolicy::Wrap => {
+                let (w, h) = self.pixel_bounds();
+                Vec2::new(d.x - (d.x / w).round() * w, d.y - (d.y / h).round() * h)
+            }
         }
     }
 
+    /// Distance between two world positions, respecting `edge_policy`.
+    pub fn distance(&self, a: Vec2, b: Vec2) -> f32 {
+        self.delta(a, b).length()
+    }
+
+    /// Copies of `pos` shifted by whole map sizes that lie within `margin`
+    /// of the map, for spatial queries that need to see across a wrapped
+    /// edge. Empty under `Wall`.
+    pub fn wrapped_images(&self, pos: Vec2, margin: f32) -> Vec<Vec2> {
+        if self.edge_policy == EdgePolicy::Wall {
+            return Vec::new();
+        }
+        let (w, h) = self.pixel_bounds();
+        let shift = |v: f32, size: f32| -> f32 {
+            if v < margin {
+                size
+            } else if v > size - margin {
+                -size
+            } else {
+                0.0
+            }
+        };
+        let sx = shift(pos.x, w);
+        let sy = shift(pos.y, h);
+        let mut images: Vec<Vec2> = [Vec2::new(sx, 0.0), Vec2::new(0.0, sy), Vec2::new(sx, sy)]
+            .into_iter()
+            .filter(|s| *s != Vec2::ZERO)
+            .map(|s| pos + s)
+            .collect();
+        // Near only one edge, the corner shift repeats the single-axis one.
+        images.dedup();
+        images
+    }
+
     pub fn get_tile(&self, x: u32, y: u32) -> Option<TileType> {
         if x >= self.width || y >= self.height {
             return None;
@@ -243,6 +322,7 @@ impl WorldMap {
 
     /// Look up the tile type at a world position, if any.
     pub fn tile_at(&self, pos: Vec2) -> Option<TileType> {
+        let pos = self.wrap_position(pos);
         if !self.in_bounds(pos) {
             return None;
         }
@@ -250,11 +330,57 @@ impl WorldMap {
         self.get_tile(tx, ty)
     }
 
-    /// Check if a world position is walkable (in bounds and not impassable terrain).
+    /// Check if a world position is walkable (in bounds and not impassable
+    /// terrain). Under `Wrap` every position maps onto some tile.
     pub fn is_walkable(&self, pos: Vec2) -> bool {
         self.tile_at(pos).is_some_and(|t| t.is_walkable())
     }
 
+    /// Whether nothing opaque stands between two world positions. Walks a
+    /// Bresenham line over the tiles between them (across a wrapped edge
+    /// when that is shorter); the end tiles themselves never block, so an
+    /// agent standing on rock can still see out and be seen.
+    pub fn line_of_sight(&self, from: Vec2, to: Vec2) -> bool {
+        let tile = |p: Vec2| {
+            (
+                (p.x / TILE_SIZE).floor() as i32,
+                (p.y / TILE_SIZE).floor() as i32,
+            )
+        };
+        let (x0, y0) = tile(from);
+        let (x1, y1) = tile(from + self.delta(from, to));
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+        let (mut x, mut y) = (x0, y0);
+        let mut err = dx + dy;
+        loop {
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+            if (x, y) == (x1, y1) || (x, y) == (x0, y0) {
+                return true;
+            }
+            let center = self.tile_to_world(x, y);
+            if self.tile_at(center).is_some_and(|t| t.is_opaque()) {
+                return false;
+            }
+        }
+    }
+
+    /// Length in tiles of the shortest walkable route between two tiles
+    /// under the default `PathfindingConfig`, or `None` when either end is
+    /// blocked or no route exists. Unobstructed routes cost their
+    /// straight-line distance.
+    pub fn path_cost(&self, from: GridTile, to: GridTile) -> Option<f32> {
+        find_path(self, from, to, &PathfindingConfig::default()).map(|path| path_length(&path))
+    }
+
     /// Movement speed multiplier at a world position. Returns 0.0 for blocked or out-of-bounds.
     pub fn speed_at(&self, pos: Vec2) -> f32 {
         self.tile_at(pos)
@@ -1099,6 +1225,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wrap_distance_takes_the_short_way_around() {
+        let mut map = WorldMap::new(CHUNK_SIZE, CHUNK_SIZE);
+        let width = CHUNK_SIZE as f32 * TILE_SIZE;
+        let a = Vec2::new(4.0, 10.0);
+        let b = Vec2::new(width - 4.0, 10.0);
+        assert!((map.distance(a, b) - (width - 8.0)).abs() < 1e-3);
+
+        map.edge_policy = EdgePolicy::Wrap;
+        assert!((map.distance(a, b) - 8.0).abs() < 1e-3);
+        assert_eq!(map.wrap_position(Vec2::new(-4.0, 10.0)), b);
+    }
+
+    #[test]
+    fn rock_between_two_points_blocks_line_of_sight() {
+        let mut map = WorldMap::new(CHUNK_SIZE, CHUNK_SIZE);
+        map.chunks.insert(IVec2::new(0, 0), Chunk::new(0, 0));
+        map.set_tile(5, 4, TileType::Rock);
+        let (a, b) = (map.tile_to_world(2, 4), map.tile_to_world(9, 4));
+
+        assert!(!map.line_of_sight(a, b));
+        assert!(!map.line_of_sight(b, a));
+        assert!(map.line_of_sight(a, map.tile_to_world(9, 8)));
+        assert!(
+            map.line_of_sight(a, map.tile_to_world(5, 4)),
+            "the rock itself is visible"
+        );
+        map.set_tile(5, 4, TileType::Water);
+        assert!(map.line_of_sight(a, b), "water does not block sight");
+    }
+
     #[test]
     fn world_map_elevation_at_returns_stored_value() {
         let mut map = WorldMap::new(CHUNK_SIZE, CHUNK_SIZE);
@@ -1,6 +1,8 @@
+pub mod ambient_spawn;
 pub mod apple_tree;
 pub mod becomes;
 pub mod berry_bush;
+pub mod biome;
 pub mod campfire;
 pub mod construction_site;
 pub mod corpse;
@@ -13,11 +15,13 @@ pub mod field_grid_plugin;
 pub mod fish;
 pub mod fish_movement;
 pub mod forecast;
+pub mod ground_item;
 pub mod house;
 pub mod human;
 pub mod lean_to;
 pub mod liquid;
 pub mod map;
+pub mod pathfinding;
 pub mod property;
 pub mod sapling;
 pub mod sense_sources;
@@ -29,6 +33,7 @@ pub mod spawn_placement;
 pub mod spawner;
 pub mod stone_node;
 pub mod storage_chest;
+pub mod weather;
 pub mod wolf;
 pub mod wood_log;
 
@@ -43,6 +48,8 @@ impl Plugin for WorldPlugin {
             .register_type::<emits_effect::EmitsEffect>()
             .register_type::<construction_site::ConstructionSiteMarker>()
             .register_type::<sense_sources::SoundSource>()
+            .register_type::<pathfinding::PathfindingConfig>()
+            .init_resource::<pathfinding::PathfindingConfig>()
             .init_resource::<forecast::WorldForecast>()
             .init_resource::<entity_positions::WorldEntityPositions>()
             .add_systems(
@@ -58,6 +65,9 @@ impl Plugin for WorldPlugin {
             .add_plugins(field_grid_plugin::FieldGridPlugin)
             .add_plugins(liquid::LiquidPlugin)
             .add_plugins(severed_part::SeveredPartPlugin)
+            .add_plugins(corpse::CorpsePlugin)
+            .add_plugins(weather::WeatherPlugin)
+            .add_plugins(ground_item::GroundItemPlugin)
             .add_plugins(fish_movement::FishMovementPlugin);
     }
 }
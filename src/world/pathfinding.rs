@@ -0,0 +1,338 @@
+//! Grid pathfinding: A* over walkable tiles, with optional diagonals and smoothing.
+//!
+//! Reads: PathfindingConfig, WorldMap (tile walkability)
+//! Writes: nothing (pure functions returning tile waypoints)
+//! Upstream: WorldPlugin (init_resource)
+//! Downstream: WorldMap::path_cost (planner walk costs), callers planning multi-tile walks
+//!
+//! A 4-directional search moves in staircases: a route to a tile up and to
+//! the right zig-zags one step at a time. With `allow_diagonals` the search
+//! also steps diagonally at `diagonal_cost` (√2 by default, so a diagonal is
+//! never cheaper than it is long) but never cuts a blocked corner. With
+//! `smooth`, a post-pass drops every waypoint the agent could skip by
+//! walking a straight line between its neighbours, so what remains are the
+//! turning points of a natural diagonal route. Map edges are walls for the
+//! search regardless of `EdgePolicy`.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use bevy::prelude::*;
+
+use crate::world::map::WorldMap;
+
+/// A tile coordinate on the map grid.
+pub type GridTile = (i32, i32);
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct PathfindingConfig {
+    /// Off = 4-directional search only.
+    pub allow_diagonals: bool,
+    /// Cost of a diagonal step; an orthogonal step costs 1.
+    pub diagonal_cost: f32,
+    /// Shortcut waypoints that have a clear straight line between them.
+    pub smooth: bool,
+    /// Tiles expanded before the search gives up.
+    pub max_expanded: usize,
+}
+
+impl Default for PathfindingConfig {
+    fn default() -> Self {
+        Self {
+            allow_diagonals: true,
+            diagonal_cost: std::f32::consts::SQRT_2,
+            smooth: true,
+            max_expanded: 4096,
+        }
+    }
+}
+
+const ORTHOGONAL: [GridTile; 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DIAGONAL: [GridTile; 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+/// Whether `tile` is on the map and traversable.
+pub fn walkable(map: &WorldMap, (x, y): GridTile) -> bool {
+    x >= 0
+        && y >= 0
+        && map
+            .get_tile(x as u32, y as u32)
+            .is_some_and(|t| t.is_walkable())
+}
+
+/// Open-set entry. Ordered so `BinaryHeap` pops the lowest `f` first, ties
+/// broken on the tile so equal-cost searches are deterministic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Open {
+    f: f32,
+    tile: GridTile,
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .f
+            .total_cmp(&self.f)
+            .then_with(|| other.tile.cmp(&self.tile))
+    }
+}
+
+fn heuristic(config: &PathfindingConfig, a: GridTile, b: GridTile) -> f32 {
+    let dx = (a.0 - b.0).abs() as f32;
+    let dy = (a.1 - b.1).abs() as f32;
+    if config.allow_diagonals {
+        // Octile distance: diagonal steps for the shorter axis.
+        dx.max(dy) + (config.diagonal_cost - 1.0) * dx.min(dy)
+    } else {
+        dx + dy
+    }
+}
+
+/// Shortest walkable route from `start` to `goal`, both inclusive, or
+/// `None` if either end is blocked or the goal is out of reach within
+/// `max_expanded` tiles. Smoothed when `config.smooth` is set.
+pub fn find_path(
+    map: &WorldMap,
+    start: GridTile,
+    goal: GridTile,
+    config: &PathfindingConfig,
+) -> Option<Vec<GridTile>> {
+    if !walkable(map, start) || !walkable(map, goal) {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<GridTile, GridTile> = HashMap::new();
+    let mut cost: HashMap<GridTile, f32> = HashMap::from([(start, 0.0)]);
+    open.push(Open {
+        f: heuristic(config, start, goal),
+        tile: start,
+    });
+
+    let mut expanded = 0;
+    while let Some(Open { tile, .. }) = open.pop() {
+        if tile == goal {
+            let mut path = vec![goal];
+            let mut at = goal;
+            while let Some(&prev) = came_from.get(&at) {
+                path.push(prev);
+                at = prev;
+            }
+            path.reverse();
+            return Some(if config.smooth {
+                smooth_path(map, &path)
+            } else {
+                path
+            });
+        }
+        expanded += 1;
+        if expanded > config.max_expanded {
+            return None;
+        }
+
+        let here = cost[&tile];
+        let orthogonal = ORTHOGONAL.iter().map(|&step| (step, 1.0));
+        let diagonal = DIAGONAL
+            .iter()
+            .filter(|_| config.allow_diagonals)
+            // No corner cutting: both orthogonal neighbours must be clear.
+            .filter(|(dx, dy)| {
+                walkable(map, (tile.0 + dx, tile.1)) && walkable(map, (tile.0, tile.1 + dy))
+            })
+            .map(|&step| (step, config.diagonal_cost));
+        for ((dx, dy), step_cost) in orthogonal.chain(diagonal) {
+            let next = (tile.0 + dx, tile.1 + dy);
+            if !walkable(map, next) {
+                continue;
+            }
+            let tentative = here + step_cost;
+            if cost.get(&next).is_some_and(|&known| known <= tentative) {
+                continue;
+            }
+            cost.insert(next, tentative);
+            came_from.insert(next, tile);
+            open.push(Open {
+                f: tentative + heuristic(config, next, goal),
+                tile: next,
+            });
+        }
+    }
+    None
+}
+
+/// Whether the straight segment between the centres of `from` and `to`
+/// crosses only walkable tiles. Where the segment passes exactly through a
+/// tile corner, both tiles beside the corner must be clear.
+pub fn has_line_of_sight(map: &WorldMap, from: GridTile, to: GridTile) -> bool {
+    let (nx, ny) = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+    let (sx, sy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+    let (mut x, mut y) = from;
+    let (mut ix, mut iy) = (0, 0);
+    if !walkable(map, from) {
+        return false;
+    }
+    while ix < nx || iy < ny {
+        let decision = (1 + 2 * ix) * ny - (1 + 2 * iy) * nx;
+        if decision == 0 {
+            if !walkable(map, (x + sx, y)) || !walkable(map, (x, y + sy)) {
+                return false;
+            }
+            x += sx;
+            y += sy;
+            ix += 1;
+            iy += 1;
+        } else if decision < 0 {
+            x += sx;
+            ix += 1;
+        } else {
+            y += sy;
+            iy += 1;
+        }
+        if !walkable(map, (x, y)) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Drop waypoints that can be skipped: keep only the tiles where a straight
+/// line from the previous kept waypoint would leave walkable ground.
+pub fn smooth_path(map: &WorldMap, path: &[GridTile]) -> Vec<GridTile> {
+    let Some((&first, rest)) = path.split_first() else {
+        return Vec::new();
+    };
+    let mut smoothed = vec![first];
+    let mut anchor = first;
+    for window in rest.windows(2) {
+        let (prev, next) = (window[0], window[1]);
+        if !has_line_of_sight(map, anchor, next) {
+            smoothed.push(prev);
+            anchor = prev;
+        }
+    }
+    if let Some(&last) = rest.last() {
+        smoothed.push(last);
+    }
+    smoothed
+}
+
+/// Straight-line length of a waypoint list, in tiles.
+pub fn path_length(path: &[GridTile]) -> f32 {
+    path.windows(2)
+        .map(|w| {
+            let (dx, dy) = ((w[1].0 - w[0].0) as f32, (w[1].1 - w[0].1) as f32);
+            dx.hypot(dy)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::map::{CHUNK_SIZE, Chunk, TileType};
+
+    fn grass_map(chunks: i32) -> WorldMap {
+        let size = CHUNK_SIZE * chunks as u32;
+        let mut map = WorldMap::new(size, size);
+        for cx in 0..chunks {
+            for cy in 0..chunks {
+                map.chunks.insert(IVec2::new(cx, cy), Chunk::new(cx, cy));
+            }
+        }
+        map
+    }
+
+    #[test]
+    fn diagonal_path_is_shorter_and_smoothed_than_four_directional() {
+        let mut map = grass_map(2);
+        // A pond between the ends, so the route has to bend.
+        for x in 6..10 {
+            for y in 4..8 {
+                map.set_tile(x, y, TileType::Water);
+            }
+        }
+        let (start, goal) = ((2, 2), (14, 11));
+
+        let four = PathfindingConfig {
+            allow_diagonals: false,
+            smooth: false,
+            ..Default::default()
+        };
+        let grid = find_path(&map, start, goal, &four).expect("4-dir route");
+        assert_eq!(grid.len(), 12 + 9 + 1, "4-dir takes a Manhattan route");
+        assert!(
+            grid.windows(2)
+                .all(|w| (w[1].0 - w[0].0).abs() + (w[1].1 - w[0].1).abs() == 1),
+            "4-dir steps are orthogonal only"
+        );
+
+        let eight = PathfindingConfig::default();
+        let natural = find_path(&map, start, goal, &eight).expect("8-dir route");
+        assert_eq!(natural.first(), Some(&start));
+        assert_eq!(natural.last(), Some(&goal));
+        assert!(
+            path_length(&natural) < path_length(&grid),
+            "8-dir {} vs 4-dir {}",
+            path_length(&natural),
+            path_length(&grid)
+        );
+        assert!(
+            natural.len() < grid.len() / 4,
+            "smoothing left {} waypoints",
+            natural.len()
+        );
+        for leg in natural.windows(2) {
+            assert!(
+                has_line_of_sight(&map, leg[0], leg[1]),
+                "leg {:?} -> {:?} crosses the pond",
+                leg[0],
+                leg[1]
+            );
+        }
+    }
+
+    #[test]
+    fn line_of_sight_refuses_to_squeeze_between_diagonal_walls() {
+        let mut map = grass_map(1);
+        map.set_tile(3, 2, TileType::Water);
+        map.set_tile(2, 3, TileType::Water);
+
+        assert!(!has_line_of_sight(&map, (2, 2), (3, 3)));
+        assert!(has_line_of_sight(&map, (4, 4), (8, 6)));
+        let path =
+            find_path(&map, (2, 2), (3, 3), &PathfindingConfig::default()).expect("a way around");
+        assert!(path.len() > 2, "walked around, not through the corner");
+        assert!(path.windows(2).all(|w| has_line_of_sight(&map, w[0], w[1])));
+    }
+
+    #[test]
+    fn path_cost_is_straight_line_in_the_open_and_none_when_walled_off() {
+        let mut map = grass_map(1);
+        assert!((map.path_cost((1, 1), (4, 5)).unwrap() - 5.0).abs() < 1e-4);
+
+        // Ring of water around (10, 10).
+        for x in 8..=12 {
+            for y in 8..=12 {
+                if x == 8 || x == 12 || y == 8 || y == 12 {
+                    map.set_tile(x, y, TileType::Water);
+                }
+            }
+        }
+        assert_eq!(map.path_cost((1, 1), (10, 10)), None);
+        let around = map
+            .path_cost((10, 6), (10, 14))
+            .expect("route around the ring");
+        assert!(
+            around > 8.0,
+            "detour {around} should exceed the 8-tile line"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Property components for world entities — auto-derived ontology traits.
 //!
-//! Reads:  [`EntityType`] on newly-spawned entities
-//! Writes: [`Ontology`] (`HasTrait` and `Produces` triples)
+//! Reads:  [`EntityType`] on newly-spawned entities, [`ResourceRegeneration`] (regrowth interval)
+//! Writes: [`Ontology`] (`HasTrait`, `Produces` and `RegenerationRate` triples)
 //! Upstream: world spawners (apple_tree, campfire, etc.)
 //! Downstream: agent perception, culture knowledge seeding
 
@@ -9,6 +9,7 @@ use bevy::prelude::*;
 
 use crate::agent::inventory::EntityType;
 use crate::agent::mind::knowledge::{Concept, Ontology};
+use crate::world::apple_tree::ResourceRegeneration;
 
 // ─── Marker traits ──────────────────────────────────────────────────────────
 
@@ -207,6 +208,18 @@ pub fn derive_ontology_harvestable_component(
     }
 }
 
+/// Derives `(kind, RegenerationRate, seconds)` from a harvestable's
+/// `ResourceRegeneration`, so the ontology's regrowth fact follows the
+/// world's actual tuning instead of a hand-written constant.
+pub fn derive_ontology_resource_regeneration(
+    query: Query<(&EntityType, &ResourceRegeneration), Added<ResourceRegeneration>>,
+    mut ontology: ResMut<Ontology>,
+) {
+    for (entity_type, regen) in query.iter() {
+        ontology.ensure_regeneration_rate(entity_type.0, regen.interval);
+    }
+}
+
 /// Marks an entity as having been built by an agent (vs spawned by world generation).
 /// Used for ownership, territory, and knowledge ("Alice built this").
 /// Natural entities (trees, caves) do not have this component.
@@ -348,7 +361,7 @@ pub fn shelter_system(
 /// Registers all ontology-derivation systems generated by `define_property_component!`.
 ///
 /// Each system fires on `Added<Component>` so derivation works for entities
-/// spawned at startup AND mid-game.  `ensure_trait`/`ensure_production` are
+/// spawned at startup AND mid-game.  The `ensure_*` helpers are
 /// idempotent, so multiple spawns of the same entity type are safe.
 pub struct OntologyDerivationPlugin;
 
@@ -373,6 +386,7 @@ impl Plugin for OntologyDerivationPlugin {
                     derive_ontology_durability,
                     derive_ontology_built_by,
                     derive_ontology_harvestable_component,
+                    derive_ontology_resource_regeneration,
                     fuel_system,
                     durability_system,
                     shelter_system,
@@ -446,4 +460,25 @@ mod tests {
             "duplicate call must not add a second triple"
         );
     }
+
+    // ── ensure_regeneration_rate ────────────────────────────────────────────
+
+    #[test]
+    fn ensure_regeneration_rate_replaces_a_stale_rate() {
+        use crate::agent::mind::knowledge::{Node, Predicate, Quantity, Value};
+        let mut ontology = empty_ontology();
+        ontology.ensure_regeneration_rate(Concept::BerryBush, 8.0);
+        ontology.ensure_regeneration_rate(Concept::BerryBush, 8.0);
+        ontology.ensure_regeneration_rate(Concept::BerryBush, 12.0);
+        let rates: Vec<_> = ontology
+            .triples
+            .iter()
+            .filter(|t| {
+                t.subject == Node::Concept(Concept::BerryBush)
+                    && t.predicate == Predicate::RegenerationRate
+            })
+            .collect();
+        assert_eq!(rates.len(), 1, "one rate per producer");
+        assert_eq!(rates[0].object, Value::Quantity(Quantity::Exact(12.0)));
+    }
 }
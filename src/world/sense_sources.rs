@@ -40,4 +40,8 @@ pub struct SoundSource {
     pub kind: SoundKind,
     /// Affects effective hearing range (0.0 = whisper, 1.0 = loud).
     pub intensity: f32,
+    /// Species the call means something to. An alarm call frightens
+    /// listeners of this species only; everyone else just hears a noise.
+    /// `None` for sounds that carry no species-specific meaning.
+    pub species: Option<crate::agent::mind::knowledge::Concept>,
 }
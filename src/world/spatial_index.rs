@@ -78,19 +78,28 @@ impl SpatialIndex {
     /// so a few extra entities from adjacent chunks will be included. This is vastly cheaper
     /// than O(all_entities) linear scans.
     pub fn entities_near(&self, pos: Vec2, radius: f32) -> Vec<Entity> {
+        self.query_radius(pos, radius).collect()
+    }
+
+    /// Borrowing form of [`Self::entities_near`]: walks the same chunk
+    /// buckets without collecting them.
+    pub fn query_radius(&self, pos: Vec2, radius: f32) -> impl Iterator<Item = Entity> + '_ {
         let chunk_radius = chunk_radius_for(radius);
         let center_chunk = world_pos_to_chunk(pos);
+        (-chunk_radius..=chunk_radius)
+            .flat_map(move |dy| (-chunk_radius..=chunk_radius).map(move |dx| IVec2::new(dx, dy)))
+            .filter_map(move |offset| self.buckets.get(&(center_chunk + offset)))
+            .flatten()
+            .copied()
+    }
 
-        let mut result = Vec::new();
-        for dy in -chunk_radius..=chunk_radius {
-            for dx in -chunk_radius..=chunk_radius {
-                let chunk = center_chunk + IVec2::new(dx, dy);
-                if let Some(bucket) = self.buckets.get(&chunk) {
-                    result.extend_from_slice(bucket);
-                }
-            }
-        }
-        result
+    /// Number of entities in the index — what a brute-force scan would visit.
+    pub fn len(&self) -> usize {
+        self.entity_chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entity_chunks.is_empty()
     }
 
     /// Return all entities currently in the given chunk.
@@ -296,6 +305,21 @@ mod tests {
         index.remove_entity(entity(999)); // Should not panic.
     }
 
+    #[test]
+    fn query_radius_skips_far_chunks_that_a_full_scan_would_visit() {
+        let mut index = SpatialIndex::default();
+        index.update_entity(entity(1), chunk(0, 0));
+        index.update_entity(entity(2), chunk(1, 0));
+        index.update_entity(entity(3), chunk(9, 9));
+
+        let near: Vec<Entity> = index.query_radius(Vec2::ZERO, 1.0).collect();
+
+        assert_eq!(near, index.entities_near(Vec2::ZERO, 1.0));
+        assert_eq!(near.len(), 2);
+        assert!(!near.contains(&entity(3)));
+        assert_eq!(index.len(), 3);
+    }
+
     #[test]
     fn empty_index_returns_empty_results() {
         let index = SpatialIndex::default();
@@ -1,14 +1,17 @@
 //! WorldSpawnConfig: unified configuration for world entity placement.
 //!
 //! Reads: WorldMap (terrain), constants::world (default counts)
-//! Writes: SpawnLayout (positions + initial resource amounts for all entity types)
-//! Upstream: world::map (terrain data), world::spawn_placement (placement algorithms)
-//! Downstream: world::spawner (windowed game), headless (CLI), testing::world (TestWorld)
+//! Writes: SpawnLayout (positions + initial resource amounts for all entity types, biome map)
+//! Upstream: world::map (terrain data), world::spawn_placement (placement algorithms),
+//!           world::biome (biome regions)
+//! Downstream: world::spawner (windowed game), headless (CLI), testing::world (TestWorld),
+//!             world::ambient_spawn (via `ambient`)
 
 use bevy::math::{UVec2, Vec2};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
+use crate::agent::mind::knowledge::Concept;
 use crate::constants::world::{
     APPLE_TREE_SPAWN_COUNT, BERRY_BUSH_SPAWN_COUNT, DEER_HERD_RADIUS_TILES, DEER_HERD_SIZE,
     DEER_MIN_DISTANCE_FROM_SETTLEMENT, DEER_SPAWN_COUNT, HUMAN_CLUSTER_RADIUS_TILES,
@@ -17,12 +20,15 @@ use crate::constants::world::{
     SETTLEMENT_FOOD_RADIUS_TILES, STONE_NODE_SPAWN_COUNT, WOLF_MIN_DISTANCE_FROM_SETTLEMENT,
     WOLF_PACK_RADIUS_TILES, WOLF_PACK_SIZE, WOLF_SPAWN_COUNT, WOOD_LOG_SPAWN_COUNT,
 };
+use crate::world::ambient_spawn::AmbientSpawnConfig;
+use crate::world::biome::{BiomeConfig, BiomeMap};
 use crate::world::map::{
     DEFAULT_TERRAIN_SEED, TileType, WORLD_HEIGHT, WORLD_WIDTH, WorldMap, river_center_x,
 };
 use crate::world::spawn_placement::{
     SettlementSearch, cluster_positions, cluster_water_positions, find_biome_tile,
     find_interior_biome_tile, find_settlement_center, find_tile_away_from, find_water_tile,
+    nearest_tile_where,
 };
 
 /// Minimum tile distance from any water for vegetation that should cluster
@@ -39,6 +45,7 @@ pub enum SpawnAlgorithm {
     /// Settlement detection, biome clustering, herd grouping — same as the normal game.
     Realistic,
     /// Uniform random scatter within a fixed pixel area (fast, simple).
+    /// Draws that land on the wrong terrain are re-drawn, then nudged.
     Uniform,
 }
 
@@ -61,6 +68,16 @@ pub struct WorldSpawnConfig {
     /// Seed for the spawn-position RNG. Same seed + same config → same layout.
     pub seed: u64,
     pub spawn_algorithm: SpawnAlgorithm,
+    /// Biome regions and how strongly resources cluster in them. Only the
+    /// Realistic algorithm uses biomes.
+    pub biomes: BiomeConfig,
+    /// Uniform algorithm: random draws per land entity before one that
+    /// keeps landing in water is nudged to the nearest walkable tile
+    /// instead. Fish always take one draw and are nudged into water.
+    pub placement_attempts: usize,
+    /// Newcomers arriving at the map edge after the population shrinks.
+    /// Not part of the layout; spawners insert it as a resource.
+    pub ambient: AmbientSpawnConfig,
 }
 
 impl WorldSpawnConfig {
@@ -81,6 +98,9 @@ impl WorldSpawnConfig {
             wood_logs: WOOD_LOG_SPAWN_COUNT,
             seed: 0,
             spawn_algorithm: SpawnAlgorithm::Realistic,
+            biomes: BiomeConfig::default(),
+            placement_attempts: MAX_SPAWN_ATTEMPTS,
+            ambient: AmbientSpawnConfig::default(),
         }
     }
 
@@ -89,7 +109,7 @@ impl WorldSpawnConfig {
     pub fn compute_layout(&self, map: &WorldMap) -> SpawnLayout {
         match self.spawn_algorithm {
             SpawnAlgorithm::Realistic => compute_realistic_layout(self, map),
-            SpawnAlgorithm::Uniform => compute_uniform_layout(self),
+            SpawnAlgorithm::Uniform => compute_uniform_layout(self, map),
         }
     }
 }
@@ -125,6 +145,9 @@ pub struct SpawnLayout {
     pub stone_node_positions: Vec<(Vec2, u32)>,
     /// Each entry is (world position, initial wood count).
     pub wood_log_positions: Vec<(Vec2, u32)>,
+    /// Biome regions the resources were placed against. Spawners insert it
+    /// as a resource. `None` for the Uniform algorithm or with biomes off.
+    pub biomes: Option<BiomeMap>,
 }
 
 // ─── Realistic layout ─────────────────────────────────────────────────────
@@ -134,6 +157,23 @@ fn compute_realistic_layout(config: &WorldSpawnConfig, map: &WorldMap) -> SpawnL
     let mut layout = SpawnLayout::default();
 
     let settlement = find_settlement_center(map, &SettlementSearch::default());
+    let biomes = config
+        .biomes
+        .enabled
+        .then(|| BiomeMap::generate(map, &config.biomes));
+    // Where a resource of `concept` goes when it lands in its home biome.
+    let home_tile = |rng: &mut ChaCha8Rng, concept: Concept, allowed: &[TileType]| {
+        biomes.as_ref().and_then(|b| {
+            b.home_tile(
+                map,
+                rng,
+                &config.biomes,
+                concept,
+                allowed,
+                MAX_SPAWN_ATTEMPTS,
+            )
+        })
+    };
 
     // Berry bushes near the settlement are planted first so agents perceive
     // food as soon as they spawn.
@@ -177,33 +217,43 @@ fn compute_realistic_layout(config: &WorldSpawnConfig, map: &WorldMap) -> SpawnL
         );
     }
 
-    // Apple trees cluster in the island interior, away from the coast.
+    // Apple trees grow mostly in forest, otherwise in the island interior,
+    // away from the coast.
     for _ in 0..config.apple_trees {
-        if let Some(pos) = find_interior_biome_tile(
-            map,
-            &mut rng,
-            &[TileType::Grass],
-            VEGETATION_INTERIOR_MIN_WATER_DIST,
-            MAX_SPAWN_ATTEMPTS,
-        ) {
+        if let Some(pos) =
+            home_tile(&mut rng, Concept::AppleTree, &[TileType::Grass]).or_else(|| {
+                find_interior_biome_tile(
+                    map,
+                    &mut rng,
+                    &[TileType::Grass],
+                    VEGETATION_INTERIOR_MIN_WATER_DIST,
+                    MAX_SPAWN_ATTEMPTS,
+                )
+            })
+        {
             layout.apple_tree_positions.push((pos, 5));
         }
     }
 
-    // Remaining berry bushes scatter across the interior, away from the coast.
+    // Remaining berry bushes favour the plains, otherwise scatter across the
+    // interior, away from the coast.
     let scattered = config.berry_bushes.saturating_sub(if settlement.is_some() {
         SETTLEMENT_BERRY_BUSH_COUNT
     } else {
         0
     });
     for _ in 0..scattered {
-        if let Some(pos) = find_interior_biome_tile(
-            map,
-            &mut rng,
-            &[TileType::Grass],
-            VEGETATION_INTERIOR_MIN_WATER_DIST,
-            MAX_SPAWN_ATTEMPTS,
-        ) {
+        if let Some(pos) =
+            home_tile(&mut rng, Concept::BerryBush, &[TileType::Grass]).or_else(|| {
+                find_interior_biome_tile(
+                    map,
+                    &mut rng,
+                    &[TileType::Grass],
+                    VEGETATION_INTERIOR_MIN_WATER_DIST,
+                    MAX_SPAWN_ATTEMPTS,
+                )
+            })
+        {
             layout.berry_bush_positions.push((pos, 4));
         }
     }
@@ -221,9 +271,11 @@ fn compute_realistic_layout(config: &WorldSpawnConfig, map: &WorldMap) -> SpawnL
         }
     }
 
-    // Wood logs scatter across grass.
+    // Wood logs lie mostly in forest, otherwise anywhere on grass.
     for _ in 0..config.wood_logs {
-        if let Some(pos) = find_biome_tile(map, &mut rng, &[TileType::Grass], MAX_SPAWN_ATTEMPTS) {
+        if let Some(pos) = home_tile(&mut rng, Concept::WoodLog, &[TileType::Grass])
+            .or_else(|| find_biome_tile(map, &mut rng, &[TileType::Grass], MAX_SPAWN_ATTEMPTS))
+        {
             layout.wood_log_positions.push((pos, 4));
         }
     }
@@ -245,6 +297,7 @@ fn compute_realistic_layout(config: &WorldSpawnConfig, map: &WorldMap) -> SpawnL
         }
     }
 
+    layout.biomes = biomes;
     layout
 }
 
@@ -477,50 +530,45 @@ fn fallback_random_walkable(map: &WorldMap, rng: &mut impl rand::Rng, count: usi
 
 // ─── Uniform layout ───────────────────────────────────────────────────────
 
-fn compute_uniform_layout(config: &WorldSpawnConfig) -> SpawnLayout {
+fn compute_uniform_layout(config: &WorldSpawnConfig, map: &WorldMap) -> SpawnLayout {
     let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
     let mut layout = SpawnLayout::default();
+    let attempts = config.placement_attempts;
+    let land = |rng: &mut ChaCha8Rng| uniform_pos_where(map, rng, attempts, TileType::is_walkable);
+    // Fish take one draw and are nudged into water: on a map with little
+    // or none, re-drawing would shift every placement after them.
+    let water = |rng: &mut ChaCha8Rng| uniform_pos_where(map, rng, 1, TileType::is_water);
 
     for _ in 0..config.humans {
-        layout.human_positions.push(random_uniform_pos(&mut rng));
+        layout.human_positions.push(land(&mut rng));
     }
     // Uniform layout doesn't cluster — each deer/wolf is its own "herd of
     // one" so the spawner's introduction loop trivially skips them.
     for _ in 0..config.deer {
-        layout.deer_herds.push(vec![random_uniform_pos(&mut rng)]);
+        layout.deer_herds.push(vec![land(&mut rng)]);
     }
     for _ in 0..config.wolves {
-        layout.wolf_packs.push(vec![random_uniform_pos(&mut rng)]);
+        layout.wolf_packs.push(vec![land(&mut rng)]);
     }
-    // Uniform layout has no map awareness — minnows just scatter as singletons
-    // (the spawner happily makes a "school of one" out of each).
+    // Fish don't cluster either — minnows scatter as singletons (the
+    // spawner happily makes a "school of one" out of each).
     for _ in 0..config.minnows {
-        layout
-            .minnow_schools
-            .push(vec![random_uniform_pos(&mut rng)]);
+        layout.minnow_schools.push(vec![water(&mut rng)]);
     }
     for _ in 0..config.pikes {
-        layout.pike_positions.push(random_uniform_pos(&mut rng));
+        layout.pike_positions.push(water(&mut rng));
     }
     for _ in 0..config.berry_bushes {
-        layout
-            .berry_bush_positions
-            .push((random_uniform_pos(&mut rng), 5));
+        layout.berry_bush_positions.push((land(&mut rng), 5));
     }
     for _ in 0..config.apple_trees {
-        layout
-            .apple_tree_positions
-            .push((random_uniform_pos(&mut rng), 7));
+        layout.apple_tree_positions.push((land(&mut rng), 7));
     }
     for _ in 0..config.stone_nodes {
-        layout
-            .stone_node_positions
-            .push((random_uniform_pos(&mut rng), 5));
+        layout.stone_node_positions.push((land(&mut rng), 5));
     }
     for _ in 0..config.wood_logs {
-        layout
-            .wood_log_positions
-            .push((random_uniform_pos(&mut rng), 4));
+        layout.wood_log_positions.push((land(&mut rng), 4));
     }
 
     layout
@@ -533,3 +581,115 @@ fn random_uniform_pos(rng: &mut ChaCha8Rng) -> Vec2 {
         rng.random_range(0.0..UNIFORM_AREA_PX),
     )
 }
+
+/// A uniform draw on terrain that passes `accept`. Re-draws up to
+/// `attempts` times, then nudges the last draw to the nearest valid tile.
+/// Falls back to the raw draw only when the map has no valid tile at all.
+fn uniform_pos_where(
+    map: &WorldMap,
+    rng: &mut ChaCha8Rng,
+    attempts: usize,
+    accept: fn(&TileType) -> bool,
+) -> Vec2 {
+    let valid = |pos: Vec2| map.tile_at(pos).is_some_and(|tile| accept(&tile));
+    let mut pos = random_uniform_pos(rng);
+    for _ in 1..attempts {
+        if valid(pos) {
+            return pos;
+        }
+        pos = random_uniform_pos(rng);
+    }
+    if valid(pos) {
+        return pos;
+    }
+    nearest_tile_where(map, pos, |tile| accept(&tile)).unwrap_or(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::map::{CHUNK_SIZE, Chunk};
+    use bevy::math::IVec2;
+
+    /// 64×64 grass with a lake covering most of the middle.
+    fn lake_map() -> WorldMap {
+        let size = CHUNK_SIZE * 4;
+        let mut map = WorldMap::new(size, size);
+        for cx in 0..4 {
+            for cy in 0..4 {
+                map.chunks.insert(IVec2::new(cx, cy), Chunk::new(cx, cy));
+            }
+        }
+        for x in 8..56 {
+            for y in 8..56 {
+                map.set_tile(x, y, TileType::Water);
+            }
+        }
+        map
+    }
+
+    fn land_positions(layout: &SpawnLayout) -> Vec<Vec2> {
+        let mut positions = layout.human_positions.clone();
+        positions.extend(layout.deer_herds.iter().flatten());
+        positions.extend(layout.wolf_packs.iter().flatten());
+        for resources in [
+            &layout.berry_bush_positions,
+            &layout.apple_tree_positions,
+            &layout.stone_node_positions,
+            &layout.wood_log_positions,
+        ] {
+            positions.extend(resources.iter().map(|(pos, _)| *pos));
+        }
+        positions
+    }
+
+    #[test]
+    fn seeded_uniform_spawn_is_reproducible_and_on_valid_terrain() {
+        let map = lake_map();
+        // One attempt forces the nudge path for every draw into the lake.
+        for placement_attempts in [MAX_SPAWN_ATTEMPTS, 1] {
+            let config = WorldSpawnConfig {
+                map_size: (map.width, map.height),
+                humans: 12,
+                deer: 6,
+                wolves: 2,
+                minnows: 4,
+                pikes: 2,
+                berry_bushes: 20,
+                apple_trees: 10,
+                stone_nodes: 5,
+                wood_logs: 5,
+                seed: 31,
+                spawn_algorithm: SpawnAlgorithm::Uniform,
+                placement_attempts,
+                ..WorldSpawnConfig::game_defaults()
+            };
+
+            let first = config.compute_layout(&map);
+            let second = config.compute_layout(&map);
+            assert_eq!(land_positions(&first), land_positions(&second));
+            assert_eq!(first.minnow_schools, second.minnow_schools);
+            assert_eq!(first.pike_positions, second.pike_positions);
+
+            let land = land_positions(&first);
+            assert_eq!(land.len(), 12 + 6 + 2 + 20 + 10 + 5 + 5);
+            for pos in land {
+                assert!(
+                    map.is_walkable(pos),
+                    "{pos} is not walkable (attempts {placement_attempts})"
+                );
+            }
+            for pos in first
+                .minnow_schools
+                .iter()
+                .flatten()
+                .chain(&first.pike_positions)
+            {
+                assert!(
+                    map.tile_at(*pos).is_some_and(|t| t.is_water()),
+                    "fish at {pos} is on land"
+                );
+            }
+        }
+    }
+}
@@ -3,7 +3,8 @@
 //! Reads: WorldMap, TileType (terrain queries only — no ECS state)
 //! Writes: nothing — pure functions returning tile coordinates / world positions
 //! Upstream: spawner (initial population system)
-//! Downstream: spawner uses these to cluster humans, scatter herds, and bias resources to biomes
+//! Downstream: spawner uses these to cluster humans, scatter herds, and bias resources to biomes;
+//!             spawn_config nudges invalid Uniform draws onto the nearest valid tile
 
 use bevy::math::{UVec2, Vec2};
 use rand::Rng;
@@ -317,6 +318,40 @@ fn tile_distance_sq(a: UVec2, b: UVec2) -> i64 {
 
 /// Solid ground for entity placement: walkable land, never water or shallow water.
 /// Entities should not be initialized standing in any kind of water.
+/// Nearest tile to `pos` whose terrain passes `accept`, searched ring by
+/// ring outward, as a world position (tile center). `None` if no tile on
+/// the map qualifies.
+pub fn nearest_tile_where(
+    map: &WorldMap,
+    pos: Vec2,
+    accept: impl Fn(TileType) -> bool,
+) -> Option<Vec2> {
+    if map.width == 0 || map.height == 0 {
+        return None;
+    }
+    let (cx, cy) = map.world_to_tile(pos);
+    let (cx, cy) = (cx.min(map.width - 1), cy.min(map.height - 1));
+    let max_radius = map.width.max(map.height) as i32;
+    for radius in 0..=max_radius {
+        for dy in -radius..=radius {
+            let step = if dy.abs() == radius {
+                1
+            } else {
+                (2 * radius).max(1)
+            };
+            for dx in (-radius..=radius).step_by(step as usize) {
+                let Some((x, y)) = offset(cx, cy, dx, dy) else {
+                    continue;
+                };
+                if map.get_tile(x, y).is_some_and(&accept) {
+                    return Some(map.tile_to_world(x as i32, y as i32));
+                }
+            }
+        }
+    }
+    None
+}
+
 fn is_solid_ground(tile: TileType) -> bool {
     !matches!(tile, TileType::Water | TileType::ShallowWater)
 }
@@ -2,7 +2,8 @@
 //! Ensures consistent ECS components + Knowledge Graph assertions.
 //!
 //! Reads: WorldMap, Ontology, SimConfig (mode + seed), WorldSpawnConfig (layout computation)
-//! Writes: Person, Deer, Wolf, BerryBush, AppleTree entities (initial population)
+//! Writes: Person, Deer, Wolf, BerryBush, AppleTree entities (initial population),
+//!         AmbientSpawnConfig (from the world-gen config)
 //! Upstream: world::map (terrain), world::spawn_config (placement layout), menu (SimConfig)
 //! Downstream: agent systems consume the resulting entities
 //!
@@ -17,6 +18,7 @@
 
 use crate::agent::mind::knowledge::Ontology;
 use crate::menu::{AppState, SimConfig, SimMode};
+use crate::world::ambient_spawn::{AmbientSpawnConfig, ambient_spawn};
 use crate::world::spawn_config::{SpawnLayout, WorldSpawnConfig};
 use bevy::prelude::*;
 
@@ -122,6 +124,9 @@ impl Plugin for SpawnerPlugin {
             )
             .add_systems(FixedUpdate, regenerate_resources)
             .add_systems(FixedUpdate, grow_saplings)
+            .init_resource::<AmbientSpawnConfig>()
+            .register_type::<AmbientSpawnConfig>()
+            .add_systems(FixedUpdate, ambient_spawn)
             .add_systems(
                 Update,
                 (
@@ -149,6 +154,7 @@ fn spawn_initial_population(
         ..WorldSpawnConfig::game_defaults()
     };
     let layout = config.compute_layout(&map);
+    commands.insert_resource(config.ambient.clone());
     let spawned = apply_layout(
         &mut commands,
         &ontology,
@@ -348,6 +354,10 @@ pub fn apply_layout(
         spawned.push(spawn_wood_log(commands, palette, pos, wood));
     }
 
+    if let Some(biomes) = &layout.biomes {
+        commands.insert_resource(biomes.clone());
+    }
+
     spawned
 }
 
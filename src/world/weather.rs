@@ -0,0 +1,220 @@
+//! Weather and time-of-day phase: the ambient conditions agents react to.
+//!
+//! Reads: WeatherConfig, TickCount, SimRng (spell lengths and transition rolls)
+//! Writes: Weather resource, SimRng
+//! Upstream: world::environment (light bands that define DayPhase)
+//! Downstream: psyche::emotions::update_mood (environment-driven mood), movement::calculate_speed (wet ground), perception (sight range), world::environment (sky tint)
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::constants::weather::*;
+use crate::core::{GameTime, SimRng, TickCount};
+
+/// Current sky over the whole map. `advance_weather` moves it one rung at
+/// a time, so rain always passes through overcast on its way to clearing.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub enum Weather {
+    #[default]
+    Clear,
+    Overcast,
+    Rain,
+    Storm,
+}
+
+impl Weather {
+    pub const ALL: [Weather; 4] = [
+        Weather::Clear,
+        Weather::Overcast,
+        Weather::Rain,
+        Weather::Storm,
+    ];
+
+    /// One rung worse; a storm stays a storm.
+    pub fn heavier(self) -> Self {
+        match self {
+            Weather::Clear => Weather::Overcast,
+            Weather::Overcast => Weather::Rain,
+            Weather::Rain | Weather::Storm => Weather::Storm,
+        }
+    }
+
+    /// One rung better; a clear sky stays clear.
+    pub fn lighter(self) -> Self {
+        match self {
+            Weather::Clear | Weather::Overcast => Weather::Clear,
+            Weather::Rain => Weather::Overcast,
+            Weather::Storm => Weather::Rain,
+        }
+    }
+
+    /// Walking speed multiplier: wet ground slows everyone down.
+    pub fn speed_multiplier(self) -> f32 {
+        match self {
+            Weather::Clear | Weather::Overcast => 1.0,
+            Weather::Rain => RAIN_SPEED_MULTIPLIER,
+            Weather::Storm => STORM_SPEED_MULTIPLIER,
+        }
+    }
+
+    /// Sight range multiplier, applied on top of night blindness.
+    pub fn vision_multiplier(self) -> f32 {
+        match self {
+            Weather::Clear => 1.0,
+            Weather::Overcast => OVERCAST_VISION_MULTIPLIER,
+            Weather::Rain => RAIN_VISION_MULTIPLIER,
+            Weather::Storm => STORM_VISION_MULTIPLIER,
+        }
+    }
+
+    /// RGB multiplier the game view is tinted toward under this sky.
+    pub fn tint(self) -> Vec3 {
+        match self {
+            Weather::Clear => Vec3::ONE,
+            Weather::Overcast => Vec3::new(0.9, 0.92, 0.95),
+            Weather::Rain => Vec3::new(0.78, 0.82, 0.9),
+            Weather::Storm => Vec3::new(0.6, 0.63, 0.72),
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct WeatherConfig {
+    /// Hold the current sky in place. Set from the Settings tab to test
+    /// behaviour under one kind of weather.
+    pub locked: bool,
+    pub min_spell_ticks: u64,
+    pub max_spell_ticks: u64,
+    pub worsen_chance: f64,
+}
+
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self {
+            locked: false,
+            min_spell_ticks: MIN_SPELL_TICKS,
+            max_spell_ticks: MAX_SPELL_TICKS,
+            worsen_chance: WORSEN_CHANCE,
+        }
+    }
+}
+
+/// When the current spell ends, the sky steps one rung heavier or lighter
+/// and a new spell length is drawn. Every draw goes through `SimRng`, so
+/// the same seed brings the same weather. The first spell always runs
+/// `min_spell_ticks` without a draw, and a locked sky draws nothing.
+pub fn advance_weather(
+    config: Res<WeatherConfig>,
+    tick: Res<TickCount>,
+    mut weather: ResMut<Weather>,
+    mut sim_rng: ResMut<SimRng>,
+    mut spell_ends: Local<Option<u64>>,
+) {
+    let ends = *spell_ends.get_or_insert(tick.current + config.min_spell_ticks);
+    if config.locked || tick.current < ends {
+        return;
+    }
+    let rng = sim_rng.inner_mut();
+    let next = if rng.random_bool(config.worsen_chance) {
+        weather.heavier()
+    } else {
+        weather.lighter()
+    };
+    // Only write on an actual change so `is_changed` readers stay quiet.
+    if next != *weather {
+        *weather = next;
+    }
+    let spell = rng
+        .random_range(config.min_spell_ticks..=config.max_spell_ticks.max(config.min_spell_ticks));
+    *spell_ends = Some(tick.current + spell);
+}
+
+/// Coarse time-of-day band, aligned with the light ramps in
+/// [`crate::world::environment::compute_light_level`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPhase {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl DayPhase {
+    pub fn at_hour(hour: f32) -> Self {
+        match hour {
+            h if (5.0..7.0).contains(&h) => DayPhase::Dawn,
+            h if (7.0..18.0).contains(&h) => DayPhase::Day,
+            h if (18.0..20.0).contains(&h) => DayPhase::Dusk,
+            _ => DayPhase::Night,
+        }
+    }
+
+    pub fn at_tick(tick: u64) -> Self {
+        Self::at_hour(GameTime::hour_at_tick(tick))
+    }
+}
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Weather>()
+            .register_type::<WeatherConfig>()
+            .init_resource::<Weather>()
+            .init_resource::<WeatherConfig>()
+            .add_systems(FixedUpdate, advance_weather);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weather_changes_one_rung_at_a_time() {
+        for w in Weather::ALL {
+            let rung = |w: Weather| Weather::ALL.iter().position(|x| *x == w).unwrap() as i32;
+            assert!((rung(w.heavier()) - rung(w)).abs() <= 1);
+            assert!((rung(w.lighter()) - rung(w)).abs() <= 1);
+        }
+        assert_eq!(Weather::Storm.heavier(), Weather::Storm);
+        assert_eq!(Weather::Clear.lighter(), Weather::Clear);
+    }
+
+    fn run_for(seed: u64, locked: bool, ticks: u64) -> Vec<Weather> {
+        let mut world = World::new();
+        world.insert_resource(SimRng::from_seed(seed));
+        world.insert_resource(TickCount::default());
+        world.insert_resource(Weather::default());
+        world.insert_resource(WeatherConfig {
+            locked,
+            min_spell_ticks: 10,
+            max_spell_ticks: 20,
+            ..default()
+        });
+        // A schedule keeps `advance_weather`'s spell timer between ticks.
+        let mut schedule = Schedule::default();
+        schedule.add_systems(advance_weather);
+        let mut seen = Vec::new();
+        for t in 0..ticks {
+            world.resource_mut::<TickCount>().current = t;
+            schedule.run(&mut world);
+            seen.push(*world.resource::<Weather>());
+        }
+        seen
+    }
+
+    #[test]
+    fn same_seed_brings_the_same_weather() {
+        let a = run_for(7, false, 2_000);
+        assert_eq!(a, run_for(7, false, 2_000));
+        assert!(a.iter().any(|w| *w != Weather::Clear), "sky never changed");
+    }
+
+    #[test]
+    fn locked_sky_holds() {
+        assert!(run_for(7, true, 2_000).iter().all(|w| *w == Weather::Clear));
+    }
+}
@@ -4,6 +4,13 @@
 //! Writes: Wolf component, MindGraph (innate prey/threat knowledge), world entities
 //! Upstream: world::spawner (calls spawn_wolf), world::map (biome placement)
 //! Downstream: agent brains (fear/flee in humans/deer, anger/attack in wolves)
+//!
+//! Wolf danger is species knowledge, not ontology: humans and deer each get
+//! `Wolf HasTrait Dangerous` innately, while the shared ontology only says
+//! `Wolf IsA Animal` (marking it there would make wolves fear each other).
+//! Nobody carries a `Wolf TriggersEmotion Fear` script either — fear comes
+//! from `react_to_danger` seeing a Dangerous entity and from outcome
+//! processing after an attack.
 
 use crate::agent::biology::body::BodyNodeKind;
 use crate::agent::body::genetics::founder::random_genome;
@@ -191,6 +198,7 @@ pub fn spawn_wolf<R: Rng>(
             // Vision range overwritten by develop_phenotype_system; placeholder = species baseline.
             crate::agent::mind::perception::Vision {
                 range: SpeciesProfile::wolf().vision_range,
+                fov_degrees: SpeciesProfile::wolf().fov_degrees,
             },
             crate::agent::mind::perception::VisibleObjects::default(),
             Visibility::default(),
@@ -11,6 +11,7 @@
 //! Intent selection tests (issue #46):
 //! 5. After a Greet turn (expects_response=true), the partner uses Answer intent
 //! 6. An agent with personal high-salience danger knowledge warns their partner
+//! 7. An extravert keeps a conversation going longer than an introvert
 
 use bevy::math::Vec2;
 use worldsim::agent::actions::ActionType;
@@ -958,3 +959,385 @@ fn social_drive_drains_per_turn() {
         "companionship should increase over conversation turns (before={before:.3}, after={after:.3})"
     );
 }
+
+/// Once an agent has had their fill of company they say goodbye, the
+/// partner returns it, and the conversation ends naturally — no abandonment
+/// — with the partner feeling warmer toward them for the polite exit.
+#[test]
+fn satisfied_agent_says_farewell_and_conversation_ends_gracefully() {
+    use worldsim::agent::engagement::EngagementEndReason;
+    use worldsim::agent::engagement::converse::ConversationState;
+    use worldsim::agent::events::EngagementBeatPayload;
+    use worldsim::agent::psyche::social_graph::SocialGraph;
+
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(210.0, 200.0))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    let bob = agents["bob"];
+
+    world.enable_fast_brains();
+    // Wait until the greeting exchange is over.
+    let mut active = false;
+    for _ in 0..40 {
+        world.tick(10);
+        let registry = world.app().world().resource::<ConverseRegistry>();
+        active = registry
+            .conversations
+            .values()
+            .any(|c| c.participants.contains(&alice) && c.state == ConversationState::Active);
+        if active {
+            break;
+        }
+    }
+    assert!(active, "alice and bob should be mid-conversation");
+
+    let affection_before = world
+        .app()
+        .world()
+        .resource::<SocialGraph>()
+        .affection(bob, alice);
+    let events_before = world.sim_events().all().len();
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<PsychologicalDrives>(alice)
+        .expect("alice should have PsychologicalDrives")
+        .companionship
+        .set(1.0);
+
+    for _ in 0..30 {
+        world.tick(10);
+        if !world.in_conversation(alice) {
+            break;
+        }
+    }
+
+    let events = &world.sim_events().all()[events_before..];
+    let alice_said_goodbye = events.iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::EngagementBeat {
+                payload: EngagementBeatPayload::Converse {
+                    speaker,
+                    intent: Intent::Farewell,
+                    ..
+                },
+                ..
+            } if *speaker == alice
+        )
+    });
+    let bob_said_goodbye = events.iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::EngagementBeat {
+                payload: EngagementBeatPayload::Converse {
+                    speaker,
+                    intent: Intent::Farewell,
+                    ..
+                },
+                ..
+            } if *speaker == bob
+        )
+    });
+    let ended_naturally = events.iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::EngagementEnded {
+                kind: EngagementKind::Converse,
+                reason: EngagementEndReason::Natural,
+                ..
+            }
+        )
+    });
+    let abandoned = events.iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::EngagementEnded {
+                reason: EngagementEndReason::Abandoned,
+                ..
+            }
+        )
+    });
+
+    assert!(alice_said_goodbye, "satisfied alice should say farewell");
+    assert!(bob_said_goodbye, "bob should return the goodbye");
+    assert!(ended_naturally, "conversation should end naturally");
+    assert!(!abandoned, "a returned goodbye is not an abandonment");
+
+    let affection_after = world
+        .app()
+        .world()
+        .resource::<SocialGraph>()
+        .affection(bob, alice);
+    assert!(
+        affection_after > affection_before,
+        "bob should feel warmer toward alice after a polite exit \
+         ({affection_before:.3} -> {affection_after:.3})"
+    );
+}
+
+/// Two agents who never tire of each other still part once the
+/// conversation hits `ConversationConfig::max_turns`: the speaker at the
+/// cap says goodbye, the partner returns it, and the conversation closes
+/// naturally.
+#[test]
+fn long_conversation_hits_turn_cap_and_ends_with_farewell() {
+    use worldsim::agent::engagement::EngagementEndReason;
+    use worldsim::agent::engagement::converse::ConversationConfig;
+    use worldsim::agent::events::EngagementBeatPayload;
+
+    const MAX_TURNS: usize = 3;
+
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(210.0, 200.0))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    world
+        .app_mut()
+        .world_mut()
+        .insert_resource(ConversationConfig {
+            max_turns: MAX_TURNS,
+        });
+
+    world.enable_fast_brains();
+    let mut started = false;
+    for _ in 0..60 {
+        world.tick(10);
+        started |= world.in_conversation(alice);
+        if started && !world.in_conversation(alice) {
+            break;
+        }
+    }
+    assert!(started, "alice and bob should start talking");
+    assert!(
+        !world.in_conversation(alice),
+        "the conversation should end once the turn cap is reached"
+    );
+
+    let first_id = world
+        .sim_events()
+        .all()
+        .iter()
+        .find_map(|e| match &e.kind {
+            SimEventKind::EngagementStarted {
+                kind: EngagementKind::Converse,
+                engagement_id,
+                ..
+            } => Some(*engagement_id),
+            _ => None,
+        })
+        .expect("a conversation should have started");
+    let intents: Vec<Intent> = world
+        .sim_events()
+        .all()
+        .iter()
+        .filter_map(|e| match &e.kind {
+            SimEventKind::EngagementBeat {
+                engagement_id,
+                payload: EngagementBeatPayload::Converse { intent, .. },
+                ..
+            } if *engagement_id == first_id => Some(*intent),
+            _ => None,
+        })
+        .collect();
+
+    assert!(
+        intents.len() <= MAX_TURNS + 2,
+        "only the goodbye and its reply may follow the cap, got {intents:?}"
+    );
+    assert!(
+        intents[..MAX_TURNS.min(intents.len())]
+            .iter()
+            .all(|i| *i != Intent::Farewell),
+        "nobody had a reason to leave before the cap: {intents:?}"
+    );
+    assert_eq!(
+        intents.last(),
+        Some(&Intent::Farewell),
+        "the conversation should close on a goodbye: {intents:?}"
+    );
+    let ended_naturally = world.sim_events().all().iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::EngagementEnded {
+                kind: EngagementKind::Converse,
+                engagement_id,
+                reason: EngagementEndReason::Natural,
+                ..
+            } if *engagement_id == first_id
+        )
+    });
+    assert!(ended_naturally, "a capped conversation ends gracefully");
+}
+
+/// Id, participants and `(speaker, intent, tick)` per turn for every
+/// conversation, in id order. Entities are replaced by agent names so two
+/// separate worlds can be compared.
+type ConversationLog = Vec<(u64, Vec<String>, Vec<(String, Intent, u64)>)>;
+
+fn conversation_log(
+    world: &TestWorld,
+    agents: &[(&str, bevy::prelude::Entity)],
+) -> ConversationLog {
+    let name = |entity: bevy::prelude::Entity| {
+        agents
+            .iter()
+            .find(|(_, e)| *e == entity)
+            .map_or_else(|| format!("{entity:?}"), |(n, _)| n.to_string())
+    };
+    let registry = world.app().world().resource::<ConverseRegistry>();
+    registry
+        .conversations
+        .values()
+        .map(|conv| {
+            (
+                conv.id.0,
+                conv.participants.iter().map(|e| name(*e)).collect(),
+                conv.turns
+                    .iter()
+                    .map(|t| (name(t.speaker), t.intent, t.timestamp))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Two runs from the same seed must mint the same conversation ids and
+/// replay the same turns — the record/replay feature depends on it. Two
+/// pairs far apart start their conversations on the same tick, which is
+/// where iteration order used to leak into id assignment.
+#[test]
+fn seeded_runs_produce_identical_conversation_ids_and_turns() {
+    let run = || {
+        let (mut world, agents) = TestWorld::scenario(42)
+            .map_size(64, 64)
+            .noise_biomes(false)
+            .agent("alice")
+            .pos(Vec2::new(200.0, 200.0))
+            .social_drive(HIGH_SOCIAL)
+            .done()
+            .agent("bob")
+            .pos(Vec2::new(210.0, 200.0))
+            .social_drive(HIGH_SOCIAL)
+            .done()
+            .agent("carol")
+            .pos(Vec2::new(800.0, 800.0))
+            .social_drive(HIGH_SOCIAL)
+            .done()
+            .agent("dave")
+            .pos(Vec2::new(810.0, 800.0))
+            .social_drive(HIGH_SOCIAL)
+            .done()
+            .build();
+        world.enable_fast_brains();
+        world.tick(300);
+        let names = ["alice", "bob", "carol", "dave"].map(|n| (n, agents[n]));
+        conversation_log(&world, &names)
+    };
+
+    let first = run();
+    let second = run();
+
+    assert!(
+        first.len() >= 2,
+        "both pairs should have talked, got {first:?}"
+    );
+    assert!(
+        first.iter().any(|(_, _, turns)| !turns.is_empty()),
+        "conversations should have turns to compare"
+    );
+    assert_eq!(first, second, "seeded runs diverged");
+}
+
+/// Turns and shared triples of the first conversation in a run where
+/// alice has the given extraversion and everything else is identical.
+fn first_conversation_size(alice_extraversion: f32) -> (usize, usize) {
+    use worldsim::agent::events::EngagementBeatPayload;
+    use worldsim::testing::personality;
+
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .genome(personality().extraversion(alice_extraversion))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(210.0, 200.0))
+        .social_drive(HIGH_SOCIAL)
+        .done()
+        .build();
+    let alice = agents["alice"];
+
+    world.enable_fast_brains();
+    let mut started = false;
+    for _ in 0..80 {
+        world.tick(10);
+        started |= world.in_conversation(alice);
+        if started && !world.in_conversation(alice) {
+            break;
+        }
+    }
+    assert!(started, "alice and bob should start talking");
+    assert!(
+        !world.in_conversation(alice),
+        "the conversation should have run its course"
+    );
+
+    let mut first_id = None;
+    let (mut turns, mut shared) = (0, 0);
+    for event in world.sim_events().all() {
+        match &event.kind {
+            SimEventKind::EngagementStarted {
+                kind: EngagementKind::Converse,
+                engagement_id,
+                ..
+            } if first_id.is_none() => first_id = Some(*engagement_id),
+            SimEventKind::EngagementBeat {
+                engagement_id,
+                payload: EngagementBeatPayload::Converse { content_count, .. },
+                ..
+            } if Some(*engagement_id) == first_id => {
+                turns += 1;
+                shared += content_count;
+            }
+            _ => {}
+        }
+    }
+    (turns, shared)
+}
+
+#[test]
+fn extraverted_agent_holds_longer_conversations_than_an_introvert() {
+    let (outgoing_turns, outgoing_shared) = first_conversation_size(0.9);
+    let (reserved_turns, reserved_shared) = first_conversation_size(0.1);
+
+    assert!(
+        outgoing_turns > reserved_turns,
+        "extravert talked for {outgoing_turns} turns, introvert for {reserved_turns}"
+    );
+    assert!(
+        outgoing_shared >= reserved_shared,
+        "extravert shared {outgoing_shared} triples, introvert {reserved_shared}"
+    );
+}
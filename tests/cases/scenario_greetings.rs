@@ -1,11 +1,16 @@
 //! Scenario tests for the social acknowledgment system (#467).
 //!
 //! Verifies that agents who know each other exchange passing greetings
-//! when in visual range, without entering a full conversation.
+//! when they come into visual range after time apart, without entering a
+//! full conversation.
 
-use bevy::math::Vec2;
+use bevy::math::{Vec2, Vec3};
+use bevy::prelude::{Entity, Transform};
 use worldsim::agent::body::needs::PsychologicalDrives;
+use worldsim::agent::engagement::converse::Intent;
 use worldsim::agent::events::{SimEvent, SimEventKind};
+use worldsim::agent::psyche::social_graph::SocialGraph;
+use worldsim::core::time::GameTime;
 use worldsim::testing::TestWorld;
 
 #[test]
@@ -136,3 +141,99 @@ fn greeting_bumps_companionship() {
         "companionship should increase after greeting (before={before:.4}, after={after:.4})"
     );
 }
+
+fn greetings_between(world: &TestWorld, a: Entity, b: Entity, since: u64) -> usize {
+    world
+        .sim_events()
+        .all()
+        .iter()
+        .filter(|e| {
+            e.tick >= since
+                && matches!(
+                    e.kind,
+                    SimEventKind::SocialAcknowledgment { actor, target, intent: Intent::Greet }
+                        if (actor == a && target == b) || (actor == b && target == a)
+                )
+        })
+        .count()
+}
+
+fn mutual_affection(world: &TestWorld, a: Entity, b: Entity) -> f32 {
+    let graph = world.app().world().resource::<SocialGraph>();
+    graph.get(a, b).map_or(0.0, |e| e.affection) + graph.get(b, a).map_or(0.0, |e| e.affection)
+}
+
+#[test]
+fn reunited_acquaintances_greet_and_warm_slightly() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .social_drive(0.3)
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(210.0, 200.0))
+        .social_drive(0.3)
+        .done()
+        .relationship("alice", "bob", |r| r.trust(0.4).affection(0.3))
+        .build();
+    let (alice, bob) = (agents["alice"], agents["bob"]);
+
+    world.enable_fast_brains();
+    world.tick(120);
+    assert!(
+        greetings_between(&world, alice, bob, 0) > 0,
+        "acquaintances meeting for the first time should greet"
+    );
+
+    // Part ways for longer than the reunion gap.
+    world.get_mut::<Transform>(bob).translation = Vec3::new(900.0, 900.0, 0.0);
+    world.tick(GameTime::TICKS_PER_MINUTE * 12);
+
+    let reunion_tick = world.current_tick();
+    let before = mutual_affection(&world, alice, bob);
+    let alice_pos = world.get::<Transform>(alice).translation;
+    world.get_mut::<Transform>(bob).translation = alice_pos + Vec3::new(10.0, 0.0, 0.0);
+    world.tick(120);
+
+    assert!(
+        greetings_between(&world, alice, bob, reunion_tick) > 0,
+        "acquaintances reunited after a while apart should greet again"
+    );
+    let after = mutual_affection(&world, alice, bob);
+    assert!(
+        after > before && after - before < 0.05,
+        "a passing greeting should nudge affection up slightly (before={before:.4}, after={after:.4})"
+    );
+}
+
+#[test]
+fn companions_staying_together_do_not_regreet() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .social_drive(0.3)
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(210.0, 200.0))
+        .social_drive(0.3)
+        .done()
+        .relationship("alice", "bob", |r| r.trust(0.5).affection(0.6))
+        .build();
+    let (alice, bob) = (agents["alice"], agents["bob"]);
+
+    world.enable_fast_brains();
+    world.tick(120);
+    let first_meeting = greetings_between(&world, alice, bob, 0);
+
+    // Well past the per-pair cooldown, but never out of sight.
+    world.tick(600);
+    assert_eq!(
+        greetings_between(&world, alice, bob, 0),
+        first_meeting,
+        "agents who stay in sight of each other shouldn't keep greeting"
+    );
+}
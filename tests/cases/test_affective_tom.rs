@@ -159,3 +159,30 @@ fn two_observers_track_the_target_independently() {
     assert!(later_a > observation_a.unwrap());
     assert!(later_b >= later_a - 4 && later_b <= later_a + 4);
 }
+
+#[test]
+fn distant_mood_reads_are_less_confident_than_close_ones() {
+    let mut world = TestWorld::with_seed(0);
+    let observer = world.spawn_agent(AgentConfig::at(Vec2::new(0.0, 0.0)));
+    let near = world.spawn_agent(AgentConfig::at(Vec2::new(20.0, 0.0)));
+    let far = world.spawn_agent(AgentConfig::at(Vec2::new(0.0, 160.0)));
+    seed_emotion(&mut world, near, Emotion::new(EmotionType::Sadness, 0.6));
+    seed_emotion(&mut world, far, Emotion::new(EmotionType::Sadness, 0.6));
+
+    world.tick(5);
+
+    let now = world.current_tick();
+    let tom = world.get::<AffectiveToM>(observer);
+    let near_conf = tom
+        .perceived_mood(near)
+        .expect("observer should read the nearby agent")
+        .confidence_at(now);
+    let far_conf = tom
+        .perceived_mood(far)
+        .expect("observer should read the distant agent")
+        .confidence_at(now);
+    assert!(
+        near_conf > far_conf + 0.3,
+        "a face 20px away should read far more reliably than one 160px away (near={near_conf:.3}, far={far_conf:.3})"
+    );
+}
@@ -0,0 +1,74 @@
+//! End-to-end tests for species-scoped alarm calls: `AlarmSignal` →
+//! `emit_alarm_calls` → `hear_alarm_signals` → fear → Flee.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::ActionType;
+use worldsim::agent::events::{SimEvent, SimEventKind};
+use worldsim::agent::psyche::emotions::{EmotionType, EmotionalState};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+fn fear(world: &TestWorld, agent: Entity) -> f32 {
+    world
+        .get::<EmotionalState>(agent)
+        .active_emotions
+        .iter()
+        .filter(|e| e.emotion_type == EmotionType::Fear)
+        .map(|e| e.intensity)
+        .sum()
+}
+
+fn agent_started_action(world: &TestWorld, agent: Entity, action: ActionType) -> bool {
+    world.sim_events().all().iter().any(|e| {
+        matches!(
+            e,
+            SimEvent { kind: SimEventKind::ActionStarted { agent: a, action: act, .. }, .. }
+                if *a == agent && *act == action
+        )
+    })
+}
+
+fn sound_alarm(world: &mut TestWorld, caller: Entity) {
+    world.app_mut().world_mut().write_message(SimEvent::single(
+        0,
+        caller,
+        SimEventKind::ActionStarted {
+            agent: caller,
+            action: ActionType::AlarmSignal,
+            target: None,
+            plan_id: None,
+            plan_step: None,
+        },
+    ));
+}
+
+#[test]
+fn alarm_call_frightens_herd_but_not_other_species() {
+    let mut world = TestWorld::with_seed(42);
+    let caller = world.spawn_deer(Vec2::new(100.0, 100.0));
+    // Out of the caller's sight but well within earshot.
+    let herdmate = world.spawn_deer(Vec2::new(400.0, 100.0));
+    let human = world.spawn_agent(AgentConfig::at(Vec2::new(100.0, 350.0)));
+
+    let herdmate_before = fear(&world, herdmate);
+    let human_before = fear(&world, human);
+
+    sound_alarm(&mut world, caller);
+    world.tick(2);
+
+    assert!(
+        fear(&world, herdmate) > 0.5,
+        "same-species listener should be frightened by the alarm (before {herdmate_before:.2}, after {:.2})",
+        fear(&world, herdmate)
+    );
+    assert!(
+        fear(&world, human) <= human_before + 0.05,
+        "a human should hear the call without catching the fear (before {human_before:.2}, after {:.2})",
+        fear(&world, human)
+    );
+
+    world.tick(120);
+    assert!(
+        agent_started_action(&world, herdmate, ActionType::Flee),
+        "frightened herdmate should flee without ever seeing the threat"
+    );
+}
@@ -0,0 +1,72 @@
+//! Ambient spawning: once deaths drop the living human count below the
+//! configured floor, a newcomer arrives on a walkable map-edge tile.
+
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, Transform, With};
+use worldsim::agent::biology::body::{Body, BodyNodeKind};
+use worldsim::agent::{Alive, Person};
+use worldsim::testing::{AgentConfig, TestWorld};
+use worldsim::world::ambient_spawn::AmbientSpawnConfig;
+use worldsim::world::map::WorldMap;
+
+fn living_people(world: &mut TestWorld) -> Vec<Entity> {
+    let world = world.app_mut().world_mut();
+    let mut query = world.query_filtered::<Entity, (With<Person>, With<Alive>)>();
+    query.iter(world).collect()
+}
+
+#[test]
+fn a_newcomer_arrives_at_the_map_edge_after_population_drops_below_the_floor() {
+    let mut world = TestWorld::with_seed(7);
+    let founders: Vec<Entity> = (0..3)
+        .map(|i| world.spawn_agent(AgentConfig::at(Vec2::new(500.0 + i as f32 * 8.0, 500.0))))
+        .collect();
+    world.app_mut().insert_resource(AmbientSpawnConfig {
+        enabled: true,
+        population_floor: 3,
+        interval_ticks: 600,
+    });
+
+    world.tick(5);
+    assert_eq!(
+        living_people(&mut world).len(),
+        3,
+        "no newcomer while the population sits at the floor"
+    );
+
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<Body>(founders[0])
+        .expect("founder has a body")
+        .node_mut(BodyNodeKind::Heart)
+        .expect("body has a heart")
+        .current_hp = 0.0;
+
+    let mut newcomer = None;
+    for _ in 0..60 {
+        world.tick(1);
+        newcomer = living_people(&mut world)
+            .into_iter()
+            .find(|e| !founders.contains(e));
+        if newcomer.is_some() {
+            break;
+        }
+    }
+    let newcomer = newcomer.expect("a newcomer spawns once a founder dies");
+
+    let pos = world.get::<Transform>(newcomer).translation.truncate();
+    let map = world.app().world().resource::<WorldMap>();
+    let (x, y) = map.world_to_tile(pos);
+    assert!(
+        x == 0 || y == 0 || x + 1 == map.width || y + 1 == map.height,
+        "newcomer should arrive on an edge tile, got ({x}, {y})"
+    );
+
+    world.tick(30);
+    assert_eq!(
+        living_people(&mut world).len(),
+        3,
+        "one arrival restores the floor; no second newcomer follows"
+    );
+}
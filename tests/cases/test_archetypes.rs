@@ -0,0 +1,67 @@
+//! Archetypes seed designed starting profiles: a forager spawns knowing
+//! the food around it, with its personality and skills skewed to match.
+
+use bevy::math::Vec2;
+use worldsim::agent::archetype::Archetype;
+use worldsim::agent::body::genetics::phenotype::Phenotype;
+use worldsim::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use worldsim::agent::skills::{SkillKind, Skills};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+#[test]
+fn forager_spawns_knowing_nearby_food_with_skewed_personality() {
+    let mut world = TestWorld::with_seed(42);
+    // Out of sight (human vision is 15 tiles) but inside the forager's
+    // 20-tile memory radius.
+    let near_bush = world.spawn_berry_bush(Vec2::new(500.0, 200.0), 4);
+    // Well beyond the memory radius.
+    let far_bush = world.spawn_berry_bush(Vec2::new(200.0, 900.0), 4);
+    let forager = world
+        .spawn_agent(AgentConfig::at(Vec2::new(200.0, 200.0)).with_archetype(Archetype::Forager));
+    let plain = world.spawn_agent(AgentConfig::at(Vec2::new(200.0, 220.0)));
+
+    world.tick(1);
+
+    let mind = world.get::<MindGraph>(forager);
+    assert!(
+        !mind
+            .query(
+                Some(&Node::Entity(near_bush)),
+                Some(Predicate::IsA),
+                Some(&Value::Concept(Concept::BerryBush)),
+            )
+            .is_empty(),
+        "forager should start knowing the nearby bush is a berry bush"
+    );
+    assert!(
+        !mind
+            .query(
+                Some(&Node::Entity(near_bush)),
+                Some(Predicate::Contains),
+                Some(&Value::Item(Concept::Berry, 4)),
+            )
+            .is_empty(),
+        "forager should start knowing the nearby bush holds berries"
+    );
+    assert!(
+        mind.query(Some(&Node::Entity(far_bush)), None, None)
+            .is_empty(),
+        "food beyond the memory radius stays unknown"
+    );
+    assert!(
+        world
+            .get::<MindGraph>(plain)
+            .query(Some(&Node::Entity(near_bush)), None, None)
+            .is_empty(),
+        "an agent without the archetype has no prior food knowledge"
+    );
+
+    let forager_openness = world.get::<Phenotype>(forager).openness;
+    let plain_openness = world.get::<Phenotype>(plain).openness;
+    assert!(
+        forager_openness > plain_openness + 0.1,
+        "forager openness {forager_openness} should be skewed above baseline {plain_openness}"
+    );
+    assert!(world.get::<Skills>(forager).level(SkillKind::Harvesting) > 0.35);
+    assert!(world.get::<Skills>(plain).level(SkillKind::Harvesting) < 0.1);
+}
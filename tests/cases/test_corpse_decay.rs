@@ -0,0 +1,84 @@
+//! Corpses rot: after `CorpseConfig::decay_ticks` the body is despawned
+//! and every mind forgets it. Until then scavengers feed from it.
+
+use bevy::math::Vec2;
+use bevy::prelude::Entity;
+use worldsim::agent::actions::ActionType;
+use worldsim::agent::biology::body::{Body, BodyNodeKind};
+use worldsim::agent::body::metabolism::Metabolism;
+use worldsim::agent::body::needs::PhysicalNeeds;
+use worldsim::agent::body::species::{Diet, SpeciesProfile};
+use worldsim::agent::events::{SimEvent, SimEventKind};
+use worldsim::agent::mind::knowledge::{MindGraph, Node, Value};
+use worldsim::testing::TestWorld;
+use worldsim::world::corpse::{Corpse, CorpseConfig};
+
+fn started_devour_on(world: &TestWorld, agent: Entity, target: Entity) -> bool {
+    world.sim_events().all().iter().any(|e| {
+        matches!(
+            e,
+            SimEvent {
+                kind: SimEventKind::ActionStarted {
+                    agent: a, action: ActionType::Devour, target: Some(t), ..
+                },
+                ..
+            } if *a == agent && *t == target
+        )
+    })
+}
+
+#[test]
+fn scavenger_feeds_before_corpse_rots_away() {
+    let mut world = TestWorld::with_seed(42);
+    world
+        .app_mut()
+        .insert_resource(CorpseConfig { decay_ticks: 1_000 });
+
+    let deer = world.spawn_deer(Vec2::new(50.0, 50.0));
+    let scavenger = world.spawn_wolf(Vec2::new(50.0, 50.0));
+    {
+        let app_world = world.app_mut().world_mut();
+        app_world
+            .get_mut::<SpeciesProfile>(scavenger)
+            .expect("wolf has SpeciesProfile")
+            .diet = Diet::Scavenger;
+        app_world
+            .get_mut::<PhysicalNeeds>(scavenger)
+            .expect("wolf has PhysicalNeeds")
+            .metabolism = Metabolism::at_urgency(0.95);
+    }
+    world.tick(5);
+
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<Body>(deer)
+        .expect("deer has Body")
+        .node_mut(BodyNodeKind::Heart)
+        .expect("body has Heart")
+        .current_hp = 0.0;
+    world.tick(600);
+
+    assert!(
+        world.app().world().get::<Corpse>(deer).is_some(),
+        "corpse should still be fresh before its timer runs out"
+    );
+    assert!(
+        started_devour_on(&world, scavenger, deer),
+        "hungry scavenger should feed on the corpse while it lasts"
+    );
+
+    world.tick(500);
+
+    assert!(
+        !world.entity_exists(deer),
+        "rotted corpse should be despawned"
+    );
+    let mind = world.get::<MindGraph>(scavenger);
+    assert!(
+        !mind
+            .iter()
+            .any(|t| t.subject == Node::Entity(deer) || t.object == Value::Entity(deer)),
+        "scavenger should forget the despawned corpse"
+    );
+}
@@ -209,6 +209,7 @@ fn deposit_can_start_requires_target_entity() {
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     };
     assert!(deposit.can_start(&no_target).is_err());
 
@@ -228,6 +229,7 @@ fn deposit_can_start_requires_target_entity() {
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     };
     assert!(deposit.can_start(&with_target).is_ok());
 }
@@ -258,6 +260,7 @@ fn deposit_can_start_requires_non_empty_inventory() {
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     };
     assert!(
         deposit.can_start(&ctx).is_err(),
@@ -0,0 +1,95 @@
+//! Drop: a full-handed agent sets its least valuable stack down so it can
+//! pick up food.
+//!
+//! Pickup refuses to overfill a capped carry. Drop moves the whole
+//! inedible stack into a fresh `GroundItem` on the agent's tile, after
+//! which the same Pickup succeeds.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::{ActionState, ActionType, ActiveActions};
+use worldsim::agent::item_slots::ItemSlots;
+use worldsim::agent::mind::knowledge::Concept;
+use worldsim::testing::TestWorld;
+use worldsim::world::ground_item::GroundItem;
+
+fn run_action(world: &mut TestWorld, agent: Entity, action: ActionType, target: Option<Entity>) {
+    {
+        let pos = world.get::<Transform>(agent).translation.truncate();
+        let mut active = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ActiveActions>(agent)
+            .expect("agent has ActiveActions");
+        let mut state = ActionState::new(action, 0);
+        state.target_entity = target;
+        state.target_position = Some(pos);
+        state.ticks_remaining = 0;
+        active.insert(state);
+    }
+    world.tick(2);
+}
+
+fn ground_stones(world: &mut TestWorld) -> u32 {
+    let world = world.app_mut().world_mut();
+    let mut items = world.query_filtered::<&ItemSlots, With<GroundItem>>();
+    items
+        .iter(world)
+        .map(|slots| slots.count(Concept::Stone))
+        .sum()
+}
+
+#[test]
+fn full_inventory_agent_drops_stones_to_pick_up_food() {
+    let pos = Vec2::new(100.0, 100.0);
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(pos)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    let berries = world.spawn_ground_item(pos, Concept::Berry, 1);
+
+    // Daze Alice so the brain doesn't replace the injected actions.
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(alice)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+    {
+        let mut carry = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ItemSlots>(alice)
+            .expect("alice has ItemSlots");
+        *carry = ItemSlots::agent_carry();
+        carry.slots[0].capacity = Some(2);
+        carry.add(Concept::Stone, 2);
+        assert!(carry.is_full());
+    }
+
+    run_action(&mut world, alice, ActionType::Pickup, Some(berries));
+    assert_eq!(
+        world.get::<ItemSlots>(alice).count(Concept::Berry),
+        0,
+        "a full carry has no room for the berry"
+    );
+
+    run_action(&mut world, alice, ActionType::Drop, None);
+    assert_eq!(world.get::<ItemSlots>(alice).count(Concept::Stone), 0);
+    assert_eq!(
+        ground_stones(&mut world),
+        2,
+        "the whole stone stack should be lying on the ground"
+    );
+
+    run_action(&mut world, alice, ActionType::Pickup, Some(berries));
+    assert_eq!(
+        world.get::<ItemSlots>(alice).count(Concept::Berry),
+        1,
+        "with room freed, the berry should be picked up"
+    );
+}
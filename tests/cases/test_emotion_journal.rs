@@ -0,0 +1,65 @@
+//! Emotion journal: opt-in per-agent `(tick, emotion, intensity)` samples
+//! exported as CSV for plotting affective dynamics outside the sim.
+
+use bevy::prelude::*;
+use worldsim::agent::psyche::emotion_journal::{EmotionJournal, EmotionJournalConfig};
+use worldsim::agent::psyche::emotions::{Emotion, EmotionType, EmotionalState};
+use worldsim::testing::TestWorld;
+
+fn frightened_alice(journal: Option<EmotionJournalConfig>) -> (TestWorld, Entity) {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(100.0, 100.0))
+        .done()
+        .build();
+    let alice = agents["alice"];
+    if let Some(config) = journal {
+        world.app_mut().insert_resource(config);
+    }
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<EmotionalState>(alice)
+        .expect("agent has emotions")
+        .add_emotion(Emotion::new(EmotionType::Fear, 0.9));
+    (world, alice)
+}
+
+#[test]
+fn csv_contains_fear_samples_fading_over_time() {
+    let (mut world, alice) = frightened_alice(Some(EmotionJournalConfig {
+        enabled: true,
+        interval: 10,
+        capacity: 1000,
+    }));
+    world.tick(60);
+
+    let csv = world.get::<EmotionJournal>(alice).to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("tick,emotion,intensity"));
+
+    let fear: Vec<(u64, f32)> = lines
+        .filter_map(|line| {
+            let mut cols = line.split(',');
+            let tick = cols.next()?.parse().ok()?;
+            (cols.next()? == "Fear").then_some(())?;
+            Some((tick, cols.next()?.parse().ok()?))
+        })
+        .collect();
+    assert!(fear.len() >= 5, "expected a Fear row per interval: {csv}");
+    assert!(fear.iter().all(|(tick, _)| tick % 10 == 0));
+    assert!(
+        fear.windows(2).all(|w| w[1].1 <= w[0].1),
+        "unreinforced fear should fade: {fear:?}"
+    );
+}
+
+#[test]
+fn journal_is_off_by_default() {
+    let (mut world, alice) = frightened_alice(None);
+    world.tick(60);
+
+    assert!(world.app().world().get::<EmotionJournal>(alice).is_none());
+}
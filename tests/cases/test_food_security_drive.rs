@@ -172,7 +172,7 @@ fn food_security_never_exceeds_one() {
 #[test]
 fn insecure_agent_with_wood_plans_build_storage_chest() {
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Quantity, Triple, setup_ontology};
 
@@ -224,9 +224,11 @@ fn insecure_agent_with_wood_plans_build_storage_chest() {
         &mind,
         Some(&inventory),
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     let plan = plan.unwrap_or_else(|| {
         panic!(
@@ -392,7 +394,7 @@ fn stock_chest_on_complete_moves_food_into_chest() {
 fn hungry_agent_with_known_stocked_chest_plans_take_then_eat() {
     use bevy::prelude::Entity;
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Quantity, Triple, setup_ontology};
 
@@ -450,9 +452,11 @@ fn hungry_agent_with_known_stocked_chest_plans_take_then_eat() {
         &mind,
         None,
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     let plan = plan.unwrap_or_else(|| {
         panic!(
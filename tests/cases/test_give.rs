@@ -0,0 +1,111 @@
+//! Give: handing apples to a friend moves them between inventories, and
+//! both sides see the result and the recipient warms to the giver.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::{ActionState, ActionType, ActiveActions};
+use worldsim::agent::events::{RelationshipDimension, SimEventKind};
+use worldsim::agent::item_slots::ItemSlots;
+use worldsim::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
+use worldsim::testing::TestWorld;
+
+/// Apples `observer` believes `subject` is carrying, if it has a belief.
+fn believed_apples(world: &TestWorld, observer: Entity, subject: Entity) -> Option<u32> {
+    world
+        .get::<MindGraph>(observer)
+        .query(
+            Some(&Node::Entity(subject)),
+            Some(Predicate::Contains),
+            None,
+        )
+        .into_iter()
+        .find_map(|t| match t.object {
+            Value::Item(Concept::Apple, qty) => Some(qty),
+            _ => None,
+        })
+}
+
+#[test]
+fn giving_apples_moves_them_and_both_minds_see_it() {
+    let pos = Vec2::new(100.0, 100.0);
+    let bob_pos = pos + Vec2::new(16.0, 0.0);
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(pos)
+        .done()
+        .agent("bob")
+        .pos(bob_pos)
+        .done()
+        .relationship("alice", "bob", |r| r.affection(0.8))
+        .build();
+    let alice = agents["alice"];
+    let bob = agents["bob"];
+
+    // Daze both so neither brain replaces the injected Give or eats the gift.
+    for agent in [alice, bob] {
+        world
+            .app_mut()
+            .world_mut()
+            .entity_mut(agent)
+            .insert(worldsim::agent::Dazed {
+                until_tick: u64::MAX,
+            });
+    }
+    world.get_mut::<ItemSlots>(alice).add(Concept::Apple, 5);
+    let alice_before = world.item_count(alice, Concept::Apple);
+    let bob_before = world.item_count(bob, Concept::Apple);
+    {
+        let mut active = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ActiveActions>(alice)
+            .expect("alice has ActiveActions");
+        let mut state = ActionState::new(ActionType::Give, 0);
+        state.target_entity = Some(bob);
+        state.target_position = Some(bob_pos);
+        active.insert(state);
+    }
+
+    world.tick(30);
+
+    let quantity = worldsim::constants::actions::give::QUANTITY;
+    assert_eq!(
+        world.item_count(alice, Concept::Apple),
+        alice_before - quantity,
+        "alice should have handed over {quantity} apples"
+    );
+    assert_eq!(
+        world.item_count(bob, Concept::Apple),
+        bob_before + quantity,
+        "bob should have received {quantity} apples"
+    );
+
+    assert_eq!(
+        believed_apples(&world, alice, bob),
+        Some(bob_before + quantity),
+        "alice should see the apples in bob's hands"
+    );
+    assert_eq!(
+        believed_apples(&world, bob, alice),
+        Some(alice_before - quantity),
+        "bob should see what alice has left"
+    );
+
+    let bob_warmed = world.sim_events().all().iter().any(|e| {
+        matches!(
+            e.kind,
+            SimEventKind::RelationshipChanged {
+                agent,
+                other,
+                dimension: RelationshipDimension::Affection,
+                old_value,
+                new_value,
+            } if agent == bob && other == alice && new_value > old_value
+        )
+    });
+    assert!(
+        bob_warmed,
+        "the gift should raise bob's affection for alice"
+    );
+}
@@ -0,0 +1,59 @@
+//! Ground items: loose Things dropped in the world that agents collect with
+//! Pickup.
+//!
+//! A `GroundItem` carries a Pickup `Affordance`, so the planner enumerates
+//! it like any other resource. Completing Pickup moves one Thing into the
+//! agent's `ItemSlots`; the emptied ground item despawns.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::{ActionState, ActionType, ActiveActions};
+use worldsim::agent::item_slots::ItemSlots;
+use worldsim::agent::mind::knowledge::Concept;
+use worldsim::testing::TestWorld;
+
+#[test]
+fn agent_picks_up_dropped_item_into_inventory() {
+    let pos = Vec2::new(100.0, 100.0);
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(pos)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    let stick = world.spawn_ground_item(pos, Concept::Stick, 1);
+
+    // Daze Alice so the brain doesn't replace the injected Pickup.
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(alice)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+    {
+        let mut active = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ActiveActions>(alice)
+            .expect("alice has ActiveActions");
+        let mut state = ActionState::new(ActionType::Pickup, 0);
+        state.target_entity = Some(stick);
+        state.target_position = Some(pos);
+        state.ticks_remaining = 0;
+        active.insert(state);
+    }
+
+    world.tick(2);
+
+    assert_eq!(
+        world.get::<ItemSlots>(alice).count(Concept::Stick),
+        1,
+        "picked-up stick should be in Alice's inventory"
+    );
+    assert!(
+        world.app().world().get_entity(stick).is_err(),
+        "emptied ground item should be despawned"
+    );
+}
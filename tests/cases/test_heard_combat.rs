@@ -0,0 +1,85 @@
+//! End-to-end tests for loud actions: an `Attack` starting →
+//! `emit_loud_actions` → `perceive_sound` → `(Attacker, Heard, Attack)` and
+//! a dangerous direction in the listener's mind → fear via `react_to_danger`.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::ActionType;
+use worldsim::agent::events::{SimEvent, SimEventKind};
+use worldsim::agent::mind::knowledge::{MindGraph, Node, Predicate, Sense, Value};
+use worldsim::agent::psyche::emotions::{EmotionType, EmotionalState};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+fn fear(world: &TestWorld, agent: Entity) -> f32 {
+    world
+        .get::<EmotionalState>(agent)
+        .active_emotions
+        .iter()
+        .filter(|e| e.emotion_type == EmotionType::Fear)
+        .map(|e| e.intensity)
+        .sum()
+}
+
+fn heard_attack(world: &TestWorld, listener: Entity, attacker: Entity) -> bool {
+    world
+        .get::<MindGraph>(listener)
+        .query(
+            Some(&Node::Entity(attacker)),
+            Some(Predicate::Heard),
+            Some(&Value::Action(ActionType::Attack)),
+        )
+        .iter()
+        .any(|t| t.meta.source_sense == Some(Sense::Hearing))
+}
+
+fn start_attack(world: &mut TestWorld, attacker: Entity, victim: Entity) {
+    world.app_mut().world_mut().write_message(SimEvent::single(
+        0,
+        attacker,
+        SimEventKind::ActionStarted {
+            agent: attacker,
+            action: ActionType::Attack,
+            target: Some(victim),
+            plan_id: None,
+            plan_step: None,
+        },
+    ));
+}
+
+#[test]
+fn listener_in_earshot_hears_the_fight_and_grows_afraid() {
+    let mut world = TestWorld::with_seed(42);
+    let attacker = world.spawn_agent(AgentConfig::at(Vec2::new(100.0, 100.0)));
+    let victim = world.spawn_agent(AgentConfig::at(Vec2::new(120.0, 100.0)));
+    // Well inside the sound radius, regardless of line of sight.
+    let listener = world.spawn_agent(AgentConfig::at(Vec2::new(100.0, 400.0)));
+
+    let before = fear(&world, listener);
+    start_attack(&mut world, attacker, victim);
+    world.tick(2);
+
+    assert!(
+        heard_attack(&world, listener, attacker),
+        "listener should record (attacker, Heard, Attack) via hearing"
+    );
+    assert!(
+        fear(&world, listener) > before,
+        "a heard fight should frighten the listener (before {before:.2}, after {:.2})",
+        fear(&world, listener)
+    );
+}
+
+#[test]
+fn listener_beyond_sound_radius_hears_nothing() {
+    let mut world = TestWorld::with_seed(42);
+    let attacker = world.spawn_agent(AgentConfig::at(Vec2::new(100.0, 100.0)));
+    let victim = world.spawn_agent(AgentConfig::at(Vec2::new(120.0, 100.0)));
+    let listener = world.spawn_agent(AgentConfig::at(Vec2::new(100.0, 900.0)));
+
+    start_attack(&mut world, attacker, victim);
+    world.tick(2);
+
+    assert!(
+        !heard_attack(&world, listener, attacker),
+        "a fight beyond the sound radius should go unheard"
+    );
+}
@@ -48,6 +48,7 @@ fn make_ctx<'a>(
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     }
 }
 
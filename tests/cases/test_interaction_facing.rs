@@ -0,0 +1,56 @@
+//! Auto-facing: an agent starting an entity-targeted action within range
+//! turns to face the target.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::{ActionState, ActionType, ActiveActions};
+use worldsim::agent::mind::knowledge::Concept;
+use worldsim::agent::movement::Facing;
+use worldsim::testing::TestWorld;
+
+#[test]
+fn agent_faces_target_when_interaction_begins() {
+    let pos = Vec2::new(100.0, 100.0);
+    let stick_pos = pos + Vec2::new(0.0, 20.0);
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(pos)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    let stick = world.spawn_ground_item(stick_pos, Concept::Stick, 1);
+
+    // Daze Alice so the brain doesn't replace the injected Pickup.
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(alice)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+    {
+        let mut active = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ActiveActions>(alice)
+            .expect("alice has ActiveActions");
+        let mut state = ActionState::new(ActionType::Pickup, 0);
+        state.target_entity = Some(stick);
+        state.target_position = Some(stick_pos);
+        active.insert(state);
+    }
+
+    world.tick(1);
+
+    let facing = world
+        .app()
+        .world()
+        .get::<Facing>(alice)
+        .expect("alice should have turned toward the stick");
+    assert!(
+        facing.0.distance(Vec2::Y) < 1e-4,
+        "facing should point at the stick (+Y), got {:?}",
+        facing.0
+    );
+}
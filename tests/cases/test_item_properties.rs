@@ -4,12 +4,15 @@
 //! - Harvested apples get freshness = 1.0 and created_at stamped
 //! - Freshness decays over time via the freshness_decay_system
 //! - Freshness reaching 0 converts the concept to its rotten variant
+//!   (or discards items that have none)
+//! - SpoilageConfig scales the decay rate
 //! - Properties are preserved through deposit/take transfers
 
 use bevy::prelude::Vec2;
 use worldsim::agent::events::SimEventKind;
 use worldsim::agent::item_slots::{
-    Access, ItemSlots, Slot, SlotFilter, SlotRole, Thing, ThingProperties, perishable_decay_rate,
+    Access, ItemSlots, Slot, SlotFilter, SlotRole, SpoilageConfig, Thing, ThingProperties,
+    perishable_decay_rate,
 };
 use worldsim::agent::mind::knowledge::Concept;
 use worldsim::testing::{AgentConfig, TestWorld};
@@ -130,6 +133,63 @@ fn freshness_reaches_zero_and_converts_to_rotten_apple() {
     );
 }
 
+#[test]
+fn meat_with_no_rotten_variant_is_discarded_at_zero() {
+    let mut world = TestWorld::new();
+    let agent = world.spawn_agent(AgentConfig::at(Vec2::new(50.0, 50.0)));
+
+    let rate = perishable_decay_rate(Concept::Meat).unwrap();
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<ItemSlots>(agent)
+        .unwrap()
+        .add_thing(Thing {
+            concept: Concept::Meat,
+            properties: ThingProperties {
+                freshness: Some(rate * 0.5),
+                ..Default::default()
+            },
+        });
+
+    world.tick(100);
+
+    let slots = world.app().world().get::<ItemSlots>(agent).unwrap();
+    assert_eq!(slots.count(Concept::Meat), 0, "Rotted meat should be gone");
+}
+
+#[test]
+fn spoilage_config_scales_decay_rate() {
+    let mut world = TestWorld::new();
+    let agent = world.spawn_agent(AgentConfig::at(Vec2::new(50.0, 50.0)));
+    world.app_mut().world_mut().insert_resource(SpoilageConfig {
+        decay_multiplier: 3.0,
+    });
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<ItemSlots>(agent)
+        .unwrap()
+        .add_thing(Thing::fresh(Concept::Apple, 0));
+
+    world.tick(100);
+
+    let freshness = world
+        .app()
+        .world()
+        .get::<ItemSlots>(agent)
+        .unwrap()
+        .all_items()
+        .find(|t| t.concept == Concept::Apple)
+        .and_then(|t| t.properties.freshness)
+        .unwrap();
+    let expected = 1.0 - 3.0 * perishable_decay_rate(Concept::Apple).unwrap();
+    assert!(
+        (freshness - expected).abs() < 0.001,
+        "Expected freshness={expected:.4}, got {freshness:.4}"
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Harvest stamps freshness
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,77 @@
+//! "Known empty" vs "unknown" resources.
+//!
+//! Seeing a harvestable bare — even on first sight, with no earlier belief
+//! to zero out — records `Contains(item, 0)` and drops it from planning.
+//! A resource the agent knows of but has never looked inside stays a
+//! candidate worth checking.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
+use worldsim::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Triple, Value};
+use worldsim::testing::TestWorld;
+
+#[test]
+fn observed_empty_bush_is_skipped_while_unknown_bush_stays_a_candidate() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(32, 32)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(50.0, 50.0))
+        .done()
+        .build();
+    let alice = agents["alice"];
+
+    let empty_pos = Vec2::new(62.0, 50.0);
+    let unknown_pos = Vec2::new(420.0, 420.0);
+    let empty = world.spawn_berry_bush(empty_pos, 0);
+    let unknown = world.spawn_berry_bush(unknown_pos, 5);
+    // Alice has heard of the far bush but never seen it.
+    world.get_mut::<MindGraph>(alice).assert(Triple::new(
+        Node::Entity(unknown),
+        Predicate::IsA,
+        Value::Concept(Concept::BerryBush),
+    ));
+
+    // Long enough for the spatial index and a look; short of any regrowth.
+    world.tick(3);
+
+    let mind = world.get::<MindGraph>(alice);
+    assert!(
+        mind.has(
+            &Node::Entity(empty),
+            Predicate::Contains,
+            &Value::Item(Concept::Berry, 0)
+        ),
+        "a bare bush seen for the first time is believed empty"
+    );
+    assert!(mind.is_known_empty(empty));
+    assert!(
+        !mind.is_known_empty(unknown),
+        "never looked inside is not the same as empty"
+    );
+
+    let registry = ActionRegistry::new();
+    let harvest = registry
+        .get(ActionType::Harvest)
+        .expect("harvest registered");
+    assert!(
+        !harvest.is_plan_valid(
+            &TargetCandidate::Entity {
+                entity: empty,
+                pos: empty_pos,
+            },
+            mind,
+        ),
+        "known-empty bush is skipped"
+    );
+    assert!(
+        harvest.is_plan_valid(
+            &TargetCandidate::Entity {
+                entity: unknown,
+                pos: unknown_pos,
+            },
+            mind,
+        ),
+        "unknown bush is still worth checking"
+    );
+}
@@ -0,0 +1,110 @@
+//! Integration tests for the public `Milestone` stream.
+//!
+//! Embedding code subscribes with `MessageReader<Milestone>` instead of
+//! polling components; these tests check that the occurrences it promises
+//! actually arrive.
+
+use bevy::prelude::*;
+use worldsim::agent::biology::body::{Body, BodyNodeKind};
+use worldsim::agent::milestones::Milestone;
+use worldsim::agent::mind::knowledge::Concept;
+use worldsim::agent::psyche::relationships::{InteractionRecord, RelationshipHistory};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+#[derive(Resource, Default)]
+struct MilestoneCollector {
+    milestones: Vec<Milestone>,
+}
+
+fn collect_milestones(
+    mut reader: MessageReader<Milestone>,
+    mut collector: ResMut<MilestoneCollector>,
+) {
+    collector.milestones.extend(reader.read().cloned());
+}
+
+#[test]
+fn death_and_new_friendship_each_publish_a_milestone() {
+    let mut world = TestWorld::with_seed(42);
+    world
+        .app_mut()
+        .init_resource::<MilestoneCollector>()
+        .add_systems(Last, collect_milestones);
+
+    let alice = world.spawn_agent(AgentConfig {
+        pos: Vec2::new(40.0, 40.0),
+        ..Default::default()
+    });
+    let bob = world.spawn_agent(AgentConfig {
+        pos: Vec2::new(50.0, 40.0),
+        ..Default::default()
+    });
+    let carol = world.spawn_agent(AgentConfig {
+        pos: Vec2::new(400.0, 400.0),
+        ..Default::default()
+    });
+
+    // Alice and Bob go way back: enough warm exchanges to count as friends
+    // the next time Alice sees him.
+    world.introduce_agent(alice, bob, "Bob", 0.5);
+    world.introduce_agent(bob, alice, "Alice", 0.5);
+    {
+        let mut history = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<RelationshipHistory>(alice)
+            .expect("agents keep a relationship history");
+        for tick in 0..10 {
+            history.push(
+                bob,
+                InteractionRecord {
+                    tick,
+                    topic: None,
+                    valence: 0.8,
+                },
+            );
+        }
+    }
+
+    // Carol's heart gives out.
+    world
+        .app_mut()
+        .world_mut()
+        .get_mut::<Body>(carol)
+        .expect("agent has a body")
+        .node_mut(BodyNodeKind::Heart)
+        .expect("body has a heart")
+        .current_hp = 0.0;
+
+    world.tick(30);
+
+    let milestones = &world
+        .app()
+        .world()
+        .resource::<MilestoneCollector>()
+        .milestones;
+    let died = milestones
+        .iter()
+        .filter(|m| matches!(m, Milestone::Died { agent, .. } if *agent == carol))
+        .count();
+    assert_eq!(died, 1, "one death milestone for carol: {milestones:?}");
+
+    let befriended = milestones
+        .iter()
+        .filter(|m| {
+            matches!(
+                m,
+                Milestone::RelationshipFormed {
+                    agent,
+                    other,
+                    category: Concept::Friend,
+                    ..
+                } if *agent == alice && *other == bob
+            )
+        })
+        .count();
+    assert_eq!(
+        befriended, 1,
+        "alice befriends bob exactly once, not every tick she sees him: {milestones:?}"
+    );
+}
@@ -6,13 +6,20 @@
 //! so the plan step never advances and the agent is stuck forever.
 //!
 //! Fix: snap position to the exact target when arriving within threshold.
+//!
+//! Also covers map edges: under `EdgePolicy::Wrap` an agent walking off one
+//! edge reappears on the opposite one; under `Wall` it is blocked. And
+//! targets that can be approached but not stood on: the agent settles at
+//! the closest point instead of hovering there or failing the move. Walls
+//! across a Walk's straight line are routed around via A* waypoints.
 
 use bevy::prelude::*;
 use worldsim::agent::TargetPosition;
 use worldsim::agent::actions::{ActionState, ActionType, ActiveActions};
 use worldsim::agent::brains::proposal::BrainState;
+use worldsim::agent::events::SimEventKind;
 use worldsim::testing::{AgentConfig, TestWorld};
-use worldsim::world::map::TILE_SIZE;
+use worldsim::world::map::{EdgePolicy, TILE_SIZE, TileType, WorldMap};
 
 /// Regression: Walk action arriving within ARRIVAL_THRESHOLD of a tile boundary
 /// must snap position to the exact target so the perceived tile matches the Walk
@@ -88,3 +95,264 @@ fn walk_snaps_to_target_when_arriving_near_tile_boundary() {
         (target.y / TILE_SIZE).floor() as i32,
     );
 }
+
+/// Walk a dazed agent from just inside the west edge toward a point 10 px
+/// past it, under the given edge policy. Returns the final position and the
+/// map's pixel width.
+fn walk_west_past_edge(policy: EdgePolicy) -> (Vec2, f32) {
+    let start = Vec2::new(6.0, 50.0);
+    let target = Vec2::new(-10.0, 50.0);
+
+    let mut world = TestWorld::with_seed(42);
+    world
+        .app_mut()
+        .world_mut()
+        .resource_mut::<WorldMap>()
+        .edge_policy = policy;
+    let agent = world.spawn_agent(AgentConfig::at(start));
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(agent)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+
+    world.tick(1);
+    {
+        let w = world.app_mut().world_mut();
+        w.get_mut::<ActiveActions>(agent).unwrap().clear();
+        w.get_mut::<BrainState>(agent)
+            .unwrap()
+            .chosen_actions
+            .clear();
+        w.get_mut::<ActiveActions>(agent)
+            .unwrap()
+            .insert(ActionState {
+                action_type: ActionType::Walk,
+                target_position: Some(target),
+                ..Default::default()
+            });
+        w.get_mut::<TargetPosition>(agent).unwrap().0 = Some(target);
+    }
+
+    world.tick(40);
+    let map_width = world.app().world().resource::<WorldMap>().pixel_bounds().0;
+    (
+        world.get::<Transform>(agent).translation.truncate(),
+        map_width,
+    )
+}
+
+#[test]
+fn walking_off_the_edge_wraps_to_the_opposite_side_under_wrap() {
+    let (pos, map_width) = walk_west_past_edge(EdgePolicy::Wrap);
+
+    assert!(
+        (pos.x - (map_width - 10.0)).abs() < 0.01 && (pos.y - 50.0).abs() < 0.01,
+        "agent should reappear at the east edge (x = {}), got {pos:?}",
+        map_width - 10.0
+    );
+}
+
+#[test]
+fn walking_off_the_edge_is_blocked_under_wall() {
+    let (pos, _) = walk_west_past_edge(EdgePolicy::Wall);
+
+    assert!(
+        (0.0..TILE_SIZE).contains(&pos.x),
+        "agent should stop at the west wall, got {pos:?}"
+    );
+}
+
+/// Soft collision: two dazed agents walking to the same tile don't end up
+/// stacked. Once both have settled, the extra one is nudged onto a
+/// neighbouring tile.
+#[test]
+fn agents_targeting_the_same_tile_settle_on_adjacent_tiles() {
+    let target = Vec2::new(5.5 * TILE_SIZE, 3.5 * TILE_SIZE);
+    let mut world = TestWorld::with_seed(42);
+    let agents = [
+        world.spawn_agent(AgentConfig::at(target - Vec2::new(3.0 * TILE_SIZE, 0.0))),
+        world.spawn_agent(AgentConfig::at(target + Vec2::new(3.0 * TILE_SIZE, 0.0))),
+    ];
+    for &agent in &agents {
+        world
+            .app_mut()
+            .world_mut()
+            .entity_mut(agent)
+            .insert(worldsim::agent::Dazed {
+                until_tick: u64::MAX,
+            });
+    }
+
+    world.tick(1);
+    for &agent in &agents {
+        let w = world.app_mut().world_mut();
+        w.get_mut::<ActiveActions>(agent).unwrap().clear();
+        w.get_mut::<BrainState>(agent)
+            .unwrap()
+            .chosen_actions
+            .clear();
+        w.get_mut::<ActiveActions>(agent)
+            .unwrap()
+            .insert(ActionState {
+                action_type: ActionType::Walk,
+                target_position: Some(target),
+                ..Default::default()
+            });
+        w.get_mut::<TargetPosition>(agent).unwrap().0 = Some(target);
+    }
+
+    world.tick(120);
+
+    let tiles = agents.map(|agent| {
+        let pos = world.get::<Transform>(agent).translation.truncate();
+        IVec2::new(
+            (pos.x / TILE_SIZE).floor() as i32,
+            (pos.y / TILE_SIZE).floor() as i32,
+        )
+    });
+    assert_ne!(tiles[0], tiles[1], "agents must not share a tile");
+    let gap = (tiles[0] - tiles[1]).abs();
+    assert!(
+        gap.max_element() == 1,
+        "agents should end up on adjacent tiles, got {tiles:?}"
+    );
+    assert!(
+        tiles.contains(&IVec2::new(5, 3)),
+        "one agent should hold the target tile, got {tiles:?}"
+    );
+}
+
+/// A Walk target 4 px inside a pond can't be stood on, and it's past
+/// ARRIVAL_THRESHOLD from the shore. The agent walks up to the water's
+/// edge, stalls there, and settles: the Walk completes (rather than
+/// failing as path-blocked one step short) and the agent stays put.
+#[test]
+fn walk_toward_a_point_just_inside_an_obstacle_settles_at_its_edge() {
+    let start = Vec2::new(2.5 * TILE_SIZE, 3.5 * TILE_SIZE);
+    let target = Vec2::new(5.0 * TILE_SIZE + 4.0, 3.5 * TILE_SIZE);
+
+    let mut world = TestWorld::with_seed(42);
+    world.set_tile(5, 3, TileType::Water);
+    let agent = world.spawn_agent(AgentConfig::at(start));
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(agent)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+
+    world.tick(1);
+    let now = world.current_tick();
+    {
+        let w = world.app_mut().world_mut();
+        w.get_mut::<ActiveActions>(agent).unwrap().clear();
+        w.get_mut::<BrainState>(agent)
+            .unwrap()
+            .chosen_actions
+            .clear();
+        w.get_mut::<ActiveActions>(agent)
+            .unwrap()
+            .insert(ActionState::new(ActionType::Walk, now).with_target_position(target));
+        w.get_mut::<TargetPosition>(agent).unwrap().0 = Some(target);
+    }
+
+    world.tick(100);
+
+    assert!(
+        !world.get::<ActiveActions>(agent).contains(ActionType::Walk),
+        "the Walk should have settled and ended"
+    );
+    assert!(
+        world.sim_events().all().iter().any(|e| matches!(
+            e.kind,
+            SimEventKind::ActionCompleted {
+                agent: a,
+                action: ActionType::Walk,
+                ..
+            } if a == agent
+        )),
+        "settling counts as arriving, not as a blocked path"
+    );
+    let settled = world.get::<Transform>(agent).translation.truncate();
+    assert_eq!(
+        (settled.x / TILE_SIZE).floor() as i32,
+        4,
+        "agent should stop on the shore tile, got {settled:?}"
+    );
+    assert!(settled.distance(target) <= TILE_SIZE);
+
+    world.tick(20);
+    let later = world.get::<Transform>(agent).translation.truncate();
+    assert!(
+        later.distance(settled) < 0.01,
+        "a settled agent stays put: {settled:?} -> {later:?}"
+    );
+}
+
+/// A Walk whose straight line runs into a wall routes through the gap
+/// instead of failing as path-blocked, and ends on the target tile.
+#[test]
+fn walk_blocked_by_a_wall_reroutes_through_the_gap() {
+    let start = Vec2::new(2.5 * TILE_SIZE, 3.5 * TILE_SIZE);
+    let target = Vec2::new(8.5 * TILE_SIZE, 3.5 * TILE_SIZE);
+
+    let mut world = TestWorld::with_seed(42);
+    for x in 0..12 {
+        for y in 0..12 {
+            world.set_tile(x, y, TileType::Grass);
+        }
+    }
+    // Wall at x = 5 from the map edge up to y = 6; the gap is at y = 7.
+    for y in 0..7 {
+        world.set_tile(5, y, TileType::Water);
+    }
+    let agent = world.spawn_agent(AgentConfig::at(start));
+    world
+        .app_mut()
+        .world_mut()
+        .entity_mut(agent)
+        .insert(worldsim::agent::Dazed {
+            until_tick: u64::MAX,
+        });
+
+    world.tick(1);
+    let now = world.current_tick();
+    {
+        let w = world.app_mut().world_mut();
+        w.get_mut::<ActiveActions>(agent).unwrap().clear();
+        w.get_mut::<BrainState>(agent)
+            .unwrap()
+            .chosen_actions
+            .clear();
+        w.get_mut::<ActiveActions>(agent)
+            .unwrap()
+            .insert(ActionState::new(ActionType::Walk, now).with_target_position(target));
+        w.get_mut::<TargetPosition>(agent).unwrap().0 = Some(target);
+    }
+
+    world.tick(600);
+
+    let failed = world.sim_events().all().iter().any(|e| {
+        matches!(
+            e.kind,
+            SimEventKind::ActionFailed {
+                agent: a,
+                action: ActionType::Walk,
+                ..
+            } if a == agent
+        )
+    });
+    assert!(
+        !failed,
+        "the wall should be walked around, not fail the Walk"
+    );
+    let end = world.get::<Transform>(agent).translation.truncate();
+    assert!(
+        end.distance(target) < 1.0,
+        "agent should reach the far side of the wall, got {end:?}"
+    );
+}
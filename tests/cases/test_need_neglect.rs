@@ -0,0 +1,59 @@
+//! Time since a need was last satisfied feeds urgency: at equal hunger, an
+//! agent who hasn't eaten all day feels hungrier than one who just ate.
+
+use bevy::math::Vec2;
+use worldsim::agent::body::metabolism::Metabolism;
+use worldsim::agent::body::needs::PhysicalNeeds;
+use worldsim::agent::nervous_system::cns::CentralNervousSystem;
+use worldsim::agent::nervous_system::urgency::UrgencySource;
+use worldsim::agent::psyche::personality::Personality;
+use worldsim::core::{GameTime, TickCount};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+#[test]
+fn hunger_urgency_is_higher_for_an_agent_that_has_not_eaten_in_a_day() {
+    let mut world = TestWorld::with_seed(11);
+    let fed = world.spawn_agent(AgentConfig::at(Vec2::new(40.0, 40.0)));
+    let starved = world.spawn_agent(AgentConfig::at(Vec2::new(200.0, 40.0)));
+
+    // Start a day in so "last ate a day ago" is representable, and give
+    // both agents the same personality so sensitivity doesn't differ.
+    let now = GameTime::TICKS_PER_DAY;
+    world
+        .app_mut()
+        .world_mut()
+        .resource_mut::<TickCount>()
+        .current = now;
+    let personality = world.get::<Personality>(fed).clone();
+    for (agent, last_ate) in [(fed, now), (starved, 0)] {
+        let w = world.app_mut().world_mut();
+        w.entity_mut(agent).insert((
+            personality.clone(),
+            worldsim::agent::Dazed {
+                until_tick: u64::MAX,
+            },
+        ));
+        let mut needs = w.get_mut::<PhysicalNeeds>(agent).unwrap();
+        needs.metabolism = Metabolism::at_urgency(0.5);
+        needs.last_ate_tick = last_ate;
+        // Empty urgencies force a regeneration on the next tick for both.
+        w.get_mut::<CentralNervousSystem>(agent)
+            .unwrap()
+            .urgencies
+            .clear();
+    }
+
+    world.tick(1);
+
+    let hunger = |agent| {
+        world
+            .get::<CentralNervousSystem>(agent)
+            .urgency_value(UrgencySource::Hunger)
+    };
+    let (fed_hunger, starved_hunger) = (hunger(fed), hunger(starved));
+    assert!(fed_hunger > 0.0, "hunger urgency should be present");
+    assert!(
+        starved_hunger > fed_hunger * 1.3,
+        "a day without food should raise hunger urgency: starved {starved_hunger:.3} vs fed {fed_hunger:.3}"
+    );
+}
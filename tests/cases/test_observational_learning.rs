@@ -0,0 +1,76 @@
+//! Observational learning: a Nomad who doesn't know berry bushes can be
+//! harvested watches a Gatherer harvest one, and afterwards believes the
+//! bush type affords Harvest and can plan a Harvest of its own.
+
+use bevy::prelude::*;
+use worldsim::agent::actions::action::HARVEST_DEF;
+use worldsim::agent::actions::registry::{Action, TargetCandidate};
+use worldsim::agent::actions::{ActionState, ActionType, ActiveActions, GenericAction};
+use worldsim::agent::culture::Culture;
+use worldsim::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Source, Value};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+#[test]
+fn witness_learns_a_novel_resource_is_harvestable_by_watching() {
+    let mut world = TestWorld::with_seed(42);
+    let bush_pos = Vec2::new(100.0, 100.0);
+    let bush = world.spawn_berry_bush(bush_pos, 5);
+    let gatherer = world.spawn_agent(
+        AgentConfig::at(bush_pos + Vec2::new(0.0, 12.0)).with_culture(Culture::Gatherer),
+    );
+    let witness = world.spawn_agent(AgentConfig::at(bush_pos + Vec2::new(48.0, 0.0)));
+    for agent in [gatherer, witness] {
+        world
+            .app_mut()
+            .world_mut()
+            .entity_mut(agent)
+            .insert(worldsim::agent::Dazed {
+                until_tick: u64::MAX,
+            });
+    }
+
+    // Let perception settle so the witness sees both the bush and the
+    // gatherer.
+    world.tick(20);
+
+    let harvest = GenericAction::new(&HARVEST_DEF);
+    let target = TargetCandidate::Entity {
+        entity: bush,
+        pos: bush_pos,
+    };
+    assert!(
+        !harvest.is_plan_valid(&target, world.get::<MindGraph>(witness)),
+        "a Nomad should not yet consider harvesting a berry bush"
+    );
+
+    {
+        let mut active = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<ActiveActions>(gatherer)
+            .expect("gatherer has ActiveActions");
+        let mut state = ActionState::new(ActionType::Harvest, 0);
+        state.target_entity = Some(bush);
+        state.ticks_remaining = 0;
+        active.insert(state);
+    }
+    world.tick(2);
+
+    let mind = world.get::<MindGraph>(witness);
+    let affords = mind.query(
+        Some(&Node::Concept(Concept::BerryBush)),
+        Some(Predicate::Affords),
+        Some(&Value::Action(ActionType::Harvest)),
+    );
+    assert_eq!(
+        affords.len(),
+        1,
+        "witness should learn BerryBush affords Harvest"
+    );
+    assert_eq!(affords[0].meta.source, Source::Observed);
+    assert_eq!(affords[0].meta.informant, Some(gatherer));
+    assert!(
+        harvest.is_plan_valid(&target, mind),
+        "after watching, the witness should consider harvesting the bush"
+    );
+}
@@ -0,0 +1,65 @@
+//! Sudden pain is emotionally salient: a fresh injury spikes fear at once
+//! (and anger in disagreeable agents), while pain that lingers does not
+//! keep re-triggering it.
+
+use bevy::prelude::*;
+use worldsim::agent::biology::body::{Body, Injury, InjuryType};
+use worldsim::agent::events::SimEventKind;
+use worldsim::agent::psyche::emotions::{EmotionType, EmotionalState};
+use worldsim::agent::psyche::personality::{Personality, PersonalityTraits};
+use worldsim::testing::{AgentConfig, TestWorld};
+
+fn intensity(world: &TestWorld, agent: Entity, emotion: EmotionType) -> f32 {
+    world
+        .get::<EmotionalState>(agent)
+        .get_emotion_intensity(emotion)
+}
+
+#[test]
+fn sudden_injury_spikes_fear_immediately() {
+    let mut world = TestWorld::with_seed(3);
+    let agent = world.spawn_agent(AgentConfig::default());
+    world.get_mut::<Personality>(agent).traits =
+        PersonalityTraits::uniform(0.5, 0.5, 0.5, 0.1, 0.5);
+    world.tick(2);
+    let fear_before = intensity(&world, agent, EmotionType::Fear);
+    let anger_before = intensity(&world, agent, EmotionType::Anger);
+
+    {
+        let mut body = world
+            .app_mut()
+            .world_mut()
+            .get_mut::<Body>(agent)
+            .expect("agent should have a Body");
+        body.parts[0].injuries.push(Injury {
+            injury_type: InjuryType::Slash,
+            severity: 0.3,
+            pain: 5.0,
+            healed_amount: 0.0,
+            bleed_rate: 0.0,
+        });
+    }
+    world.tick(1);
+
+    let fear_spike = intensity(&world, agent, EmotionType::Fear);
+    assert!(
+        fear_spike - fear_before >= 0.5,
+        "a fresh injury should frighten at once (fear {fear_before} -> {fear_spike})"
+    );
+    assert!(
+        intensity(&world, agent, EmotionType::Anger) > anger_before,
+        "a disagreeable agent should also get angry"
+    );
+    assert!(world.sim_events().all().iter().any(|e| matches!(
+        e.kind,
+        SimEventKind::EmotionTriggered {
+            agent: a,
+            emotion: EmotionType::Fear,
+            ..
+        } if a == agent
+    )));
+
+    // The wound still hurts, but it is no longer news.
+    world.tick(30);
+    assert!(intensity(&world, agent, EmotionType::Fear) <= fear_spike);
+}
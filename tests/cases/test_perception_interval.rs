@@ -0,0 +1,67 @@
+//! Integration tests for the configurable perception interval.
+//!
+//! With `PerceptionConfig::interval_ticks` above 1 an agent takes a fresh
+//! look only every N ticks. Its percepts must land on that cadence, and
+//! what it saw must persist in between so behavior keeps working.
+
+use worldsim::agent::mind::knowledge::{MindGraph, Node, Predicate};
+use worldsim::agent::mind::perception::{PerceptionConfig, VisibleObjects};
+use worldsim::testing::TestWorld;
+
+const INTERVAL: u32 = 5;
+
+fn last_seen_tick(
+    world: &TestWorld,
+    observer: bevy::prelude::Entity,
+    target: bevy::prelude::Entity,
+) -> Option<u64> {
+    world
+        .get::<MindGraph>(observer)
+        .query(
+            Some(&Node::Entity(target)),
+            Some(Predicate::LocatedAt),
+            None,
+        )
+        .first()
+        .map(|t| t.meta.timestamp)
+}
+
+#[test]
+fn percepts_are_written_every_interval_and_persist_in_between() {
+    let (mut world, a, b) = TestWorld::two_strangers(42);
+    world
+        .app_mut()
+        .world_mut()
+        .insert_resource(PerceptionConfig {
+            interval_ticks: INTERVAL,
+            ..Default::default()
+        });
+    // Let the spatial index fill and the first look land.
+    world.tick(u64::from(INTERVAL) * 2);
+
+    let mut writes: Vec<u64> = Vec::new();
+    for _ in 0..INTERVAL * 4 {
+        world.tick(1);
+        assert!(
+            world.get::<VisibleObjects>(a).entities.contains(&b),
+            "a keeps seeing b between looks"
+        );
+        let seen = last_seen_tick(&world, a, b).expect("a has a location belief for b");
+        if writes.last() != Some(&seen) {
+            writes.push(seen);
+        }
+    }
+
+    assert!(
+        writes.len() >= 3,
+        "expected several looks over {} ticks, got {writes:?}",
+        INTERVAL * 4
+    );
+    for pair in writes.windows(2) {
+        assert_eq!(
+            pair[1] - pair[0],
+            u64::from(INTERVAL),
+            "looks should land every {INTERVAL} ticks: {writes:?}"
+        );
+    }
+}
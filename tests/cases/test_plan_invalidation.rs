@@ -260,3 +260,88 @@ fn retain_sweep_emits_plan_abandoned_for_stale_urgency() {
          relative cutoff"
     );
 }
+
+/// Harvest target of the first plan in `agent`'s memory that has one.
+fn planned_harvest_target(world: &TestWorld, agent: Entity) -> Option<Entity> {
+    world
+        .get::<PlanMemory>(agent)
+        .plans
+        .iter()
+        .find_map(|plan| {
+            plan.steps
+                .iter()
+                .find(|step| step.action_type == ActionType::Harvest)
+                .and_then(|step| step.target_entity)
+        })
+}
+
+/// A harvest target despawned while the agent is still walking to it must
+/// kill the plan outright (TargetGone) and the brain must replan toward
+/// the other bush right away, not at the next 60-tick thinking interval.
+#[test]
+fn despawned_harvest_target_invalidates_plan_and_replans_elsewhere() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(32, 32)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(100.0, 100.0))
+        .hunger_urgency(0.9)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    let east_bush = world.spawn_berry_bush(Vec2::new(180.0, 100.0), 5);
+    let north_bush = world.spawn_berry_bush(Vec2::new(100.0, 200.0), 5);
+
+    let mut doomed = None;
+    for _ in 0..300 {
+        world.tick(1);
+        if let Some(target) = planned_harvest_target(&world, alice) {
+            doomed = Some(target);
+            break;
+        }
+    }
+    let doomed = doomed.expect("hungry alice should plan to harvest one of the bushes");
+    let other = if doomed == east_bush {
+        north_bush
+    } else {
+        east_bush
+    };
+
+    world.app_mut().world_mut().despawn(doomed);
+
+    let mut replanned = false;
+    for _ in 0..3 {
+        world.tick(1);
+        if planned_harvest_target(&world, alice) == Some(other) {
+            replanned = true;
+            break;
+        }
+    }
+
+    let abandoned = world.sim_events().all().iter().any(|e| {
+        matches!(
+            &e.kind,
+            SimEventKind::PlanAbandoned {
+                agent,
+                reason: PlanAbandonReason::TargetGone,
+                ..
+            } if *agent == alice
+        )
+    });
+    assert!(
+        abandoned,
+        "the plan targeting the despawned bush must be abandoned as TargetGone"
+    );
+    assert!(
+        replanned,
+        "alice should replan toward the remaining bush within a few ticks"
+    );
+    assert!(
+        world
+            .get::<PlanMemory>(alice)
+            .plans
+            .iter()
+            .all(|plan| plan.steps.iter().all(|s| s.target_entity != Some(doomed))),
+        "no held plan may still reference the despawned bush"
+    );
+}
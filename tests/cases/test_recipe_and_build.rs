@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
 use worldsim::agent::brains::thinking::{Goal, TriplePattern};
 use worldsim::agent::culture::{Culture, create_cultural_knowledge};
 use worldsim::agent::mind::knowledge::{
@@ -154,9 +154,11 @@ fn goap_plans_harvest_then_build() {
         &mind,
         None,
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     assert!(plan.is_some(), "Planner must find a plan");
 
@@ -222,6 +224,7 @@ fn build_action_consumes_wood() {
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     };
 
     assert!(
@@ -298,6 +301,7 @@ fn build_fails_without_materials() {
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     };
 
     assert!(
@@ -169,7 +169,7 @@ fn rest_quality_never_exceeds_one() {
 #[test]
 fn unrested_agent_with_wood_plans_build_lean_to_for_rest_quality_goal() {
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Quantity, Triple, setup_ontology};
 
@@ -221,9 +221,11 @@ fn unrested_agent_with_wood_plans_build_lean_to_for_rest_quality_goal() {
         &mind,
         Some(&inventory),
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     let plan = plan.unwrap_or_else(|| {
         panic!(
@@ -267,7 +269,7 @@ fn unrested_agent_with_wood_plans_build_lean_to_for_rest_quality_goal() {
 #[test]
 fn agent_with_only_wood_cannot_plan_build_house() {
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Triple, setup_ontology};
 
@@ -328,9 +330,11 @@ fn agent_with_only_wood_cannot_plan_build_house() {
         &mind,
         Some(&inventory),
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &[build_house, rest_in_shelter],
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
 
     assert!(
@@ -38,6 +38,7 @@ fn ctx_with_needs<'a>(
         emotional: None,
         current_tick: 0,
         unreachable_tiles: &[],
+        skills: None,
     }
 }
 
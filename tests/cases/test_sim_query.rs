@@ -0,0 +1,94 @@
+//! Exercises the `sim::query` facade against a small two-agent scenario:
+//! relationships, beliefs, and held goals read without touching the
+//! underlying components directly.
+
+use bevy::math::Vec2;
+use worldsim::agent::brains::plan_memory::{HeldPlan, PlanMemory, PlanSource, PlanState};
+use worldsim::agent::brains::proposal::BrainType;
+use worldsim::agent::brains::thinking::{Goal, TriplePattern};
+use worldsim::agent::mind::knowledge::{Concept, Metadata, Node, Predicate, Triple, Value};
+use worldsim::agent::nervous_system::urgency::UrgencySource;
+use worldsim::sim::query;
+use worldsim::testing::TestWorld;
+
+fn campfire_goal() -> Goal {
+    Goal {
+        conditions: vec![TriplePattern::new(
+            Some(Node::Self_),
+            Some(Predicate::Contains),
+            Some(Value::Item(Concept::Campfire, 1)),
+        )],
+        priority: 1.0,
+    }
+}
+
+#[test]
+fn query_facade_reads_relationships_beliefs_and_goals() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(200.0, 200.0))
+        .knowledge(vec![Triple::with_meta(
+            Node::Concept(Concept::BerryBush),
+            Predicate::Produces,
+            Value::Item(Concept::Berry, 1),
+            Metadata::semantic(0),
+        )])
+        .done()
+        .agent("bob")
+        .pos(Vec2::new(260.0, 200.0))
+        .done()
+        .relationship("alice", "bob", |r| r.trust(0.5).affection(0.6))
+        .build();
+    let alice = agents["alice"];
+    let bob = agents["bob"];
+
+    let (trust, affection) = query::relationship(world.app().world(), alice, bob);
+    assert!((trust - 0.5).abs() < 1e-4, "trust was {trust}");
+    assert!((affection - 0.6).abs() < 1e-4, "affection was {affection}");
+
+    let berry_bush = Node::Concept(Concept::BerryBush);
+    let berry = Value::Item(Concept::Berry, 1);
+    assert!(query::belief_exists(
+        world.app().world(),
+        alice,
+        &berry_bush,
+        Predicate::Produces,
+        &berry,
+    ));
+    assert!(!query::belief_exists(
+        world.app().world(),
+        bob,
+        &berry_bush,
+        Predicate::Produces,
+        &berry,
+    ));
+
+    {
+        let world_mut = world.app_mut().world_mut();
+        let mut memory = world_mut
+            .get_mut::<PlanMemory>(alice)
+            .expect("alice should have PlanMemory");
+        let id = memory.mint_plan_id();
+        memory.insert(HeldPlan {
+            id,
+            goal: campfire_goal(),
+            steps: Vec::new(),
+            state: PlanState::Background,
+            commitment: 0.0,
+            subjective_cost: 0.0,
+            source: PlanSource::Brain(BrainType::Rational),
+            driving_urgency: UrgencySource::Warmth,
+            created_at_urgency: 0.0,
+            created_at: 0,
+            last_touched: 0,
+            current_step: 0,
+        });
+    }
+
+    let wants_campfire = query::agents_with_goal(world.app_mut().world_mut(), |goal| {
+        goal.target_concept() == Some(Concept::Campfire)
+    });
+    assert_eq!(wants_campfire, vec![alice]);
+}
@@ -0,0 +1,62 @@
+//! Single-step while paused: a `TickCount::stepping` cycle advances exactly
+//! one tick and runs the whole simulation — rational planning and action
+//! execution included — before the world pauses again.
+
+use bevy::math::Vec2;
+use bevy::prelude::Transform;
+use worldsim::agent::brains::plan_memory::PlanMemory;
+use worldsim::core::tick::TickCount;
+use worldsim::testing::TestWorld;
+
+#[test]
+fn paused_world_advances_one_tick_per_step_and_agents_still_act() {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(32, 32)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(100.0, 100.0))
+        .hunger_urgency(0.9)
+        .done()
+        .build();
+    let alice = agents["alice"];
+    world.spawn_berry_bush(Vec2::new(200.0, 100.0), 5);
+    world
+        .app_mut()
+        .world_mut()
+        .resource_mut::<TickCount>()
+        .paused = true;
+
+    let start_tick = world.current_tick();
+    let start_pos = world.get::<Transform>(alice).translation;
+    world.tick(50);
+    assert_eq!(
+        world.current_tick(),
+        start_tick,
+        "paused world must not tick"
+    );
+    assert!(world.get::<PlanMemory>(alice).plans.is_empty());
+    assert_eq!(world.get::<Transform>(alice).translation, start_pos);
+
+    let mut planned = false;
+    let mut moved = false;
+    for step in 1..=300 {
+        world
+            .app_mut()
+            .world_mut()
+            .resource_mut::<TickCount>()
+            .stepping = true;
+        world.tick(1);
+
+        let tick = world.app().world().resource::<TickCount>();
+        assert_eq!(tick.current, start_tick + step, "one tick per step");
+        assert!(tick.paused && !tick.stepping, "step re-pauses");
+
+        planned |= !world.get::<PlanMemory>(alice).plans.is_empty();
+        moved |= world.get::<Transform>(alice).translation != start_pos;
+        if planned && moved {
+            break;
+        }
+    }
+    assert!(planned, "rational planning should run during steps");
+    assert!(moved, "action execution should move alice during steps");
+}
@@ -11,8 +11,13 @@
 //! 2. The belief updater records `(Tile, HasTrait, Unreachable)` in the mind.
 //! 3. `generate_implicit_walk` skips tiles marked Unreachable, so the next
 //!    replan picks a different (reachable) goal.
+//!
+//! With a world map the planner now drops walk targets no A* route reaches,
+//! so a target behind an unbroken wall never gets planned in the first place.
 
 use bevy::math::Vec2;
+use worldsim::agent::actions::ActionType;
+use worldsim::agent::events::SimEventKind;
 use worldsim::agent::mind::knowledge::{Concept, MindGraph, Node, Predicate, Value};
 use worldsim::testing::TestWorld;
 use worldsim::world::map::{TILE_SIZE, TileType};
@@ -26,19 +31,18 @@ fn tile_of(pos: Vec2) -> (i32, i32) {
 }
 
 #[test]
-fn agent_with_unreachable_target_marks_it_and_replans() {
+fn agent_never_commits_to_a_bush_behind_an_unbroken_wall() {
     // 32x32 grass map with a vertical wall of water splitting it in half.
     // Agent spawns on the left at tile (3, 3); the only berry bush is on
-    // the right side of the wall. Without the fix the agent would plan
-    // Walk→Harvest→Eat, try to straight-line through the water wall, have
-    // the walker silently report "completed", and re-plan the same walk
-    // every tick forever. With the fix the walker emits PathBlocked, the
-    // belief updater writes an Unreachable triple, and the planner stops
-    // picking that tile.
+    // the right side of the wall. Before #364 the agent planned
+    // Walk→Harvest→Eat, straight-lined into the water, and re-planned the
+    // same walk every tick forever. The planner now prices walks by their
+    // A* route over the map, so a bush no route reaches is never planned
+    // for at all — the PathBlocked → Unreachable loop remains the fallback
+    // for terrain that changes under a walk already underway.
     //
     // Water column at tile x=6 (world x range 96-112). Agent at (50, 50)
-    // → tile (3, 3). Bush at (200, 50) → tile (12, 3). The straight-line
-    // path crosses water.
+    // → tile (3, 3). Bush at (200, 50) → tile (12, 3).
     let wall_tile_x: u32 = 6;
     let (mut world, agents) = TestWorld::scenario(42)
         .map_size(32, 32)
@@ -52,43 +56,35 @@ fn agent_with_unreachable_target_marks_it_and_replans() {
         .build();
     let starver = agents["starver"];
 
-    // Tick long enough for perception + a full brain cycle or two. The
-    // thinking interval is 60 ticks; 600 ticks is ~10 brain ticks, plenty
-    // of room to try the blocked target and record the failure.
+    // ~10 brain ticks at the 60-tick thinking interval.
     for _ in 0..600 {
         world.tick(1);
     }
 
-    // The core assertion: the mind graph contains at least one tile marked
-    // Unreachable. Without the fix no such triple ever gets written because
-    // the walker silently "completes" blocked walks.
-    let mind = world.get::<MindGraph>(starver);
-    let unreachable_tiles: Vec<_> = mind
-        .query(
-            None,
-            Some(Predicate::HasTrait),
-            Some(&Value::Concept(Concept::Unreachable)),
+    let harvested = world.sim_events().all().iter().any(|e| {
+        matches!(
+            e.kind,
+            SimEventKind::ActionStarted {
+                agent,
+                action: ActionType::Harvest,
+                ..
+            } if agent == starver
         )
-        .into_iter()
-        .filter_map(|t| match t.subject {
-            Node::Tile(tile) => Some(tile),
-            _ => None,
-        })
-        .collect();
+    });
+    let pos = world
+        .app_mut()
+        .world()
+        .get::<bevy::prelude::Transform>(starver)
+        .map(|t| t.translation.truncate())
+        .unwrap_or_default();
+    assert!(
+        !harvested,
+        "agent started a Harvest on a bush no route reaches"
+    );
     assert!(
-        !unreachable_tiles.is_empty(),
-        "expected at least one tile marked Unreachable after 600 ticks of \
-         trying to reach a bush behind a water wall; found none. \
-         Agent position: {:?}, last action: {:?}",
-        tile_of(
-            world
-                .app_mut()
-                .world()
-                .get::<bevy::prelude::Transform>(starver)
-                .map(|t| t.translation.truncate())
-                .unwrap_or_default()
-        ),
-        world.current_action(starver),
+        tile_of(pos).0 < wall_tile_x as i32,
+        "agent crossed an unbroken wall: {:?}",
+        tile_of(pos)
     );
 }
 
@@ -123,7 +123,7 @@ fn warmth_never_exceeds_one() {
 #[test]
 fn cold_agent_with_wood_plans_build_for_warmth_goal() {
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Quantity, Triple, setup_ontology};
 
@@ -178,9 +178,11 @@ fn cold_agent_with_wood_plans_build_for_warmth_goal() {
         &mind,
         Some(&inventory),
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     let plan = plan.unwrap_or_else(|| {
         panic!(
@@ -226,7 +228,7 @@ fn cold_agent_with_wood_plans_build_for_warmth_goal() {
 fn agent_on_known_campfire_plans_warm_up_without_build() {
     use bevy::prelude::Entity;
     use worldsim::agent::actions::{ActionRegistry, ActionType, TargetCandidate};
-    use worldsim::agent::brains::planner::{PlanCostContext, regressive_plan};
+    use worldsim::agent::brains::planner::{PlanCostContext, PlannerConfig, regressive_plan};
     use worldsim::agent::brains::thinking::TriplePattern;
     use worldsim::agent::mind::knowledge::{Quantity, Triple, setup_ontology};
 
@@ -274,9 +276,11 @@ fn agent_on_known_campfire_plans_warm_up_without_build() {
         &mind,
         None,
         &worldsim::world::entity_positions::WorldEntityPositions::default(),
+        None,
         &goal,
         &available,
         &PlanCostContext::neutral(),
+        &PlannerConfig::default(),
     );
     let plan = plan.expect("Planner must close warmth goal when already at a campfire");
 
@@ -0,0 +1,44 @@
+//! Weather and time of day color mood: sunshine lifts it, storms and
+//! darkness weigh it down.
+
+use bevy::prelude::*;
+use worldsim::agent::psyche::emotions::EmotionalState;
+use worldsim::core::GameTime;
+use worldsim::core::tick::TickCount;
+use worldsim::testing::TestWorld;
+use worldsim::world::weather::Weather;
+
+/// First tick at which the wall clock reads exactly `hour`:00.
+fn tick_at_hour(hour: f32) -> u64 {
+    (0..GameTime::TICKS_PER_DAY)
+        .step_by(GameTime::TICKS_PER_MINUTE as usize)
+        .find(|t| GameTime::hour_at_tick(*t) == hour)
+        .expect("every hour occurs once a day")
+}
+
+fn settled_mood(weather: Weather, hour: f32) -> f32 {
+    let (mut world, agents) = TestWorld::scenario(42)
+        .map_size(64, 64)
+        .noise_biomes(false)
+        .agent("alice")
+        .pos(Vec2::new(100.0, 100.0))
+        .done()
+        .build();
+    let app_world = world.app_mut().world_mut();
+    app_world.insert_resource(weather);
+    app_world.resource_mut::<TickCount>().current = tick_at_hour(hour);
+
+    world.tick(300);
+    world.get::<EmotionalState>(agents["alice"]).current_mood
+}
+
+#[test]
+fn clear_day_mood_is_higher_than_stormy_night() {
+    let clear_noon = settled_mood(Weather::Clear, 12.0);
+    let stormy_midnight = settled_mood(Weather::Storm, 0.0);
+
+    assert!(
+        clear_noon > stormy_midnight + 0.1,
+        "clear noon mood {clear_noon:.3} should beat stormy midnight {stormy_midnight:.3}"
+    );
+}
@@ -17,9 +17,18 @@ mod scenario_learning;
 #[path = "cases/test_affective_tom.rs"]
 mod test_affective_tom;
 
+#[path = "cases/test_alarm_signal.rs"]
+mod test_alarm_signal;
+
+#[path = "cases/test_ambient_spawn.rs"]
+mod test_ambient_spawn;
+
 #[path = "cases/test_anticipation_forecast.rs"]
 mod test_anticipation_forecast;
 
+#[path = "cases/test_archetypes.rs"]
+mod test_archetypes;
+
 #[path = "cases/test_becomes_substrate.rs"]
 mod test_becomes_substrate;
 
@@ -44,6 +53,9 @@ mod test_campfire_ownership;
 #[path = "cases/test_cooking.rs"]
 mod test_cooking;
 
+#[path = "cases/test_corpse_decay.rs"]
+mod test_corpse_decay;
+
 #[path = "cases/test_culture.rs"]
 mod test_culture;
 
@@ -59,6 +71,9 @@ mod test_deposit_and_take;
 #[path = "cases/test_despawn_cancels_action.rs"]
 mod test_despawn_cancels_action;
 
+#[path = "cases/test_drop_item.rs"]
+mod test_drop_item;
+
 #[path = "cases/test_eat_harvest_cycle.rs"]
 mod test_eat_harvest_cycle;
 
@@ -68,6 +83,9 @@ mod test_effort_model;
 #[path = "cases/test_emits_effect_substrate.rs"]
 mod test_emits_effect_substrate;
 
+#[path = "cases/test_emotion_journal.rs"]
+mod test_emotion_journal;
+
 #[path = "cases/test_entity_emotions.rs"]
 mod test_entity_emotions;
 
@@ -80,9 +98,15 @@ mod test_food_security_drive;
 #[path = "cases/test_fresh_agent_urgency_bootstrap.rs"]
 mod test_fresh_agent_urgency_bootstrap;
 
+#[path = "cases/test_give.rs"]
+mod test_give;
+
 #[path = "cases/test_graze.rs"]
 mod test_graze;
 
+#[path = "cases/test_ground_item_pickup.rs"]
+mod test_ground_item_pickup;
+
 #[path = "cases/test_harvest_empty_belief_update.rs"]
 mod test_harvest_empty_belief_update;
 
@@ -92,6 +116,9 @@ mod test_harvest_knowledge;
 #[path = "cases/test_harvestable_materials.rs"]
 mod test_harvestable_materials;
 
+#[path = "cases/test_heard_combat.rs"]
+mod test_heard_combat;
+
 #[path = "cases/test_human_actions.rs"]
 mod test_human_actions;
 
@@ -101,9 +128,15 @@ mod test_hunger_timing;
 #[path = "cases/test_hunting_loop.rs"]
 mod test_hunting_loop;
 
+#[path = "cases/test_interaction_facing.rs"]
+mod test_interaction_facing;
+
 #[path = "cases/test_item_properties.rs"]
 mod test_item_properties;
 
+#[path = "cases/test_known_empty.rs"]
+mod test_known_empty;
+
 #[path = "cases/test_labor_accumulation.rs"]
 mod test_labor_accumulation;
 
@@ -116,21 +149,36 @@ mod test_look_for_fallback;
 #[path = "cases/test_main_menu.rs"]
 mod test_main_menu;
 
+#[path = "cases/test_milestones.rs"]
+mod test_milestones;
+
 #[path = "cases/test_movement.rs"]
 mod test_movement;
 
 #[path = "cases/test_multi_sense_perception.rs"]
 mod test_multi_sense_perception;
 
+#[path = "cases/test_need_neglect.rs"]
+mod test_need_neglect;
+
 #[path = "cases/test_observability.rs"]
 mod test_observability;
 
+#[path = "cases/test_observational_learning.rs"]
+mod test_observational_learning;
+
 #[path = "cases/test_other_regarding.rs"]
 mod test_other_regarding;
 
+#[path = "cases/test_pain_emotion.rs"]
+mod test_pain_emotion;
+
 #[path = "cases/test_perception_cache.rs"]
 mod test_perception_cache;
 
+#[path = "cases/test_perception_interval.rs"]
+mod test_perception_interval;
+
 #[path = "cases/test_plan_invalidation.rs"]
 mod test_plan_invalidation;
 
@@ -146,6 +194,9 @@ mod test_reactive_drift;
 #[path = "cases/test_recipe_and_build.rs"]
 mod test_recipe_and_build;
 
+#[path = "cases/test_recovery_drive.rs"]
+mod test_recovery_drive;
+
 #[path = "cases/test_relationship_decay.rs"]
 mod test_relationship_decay;
 
@@ -167,6 +218,12 @@ mod test_second_human_group;
 #[path = "cases/test_sim_events.rs"]
 mod test_sim_events;
 
+#[path = "cases/test_sim_query.rs"]
+mod test_sim_query;
+
+#[path = "cases/test_single_step.rs"]
+mod test_single_step;
+
 #[path = "cases/test_skills.rs"]
 mod test_skills;
 
@@ -206,6 +263,9 @@ mod test_walker_path_blocked;
 #[path = "cases/test_warmth_drive.rs"]
 mod test_warmth_drive;
 
+#[path = "cases/test_weather_mood.rs"]
+mod test_weather_mood;
+
 #[path = "cases/test_wolf.rs"]
 mod test_wolf;
 